@@ -41,10 +41,18 @@ w: number,
  */
 h: number, };
 export type Axis = "X" | "Y";
-export type BorderSelection = "all" | "inner" | "outer" | "horizontal" | "vertical" | "left" | "top" | "right" | "bottom" | "clear";
-export type BorderSide = "Top" | "Bottom" | "Left" | "Right";
+export type BorderSelection = "all" | "inner" | "outer" | "horizontal" | "vertical" | "left" | "top" | "right" | "bottom" | "diagonalup" | "diagonaldown" | "clear";
+export type BorderSide = "Top" | "Bottom" | "Left" | "Right" | "DiagonalUp" | "DiagonalDown";
 export type BorderStyle = { color: Rgba, line: CellBorderLine, };
-export type BorderStyleCell = { top: BorderStyleTimestamp | null, bottom: BorderStyleTimestamp | null, left: BorderStyleTimestamp | null, right: BorderStyleTimestamp | null, };
+export type BorderStyleCell = { top: BorderStyleTimestamp | null, bottom: BorderStyleTimestamp | null, left: BorderStyleTimestamp | null, right: BorderStyleTimestamp | null, 
+/**
+ * Diagonal from the bottom-left to the top-right corner.
+ */
+diagonal_up: BorderStyleTimestamp | null, 
+/**
+ * Diagonal from the top-left to the bottom-right corner.
+ */
+diagonal_down: BorderStyleTimestamp | null, };
 export type BorderStyleTimestamp = { color: Rgba, line: CellBorderLine, timestamp: SmallTimestamp, };
 export type JsCellsA1Error = { core_error: string, };
 export type JsCellsA1Response = { values: JsCellsA1Values | null, error: JsCellsA1Error | null, };
@@ -52,25 +60,118 @@ export type JsCellsA1Value = { x: number, y: number, v: string, t: number, };
 export type JsCellsA1Values = { cells: Array<JsCellsA1Value>, x: number, y: number, w: number, h: number, one_dimensional: boolean, two_dimensional: boolean, has_headers: boolean, };
 export type JsCellValueResult = [string, number];
 export type CellAlign = "center" | "left" | "right";
-export type CellBorderLine = "line1" | "line2" | "line3" | "dotted" | "dashed" | "double" | "clear";
-export type CellFormatSummary = { bold: boolean | null, italic: boolean | null, commas: boolean | null, textColor: string | null, fillColor: string | null, align: CellAlign | null, verticalAlign: CellVerticalAlign | null, wrap: CellWrap | null, dateTime: string | null, cellType: CellType | null, underline: boolean | null, strikeThrough: boolean | null, };
+export type CellBorderLine = "line1" | "line2" | "line3" | "dotted" | "dashed" | "double" | "hair" | "mediumdashed" | "dashdot" | "mediumdashdot" | "dashdotdot" | "mediumdashdotdot" | "slantdashdot" | "clear";
+export type CellFormatSummary = { bold: boolean | null, italic: boolean | null, commas: boolean | null, textColor: string | null, fillColor: string | null, align: CellAlign | null, verticalAlign: CellVerticalAlign | null, wrap: CellWrap | null, dateTime: string | null, cellType: CellType | null, underline: boolean | null, strikeThrough: boolean | null, fontSize: number | null, fontFamily: string | null, textRotation: number | null, indent: number | null, 
+/**
+ * Name of the named cell style.
+ */
+style: string | null, };
 export type CellRefCoord = { coord: bigint, is_absolute: boolean, };
 export type CellRefRange = { range: RefRangeBounds, } | { range: TableRef, };
 export type CellRefRangeEnd = { col: CellRefCoord, row: CellRefCoord, };
+export type CellStyle = { name: string, 
+/**
+ * Attributes that the style sets. Colors may reference the theme.
+ */
+format: Format, borders: CellStyleBorders, };
+export type CellStyleBorder = { 
+/**
+ * Css color, or a theme color (eg, `theme:accent1`).
+ */
+color: string, line: CellBorderLine, };
+export type CellStyleBorders = { top: CellStyleBorder | null, bottom: CellStyleBorder | null, left: CellStyleBorder | null, right: CellStyleBorder | null, };
 export type CellVerticalAlign = "top" | "middle" | "bottom";
 export type CellWrap = "overflow" | "wrap" | "clip";
 export type CodeCellLanguage = "Python" | "Formula" | { "Connection": { kind: ConnectionKind, id: string, } } | "Javascript" | "Import";
+export type ColorScalePoint = { value: ScaleValue, color: string, };
 export type ColumnRow = { column: number, row: number, };
+export type Comment = { id: string, author: string, created: string, text: string, 
+/**
+ * Users mentioned in the text with @name (without the @).
+ */
+mentions: Array<string>, };
+export type CommentThread = { comments: Array<Comment>, resolved: boolean, };
+export type CompareOperator = "Equal" | "NotEqual" | "GreaterThan" | "GreaterThanOrEqual" | "LessThan" | "LessThanOrEqual" | "Between" | "NotBetween";
+export type ConditionalFormat = { id: string, selection: A1Selection, rule: ConditionalFormatRule, };
+export type ConditionalFormatCondition = { "Compare": { operator: CompareOperator, values: Array<string>, } } | { "Text": { operator: TextOperator, text: string, } } | { "DateOccurring": DateOccurring } | "Duplicate" | "Unique" | { "TopBottom": { top: boolean, rank: number, percent: boolean, } } | { "Average": { above: boolean, or_equal: boolean, } } | { "Formula": string };
+export type ConditionalFormatRule = { "Highlight": { condition: ConditionalFormatCondition, style: ConditionalFormatStyle, } } | { "ColorScale": Array<ColorScalePoint> } | { "DataBar": DataBar } | { "IconSet": IconSet };
+export type ConditionalFormatStyle = { fill_color: string | null, text_color: string | null, bold: boolean | null, italic: boolean | null, underline: boolean | null, strike_through: boolean | null, };
 export type ConnectionKind = "POSTGRES" | "MYSQL" | "MSSQL" | "SNOWFLAKE";
+export type DataCleaningAction = "TrimWhitespace" | { "NormalizeCase": DataCleaningCase } | "ConvertText" | "FillBlanksDown";
+export type DataCleaningCase = "Upper" | "Lower" | "Title";
+export type DataCleaningSummary = { cells_changed: bigint, rows_removed: bigint, };
+export type DataBar = { min: ScaleValue, max: ScaleValue, color: string, show_value: boolean, };
+export type DataTableAggregate = "Sum" | "Average" | "Count" | "CountA" | "Min" | "Max";
+export type DataTableJoinKind = "Inner" | "Left" | "Outer";
 export type DataTableSort = { column_index: number, direction: SortDirection, };
+export type DataTableTransform = { "GroupBy": { table: string, group_by: Array<string>, values: Array<string>, aggregate: DataTableAggregate, } } | { "Unpivot": { table: string, id_columns: Array<string>, attribute_header: string | null, value_header: string | null, } } | { "Pivot": { table: string, rows: Array<string>, column: string, value: string, aggregate: DataTableAggregate, } } | { "Join": { left: string, right: string, left_on: Array<string>, right_on: Array<string>, kind: DataTableJoinKind, } };
+export type DateOccurring = "Yesterday" | "Today" | "Tomorrow" | "Last7Days" | "LastWeek" | "ThisWeek" | "NextWeek" | "LastMonth" | "ThisMonth" | "NextMonth";
+export type DateOrder = "Mdy" | "Dmy" | "Ymd";
+export type DateSettings = { date_system: DateSystem, 
+/**
+ * Excel compatibility mode: numbers passed to date functions are read as
+ * serial dates, and date functions (eg, `DATEVALUE`) return serial
+ * numbers instead of dates.
+ */
+serial_dates: boolean, };
+export type DateSystem = "Excel1900" | "Excel1904";
 export type DateTimeRange = { "DateRange": [bigint | null, bigint | null] } | { "DateEqual": Array<bigint> } | { "DateNotEqual": Array<bigint> } | { "TimeRange": [number | null, number | null] } | { "TimeEqual": Array<number> } | { "TimeNotEqual": Array<number> };
 export type Direction = "Up" | "Down" | "Left" | "Right";
-export type Format = { align: CellAlign | null, vertical_align: CellVerticalAlign | null, wrap: CellWrap | null, numeric_format: NumericFormat | null, numeric_decimals: number | null, numeric_commas: boolean | null, bold: boolean | null, italic: boolean | null, text_color: string | null, fill_color: string | null, date_time: string | null, underline: boolean | null, strike_through: boolean | null, };
-export type FormatUpdate = { align: CellAlign | null | null, vertical_align: CellVerticalAlign | null | null, wrap: CellWrap | null | null, numeric_format: NumericFormat | null | null, numeric_decimals: number | null | null, numeric_commas: boolean | null | null, bold: boolean | null | null, italic: boolean | null | null, text_color: string | null | null, fill_color: string | null | null, render_size: RenderSize | null | null, date_time: string | null | null, underline: boolean | null | null, strike_through: boolean | null | null, };
+export type DuplicateKeep = "First" | "Last";
+export type Format = { align: CellAlign | null, vertical_align: CellVerticalAlign | null, wrap: CellWrap | null, numeric_format: NumericFormat | null, numeric_decimals: number | null, numeric_commas: boolean | null, bold: boolean | null, italic: boolean | null, text_color: string | null, fill_color: string | null, date_time: string | null, underline: boolean | null, strike_through: boolean | null, 
+/**
+ * Font size in points.
+ */
+font_size: number | null, font_family: string | null, 
+/**
+ * Counterclockwise rotation in degrees (-90 to 90), or `VERTICAL_TEXT`
+ * for letters stacked vertically.
+ */
+text_rotation: number | null, 
+/**
+ * Indent level (each level is the width of a few characters).
+ */
+indent: number | null, 
+/**
+ * Name of the named cell style applied to the cell. The style's
+ * attributes are stored in the other fields.
+ */
+style: string | null, };
+export type FormatUpdate = { align: CellAlign | null | null, vertical_align: CellVerticalAlign | null | null, wrap: CellWrap | null | null, numeric_format: NumericFormat | null | null, numeric_decimals: number | null | null, numeric_commas: boolean | null | null, bold: boolean | null | null, italic: boolean | null | null, text_color: string | null | null, fill_color: string | null | null, render_size: RenderSize | null | null, date_time: string | null | null, underline: boolean | null | null, strike_through: boolean | null | null, font_size: number | null | null, font_family: string | null | null, text_rotation: number | null | null, indent: number | null | null, 
+/**
+ * Name of the named cell style.
+ */
+style: string | null | null, };
 export type GridBounds = { "type": "empty" } | { "type": "nonEmpty" } & Rect;
+export type Hyperlink = { 
+/**
+ * The external URL, or `#` followed by an A1 reference for internal
+ * links.
+ */
+url: string, 
+/**
+ * The display text. The url is displayed when this is empty.
+ */
+text: string, };
+export type IconSet = { kind: IconSetKind, 
+/**
+ * The lowest value of each icon after the first one, in ascending order.
+ */
+thresholds: Array<ScaleValue>, 
+/**
+ * Uses the icons in the reverse order (ie, the first icon for the highest
+ * values).
+ */
+reverse: boolean, show_value: boolean, };
+export type IconSetKind = "Arrows3" | "TrafficLights3" | "Symbols3" | "Flags3" | "Arrows4" | "Rating4" | "Arrows5" | "Rating5";
+export type JsBorderDiagonal = { color: Rgba, line: CellBorderLine, x: bigint, y: bigint, width: bigint | null, height: bigint | null, 
+/**
+ * true for bottom-left to top-right, false for top-left to bottom-right
+ */
+up: boolean, };
 export type JsBorderHorizontal = { color: Rgba, line: CellBorderLine, x: bigint, y: bigint, width: bigint | null, unbounded: boolean, };
 export type JsBorderVertical = { color: Rgba, line: CellBorderLine, x: bigint, y: bigint, height: bigint | null, unbounded: boolean, };
-export type JsBordersSheet = { horizontal: Array<JsBorderHorizontal> | null, vertical: Array<JsBorderVertical> | null, };
+export type JsBordersSheet = { horizontal: Array<JsBorderHorizontal> | null, vertical: Array<JsBorderVertical> | null, diagonal: Array<JsBorderDiagonal> | null, };
 export type JsCellsAccessed = { sheetId: string, ranges: Array<CellRefRange>, };
 export type JsCellValue = { value: string, kind: string, };
 export type JsCellValuePos = { value: string, kind: string, pos: string, };
@@ -81,22 +182,78 @@ export type JsCodeCell = { x: bigint, y: bigint, code_string: string, language:
 export type JsCodeResult = { transaction_id: string, success: boolean, std_out: string | null, std_err: string | null, line_number: number | null, output_value: JsCellValueResult | null, output_array: Array<Array<JsCellValueResult>> | null, output_display_type: string | null, cancel_compute: boolean | null, chart_pixel_output: [number, number] | null, has_headers: boolean, };
 export type JsCodeTableContext = { sheet_name: string, code_table_name: string, all_columns: Array<string>, visible_columns: Array<string>, first_row_visible_values: Array<JsCellValuePos>, last_row_visible_values: Array<JsCellValuePos>, bounds: string, show_name: boolean, show_columns: boolean, language: CodeCellLanguage, code_string: string, std_err: string | null, error: boolean, spill: boolean, };
 export type JsColumnWidth = { column: bigint, width: number, };
+export type JsCommentThread = { x: bigint, y: bigint, thread: CommentThread, };
 export type JsCoordinate = { x: number, y: number, };
 export type JsDataTableColumnHeader = { name: string, display: boolean, valueIndex: number, };
 export type JsDataTableContext = { sheet_name: string, data_table_name: string, all_columns: Array<string>, visible_columns: Array<string>, first_row_visible_values: Array<JsCellValuePos>, last_row_visible_values: Array<JsCellValuePos>, bounds: string, show_name: boolean, show_columns: boolean, };
 export type JsFormulaParseResult = { parse_error_msg: string | null, parse_error_span: Span | null, cells_accessed: Array<JsCellsAccessed>, spans: Array<Span>, };
 export type JsHtmlOutput = { sheet_id: string, x: number, y: number, w: number, h: number, html: string | null, name: string, show_name: boolean, };
-export type JsNumber = { decimals: number | null, commas: boolean | null, format: NumericFormat | null, };
+export type JsNumber = { decimals: number | null, commas: boolean | null, format: NumericFormat | null, 
+/**
+ * Separators of the file's locale, set only when they differ from `.`
+ * and `,`.
+ */
+decimal_separator?: string, group_separator?: string, };
 export type JsOffset = { column: number | null, row: number | null, size: number, };
 export type JsRenderCell = { x: bigint, y: bigint, value: string, 
 /**
  * Code language, set only for the top left cell of a code output.
  */
-language: CodeCellLanguage | null, align: CellAlign | null, verticalAlign: CellVerticalAlign | null, wrap: CellWrap | null, bold: boolean | null, italic: boolean | null, textColor: string | null, special: JsRenderCellSpecial | null, number: JsNumber | null, underline: boolean | null, strikeThrough: boolean | null, columnHeader: boolean | null, };
-export type JsRenderCellSpecial = "Chart" | "SpillError" | "RunError" | "Logical" | "Checkbox" | "List";
+language: CodeCellLanguage | null, align: CellAlign | null, verticalAlign: CellVerticalAlign | null, wrap: CellWrap | null, bold: boolean | null, italic: boolean | null, textColor: string | null, special: JsRenderCellSpecial | null, number: JsNumber | null, underline: boolean | null, strikeThrough: boolean | null, 
+/**
+ * Font size in points.
+ */
+fontSize: number | null, fontFamily: string | null, 
+/**
+ * Rotation in degrees, or `VERTICAL_TEXT` for stacked letters.
+ */
+textRotation: number | null, indent: number | null, columnHeader: boolean | null, 
+/**
+ * The merged cell, set only for the top left cell of a merged cell.
+ */
+mergeRect: Rect | null, 
+/**
+ * The styled spans of rich text. `value` is the plain text. Styles that
+ * are not set on a span use the cell's style.
+ */
+richText: Array<TextSpan> | null, 
+/**
+ * Data bar from a conditional format.
+ */
+dataBar: JsRenderDataBar | null, 
+/**
+ * Icon from a conditional format icon set.
+ */
+icon: JsRenderIcon | null, 
+/**
+ * A character repeated to fill the cell, from a custom number format
+ * (eg, the `* ` in accounting formats).
+ */
+numberFill: JsNumberFill | null, 
+/**
+ * The selected values of a multi-select list, rendered as chips.
+ */
+chips: Array<string> | null, };
+export type JsRenderCellSpecial = "Chart" | "SpillError" | "RunError" | "Logical" | "Checkbox" | "List" | "MultiSelectList" | "Hyperlink";
 export type JsRenderCodeCell = { x: number, y: number, w: number, h: number, language: CodeCellLanguage, state: JsRenderCodeCellState, spill_error: Array<Pos> | null, name: string, columns: Array<JsDataTableColumnHeader>, first_row_header: boolean, sort: Array<DataTableSort> | null, sort_dirty: boolean, alternating_colors: boolean, is_code: boolean, is_html: boolean, is_html_image: boolean, show_name: boolean, show_columns: boolean, };
 export type JsRenderCodeCellState = "NotYetRun" | "RunError" | "SpillError" | "Success" | "HTML" | "Image";
+export type JsRenderDataBar = { color: string, 
+/**
+ * Width of the bar as a percent of the cell's width.
+ */
+percent: number, };
+export type JsNumberFill = { 
+/**
+ * The character index in the value where the fill is inserted.
+ */
+index: number, char: string, };
 export type JsRenderFill = { x: bigint, y: bigint, w: number, h: number, color: string, };
+export type JsRenderIcon = { iconSet: IconSetKind, 
+/**
+ * Index of the icon in the set, starting with the icon for the lowest
+ * values.
+ */
+index: number, };
 export type JsResponse = { result: boolean, error: string | null, };
 export type JsReturnInfo = { line_number: number | null, output_type: string | null, };
 export type JsRowHeight = { row: bigint, height: number, };
@@ -107,10 +264,44 @@ export type JsSummarizeSelectionResult = { count: bigint, sum: number | null, av
 export type JsTableInfo = { name: string, sheet_name: string, chart: boolean, language: CodeCellLanguage, };
 export type JsTablesContext = { sheet_name: string, data_tables: Array<JsDataTableContext>, code_tables: Array<JsCodeTableContext>, charts: Array<JsChartContext>, };
 export type JsValidationWarning = { x: bigint, y: bigint, validation: string | null, style: ValidationStyle | null, };
+export type Locale = { 
+/**
+ * BCP 47 name of the locale (eg, "de-DE").
+ */
+name: string, 
+/**
+ * Order used to read ambiguous numeric dates.
+ */
+date_order: DateOrder, 
+/**
+ * Default strftime format for dates.
+ */
+date_format: string, 
+/**
+ * Default strftime format for times.
+ */
+time_format: string, decimal_separator: string, group_separator: string, 
+/**
+ * Currency symbol used for new currency formats.
+ */
+currency_symbol: string, 
+/**
+ * First day of the week, where 0 is Sunday and 1 is Monday.
+ */
+first_day_of_week: number, 
+/**
+ * Separator between formula arguments (`,` or `;`).
+ */
+argument_separator: string, };
 export type MinMax = { min: number, max: number, };
 export type NumberRange = { "Range": [number | null, number | null] } | { "Equal": Array<number> } | { "NotEqual": Array<number> };
-export type NumericFormat = { type: NumericFormatKind, symbol: string | null, };
-export type NumericFormatKind = "NUMBER" | "CURRENCY" | "PERCENTAGE" | "EXPONENTIAL";
+export type NumericFormat = { type: NumericFormatKind, 
+/**
+ * The currency symbol for `Currency`, or the format code for `Custom`.
+ */
+symbol: string | null, };
+export type NumericFormatKind = "NUMBER" | "CURRENCY" | "PERCENTAGE" | "EXPONENTIAL" | "CUSTOM";
+export type OutlineGroup = { start: bigint, end: bigint, level: number, collapsed: boolean, };
 export type PasteSpecial = "None" | "Values" | "Formats";
 export type Pos = { 
 /**
@@ -121,6 +312,11 @@ x: bigint,
  * Row
  */
 y: bigint, };
+export type ProtectedRange = { id: string, selection: A1Selection, description: string | null, 
+/**
+ * Emails of the users who may edit the range.
+ */
+editors: Array<string>, };
 export type Rect = { 
 /**
  * Upper-left corner.
@@ -131,6 +327,7 @@ min: Pos,
  */
 max: Pos, };
 export type RefRangeBounds = { start: CellRefRangeEnd, end: CellRefRangeEnd, };
+export type RichText = { spans: Array<TextSpan>, };
 export type Rgba = { red: number, green: number, blue: number, alpha: number, };
 export type RunError = { 
 /**
@@ -142,11 +339,30 @@ span: Span | null,
  */
 msg: RunErrorMsg, };
 export type RunErrorMsg = { "CodeRunError": string } | "Spill" | { "Unimplemented": string } | "UnknownError" | { "InternalError": string } | { "Unterminated": string } | { "Expected": { expected: string, got: string | null, } } | { "Unexpected": string } | { "TooManyArguments": { func_name: string, max_arg_count: number, } } | { "MissingRequiredArgument": { func_name: string, arg_name: string, } } | "BadFunctionName" | "BadCellReference" | "BadNumber" | { "BadOp": { op: string, ty1: string, ty2: string | null, use_duration_instead: boolean, } } | { "ExactArraySizeMismatch": { expected: ArraySize, got: ArraySize, } } | { "ExactArrayAxisMismatch": { axis: Axis, expected: number, got: number, } } | { "ArrayAxisMismatch": { axis: Axis, expected: number, got: number, } } | "EmptyArray" | "NonRectangularArray" | "NonLinearArray" | "ArrayTooBig" | "NotAvailable" | "Name" | "Null" | "Num" | "Value" | "CircularReference" | "Overflow" | "DivideByZero" | "NegativeExponent" | "NaN" | "IndexOutOfBounds" | "NoMatch" | "InvalidArgument" | "NotANumber" | "Infinity";
+export type ScaleValue = "Min" | "Max" | { "Number": number } | { "Percent": number } | { "Percentile": number };
 export type SearchOptions = { case_sensitive: boolean | null, whole_cell: boolean | null, search_code: boolean | null, sheet_id: string | null, };
 export type SheetBounds = { sheet_id: string, bounds: GridBounds, bounds_without_formatting: GridBounds, format_bounds: GridBounds, };
 export type SheetId = { id: string, };
-export type SheetInfo = { sheet_id: string, name: string, order: string, color: string | null, offsets: string, bounds: GridBounds, bounds_without_formatting: GridBounds, format_bounds: GridBounds, };
+export type SheetInfo = { sheet_id: string, name: string, order: string, color: string | null, offsets: string, bounds: GridBounds, bounds_without_formatting: GridBounds, format_bounds: GridBounds, merge_cells: Array<Rect>, 
+/**
+ * Column and row groups, used to render the outline toggles.
+ */
+column_outline: Array<OutlineGroup>, row_outline: Array<OutlineGroup>, frozen_columns: number, frozen_rows: number, };
 export type SheetPos = { x: bigint, y: bigint, sheet_id: SheetId, };
+export type SheetProtection = { 
+/**
+ * Whether the whole sheet is protected.
+ */
+protected: boolean, 
+/**
+ * Emails of the users who may edit a protected sheet and change its
+ * protection.
+ */
+editors: Array<string>, 
+/**
+ * Ranges of a protected sheet that anyone may edit.
+ */
+editable_ranges: Array<A1Selection>, ranges: Array<ProtectedRange>, };
 export type SheetRect = { 
 /**
  * Upper-left corner.
@@ -172,18 +388,71 @@ start: number,
  */
 end: number, };
 export type TableRef = { table_name: string, data: boolean, headers: boolean, totals: boolean, col_range: ColRange, };
+export type TableStyle = { 
+/**
+ * The preset the style is based on (eg, `TableStyleMedium2`).
+ */
+name: string, whole_table: TableStyleElement, header_row: TableStyleElement, 
+/**
+ * Applied to the first, third, etc. data rows.
+ */
+row_band: TableStyleElement, 
+/**
+ * Applied to the first, third, etc. columns.
+ */
+column_band: TableStyleElement, first_column: TableStyleElement, last_column: TableStyleElement, totals_row: TableStyleElement, banded_rows: boolean, banded_columns: boolean, show_first_column: boolean, show_last_column: boolean, 
+/**
+ * Whether the table's last row is a totals row.
+ */
+show_totals_row: boolean, };
+export type TableStyleElement = { fill_color: string | null, text_color: string | null, bold: boolean | null, };
 export type TextCase = { "CaseInsensitive": Array<string> } | { "CaseSensitive": Array<string> };
 export type TextMatch = { "Exactly": TextCase } | { "Contains": TextCase } | { "NotContains": TextCase } | { "TextLength": { min: number | null, max: number | null, } };
-export type TransactionName = "Unknown" | "ResizeColumn" | "ResizeRow" | "ResizeRows" | "ResizeColumns" | "Autocomplete" | "SetBorders" | "SetCells" | "SetFormats" | "SetDataTableAt" | "CutClipboard" | "PasteClipboard" | "SetCode" | "RunCode" | "FlattenDataTable" | "SwitchDataTableKind" | "GridToDataTable" | "DataTableMeta" | "DataTableMutations" | "DataTableFirstRowAsHeader" | "DataTableAddDataTable" | "Import" | "SetSheetMetadata" | "SheetAdd" | "SheetDelete" | "DuplicateSheet" | "MoveCells" | "Validation" | "ManipulateColumnRow";
+export type TextOperator = "Contains" | "NotContains" | "BeginsWith" | "EndsWith";
+export type TextSpan = { text: string, bold: boolean | null, italic: boolean | null, underline: boolean | null, strikeThrough: boolean | null, textColor: string | null, };
+export type TextToColumnsOptions = { split: TextToColumnsSplit, 
+/**
+ * Removes leading and trailing whitespace from each part.
+ */
+trim_whitespace: boolean, 
+/**
+ * Converts each resulting column to numbers, dates, etc. when all of its
+ * values can be converted.
+ */
+detect_types: boolean, };
+export type TextToColumnsPreview = { 
+/**
+ * The split text of the first rows.
+ */
+rows: Array<Array<string>>, 
+/**
+ * True if the new columns would overwrite existing cells.
+ */
+overwrites_cells: boolean, 
+/**
+ * True if the new columns would overwrite code or tables. The operation
+ * is refused in this case.
+ */
+overwrites_code: boolean, };
+export type TextToColumnsSplit = { "Delimiter": { delimiter: string, merge_consecutive: boolean, } } | { "FixedWidths": Array<number> } | { "Regex": string };
+export type Theme = { colors: Array<ThemeColor>, };
+export type ThemeColor = { name: string, color: string, };
+export type TransactionName = "Unknown" | "ResizeColumn" | "ResizeRow" | "ResizeRows" | "ResizeColumns" | "Autocomplete" | "SetBorders" | "SetCells" | "SetFormats" | "SetDataTableAt" | "CutClipboard" | "PasteClipboard" | "SetCode" | "RunCode" | "FlattenDataTable" | "SwitchDataTableKind" | "GridToDataTable" | "DataTableMeta" | "DataTableMutations" | "DataTableFirstRowAsHeader" | "DataTableAddDataTable" | "DataTableTransform" | "DataCleaning" | "RemoveDuplicates" | "TextToColumns" | "FlashFill" | "SetCustomLists" | "CellStyle" | "SetTheme" | "SetLocale" | "SetTimeZone" | "SetDateSettings" | "MergeCells" | "HideColumnsRows" | "GroupColumnsRows" | "Comment" | "ConditionalFormat" | "Protection" | "Import" | "SetSheetMetadata" | "SheetAdd" | "SheetDelete" | "DuplicateSheet" | "MoveCells" | "Validation" | "ManipulateColumnRow";
 export type TransientResize = { row: bigint | null, column: bigint | null, old_size: number, new_size: number, };
 export type Validation = { id: string, selection: A1Selection, rule: ValidationRule, message: ValidationMessage, error: ValidationError, };
+export type ValidationCustom = { formula: string, ignore_blank: boolean, };
 export type ValidationDateTime = { ignore_blank: boolean, require_date: boolean, require_time: boolean, prohibit_date: boolean, prohibit_time: boolean, ranges: Array<DateTimeRange>, };
 export type ValidationError = { show: boolean, style: ValidationStyle, title: string | null, message: string | null, };
-export type ValidationList = { source: ValidationListSource, ignore_blank: boolean, drop_down: boolean, };
-export type ValidationListSource = { "Selection": A1Selection } | { "List": Array<string> };
+export type ValidationList = { source: ValidationListSource, ignore_blank: boolean, drop_down: boolean, 
+/**
+ * Whether a cell may hold several values from the list, stored as text
+ * separated by [`MULTI_SELECT_DELIMITER`] (see [`Self::join_values`]).
+ */
+multi_select: boolean, };
+export type ValidationListSource = { "Selection": A1Selection } | { "List": Array<string> } | { "Formula": string };
 export type ValidationLogical = { show_checkbox: boolean, ignore_blank: boolean, };
 export type ValidationMessage = { show: boolean, title: string | null, message: string | null, };
 export type ValidationNumber = { ignore_blank: boolean, ranges: Array<NumberRange>, };
-export type ValidationRule = "None" | { "List": ValidationList } | { "Logical": ValidationLogical } | { "Text": ValidationText } | { "Number": ValidationNumber } | { "DateTime": ValidationDateTime };
+export type ValidationRule = "None" | { "List": ValidationList } | { "Logical": ValidationLogical } | { "Text": ValidationText } | { "Number": ValidationNumber } | { "DateTime": ValidationDateTime } | { "Custom": ValidationCustom };
 export type ValidationStyle = "Stop" | "Warning" | "Information";
 export type ValidationText = { ignore_blank: boolean, text_match: Array<TextMatch>, };
@@ -91,6 +91,9 @@ export interface ReceiveTransaction {
   file_id: string;
   operations: string | Buffer;
   sequence_num: number;
+
+  // email of the sender, used to check protected ranges
+  editor?: string;
 }
 
 export interface ReceiveTransactionAck {
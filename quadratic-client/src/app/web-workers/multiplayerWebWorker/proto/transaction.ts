@@ -80,6 +80,13 @@ export interface ReceiveTransaction {
    * @generated from protobuf field: bytes operations = 5;
    */
   operations: Uint8Array;
+  /**
+   * email of the sender, used by the files service and by clients to check
+   * protected ranges (empty if unknown)
+   *
+   * @generated from protobuf field: string editor = 6;
+   */
+  editor: string;
 }
 /**
  * @generated from protobuf message multiplayer.ReceiveTransactions
@@ -264,6 +271,7 @@ class ReceiveTransaction$Type extends MessageType<ReceiveTransaction> {
         L: 0 /*LongType.BIGINT*/,
       },
       { no: 5, name: 'operations', kind: 'scalar', T: 12 /*ScalarType.BYTES*/ },
+      { no: 6, name: 'editor', kind: 'scalar', T: 9 /*ScalarType.STRING*/ },
     ]);
   }
   create(value?: PartialMessage<ReceiveTransaction>): ReceiveTransaction {
@@ -273,6 +281,7 @@ class ReceiveTransaction$Type extends MessageType<ReceiveTransaction> {
     message.file_id = '';
     message.sequence_num = 0n;
     message.operations = new Uint8Array(0);
+    message.editor = '';
     if (value !== undefined) reflectionMergePartial<ReceiveTransaction>(this, message, value);
     return message;
   }
@@ -302,6 +311,9 @@ class ReceiveTransaction$Type extends MessageType<ReceiveTransaction> {
         case /* bytes operations */ 5:
           message.operations = reader.bytes();
           break;
+        case /* string editor */ 6:
+          message.editor = reader.string();
+          break;
         default:
           let u = options.readUnknownField;
           if (u === 'throw')
@@ -323,6 +335,8 @@ class ReceiveTransaction$Type extends MessageType<ReceiveTransaction> {
     if (message.sequence_num !== 0n) writer.tag(4, WireType.Varint).uint64(message.sequence_num);
     /* bytes operations = 5; */
     if (message.operations.length) writer.tag(5, WireType.LengthDelimited).bytes(message.operations);
+    /* string editor = 6; */
+    if (message.editor !== '') writer.tag(6, WireType.LengthDelimited).string(message.editor);
     let u = options.writeUnknownFields;
     if (u !== false) (u == true ? UnknownFieldHandler.onWrite : u)(this.typeName, message, writer);
     return writer;
@@ -332,7 +332,7 @@ class Core {
             ? new Uint8Array(Buffer.from(data.operations, 'base64'))
             : data.operations;
 
-        this.gridController.multiplayerTransaction(data.id, data.sequence_num, operations);
+        this.gridController.multiplayerTransaction(data.id, data.sequence_num, operations, data.editor || undefined);
         offline.markTransactionSent(data.id);
 
         // update the multiplayer state
@@ -372,6 +372,7 @@ class Core {
           id: transaction.id,
           file_id: transaction.file_id,
           sequence_num: transaction.sequence_num,
+          editor: transaction.editor || undefined,
           operations:
             typeof transaction.operations === 'string'
               ? Array.from(Buffer.from(transaction.operations, 'base64'))
@@ -6,7 +6,11 @@ export const convertNumber = (n: string, format: JsNumber, currentFractionDigits
   let number = new BigNumber(n);
   const isNegative = number.isNegative();
 
-  let options: BigNumber.Format = { decimalSeparator: '.', groupSeparator: ',', prefix: isNegative ? '-' : '' };
+  let options: BigNumber.Format = {
+    decimalSeparator: format.decimal_separator ?? '.',
+    groupSeparator: format.group_separator ?? ',',
+    prefix: isNegative ? '-' : '',
+  };
 
   if (format.format?.type === 'PERCENTAGE') {
     number = number.times(100);
@@ -43,11 +47,8 @@ export const convertNumber = (n: string, format: JsNumber, currentFractionDigits
   }
 
   if (isScientific) {
-    if (currentFractionDigits !== undefined) {
-      return number.toExponential(currentFractionDigits);
-    } else {
-      return number.toExponential(2);
-    }
+    const exponential = number.toExponential(currentFractionDigits ?? 2);
+    return format.decimal_separator ? exponential.replace('.', format.decimal_separator) : exponential;
   }
 
   if (currentFractionDigits !== undefined) {
@@ -84,8 +85,9 @@ export const getFractionDigits = (number: string, current: string, format: JsNum
     current = current.slice(0, -1);
   }
 
-  if (current.includes('.')) {
-    return current.split('.')[1].length;
+  const decimalSeparator = format.decimal_separator ?? '.';
+  if (current.includes(decimalSeparator)) {
+    return current.split(decimalSeparator)[1].length;
   }
   return 0;
 };
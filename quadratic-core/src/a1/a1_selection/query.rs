@@ -429,6 +429,22 @@ impl A1Selection {
         }
     }
 
+    /// Returns the top left cell of the first range (or the cursor if there
+    /// are no ranges). Relative references in a rule's formula (eg, of a
+    /// conditional format or validation) are relative to this cell.
+    pub fn anchor(&self, a1_context: &A1Context) -> Pos {
+        match self.ranges.first() {
+            Some(CellRefRange::Sheet { range }) => Pos {
+                x: range.start.col().min(range.end.col()),
+                y: range.start.row().min(range.end.row()),
+            },
+            Some(range) => range
+                .to_rect(a1_context)
+                .map_or(self.cursor, |rect| rect.min),
+            None => self.cursor,
+        }
+    }
+
     pub fn to_cursor_sheet_pos(&self) -> SheetPos {
         self.cursor.to_sheet_pos(self.sheet_id)
     }
@@ -942,6 +958,15 @@ mod tests {
         assert!(!A1Selection::test_a1("*").is_selected_rows_finite(&context));
     }
 
+    #[test]
+    fn test_anchor() {
+        let context = A1Context::default();
+        assert_eq!(A1Selection::test_a1("B2:D5").anchor(&context), pos![B2]);
+        assert_eq!(A1Selection::test_a1("D5:B2").anchor(&context), pos![B2]);
+        assert_eq!(A1Selection::test_a1("C3,A1").anchor(&context), pos![C3]);
+        assert_eq!(A1Selection::test_a1("B:C").anchor(&context), pos![B1]);
+    }
+
     #[test]
     fn test_single_rect_or_cursor() {
         let context = A1Context::default();
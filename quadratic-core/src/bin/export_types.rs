@@ -18,10 +18,15 @@ use quadratic_core::controller::execution::run_code::get_cells::JsCellsA1Values;
 use quadratic_core::controller::operations::clipboard::PasteSpecial;
 use quadratic_core::controller::transaction_types::JsCellValueResult;
 use quadratic_core::controller::transaction_types::JsCodeResult;
+use quadratic_core::date_time::{DateOrder, DateSettings, DateSystem};
 use quadratic_core::formulas::parse_formula::JsFormulaParseResult;
 use quadratic_core::grid::JsCellsAccessed;
+use quadratic_core::grid::data_table::table_style::{TableStyle, TableStyleElement};
 use quadratic_core::grid::formats::Format;
 use quadratic_core::grid::formats::FormatUpdate;
+use quadratic_core::grid::formats::{
+    CellStyle, CellStyleBorder, CellStyleBorders, Theme, ThemeColor,
+};
 use quadratic_core::grid::js_types::JsChartContext;
 use quadratic_core::grid::js_types::JsCodeTableContext;
 use quadratic_core::grid::js_types::JsColumnWidth;
@@ -31,9 +36,10 @@ use quadratic_core::grid::js_types::JsSelectionContext;
 use quadratic_core::grid::js_types::JsTablesContext;
 use quadratic_core::grid::js_types::{
     CellFormatSummary, JsCellValue, JsCellValuePos, JsCellValuePosContext, JsClipboard, JsCodeCell,
-    JsDataTableColumnHeader, JsHtmlOutput, JsNumber, JsOffset, JsRenderCell, JsRenderCellSpecial,
-    JsRenderCodeCell, JsRenderCodeCellState, JsRenderFill, JsReturnInfo, JsRowHeight, JsSheetFill,
-    JsSnackbarSeverity, JsSummarizeSelectionResult, JsValidationWarning,
+    JsCommentThread, JsDataTableColumnHeader, JsHtmlOutput, JsNumber, JsNumberFill, JsOffset,
+    JsRenderCell, JsRenderCellSpecial, JsRenderCodeCell, JsRenderCodeCellState, JsRenderDataBar,
+    JsRenderFill, JsRenderIcon, JsReturnInfo, JsRowHeight, JsSheetFill, JsSnackbarSeverity,
+    JsSummarizeSelectionResult, JsValidationWarning,
 };
 use quadratic_core::grid::sheet::borders::BorderSelection;
 use quadratic_core::grid::sheet::borders::BorderSide;
@@ -41,15 +47,30 @@ use quadratic_core::grid::sheet::borders::BorderStyle;
 use quadratic_core::grid::sheet::borders::BorderStyleCell;
 use quadratic_core::grid::sheet::borders::BorderStyleTimestamp;
 use quadratic_core::grid::sheet::borders::CellBorderLine;
+use quadratic_core::grid::sheet::borders::JsBorderDiagonal;
 use quadratic_core::grid::sheet::borders::JsBorderHorizontal;
 use quadratic_core::grid::sheet::borders::JsBorderVertical;
 use quadratic_core::grid::sheet::borders::JsBordersSheet;
+use quadratic_core::grid::sheet::comments::{Comment, CommentThread};
+use quadratic_core::grid::sheet::conditional_formats::conditional_format::{
+    ConditionalFormat, ConditionalFormatStyle,
+};
+use quadratic_core::grid::sheet::conditional_formats::rules::{
+    ColorScalePoint, CompareOperator, ConditionalFormatCondition, ConditionalFormatRule, DataBar,
+    DateOccurring, IconSet, IconSetKind, ScaleValue, TextOperator,
+};
+use quadratic_core::grid::sheet::data_cleaning::{
+    DataCleaningAction, DataCleaningCase, DataCleaningSummary, DuplicateKeep,
+};
 use quadratic_core::grid::sheet::keyboard::Direction;
+use quadratic_core::grid::sheet::outlines::OutlineGroup;
+use quadratic_core::grid::sheet::protection::{ProtectedRange, SheetProtection};
 use quadratic_core::grid::sheet::search::SearchOptions;
-use quadratic_core::grid::sheet::validations::validation::{
-    Validation, ValidationError, ValidationMessage, ValidationStyle,
+use quadratic_core::grid::sheet::text_to_columns::{
+    TextToColumnsOptions, TextToColumnsPreview, TextToColumnsSplit,
 };
 use quadratic_core::grid::sheet::validations::rules::ValidationRule;
+use quadratic_core::grid::sheet::validations::rules::validation_custom::ValidationCustom;
 use quadratic_core::grid::sheet::validations::rules::validation_date_time::{
     DateTimeRange, ValidationDateTime,
 };
@@ -63,10 +84,15 @@ use quadratic_core::grid::sheet::validations::rules::validation_number::{
 use quadratic_core::grid::sheet::validations::rules::validation_text::{
     TextCase, TextMatch, ValidationText,
 };
+use quadratic_core::grid::sheet::validations::validation::{
+    Validation, ValidationError, ValidationMessage, ValidationStyle,
+};
 use quadratic_core::grid::sort::DataTableSort;
 use quadratic_core::grid::sort::SortDirection;
+use quadratic_core::grid::transform::{DataTableAggregate, DataTableJoinKind, DataTableTransform};
 use quadratic_core::grid::{
-    CellAlign, CellVerticalAlign, CellWrap, GridBounds, NumericFormat, NumericFormatKind, SheetId,
+    CellAlign, CellVerticalAlign, CellWrap, GridBounds, Locale, NumericFormat, NumericFormatKind,
+    SheetId,
 };
 use quadratic_core::grid::{CodeCellLanguage, ConnectionKind};
 use quadratic_core::sheet_offsets::resize_transient::TransientResize;
@@ -75,7 +101,8 @@ use quadratic_core::small_timestamp::SmallTimestamp;
 use quadratic_core::wasm_bindings::controller::bounds::MinMax;
 use quadratic_core::wasm_bindings::controller::sheet_info::{SheetBounds, SheetInfo};
 use quadratic_core::{
-    ArraySize, Axis, Pos, Rect, RunError, RunErrorMsg, SheetPos, SheetRect, Span,
+    ArraySize, Axis, Hyperlink, Pos, Rect, RichText, RunError, RunErrorMsg, SheetPos, SheetRect,
+    Span, TextSpan,
 };
 use ts_rs::TS;
 
@@ -114,17 +141,44 @@ fn main() {
         CellRefCoord,
         CellRefRange,
         CellRefRangeEnd,
+        CellStyle,
+        CellStyleBorder,
+        CellStyleBorders,
         CellVerticalAlign,
         CellWrap,
         CodeCellLanguage,
+        ColorScalePoint,
         ColumnRow,
+        Comment,
+        CommentThread,
+        CompareOperator,
+        ConditionalFormat,
+        ConditionalFormatCondition,
+        ConditionalFormatRule,
+        ConditionalFormatStyle,
         ConnectionKind,
+        DataCleaningAction,
+        DataCleaningCase,
+        DataCleaningSummary,
+        DataBar,
+        DataTableAggregate,
+        DataTableJoinKind,
         DataTableSort,
+        DataTableTransform,
+        DateOccurring,
+        DateOrder,
+        DateSettings,
+        DateSystem,
         DateTimeRange,
         Direction,
+        DuplicateKeep,
         Format,
         FormatUpdate,
         GridBounds,
+        Hyperlink,
+        IconSet,
+        IconSetKind,
+        JsBorderDiagonal,
         JsBorderHorizontal,
         JsBorderVertical,
         JsBordersSheet,
@@ -138,6 +192,7 @@ fn main() {
         JsCodeResult,
         JsCodeTableContext,
         JsColumnWidth,
+        JsCommentThread,
         JsCoordinate,
         JsDataTableColumnHeader,
         JsDataTableContext,
@@ -149,7 +204,10 @@ fn main() {
         JsRenderCellSpecial,
         JsRenderCodeCell,
         JsRenderCodeCellState,
+        JsRenderDataBar,
+        JsNumberFill,
         JsRenderFill,
+        JsRenderIcon,
         JsResponse,
         JsReturnInfo,
         JsRowHeight,
@@ -160,32 +218,48 @@ fn main() {
         JsTableInfo,
         JsTablesContext,
         JsValidationWarning,
+        Locale,
         MinMax,
         NumberRange,
         NumericFormat,
         NumericFormatKind,
+        OutlineGroup,
         PasteSpecial,
         Pos,
+        ProtectedRange,
         Rect,
         RefRangeBounds,
+        RichText,
         Rgba,
         RunError,
         RunErrorMsg,
+        ScaleValue,
         SearchOptions,
         SheetBounds,
         SheetId,
         SheetInfo,
         SheetPos,
+        SheetProtection,
         SheetRect,
         SmallTimestamp,
         SortDirection,
         Span,
         TableRef,
+        TableStyle,
+        TableStyleElement,
         TextCase,
         TextMatch,
+        TextOperator,
+        TextSpan,
+        TextToColumnsOptions,
+        TextToColumnsPreview,
+        TextToColumnsSplit,
+        Theme,
+        ThemeColor,
         TransactionName,
         TransientResize,
         Validation,
+        ValidationCustom,
         ValidationDateTime,
         ValidationError,
         ValidationList,
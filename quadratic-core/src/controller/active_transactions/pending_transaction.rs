@@ -43,6 +43,9 @@ pub struct PendingTransaction {
 
     pub source: TransactionSource,
 
+    /// email of the sender of a transaction received from the server
+    pub editor: Option<String>,
+
     /// pending operations
     pub operations: VecDeque<Operation>,
 
@@ -79,6 +82,15 @@ pub struct PendingTransaction {
     /// sheets w/updated validations warnings
     pub validations_warnings: HashMap<SheetId, SheetValidationsWarnings>,
 
+    /// sheets w/updated comments
+    pub comments: HashSet<SheetId>,
+
+    /// sheets w/updated conditional formats
+    pub conditional_formats: HashSet<SheetId>,
+
+    /// sheets w/updated protection
+    pub sheet_protection: HashSet<SheetId>,
+
     /// sheets w/updated rows to resize
     pub resize_rows: HashMap<SheetId, HashSet<i64>>,
 
@@ -118,6 +130,7 @@ impl Default for PendingTransaction {
             id: Uuid::new_v4(),
             transaction_name: TransactionName::Unknown,
             source: TransactionSource::User,
+            editor: None,
             cursor: None,
             operations: VecDeque::new(),
             reverse_operations: Vec::new(),
@@ -131,6 +144,9 @@ impl Default for PendingTransaction {
             cursor_undo_redo: None,
             validations: HashSet::new(),
             validations_warnings: HashMap::new(),
+            comments: HashSet::new(),
+            conditional_formats: HashSet::new(),
+            sheet_protection: HashSet::new(),
             resize_rows: HashMap::new(),
             dirty_hashes: HashMap::new(),
             sheet_borders: HashSet::new(),
@@ -153,6 +169,7 @@ impl PendingTransaction {
             sequence_num,
             operations: self.operations.clone().into(),
             cursor: self.cursor.clone(),
+            editor: self.editor.clone(),
         }
     }
 
@@ -163,6 +180,7 @@ impl PendingTransaction {
             sequence_num: None,
             operations: self.forward_operations.clone(),
             cursor: None,
+            editor: None,
         }
     }
 
@@ -176,6 +194,7 @@ impl PendingTransaction {
             sequence_num: None,
             operations,
             cursor: self.cursor.clone(),
+            editor: None,
         }
     }
 
@@ -265,6 +284,20 @@ impl PendingTransaction {
         dirty_hashes.extend(hashes);
     }
 
+    /// Returns the rects of the dirty hashes.
+    pub fn dirty_sheet_rects(&self) -> Vec<SheetRect> {
+        let (width, height) = (CELL_SHEET_WIDTH as i64, CELL_SHEET_HEIGHT as i64);
+        self.dirty_hashes
+            .iter()
+            .flat_map(|(sheet_id, hashes)| {
+                hashes.iter().map(move |hash| {
+                    let (x, y) = (hash.x * width, hash.y * height);
+                    SheetRect::new(x, y, x + width - 1, y + height - 1, *sheet_id)
+                })
+            })
+            .collect()
+    }
+
     pub fn add_dirty_hashes_from_sheet_rect(&mut self, sheet_rect: SheetRect) {
         if !(cfg!(target_family = "wasm") || cfg!(test)) || self.is_server() {
             return;
@@ -25,6 +25,23 @@ pub enum TransactionName {
     DataTableMutations,
     DataTableFirstRowAsHeader,
     DataTableAddDataTable,
+    DataTableTransform,
+    DataCleaning,
+    RemoveDuplicates,
+    TextToColumns,
+    FlashFill,
+    SetCustomLists,
+    CellStyle,
+    SetTheme,
+    SetLocale,
+    SetTimeZone,
+    SetDateSettings,
+    MergeCells,
+    HideColumnsRows,
+    GroupColumnsRows,
+    Comment,
+    ConditionalFormat,
+    Protection,
     Import,
 
     SetSheetMetadata,
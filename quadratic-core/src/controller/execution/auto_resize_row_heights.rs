@@ -699,7 +699,7 @@ mod tests {
         // should not trigger auto resize row heights for multiplayer transactions
         let mut other_gc = GridController::test();
         other_gc.grid_mut().sheets_mut()[0].id = sheet_id;
-        other_gc.received_transaction(transaction_id, 1, ops.0);
+        other_gc.received_transaction(transaction_id, 1, ops.0, None);
         let sheet = other_gc.sheet(sheet_id);
         assert_eq!(
             sheet.display_value(Pos { x: 1, y: 1 }),
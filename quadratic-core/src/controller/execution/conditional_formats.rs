@@ -0,0 +1,177 @@
+//! Evaluates the conditional formats of sheets after each transaction. Only
+//! conditional formats that changed, or whose cells (or the cells their
+//! formula accessed) are within the dirty hashes of the transaction, are
+//! evaluated again.
+
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+
+use crate::controller::GridController;
+use crate::controller::active_transactions::pending_transaction::PendingTransaction;
+use crate::formulas::{Ctx, Formula, parse_formula};
+use crate::grid::sheet::conditional_formats::conditional_format::{
+    ConditionalFormat, ConditionalFormatResults,
+};
+use crate::grid::sheet::conditional_formats::rules::{
+    ConditionalFormatCondition, ConditionalFormatRule,
+};
+use crate::grid::{CellsAccessed, Sheet, SheetId};
+use crate::{CellValue, Pos, RefAdjust, SheetPos, SheetRect, Value};
+
+impl GridController {
+    /// Parses the formula of a rule (of a conditional format or validation).
+    /// The formula's references are relative to `anchor`. Returns None if the
+    /// formula cannot be parsed.
+    pub(crate) fn parse_rule_formula(&self, source: &str, anchor: SheetPos) -> Option<Formula> {
+        let source = source.trim().trim_start_matches('=');
+        parse_formula(source, &self.a1_context, anchor).ok()
+    }
+
+    /// Evaluates the parsed formula of a rule for a cell. The formula's
+    /// relative references are moved from `anchor` to `pos`. If set,
+    /// `pending_value` replaces the cell's value. The cells accessed by the
+    /// formula are added to `cells_accessed`.
+    pub(crate) fn evaluate_rule_formula_value(
+        &self,
+        formula: &Formula,
+        anchor: SheetPos,
+        pos: SheetPos,
+        pending_value: Option<CellValue>,
+        cells_accessed: &mut CellsAccessed,
+    ) -> Value {
+        let formula = formula.adjust_references(RefAdjust {
+            relative_only: true,
+            dx: pos.x - anchor.x,
+            dy: pos.y - anchor.y,
+            ..RefAdjust::NO_OP
+        });
+        let mut ctx = Ctx::new_for_rule(self, pos);
+        ctx.pending_value = pending_value;
+        ctx.cells_accessed = std::mem::take(cells_accessed);
+        let value = formula.eval(&mut ctx).into_non_tuple().inner;
+        *cells_accessed = ctx.cells_accessed;
+        value
+    }
+
+    /// Evaluates the parsed formula of a rule for a cell (see
+    /// [`Self::evaluate_rule_formula_value`]). Errors and non-logical results
+    /// are false.
+    pub(crate) fn evaluate_rule_formula(
+        &self,
+        formula: &Formula,
+        anchor: SheetPos,
+        pos: SheetPos,
+        pending_value: Option<CellValue>,
+        cells_accessed: &mut CellsAccessed,
+    ) -> bool {
+        self.evaluate_rule_formula_value(formula, anchor, pos, pending_value, cells_accessed)
+            .into_cell_value()
+            .ok()
+            .and_then(|value| bool::try_from(&value).ok())
+            .unwrap_or(false)
+    }
+
+    /// Evaluates a conditional format of a sheet. A formula rule is parsed
+    /// once and moved to each cell.
+    fn evaluate_conditional_format(
+        &self,
+        sheet: &Sheet,
+        conditional_format: &ConditionalFormat,
+        today: NaiveDate,
+    ) -> ConditionalFormatResults {
+        let mut cells_accessed = CellsAccessed::default();
+        for range in conditional_format.selection.ranges.iter() {
+            cells_accessed.add(sheet.id, range.clone());
+        }
+        let anchor = conditional_format
+            .anchor(&self.a1_context)
+            .to_sheet_pos(sheet.id);
+        let formula = match &conditional_format.rule {
+            ConditionalFormatRule::Highlight {
+                condition: ConditionalFormatCondition::Formula(source),
+                ..
+            } => self.parse_rule_formula(source, anchor),
+            _ => None,
+        };
+        let mut cells = HashMap::new();
+        conditional_format.apply(sheet, &self.a1_context, today, &mut cells, |pos| {
+            formula.as_ref().is_some_and(|formula| {
+                self.evaluate_rule_formula(
+                    formula,
+                    anchor,
+                    pos.to_sheet_pos(sheet.id),
+                    None,
+                    &mut cells_accessed,
+                )
+            })
+        });
+        ConditionalFormatResults {
+            conditional_format: conditional_format.clone(),
+            today,
+            cells_accessed,
+            cells,
+        }
+    }
+
+    /// Evaluates the conditional formats of a sheet that are new, changed, or
+    /// that accessed a cell within `dirty`. Returns the cells whose result
+    /// changed and whether any fill changed.
+    pub(crate) fn update_sheet_conditional_formats(
+        &mut self,
+        sheet_id: SheetId,
+        dirty: &[SheetRect],
+    ) -> (Vec<Pos>, bool) {
+        let Some(sheet) = self.try_sheet(sheet_id) else {
+            return (vec![], false);
+        };
+        let today = self.now().date();
+        let conditional_formats = &sheet.conditional_formats;
+        let results = conditional_formats
+            .conditional_formats
+            .iter()
+            .filter(|cf| {
+                conditional_formats.results.get(&cf.id).is_none_or(|old| {
+                    old.conditional_format != **cf
+                        || old.today != today
+                        || dirty
+                            .iter()
+                            .any(|rect| old.cells_accessed.intersects(rect, &self.a1_context))
+                })
+            })
+            .map(|cf| self.evaluate_conditional_format(sheet, cf, today))
+            .collect();
+        match self.try_sheet_mut(sheet_id) {
+            Some(sheet) => sheet.conditional_formats.set_results(results),
+            None => (vec![], false),
+        }
+    }
+
+    /// Updates the conditional formats of all sheets that are affected by a
+    /// transaction and marks the cells whose result changed for rendering.
+    pub(crate) fn update_conditional_formats(&mut self, transaction: &mut PendingTransaction) {
+        if (!cfg!(target_family = "wasm") && !cfg!(test)) || transaction.is_server() {
+            return;
+        }
+
+        let dirty = transaction.dirty_sheet_rects();
+        for sheet_id in self.sheet_ids() {
+            if self
+                .try_sheet(sheet_id)
+                .is_none_or(|sheet| sheet.conditional_formats.is_empty())
+            {
+                continue;
+            }
+            let (changed, fills_changed) = self.update_sheet_conditional_formats(sheet_id, &dirty);
+            if !changed.is_empty() {
+                transaction.add_dirty_hashes_from_sheet_cell_positions(
+                    sheet_id,
+                    changed.into_iter().collect(),
+                );
+            }
+            if fills_changed {
+                transaction.add_fill_cells(sheet_id);
+            }
+        }
+    }
+}
@@ -76,6 +76,8 @@ impl GridController {
             }
         }
 
+        self.update_formula_validation_warnings(transaction);
+        self.update_conditional_formats(transaction);
         self.process_visible_dirty_hashes(transaction);
         self.process_remaining_dirty_hashes(transaction);
     }
@@ -144,6 +146,8 @@ impl GridController {
         cursor: Option<String>,
         transaction_name: TransactionName,
     ) -> String {
+        // protected ranges are checked before anything is changed
+        let allowed = self.allow_operations(&operations);
         let mut transaction = PendingTransaction {
             source: TransactionSource::User,
             operations: operations.into(),
@@ -152,6 +156,9 @@ impl GridController {
             ..Default::default()
         };
         let transaction_id = transaction.id.to_string();
+        if !allowed {
+            return transaction_id;
+        }
         self.start_transaction(&mut transaction);
         self.finalize_transaction(transaction);
         transaction_id
@@ -0,0 +1,138 @@
+use crate::controller::GridController;
+use crate::controller::active_transactions::pending_transaction::PendingTransaction;
+use crate::controller::operations::operation::Operation;
+
+impl GridController {
+    pub(crate) fn execute_add_comment(
+        &mut self,
+        transaction: &mut PendingTransaction,
+        op: Operation,
+    ) {
+        unwrap_op!(let AddComment { sheet_pos, comment } = op);
+
+        let Some(sheet) = self.try_sheet_mut(sheet_pos.sheet_id) else {
+            // sheet may have been deleted
+            return;
+        };
+
+        sheet.comments.add(sheet_pos.into(), comment.clone());
+
+        transaction.comments.insert(sheet_pos.sheet_id);
+        transaction
+            .reverse_operations
+            .push(Operation::DeleteComment {
+                sheet_pos,
+                comment_id: comment.id,
+            });
+        transaction
+            .forward_operations
+            .push(Operation::AddComment { sheet_pos, comment });
+    }
+
+    pub(crate) fn execute_delete_comment(
+        &mut self,
+        transaction: &mut PendingTransaction,
+        op: Operation,
+    ) {
+        unwrap_op!(let DeleteComment { sheet_pos, comment_id } = op);
+
+        let Some(sheet) = self.try_sheet_mut(sheet_pos.sheet_id) else {
+            // sheet may have been deleted
+            return;
+        };
+
+        let pos = sheet_pos.into();
+        let Some(old_thread) = sheet.comments.get(pos).cloned() else {
+            return;
+        };
+        let Some(comment) = sheet.comments.remove(pos, comment_id) else {
+            return;
+        };
+
+        transaction.comments.insert(sheet_pos.sheet_id);
+
+        // restore the whole thread (incl. its resolved state) if it was
+        // removed with its last comment
+        let reverse = if sheet.comments.get(pos).is_none() {
+            Operation::SetCommentThreads {
+                sheet_id: sheet_pos.sheet_id,
+                threads: vec![(pos, Some(old_thread))],
+            }
+        } else {
+            Operation::AddComment { sheet_pos, comment }
+        };
+        transaction.reverse_operations.push(reverse);
+        transaction
+            .forward_operations
+            .push(Operation::DeleteComment {
+                sheet_pos,
+                comment_id,
+            });
+    }
+
+    pub(crate) fn execute_set_comment_resolved(
+        &mut self,
+        transaction: &mut PendingTransaction,
+        op: Operation,
+    ) {
+        unwrap_op!(let SetCommentResolved { sheet_pos, resolved } = op);
+
+        let Some(sheet) = self.try_sheet_mut(sheet_pos.sheet_id) else {
+            // sheet may have been deleted
+            return;
+        };
+
+        let Some(old) = sheet.comments.set_resolved(sheet_pos.into(), resolved) else {
+            return;
+        };
+        if old == resolved {
+            return;
+        }
+
+        transaction.comments.insert(sheet_pos.sheet_id);
+        transaction
+            .reverse_operations
+            .push(Operation::SetCommentResolved {
+                sheet_pos,
+                resolved: old,
+            });
+        transaction
+            .forward_operations
+            .push(Operation::SetCommentResolved {
+                sheet_pos,
+                resolved,
+            });
+    }
+
+    pub(crate) fn execute_set_comment_threads(
+        &mut self,
+        transaction: &mut PendingTransaction,
+        op: Operation,
+    ) {
+        unwrap_op!(let SetCommentThreads { sheet_id, threads } = op);
+
+        let Some(sheet) = self.try_sheet_mut(sheet_id) else {
+            // sheet may have been deleted
+            return;
+        };
+
+        // restore in the reverse order so that overlapping positions end up
+        // with their original threads
+        let mut old_threads = threads
+            .iter()
+            .map(|(pos, thread)| (*pos, sheet.comments.set(*pos, thread.clone())))
+            .collect::<Vec<_>>();
+        old_threads.reverse();
+
+        transaction.comments.insert(sheet_id);
+        transaction
+            .reverse_operations
+            .push(Operation::SetCommentThreads {
+                sheet_id,
+                threads: old_threads,
+            });
+        transaction
+            .forward_operations
+            .push(Operation::SetCommentThreads { sheet_id, threads });
+    }
+}
@@ -0,0 +1,54 @@
+use crate::controller::GridController;
+use crate::controller::active_transactions::pending_transaction::PendingTransaction;
+use crate::controller::operations::operation::Operation;
+
+impl GridController {
+    pub(crate) fn execute_set_conditional_format(
+        &mut self,
+        transaction: &mut PendingTransaction,
+        op: Operation,
+    ) {
+        unwrap_op!(let SetConditionalFormat { conditional_format } = op);
+
+        let sheet_id = conditional_format.selection.sheet_id;
+        let Some(sheet) = self.try_sheet_mut(sheet_id) else {
+            // sheet may have been deleted
+            return;
+        };
+
+        transaction
+            .reverse_operations
+            .extend(sheet.conditional_formats.set(conditional_format.clone()));
+        transaction
+            .forward_operations
+            .push(Operation::SetConditionalFormat { conditional_format });
+        transaction.conditional_formats.insert(sheet_id);
+    }
+
+    pub(crate) fn execute_remove_conditional_format(
+        &mut self,
+        transaction: &mut PendingTransaction,
+        op: Operation,
+    ) {
+        unwrap_op!(let RemoveConditionalFormat { sheet_id, conditional_format_id } = op);
+
+        let Some(sheet) = self.try_sheet_mut(sheet_id) else {
+            // sheet may have been deleted
+            return;
+        };
+
+        let reverse = sheet.conditional_formats.remove(conditional_format_id);
+        if reverse.is_empty() {
+            return;
+        }
+
+        transaction.reverse_operations.extend(reverse);
+        transaction
+            .forward_operations
+            .push(Operation::RemoveConditionalFormat {
+                sheet_id,
+                conditional_format_id,
+            });
+        transaction.conditional_formats.insert(sheet_id);
+    }
+}
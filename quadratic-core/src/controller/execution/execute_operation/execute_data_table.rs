@@ -732,6 +732,7 @@ impl GridController {
                 columns,
                 show_name,
                 show_columns,
+                table_style: None,
             };
             self.execute_data_table_option_meta(transaction, new_op)?;
 
@@ -753,6 +754,7 @@ impl GridController {
             columns,
             show_name,
             show_columns,
+            table_style,
         } = op.to_owned()
         {
             // do grid mutations first to keep the borrow checker happy
@@ -851,6 +853,14 @@ impl GridController {
                 std::mem::replace(&mut data_table.alternating_colors, alternating_colors)
             });
 
+            let old_table_style = table_style.map(|table_style| {
+                // mark code cell dirty to update alternating color
+                transaction.add_code_cell(sheet_id, data_table_pos);
+                transaction.add_fill_cells(sheet_id);
+                transaction.add_dirty_hashes_from_sheet_rect(data_table_rect);
+                std::mem::replace(&mut data_table.table_style, table_style.into())
+            });
+
             if show_name.is_some() || show_columns.is_some() || columns.is_some() {
                 data_table.add_dirty_fills_and_borders(transaction, sheet_id);
                 transaction.add_dirty_hashes_from_sheet_rect(data_table_rect);
@@ -889,6 +899,7 @@ impl GridController {
                 columns: old_columns,
                 show_name: old_show_name.map(|old_show_name| old_show_name.into()),
                 show_columns: old_show_columns.map(|old_show_columns| old_show_columns.into()),
+                table_style: old_table_style.map(|old_table_style| old_table_style.into()),
             }];
             self.data_table_operations(
                 transaction,
@@ -0,0 +1,128 @@
+use crate::controller::GridController;
+use crate::controller::active_transactions::pending_transaction::PendingTransaction;
+use crate::controller::operations::operation::Operation;
+use crate::grid::GridBounds;
+
+impl GridController {
+    pub(crate) fn execute_set_custom_lists(
+        &mut self,
+        transaction: &mut PendingTransaction,
+        op: Operation,
+    ) {
+        unwrap_op!(let SetCustomLists { custom_lists } = op);
+
+        let old_custom_lists = std::mem::replace(&mut self.grid.custom_lists, custom_lists.clone());
+
+        transaction
+            .forward_operations
+            .push(Operation::SetCustomLists { custom_lists });
+        transaction
+            .reverse_operations
+            .push(Operation::SetCustomLists {
+                custom_lists: old_custom_lists,
+            });
+    }
+
+    pub(crate) fn execute_set_cell_styles(
+        &mut self,
+        transaction: &mut PendingTransaction,
+        op: Operation,
+    ) {
+        unwrap_op!(let SetCellStyles { cell_styles } = op);
+
+        let old_cell_styles = std::mem::replace(&mut self.grid.cell_styles, cell_styles.clone());
+
+        transaction
+            .forward_operations
+            .push(Operation::SetCellStyles { cell_styles });
+        transaction
+            .reverse_operations
+            .push(Operation::SetCellStyles {
+                cell_styles: old_cell_styles,
+            });
+    }
+
+    pub(crate) fn execute_set_theme(
+        &mut self,
+        transaction: &mut PendingTransaction,
+        op: Operation,
+    ) {
+        unwrap_op!(let SetTheme { theme } = op);
+
+        let old_theme = std::mem::replace(&mut self.grid.theme, theme.clone());
+
+        transaction
+            .forward_operations
+            .push(Operation::SetTheme { theme });
+        transaction
+            .reverse_operations
+            .push(Operation::SetTheme { theme: old_theme });
+    }
+
+    pub(crate) fn execute_set_locale(
+        &mut self,
+        transaction: &mut PendingTransaction,
+        op: Operation,
+    ) {
+        unwrap_op!(let SetLocale { locale } = op);
+
+        let old_locale = std::mem::replace(&mut self.grid.locale, locale.clone());
+
+        // dates and numbers are displayed using the locale
+        for sheet in self.grid.sheets() {
+            if let GridBounds::NonEmpty(bounds) = sheet.bounds(true) {
+                transaction.add_dirty_hashes_from_sheet_rect(bounds.to_sheet_rect(sheet.id));
+            }
+        }
+
+        transaction
+            .forward_operations
+            .push(Operation::SetLocale { locale });
+        transaction
+            .reverse_operations
+            .push(Operation::SetLocale { locale: old_locale });
+    }
+
+    pub(crate) fn execute_set_time_zone(
+        &mut self,
+        transaction: &mut PendingTransaction,
+        op: Operation,
+    ) {
+        unwrap_op!(let SetTimeZone { time_zone } = op);
+
+        let old_time_zone = std::mem::replace(&mut self.grid.time_zone, time_zone);
+
+        transaction
+            .forward_operations
+            .push(Operation::SetTimeZone { time_zone });
+        transaction.reverse_operations.push(Operation::SetTimeZone {
+            time_zone: old_time_zone,
+        });
+    }
+
+    pub(crate) fn execute_set_date_settings(
+        &mut self,
+        transaction: &mut PendingTransaction,
+        op: Operation,
+    ) {
+        unwrap_op!(let SetDateSettings { date_settings } = op);
+
+        let old_date_settings = std::mem::replace(&mut self.grid.date_settings, date_settings);
+
+        // numbers with custom date formats are displayed using the date system
+        for sheet in self.grid.sheets() {
+            if let GridBounds::NonEmpty(bounds) = sheet.bounds(true) {
+                transaction.add_dirty_hashes_from_sheet_rect(bounds.to_sheet_rect(sheet.id));
+            }
+        }
+
+        transaction
+            .forward_operations
+            .push(Operation::SetDateSettings { date_settings });
+        transaction
+            .reverse_operations
+            .push(Operation::SetDateSettings {
+                date_settings: old_date_settings,
+            });
+    }
+}
@@ -0,0 +1,97 @@
+use crate::Rect;
+use crate::controller::GridController;
+use crate::controller::active_transactions::pending_transaction::PendingTransaction;
+use crate::controller::operations::operation::Operation;
+use crate::grid::SheetId;
+
+impl GridController {
+    /// Marks the rects and the merged cells that changed as dirty so the
+    /// client re-renders them.
+    fn merge_cells_changed(
+        &mut self,
+        transaction: &mut PendingTransaction,
+        sheet_id: SheetId,
+        rects: impl Iterator<Item = Rect>,
+    ) {
+        for rect in rects {
+            transaction.add_dirty_hashes_from_sheet_rect(rect.to_sheet_rect(sheet_id));
+        }
+        transaction.sheet_info.insert(sheet_id);
+        transaction.add_fill_cells(sheet_id);
+        transaction.sheet_borders.insert(sheet_id);
+    }
+
+    pub(crate) fn execute_merge_cells(
+        &mut self,
+        transaction: &mut PendingTransaction,
+        op: Operation,
+    ) {
+        unwrap_op!(let MergeCells { sheet_id, rects } = op);
+
+        let Some(sheet) = self.try_sheet_mut(sheet_id) else {
+            // sheet may have been deleted
+            return;
+        };
+
+        let mut removed = vec![];
+        for rect in rects.iter() {
+            removed.extend(sheet.merge_cells.merge(*rect));
+        }
+
+        self.merge_cells_changed(
+            transaction,
+            sheet_id,
+            rects.iter().chain(removed.iter()).copied(),
+        );
+
+        transaction.forward_operations.push(Operation::MergeCells {
+            sheet_id,
+            rects: rects.clone(),
+        });
+
+        // reverse operations are applied in reverse order, so the new merged
+        // cells are removed before the replaced ones are restored
+        if !removed.is_empty() {
+            transaction.reverse_operations.push(Operation::MergeCells {
+                sheet_id,
+                rects: removed,
+            });
+        }
+        transaction
+            .reverse_operations
+            .push(Operation::UnmergeCells { sheet_id, rects });
+    }
+
+    pub(crate) fn execute_unmerge_cells(
+        &mut self,
+        transaction: &mut PendingTransaction,
+        op: Operation,
+    ) {
+        unwrap_op!(let UnmergeCells { sheet_id, rects } = op);
+
+        let Some(sheet) = self.try_sheet_mut(sheet_id) else {
+            // sheet may have been deleted
+            return;
+        };
+
+        let mut removed = vec![];
+        for rect in rects.iter() {
+            removed.extend(sheet.merge_cells.unmerge(*rect));
+        }
+
+        transaction
+            .forward_operations
+            .push(Operation::UnmergeCells {
+                sheet_id,
+                rects: rects.clone(),
+            });
+
+        if !removed.is_empty() {
+            self.merge_cells_changed(transaction, sheet_id, removed.iter().copied());
+            transaction.reverse_operations.push(Operation::MergeCells {
+                sheet_id,
+                rects: removed,
+            });
+        }
+    }
+}
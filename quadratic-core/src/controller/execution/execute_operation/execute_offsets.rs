@@ -1,5 +1,5 @@
 use crate::{
-    SheetPos,
+    SheetPos, SheetRect,
     a1::A1Selection,
     controller::{
         GridController, active_transactions::pending_transaction::PendingTransaction,
@@ -344,6 +344,155 @@ impl GridController {
             }
         }
     }
+
+    pub fn execute_set_columns_hidden(
+        &mut self,
+        transaction: &mut PendingTransaction,
+        op: Operation,
+    ) {
+        unwrap_op!(let SetColumnsHidden { sheet_id, columns, hidden } = op);
+
+        let Some(sheet) = self.try_sheet_mut(sheet_id) else {
+            // sheet may have been deleted
+            return;
+        };
+
+        let changed = columns
+            .iter()
+            .copied()
+            .filter(|&column| sheet.offsets.set_column_hidden(column, hidden) != hidden)
+            .collect::<Vec<_>>();
+        if changed.is_empty() {
+            return;
+        }
+
+        transaction
+            .forward_operations
+            .push(Operation::SetColumnsHidden {
+                sheet_id,
+                columns,
+                hidden,
+            });
+        transaction
+            .reverse_operations
+            .push(Operation::SetColumnsHidden {
+                sheet_id,
+                columns: changed.clone(),
+                hidden: !hidden,
+            });
+
+        if !transaction.is_server() {
+            if let Some(sheet) = self.try_sheet(sheet_id) {
+                for &column in changed.iter() {
+                    transaction.offsets_modified(
+                        sheet_id,
+                        Some(column),
+                        None,
+                        Some(sheet.offsets.column_width(column)),
+                    );
+                    transaction.add_dirty_hashes_from_sheet_columns(sheet, column, Some(column));
+                }
+            }
+            transaction.sheet_info.insert(sheet_id);
+            transaction.add_fill_cells(sheet_id);
+            transaction.sheet_borders.insert(sheet_id);
+            transaction.generate_thumbnail |= changed.iter().any(|&column| {
+                self.thumbnail_dirty_sheet_pos(SheetPos {
+                    x: column,
+                    y: 0,
+                    sheet_id,
+                })
+            });
+        }
+
+        // code that skips hidden cells (eg, SUBTOTAL(109, ...)) is rerun
+        if transaction.is_user_undo_redo() {
+            for (first, last) in index_runs(&changed) {
+                let sheet_rect = SheetRect::new(first, 1, last, i64::MAX, sheet_id);
+                self.add_compute_operations(transaction, &sheet_rect, None);
+            }
+        }
+    }
+
+    pub fn execute_set_rows_hidden(&mut self, transaction: &mut PendingTransaction, op: Operation) {
+        unwrap_op!(let SetRowsHidden { sheet_id, rows, hidden } = op);
+
+        let Some(sheet) = self.try_sheet_mut(sheet_id) else {
+            // sheet may have been deleted
+            return;
+        };
+
+        let changed = rows
+            .iter()
+            .copied()
+            .filter(|&row| sheet.offsets.set_row_hidden(row, hidden) != hidden)
+            .collect::<Vec<_>>();
+        if changed.is_empty() {
+            return;
+        }
+
+        transaction
+            .forward_operations
+            .push(Operation::SetRowsHidden {
+                sheet_id,
+                rows,
+                hidden,
+            });
+        transaction
+            .reverse_operations
+            .push(Operation::SetRowsHidden {
+                sheet_id,
+                rows: changed.clone(),
+                hidden: !hidden,
+            });
+
+        if !transaction.is_server() {
+            if let Some(sheet) = self.try_sheet(sheet_id) {
+                for &row in changed.iter() {
+                    transaction.offsets_modified(
+                        sheet_id,
+                        None,
+                        Some(row),
+                        Some(sheet.offsets.row_height(row)),
+                    );
+                    transaction.add_dirty_hashes_from_sheet_rows(sheet, row, Some(row));
+                }
+            }
+            transaction.sheet_info.insert(sheet_id);
+            transaction.add_fill_cells(sheet_id);
+            transaction.sheet_borders.insert(sheet_id);
+            transaction.generate_thumbnail |= changed.iter().any(|&row| {
+                self.thumbnail_dirty_sheet_pos(SheetPos {
+                    x: 0,
+                    y: row,
+                    sheet_id,
+                })
+            });
+        }
+
+        // code that skips hidden cells (eg, SUBTOTAL(109, ...)) is rerun
+        if transaction.is_user_undo_redo() {
+            for (first, last) in index_runs(&changed) {
+                let sheet_rect = SheetRect::new(1, first, i64::MAX, last, sheet_id);
+                self.add_compute_operations(transaction, &sheet_rect, None);
+            }
+        }
+    }
+}
+
+/// Splits column or row indices into runs of consecutive indices, as
+/// `(first, last)`.
+fn index_runs(indices: &[i64]) -> Vec<(i64, i64)> {
+    let mut indices = indices.to_vec();
+    indices.sort_unstable();
+    let mut runs: Vec<(i64, i64)> = vec![];
+    for index in indices {
+        match runs.last_mut() {
+            Some((_, last)) if *last + 1 == index => *last = index,
+            _ => runs.push((index, index)),
+        }
+    }
+    runs
 }
 
 #[cfg(test)]
@@ -351,8 +500,9 @@ mod tests {
     use std::collections::HashMap;
 
     use crate::{
+        Axis, CellValue, Pos,
         controller::GridController,
-        grid::js_types::JsColumnWidth,
+        grid::{CodeCellLanguage, js_types::JsColumnWidth},
         wasm_bindings::js::{clear_js_calls, expect_js_offsets},
     };
 
@@ -426,4 +576,139 @@ mod tests {
         assert_eq!(sheet.offsets.column_width(2), 200.0);
         assert_eq!(sheet.offsets.column_width(4), 400.0);
     }
+
+    #[test]
+    fn test_execute_hide_columns() {
+        clear_js_calls();
+
+        let mut gc = test_create_gc();
+        let sheet_id = first_sheet_id(&gc);
+        gc.resize_columns(
+            sheet_id,
+            vec![JsColumnWidth {
+                column: 2,
+                width: 200.0,
+            }],
+            None,
+        );
+
+        gc.hide_columns(sheet_id, vec![2, 3], None);
+        let sheet = gc.sheet(sheet_id);
+        assert_eq!(sheet.offsets.hidden_columns(), vec![2, 3]);
+        assert_eq!(sheet.offsets.column_width(2), 0.0);
+
+        let mut offsets = HashMap::<(Option<i64>, Option<i64>), f64>::new();
+        offsets.insert((Some(2), None), 0.0);
+        offsets.insert((Some(3), None), 0.0);
+        expect_js_offsets(sheet_id, offsets, true);
+
+        // only the columns that changed are unhidden on undo
+        gc.hide_columns(sheet_id, vec![3, 4], None);
+        gc.undo(None);
+        assert_eq!(gc.sheet(sheet_id).offsets.hidden_columns(), vec![2, 3]);
+
+        gc.unhide_columns(sheet_id, vec![1, 2, 3], None);
+        let sheet = gc.sheet(sheet_id);
+        assert!(sheet.offsets.hidden_columns().is_empty());
+        assert_eq!(sheet.offsets.column_width(2), 200.0);
+
+        gc.undo(None);
+        assert_eq!(gc.sheet(sheet_id).offsets.hidden_columns(), vec![2, 3]);
+        gc.redo(None);
+        assert!(gc.sheet(sheet_id).offsets.hidden_columns().is_empty());
+    }
+
+    #[test]
+    fn test_execute_hide_rows() {
+        let mut gc = test_create_gc();
+        let sheet_id = first_sheet_id(&gc);
+
+        gc.hide_rows(sheet_id, vec![5, 1], None);
+        assert_eq!(gc.sheet(sheet_id).offsets.hidden_rows(), vec![1, 5]);
+
+        gc.undo(None);
+        assert!(gc.sheet(sheet_id).offsets.hidden_rows().is_empty());
+        gc.redo(None);
+        assert_eq!(gc.sheet(sheet_id).offsets.hidden_rows(), vec![1, 5]);
+
+        let file = crate::grid::file::export(gc.grid().clone()).unwrap();
+        let grid = crate::grid::file::import(file).unwrap();
+        assert_eq!(grid.sheets()[0].offsets.hidden_rows(), vec![1, 5]);
+    }
+
+    #[test]
+    fn test_hidden_insert_delete() {
+        let mut gc = test_create_gc();
+        let sheet_id = first_sheet_id(&gc);
+        gc.hide_columns(sheet_id, vec![3], None);
+        gc.hide_rows(sheet_id, vec![3], None);
+
+        gc.insert_columns(sheet_id, 2, 1, false, None);
+        assert_eq!(gc.sheet(sheet_id).offsets.hidden_columns(), vec![4]);
+        gc.insert_rows(sheet_id, 1, 1, false, None);
+        assert_eq!(gc.sheet(sheet_id).offsets.hidden_rows(), vec![4]);
+
+        gc.delete_columns(sheet_id, vec![4], None);
+        assert!(gc.sheet(sheet_id).offsets.hidden_columns().is_empty());
+        gc.delete_rows(sheet_id, vec![4], None);
+        assert!(gc.sheet(sheet_id).offsets.hidden_rows().is_empty());
+
+        gc.undo(None);
+        assert_eq!(gc.sheet(sheet_id).offsets.hidden_rows(), vec![4]);
+        gc.undo(None);
+        assert_eq!(gc.sheet(sheet_id).offsets.hidden_columns(), vec![4]);
+    }
+
+    #[test]
+    fn test_hidden_reruns_subtotal() {
+        let mut gc = test_create_gc();
+        let sheet_id = first_sheet_id(&gc);
+        for (y, value) in [(1, "1"), (2, "2"), (3, "3")] {
+            gc.set_cell_value(
+                Pos { x: 1, y }.to_sheet_pos(sheet_id),
+                value.to_string(),
+                None,
+            );
+        }
+        gc.set_code_cell(
+            pos![sheet_id!B1],
+            CodeCellLanguage::Formula,
+            "SUBTOTAL(109, A1:A3)".to_string(),
+            None,
+            None,
+        );
+        assert_display_cell_value(&gc, sheet_id, 2, 1, "6");
+
+        gc.hide_rows(sheet_id, vec![2], None);
+        assert_display_cell_value(&gc, sheet_id, 2, 1, "4");
+
+        gc.undo(None);
+        assert_display_cell_value(&gc, sheet_id, 2, 1, "6");
+
+        // undo and redo recompute the subtotal instead of restoring the old
+        // result
+        gc.hide_rows(sheet_id, vec![2], None);
+        gc.sheet_mut(sheet_id)
+            .set_cell_value(pos![A1], CellValue::from(10));
+        gc.undo(None);
+        assert_display_cell_value(&gc, sheet_id, 2, 1, "15");
+        gc.redo(None);
+        assert_display_cell_value(&gc, sheet_id, 2, 1, "13");
+        gc.undo(None);
+
+        // collapsing a group hides its rows
+        gc.group_outline(sheet_id, Axis::Y, 2, 3, None).unwrap();
+        gc.collapse_outline_group(sheet_id, Axis::Y, 2, 1, None)
+            .unwrap();
+        assert_display_cell_value(&gc, sheet_id, 2, 1, "10");
+    }
+
+    #[test]
+    fn test_index_runs() {
+        assert_eq!(
+            super::index_runs(&[5, 1, 2, 3, 7, 8]),
+            vec![(1, 3), (5, 5), (7, 8)]
+        );
+        assert!(super::index_runs(&[]).is_empty());
+    }
 }
@@ -0,0 +1,63 @@
+use crate::controller::GridController;
+use crate::controller::active_transactions::pending_transaction::PendingTransaction;
+use crate::controller::operations::operation::Operation;
+
+impl GridController {
+    pub(crate) fn execute_set_column_outline(
+        &mut self,
+        transaction: &mut PendingTransaction,
+        op: Operation,
+    ) {
+        unwrap_op!(let SetColumnOutline { sheet_id, outline } = op);
+
+        let Some(sheet) = self.try_sheet_mut(sheet_id) else {
+            // sheet may have been deleted
+            return;
+        };
+
+        if sheet.outlines.columns == outline {
+            return;
+        }
+        let old = std::mem::replace(&mut sheet.outlines.columns, outline.clone());
+
+        transaction.sheet_info.insert(sheet_id);
+        transaction
+            .forward_operations
+            .push(Operation::SetColumnOutline { sheet_id, outline });
+        transaction
+            .reverse_operations
+            .push(Operation::SetColumnOutline {
+                sheet_id,
+                outline: old,
+            });
+    }
+
+    pub(crate) fn execute_set_row_outline(
+        &mut self,
+        transaction: &mut PendingTransaction,
+        op: Operation,
+    ) {
+        unwrap_op!(let SetRowOutline { sheet_id, outline } = op);
+
+        let Some(sheet) = self.try_sheet_mut(sheet_id) else {
+            // sheet may have been deleted
+            return;
+        };
+
+        if sheet.outlines.rows == outline {
+            return;
+        }
+        let old = std::mem::replace(&mut sheet.outlines.rows, outline.clone());
+
+        transaction.sheet_info.insert(sheet_id);
+        transaction
+            .forward_operations
+            .push(Operation::SetRowOutline { sheet_id, outline });
+        transaction
+            .reverse_operations
+            .push(Operation::SetRowOutline {
+                sheet_id,
+                outline: old,
+            });
+    }
+}
@@ -0,0 +1,37 @@
+use crate::controller::GridController;
+use crate::controller::active_transactions::pending_transaction::PendingTransaction;
+use crate::controller::operations::operation::Operation;
+
+impl GridController {
+    pub(crate) fn execute_set_sheet_protection(
+        &mut self,
+        transaction: &mut PendingTransaction,
+        op: Operation,
+    ) {
+        unwrap_op!(let SetSheetProtection { sheet_id, protection } = op);
+
+        let Some(sheet) = self.try_sheet_mut(sheet_id) else {
+            // sheet may have been deleted
+            return;
+        };
+
+        let old = std::mem::replace(&mut sheet.protection, protection.clone());
+        if old == protection {
+            return;
+        }
+
+        transaction
+            .forward_operations
+            .push(Operation::SetSheetProtection {
+                sheet_id,
+                protection,
+            });
+        transaction
+            .reverse_operations
+            .push(Operation::SetSheetProtection {
+                sheet_id,
+                protection: old,
+            });
+        transaction.sheet_protection.insert(sheet_id);
+    }
+}
@@ -53,6 +53,7 @@ impl GridController {
 
                 let sheet_id = self.grid.add_sheet(Some(sheet));
                 self.send_add_sheet(transaction, sheet_id);
+                transaction.conditional_formats.insert(sheet_id);
 
                 let mut context = self.a1_context().to_owned();
 
@@ -257,6 +258,38 @@ impl GridController {
         }
     }
 
+    pub(crate) fn execute_set_frozen_panes(
+        &mut self,
+        transaction: &mut PendingTransaction,
+        op: Operation,
+    ) {
+        unwrap_op!(let SetFrozenPanes { sheet_id, columns, rows } = op);
+
+        let Some(sheet) = self.try_sheet_mut(sheet_id) else {
+            // sheet may have been deleted
+            return;
+        };
+        let old_columns = std::mem::replace(&mut sheet.frozen_columns, columns);
+        let old_rows = std::mem::replace(&mut sheet.frozen_rows, rows);
+
+        transaction
+            .forward_operations
+            .push(Operation::SetFrozenPanes {
+                sheet_id,
+                columns,
+                rows,
+            });
+        transaction
+            .reverse_operations
+            .push(Operation::SetFrozenPanes {
+                sheet_id,
+                columns: old_columns,
+                rows: old_rows,
+            });
+
+        transaction.sheet_info.insert(sheet_id);
+    }
+
     pub(crate) fn execute_duplicate_sheet(
         &mut self,
         transaction: &mut PendingTransaction,
@@ -273,6 +306,8 @@ impl GridController {
             };
             let mut new_sheet = sheet.clone();
             new_sheet.id = new_sheet_id;
+            new_sheet.conditional_formats.duplicate(new_sheet_id);
+            new_sheet.protection.duplicate(new_sheet_id);
             let right = self.grid.next_sheet(sheet_id);
             let right_order = right.map(|right| right.order.clone());
             if let Ok(order) = key_between(Some(&sheet.order), right_order.as_deref()) {
@@ -288,6 +323,7 @@ impl GridController {
             self.grid.add_sheet(Some(new_sheet));
 
             self.send_add_sheet(transaction, new_sheet_id);
+            transaction.conditional_formats.insert(new_sheet_id);
 
             transaction
                 .forward_operations
@@ -481,6 +517,50 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_set_frozen_panes() {
+        let mut gc = GridController::test();
+        let sheet_id = gc.sheet_ids()[0];
+        gc.set_frozen_panes(sheet_id, 2, 1, None);
+        assert_eq!(gc.sheet(sheet_id).frozen_columns, 2);
+        assert_eq!(gc.sheet(sheet_id).frozen_rows, 1);
+        let sheet_info = SheetInfo::from(gc.sheet(sheet_id));
+        expect_js_call(
+            "jsSheetInfoUpdate",
+            serde_json::to_string(&sheet_info).unwrap(),
+            true,
+        );
+
+        // inserting within the frozen columns grows them
+        gc.insert_columns(sheet_id, 1, 1, false, None);
+        assert_eq!(gc.sheet(sheet_id).frozen_columns, 3);
+        gc.insert_columns(sheet_id, 5, 1, false, None);
+        assert_eq!(gc.sheet(sheet_id).frozen_columns, 3);
+        gc.undo(None);
+        gc.undo(None);
+        assert_eq!(gc.sheet(sheet_id).frozen_columns, 2);
+
+        // deleting a frozen row shrinks them
+        gc.delete_rows(sheet_id, vec![1], None);
+        assert_eq!(gc.sheet(sheet_id).frozen_rows, 0);
+        gc.undo(None);
+        assert_eq!(gc.sheet(sheet_id).frozen_rows, 1);
+
+        gc.delete_columns(sheet_id, vec![2, 3], None);
+        assert_eq!(gc.sheet(sheet_id).frozen_columns, 1);
+        gc.undo(None);
+        assert_eq!(gc.sheet(sheet_id).frozen_columns, 2);
+
+        let file = crate::grid::file::export(gc.grid().clone()).unwrap();
+        let grid = crate::grid::file::import(file).unwrap();
+        assert_eq!(grid.sheets()[0].frozen_columns, 2);
+        assert_eq!(grid.sheets()[0].frozen_rows, 1);
+
+        gc.undo(None);
+        assert_eq!(gc.sheet(sheet_id).frozen_columns, 0);
+        assert_eq!(gc.sheet(sheet_id).frozen_rows, 0);
+    }
+
     #[test]
     fn test_sheet_reorder() {
         let mut gc = GridController::test();
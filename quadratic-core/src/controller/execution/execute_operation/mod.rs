@@ -28,12 +28,18 @@ mod execute_borders;
 mod execute_borders_old;
 mod execute_code;
 mod execute_col_rows;
+mod execute_comments;
+mod execute_conditional_formats;
 mod execute_cursor;
 mod execute_data_table;
 mod execute_formats;
 mod execute_formats_old;
+mod execute_grid;
+mod execute_merge_cells;
 mod execute_move_cells;
 mod execute_offsets;
+mod execute_outlines;
+mod execute_protection;
 mod execute_sheets;
 mod execute_validation;
 mod execute_values;
@@ -200,6 +206,50 @@ impl GridController {
 
                 Operation::MoveColumns { .. } => self.execute_move_columns(transaction, op),
                 Operation::MoveRows { .. } => self.execute_move_rows(transaction, op),
+
+                Operation::SetCustomLists { .. } => self.execute_set_custom_lists(transaction, op),
+                Operation::SetCellStyles { .. } => self.execute_set_cell_styles(transaction, op),
+                Operation::SetTheme { .. } => self.execute_set_theme(transaction, op),
+                Operation::SetLocale { .. } => self.execute_set_locale(transaction, op),
+                Operation::SetTimeZone { .. } => self.execute_set_time_zone(transaction, op),
+                Operation::SetDateSettings { .. } => {
+                    self.execute_set_date_settings(transaction, op);
+                }
+
+                Operation::MergeCells { .. } => self.execute_merge_cells(transaction, op),
+                Operation::UnmergeCells { .. } => self.execute_unmerge_cells(transaction, op),
+
+                Operation::SetColumnsHidden { .. } => {
+                    self.execute_set_columns_hidden(transaction, op);
+                }
+                Operation::SetRowsHidden { .. } => self.execute_set_rows_hidden(transaction, op),
+
+                Operation::SetColumnOutline { .. } => {
+                    self.execute_set_column_outline(transaction, op);
+                }
+                Operation::SetRowOutline { .. } => self.execute_set_row_outline(transaction, op),
+
+                Operation::SetFrozenPanes { .. } => self.execute_set_frozen_panes(transaction, op),
+
+                Operation::AddComment { .. } => self.execute_add_comment(transaction, op),
+                Operation::DeleteComment { .. } => self.execute_delete_comment(transaction, op),
+                Operation::SetCommentResolved { .. } => {
+                    self.execute_set_comment_resolved(transaction, op);
+                }
+                Operation::SetCommentThreads { .. } => {
+                    self.execute_set_comment_threads(transaction, op);
+                }
+
+                Operation::SetConditionalFormat { .. } => {
+                    self.execute_set_conditional_format(transaction, op);
+                }
+                Operation::RemoveConditionalFormat { .. } => {
+                    self.execute_remove_conditional_format(transaction, op);
+                }
+
+                Operation::SetSheetProtection { .. } => {
+                    self.execute_set_sheet_protection(transaction, op);
+                }
             }
         }
         #[cfg(feature = "show-first-sheet-operations")]
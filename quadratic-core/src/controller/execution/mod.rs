@@ -1,9 +1,11 @@
 pub mod auto_resize_row_heights;
+pub mod conditional_formats;
 pub mod control_transaction;
 pub mod execute_operation;
 pub mod receive_multiplayer;
 pub mod run_code;
 pub mod spills;
+pub mod validation_formulas;
 
 use super::active_transactions::ActiveTransactions;
 use super::active_transactions::pending_transaction::PendingTransaction;
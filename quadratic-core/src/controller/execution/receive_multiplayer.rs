@@ -37,27 +37,56 @@ impl GridController {
     }
 
     /// Reapplies the rolled-back unsaved transactions after adding earlier transactions.
+    ///
+    /// Each transaction is checked again against the protection of the grid it
+    /// is reapplied to, since the server checks it at the same point. A
+    /// transaction that is no longer allowed is dropped and the user is told.
     fn reapply_unsaved_transactions(&mut self) {
-        if self.transactions.unsaved_transactions.is_empty() {
+        let mut index = 0;
+        while index < self.transactions.unsaved_transactions.len() {
+            let operations = self.transactions.unsaved_transactions[index]
+                .forward
+                .operations
+                .clone();
+            if !self.allow_operations(&operations) {
+                let unsaved = self.transactions.unsaved_transactions.remove(index);
+                self.drop_refused_transaction(unsaved.forward.id);
+                continue;
+            }
+            let mut reapply = PendingTransaction {
+                // Note: setting this to multiplayer makes it so the calculations are not rerun when reapplied.
+                // This seems the right approach, otherwise we may end up with long running calculations
+                // having to be sent to the server multiple times when multiple users are making changes.
+                source: TransactionSource::Multiplayer,
+                operations: operations.into(),
+                ..Default::default()
+            };
+            self.start_transaction(&mut reapply);
+            self.finalize_transaction(reapply);
+            index += 1;
+        }
+    }
+
+    /// Removes a transaction of ours that the server will not apply because it
+    /// changes protected ranges, so it can't be undone either.
+    fn drop_refused_transaction(&mut self, transaction_id: Uuid) {
+        self.undo_stack
+            .retain(|transaction| transaction.id != transaction_id);
+    }
+
+    /// Applies a transaction received from the server. The server skips a
+    /// transaction that changes protected ranges its editor may not edit, so
+    /// it is skipped here as well to keep the grid in sync with the file.
+    fn apply_received_transaction(&mut self, mut transaction: PendingTransaction) {
+        let editor = transaction.editor.clone();
+        if let Err(error) =
+            self.check_protection(transaction.operations.make_contiguous(), editor.as_deref())
+        {
+            dbgjs!(format!("Skipping transaction from {editor:?}: {error}"));
             return;
         }
-        let operations = self
-            .transactions
-            .unsaved_transactions
-            .iter()
-            .rev()
-            .flat_map(|unsaved_transaction| unsaved_transaction.forward.operations.clone())
-            .collect::<Vec<_>>();
-        let mut reapply = PendingTransaction {
-            // Note: setting this to multiplayer makes it so the calculations are not rerun when reapplied.
-            // This seems the right approach, otherwise we may end up with long running calculations
-            // having to be sent to the server multiple times when multiple users are making changes.
-            source: TransactionSource::Multiplayer,
-            operations: operations.into(),
-            ..Default::default()
-        };
-        self.start_transaction(&mut reapply);
-        self.finalize_transaction(reapply);
+        self.start_transaction(&mut transaction);
+        self.finalize_transaction(transaction);
     }
 
     /// Used by the server to apply transactions. Since the server owns the sequence_num,
@@ -109,36 +138,24 @@ impl GridController {
     /// Check the out_of_order_transactions to see if they are next in order. If so, we remove them from
     /// out_of_order_transactions and apply their operations.
     fn apply_out_of_order_transactions(&mut self, mut sequence_num: u64) {
-        // nothing to do here
-        if self.transactions.out_of_order_transactions.is_empty() {
-            self.transactions.last_sequence_num = sequence_num;
-            return;
+        // while an out of order transaction is next in sequence, we remove it from the list and apply it.
+        // Each is applied separately since its protection is checked against the grid before it.
+        while let Some(index) = self
+            .transactions
+            .out_of_order_transactions
+            .iter()
+            .position(|t| t.sequence_num == Some(sequence_num + 1))
+        {
+            let transaction = self.transactions.out_of_order_transactions.remove(index);
+            self.apply_received_transaction(PendingTransaction {
+                id: transaction.id,
+                source: TransactionSource::Multiplayer,
+                editor: transaction.editor,
+                operations: transaction.operations.into(),
+                ..Default::default()
+            });
+            sequence_num += 1;
         }
-
-        // combines all out of order transactions into a single vec of operations
-        let mut operations = VecDeque::new();
-        self.transactions.out_of_order_transactions.retain(|t| {
-            // while the out of order transaction is next in sequence, we apply it and remove it from the list
-            if let Some(transaction_sequence_num) = t.sequence_num {
-                if transaction_sequence_num == sequence_num + 1 {
-                    operations.extend(t.operations.clone());
-                    sequence_num += 1;
-                    false
-                } else {
-                    true
-                }
-            } else {
-                // this should not happen as sequence_num for multiplayer transactions should always be set
-                true
-            }
-        });
-        let mut out_of_order_transaction = PendingTransaction {
-            source: TransactionSource::Multiplayer,
-            operations,
-            ..Default::default()
-        };
-        self.start_transaction(&mut out_of_order_transaction);
-        self.finalize_transaction(out_of_order_transaction);
         self.transactions.last_sequence_num = sequence_num;
     }
 
@@ -167,16 +184,19 @@ impl GridController {
                     transaction.operations = unsaved.forward.operations.into();
 
                     self.mark_transaction_sent(transaction.id);
-                    self.start_transaction(&mut transaction);
-                    self.finalize_transaction(transaction);
+                    if self.allow_operations(transaction.operations.make_contiguous()) {
+                        self.start_transaction(&mut transaction);
+                        self.finalize_transaction(transaction);
+                    } else {
+                        self.drop_refused_transaction(transaction.id);
+                    }
                     self.apply_out_of_order_transactions(sequence_num);
                     self.reapply_unsaved_transactions();
                 }
             } else {
                 // If the transaction is not one of ours, then we just apply the transaction after rolling back any unsaved transactions
                 self.rollback_unsaved_transactions();
-                self.start_transaction(&mut transaction);
-                self.finalize_transaction(transaction);
+                self.apply_received_transaction(transaction);
                 self.apply_out_of_order_transactions(sequence_num);
                 self.reapply_unsaved_transactions();
             }
@@ -200,16 +220,19 @@ impl GridController {
         }
     }
 
-    /// Received a transaction from the server
+    /// Received a transaction from the server. `editor` is the email of its
+    /// sender.
     pub fn received_transaction(
         &mut self,
         transaction_id: Uuid,
         sequence_num: u64,
         operations: Vec<Operation>,
+        editor: Option<String>,
     ) {
         let transaction = PendingTransaction {
             id: transaction_id,
             source: TransactionSource::Multiplayer,
+            editor,
             operations: operations.into(),
             ..Default::default()
         };
@@ -226,7 +249,7 @@ impl GridController {
                 Transaction::decompress_and_deserialize::<Vec<Operation>>(&t.operations);
 
             if let Ok(operations) = operations {
-                self.received_transaction(t.id, t.sequence_num, operations);
+                self.received_transaction(t.id, t.sequence_num, operations, t.editor);
             } else {
                 dbgjs!(
                     "Unable to decompress and deserialize operations in received_transactions()"
@@ -314,7 +337,7 @@ mod tests {
         let operations: Vec<Operation> = transaction.operations.clone();
 
         // received our own transaction back
-        gc1.received_transaction(transaction_id, 1, operations.clone());
+        gc1.received_transaction(transaction_id, 1, operations.clone(), None);
 
         let sheet = gc1.grid().try_sheet(sheet_id).unwrap();
         assert_eq!(
@@ -325,7 +348,7 @@ mod tests {
 
         let mut gc2 = GridController::test();
         gc2.grid_mut().sheets_mut()[0].id = sheet_id;
-        gc2.received_transaction(transaction_id, 1, operations);
+        gc2.received_transaction(transaction_id, 1, operations, None);
         let sheet = gc2.grid().try_sheet(sheet_id).unwrap();
         assert_eq!(
             sheet.display_value(Pos { x: 0, y: 0 }),
@@ -376,7 +399,7 @@ mod tests {
 
         // gc1 should apply gc2's cell value to 0,0 before its unsaved transaction
         // and then reapply its unsaved transaction, overwriting 0,0
-        gc1.received_transaction(transaction_id, 1, operations);
+        gc1.received_transaction(transaction_id, 1, operations, None);
         let sheet = gc1.grid.try_sheet(sheet_id).unwrap();
         assert_eq!(
             sheet.display_value(Pos { x: 0, y: 0 }),
@@ -451,7 +474,7 @@ mod tests {
         let transaction = other.last_transaction().unwrap();
         let other_operations = transaction.operations.clone();
 
-        client.received_transaction(Uuid::new_v4(), 1, other_operations);
+        client.received_transaction(Uuid::new_v4(), 1, other_operations, None);
 
         // todo: we should generate the thumbnail as we overwrite the unsaved value again
         // we should generate the thumbnail as we overwrite the unsaved value again
@@ -502,7 +525,7 @@ mod tests {
         let out_of_order_2_operations = other.last_transaction().unwrap().operations.clone();
 
         // Send sequence_num = 2 first to client. Client stores this transaction in out_of_order_transactions but does not apply it.
-        client.received_transaction(Uuid::new_v4(), 2, out_of_order_2_operations);
+        client.received_transaction(Uuid::new_v4(), 2, out_of_order_2_operations, None);
         assert_eq!(
             client
                 .try_sheet(sheet_id)
@@ -513,7 +536,7 @@ mod tests {
         assert_eq!(client.transactions.out_of_order_transactions.len(), 1);
 
         // We receive the correctly ordered transaction. Both are applied in the correct order.
-        client.received_transaction(Uuid::new_v4(), 1, out_of_order_1_operations);
+        client.received_transaction(Uuid::new_v4(), 1, out_of_order_1_operations, None);
         assert_eq!(
             client
                 .try_sheet(sheet_id)
@@ -573,7 +596,7 @@ mod tests {
 
         // Send sequence_num = 2 first to client. Client stores this transaction in out_of_order_transactions but does not apply it.
         // We should still see our unsaved transaction.
-        client.received_transaction(Uuid::new_v4(), 2, out_of_order_2_operations);
+        client.received_transaction(Uuid::new_v4(), 2, out_of_order_2_operations, None);
         assert_eq!(
             client
                 .try_sheet(sheet_id)
@@ -585,7 +608,7 @@ mod tests {
         assert_eq!(client.transactions.unsaved_transactions.len(), 1);
 
         // We receive the correctly ordered transaction. Both are applied in the correct order.
-        client.received_transaction(Uuid::new_v4(), 1, out_of_order_1_operations);
+        client.received_transaction(Uuid::new_v4(), 1, out_of_order_1_operations, None);
         assert_eq!(
             client
                 .try_sheet(sheet_id)
@@ -596,7 +619,12 @@ mod tests {
         assert_eq!(client.transactions.out_of_order_transactions.len(), 0);
 
         // We receive our unsaved transaction back.
-        client.received_transaction(client_transaction.id, 3, client_transaction.operations);
+        client.received_transaction(
+            client_transaction.id,
+            3,
+            client_transaction.operations,
+            None,
+        );
         assert_eq!(client.transactions.unsaved_transactions.len(), 0);
         assert_eq!(
             client
@@ -667,12 +695,14 @@ mod tests {
                 id: Uuid::new_v4(),
                 sequence_num: 1,
                 operations: other_1_operations_compressed,
+                editor: None,
             },
             TransactionServer {
                 file_id: Uuid::new_v4(),
                 id: Uuid::new_v4(),
                 sequence_num: 2,
                 operations: other_2_operations_compressed,
+                editor: None,
             },
         ]);
         assert_eq!(client.transactions.last_sequence_num, 2);
@@ -745,6 +775,7 @@ mod tests {
             id: Uuid::new_v4(),
             sequence_num: 1,
             operations: other_operations_compressed,
+            editor: None,
         }]);
 
         assert_eq!(
@@ -826,6 +857,7 @@ mod tests {
             id: Uuid::new_v4(),
             sequence_num: 1,
             operations: other_operations_compressed,
+            editor: None,
         }]);
 
         // expect this to be None since the async client.set_code_cell overwrites the other's multiplayer transaction
@@ -929,13 +961,13 @@ mod tests {
         assert_eq!(gc.active_transactions().async_transactions.len(), 0);
 
         // receive back the transactions in order
-        gc.received_transaction(transaction_id_0, 1, operations_0);
+        gc.received_transaction(transaction_id_0, 1, operations_0, None);
         assert_eq!(gc.active_transactions().unsaved_transactions.len(), 2);
 
-        gc.received_transaction(transaction_id_1, 2, operations_1);
+        gc.received_transaction(transaction_id_1, 2, operations_1, None);
         assert_eq!(gc.active_transactions().unsaved_transactions.len(), 1);
 
-        gc.received_transaction(transaction_id_2, 3, operations_2);
+        gc.received_transaction(transaction_id_2, 3, operations_2, None);
         assert_eq!(gc.active_transactions().unsaved_transactions.len(), 0);
 
         let sheet = gc.grid.first_sheet();
@@ -957,13 +989,13 @@ mod tests {
     fn test_multiplayer_python_multiple_calculations_receive_back_between() {
         let mut gc = GridController::test();
         let (transaction_id_0, operations_0) = create_multiple_calculations_0(&mut gc);
-        gc.received_transaction(transaction_id_0, 1, operations_0);
+        gc.received_transaction(transaction_id_0, 1, operations_0, None);
 
         let (transaction_id_1, operations_1) = create_multiple_calculations_1(&mut gc);
-        gc.received_transaction(transaction_id_1, 2, operations_1);
+        gc.received_transaction(transaction_id_1, 2, operations_1, None);
 
         let (transaction_id_2, operations_2) = create_multiple_calculations_2(&mut gc);
-        gc.received_transaction(transaction_id_2, 3, operations_2);
+        gc.received_transaction(transaction_id_2, 3, operations_2, None);
 
         let sheet = gc.grid.first_sheet();
         assert_eq!(
@@ -1143,9 +1175,9 @@ mod tests {
         let (transaction_id_1, operations_1) = create_multiple_calculations_1(&mut gc);
         let (transaction_id_2, operations_2) = create_multiple_calculations_2(&mut gc);
 
-        gc.received_transaction(transaction_id_2, 3, operations_2);
-        gc.received_transaction(transaction_id_0, 1, operations_0);
-        gc.received_transaction(transaction_id_1, 2, operations_1);
+        gc.received_transaction(transaction_id_2, 3, operations_2, None);
+        gc.received_transaction(transaction_id_0, 1, operations_0, None);
+        gc.received_transaction(transaction_id_1, 2, operations_1, None);
 
         let cell_value_num = |n: i64| CellValue::Number(BigDecimal::from(n));
         let sheet = gc.grid.first_sheet();
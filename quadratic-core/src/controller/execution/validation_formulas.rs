@@ -0,0 +1,467 @@
+//! Evaluates validations with a formula (custom rules and lists with a
+//! formula source). A formula may reference any cell (eg,
+//! `=COUNTIF($A:$A, A2)=1`), so after each transaction the validations whose
+//! cells, or the cells their formula accessed, are within the dirty hashes are
+//! evaluated again. The warnings are derived from the grid, so they are kept
+//! outside of the undo stack and are not sent to other players.
+
+use itertools::Itertools;
+
+use crate::controller::GridController;
+use crate::controller::active_transactions::pending_transaction::PendingTransaction;
+use crate::formulas::Formula;
+use crate::grid::js_types::JsValidationWarning;
+use crate::grid::sheet::validations::rules::ValidationRule;
+use crate::grid::sheet::validations::validation::Validation;
+use crate::grid::sheet::validations::warnings::ValidationFormulaResults;
+use crate::grid::{CellsAccessed, Sheet, SheetId};
+use crate::{CellValue, Pos, SheetPos, SheetRect};
+
+impl GridController {
+    /// Validates a cell against a validation with a formula. If set,
+    /// `pending_value` replaces the cell's value (eg, for input that has not
+    /// been set yet). Other rules always pass.
+    pub(crate) fn validate_formula_rule(
+        &self,
+        validation: &Validation,
+        pos: SheetPos,
+        pending_value: Option<CellValue>,
+    ) -> bool {
+        let anchor = validation
+            .selection
+            .anchor(&self.a1_context)
+            .to_sheet_pos(pos.sheet_id);
+        let formula = validation
+            .rule
+            .formula()
+            .and_then(|source| self.parse_rule_formula(source, anchor));
+        self.validate_parsed_formula_rule(
+            validation,
+            formula.as_ref(),
+            anchor,
+            pos,
+            pending_value,
+            &mut CellsAccessed::default(),
+        )
+    }
+
+    /// Validates a cell against a validation with its parsed formula (None if
+    /// the formula cannot be parsed). The cells accessed by the formula are
+    /// added to `cells_accessed`.
+    fn validate_parsed_formula_rule(
+        &self,
+        validation: &Validation,
+        formula: Option<&Formula>,
+        anchor: SheetPos,
+        pos: SheetPos,
+        pending_value: Option<CellValue>,
+        cells_accessed: &mut CellsAccessed,
+    ) -> bool {
+        let Some(sheet) = self.try_sheet(pos.sheet_id) else {
+            return true;
+        };
+        let value = match &pending_value {
+            Some(value) => Some(value.clone()),
+            None => sheet.display_value(pos.into()),
+        };
+        match &validation.rule {
+            ValidationRule::Custom(custom) => custom.validate(value.as_ref(), |_| {
+                formula.is_some_and(|formula| {
+                    self.evaluate_rule_formula(formula, anchor, pos, pending_value, cells_accessed)
+                })
+            }),
+            ValidationRule::List(list) if validation.rule.has_formula() => {
+                let items = self.parsed_formula_list(formula, anchor, pos, cells_accessed);
+                list.validate_with(value.as_ref(), |value| items.contains(&value.to_display()))
+            }
+            _ => true,
+        }
+    }
+
+    /// Returns the values of a list validation's formula source for a cell
+    /// (blanks and duplicates are skipped).
+    pub(crate) fn formula_list(
+        &self,
+        formula: &str,
+        anchor: SheetPos,
+        pos: SheetPos,
+    ) -> Vec<String> {
+        let formula = self.parse_rule_formula(formula, anchor);
+        self.parsed_formula_list(formula.as_ref(), anchor, pos, &mut CellsAccessed::default())
+    }
+
+    /// Returns the values of a list validation's parsed formula source for a
+    /// cell (see [`Self::formula_list`]).
+    fn parsed_formula_list(
+        &self,
+        formula: Option<&Formula>,
+        anchor: SheetPos,
+        pos: SheetPos,
+        cells_accessed: &mut CellsAccessed,
+    ) -> Vec<String> {
+        let Some(formula) = formula else {
+            return vec![];
+        };
+        self.evaluate_rule_formula_value(formula, anchor, pos, None, cells_accessed)
+            .cell_values_slice()
+            .unwrap_or_default()
+            .iter()
+            .filter(|value| !value.is_blank_or_empty_string())
+            .map(|value| value.to_display())
+            .unique()
+            .collect()
+    }
+
+    /// Evaluates a validation with a formula for the cells in its selection.
+    /// The formula is parsed once and moved to each cell.
+    fn evaluate_formula_validation(
+        &self,
+        sheet: &Sheet,
+        validation: &Validation,
+    ) -> ValidationFormulaResults {
+        let mut cells_accessed = CellsAccessed::default();
+        for range in validation.selection.ranges.iter() {
+            cells_accessed.add(sheet.id, range.clone());
+        }
+        let anchor = validation
+            .selection
+            .anchor(&self.a1_context)
+            .to_sheet_pos(sheet.id);
+        let formula = validation
+            .rule
+            .formula()
+            .and_then(|source| self.parse_rule_formula(source, anchor));
+        let failing = sheet
+            .selection_values(&validation.selection, None, false, true, &self.a1_context)
+            .into_iter()
+            .flat_map(|values| values.into_keys())
+            .filter(|pos| {
+                !self.validate_parsed_formula_rule(
+                    validation,
+                    formula.as_ref(),
+                    anchor,
+                    pos.to_sheet_pos(sheet.id),
+                    None,
+                    &mut cells_accessed,
+                )
+            })
+            .collect();
+        ValidationFormulaResults {
+            validation: validation.clone(),
+            cells_accessed,
+            failing,
+        }
+    }
+
+    /// Evaluates the validations with a formula of a sheet that are new,
+    /// changed, or that accessed a cell within `dirty`. Returns the cells whose
+    /// warning may have changed.
+    pub(crate) fn update_sheet_formula_validations(
+        &mut self,
+        sheet_id: SheetId,
+        dirty: &[SheetRect],
+    ) -> Vec<Pos> {
+        let Some(sheet) = self.try_sheet(sheet_id) else {
+            return vec![];
+        };
+        let validations = &sheet.validations;
+        if validations.formula_results.is_empty()
+            && !validations
+                .validations
+                .iter()
+                .any(|validation| validation.rule.has_formula())
+        {
+            return vec![];
+        }
+        let results = validations
+            .validations
+            .iter()
+            .filter(|validation| validation.rule.has_formula())
+            .filter(|validation| {
+                validations
+                    .formula_results
+                    .get(&validation.id)
+                    .is_none_or(|old| {
+                        old.validation != **validation
+                            || dirty
+                                .iter()
+                                .any(|rect| old.cells_accessed.intersects(rect, &self.a1_context))
+                    })
+            })
+            .map(|validation| self.evaluate_formula_validation(sheet, validation))
+            .collect();
+        match self.try_sheet_mut(sheet_id) {
+            Some(sheet) => sheet
+                .validations
+                .set_formula_results(results)
+                .into_iter()
+                .collect(),
+            None => vec![],
+        }
+    }
+
+    /// Updates the warnings of validations with a formula in all sheets that
+    /// are affected by a transaction and sends the changed warnings to the
+    /// client.
+    pub(crate) fn update_formula_validation_warnings(
+        &mut self,
+        transaction: &mut PendingTransaction,
+    ) {
+        if (!cfg!(target_family = "wasm") && !cfg!(test)) || transaction.is_server() {
+            return;
+        }
+
+        let dirty = transaction.dirty_sheet_rects();
+        for sheet_id in self.sheet_ids() {
+            let changed = self.update_sheet_formula_validations(sheet_id, &dirty);
+            let Some(sheet) = self.try_sheet(sheet_id) else {
+                continue;
+            };
+            for pos in changed {
+                match sheet.validations.get_warning(pos) {
+                    Some(validation_id) => transaction.validation_warning_added(
+                        sheet_id,
+                        JsValidationWarning {
+                            x: pos.x,
+                            y: pos.y,
+                            style: sheet
+                                .validations
+                                .validation(*validation_id)
+                                .map(|validation| validation.error.style.clone()),
+                            validation: Some(*validation_id),
+                        },
+                    ),
+                    None => transaction.validation_warning_deleted(sheet_id, pos),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Rect;
+    use crate::a1::A1Selection;
+    use crate::controller::GridController;
+    use crate::controller::operations::operation::Operation;
+    use crate::grid::js_types::JsRenderCellSpecial;
+    use crate::grid::sheet::validations::rules::ValidationRule;
+    use crate::grid::sheet::validations::rules::validation_custom::ValidationCustom;
+    use crate::grid::sheet::validations::rules::validation_list::{
+        ValidationList, ValidationListSource,
+    };
+    use crate::grid::sheet::validations::validation::{Validation, ValidationStyle};
+    use crate::test_util::*;
+
+    fn custom_validation(selection: &str, formula: &str) -> Validation {
+        Validation {
+            id: uuid::Uuid::new_v4(),
+            selection: A1Selection::test_a1(selection),
+            rule: ValidationRule::Custom(ValidationCustom {
+                formula: formula.to_string(),
+                ignore_blank: true,
+            }),
+            message: Default::default(),
+            error: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_custom_validation_unique() {
+        let mut gc = GridController::test();
+        let sheet_id = first_sheet_id(&gc);
+        gc.set_cell_values(
+            pos![sheet_id!A1],
+            vec![vec!["a".into()], vec!["b".into()], vec!["a".into()]],
+            None,
+        );
+        let validation = custom_validation("A1:A10", "=COUNTIF($A$1:$A$10, A1)=1");
+        gc.update_validation(validation.clone(), None);
+
+        let sheet = gc.sheet(sheet_id);
+        assert_eq!(
+            sheet.validations.get_warning(pos![A1]),
+            Some(&validation.id)
+        );
+        assert!(!sheet.validations.has_warning(pos![A2]));
+        assert_eq!(
+            sheet.validations.get_warning(pos![A3]),
+            Some(&validation.id)
+        );
+
+        // changing another cell re-evaluates the formula
+        gc.set_cell_value(pos![sheet_id!A3], "c".to_string(), None);
+        let sheet = gc.sheet(sheet_id);
+        assert!(!sheet.validations.has_warning(pos![A1]));
+        assert!(!sheet.validations.has_warning(pos![A3]));
+
+        gc.undo(None);
+        let sheet = gc.sheet(sheet_id);
+        assert!(sheet.validations.has_warning(pos![A1]));
+        assert!(sheet.validations.has_warning(pos![A3]));
+    }
+
+    #[test]
+    fn test_custom_validation_warnings_are_derived() {
+        let mut gc = GridController::test();
+        let sheet_id = first_sheet_id(&gc);
+        gc.set_cell_values(
+            pos![sheet_id!A1],
+            vec![vec!["a".into()], vec!["b".into()]],
+            None,
+        );
+        let validation = custom_validation("A1:A10", "=COUNTIF($A$1:$A$10, A1)=1");
+        gc.update_validation(validation.clone(), None);
+
+        // warnings of formula validations are not undoable operations
+        gc.set_cell_value(pos![sheet_id!A2], "a".to_string(), None);
+        assert!(gc.sheet(sheet_id).validations.has_warning(pos![A2]));
+        let transaction = gc.last_transaction().unwrap();
+        assert!(
+            !transaction
+                .operations
+                .iter()
+                .any(|op| matches!(op, Operation::SetValidationWarning { .. }))
+        );
+
+        // a stale result is kept when the transaction does not affect the
+        // cells accessed by the formula
+        gc.sheet_mut(sheet_id)
+            .validations
+            .formula_results
+            .get_mut(&validation.id)
+            .unwrap()
+            .failing
+            .insert(pos![A5]);
+        gc.set_cell_value(pos![sheet_id!Z100], "x".to_string(), None);
+        assert!(gc.sheet(sheet_id).validations.has_warning(pos![A5]));
+
+        gc.set_cell_value(pos![sheet_id!A3], "c".to_string(), None);
+        assert!(!gc.sheet(sheet_id).validations.has_warning(pos![A5]));
+    }
+
+    #[test]
+    fn test_custom_validation_cross_field() {
+        let mut gc = GridController::test();
+        let sheet_id = first_sheet_id(&gc);
+        gc.set_cell_values(
+            pos![sheet_id!A2],
+            vec![vec!["1".into(), "5".into()], vec!["5".into(), "2".into()]],
+            None,
+        );
+        let validation = custom_validation("B2:B3", "=B2>A2");
+        gc.update_validation(validation.clone(), None);
+
+        let sheet = gc.sheet(sheet_id);
+        assert!(!sheet.validations.has_warning(pos![B2]));
+        assert_eq!(
+            sheet.validations.get_warning(pos![B3]),
+            Some(&validation.id)
+        );
+    }
+
+    #[test]
+    fn test_custom_validation_input() {
+        let mut gc = GridController::test();
+        let sheet_id = first_sheet_id(&gc);
+        gc.set_cell_value(pos![sheet_id!A1], "10".to_string(), None);
+        let mut validation = custom_validation("B1", "=B1>A1");
+        validation.error.style = ValidationStyle::Stop;
+        gc.update_validation(validation.clone(), None);
+
+        assert_eq!(
+            gc.validate_input(sheet_id, pos![B1], "5"),
+            Some(validation.id)
+        );
+        assert_eq!(gc.validate_input(sheet_id, pos![B1], "20"), None);
+    }
+
+    #[test]
+    fn test_dependent_list_validation() {
+        let mut gc = GridController::test();
+        let sheet_id = first_sheet_id(&gc);
+
+        // D1:E4 maps countries to cities
+        gc.set_cell_values(
+            pos![sheet_id!D1],
+            vec![
+                vec!["US".into(), "Boston".into()],
+                vec!["US".into(), "Denver".into()],
+                vec!["FR".into(), "Paris".into()],
+                vec!["US".into(), "Boston".into()],
+            ],
+            None,
+        );
+        gc.set_cell_values(
+            pos![sheet_id!A1],
+            vec![vec!["US".into(), "Paris".into()]],
+            None,
+        );
+        let validation = Validation {
+            id: uuid::Uuid::new_v4(),
+            selection: A1Selection::test_a1("B1:B10"),
+            rule: ValidationRule::List(ValidationList {
+                source: ValidationListSource::Formula("=FILTER($E$1:$E$4, $D$1:$D$4=A1)".into()),
+                ignore_blank: true,
+                drop_down: true,
+                multi_select: false,
+            }),
+            message: Default::default(),
+            error: Default::default(),
+        };
+        gc.update_validation(validation.clone(), None);
+
+        assert_eq!(
+            gc.validation_list(sheet_id, 2, 1),
+            Some(vec!["Boston".to_string(), "Denver".to_string()])
+        );
+        assert_eq!(
+            gc.sheet(sheet_id).validations.get_warning(pos![B1]),
+            Some(&validation.id)
+        );
+
+        // changing the country re-evaluates the list
+        gc.set_cell_value(pos![sheet_id!A1], "FR".to_string(), None);
+        assert_eq!(
+            gc.validation_list(sheet_id, 2, 1),
+            Some(vec!["Paris".to_string()])
+        );
+        assert!(!gc.sheet(sheet_id).validations.has_warning(pos![B1]));
+    }
+
+    #[test]
+    fn test_multi_select_list_validation() {
+        let mut gc = GridController::test();
+        let sheet_id = first_sheet_id(&gc);
+        let mut validation = Validation {
+            id: uuid::Uuid::new_v4(),
+            selection: A1Selection::test_a1("A1"),
+            rule: ValidationRule::List(ValidationList {
+                source: ValidationListSource::List(vec!["red".into(), "green".into()]),
+                ignore_blank: true,
+                drop_down: true,
+                multi_select: true,
+            }),
+            message: Default::default(),
+            error: Default::default(),
+        };
+        validation.error.style = ValidationStyle::Stop;
+        gc.update_validation(validation.clone(), None);
+
+        assert_eq!(gc.validate_input(sheet_id, pos![A1], "red, green"), None);
+        assert_eq!(
+            gc.validate_input(sheet_id, pos![A1], "red, blue"),
+            Some(validation.id)
+        );
+
+        gc.set_cell_value(pos![sheet_id!A1], "red, green".to_string(), None);
+        let cells = gc
+            .sheet(sheet_id)
+            .get_render_cells(Rect::test_a1("A1"), gc.a1_context());
+        assert_eq!(cells[0].special, Some(JsRenderCellSpecial::MultiSelectList));
+        assert_eq!(
+            cells[0].chips,
+            Some(vec!["red".to_string(), "green".to_string()])
+        );
+    }
+}
@@ -35,8 +35,13 @@ impl GridController {
             for x in bounds.min.x..=bounds.max.x {
                 // we need to ignore unselected columns or rows
                 if selection.might_contain_pos(Pos { x, y }, context) {
-                    if let Some((_, value)) = iter.peeking_next(|(pos, _)| pos.x == x && pos.y == y)
-                    {
+                    let value = iter.peeking_next(|(pos, _)| pos.x == x && pos.y == y);
+
+                    // hidden columns and rows are not exported
+                    if sheet.offsets.is_column_hidden(x) || sheet.offsets.is_row_hidden(y) {
+                        continue;
+                    }
+                    if let Some((_, value)) = value {
                         line.push(value.to_string());
                     } else {
                         line.push("".to_string());
@@ -57,7 +62,9 @@ mod tests {
 
     use super::*;
 
-    use crate::{Array, controller::user_actions::import::tests::simple_csv};
+    use crate::{
+        Array, CellValue, RichText, TextSpan, controller::user_actions::import::tests::simple_csv,
+    };
 
     #[test]
     fn exports_a_csv() {
@@ -81,6 +88,47 @@ mod tests {
         assert_eq!(&result, expected);
     }
 
+    #[test]
+    fn exports_a_csv_without_hidden() {
+        let mut gc = GridController::test();
+        let sheet_id = gc.sheet_ids()[0];
+
+        let vals = vec![
+            vec!["1", "2", "3"],
+            vec!["4", "5", "6"],
+            vec!["7", "8", "9"],
+        ];
+        let sheet = gc.sheet_mut(sheet_id);
+        sheet.set_cell_values(crate::Rect::new(1, 1, 3, 3), &Array::from(vals));
+        gc.hide_columns(sheet_id, vec![2], None);
+        gc.hide_rows(sheet_id, vec![1], None);
+
+        let result = gc
+            .export_csv_selection(&mut A1Selection::test_a1("A1:C3"))
+            .unwrap();
+        assert_eq!(&result, "4,6\n7,9\n");
+    }
+
+    #[test]
+    fn exports_a_csv_with_rich_text() {
+        let mut gc = GridController::test();
+        let sheet_id = gc.sheet_ids()[0];
+        let rich_text = RichText::new([
+            TextSpan {
+                bold: Some(true),
+                ..TextSpan::new("Total:")
+            },
+            TextSpan::new(" 42"),
+        ]);
+        gc.sheet_mut(sheet_id)
+            .set_cell_value(pos![A1], CellValue::RichText(rich_text));
+
+        let result = gc
+            .export_csv_selection(&mut A1Selection::test_a1("A1"))
+            .unwrap();
+        assert_eq!(&result, "Total: 42\n");
+    }
+
     #[test]
     fn exports_a_csv_with_a_data_table() {
         let (mut gc, sheet_id, pos, _) = simple_csv();
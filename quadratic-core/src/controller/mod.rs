@@ -40,6 +40,9 @@ pub struct GridController {
     // the viewport buffer is a shared array buffer that is accessed by the render web worker and the controller
     // contains current viewport position and sheet id, updated by render web worker on viewport change
     viewport_buffer: Option<ViewportBuffer>,
+
+    // email of the user editing the file, used to check sheet and range protection
+    editor: Option<String>,
 }
 
 impl Default for GridController {
@@ -53,6 +56,7 @@ impl Default for GridController {
             redo_stack: Vec::new(),
             transactions: ActiveTransactions::new(0),
             viewport_buffer: None,
+            editor: None,
         }
     }
 }
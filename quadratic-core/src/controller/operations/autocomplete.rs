@@ -6,7 +6,7 @@ use crate::{
     grid::{
         SheetId,
         formats::SheetFormatUpdates,
-        series::{SeriesOptions, find_auto_complete},
+        series::{SeriesOptions, find_auto_complete_with_custom_lists},
         sheet::borders::BordersUpdates,
         unique_data_table_name,
     },
@@ -634,11 +634,14 @@ impl GridController {
             sheet.cell_values_pos_in_rect(initial_range, true)
         };
 
-        let mut series = find_auto_complete(SeriesOptions {
-            series: values,
-            spaces: (final_range.width() * final_range.height()) as i32,
-            negative,
-        });
+        let mut series = find_auto_complete_with_custom_lists(
+            SeriesOptions {
+                series: values,
+                spaces: (final_range.width() * final_range.height()) as i32,
+                negative,
+            },
+            &self.grid.custom_lists,
+        );
 
         // we don't need to apply any operations to the cells set in
         // data_tables_and_cell_values_in_rect() for no_op_cells
@@ -31,6 +31,10 @@ impl GridController {
         // we are clearing a style.
         let clear_neighbors = clear_neighbors && style.is_none();
 
+        // clearing all borders also clears diagonals, but setting all
+        // borders does not add them
+        let clear_diagonals = style.is_none();
+
         let style = style.map_or(Some(ClearOption::Clear), |s| {
             Some(ClearOption::Some(s.into()))
         });
@@ -53,6 +57,16 @@ impl GridController {
                     .right
                     .get_or_insert_with(Default::default)
                     .set_rect(x1, y1, x2, y2, style);
+                if clear_diagonals {
+                    borders
+                        .diagonal_up
+                        .get_or_insert_default()
+                        .set_rect(x1, y1, x2, y2, style);
+                    borders
+                        .diagonal_down
+                        .get_or_insert_default()
+                        .set_rect(x1, y1, x2, y2, style);
+                }
                 if clear_neighbors {
                     if x1 > 1 {
                         borders.right.get_or_insert_default().set_rect(
@@ -338,7 +352,33 @@ impl GridController {
             }
             // for clear, we need to remove any borders that are at the edges of
             // the range--eg, the left border at the next column to the right of the range
+            BorderSelection::DiagonalUp => {
+                borders
+                    .diagonal_up
+                    .get_or_insert_default()
+                    .set_rect(x1, y1, x2, y2, style);
+            }
+            BorderSelection::DiagonalDown => {
+                borders
+                    .diagonal_down
+                    .get_or_insert_default()
+                    .set_rect(x1, y1, x2, y2, style);
+            }
             BorderSelection::Clear => {
+                borders.diagonal_up.get_or_insert_default().set_rect(
+                    x1,
+                    y1,
+                    x2,
+                    y2,
+                    Some(ClearOption::Clear),
+                );
+                borders.diagonal_down.get_or_insert_default().set_rect(
+                    x1,
+                    y1,
+                    x2,
+                    y2,
+                    Some(ClearOption::Clear),
+                );
                 borders.top.get_or_insert_default().set_rect(
                     x1,
                     y1,
@@ -0,0 +1,323 @@
+//! Operations for named cell styles and the workbook theme.
+//!
+//! A style's attributes are written to the cells it is applied to. When the
+//! definition of a style (or a theme color it uses) changes, the changed
+//! attributes are written to every cell that references the style. Style
+//! borders are only applied to sheet cells (not to cells within tables).
+
+use anyhow::{Result, bail};
+
+use super::operation::Operation;
+use crate::{
+    ClearOption,
+    a1::{A1Selection, CellRefRange, RefRangeBounds, UNBOUNDED},
+    controller::GridController,
+    grid::{
+        SheetFormatting,
+        formats::{CellStyle, FormatUpdate, ResolvedCellStyleBorders, SheetFormatUpdates, Theme},
+        sheet::borders::{BorderStyle, BordersUpdates},
+    },
+};
+
+/// Changes to the borders of cells with a style. A side of `None` is not
+/// changed.
+#[derive(Default)]
+struct CellStyleBordersUpdate {
+    top: Option<ClearOption<BorderStyle>>,
+    bottom: Option<ClearOption<BorderStyle>>,
+    left: Option<ClearOption<BorderStyle>>,
+    right: Option<ClearOption<BorderStyle>>,
+}
+
+impl CellStyleBordersUpdate {
+    /// Sets the borders of the style.
+    fn apply(borders: &ResolvedCellStyleBorders) -> Self {
+        Self {
+            top: borders.top.map(ClearOption::Some),
+            bottom: borders.bottom.map(ClearOption::Some),
+            left: borders.left.map(ClearOption::Some),
+            right: borders.right.map(ClearOption::Some),
+        }
+    }
+
+    /// Changes the borders of `old` into `new`. Removed sides are cleared.
+    fn changed(new: &ResolvedCellStyleBorders, old: &ResolvedCellStyleBorders) -> Self {
+        fn side(
+            new: Option<BorderStyle>,
+            old: Option<BorderStyle>,
+        ) -> Option<ClearOption<BorderStyle>> {
+            (new != old).then(|| new.map_or(ClearOption::Clear, ClearOption::Some))
+        }
+        Self {
+            top: side(new.top, old.top),
+            bottom: side(new.bottom, old.bottom),
+            left: side(new.left, old.left),
+            right: side(new.right, old.right),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.top.is_none() && self.bottom.is_none() && self.left.is_none() && self.right.is_none()
+    }
+
+    /// Sets the borders of every cell in the range.
+    fn set_range(&self, range: &RefRangeBounds, borders: &mut BordersUpdates) {
+        let (x1, y1, x2, y2) = range.to_contiguous2d_coords();
+        for (side, update) in [
+            (&self.top, &mut borders.top),
+            (&self.bottom, &mut borders.bottom),
+            (&self.left, &mut borders.left),
+            (&self.right, &mut borders.right),
+        ] {
+            if let Some(side) = side {
+                let value = match side {
+                    ClearOption::Some(style) => ClearOption::Some((*style).into()),
+                    ClearOption::Clear => ClearOption::Clear,
+                };
+                update
+                    .get_or_insert_default()
+                    .set_rect(x1, y1, x2, y2, Some(value));
+            }
+        }
+    }
+}
+
+/// Returns the ranges of the cells that reference the style.
+fn cell_style_ranges(formats: &SheetFormatting, name: &str) -> Vec<CellRefRange> {
+    formats
+        .style
+        .to_rects()
+        .filter(|(_, _, _, _, style)| style == name)
+        .map(|(x1, y1, x2, y2, _)| CellRefRange::Sheet {
+            range: RefRangeBounds::new_relative(
+                x1,
+                y1,
+                x2.unwrap_or(UNBOUNDED),
+                y2.unwrap_or(UNBOUNDED),
+            ),
+        })
+        .collect()
+}
+
+impl GridController {
+    /// Returns the named cell style.
+    fn cell_style(&self, name: &str) -> Option<&CellStyle> {
+        self.grid
+            .cell_styles
+            .iter()
+            .find(|cell_style| cell_style.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Creates the operations to apply a named cell style to a selection. If
+    /// `name` is None, the cells no longer reference a style but keep its
+    /// attributes.
+    pub fn apply_cell_style_operations(
+        &self,
+        selection: &A1Selection,
+        name: Option<&str>,
+    ) -> Result<Vec<Operation>> {
+        let Some(name) = name else {
+            return Ok(self.format_ops(
+                selection,
+                FormatUpdate {
+                    style: Some(None),
+                    ..Default::default()
+                },
+            ));
+        };
+        let Some(cell_style) = self.cell_style(name) else {
+            bail!("Cell style {name} does not exist");
+        };
+
+        let mut ops = self.format_ops(selection, cell_style.apply_update(&self.grid.theme));
+
+        let borders_update =
+            CellStyleBordersUpdate::apply(&cell_style.resolved_borders(&self.grid.theme));
+        if !borders_update.is_empty() {
+            let mut borders = BordersUpdates::default();
+            for range in selection.ranges.iter() {
+                if let CellRefRange::Sheet { range } = range {
+                    borders_update.set_range(range, &mut borders);
+                }
+            }
+            if !borders.is_empty() {
+                ops.push(Operation::SetBordersA1 {
+                    sheet_id: selection.sheet_id,
+                    borders,
+                });
+            }
+        }
+        Ok(ops)
+    }
+
+    /// Creates the operations that update every cell that references a
+    /// style.
+    fn cell_style_references_operations(
+        &self,
+        name: &str,
+        update: FormatUpdate,
+        borders_update: CellStyleBordersUpdate,
+    ) -> Vec<Operation> {
+        let mut ops = vec![];
+        if update.is_default() && borders_update.is_empty() {
+            return ops;
+        }
+        let context = self.a1_context();
+        for sheet in self.grid.sheets.iter() {
+            let ranges = cell_style_ranges(&sheet.formats, name);
+            if let Some(selection) = A1Selection::from_ranges(ranges, sheet.id, context) {
+                if !update.is_default() {
+                    ops.push(Operation::SetCellFormatsA1 {
+                        sheet_id: sheet.id,
+                        formats: SheetFormatUpdates::from_selection(&selection, update.clone()),
+                    });
+                }
+                if !borders_update.is_empty() {
+                    let mut borders = BordersUpdates::default();
+                    for range in selection.ranges.iter() {
+                        if let CellRefRange::Sheet { range } = range {
+                            borders_update.set_range(range, &mut borders);
+                        }
+                    }
+                    ops.push(Operation::SetBordersA1 {
+                        sheet_id: sheet.id,
+                        borders,
+                    });
+                }
+            }
+
+            if update.is_default() {
+                continue;
+            }
+            for (pos, data_table) in sheet.data_tables.iter() {
+                let ranges = cell_style_ranges(&data_table.formats, name);
+                if let Some(selection) = A1Selection::from_ranges(ranges, sheet.id, context) {
+                    ops.push(Operation::DataTableFormats {
+                        sheet_pos: pos.to_sheet_pos(sheet.id),
+                        formats: SheetFormatUpdates::from_selection(&selection, update.clone()),
+                    });
+                }
+            }
+        }
+        ops
+    }
+
+    /// Creates the operations to add or change a named cell style. If
+    /// `original_name` is set, the style with that name is replaced (and
+    /// possibly renamed), and every cell that references it is updated.
+    pub fn update_cell_style_operations(
+        &self,
+        original_name: Option<&str>,
+        mut cell_style: CellStyle,
+    ) -> Result<Vec<Operation>> {
+        cell_style.name = cell_style.name.trim().to_string();
+        cell_style.format.style = None;
+        if cell_style.name.is_empty() {
+            bail!("A cell style must have a name");
+        }
+
+        let original = match original_name {
+            Some(original_name) => match self.cell_style(original_name) {
+                Some(original) => Some(original),
+                None => bail!("Cell style {original_name} does not exist"),
+            },
+            None => None,
+        };
+        if self.grid.cell_styles.iter().any(|existing| {
+            existing.name.eq_ignore_ascii_case(&cell_style.name)
+                && original.is_none_or(|original| original.name != existing.name)
+        }) {
+            bail!("A cell style named {} already exists", cell_style.name);
+        }
+
+        let mut cell_styles = self.grid.cell_styles.clone();
+        let mut ops = vec![];
+        match original {
+            Some(original) => {
+                let theme = &self.grid.theme;
+                let update = cell_style
+                    .resolved_format(theme)
+                    .changed_from(&original.resolved_format(theme));
+                let borders_update = CellStyleBordersUpdate::changed(
+                    &cell_style.resolved_borders(theme),
+                    &original.resolved_borders(theme),
+                );
+                ops.extend(self.cell_style_references_operations(
+                    &original.name,
+                    update,
+                    borders_update,
+                ));
+                if let Some(existing) = cell_styles
+                    .iter_mut()
+                    .find(|existing| existing.name == original.name)
+                {
+                    *existing = cell_style;
+                }
+            }
+            None => cell_styles.push(cell_style),
+        }
+        ops.insert(0, Operation::SetCellStyles { cell_styles });
+        Ok(ops)
+    }
+
+    /// Creates the operations to delete a named cell style. Cells that
+    /// reference the style keep its attributes.
+    pub fn delete_cell_style_operations(&self, name: &str) -> Result<Vec<Operation>> {
+        let Some(cell_style) = self.cell_style(name) else {
+            bail!("Cell style {name} does not exist");
+        };
+        let mut ops = vec![Operation::SetCellStyles {
+            cell_styles: self
+                .grid
+                .cell_styles
+                .iter()
+                .filter(|existing| existing.name != cell_style.name)
+                .cloned()
+                .collect(),
+        }];
+        ops.extend(self.cell_style_references_operations(
+            &cell_style.name,
+            FormatUpdate {
+                style: Some(None),
+                ..Default::default()
+            },
+            CellStyleBordersUpdate::default(),
+        ));
+        Ok(ops)
+    }
+
+    /// Creates the operations to replace the workbook's theme. Cells that
+    /// reference a style that uses a changed theme color are updated.
+    pub fn set_theme_operations(&self, theme: Theme) -> Result<Vec<Operation>> {
+        for (i, color) in theme.colors.iter().enumerate() {
+            if color.name.trim().is_empty() {
+                bail!("A theme color must have a name");
+            }
+            if theme.colors[..i]
+                .iter()
+                .any(|other| other.name.eq_ignore_ascii_case(&color.name))
+            {
+                bail!("A theme cannot repeat a color name");
+            }
+        }
+
+        let old_theme = &self.grid.theme;
+        let mut ops = vec![];
+        for cell_style in self.grid.cell_styles.iter() {
+            let update = cell_style
+                .resolved_format(&theme)
+                .changed_from(&cell_style.resolved_format(old_theme));
+            let borders_update = CellStyleBordersUpdate::changed(
+                &cell_style.resolved_borders(&theme),
+                &cell_style.resolved_borders(old_theme),
+            );
+            ops.extend(self.cell_style_references_operations(
+                &cell_style.name,
+                update,
+                borders_update,
+            ));
+        }
+        ops.insert(0, Operation::SetTheme { theme });
+        Ok(ops)
+    }
+}
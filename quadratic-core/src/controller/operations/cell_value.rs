@@ -6,19 +6,19 @@ use crate::controller::GridController;
 use crate::grid::formats::{FormatUpdate, SheetFormatUpdates};
 use crate::grid::sheet::validations::validation::Validation;
 use crate::grid::{CodeCellLanguage, DataTableKind};
-use crate::{CellValue, SheetPos, a1::A1Selection};
+use crate::{CellValue, RichText, SheetPos, a1::A1Selection};
 use crate::{Pos, Rect};
 use anyhow::{Error, Result, bail};
 
 impl GridController {
-    /// Convert string to a cell_value and generate necessary operations
-    /// TODO(ddimaria): remove this and reference CellValue::string_to_cell_value directly
+    /// Convert string to a cell_value using the file's locale and generate
+    /// necessary operations
     pub(super) fn string_to_cell_value(
         &self,
         value: &str,
         allow_code: bool,
     ) -> (CellValue, FormatUpdate) {
-        CellValue::string_to_cell_value(value, allow_code)
+        CellValue::string_to_cell_value_with_locale(value, allow_code, &self.grid.locale)
     }
 
     /// Generate operations for a user-initiated change to a cell value
@@ -63,7 +63,7 @@ impl GridController {
             for (y, row) in values.into_iter().enumerate() {
                 for (x, value) in row.into_iter().enumerate() {
                     let value = value.trim().to_string();
-                    let (cell_value, format_update) = CellValue::string_to_cell_value(&value, true);
+                    let (cell_value, format_update) = self.string_to_cell_value(&value, true);
 
                     let pos = Pos::new(sheet_pos.x + x as i64, sheet_pos.y + y as i64);
                     let current_sheet_pos = SheetPos::from((pos, sheet_pos.sheet_id));
@@ -225,6 +225,38 @@ impl GridController {
         Ok((ops, data_table_ops))
     }
 
+    /// Generate operations for a user-initiated change to a cell's rich text
+    /// (eg, from the inline editor). Rich text without styles, or whose plain
+    /// text is not text (eg, a number or a formula), is set as the user's
+    /// String input.
+    pub fn set_cell_rich_text_operations(
+        &mut self,
+        sheet_pos: SheetPos,
+        rich_text: RichText,
+    ) -> Result<(Vec<Operation>, Vec<Operation>)> {
+        let plain_text = rich_text.to_plain();
+        let is_text = matches!(
+            self.string_to_cell_value(plain_text.trim(), true).0,
+            CellValue::Text(_)
+        );
+        let (mut ops, data_table_ops) =
+            self.set_cell_values_operations(sheet_pos, vec![vec![plain_text]])?;
+
+        // the plain text decides whether the cell is within (or expands) a
+        // data table; the rich text is then set in its place
+        if is_text && !rich_text.is_plain() {
+            for op in ops.iter_mut() {
+                if let Operation::SetCellValues { values, .. }
+                | Operation::SetDataTableAt { values, .. } = op
+                {
+                    *values = CellValue::RichText(rich_text.clone()).into();
+                }
+            }
+        }
+
+        Ok((ops, data_table_ops))
+    }
+
     /// Generates and returns the set of operations to delete the values and code in a Selection
     /// Does not commit the operations or create a transaction.
     ///
@@ -17,9 +17,13 @@ use crate::grid::formats::FormatUpdate;
 use crate::grid::formats::SheetFormatUpdates;
 use crate::grid::js_types::JsClipboard;
 use crate::grid::sheet::borders::BordersUpdates;
+use crate::grid::sheet::comments::CommentThread;
 use crate::grid::sheet::validations::validation::Validation;
 use crate::grid::unique_data_table_name;
-use crate::{CellValue, Pos, Rect, RefAdjust, RefError, SheetPos, SheetRect, a1::A1Selection};
+use crate::{
+    CellValue, Hyperlink, Pos, Rect, RefAdjust, RefError, RichText, SheetPos, SheetRect, TextSpan,
+    a1::A1Selection,
+};
 
 // todo: this probably belongs in sheet and not controller
 
@@ -102,6 +106,15 @@ pub struct Clipboard {
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub validations: Option<ClipboardValidations>,
 
+    // merged cells fully within the copied rect
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub merge_cells: Vec<Rect>,
+
+    // comment threads within the selection (only when cut, so they move
+    // with their cells)
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub comments: Vec<(Pos, CommentThread)>,
+
     #[serde(with = "crate::util::indexmap_serde")]
     pub data_tables: IndexMap<Pos, DataTable>,
 
@@ -133,6 +146,30 @@ impl Clipboard {
             }
         }
     }
+
+    /// Decode the table of html copied from another spreadsheet (eg, Google
+    /// Sheets) into rows of rich text. Returns None if there is no table or
+    /// if none of its cells has styled text (the plain text is pasted
+    /// instead).
+    pub fn decode_rich_text_table(html: &str) -> Option<Vec<Vec<RichText>>> {
+        let row_regex = Regex::new(r"(?is)<tr\b[^>]*>(.*?)</tr>").ok()?;
+        let cell_regex = Regex::new(r"(?is)<t[dh]\b[^>]*>(.*?)</t[dh]>").ok()?;
+
+        let rows = row_regex
+            .captures_iter(html)
+            .map(|row| {
+                cell_regex
+                    .captures_iter(&row[1])
+                    .map(|cell| RichText::from_html(&cell[1]))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        rows.iter()
+            .flatten()
+            .any(|cell| !cell.is_plain())
+            .then_some(rows)
+    }
 }
 
 impl GridController {
@@ -152,7 +189,20 @@ impl GridController {
             include_plain_text,
         )?;
 
-        let operations = self.delete_values_and_formatting_operations(selection, true);
+        let comments = sheet
+            .comments
+            .threads()
+            .filter(|(pos, _)| selection.contains_pos(*pos, self.a1_context()))
+            .map(|(pos, _)| (pos, None))
+            .collect::<Vec<_>>();
+
+        let mut operations = self.delete_values_and_formatting_operations(selection, true);
+        if !comments.is_empty() {
+            operations.push(Operation::SetCommentThreads {
+                sheet_id: selection.sheet_id,
+                threads: comments,
+            });
+        }
 
         Ok((operations, js_clipboard))
     }
@@ -511,6 +561,37 @@ impl GridController {
             if !borders.is_empty() {
                 borders.translate_in_place(contiguous_2d_translate_x, contiguous_2d_translate_y);
             }
+
+            if !clipboard.merge_cells.is_empty() {
+                ops.push(Operation::MergeCells {
+                    sheet_id: selection.sheet_id,
+                    rects: clipboard
+                        .merge_cells
+                        .iter()
+                        .map(|rect| {
+                            rect.translate(contiguous_2d_translate_x, contiguous_2d_translate_y)
+                        })
+                        .collect(),
+                });
+            }
+        }
+
+        if matches!(special, PasteSpecial::None) && !clipboard.comments.is_empty() {
+            let translate_x = start_pos.x - clipboard.origin.x;
+            let translate_y = start_pos.y - clipboard.origin.y;
+            ops.push(Operation::SetCommentThreads {
+                sheet_id: selection.sheet_id,
+                threads: clipboard
+                    .comments
+                    .iter()
+                    .map(|(pos, thread)| {
+                        (
+                            pos.translate(translate_x, translate_y, 1, 1),
+                            Some(thread.clone()),
+                        )
+                    })
+                    .collect(),
+            });
         }
 
         Ok(ops)
@@ -525,21 +606,38 @@ impl GridController {
         plain_text: String,
         special: PasteSpecial,
     ) -> Result<Vec<Operation>> {
-        // nothing to paste from plain text for formats
+        let rows = plain_text
+            .split('\n')
+            .map(|line| {
+                line.split('\t')
+                    .map(|value| RichText::new([TextSpan::new(value)]))
+                    .collect()
+            })
+            .collect();
+        self.paste_text_rows_operations(start_pos, end_pos, selection, rows, special)
+    }
+
+    /// Collect the operations to paste rows of text (plain text or rich text
+    /// copied from another spreadsheet).
+    fn paste_text_rows_operations(
+        &mut self,
+        start_pos: SheetPos,
+        end_pos: Pos,
+        selection: &A1Selection,
+        rows: Vec<Vec<RichText>>,
+        special: PasteSpecial,
+    ) -> Result<Vec<Operation>> {
+        // nothing to paste from text for formats
         if matches!(special, PasteSpecial::Formats) {
             return Ok(vec![]);
         }
 
-        let lines: Vec<&str> = plain_text.split('\n').collect();
         let mut ops = vec![];
         let mut compute_code_ops = vec![];
 
-        // calculate the width by checking the first line (with the assumption that all lines should have the same width)
-        let w = lines
-            .first()
-            .map(|line| line.split('\t').count())
-            .unwrap_or(0);
-        let h = lines.len();
+        // calculate the width by checking the first row (with the assumption that all rows should have the same width)
+        let w = rows.first().map(|row| row.len()).unwrap_or(0);
+        let h = rows.len();
 
         // If the clipboard is larger than the selection, we need to paste multiple times.
         // We don't want the paste to exceed the bounds of the selection (e.g. end_pos).
@@ -551,10 +649,21 @@ impl GridController {
         let mut values = CellValues::new(cell_value_width as u32, cell_value_height as u32);
         let mut sheet_format_updates = SheetFormatUpdates::default();
 
-        // collect the plain text clipboard cells
-        lines.iter().enumerate().for_each(|(y, line)| {
-            line.split('\t').enumerate().for_each(|(x, value)| {
-                let (cell_value, format_update) = self.string_to_cell_value(value, true);
+        // collect the text clipboard cells
+        rows.into_iter().enumerate().for_each(|(y, row)| {
+            row.into_iter().enumerate().for_each(|(x, value)| {
+                let (mut cell_value, format_update) = if value.is_plain() {
+                    self.string_to_cell_value(&value.to_plain(), true)
+                } else {
+                    (CellValue::RichText(value), FormatUpdate::default())
+                };
+
+                // pasted URLs become hyperlinks
+                if let CellValue::Text(text) = &cell_value
+                    && let Some(link) = Hyperlink::detect(text)
+                {
+                    cell_value = CellValue::Hyperlink(Box::new(link));
+                }
                 let is_code = matches!(cell_value, CellValue::Code(_));
 
                 if cell_value != CellValue::Blank {
@@ -601,8 +710,7 @@ impl GridController {
         });
 
         if !sheet_format_updates.is_default() {
-            let formats_rect =
-                Rect::from_numbers(start_pos.x, start_pos.y, w as i64, lines.len() as i64);
+            let formats_rect = Rect::from_numbers(start_pos.x, start_pos.y, w as i64, h as i64);
 
             ops.extend(self.clipboard_formats_operations(
                 start_pos.sheet_id,
@@ -636,7 +744,15 @@ impl GridController {
     ) -> Result<Vec<Operation>> {
         let mut ops = vec![];
         let mut compute_code_ops = vec![];
-        let mut clipboard = Clipboard::decode(&html)?;
+        let mut clipboard = match Clipboard::decode(&html) {
+            Ok(clipboard) => clipboard,
+            Err(e) => {
+                let rows = Clipboard::decode_rich_text_table(&html).ok_or(e)?;
+                let start_pos = insert_at.to_sheet_pos(selection.sheet_id);
+                return self
+                    .paste_text_rows_operations(start_pos, end_pos, selection, rows, special);
+            }
+        };
 
         // If the clipboard is larger than the selection, we need to paste multiple times.
         // We don't want the paste to exceed the bounds of the selection (e.g. end_pos).
@@ -1840,4 +1956,102 @@ mod test {
         // paste under the chart and cause a spill)
         assert_table_count(&gc, sheet_id, 1);
     }
+
+    #[test]
+    fn paste_hyperlinks() {
+        let mut gc = GridController::test();
+        let sheet_id = gc.sheet_ids()[0];
+
+        // pasted URLs become hyperlinks
+        gc.paste_from_clipboard(
+            &A1Selection::test_a1("A1"),
+            Some("https://example.com\tnot a link\nwww.example.com\t".to_string()),
+            None,
+            PasteSpecial::None,
+            None,
+        );
+        let link = CellValue::Hyperlink(Box::new(
+            Hyperlink::new("https://example.com", "https://example.com").unwrap(),
+        ));
+        let sheet = gc.sheet(sheet_id);
+        assert_eq!(sheet.cell_value(pos![A1]), Some(link.clone()));
+        assert_eq!(
+            sheet.cell_value(pos![B1]),
+            Some(CellValue::Text("not a link".to_string()))
+        );
+        assert_eq!(
+            sheet.cell_value(pos![A2]),
+            Some(CellValue::Hyperlink(Box::new(
+                Hyperlink::new("https://www.example.com", "www.example.com",).unwrap()
+            )))
+        );
+
+        // hyperlinks are copied as html anchors and round-trip
+        let JsClipboard { html, .. } = gc
+            .sheet(sheet_id)
+            .copy_to_clipboard(
+                &A1Selection::test_a1("A1"),
+                gc.a1_context(),
+                ClipboardOperation::Copy,
+                false,
+            )
+            .unwrap();
+        assert!(html.contains("<a href=\"https://example.com\">https://example.com</a>"));
+        paste(&mut gc, sheet_id, 4, 4, html);
+        assert_eq!(gc.sheet(sheet_id).cell_value(pos![D4]), Some(link));
+    }
+
+    #[test]
+    fn paste_rich_text() {
+        let mut gc = GridController::test();
+        let sheet_id = gc.sheet_ids()[0];
+
+        // html from another spreadsheet with partially styled text
+        let html = r#"<google-sheets-html-origin><table><tbody><tr><td><span style="font-weight:bold;">Total:</span><span> 42</span></td><td>12</td></tr><tr><td style="font-weight:bold;">all bold</td><td></td></tr></tbody></table>"#;
+        gc.paste_from_clipboard(
+            &A1Selection::test_a1("A1"),
+            Some("Total: 42\t12\nall bold\t".to_string()),
+            Some(html.to_string()),
+            PasteSpecial::None,
+            None,
+        );
+        let rich_text = CellValue::RichText(RichText::new([
+            TextSpan {
+                bold: Some(true),
+                ..TextSpan::new("Total:")
+            },
+            TextSpan::new(" 42"),
+        ]));
+        let sheet = gc.sheet(sheet_id);
+        assert_eq!(sheet.cell_value(pos![A1]), Some(rich_text.clone()));
+        assert_eq!(
+            sheet.cell_value(pos![B1]),
+            Some(CellValue::Number(12.into()))
+        );
+        assert_eq!(
+            sheet.cell_value(pos![A2]),
+            Some(CellValue::Text("all bold".to_string()))
+        );
+
+        // html without styled text is pasted as plain text
+        assert_eq!(
+            Clipboard::decode_rich_text_table("<table><tr><td><span>a</span></td></tr></table>"),
+            None
+        );
+
+        // rich text round-trips through the clipboard
+        let JsClipboard { plain_text, html } = gc
+            .sheet(sheet_id)
+            .copy_to_clipboard(
+                &A1Selection::test_a1("A1"),
+                gc.a1_context(),
+                ClipboardOperation::Copy,
+                true,
+            )
+            .unwrap();
+        assert_eq!(plain_text, "Total: 42");
+        assert!(html.contains("<span style=\"font-weight:bold;\">Total:</span> 42"));
+        paste(&mut gc, sheet_id, 4, 4, html);
+        assert_eq!(gc.sheet(sheet_id).cell_value(pos![D4]), Some(rich_text));
+    }
 }
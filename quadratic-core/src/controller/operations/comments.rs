@@ -0,0 +1,71 @@
+use super::operation::Operation;
+use crate::{SheetPos, controller::GridController, grid::sheet::comments::Comment};
+
+use anyhow::{Result, bail};
+use uuid::Uuid;
+
+impl GridController {
+    /// Creates the operation to add a comment to a cell. The comment starts a
+    /// new thread or is added as a reply to the existing thread.
+    pub fn add_comment_operations(
+        &self,
+        sheet_pos: SheetPos,
+        author: String,
+        text: String,
+    ) -> Result<Vec<Operation>> {
+        self.try_sheet_result(sheet_pos.sheet_id)?;
+        let text = text.trim();
+        if text.is_empty() {
+            bail!("Comment cannot be empty");
+        }
+
+        Ok(vec![Operation::AddComment {
+            sheet_pos,
+            comment: Comment::new(author, text.to_string()),
+        }])
+    }
+
+    /// Creates the operation to delete a comment.
+    pub fn delete_comment_operations(
+        &self,
+        sheet_pos: SheetPos,
+        comment_id: Uuid,
+    ) -> Result<Vec<Operation>> {
+        let sheet = self.try_sheet_result(sheet_pos.sheet_id)?;
+        let Some(thread) = sheet.comments.get(sheet_pos.into()) else {
+            bail!("Comment thread not found");
+        };
+        if !thread
+            .comments
+            .iter()
+            .any(|comment| comment.id == comment_id)
+        {
+            bail!("Comment not found");
+        }
+
+        Ok(vec![Operation::DeleteComment {
+            sheet_pos,
+            comment_id,
+        }])
+    }
+
+    /// Creates the operation to resolve (or reopen) a comment thread.
+    pub fn set_comment_resolved_operations(
+        &self,
+        sheet_pos: SheetPos,
+        resolved: bool,
+    ) -> Result<Vec<Operation>> {
+        let sheet = self.try_sheet_result(sheet_pos.sheet_id)?;
+        let Some(thread) = sheet.comments.get(sheet_pos.into()) else {
+            bail!("Comment thread not found");
+        };
+        if thread.resolved == resolved {
+            return Ok(vec![]);
+        }
+
+        Ok(vec![Operation::SetCommentResolved {
+            sheet_pos,
+            resolved,
+        }])
+    }
+}
@@ -0,0 +1,39 @@
+use super::operation::Operation;
+use crate::controller::GridController;
+
+use anyhow::{Result, bail};
+
+impl GridController {
+    /// Creates the operation to replace the workbook's custom lists. Values
+    /// are trimmed and empty values are removed. Each list must have at least
+    /// two values and cannot repeat a value.
+    pub fn set_custom_lists_operations(
+        &self,
+        custom_lists: Vec<Vec<String>>,
+    ) -> Result<Vec<Operation>> {
+        let mut cleaned = vec![];
+        for list in custom_lists {
+            let list = list
+                .iter()
+                .map(|value| value.trim())
+                .filter(|value| !value.is_empty())
+                .map(String::from)
+                .collect::<Vec<_>>();
+            if list.len() < 2 {
+                bail!("A custom list must have at least two values");
+            }
+            if list
+                .iter()
+                .enumerate()
+                .any(|(i, value)| list[..i].contains(value))
+            {
+                bail!("A custom list cannot repeat a value");
+            }
+            cleaned.push(list);
+        }
+
+        Ok(vec![Operation::SetCustomLists {
+            custom_lists: cleaned,
+        }])
+    }
+}
@@ -0,0 +1,300 @@
+use indexmap::IndexMap;
+
+use super::operation::Operation;
+use crate::{
+    CellValue, Pos, Rect,
+    a1::A1Selection,
+    cell_values::CellValues,
+    controller::GridController,
+    grid::{
+        Sheet,
+        formats::{Format, FormatUpdate, SheetFormatUpdates},
+        sheet::data_cleaning::{
+            DataCleaningAction, DataCleaningSummary, DuplicateKeep, duplicate_rows,
+        },
+    },
+};
+
+use anyhow::{Result, bail};
+
+/// A new value for a cell. `data_table` is the position of the imported data
+/// table that contains the cell, if any.
+struct CellChange {
+    pos: Pos,
+    value: CellValue,
+    format: FormatUpdate,
+    data_table: Option<Pos>,
+}
+
+impl GridController {
+    /// Creates the operations to clean the values in a selection. Cells that
+    /// cannot be edited (code, code output, and table headers) are skipped.
+    pub fn data_cleaning_operations(
+        &self,
+        selection: &A1Selection,
+        action: DataCleaningAction,
+    ) -> Result<(Vec<Operation>, DataCleaningSummary)> {
+        let sheet = self.try_sheet_result(selection.sheet_id)?;
+        let mut changes: IndexMap<Pos, CellChange> = IndexMap::new();
+
+        for rect in sheet.selection_to_rects(selection, false, false, self.a1_context()) {
+            if action == DataCleaningAction::FillBlanksDown {
+                for x in rect.x_range() {
+                    let mut last_value = None;
+                    for y in rect.y_range() {
+                        let pos = Pos { x, y };
+                        let Some((value, data_table)) = sheet.data_cleaning_value(pos) else {
+                            last_value = None;
+                            continue;
+                        };
+                        if !value.is_blank_or_empty_string() {
+                            last_value = Some(value);
+                        } else if let Some(last_value) = &last_value {
+                            changes.insert(
+                                pos,
+                                CellChange {
+                                    pos,
+                                    value: last_value.clone(),
+                                    format: FormatUpdate::default(),
+                                    data_table,
+                                },
+                            );
+                        }
+                    }
+                }
+            } else {
+                for pos in rect.iter() {
+                    if changes.contains_key(&pos) {
+                        continue;
+                    }
+                    let Some((value, data_table)) = sheet.data_cleaning_value(pos) else {
+                        continue;
+                    };
+                    if let Some((value, format)) = action.clean_value(&value, &self.grid.locale) {
+                        changes.insert(
+                            pos,
+                            CellChange {
+                                pos,
+                                value,
+                                format,
+                                data_table,
+                            },
+                        );
+                    }
+                }
+            }
+        }
+
+        let summary = DataCleaningSummary {
+            cells_changed: changes.len() as u64,
+            rows_removed: 0,
+        };
+        let ops = cell_changes_operations(sheet, changes.into_values().collect())?;
+
+        Ok((ops, summary))
+    }
+
+    /// Creates the operations to remove duplicate rows from a selection.
+    ///
+    /// `key_columns` are 0-indexed relative to the selection; if None, all
+    /// columns are compared. Rows within an imported data table are deleted
+    /// from the table. Otherwise, the remaining rows are moved up with their
+    /// formats and the rows left at the bottom of the selection are cleared.
+    pub fn remove_duplicates_operations(
+        &self,
+        selection: &A1Selection,
+        key_columns: Option<Vec<u32>>,
+        keep: DuplicateKeep,
+    ) -> Result<(Vec<Operation>, DataCleaningSummary)> {
+        let sheet = self.try_sheet_result(selection.sheet_id)?;
+        let rects = sheet.selection_to_rects(selection, false, false, self.a1_context());
+        let [mut rect] = rects[..] else {
+            bail!("Remove duplicates requires a single range");
+        };
+
+        // data tables only contain data rows after the name and column headers
+        let data_table = sheet
+            .data_table_at(rect.min)
+            .filter(|(_, data_table)| !data_table.is_code());
+        if let Some((data_table_pos, data_table)) = data_table {
+            if !data_table
+                .output_rect(data_table_pos, false)
+                .contains_rect(&rect)
+            {
+                bail!("Remove duplicates range must be within a single table");
+            }
+            rect.min.y = rect
+                .min
+                .y
+                .max(data_table_pos.y + data_table.y_adjustment(false));
+            if rect.min.y > rect.max.y {
+                return Ok((vec![], DataCleaningSummary::default()));
+            }
+        }
+
+        let key_columns: Vec<usize> = match key_columns {
+            Some(columns) => columns.into_iter().map(|c| c as usize).collect(),
+            None => (0..rect.width() as usize).collect(),
+        };
+        if key_columns.is_empty() || key_columns.iter().any(|&c| c >= rect.width() as usize) {
+            bail!("Invalid key columns for remove duplicates");
+        }
+
+        let mut rows = vec![];
+        for y in rect.y_range() {
+            let mut row = vec![];
+            for x in rect.x_range() {
+                let Some((value, in_data_table)) = sheet.data_cleaning_value(Pos { x, y }) else {
+                    bail!("Remove duplicates range cannot contain code");
+                };
+                if in_data_table != data_table.map(|(pos, _)| pos) {
+                    bail!("Remove duplicates range must be within a single table");
+                }
+                row.push(value);
+            }
+            rows.push(row);
+        }
+
+        let duplicates = duplicate_rows(&rows, &key_columns, keep);
+        let mut summary = DataCleaningSummary {
+            cells_changed: 0,
+            rows_removed: duplicates.len() as u64,
+        };
+        if duplicates.is_empty() {
+            return Ok((vec![], summary));
+        }
+
+        if let Some((data_table_pos, _)) = data_table {
+            summary.cells_changed = duplicates.len() as u64 * rect.width() as u64;
+            let rows = duplicates
+                .iter()
+                .map(|&index| (rect.min.y + index as i64 - data_table_pos.y) as u32)
+                .collect();
+            return Ok((
+                vec![Operation::DeleteDataTableRows {
+                    sheet_pos: data_table_pos.to_sheet_pos(sheet.id),
+                    rows,
+                    flatten: false,
+                    select_table: false,
+                }],
+                summary,
+            ));
+        }
+
+        // move the remaining rows up with their formats and clear the rows
+        // left at the bottom
+        let sources = (0..rows.len())
+            .filter(|index| duplicates.binary_search(index).is_err())
+            .map(Some)
+            .chain(std::iter::repeat_n(None, duplicates.len()));
+
+        let mut changes = vec![];
+        for ((old_row, source), y) in rows.iter().zip(sources).zip(rect.y_range()) {
+            for ((old_value, x), column) in old_row.iter().zip(rect.x_range()).zip(0..) {
+                let pos = Pos { x, y };
+                let (value, format) = match source {
+                    Some(source) => (
+                        rows[source][column].clone(),
+                        sheet.formats.format(Pos {
+                            x,
+                            y: rect.min.y + source as i64,
+                        }),
+                    ),
+                    None => (CellValue::Blank, Format::default()),
+                };
+                let format = format.changed_from(&sheet.formats.format(pos));
+                if *old_value != value || !format.is_default() {
+                    changes.push(CellChange {
+                        pos,
+                        value,
+                        format,
+                        data_table: None,
+                    });
+                }
+            }
+        }
+        summary.cells_changed = changes.len() as u64;
+
+        Ok((cell_changes_operations(sheet, changes)?, summary))
+    }
+}
+
+/// Creates the operations to set changed cells. Values within imported data
+/// tables are set with one SetDataTableAt per table. Values on the sheet are
+/// set in vertical runs so cells between changes are not touched.
+fn cell_changes_operations(sheet: &Sheet, changes: Vec<CellChange>) -> Result<Vec<Operation>> {
+    let mut ops = vec![];
+    let mut sheet_changes: Vec<CellChange> = vec![];
+    let mut data_table_changes: IndexMap<Pos, Vec<CellChange>> = IndexMap::new();
+    for change in changes {
+        match change.data_table {
+            Some(data_table_pos) => data_table_changes
+                .entry(data_table_pos)
+                .or_default()
+                .push(change),
+            None => sheet_changes.push(change),
+        }
+    }
+
+    for (data_table_pos, changes) in data_table_changes {
+        let Some(rect) = Rect::from_positions(changes.iter().map(|c| c.pos).collect()) else {
+            continue;
+        };
+        let mut values = CellValues::new(rect.width(), rect.height());
+        let mut format_ops = vec![];
+        for change in changes {
+            if !change.format.is_default() {
+                format_ops.push(Operation::DataTableFormats {
+                    sheet_pos: data_table_pos.to_sheet_pos(sheet.id),
+                    formats: sheet.to_sheet_format_updates(
+                        change.pos.to_sheet_pos(sheet.id),
+                        data_table_pos,
+                        change.format,
+                    )?,
+                });
+            }
+            values.set(
+                (change.pos.x - rect.min.x) as u32,
+                (change.pos.y - rect.min.y) as u32,
+                change.value,
+            );
+        }
+        ops.push(Operation::SetDataTableAt {
+            sheet_pos: rect.min.to_sheet_pos(sheet.id),
+            values,
+        });
+        ops.extend(format_ops);
+    }
+
+    sheet_changes.sort_by_key(|change| (change.pos.x, change.pos.y));
+    let mut sheet_format_updates = SheetFormatUpdates::default();
+    let mut runs: Vec<(Pos, CellValues)> = vec![];
+    for change in sheet_changes {
+        if !change.format.is_default() {
+            sheet_format_updates.set_format_cell(change.pos, change.format);
+        }
+        match runs.last_mut() {
+            Some((start, values))
+                if start.x == change.pos.x && start.y + values.h as i64 == change.pos.y =>
+            {
+                values.set(0, values.h, change.value);
+            }
+            _ => runs.push((change.pos, CellValues::from(change.value))),
+        }
+    }
+    ops.extend(
+        runs.into_iter()
+            .map(|(start, values)| Operation::SetCellValues {
+                sheet_pos: start.to_sheet_pos(sheet.id),
+                values,
+            }),
+    );
+    if !sheet_format_updates.is_default() {
+        ops.push(Operation::SetCellFormatsA1 {
+            sheet_id: sheet.id,
+            formats: sheet_format_updates,
+        });
+    }
+
+    Ok(ops)
+}
@@ -5,7 +5,9 @@ use crate::{
     controller::GridController,
     grid::{
         DataTable, DataTableKind,
-        data_table::{column_header::DataTableColumnHeader, sort::DataTableSort},
+        data_table::{
+            column_header::DataTableColumnHeader, sort::DataTableSort, table_style::TableStyle,
+        },
         formats::SheetFormatUpdates,
         unique_data_table_name,
     },
@@ -89,6 +91,24 @@ impl GridController {
             columns,
             show_name: show_name.map(|show_name| show_name.into()),
             show_columns: show_columns.map(|show_columns| show_columns.into()),
+            table_style: None,
+        }]
+    }
+
+    /// Sets or clears the style of a table.
+    pub fn data_table_style_operations(
+        &self,
+        sheet_pos: SheetPos,
+        table_style: Option<TableStyle>,
+    ) -> Vec<Operation> {
+        vec![Operation::DataTableOptionMeta {
+            sheet_pos,
+            name: None,
+            alternating_colors: None,
+            columns: None,
+            show_name: None,
+            show_columns: None,
+            table_style: Some(table_style.into()),
         }]
     }
 
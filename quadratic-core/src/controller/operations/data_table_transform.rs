@@ -0,0 +1,77 @@
+use super::operation::Operation;
+use crate::{
+    CellValue, SheetPos,
+    cellvalue::Import,
+    controller::GridController,
+    formulas::{Ctx, parse_formula},
+    grid::{
+        CodeCellLanguage, DataTable, DataTableKind, data_table::transform::DataTableTransform,
+        unique_data_table_name,
+    },
+};
+
+use anyhow::{Result, bail};
+
+impl GridController {
+    /// Creates a new data table at `sheet_pos` from a transform of existing
+    /// tables.
+    ///
+    /// If `live` is true, the table is a formula that recomputes whenever its
+    /// source tables change. Otherwise the transform is evaluated now and the
+    /// result is stored as an imported table.
+    pub fn data_table_transform_operations(
+        &self,
+        sheet_pos: SheetPos,
+        transform: DataTableTransform,
+        live: bool,
+        name: Option<String>,
+    ) -> Result<Vec<Operation>> {
+        let a1_context = self.a1_context();
+        for table in transform.source_tables() {
+            if a1_context.try_table(table).is_none() {
+                bail!("Table '{table}' not found");
+            }
+        }
+
+        let name = name.unwrap_or_else(|| transform.default_name());
+        let name = unique_data_table_name(&name, false, Some(sheet_pos), a1_context);
+        let formula = transform.to_formula();
+
+        if live {
+            let mut ops = self.set_code_cell_operations(
+                sheet_pos,
+                CodeCellLanguage::Formula,
+                formula,
+                Some(name),
+            );
+            ops.extend(self.data_table_first_row_as_header_operations(sheet_pos, true));
+            return Ok(ops);
+        }
+
+        let parsed = parse_formula(&formula, a1_context, sheet_pos)?;
+        let mut ctx = Ctx::new(self, sheet_pos);
+        let value = parsed.eval(&mut ctx).into_non_tuple().inner;
+        if let Some(error) = value.errors().into_iter().next() {
+            bail!("Error creating table '{name}': {error}");
+        }
+
+        let import = Import::new(name.to_owned());
+        let data_table = DataTable::new(
+            DataTableKind::Import(import.to_owned()),
+            &name,
+            value,
+            false,
+            true,
+            Some(true),
+            Some(true),
+            None,
+        );
+
+        Ok(vec![Operation::AddDataTable {
+            sheet_pos,
+            data_table,
+            cell_value: CellValue::Import(import),
+            index: None,
+        }])
+    }
+}
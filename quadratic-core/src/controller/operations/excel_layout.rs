@@ -0,0 +1,1320 @@
+//! Reads the parts of an Excel worksheet's layout that calamine does not
+//! expose (eg, hidden columns and rows, outlines, frozen panes, hyperlinks,
+//! rich text, number formats, fonts, tables) directly from the worksheet XML.
+//!
+//! The layout is best effort: anything that cannot be read is ignored so the
+//! values are still imported.
+
+use std::collections::HashMap;
+use std::io::{Cursor, Read, Seek};
+
+use quick_xml::Reader;
+use quick_xml::encoding::Decoder;
+use quick_xml::events::{BytesStart, Event};
+use zip::ZipArchive;
+
+use crate::color::Rgba;
+use crate::date_time::DateSystem;
+use crate::grid::data_table::table_style::TableStyle;
+use crate::grid::formats::{THEME_COLOR_PREFIX, Theme};
+use crate::grid::formatting::VERTICAL_TEXT;
+use crate::grid::sheet::borders::{BorderStyleCell, BorderStyleTimestamp, CellBorderLine};
+use crate::{Hyperlink, Pos, RichText, TextSpan, grid::sheet::outlines::Outline};
+
+/// The fonts, alignment and borders of an Excel cell style that have a
+/// Quadratic format. Values that match the workbook's default font are None.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ExcelCellStyle {
+    pub font_size: Option<i16>,
+    pub font_family: Option<String>,
+    pub text_rotation: Option<i16>,
+    pub indent: Option<u8>,
+    pub borders: ExcelBorders,
+}
+
+impl ExcelCellStyle {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// A side of an Excel cell border. The color is a hex color or a theme color
+/// reference; None is Excel's automatic (black) color.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExcelBorder {
+    pub line: CellBorderLine,
+    pub color: Option<String>,
+}
+
+/// The borders of an Excel cell style.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ExcelBorders {
+    pub left: Option<ExcelBorder>,
+    pub right: Option<ExcelBorder>,
+    pub top: Option<ExcelBorder>,
+    pub bottom: Option<ExcelBorder>,
+    pub diagonal_up: Option<ExcelBorder>,
+    pub diagonal_down: Option<ExcelBorder>,
+}
+
+impl ExcelBorders {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Returns the borders with their colors taken from the theme.
+    pub fn style_cell(&self, theme: &Theme) -> BorderStyleCell {
+        let style = |border: &Option<ExcelBorder>| {
+            let border = border.as_ref()?;
+            let color = border
+                .color
+                .as_ref()
+                .and_then(|color| theme.resolve(color))
+                .and_then(|color| Rgba::color_from_str(&color).ok())
+                .unwrap_or_default();
+            Some(BorderStyleTimestamp::new(color, border.line))
+        };
+        BorderStyleCell {
+            top: style(&self.top),
+            bottom: style(&self.bottom),
+            left: style(&self.left),
+            right: style(&self.right),
+            diagonal_up: style(&self.diagonal_up),
+            diagonal_down: style(&self.diagonal_down),
+        }
+    }
+}
+
+/// An Excel table with its table style options.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExcelTable {
+    pub name: String,
+    pub start: Pos,
+    pub end: Pos,
+    pub header_row: bool,
+    pub totals_row: bool,
+
+    /// The name of the table style (eg, `TableStyleMedium2`).
+    pub style_name: Option<String>,
+    pub show_first_column: bool,
+    pub show_last_column: bool,
+    pub show_row_stripes: bool,
+    pub show_column_stripes: bool,
+}
+
+impl ExcelTable {
+    /// Returns the table's style. Returns None if the table has no style or
+    /// uses a custom style (which is not imported).
+    pub fn table_style(&self, theme: &Theme) -> Option<TableStyle> {
+        let mut table_style = TableStyle::preset(self.style_name.as_deref()?, theme)?;
+        table_style.banded_rows = self.show_row_stripes;
+        table_style.banded_columns = self.show_column_stripes;
+        table_style.show_first_column = self.show_first_column;
+        table_style.show_last_column = self.show_last_column;
+        table_style.show_totals_row = self.totals_row;
+        Some(table_style)
+    }
+}
+
+/// The worksheet XML of an Excel file, by sheet name.
+#[derive(Debug, Default)]
+pub struct ExcelLayout {
+    worksheets: HashMap<String, String>,
+
+    /// The targets of each worksheet's relationships (eg, hyperlink URLs),
+    /// by sheet name and relationship id.
+    worksheet_targets: HashMap<String, HashMap<String, String>>,
+
+    /// The shared strings that have styled runs, by shared string index.
+    rich_text_strings: HashMap<usize, RichText>,
+
+    /// The number format code of each cell style, by style index. General
+    /// formats are None.
+    cell_number_formats: Vec<Option<String>>,
+
+    /// The fonts and alignment of each cell style, by style index.
+    cell_styles: Vec<ExcelCellStyle>,
+
+    /// The tables of each worksheet, by sheet name.
+    tables: HashMap<String, Vec<ExcelTable>>,
+
+    /// Whether the workbook uses the 1904 date system (eg, older Mac
+    /// workbooks).
+    date_1904: bool,
+}
+
+impl ExcelLayout {
+    /// Reads the worksheet XML of an Excel file. Returns an empty layout if
+    /// the file cannot be read.
+    pub fn new(file: &[u8]) -> Self {
+        Self::read(file).unwrap_or_default()
+    }
+
+    fn read(file: &[u8]) -> Option<Self> {
+        let mut archive = ZipArchive::new(Cursor::new(file)).ok()?;
+        let workbook = read_file(&mut archive, "xl/workbook.xml")?;
+        let relationships = read_file(&mut archive, "xl/_rels/workbook.xml.rels")?;
+
+        let targets = relationship_targets(&relationships);
+
+        let mut worksheets = HashMap::new();
+        let mut worksheet_targets = HashMap::new();
+        let mut tables = HashMap::new();
+        for sheet in elements(&workbook, "sheet") {
+            let (Some(name), Some(id)) = (sheet.attribute("name"), sheet.attribute("r:id")) else {
+                continue;
+            };
+            let Some(target) = targets.get(id) else {
+                continue;
+            };
+            let path = target_path("xl", target);
+            let Some(xml) = read_file(&mut archive, &path) else {
+                continue;
+            };
+
+            // the relationships of xl/worksheets/sheet1.xml are in
+            // xl/worksheets/_rels/sheet1.xml.rels
+            let (dir, file) = path.rsplit_once('/').unwrap_or(("", &path));
+            if let Some(rels) = read_file(&mut archive, &format!("{dir}/_rels/{file}.rels")) {
+                let targets = relationship_targets(&rels);
+
+                // tables are in separate files (eg, xl/tables/table1.xml)
+                let sheet_tables = elements(&xml, "tablePart")
+                    .filter_map(|table_part| {
+                        let target = targets.get(table_part.attribute("r:id")?)?;
+                        excel_table(&read_file(&mut archive, &target_path(dir, target))?)
+                    })
+                    .collect::<Vec<_>>();
+                if !sheet_tables.is_empty() {
+                    tables.insert(name.to_string(), sheet_tables);
+                }
+
+                worksheet_targets.insert(name.to_string(), targets);
+            }
+            worksheets.insert(name.to_string(), xml);
+        }
+
+        let rich_text_strings = read_file(&mut archive, "xl/sharedStrings.xml")
+            .map(|xml| rich_text_strings(&xml))
+            .unwrap_or_default();
+
+        let styles = read_file(&mut archive, "xl/styles.xml").unwrap_or_default();
+        let cell_number_formats = cell_number_formats(&styles);
+        let cell_styles = cell_styles(&styles);
+
+        let date_1904 = elements(&workbook, "workbookPr")
+            .next()
+            .is_some_and(|properties| is_true(properties.attribute("date1904")));
+
+        Some(Self {
+            worksheets,
+            worksheet_targets,
+            rich_text_strings,
+            cell_number_formats,
+            cell_styles,
+            tables,
+            date_1904,
+        })
+    }
+
+    /// Returns the date system that the workbook's serial dates use.
+    pub fn date_system(&self) -> DateSystem {
+        if self.date_1904 {
+            DateSystem::Excel1904
+        } else {
+            DateSystem::Excel1900
+        }
+    }
+
+    /// Returns the hidden columns of a sheet (1-indexed).
+    pub fn hidden_columns(&self, sheet_name: &str) -> Vec<i64> {
+        let Some(xml) = self.worksheets.get(sheet_name) else {
+            return vec![];
+        };
+        elements(xml, "col")
+            .filter(|col| is_true(col.attribute("hidden")))
+            .filter_map(|col| {
+                let min = col.attribute("min")?.parse::<i64>().ok()?;
+                let max = col.attribute("max")?.parse::<i64>().ok()?;
+                Some(min..=max)
+            })
+            .flatten()
+            .collect()
+    }
+
+    /// Returns the hidden rows of a sheet (1-indexed).
+    pub fn hidden_rows(&self, sheet_name: &str) -> Vec<i64> {
+        let Some(xml) = self.worksheets.get(sheet_name) else {
+            return vec![];
+        };
+        elements(xml, "row")
+            .filter(|row| is_true(row.attribute("hidden")))
+            .filter_map(|row| row.attribute("r")?.parse::<i64>().ok())
+            .collect()
+    }
+
+    /// Returns the column outline (groups) of a sheet.
+    pub fn column_outline(&self, sheet_name: &str) -> Outline {
+        let Some(xml) = self.worksheets.get(sheet_name) else {
+            return Outline::default();
+        };
+        let mut levels = vec![];
+        let mut summaries = vec![];
+        for col in elements(xml, "col") {
+            let (Some(min), Some(max)) = (
+                col.attribute("min").and_then(|min| min.parse::<i64>().ok()),
+                col.attribute("max").and_then(|max| max.parse::<i64>().ok()),
+            ) else {
+                continue;
+            };
+            let level = outline_level(&col);
+            let collapsed = is_true(col.attribute("collapsed"));
+            for column in min..=max {
+                if level > 0 {
+                    levels.push((column, level));
+                }
+                if collapsed {
+                    summaries.push(column);
+                }
+            }
+        }
+        outline(levels, summaries)
+    }
+
+    /// Returns the row outline (groups) of a sheet.
+    pub fn row_outline(&self, sheet_name: &str) -> Outline {
+        let Some(xml) = self.worksheets.get(sheet_name) else {
+            return Outline::default();
+        };
+        let mut levels = vec![];
+        let mut summaries = vec![];
+        for element in elements(xml, "row") {
+            let Some(row) = element
+                .attribute("r")
+                .and_then(|row| row.parse::<i64>().ok())
+            else {
+                continue;
+            };
+            let level = outline_level(&element);
+            if level > 0 {
+                levels.push((row, level));
+            }
+            if is_true(element.attribute("collapsed")) {
+                summaries.push(row);
+            }
+        }
+        outline(levels, summaries)
+    }
+
+    /// Returns the number of frozen columns and rows of a sheet. Panes that
+    /// are split but not frozen are ignored.
+    pub fn frozen_panes(&self, sheet_name: &str) -> (u32, u32) {
+        let Some(pane) = self
+            .worksheets
+            .get(sheet_name)
+            .and_then(|xml| elements(xml, "pane").next())
+        else {
+            return (0, 0);
+        };
+        if !matches!(pane.attribute("state"), Some("frozen" | "frozenSplit")) {
+            return (0, 0);
+        }
+        let split = |name| {
+            pane.attribute(name)
+                .and_then(|split| split.parse::<f64>().ok())
+                .map_or(0, |split| split.max(0.0) as u32)
+        };
+        (split("xSplit"), split("ySplit"))
+    }
+
+    /// Returns the hyperlinks of a sheet with the cell range they apply to
+    /// (usually a single cell) and their display text (if set). Internal
+    /// links (`location`) are returned as `#` followed by the reference.
+    pub fn hyperlinks(&self, sheet_name: &str) -> Vec<(Pos, Pos, Hyperlink)> {
+        let Some(xml) = self.worksheets.get(sheet_name) else {
+            return vec![];
+        };
+        let targets = self.worksheet_targets.get(sheet_name);
+        elements(xml, "hyperlink")
+            .filter_map(|hyperlink| {
+                let range = hyperlink.attribute("ref")?;
+                let (start, end) = match range.split_once(':') {
+                    Some((start, end)) => (Pos::try_a1_string(start)?, Pos::try_a1_string(end)?),
+                    None => {
+                        let pos = Pos::try_a1_string(range)?;
+                        (pos, pos)
+                    }
+                };
+                let external = hyperlink.attribute("r:id").and_then(|id| targets?.get(id));
+                let url = match (external, hyperlink.attribute("location")) {
+                    (Some(url), Some(location)) => format!("{url}#{location}"),
+                    (Some(url), None) => url.clone(),
+                    (None, Some(location)) => format!("#{location}"),
+                    (None, None) => return None,
+                };
+                let text = hyperlink.attribute("display").unwrap_or_default();
+                Some((start, end, Hyperlink::new(url, text)?))
+            })
+            .collect()
+    }
+
+    /// Returns the cells of a sheet whose shared string has styled runs.
+    pub fn rich_text(&self, sheet_name: &str) -> Vec<(Pos, RichText)> {
+        if self.rich_text_strings.is_empty() {
+            return vec![];
+        }
+        let Some(xml) = self.worksheets.get(sheet_name) else {
+            return vec![];
+        };
+        element_trees(xml, "c")
+            .filter(|cell| cell.attribute("t") == Some("s"))
+            .filter_map(|cell| {
+                let pos = Pos::try_a1_string(cell.attribute("r")?)?;
+                let index = cell.child("v")?.text.trim().parse::<usize>().ok()?;
+                Some((pos, self.rich_text_strings.get(&index)?.clone()))
+            })
+            .collect()
+    }
+
+    /// Returns the cells of a sheet that have a number format other than
+    /// General, with the format code.
+    pub fn number_formats(&self, sheet_name: &str) -> Vec<(Pos, String)> {
+        if self.cell_number_formats.is_empty() {
+            return vec![];
+        }
+        let Some(xml) = self.worksheets.get(sheet_name) else {
+            return vec![];
+        };
+        cell_style_indices(xml)
+            .filter_map(|(pos, style)| Some((pos, self.cell_number_formats.get(style)?.clone()?)))
+            .collect()
+    }
+
+    /// Returns the cells of a sheet whose style has a font, alignment or
+    /// border that is not the default.
+    pub fn cell_styles(&self, sheet_name: &str) -> Vec<(Pos, ExcelCellStyle)> {
+        if self.cell_styles.iter().all(ExcelCellStyle::is_default) {
+            return vec![];
+        }
+        let Some(xml) = self.worksheets.get(sheet_name) else {
+            return vec![];
+        };
+        cell_style_indices(xml)
+            .filter_map(|(pos, style)| {
+                let style = self.cell_styles.get(style)?;
+                (!style.is_default()).then(|| (pos, style.clone()))
+            })
+            .collect()
+    }
+    /// Returns the tables of a sheet.
+    pub fn tables(&self, sheet_name: &str) -> &[ExcelTable] {
+        self.tables.get(sheet_name).map_or(&[], Vec::as_slice)
+    }
+}
+
+/// Reads an Excel table from its XML. Tables without a range are ignored.
+fn excel_table(xml: &str) -> Option<ExcelTable> {
+    let table = element_trees(xml, "table").next()?;
+    let (start, end) = table.attribute("ref")?.split_once(':')?;
+    let name = table.attribute("displayName").or(table.attribute("name"))?;
+    let count = |name| {
+        table
+            .attribute(name)
+            .and_then(|count| count.parse::<u32>().ok())
+            .unwrap_or_default()
+    };
+    let header_row = table.attribute("headerRowCount") != Some("0");
+
+    let style_info = table.child("tableStyleInfo");
+    let option = |name| is_true(style_info.and_then(|style_info| style_info.attribute(name)));
+    Some(ExcelTable {
+        name: name.to_string(),
+        start: Pos::try_a1_string(start)?,
+        end: Pos::try_a1_string(end)?,
+        header_row,
+        totals_row: count("totalsRowCount") > 0,
+        style_name: style_info
+            .and_then(|style_info| style_info.attribute("name"))
+            .map(String::from),
+        show_first_column: option("showFirstColumn"),
+        show_last_column: option("showLastColumn"),
+        show_row_stripes: option("showRowStripes"),
+        show_column_stripes: option("showColumnStripes"),
+    })
+}
+
+/// Returns the path of a relationship target. Targets are relative to the
+/// directory of the part that references them, unless they start with `/`.
+fn target_path(dir: &str, target: &str) -> String {
+    if let Some(path) = target.strip_prefix('/') {
+        return path.to_string();
+    }
+    let mut parts = dir
+        .split('/')
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>();
+    for part in target.split('/') {
+        match part {
+            ".." => {
+                parts.pop();
+            }
+            "." | "" => (),
+            _ => parts.push(part),
+        }
+    }
+    parts.join("/")
+}
+
+/// Returns the cells of a worksheet with their style index.
+fn cell_style_indices(xml: &str) -> impl Iterator<Item = (Pos, usize)> + '_ {
+    elements(xml, "c").filter_map(|cell| {
+        let style = cell.attribute("s")?.parse::<usize>().ok()?;
+        Some((Pos::try_a1_string(cell.attribute("r")?)?, style))
+    })
+}
+
+/// Returns the borders in the styles XML, by border id.
+fn borders(xml: &str) -> Vec<ExcelBorders> {
+    let Some(borders) = element_trees(xml, "borders").next() else {
+        return vec![];
+    };
+    borders
+        .children("border")
+        .map(|border| {
+            let mut borders = ExcelBorders::default();
+            for side in &border.children {
+                let Some(line) = side.attribute("style").and_then(CellBorderLine::from_excel)
+                else {
+                    continue;
+                };
+                let color = side.child("color").and_then(border_color);
+                let style = Some(ExcelBorder { line, color });
+                match side.name.as_str() {
+                    "left" | "start" => borders.left = style,
+                    "right" | "end" => borders.right = style,
+                    "top" => borders.top = style,
+                    "bottom" => borders.bottom = style,
+                    "diagonal" => {
+                        if is_true(border.attribute("diagonalUp")) {
+                            borders.diagonal_up = style.clone();
+                        }
+                        if is_true(border.attribute("diagonalDown")) {
+                            borders.diagonal_down = style;
+                        }
+                    }
+                    _ => (),
+                }
+            }
+            borders
+        })
+        .collect()
+}
+
+/// Converts an Excel color element to a hex color or a theme color
+/// reference. Tints and indexed colors are ignored.
+fn border_color(color: &XmlElement) -> Option<String> {
+    if let Some(rgb) = color.attribute("rgb").filter(|rgb| rgb.len() == 8) {
+        // ARGB
+        return Some(format!("#{}", rgb[2..].to_ascii_lowercase()));
+    }
+    // Excel swaps the first two pairs of the theme's colors
+    let theme = match color.attribute("theme")?.parse::<usize>().ok()? {
+        0 => "light1",
+        1 => "dark1",
+        2 => "light2",
+        3 => "dark2",
+        index @ 4..=9 => [
+            "accent1", "accent2", "accent3", "accent4", "accent5", "accent6",
+        ][index - 4],
+        _ => return None,
+    };
+    Some(format!("{THEME_COLOR_PREFIX}{theme}"))
+}
+
+/// Returns the fonts, alignment and borders of each cell style in the styles
+/// XML.
+fn cell_styles(xml: &str) -> Vec<ExcelCellStyle> {
+    let borders = borders(xml);
+
+    let fonts = element_trees(xml, "fonts")
+        .next()
+        .map(|fonts| {
+            fonts
+                .children("font")
+                .map(|font| {
+                    let size = font
+                        .child("sz")
+                        .and_then(|size| size.attribute("val")?.parse::<f64>().ok())
+                        .map(|size| size.round() as i16);
+                    let name = font
+                        .child("name")
+                        .and_then(|name| name.attribute("val"))
+                        .map(String::from);
+                    (size, name)
+                })
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    let (default_size, default_name) = fonts.first().cloned().unwrap_or_default();
+
+    let Some(styles) = element_trees(xml, "cellXfs").next() else {
+        return vec![];
+    };
+    styles
+        .children("xf")
+        .map(|xf| {
+            let mut style = ExcelCellStyle::default();
+            if let Some((size, name)) = xf
+                .attribute("fontId")
+                .and_then(|id| id.parse::<usize>().ok())
+                .and_then(|id| fonts.get(id))
+            {
+                style.font_size = size.filter(|_| *size != default_size);
+                style.font_family = name.clone().filter(|_| *name != default_name);
+            }
+            if let Some(borders) = xf
+                .attribute("borderId")
+                .and_then(|id| id.parse::<usize>().ok())
+                .and_then(|id| borders.get(id))
+            {
+                style.borders = borders.clone();
+            }
+            if let Some(alignment) = xf.child("alignment") {
+                style.text_rotation = alignment
+                    .attribute("textRotation")
+                    .and_then(|rotation| rotation.parse::<i16>().ok())
+                    .and_then(text_rotation);
+                style.indent = alignment
+                    .attribute("indent")
+                    .and_then(|indent| indent.parse::<u8>().ok())
+                    .filter(|indent| *indent > 0);
+            }
+            style
+        })
+        .collect()
+}
+
+/// Converts an Excel text rotation (0 to 90 degrees up, 91 to 180 for 1 to
+/// 90 degrees down, or 255 for vertical text) to a `text_rotation` format.
+fn text_rotation(rotation: i16) -> Option<i16> {
+    match rotation {
+        1..=90 => Some(rotation),
+        91..=180 => Some(90 - rotation),
+        255 => Some(VERTICAL_TEXT),
+        _ => None,
+    }
+}
+
+/// Returns the number format code of each cell style in the styles XML.
+fn cell_number_formats(xml: &str) -> Vec<Option<String>> {
+    let custom = element_trees(xml, "numFmts")
+        .next()
+        .map(|formats| {
+            formats
+                .children("numFmt")
+                .filter_map(|format| {
+                    let id = format.attribute("numFmtId")?.parse::<u32>().ok()?;
+                    Some((id, format.attribute("formatCode")?.to_string()))
+                })
+                .collect::<HashMap<_, _>>()
+        })
+        .unwrap_or_default();
+    let Some(styles) = element_trees(xml, "cellXfs").next() else {
+        return vec![];
+    };
+    styles
+        .children("xf")
+        .map(|xf| {
+            let id = xf.attribute("numFmtId")?.parse::<u32>().ok()?;
+            custom
+                .get(&id)
+                .cloned()
+                .or_else(|| built_in_number_format(id).map(String::from))
+                .filter(|code| !code.eq_ignore_ascii_case("general"))
+        })
+        .collect()
+}
+
+/// Returns the format code of Excel's built-in number formats (the ones
+/// that are not written to the styles XML).
+fn built_in_number_format(id: u32) -> Option<&'static str> {
+    match id {
+        1 => Some("0"),
+        2 => Some("0.00"),
+        3 => Some("#,##0"),
+        4 => Some("#,##0.00"),
+        9 => Some("0%"),
+        10 => Some("0.00%"),
+        11 => Some("0.00E+00"),
+        12 => Some("# ?/?"),
+        13 => Some("# ??/??"),
+        14 => Some("mm-dd-yy"),
+        15 => Some("d-mmm-yy"),
+        16 => Some("d-mmm"),
+        17 => Some("mmm-yy"),
+        18 => Some("h:mm AM/PM"),
+        19 => Some("h:mm:ss AM/PM"),
+        20 => Some("h:mm"),
+        21 => Some("h:mm:ss"),
+        22 => Some("m/d/yy h:mm"),
+        37 => Some("#,##0 ;(#,##0)"),
+        38 => Some("#,##0 ;[Red](#,##0)"),
+        39 => Some("#,##0.00;(#,##0.00)"),
+        40 => Some("#,##0.00;[Red](#,##0.00)"),
+        44 => Some(r#"_("$"* #,##0.00_);_("$"* \(#,##0.00\);_("$"* "-"??_);_(@_)"#),
+        45 => Some("mm:ss"),
+        46 => Some("[h]:mm:ss"),
+        47 => Some("mmss.0"),
+        48 => Some("##0.0E+0"),
+        49 => Some("@"),
+        _ => None,
+    }
+}
+
+/// Returns the shared strings that have styled runs by index. Styles that
+/// are not supported (eg, fonts and theme colors) are ignored.
+fn rich_text_strings(xml: &str) -> HashMap<usize, RichText> {
+    element_trees(xml, "si")
+        .enumerate()
+        .filter_map(|(index, shared_string)| {
+            let rich_text = RichText::new(shared_string.children("r").map(|run| {
+                let text = run
+                    .children("t")
+                    .map(|text| text.text.as_str())
+                    .collect::<String>();
+                let mut span = TextSpan::new(text);
+                let properties = run
+                    .child("rPr")
+                    .map_or(&[][..], |properties| properties.children.as_slice());
+                for property in properties {
+                    let on = !matches!(property.attribute("val"), Some("0" | "false" | "none"));
+                    match property.name.as_str() {
+                        "b" => span.bold = Some(on),
+                        "i" => span.italic = Some(on),
+                        "u" => span.underline = Some(on),
+                        "strike" => span.strike_through = Some(on),
+                        "color" => {
+                            // ARGB
+                            span.text_color = property
+                                .attribute("rgb")
+                                .filter(|rgb| rgb.len() == 8)
+                                .map(|rgb| format!("#{}", &rgb[2..]));
+                        }
+                        _ => (),
+                    }
+                }
+                span
+            }));
+            (!rich_text.is_plain()).then_some((index, rich_text))
+        })
+        .collect()
+}
+
+/// Returns the targets of a relationships file by relationship id.
+fn relationship_targets(xml: &str) -> HashMap<String, String> {
+    elements(xml, "Relationship")
+        .filter_map(|relationship| {
+            Some((
+                relationship.attribute("Id")?.to_string(),
+                relationship.attribute("Target")?.to_string(),
+            ))
+        })
+        .collect()
+}
+
+fn outline_level(element: &XmlElement) -> u8 {
+    element
+        .attribute("outlineLevel")
+        .and_then(|level| level.parse::<u8>().ok())
+        .unwrap_or(0)
+}
+
+/// Creates an outline from levels and the collapsed summary indices. Excel
+/// marks the summary column or row (after the group by default, or before
+/// it) as collapsed, not the group itself.
+fn outline(levels: Vec<(i64, u8)>, summaries: Vec<i64>) -> Outline {
+    let outline = Outline::new(levels, []);
+    let collapsed = summaries
+        .into_iter()
+        .filter_map(|summary| {
+            let level = outline.level(summary) + 1;
+            let after = outline.group(summary + 1, level);
+            let mut start = summary - 1;
+            while outline.level(start - 1) >= level {
+                start -= 1;
+            }
+            outline
+                .group(start, level)
+                .filter(|group| group.end == summary - 1)
+                .or(after)
+                .map(|group| (group.start, group.level))
+        })
+        .collect::<Vec<_>>();
+    Outline::new(outline.levels().collect::<Vec<_>>(), collapsed)
+}
+
+fn read_file<R: Read + Seek>(archive: &mut ZipArchive<R>, path: &str) -> Option<String> {
+    let mut xml = String::new();
+    archive.by_name(path).ok()?.read_to_string(&mut xml).ok()?;
+    Some(xml)
+}
+
+/// An XML element with its namespace prefix removed and its attribute values
+/// and text unescaped.
+#[derive(Debug, Default, Clone, PartialEq)]
+struct XmlElement {
+    name: String,
+    attributes: Vec<(String, String)>,
+
+    /// The child elements. Only read by [`element_trees`].
+    children: Vec<XmlElement>,
+
+    /// The text and CDATA directly inside the element. Only read by
+    /// [`element_trees`].
+    text: String,
+}
+
+impl XmlElement {
+    fn new(start: &BytesStart<'_>, decoder: Decoder) -> Self {
+        let mut attributes = start.attributes();
+        attributes.with_checks(false);
+        Self {
+            name: String::from_utf8_lossy(start.local_name().as_ref()).into_owned(),
+            attributes: attributes
+                .filter_map(|attribute| {
+                    let attribute = attribute.ok()?;
+                    let key = String::from_utf8_lossy(attribute.key.as_ref()).into_owned();
+                    Some((
+                        key,
+                        attribute
+                            .decode_and_unescape_value(decoder)
+                            .ok()?
+                            .into_owned(),
+                    ))
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Returns the value of an attribute. A prefixed name (eg, `r:id`)
+    /// matches the attribute with any namespace prefix, since files do not
+    /// always use the usual prefix.
+    fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| match name.split_once(':') {
+                Some((_, name)) => key.split_once(':').is_some_and(|(_, key)| key == name),
+                None => key == name,
+            })
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Returns the child elements with a name.
+    fn children<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a XmlElement> {
+        self.children.iter().filter(move |child| child.name == name)
+    }
+
+    /// Returns the first child element with a name.
+    fn child(&self, name: &str) -> Option<&XmlElement> {
+        self.children.iter().find(|child| child.name == name)
+    }
+
+    /// Reads the children and text of the element, up to its end tag.
+    fn read_children(&mut self, reader: &mut Reader<&[u8]>) -> Option<()> {
+        loop {
+            match reader.read_event().ok()? {
+                Event::Start(start) => {
+                    let mut child = Self::new(&start, reader.decoder());
+                    child.read_children(reader)?;
+                    self.children.push(child);
+                }
+                Event::Empty(start) => self.children.push(Self::new(&start, reader.decoder())),
+                Event::Text(text) => self.text += &text.unescape().ok()?,
+                Event::CData(text) => self.text += &String::from_utf8_lossy(&text),
+                Event::End(_) => return Some(()),
+                Event::Eof => return None,
+                _ => (),
+            }
+        }
+    }
+}
+
+/// Iterates over the elements of an XML document with a name, in document
+/// order. Reading stops at the first malformed part of the document.
+struct XmlElements<'a> {
+    reader: Reader<&'a [u8]>,
+    name: &'a str,
+
+    /// Whether to read each element's children and text.
+    trees: bool,
+}
+
+impl Iterator for XmlElements<'_> {
+    type Item = XmlElement;
+
+    fn next(&mut self) -> Option<XmlElement> {
+        loop {
+            let (start, empty) = match self.reader.read_event().ok()? {
+                Event::Start(start) => (start, false),
+                Event::Empty(start) => (start, true),
+                Event::Eof => return None,
+                _ => continue,
+            };
+            if start.local_name().as_ref() != self.name.as_bytes() {
+                continue;
+            }
+            let mut element = XmlElement::new(&start, self.reader.decoder());
+            if self.trees && !empty {
+                element.read_children(&mut self.reader)?;
+            }
+            return Some(element);
+        }
+    }
+}
+
+/// Returns the elements with a name (without their children), ignoring
+/// namespace prefixes.
+fn elements<'a>(xml: &'a str, name: &'a str) -> XmlElements<'a> {
+    XmlElements {
+        reader: Reader::from_str(xml),
+        name,
+        trees: false,
+    }
+}
+
+/// Returns the elements with a name with their children and text, ignoring
+/// namespace prefixes. Elements inside a returned element are not returned
+/// separately.
+fn element_trees<'a>(xml: &'a str, name: &'a str) -> XmlElements<'a> {
+    XmlElements {
+        reader: Reader::from_str(xml),
+        name,
+        trees: true,
+    }
+}
+
+fn is_true(value: Option<&str>) -> bool {
+    matches!(value, Some("1" | "true"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_elements() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <x:worksheet xmlns:x="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:rel="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+            <x:cols><x:col min="2" max="3" width="9.5" hidden="1"/><col hidden='1' max="5" min="5"></col><colBreaks/></x:cols>
+            <x:hyperlinks><x:hyperlink ref="A1" rel:id="rId1" display="R&amp;D &lt;&quot;Q1&quot;&gt; &#39;24&#x21;"/></x:hyperlinks></x:worksheet>"#;
+        let cols = elements(xml, "col").collect::<Vec<_>>();
+        assert_eq!(cols.len(), 2);
+        assert_eq!(cols[0].name, "col");
+        assert_eq!(cols[0].attribute("min"), Some("2"));
+        assert_eq!(cols[0].attribute("hidden"), Some("1"));
+        assert_eq!(cols[0].attribute("style"), None);
+        assert_eq!(cols[1].attribute("min"), Some("5"));
+        assert_eq!(cols[1].attribute("hidden"), Some("1"));
+
+        let hyperlink = elements(xml, "hyperlink").next().unwrap();
+        assert_eq!(hyperlink.attribute("r:id"), Some("rId1"));
+        assert_eq!(hyperlink.attribute("id"), None);
+        assert_eq!(hyperlink.attribute("display"), Some("R&D <\"Q1\"> '24!"));
+
+        // reading stops at malformed XML
+        assert_eq!(
+            elements(r#"<col min="1"/><col min="2 />"#, "col").count(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_element_trees() {
+        let xml = r#"<sst><si><t>a &amp; b</t></si><si><r><t><![CDATA[<1> & ]]></t></r><r><t>2</t></r></si><si/></sst>"#;
+        let shared_strings = element_trees(xml, "si").collect::<Vec<_>>();
+        assert_eq!(shared_strings.len(), 3);
+        assert_eq!(shared_strings[0].child("t").unwrap().text, "a & b");
+        assert_eq!(
+            shared_strings[1]
+                .children("r")
+                .map(|run| run.child("t").unwrap().text.as_str())
+                .collect::<Vec<_>>(),
+            vec!["<1> & ", "2"]
+        );
+        assert!(shared_strings[2].children.is_empty());
+    }
+
+    #[test]
+    fn test_hidden_columns_rows() {
+        let layout = ExcelLayout {
+            worksheets: HashMap::from([(
+                "Sheet1".to_string(),
+                r#"<worksheet><cols><col min="1" max="1" width="5"/><col min="2" max="3" hidden="1"/></cols>
+                <sheetData><row r="1"><c r="A1"/></row><row r="4" hidden="1"/><rowBreaks/></sheetData></worksheet>"#
+                    .to_string(),
+            )]),
+            ..Default::default()
+        };
+        assert_eq!(layout.hidden_columns("Sheet1"), vec![2, 3]);
+        assert_eq!(layout.hidden_rows("Sheet1"), vec![4]);
+        assert!(layout.hidden_columns("Sheet2").is_empty());
+    }
+
+    #[test]
+    fn test_outlines() {
+        let layout = ExcelLayout {
+            worksheets: HashMap::from([(
+                "Sheet1".to_string(),
+                r#"<worksheet><cols><col min="2" max="3" outlineLevel="1" hidden="1"/><col min="4" max="4" collapsed="1"/></cols>
+                <sheetData><row r="2" outlineLevel="1"/><row r="3" outlineLevel="2" hidden="1"/><row r="4" outlineLevel="1" collapsed="1"/></sheetData></worksheet>"#
+                    .to_string(),
+            )]),
+            ..Default::default()
+        };
+        let columns = layout.column_outline("Sheet1");
+        assert_eq!(columns.levels().collect::<Vec<_>>(), vec![(2, 1), (3, 1)]);
+        assert_eq!(columns.collapsed().collect::<Vec<_>>(), vec![(2, 1)]);
+
+        let rows = layout.row_outline("Sheet1");
+        assert_eq!(
+            rows.levels().collect::<Vec<_>>(),
+            vec![(2, 1), (3, 2), (4, 1)]
+        );
+        assert_eq!(rows.collapsed().collect::<Vec<_>>(), vec![(3, 2)]);
+        assert!(layout.row_outline("Sheet2").is_empty());
+    }
+
+    #[test]
+    fn test_frozen_panes() {
+        let layout = ExcelLayout {
+            worksheets: HashMap::from([
+                (
+                    "Frozen".to_string(),
+                    r#"<worksheet><sheetViews><sheetView workbookViewId="0"><pane xSplit="1" ySplit="2" topLeftCell="B3" activePane="bottomRight" state="frozen"/></sheetView></sheetViews></worksheet>"#.to_string(),
+                ),
+                (
+                    "Split".to_string(),
+                    r#"<worksheet><sheetViews><sheetView workbookViewId="0"><pane xSplit="2400" ySplit="1200" topLeftCell="C3"/></sheetView></sheetViews></worksheet>"#.to_string(),
+                ),
+            ]),
+            ..Default::default()
+        };
+        assert_eq!(layout.frozen_panes("Frozen"), (1, 2));
+        assert_eq!(layout.frozen_panes("Split"), (0, 0));
+        assert_eq!(layout.frozen_panes("Missing"), (0, 0));
+    }
+
+    #[test]
+    fn test_hyperlinks() {
+        let layout = ExcelLayout {
+            worksheets: HashMap::from([(
+                "Sheet1".to_string(),
+                r#"<worksheet><sheetData/><hyperlinks><hyperlink ref="A1" r:id="rId1"/><hyperlink ref="B2:B3" location="'Sheet 2'!A1" display="Go &amp; see"/><hyperlink ref="C1" r:id="rId2" location="top"/><hyperlink ref="D1"/></hyperlinks></worksheet>"#
+                    .to_string(),
+            )]),
+            worksheet_targets: HashMap::from([(
+                "Sheet1".to_string(),
+                relationship_targets(
+                    r#"<Relationships><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/hyperlink" Target="https://example.com/?a=1&amp;b=2" TargetMode="External"/><Relationship Id="rId2" Target="https://example.com/docs" TargetMode="External"/></Relationships>"#,
+                ),
+            )]),
+            ..Default::default()
+        };
+        assert_eq!(
+            layout.hyperlinks("Sheet1"),
+            vec![
+                (
+                    pos![A1],
+                    pos![A1],
+                    Hyperlink::new("https://example.com/?a=1&b=2", "").unwrap()
+                ),
+                (
+                    pos![B2],
+                    pos![B3],
+                    Hyperlink::new("#'Sheet 2'!A1", "Go & see").unwrap()
+                ),
+                (
+                    pos![C1],
+                    pos![C1],
+                    Hyperlink::new("https://example.com/docs#top", "").unwrap()
+                ),
+            ]
+        );
+        assert!(layout.hyperlinks("Sheet2").is_empty());
+    }
+
+    #[test]
+    fn test_rich_text() {
+        let rich_text_strings = rich_text_strings(
+            r#"<sst count="4" uniqueCount="4"><si><t>plain</t></si><si/><si><r><rPr><b/><sz val="11"/><color rgb="FFFF0000"/><rFont val="Calibri"/></rPr><t>Total:</t></r><r><rPr><sz val="11"/><color theme="1"/></rPr><t xml:space="preserve"> 42 &amp; </t></r><r><rPr><i/><u val="none"/></rPr><t>more</t></r></si><si><r><t>no</t></r><r><rPr><sz val="9"/></rPr><t> styles</t></r></si></sst>"#,
+        );
+        let expected = RichText::new([
+            TextSpan {
+                bold: Some(true),
+                text_color: Some("#FF0000".to_string()),
+                ..TextSpan::new("Total:")
+            },
+            TextSpan::new(" 42 & "),
+            TextSpan {
+                italic: Some(true),
+                underline: Some(false),
+                ..TextSpan::new("more")
+            },
+        ]);
+        assert_eq!(rich_text_strings, HashMap::from([(2, expected.clone())]));
+
+        let layout = ExcelLayout {
+            worksheets: HashMap::from([(
+                "Sheet1".to_string(),
+                r#"<worksheet><sheetData><row r="1"><c r="A1" t="s"><v>0</v></c><c r="B1" s="1" t="s"><v>2</v></c><c r="C1"><v>2</v></c></row></sheetData></worksheet>"#
+                    .to_string(),
+            )]),
+            rich_text_strings,
+            ..Default::default()
+        };
+        assert_eq!(layout.rich_text("Sheet1"), vec![(pos![B1], expected)]);
+        assert!(layout.rich_text("Sheet2").is_empty());
+    }
+
+    #[test]
+    fn test_number_formats() {
+        let cell_number_formats = cell_number_formats(
+            r#"<styleSheet><numFmts count="2"><numFmt numFmtId="164" formatCode="0.0,,&quot;M&quot;"/><numFmt formatCode="[Red][&lt;=-1]&quot;&lt; &amp; &gt;&quot;0;0" numFmtId="165"></numFmt></numFmts>
+            <cellStyleXfs count="1"><xf numFmtId="0"/></cellStyleXfs>
+            <cellXfs count="5"><xf numFmtId="0" fontId="0"/><xf numFmtId="164" applyNumberFormat="1"/><xf numFmtId="4"/><xf numFmtId="999"/><xf numFmtId="165"/></cellXfs>
+            <dxfs count="1"><dxf><numFmt numFmtId="164" formatCode="0"/></dxf></dxfs></styleSheet>"#,
+        );
+        assert_eq!(
+            cell_number_formats,
+            vec![
+                None,
+                Some("0.0,,\"M\"".to_string()),
+                Some("#,##0.00".to_string()),
+                None,
+                Some("[Red][<=-1]\"< & >\"0;0".to_string()),
+            ]
+        );
+
+        let layout = ExcelLayout {
+            worksheets: HashMap::from([(
+                "Sheet1".to_string(),
+                r#"<worksheet><cols><col min="1" max="1" style="1"/></cols><sheetData><row r="1"><c r="A1" s="1"><v>1</v></c><c r="B1" s="2"/><c r="C1"><v>2</v></c><c r="D1" s="0"><v>2</v></c></row></sheetData></worksheet>"#
+                    .to_string(),
+            )]),
+            cell_number_formats,
+            ..Default::default()
+        };
+        assert_eq!(
+            layout.number_formats("Sheet1"),
+            vec![
+                (pos![A1], "0.0,,\"M\"".to_string()),
+                (pos![B1], "#,##0.00".to_string())
+            ]
+        );
+        assert!(layout.number_formats("Sheet2").is_empty());
+    }
+
+    #[test]
+    fn test_cell_styles() {
+        let cell_styles = cell_styles(
+            r#"<styleSheet><fonts count="3"><font><sz val="11"/><color theme="1"/><name val="Calibri"/><family val="2"/></font><font><b/><sz val="18"/><name val="Calibri"/></font><font><sz val="11"/><name val="Times &amp; Co"/></font></fonts>
+            <cellStyleXfs count="1"><xf numFmtId="0" fontId="1"/></cellStyleXfs>
+            <cellXfs count="5"><xf numFmtId="0" fontId="0"/><xf numFmtId="0" fontId="1" applyFont="1"/><xf numFmtId="0" fontId="2"><alignment textRotation="135" indent="2"/></xf><xf fontId="0"><alignment horizontal="center" textRotation="255"/></xf><xf fontId="0"><alignment textRotation="45" indent="0"/></xf></cellXfs></styleSheet>"#,
+        );
+        assert_eq!(
+            cell_styles,
+            vec![
+                ExcelCellStyle::default(),
+                ExcelCellStyle {
+                    font_size: Some(18),
+                    ..Default::default()
+                },
+                ExcelCellStyle {
+                    font_family: Some("Times & Co".to_string()),
+                    text_rotation: Some(-45),
+                    indent: Some(2),
+                    ..Default::default()
+                },
+                ExcelCellStyle {
+                    text_rotation: Some(VERTICAL_TEXT),
+                    ..Default::default()
+                },
+                ExcelCellStyle {
+                    text_rotation: Some(45),
+                    ..Default::default()
+                },
+            ]
+        );
+
+        let layout = ExcelLayout {
+            worksheets: HashMap::from([(
+                "Sheet1".to_string(),
+                r#"<worksheet><sheetData><row r="1"><c r="A1" s="1"><v>1</v></c><c r="B1" s="0"/><c r="C1" s="3"><v>2</v></c></row></sheetData></worksheet>"#
+                    .to_string(),
+            )]),
+            cell_styles: cell_styles.clone(),
+            ..Default::default()
+        };
+        assert_eq!(
+            layout.cell_styles("Sheet1"),
+            vec![
+                (pos![A1], cell_styles[1].clone()),
+                (pos![C1], cell_styles[3].clone())
+            ]
+        );
+        assert!(layout.cell_styles("Sheet2").is_empty());
+    }
+
+    #[test]
+    fn test_borders() {
+        let cell_styles = cell_styles(
+            r#"<styleSheet><borders count="3"><border><left/><right/><top/><bottom/><diagonal/></border>
+            <border diagonalUp="1"><left style="mediumDashDot"><color rgb="FFFF0000"/></left><right style="thin"><color auto="1"/></right><top/><bottom style="double"><color theme="4"/></bottom><diagonal style="hair"><color indexed="64"/></diagonal></border>
+            <border diagonalDown="1"><diagonal style="slantDashDot"/></border></borders>
+            <cellXfs count="3"><xf borderId="0"/><xf borderId="1" applyBorder="1"/><xf borderId="2"/></cellXfs></styleSheet>"#,
+        );
+        assert!(cell_styles[0].is_default());
+        assert_eq!(
+            cell_styles[1].borders,
+            ExcelBorders {
+                left: Some(ExcelBorder {
+                    line: CellBorderLine::MediumDashDot,
+                    color: Some("#ff0000".to_string()),
+                }),
+                right: Some(ExcelBorder {
+                    line: CellBorderLine::Line1,
+                    color: None,
+                }),
+                bottom: Some(ExcelBorder {
+                    line: CellBorderLine::Double,
+                    color: Some("theme:accent1".to_string()),
+                }),
+                diagonal_up: Some(ExcelBorder {
+                    line: CellBorderLine::Hair,
+                    color: None,
+                }),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            cell_styles[2].borders.diagonal_down,
+            Some(ExcelBorder {
+                line: CellBorderLine::SlantDashDot,
+                color: None,
+            })
+        );
+        assert_eq!(cell_styles[2].borders.diagonal_up, None);
+
+        let style_cell = cell_styles[1].borders.style_cell(&Theme::default());
+        assert_eq!(style_cell.left.unwrap().color, Rgba::new(255, 0, 0, 255));
+        assert_eq!(
+            style_cell.bottom.unwrap().color,
+            Rgba::new(0x44, 0x72, 0xc4, 255)
+        );
+        assert_eq!(style_cell.right.unwrap().color, Rgba::default());
+        assert_eq!(style_cell.top, None);
+    }
+
+    #[test]
+    fn test_target_path() {
+        assert_eq!(
+            target_path("xl", "worksheets/sheet1.xml"),
+            "xl/worksheets/sheet1.xml"
+        );
+        assert_eq!(
+            target_path("xl/worksheets", "../tables/table1.xml"),
+            "xl/tables/table1.xml"
+        );
+        assert_eq!(
+            target_path("xl/worksheets", "/xl/tables/table1.xml"),
+            "xl/tables/table1.xml"
+        );
+    }
+
+    #[test]
+    fn test_excel_table() {
+        let table = excel_table(
+            r#"<table xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" id="1" name="Table1" displayName="Sales" ref="B2:D6" totalsRowCount="1"><autoFilter ref="B2:D5"/><tableColumns count="3"><tableColumn id="1" name="Region"/></tableColumns><tableStyleInfo name="TableStyleMedium2" showFirstColumn="1" showLastColumn="0" showRowStripes="1" showColumnStripes="0"/></table>"#,
+        )
+        .unwrap();
+        assert_eq!(
+            table,
+            ExcelTable {
+                name: "Sales".to_string(),
+                start: pos![B2],
+                end: pos![D6],
+                header_row: true,
+                totals_row: true,
+                style_name: Some("TableStyleMedium2".to_string()),
+                show_first_column: true,
+                show_last_column: false,
+                show_row_stripes: true,
+                show_column_stripes: false,
+            }
+        );
+        let table_style = table.table_style(&Theme::default()).unwrap();
+        assert_eq!(table_style.name, "TableStyleMedium2");
+        assert!(table_style.banded_rows);
+        assert!(table_style.show_first_column);
+        assert!(table_style.show_totals_row);
+
+        let table = excel_table(
+            r#"<table id="2" name="Table2" ref="A1:A3" headerRowCount="0"><tableStyleInfo name="Custom Style"/></table>"#,
+        )
+        .unwrap();
+        assert_eq!(table.name, "Table2");
+        assert!(!table.header_row);
+        assert_eq!(table.table_style(&Theme::default()), None);
+
+        assert_eq!(excel_table(r#"<table id="3" name="Table3"/>"#), None);
+    }
+
+    #[test]
+    fn test_read() {
+        use std::io::Write;
+
+        use zip::ZipWriter;
+        use zip::write::SimpleFileOptions;
+
+        let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+        for (path, xml) in [
+            (
+                "xl/workbook.xml",
+                r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+                <x:workbook xmlns:x="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+                <x:workbookPr date1904="1"/><x:sheets><x:sheet name="R&amp;D &quot;Q1&quot;" sheetId="1" r:id="rId1"/></x:sheets></x:workbook>"#,
+            ),
+            (
+                "xl/_rels/workbook.xml.rels",
+                r#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Target="worksheets/sheet1.xml" Id="rId1"/></Relationships>"#,
+            ),
+            (
+                "xl/worksheets/sheet1.xml",
+                r#"<x:worksheet xmlns:x="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:rel="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+                <x:cols><x:col min="2" max="2" hidden="1"/></x:cols><x:sheetData/>
+                <x:hyperlinks><x:hyperlink ref="A1" rel:id="rId1"/></x:hyperlinks></x:worksheet>"#,
+            ),
+            (
+                "xl/worksheets/_rels/sheet1.xml.rels",
+                r#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Target="https://example.com/?a=1&amp;b=%22&lt;2&gt;%22" TargetMode="External"/></Relationships>"#,
+            ),
+        ] {
+            zip.start_file(path, SimpleFileOptions::default()).unwrap();
+            zip.write_all(xml.as_bytes()).unwrap();
+        }
+        let file = zip.finish().unwrap().into_inner();
+
+        let layout = ExcelLayout::new(&file);
+        assert_eq!(layout.date_system(), DateSystem::Excel1904);
+        assert_eq!(layout.hidden_columns("R&D \"Q1\""), vec![2]);
+        assert_eq!(
+            layout.hyperlinks("R&D \"Q1\""),
+            vec![(
+                pos![A1],
+                pos![A1],
+                Hyperlink::new("https://example.com/?a=1&b=%22<2>%22", "").unwrap()
+            )]
+        );
+    }
+}
@@ -0,0 +1,168 @@
+use super::operation::Operation;
+use crate::{
+    CellValue, Pos, Rect,
+    cell_values::CellValues,
+    controller::GridController,
+    grid::{Sheet, SheetId, series::flash_fill::FlashFillProgram},
+};
+
+use anyhow::{Result, bail};
+
+/// Maximum number of source columns used to learn a flash fill.
+const MAX_SOURCE_COLUMNS: usize = 10;
+
+impl GridController {
+    /// Creates the operations to flash fill a column.
+    ///
+    /// `range` is a single column that contains one or more examples. The
+    /// source columns are the columns next to the examples. The column is
+    /// filled down to the end of the source data.
+    pub fn flash_fill_operations(&self, sheet_id: SheetId, range: Rect) -> Result<Vec<Operation>> {
+        let sheet = self.try_sheet_result(sheet_id)?;
+        if range.width() != 1 {
+            bail!("Flash fill requires a single column");
+        }
+        let x = range.min.x;
+
+        let example_rows = range
+            .y_range()
+            .filter(|&y| sheet.has_content(Pos { x, y }))
+            .collect::<Vec<_>>();
+        if example_rows.is_empty() {
+            bail!("Flash fill requires at least one example");
+        }
+
+        let source_columns = flash_fill_source_columns(sheet, x, &example_rows);
+        if source_columns.is_empty() {
+            bail!("Flash fill requires data next to the examples");
+        }
+
+        let display = |pos: Pos| {
+            sheet
+                .display_value(pos)
+                .map(|value| value.to_display())
+                .unwrap_or_default()
+        };
+        let sources = |y: i64| {
+            source_columns
+                .iter()
+                .map(|&x| display(Pos { x, y }))
+                .collect::<Vec<_>>()
+        };
+
+        let examples = example_rows
+            .iter()
+            .map(|&y| (sources(y), display(Pos { x, y })))
+            .collect::<Vec<_>>();
+        let Some(program) = FlashFillProgram::learn(&examples) else {
+            bail!("Flash fill could not find a pattern in the examples");
+        };
+
+        let mut end_y = range.max.y;
+        while source_columns
+            .iter()
+            .any(|&x| sheet.has_content(Pos { x, y: end_y + 1 }))
+        {
+            end_y += 1;
+        }
+
+        // set the values in runs of consecutive filled rows so existing
+        // values are not touched
+        let mut runs: Vec<(i64, Vec<Vec<CellValue>>)> = vec![];
+        for y in range.min.y..=end_y {
+            if sheet.has_content(Pos { x, y }) {
+                continue;
+            }
+            let sources = sources(y);
+            if sources.iter().all(|source| source.is_empty()) {
+                continue;
+            }
+            let Some(value) = program.apply(&sources).filter(|value| !value.is_empty()) else {
+                continue;
+            };
+            match runs.last_mut() {
+                Some((start_y, values)) if *start_y + values.len() as i64 == y => {
+                    values.push(vec![CellValue::Text(value)]);
+                }
+                _ => runs.push((y, vec![vec![CellValue::Text(value)]])),
+            }
+        }
+
+        Ok(runs
+            .into_iter()
+            .map(|(y, values)| Operation::SetCellValues {
+                sheet_pos: Pos { x, y }.to_sheet_pos(sheet_id),
+                values: CellValues::from(values),
+            })
+            .collect())
+    }
+}
+
+/// Returns the columns next to `x` that have content in any of the example
+/// rows, in order. Only the [`MAX_SOURCE_COLUMNS`] nearest columns are used.
+fn flash_fill_source_columns(sheet: &Sheet, x: i64, example_rows: &[i64]) -> Vec<i64> {
+    let has_content = |x: i64| {
+        example_rows
+            .iter()
+            .any(|&y| sheet.has_content(Pos { x, y }))
+    };
+
+    let mut columns = vec![];
+    let mut left = Some(x - 1).filter(|&left| left >= 1 && has_content(left));
+    let mut right = Some(x + 1).filter(|&right| has_content(right));
+    while columns.len() < MAX_SOURCE_COLUMNS && (left.is_some() || right.is_some()) {
+        if let Some(column) = left {
+            columns.push(column);
+            left = Some(column - 1).filter(|&left| left >= 1 && has_content(left));
+        }
+        if let Some(column) = right
+            && columns.len() < MAX_SOURCE_COLUMNS
+        {
+            columns.push(column);
+            right = Some(column + 1).filter(|&right| has_content(right));
+        }
+    }
+    columns.sort_unstable();
+    columns
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{SheetPos, test_util::*};
+
+    #[test]
+    fn test_flash_fill_wide_sheet() {
+        // a wide block of data only uses the columns nearest to the examples
+        let mut gc = GridController::test();
+        let values = (1..=3)
+            .map(|y| {
+                (1..=500)
+                    .map(|x| match (x, y) {
+                        (99, 1) => "jane@example.com".to_string(),
+                        (99, _) => format!("user{y}@test{y}.org"),
+                        (100, 1) => "example.com".to_string(),
+                        (100, _) => String::new(),
+                        _ => format!("value {x} {y}"),
+                    })
+                    .collect()
+            })
+            .collect();
+        gc.set_cell_values(SheetPos::new(SheetId::TEST, 1, 1), values, None);
+
+        let sheet = gc.sheet(SheetId::TEST);
+        let columns = flash_fill_source_columns(sheet, 100, &[1]);
+        assert_eq!(columns, (95..=99).chain(101..=105).collect::<Vec<_>>());
+
+        gc.flash_fill(SheetId::TEST, Rect::new(100, 1, 100, 1), None)
+            .unwrap();
+        assert_cell_value_col(
+            &gc,
+            SheetId::TEST,
+            100,
+            1,
+            3,
+            vec!["example.com", "test2.org", "test3.org"],
+        );
+    }
+}
@@ -1,25 +1,26 @@
 use std::{borrow::Cow, io::Cursor};
 
 use anyhow::{Result, anyhow, bail};
-use chrono::{NaiveDate, NaiveTime};
 use csv_sniffer::Sniffer;
 
 use crate::{
-    Array, ArraySize, CellValue, Pos, SheetPos,
+    Array, ArraySize, CellValue, ClearOption, Hyperlink, Pos, Rect, SheetPos,
     arrow::arrow_col_to_cell_value_vec,
     cellvalue::Import,
     controller::GridController,
+    date_time::{DateSettings, DateSystem},
     grid::{
-        CodeCellLanguage, CodeCellValue, DataTable, Sheet, SheetId,
-        file::sheet_schema::export_sheet, formats::SheetFormatUpdates,
+        CodeCellLanguage, CodeCellValue, DataTable, GridBounds, NumericFormat, NumericFormatKind,
+        Sheet, SheetId, file::sheet_schema::export_sheet, formats::SheetFormatUpdates,
     },
+    values::format_code::FormatCode,
 };
 use bytes::Bytes;
 use calamine::{Data as ExcelData, Reader as ExcelReader, Xlsx, XlsxError};
 use lexicon_fractional_index::key_between;
 use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
 
-use super::operation::Operation;
+use super::{excel_layout::ExcelLayout, operation::Operation};
 
 const IMPORT_LINES_PER_OPERATION: u32 = 10000;
 
@@ -248,6 +249,8 @@ impl GridController {
 
         let cursor = Cursor::new(file);
         let mut workbook: Xlsx<_> = ExcelReader::new(cursor).map_err(error)?;
+        let layout = ExcelLayout::new(file);
+        let date_system = layout.date_system();
         let sheets = workbook.sheet_names().to_owned();
 
         let existing_sheet_names = self.sheet_names();
@@ -292,28 +295,26 @@ impl GridController {
                             .unwrap_or(CellValue::Text(value.to_string())),
                         ExcelData::DateTime(value) => {
                             if value.is_datetime() {
-                                value.as_datetime().map_or_else(
+                                // calamine does not read the date system of
+                                // every workbook, so the serial is converted
+                                // using the layout's date system
+                                let serial = value.as_f64();
+                                date_system.serial_to_date_time(serial).map_or_else(
                                     || CellValue::Blank,
                                     |v| {
-                                        // there's probably a better way to figure out if it's a Date or a DateTime, but this works for now
-                                        if let (Ok(zero_time), Ok(zero_date)) = (
-                                            NaiveTime::parse_from_str("00:00:00", "%H:%M:%S"),
-                                            NaiveDate::parse_from_str("1899-12-31", "%Y-%m-%d"),
-                                        ) {
-                                            if v.time() == zero_time {
-                                                CellValue::Date(v.date())
-                                            } else if v.date() == zero_date {
-                                                CellValue::Time(v.time())
-                                            } else {
-                                                CellValue::DateTime(v)
-                                            }
+                                        if serial.fract() == 0.0 {
+                                            CellValue::Date(v.date())
+                                        } else if (0.0..1.0).contains(&serial) {
+                                            CellValue::Time(v.time())
                                         } else {
                                             CellValue::DateTime(v)
                                         }
                                     },
                                 )
                             } else {
-                                CellValue::Text(value.to_string())
+                                // durations are shown with their number
+                                // format (eg, `[h]:mm:ss`)
+                                CellValue::from(value.as_f64())
                             }
                         }
                         ExcelData::DurationIso(value) => CellValue::Text(value.to_string()),
@@ -395,13 +396,142 @@ impl GridController {
                 current_y_formula += 1;
             }
 
+            // calamine reads rich text as its plain text
+            for (pos, rich_text) in layout.rich_text(&sheet_name) {
+                if matches!(sheet.cell_value_ref(pos), Some(CellValue::Text(_))) {
+                    sheet.set_cell_value(pos, CellValue::RichText(rich_text));
+                }
+            }
+
+            // number formats (dates and times are already imported as date
+            // and time values)
+            for (pos, code) in layout.number_formats(&sheet_name) {
+                let Ok(format_code) = FormatCode::parse(&code) else {
+                    continue;
+                };
+                let is_date_time = matches!(
+                    sheet.cell_value_ref(pos),
+                    Some(
+                        CellValue::Date(_)
+                            | CellValue::DateTime(_)
+                            | CellValue::DateTimeTz(_)
+                            | CellValue::Time(_)
+                    )
+                );
+                if code == "@" || (is_date_time && format_code.is_date_time()) {
+                    continue;
+                }
+                sheet.formats.numeric_format.set(
+                    pos,
+                    Some(NumericFormat {
+                        kind: NumericFormatKind::Custom,
+                        symbol: Some(code),
+                    }),
+                );
+            }
+
+            // fonts, text rotation, indent and borders
+            for (pos, style) in layout.cell_styles(&sheet_name) {
+                sheet.formats.font_size.set(pos, style.font_size);
+                sheet.formats.font_family.set(pos, style.font_family);
+                sheet.formats.text_rotation.set(pos, style.text_rotation);
+                sheet.formats.indent.set(pos, style.indent);
+                if !style.borders.is_empty() {
+                    sheet
+                        .borders
+                        .set_style_cell(pos, style.borders.style_cell(&self.grid.theme));
+                }
+            }
+
+            // hyperlinks
+            sheet.recalculate_bounds(self.a1_context());
+            set_hyperlinks(&mut sheet, layout.hyperlinks(&sheet_name));
+
+            // merged cells
+            if let Some(merges) = workbook.worksheet_merge_cells(&sheet_name) {
+                for merge in merges.map_err(error)? {
+                    sheet.merge_cells.merge(Rect::new_span(
+                        xlsx_range_to_pos(merge.start),
+                        xlsx_range_to_pos(merge.end),
+                    ));
+                }
+            }
+
+            // hidden columns and rows
+            for column in layout.hidden_columns(&sheet_name) {
+                sheet.offsets.set_column_hidden(column, true);
+            }
+            for row in layout.hidden_rows(&sheet_name) {
+                sheet.offsets.set_row_hidden(row, true);
+            }
+
+            // column and row groups
+            sheet.outlines.columns = layout.column_outline(&sheet_name);
+            sheet.outlines.rows = layout.row_outline(&sheet_name);
+
+            // frozen panes
+            (sheet.frozen_columns, sheet.frozen_rows) = layout.frozen_panes(&sheet_name);
+
+            // tables become data tables with their table style. Tables with
+            // formulas are kept as cells, since data table values cannot be
+            // formulas.
+            let mut table_ops = vec![];
+            for table in layout.tables(&sheet_name) {
+                let rect = Rect::new_span(table.start, table.end);
+                if rect
+                    .iter()
+                    .any(|pos| matches!(sheet.cell_value_ref(pos), Some(CellValue::Code(_))))
+                {
+                    continue;
+                }
+                let sheet_pos = table.start.to_sheet_pos(sheet.id);
+                table_ops.push(Operation::GridToDataTable {
+                    sheet_rect: rect.to_sheet_rect(sheet.id),
+                });
+                if table.header_row {
+                    table_ops.push(Operation::DataTableFirstRowAsHeader {
+                        sheet_pos,
+                        first_row_is_header: true,
+                    });
+                }
+                let name =
+                    DataTable::validate_table_name(&table.name, sheet_pos, self.a1_context())
+                        .is_ok()
+                        .then(|| table.name.clone());
+                // Excel does not show the table's name, so hiding it keeps
+                // the table's cells in place
+                table_ops.push(Operation::DataTableOptionMeta {
+                    sheet_pos,
+                    name,
+                    alternating_colors: None,
+                    columns: None,
+                    show_name: Some(ClearOption::Some(false)),
+                    show_columns: (!table.header_row).then_some(ClearOption::Some(false)),
+                    table_style: table.table_style(&self.grid.theme).map(ClearOption::Some),
+                });
+            }
+
             // add new sheets
             ops.push(Operation::AddSheetSchema {
                 schema: Box::new(export_sheet(sheet)),
             });
+            ops.extend(table_ops);
             ops.extend(formula_compute_ops);
         }
 
+        // dates were converted above, but the file keeps a Mac workbook's
+        // date system so that serial dates in formulas match Excel
+        if date_system == DateSystem::Excel1904
+            && self.grid.date_settings.date_system != date_system
+        {
+            ops.push(Operation::SetDateSettings {
+                date_settings: DateSettings {
+                    date_system,
+                    ..self.grid.date_settings
+                },
+            });
+        }
+
         Ok(ops)
     }
 
@@ -495,6 +625,33 @@ impl GridController {
     }
 }
 
+/// Sets the hyperlinks of an imported sheet. Linked cells keep their value
+/// (formula cells keep their formula). A link's range may span whole columns
+/// or rows, so it is clamped to the sheet's data (the sheet's bounds must be
+/// up to date).
+fn set_hyperlinks(sheet: &mut Sheet, hyperlinks: Vec<(Pos, Pos, Hyperlink)>) {
+    let data_bounds = sheet.bounds(true);
+    for (start, end, link) in hyperlinks {
+        let data_rect = match data_bounds {
+            GridBounds::NonEmpty(bounds) => Rect::new_span(start, end).intersection(&bounds),
+            GridBounds::Empty => None,
+        };
+        let cells = data_rect
+            .into_iter()
+            .flat_map(|rect| rect.iter())
+            .filter(|pos| *pos != start);
+        for pos in std::iter::once(start).chain(cells) {
+            let link = match sheet.cell_value_ref(pos) {
+                Some(CellValue::Code(_)) => continue,
+                Some(value) if !value.is_blank_or_empty_string() => link.attach_to(value.clone()),
+                _ if pos == start => link.clone(),
+                _ => continue,
+            };
+            sheet.set_cell_value(pos, CellValue::Hyperlink(Box::new(link)));
+        }
+    }
+}
+
 fn read_utf16(bytes: &[u8]) -> Option<String> {
     if bytes.is_empty() && bytes.len() % 2 == 0 {
         return None;
@@ -739,6 +896,43 @@ mod test {
         assert_eq!(sheet.cell_value((4, 1).into()), None);
     }
 
+    #[test]
+    fn test_set_hyperlinks() {
+        let mut sheet = Sheet::test();
+        sheet.set_cell_value(pos![A2], CellValue::Number(5.into()));
+        sheet.set_cell_value(pos![A3], CellValue::Text("Docs".into()));
+        sheet.set_cell_value(pos![C3], CellValue::Text("outside".into()));
+        sheet.recalculate_bounds(&Default::default());
+
+        // a whole column link only touches the cells with data
+        let link = Hyperlink::new("https://example.com", "Example").unwrap();
+        set_hyperlinks(
+            &mut sheet,
+            vec![(pos![A1], Pos { x: 1, y: 1_048_576 }, link.clone())],
+        );
+        assert_eq!(
+            sheet.cell_value(pos![A1]),
+            Some(CellValue::Hyperlink(Box::new(link.clone())))
+        );
+        assert_eq!(
+            sheet.cell_value(pos![A2]),
+            Some(CellValue::Hyperlink(Box::new(
+                link.attach_to(CellValue::Number(5.into()))
+            )))
+        );
+        assert_eq!(
+            sheet.cell_value(pos![A3]),
+            Some(CellValue::Hyperlink(Box::new(
+                link.attach_to(CellValue::Text("Docs".into()))
+            )))
+        );
+        assert_eq!(sheet.cell_value(pos![A4]), None);
+        assert_eq!(
+            sheet.cell_value(pos![C3]),
+            Some(CellValue::Text("outside".into()))
+        );
+    }
+
     #[test]
     fn import_excel_invalid() {
         let mut gc = GridController::new_blank();
@@ -898,4 +1092,34 @@ mod test {
             ))
         );
     }
+
+    #[test]
+    fn import_excel_date_1904() {
+        // the same serials as date_time.xlsx, in a workbook that uses the
+        // 1904 date system
+        let mut gc = GridController::new_blank();
+        let file = include_bytes!("../../../test-files/date_time_1904.xlsx");
+        gc.import_excel(file.as_ref(), "excel", None).unwrap();
+
+        let sheet = gc.sheet(gc.grid.sheets()[0].id);
+        assert_eq!(
+            sheet.cell_value((1, 2).into()),
+            Some(CellValue::Date(
+                NaiveDate::from_ymd_opt(1994, 12, 22).unwrap()
+            ))
+        );
+        assert_eq!(
+            sheet.cell_value((2, 2).into()),
+            Some(CellValue::DateTime(
+                NaiveDateTime::parse_from_str("2025-1-6 15:45", "%Y-%m-%d %H:%M").unwrap()
+            ))
+        );
+        assert_eq!(
+            sheet.cell_value((3, 2).into()),
+            Some(CellValue::Time(
+                NaiveTime::parse_from_str("13:23:00", "%H:%M:%S").unwrap()
+            ))
+        );
+        assert_eq!(gc.date_settings().date_system, DateSystem::Excel1904);
+    }
 }
@@ -0,0 +1,60 @@
+use super::operation::Operation;
+use crate::{controller::GridController, date_time::DateSettings, grid::Locale};
+
+use anyhow::{Result, bail};
+use chrono::format::StrftimeItems;
+use chrono_tz::Tz;
+
+impl GridController {
+    /// Creates the operation to replace the file's locale. The separators
+    /// must be distinct so that numbers and formulas can be read back.
+    pub fn set_locale_operations(&self, locale: Locale) -> Result<Vec<Operation>> {
+        if locale.name.trim().is_empty() {
+            bail!("A locale must have a name");
+        }
+        if !matches!(locale.decimal_separator, '.' | ',') {
+            bail!("The decimal separator must be '.' or ','");
+        }
+        if !matches!(locale.argument_separator, ',' | ';') {
+            bail!("The argument separator must be ',' or ';'");
+        }
+        if locale.group_separator.is_ascii_digit()
+            || locale.group_separator == locale.decimal_separator
+        {
+            bail!("The group separator must differ from the decimal separator");
+        }
+        if locale.argument_separator == locale.decimal_separator {
+            bail!("The argument separator must differ from the decimal separator");
+        }
+        if locale.first_day_of_week > 6 {
+            bail!("The first day of the week must be between 0 and 6");
+        }
+        for format in [&locale.date_format, &locale.time_format] {
+            if StrftimeItems::new(format).parse().is_err() {
+                bail!("Invalid date or time format: {format}");
+            }
+        }
+
+        Ok(vec![Operation::SetLocale { locale }])
+    }
+
+    /// Creates the operation to replace the file's time zone from an IANA
+    /// name (eg, `Europe/Berlin`). An empty name removes the file's time zone
+    /// so that each user's local time zone is used.
+    pub fn set_time_zone_operations(&self, time_zone: &str) -> Result<Vec<Operation>> {
+        let time_zone = match time_zone.trim() {
+            "" => None,
+            name => match name.parse::<Tz>() {
+                Ok(time_zone) => Some(time_zone),
+                Err(_) => bail!("Unknown time zone: {time_zone}"),
+            },
+        };
+
+        Ok(vec![Operation::SetTimeZone { time_zone }])
+    }
+
+    /// Creates the operation to replace the file's date settings.
+    pub fn set_date_settings_operations(&self, date_settings: DateSettings) -> Vec<Operation> {
+        vec![Operation::SetDateSettings { date_settings }]
+    }
+}
@@ -0,0 +1,102 @@
+use super::operation::Operation;
+use crate::{
+    CellValue, Pos, Rect, a1::A1Selection, cell_values::CellValues, controller::GridController,
+    grid::Sheet,
+};
+
+use anyhow::{Result, bail};
+
+impl GridController {
+    /// Creates the operations to merge each range of the selection into a
+    /// single cell. Only the value of the top-left cell is kept.
+    pub fn merge_cells_operations(&self, selection: &A1Selection) -> Result<Vec<Operation>> {
+        let sheet = self.try_sheet_result(selection.sheet_id)?;
+        if !selection.ranges.iter().all(|range| range.is_finite()) {
+            bail!("Cannot merge unbounded columns or rows");
+        }
+
+        let rects = sheet
+            .selection_to_rects(selection, false, false, self.a1_context())
+            .into_iter()
+            .filter(|rect| rect.len() > 1)
+            .collect::<Vec<_>>();
+        if rects.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let mut ops = vec![];
+        for rect in rects.iter() {
+            if !only_anchor_data_table(sheet, *rect) {
+                bail!("Cannot merge cells that contain tables or code");
+            }
+            ops.extend(clear_hidden_values_operations(sheet, *rect));
+        }
+        ops.push(Operation::MergeCells {
+            sheet_id: sheet.id,
+            rects,
+        });
+
+        Ok(ops)
+    }
+
+    /// Creates the operation to unmerge all merged cells that intersect the
+    /// selection.
+    pub fn unmerge_cells_operations(&self, selection: &A1Selection) -> Result<Vec<Operation>> {
+        let sheet = self.try_sheet_result(selection.sheet_id)?;
+        let rects = sheet
+            .selection_to_rects(selection, false, false, self.a1_context())
+            .into_iter()
+            .filter(|rect| !sheet.merge_cells.in_rect(*rect).is_empty())
+            .collect::<Vec<_>>();
+        if rects.is_empty() {
+            return Ok(vec![]);
+        }
+
+        Ok(vec![Operation::UnmergeCells {
+            sheet_id: sheet.id,
+            rects,
+        }])
+    }
+}
+
+/// Returns true if the only data table in the rect is a single cell at its
+/// anchor (eg, a formula that is kept as the merged cell's value).
+fn only_anchor_data_table(sheet: &Sheet, rect: Rect) -> bool {
+    sheet
+        .data_tables_within_rect(rect, false)
+        .unwrap_or_default()
+        .into_iter()
+        .all(|pos| {
+            pos == rect.min
+                && sheet
+                    .data_table(pos)
+                    .is_some_and(|dt| dt.output_rect(pos, false) == Rect::single_pos(pos))
+        })
+}
+
+/// Creates the operations to clear the values of the cells hidden by a
+/// merge: the rest of the first row, then the remaining rows.
+fn clear_hidden_values_operations(sheet: &Sheet, rect: Rect) -> Vec<Operation> {
+    let first_row = Rect::new(rect.min.x + 1, rect.min.y, rect.max.x, rect.min.y);
+    let other_rows = Rect::new(rect.min.x, rect.min.y + 1, rect.max.x, rect.max.y);
+
+    [
+        (rect.width() > 1).then_some(first_row),
+        (rect.height() > 1).then_some(other_rows),
+    ]
+    .into_iter()
+    .flatten()
+    .filter(|hidden| {
+        hidden
+            .iter()
+            .any(|pos: Pos| sheet.cell_value_ref(pos).is_some())
+    })
+    .map(|hidden| Operation::SetCellValues {
+        sheet_pos: hidden.min.to_sheet_pos(sheet.id),
+        values: CellValues::from(vec![
+            vec![CellValue::Blank; hidden.width() as usize];
+            hidden.height() as usize
+        ]),
+    })
+    .collect()
+}
@@ -4,11 +4,22 @@
 
 pub mod autocomplete;
 pub mod borders;
+pub mod cell_styles;
 pub mod cell_value;
 pub mod clipboard;
 pub mod code_cell;
+pub mod comments;
+pub mod custom_lists;
+pub mod data_cleaning;
 pub mod data_table;
+pub mod data_table_transform;
+pub mod excel_layout;
+pub mod flash_fill;
 pub mod formats;
 pub mod import;
+pub mod locale;
+pub mod merge_cells;
 pub mod operation;
+pub mod outlines;
 pub mod sheets;
+pub mod text_to_columns;
@@ -3,14 +3,17 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::{
-    CellValue, ClearOption, CopyFormats, SheetPos, SheetRect,
+    CellValue, ClearOption, CopyFormats, Pos, Rect, SheetPos, SheetRect,
     a1::A1Selection,
     cell_values::CellValues,
+    date_time::DateSettings,
     grid::{
-        DataTable, DataTableKind, Sheet, SheetId,
-        data_table::{column_header::DataTableColumnHeader, sort::DataTableSort},
+        DataTable, DataTableKind, Locale, Sheet, SheetId,
+        data_table::{
+            column_header::DataTableColumnHeader, sort::DataTableSort, table_style::TableStyle,
+        },
         file::sheet_schema::SheetSchema,
-        formats::{Formats, SheetFormatUpdates},
+        formats::{CellStyle, Formats, SheetFormatUpdates, Theme},
         formatting::CellFmtArray,
         js_types::{JsColumnWidth, JsRowHeight},
         sheet::{
@@ -18,6 +21,10 @@ use crate::{
                 BordersUpdates,
                 borders_old::{BorderStyleCellUpdates, SheetBorders},
             },
+            comments::{Comment, CommentThread},
+            conditional_formats::conditional_format::ConditionalFormat,
+            outlines::Outline,
+            protection::SheetProtection,
             validations::validation::Validation,
         },
     },
@@ -103,6 +110,8 @@ pub enum Operation {
         columns: Option<Vec<DataTableColumnHeader>>,
         show_name: Option<ClearOption<bool>>,
         show_columns: Option<ClearOption<bool>>,
+        #[serde(default)]
+        table_style: Option<ClearOption<TableStyle>>,
     },
     DataTableFormats {
         sheet_pos: SheetPos,
@@ -403,4 +412,130 @@ pub enum Operation {
         #[serde(default)]
         copy_formats: CopyFormats,
     },
+
+    /// Sets the workbook's custom lists used by autocomplete.
+    SetCustomLists {
+        custom_lists: Vec<Vec<String>>,
+    },
+
+    /// Sets the workbook's named cell styles. Cells that reference a style
+    /// are updated by separate format operations.
+    SetCellStyles {
+        cell_styles: Vec<CellStyle>,
+    },
+
+    /// Sets the workbook's theme palette.
+    SetTheme {
+        theme: Theme,
+    },
+
+    /// Sets the file's locale. Cells are re-rendered since the locale sets
+    /// the default date and number display.
+    SetLocale {
+        locale: Locale,
+    },
+
+    /// Sets the file's time zone, which is used for the current time and as
+    /// the default zone when converting date times. `None` uses each user's
+    /// local time zone.
+    SetTimeZone {
+        time_zone: Option<chrono_tz::Tz>,
+    },
+
+    /// Sets the file's date system and whether date functions use Excel
+    /// serial numbers.
+    SetDateSettings {
+        date_settings: DateSettings,
+    },
+
+    /// Merges each rect into a single cell. Merged cells that overlap a rect
+    /// are removed.
+    MergeCells {
+        sheet_id: SheetId,
+        rects: Vec<Rect>,
+    },
+
+    /// Removes all merged cells that intersect the rects.
+    UnmergeCells {
+        sheet_id: SheetId,
+        rects: Vec<Rect>,
+    },
+
+    /// Hides or unhides columns. Hidden columns keep their width.
+    SetColumnsHidden {
+        sheet_id: SheetId,
+        columns: Vec<i64>,
+        hidden: bool,
+    },
+
+    /// Hides or unhides rows. Hidden rows keep their height.
+    SetRowsHidden {
+        sheet_id: SheetId,
+        rows: Vec<i64>,
+        hidden: bool,
+    },
+
+    /// Replaces the column outline (groups) of a sheet. Collapsing a group
+    /// also requires a SetColumnsHidden operation.
+    SetColumnOutline {
+        sheet_id: SheetId,
+        outline: Outline,
+    },
+
+    /// Replaces the row outline (groups) of a sheet. Collapsing a group also
+    /// requires a SetRowsHidden operation.
+    SetRowOutline {
+        sheet_id: SheetId,
+        outline: Outline,
+    },
+
+    /// Sets the number of columns and rows frozen at the left and top of the
+    /// sheet.
+    SetFrozenPanes {
+        sheet_id: SheetId,
+        columns: u32,
+        rows: u32,
+    },
+
+    /// Adds a comment to the thread at the cell, creating the thread if
+    /// needed.
+    AddComment {
+        sheet_pos: SheetPos,
+        comment: Comment,
+    },
+
+    /// Removes a comment. The thread is removed with its last comment.
+    DeleteComment {
+        sheet_pos: SheetPos,
+        comment_id: Uuid,
+    },
+
+    /// Resolves or reopens the thread at the cell.
+    SetCommentResolved {
+        sheet_pos: SheetPos,
+        resolved: bool,
+    },
+
+    /// Replaces (or removes) whole threads. Used to move threads with their
+    /// cells and to restore them on undo.
+    SetCommentThreads {
+        sheet_id: SheetId,
+        threads: Vec<(Pos, Option<CommentThread>)>,
+    },
+
+    /// Creates or updates a conditional format.
+    SetConditionalFormat {
+        conditional_format: ConditionalFormat,
+    },
+
+    RemoveConditionalFormat {
+        sheet_id: SheetId,
+        conditional_format_id: Uuid,
+    },
+
+    /// Replaces the protection of a sheet.
+    SetSheetProtection {
+        sheet_id: SheetId,
+        protection: SheetProtection,
+    },
 }
@@ -0,0 +1,94 @@
+use super::operation::Operation;
+use crate::{
+    Axis,
+    controller::GridController,
+    grid::{SheetId, sheet::outlines::Outline},
+};
+
+use anyhow::{Result, bail};
+
+/// Creates the operation to replace the column or row outline.
+fn outline_operation(sheet_id: SheetId, axis: Axis, outline: Outline) -> Operation {
+    match axis {
+        Axis::X => Operation::SetColumnOutline { sheet_id, outline },
+        Axis::Y => Operation::SetRowOutline { sheet_id, outline },
+    }
+}
+
+/// Creates the operation to hide or unhide columns or rows.
+fn hidden_operation(sheet_id: SheetId, axis: Axis, indices: Vec<i64>, hidden: bool) -> Operation {
+    match axis {
+        Axis::X => Operation::SetColumnsHidden {
+            sheet_id,
+            columns: indices,
+            hidden,
+        },
+        Axis::Y => Operation::SetRowsHidden {
+            sheet_id,
+            rows: indices,
+            hidden,
+        },
+    }
+}
+
+impl GridController {
+    /// Creates the operations to group (or ungroup) the columns or rows from
+    /// start to end, which adds (or removes) one outline level.
+    pub fn group_operations(
+        &self,
+        sheet_id: SheetId,
+        axis: Axis,
+        start: i64,
+        end: i64,
+        group: bool,
+    ) -> Result<Vec<Operation>> {
+        if start < 1 || end < start {
+            bail!("Invalid range to group");
+        }
+        let sheet = self.try_sheet_result(sheet_id)?;
+        let mut outline = sheet.outlines.get(axis).clone();
+        let changed = if group {
+            outline.group_range(start, end)
+        } else {
+            outline.ungroup_range(start, end)
+        };
+        if !changed {
+            return Ok(vec![]);
+        }
+
+        Ok(vec![outline_operation(sheet_id, axis, outline)])
+    }
+
+    /// Creates the operations to collapse (or expand) a group. Collapsing
+    /// hides the group's columns or rows; expanding shows them, except for
+    /// those within a collapsed nested group.
+    pub fn collapse_group_operations(
+        &self,
+        sheet_id: SheetId,
+        axis: Axis,
+        start: i64,
+        level: u8,
+        collapse: bool,
+    ) -> Result<Vec<Operation>> {
+        let sheet = self.try_sheet_result(sheet_id)?;
+        let mut outline = sheet.outlines.get(axis).clone();
+        let Some(group) = outline.group(start, level) else {
+            bail!("Group not found");
+        };
+        if !outline.set_collapsed(start, level, collapse) {
+            return Ok(vec![]);
+        }
+
+        let indices = if collapse {
+            (group.start..=group.end).collect()
+        } else {
+            outline.visible_when_expanded(&group)
+        };
+        let mut ops = vec![outline_operation(sheet_id, axis, outline)];
+        if !indices.is_empty() {
+            ops.push(hidden_operation(sheet_id, axis, indices, collapse));
+        }
+
+        Ok(ops)
+    }
+}
@@ -22,6 +22,19 @@ impl GridController {
         vec![Operation::SetSheetColor { sheet_id, color }]
     }
 
+    pub fn set_frozen_panes_operations(
+        &mut self,
+        sheet_id: SheetId,
+        columns: u32,
+        rows: u32,
+    ) -> Vec<Operation> {
+        vec![Operation::SetFrozenPanes {
+            sheet_id,
+            columns,
+            rows,
+        }]
+    }
+
     /// Returns all sheet names
     pub fn sheet_names(&self) -> Vec<&str> {
         self.grid.sheets().iter().map(|s| s.name.as_str()).collect()
@@ -89,6 +102,8 @@ impl GridController {
         let new_name = format!("{} Copy", sheet.name);
         new_sheet.id = new_sheet_id;
         new_sheet.name = new_name.clone();
+        new_sheet.conditional_formats.duplicate(new_sheet_id);
+        new_sheet.protection.duplicate(new_sheet_id);
 
         new_sheet.replace_sheet_name_in_code_cells(&sheet.name, &new_name);
 
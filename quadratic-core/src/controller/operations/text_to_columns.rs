@@ -0,0 +1,188 @@
+use super::operation::Operation;
+use crate::{
+    CellValue, Pos, Rect,
+    a1::A1Selection,
+    cell_values::CellValues,
+    controller::GridController,
+    grid::{
+        Sheet,
+        formats::SheetFormatUpdates,
+        sheet::text_to_columns::{TextToColumnsOptions, TextToColumnsPreview},
+    },
+};
+
+use anyhow::{Result, bail};
+
+/// The split text of a column. Rows that are not text are None.
+struct TextToColumnsSplitRows {
+    rect: Rect,
+    rows: Vec<Option<Vec<String>>>,
+    width: usize,
+}
+
+impl TextToColumnsSplitRows {
+    /// Returns what the new columns would overwrite.
+    fn overlap(&self, sheet: &Sheet) -> TextToColumnsOverlap {
+        let mut overlap = TextToColumnsOverlap::default();
+        for (parts, y) in self.rows.iter().zip(self.rect.y_range()) {
+            if parts.is_none() {
+                continue;
+            }
+            for x in self.rect.min.x + 1..self.rect.min.x + self.width as i64 {
+                let pos = Pos { x, y };
+                if sheet.data_table_at(pos).is_some()
+                    || matches!(
+                        sheet.cell_value_ref(pos),
+                        Some(CellValue::Code(_) | CellValue::Import(_))
+                    )
+                {
+                    overlap.code = true;
+                }
+                overlap.cells = overlap.cells || sheet.has_content(pos);
+            }
+        }
+        overlap
+    }
+}
+
+/// What the new columns of a text to columns operation would overwrite.
+#[derive(Default)]
+struct TextToColumnsOverlap {
+    /// True if any existing cell would be overwritten.
+    cells: bool,
+
+    /// True if code or a table would be overwritten.
+    code: bool,
+}
+
+impl GridController {
+    /// Splits the text in a single column selection.
+    fn text_to_columns_split(
+        &self,
+        selection: &A1Selection,
+        options: &TextToColumnsOptions,
+    ) -> Result<(&Sheet, TextToColumnsSplitRows)> {
+        let sheet = self.try_sheet_result(selection.sheet_id)?;
+        let rects = sheet.selection_to_rects(selection, false, false, self.a1_context());
+        let [rect] = rects[..] else {
+            bail!("Text to columns requires a single column");
+        };
+        if rect.width() != 1 {
+            bail!("Text to columns requires a single column");
+        }
+
+        let mut texts = vec![];
+        for pos in rect.iter() {
+            if sheet.data_table_at(pos).is_some() {
+                bail!("Text to columns cannot be used within a table");
+            }
+            texts.push(match sheet.cell_value_ref(pos) {
+                Some(CellValue::Text(text)) => Some(text.clone()),
+                _ => None,
+            });
+        }
+        let rows = options.split(&texts)?;
+        let width = rows.iter().flatten().map(Vec::len).max().unwrap_or(0);
+
+        Ok((sheet, TextToColumnsSplitRows { rect, rows, width }))
+    }
+
+    /// Returns a preview of the first `max_rows` split rows and whether the
+    /// new columns would overwrite existing cells, code or tables.
+    pub fn text_to_columns_preview(
+        &self,
+        selection: &A1Selection,
+        options: &TextToColumnsOptions,
+        max_rows: u32,
+    ) -> Result<TextToColumnsPreview> {
+        let (sheet, split) = self.text_to_columns_split(selection, options)?;
+        let overlap = split.overlap(sheet);
+        let rows = split
+            .rows
+            .iter()
+            .zip(split.rect.y_range())
+            .take(max_rows as usize)
+            .map(|(parts, y)| {
+                parts.clone().unwrap_or_else(|| {
+                    vec![
+                        sheet
+                            .display_value(Pos {
+                                x: split.rect.min.x,
+                                y,
+                            })
+                            .map(|value| value.to_display())
+                            .unwrap_or_default(),
+                    ]
+                })
+            })
+            .collect();
+
+        Ok(TextToColumnsPreview {
+            rows,
+            overwrites_cells: overlap.cells,
+            overwrites_code: overlap.code,
+        })
+    }
+
+    /// Creates the operations to split the text in a single column selection
+    /// into the column and the columns to its right. Cells that are not text
+    /// are not changed.
+    pub fn text_to_columns_operations(
+        &self,
+        selection: &A1Selection,
+        options: &TextToColumnsOptions,
+    ) -> Result<Vec<Operation>> {
+        let (sheet, split) = self.text_to_columns_split(selection, options)?;
+        if split.width <= 1 {
+            return Ok(vec![]);
+        }
+        if split.overlap(sheet).code {
+            bail!("Text to columns cannot overwrite code or tables");
+        }
+
+        let values = options.cell_values(&split.rows, split.width, &self.grid.locale);
+
+        // set the values in runs of consecutive split rows so cells in rows
+        // that are not split are not touched
+        let mut ops = vec![];
+        let mut formats = SheetFormatUpdates::default();
+        let mut run: Option<(i64, Vec<Vec<CellValue>>)> = None;
+        for (row, y) in values.into_iter().chain([None]).zip(split.rect.min.y..) {
+            match row {
+                Some(row) => {
+                    let row = row
+                        .into_iter()
+                        .zip(split.rect.min.x..)
+                        .map(|((value, format), x)| {
+                            if !format.is_default() {
+                                formats.set_format_cell(Pos { x, y }, format);
+                            }
+                            value
+                        })
+                        .collect();
+                    run.get_or_insert_with(|| (y, vec![])).1.push(row);
+                }
+                None => {
+                    if let Some((start_y, rows)) = run.take() {
+                        ops.push(Operation::SetCellValues {
+                            sheet_pos: Pos {
+                                x: split.rect.min.x,
+                                y: start_y,
+                            }
+                            .to_sheet_pos(sheet.id),
+                            values: CellValues::from(rows),
+                        });
+                    }
+                }
+            }
+        }
+        if !formats.is_default() {
+            ops.push(Operation::SetCellFormatsA1 {
+                sheet_id: sheet.id,
+                formats,
+            });
+        }
+
+        Ok(ops)
+    }
+}
@@ -43,6 +43,9 @@ impl GridController {
         self.process_visible_dirty_hashes(transaction);
         self.process_remaining_dirty_hashes(transaction);
         self.send_validations(transaction);
+        self.send_comments(transaction);
+        self.send_conditional_formats(transaction);
+        self.send_sheet_protection(transaction);
         self.send_borders(transaction);
 
         transaction.fill_cells.iter().for_each(|sheet_id| {
@@ -185,7 +188,12 @@ impl GridController {
             CELL_SHEET_WIDTH as i64,
             CELL_SHEET_HEIGHT as i64,
         );
-        let render_cells = sheet.get_render_cells(rect, &self.a1_context);
+        let render_cells = sheet.get_render_cells_with_locale(
+            rect,
+            &self.a1_context,
+            &self.grid.locale,
+            self.grid.date_settings.date_system,
+        );
         if let Ok(cells) = serde_json::to_string(&render_cells) {
             crate::wasm_bindings::js::jsRenderCellSheets(
                 sheet_id.to_string(),
@@ -411,6 +419,48 @@ impl GridController {
         transaction.validations_warnings.clear();
     }
 
+    fn send_conditional_formats(&self, transaction: &mut PendingTransaction) {
+        if (!cfg!(target_family = "wasm") && !cfg!(test)) || transaction.is_server() {
+            transaction.conditional_formats.clear();
+            return;
+        }
+
+        for sheet_id in transaction.conditional_formats.iter() {
+            if let Some(sheet) = self.try_sheet(*sheet_id) {
+                sheet.send_all_conditional_formats();
+            }
+        }
+        transaction.conditional_formats.clear();
+    }
+
+    fn send_sheet_protection(&self, transaction: &mut PendingTransaction) {
+        if (!cfg!(target_family = "wasm") && !cfg!(test)) || transaction.is_server() {
+            transaction.sheet_protection.clear();
+            return;
+        }
+
+        for sheet_id in transaction.sheet_protection.iter() {
+            if let Some(sheet) = self.try_sheet(*sheet_id) {
+                sheet.send_sheet_protection();
+            }
+        }
+        transaction.sheet_protection.clear();
+    }
+
+    fn send_comments(&self, transaction: &mut PendingTransaction) {
+        if (!cfg!(target_family = "wasm") && !cfg!(test)) || transaction.is_server() {
+            transaction.comments.clear();
+            return;
+        }
+
+        for sheet_id in transaction.comments.iter() {
+            if let Some(sheet) = self.try_sheet(*sheet_id) {
+                sheet.send_all_comments();
+            }
+        }
+        transaction.comments.clear();
+    }
+
     fn send_borders(&self, transaction: &mut PendingTransaction) {
         if (!cfg!(target_family = "wasm") && !cfg!(test)) || transaction.is_server() {
             transaction.sheet_borders.clear();
@@ -155,6 +155,7 @@ impl GridController {
             columns,
             show_name: show_name.map(|show_name| Some(show_name).into()),
             show_columns: show_columns.map(|show_columns| Some(show_columns).into()),
+            table_style: None,
         };
         self.start_user_transaction(vec![op], None, TransactionName::Unknown);
     }
@@ -192,6 +192,7 @@ mod test {
                 bottom: Some(BorderStyleTimestamp::default()),
                 left: Some(BorderStyleTimestamp::default()),
                 right: Some(BorderStyleTimestamp::default()),
+                ..Default::default()
             },
         );
         assert!(!gc.thumbnail_dirty_borders(wrong_sheet_id, &intersecting_borders));
@@ -211,6 +212,7 @@ mod test {
                 bottom: Some(BorderStyleTimestamp::default()),
                 left: Some(BorderStyleTimestamp::default()),
                 right: Some(BorderStyleTimestamp::default()),
+                ..Default::default()
             },
         );
         assert!(!gc.thumbnail_dirty_borders(sheet_id, &non_intersecting_borders));
@@ -113,6 +113,10 @@ pub struct Transaction {
     pub sequence_num: Option<u64>,
     pub operations: Vec<Operation>,
     pub cursor: Option<String>,
+
+    /// Email of the sender of a transaction received from the server.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub editor: Option<String>,
 }
 
 impl Transaction {
@@ -157,6 +161,7 @@ impl Transaction {
                     file_id: Uuid::parse_str(&decoded.file_id)?,
                     operations: decoded.operations,
                     sequence_num: decoded.sequence_num,
+                    editor: Some(decoded.editor).filter(|editor| !editor.is_empty()),
                 })
             }
             _ => bail!("Invalid transaction version: {version}"),
@@ -215,6 +220,10 @@ pub struct TransactionServer {
     pub file_id: Uuid,
     pub operations: Vec<u8>,
     pub sequence_num: u64,
+
+    /// Email of the sender, set by the multiplayer server.
+    #[serde(default)]
+    pub editor: Option<String>,
 }
 
 // From doesn't work since we don't have file_id
@@ -230,6 +239,7 @@ impl TryInto<Transaction> for TransactionServer {
             sequence_num: Some(self.sequence_num),
             operations,
             cursor: None,
+            editor: self.editor,
         })
     }
 }
@@ -22,7 +22,8 @@ impl GridController {
 
 #[cfg(test)]
 mod tests {
-    use crate::grid::sheet::borders::{BorderSelection, BorderStyle};
+    use crate::Rect;
+    use crate::grid::sheet::borders::{BorderSelection, BorderSide, BorderStyle, CellBorderLine};
 
     use super::*;
 
@@ -86,6 +87,73 @@ mod tests {
         assert!(sheet.borders.vertical_borders(None).is_none());
     }
 
+    #[test]
+    fn test_diagonal_borders() {
+        let mut gc = GridController::test();
+        let sheet_id = gc.sheet_ids()[0];
+        let style = BorderStyle {
+            line: CellBorderLine::DashDot,
+            ..Default::default()
+        };
+        gc.set_borders(
+            A1Selection::test_a1("A1:B2"),
+            BorderSelection::DiagonalUp,
+            Some(style),
+            None,
+        );
+        let sheet = gc.sheet(sheet_id);
+        assert_eq!(
+            sheet.borders.get_side(BorderSide::DiagonalUp, pos![B2]),
+            Some(style)
+        );
+        assert_eq!(
+            sheet.borders.get_side(BorderSide::DiagonalDown, pos![B2]),
+            None
+        );
+        assert_eq!(sheet.borders.finite_bounds(), Some(Rect::test_a1("A1:B2")));
+        assert_eq!(sheet.borders_in_sheet().unwrap().diagonal.unwrap().len(), 1);
+
+        // setting all borders keeps the diagonals
+        gc.set_borders(
+            A1Selection::test_a1("A1:B2"),
+            BorderSelection::All,
+            Some(BorderStyle::default()),
+            None,
+        );
+        let sheet = gc.sheet(sheet_id);
+        assert_eq!(
+            sheet.borders.get_side(BorderSide::DiagonalUp, pos![A1]),
+            Some(style)
+        );
+
+        gc.clear_format_borders(&A1Selection::test_a1("A1"), None);
+        let sheet = gc.sheet(sheet_id);
+        assert_eq!(
+            sheet.borders.get_side(BorderSide::DiagonalUp, pos![A1]),
+            None
+        );
+        assert_eq!(
+            sheet.borders.get_side(BorderSide::DiagonalUp, pos![B1]),
+            Some(style)
+        );
+
+        gc.undo(None);
+        let sheet = gc.sheet(sheet_id);
+        assert_eq!(
+            sheet.borders.get_side(BorderSide::DiagonalUp, pos![A1]),
+            Some(style)
+        );
+
+        gc.set_borders(
+            A1Selection::test_a1("A1:B2"),
+            BorderSelection::Clear,
+            None,
+            None,
+        );
+        let sheet = gc.sheet(sheet_id);
+        assert!(sheet.borders.diagonal_borders(None).is_none());
+    }
+
     #[test]
     fn test_clear_borders() {
         let mut gc = GridController::test();
@@ -0,0 +1,256 @@
+use crate::a1::A1Selection;
+use crate::controller::GridController;
+use crate::controller::active_transactions::transaction_name::TransactionName;
+use crate::grid::formats::{CellStyle, Theme};
+use anyhow::Result;
+
+impl GridController {
+    /// Returns the workbook's named cell styles.
+    pub fn cell_styles(&self) -> &[CellStyle] {
+        &self.grid.cell_styles
+    }
+
+    /// Returns the workbook's theme.
+    pub fn theme(&self) -> &Theme {
+        &self.grid.theme
+    }
+
+    /// Applies a named cell style to a selection. If `name` is None, the
+    /// cells no longer reference a style but keep its attributes.
+    pub fn apply_cell_style(
+        &mut self,
+        selection: &A1Selection,
+        name: Option<&str>,
+        cursor: Option<String>,
+    ) -> Result<()> {
+        let ops = self.apply_cell_style_operations(selection, name)?;
+        self.start_user_transaction(ops, cursor, TransactionName::CellStyle);
+        Ok(())
+    }
+
+    /// Adds a named cell style, or replaces the style named `original_name`
+    /// and updates every cell that references it.
+    pub fn update_cell_style(
+        &mut self,
+        original_name: Option<&str>,
+        cell_style: CellStyle,
+        cursor: Option<String>,
+    ) -> Result<()> {
+        let ops = self.update_cell_style_operations(original_name, cell_style)?;
+        self.start_user_transaction(ops, cursor, TransactionName::CellStyle);
+        Ok(())
+    }
+
+    /// Deletes a named cell style. Cells that reference it keep its
+    /// attributes.
+    pub fn delete_cell_style(&mut self, name: &str, cursor: Option<String>) -> Result<()> {
+        let ops = self.delete_cell_style_operations(name)?;
+        self.start_user_transaction(ops, cursor, TransactionName::CellStyle);
+        Ok(())
+    }
+
+    /// Replaces the workbook's theme and updates the cells whose styles use
+    /// a changed theme color.
+    pub fn set_theme(&mut self, theme: Theme, cursor: Option<String>) -> Result<()> {
+        let ops = self.set_theme_operations(theme)?;
+        self.start_user_transaction(ops, cursor, TransactionName::SetTheme);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        color::Rgba,
+        grid::{
+            Format, SheetId,
+            file::{export, import},
+            formats::{CellStyleBorder, CellStyleBorders},
+            sheet::borders::{BorderSide, BorderStyle, CellBorderLine},
+        },
+        test_util::*,
+    };
+
+    fn header() -> CellStyle {
+        CellStyle {
+            name: "Header".to_string(),
+            format: Format {
+                bold: Some(true),
+                fill_color: Some("theme:accent1".to_string()),
+                ..Default::default()
+            },
+            borders: CellStyleBorders {
+                bottom: Some(CellStyleBorder {
+                    color: "#ff0000".to_string(),
+                    line: CellBorderLine::Line2,
+                }),
+                ..Default::default()
+            },
+        }
+    }
+
+    #[test]
+    fn test_apply_cell_style() {
+        let mut gc = GridController::test();
+        gc.update_cell_style(None, header(), None).unwrap();
+        gc.apply_cell_style(&A1Selection::test_a1("A1:B2"), Some("header"), None)
+            .unwrap();
+
+        let sheet = gc.sheet(SheetId::TEST);
+        let format = sheet.cell_format(pos![B2]);
+        assert_eq!(format.bold, Some(true));
+        assert_eq!(format.fill_color, Some("#4472c4".to_string()));
+        assert_eq!(format.style, Some("Header".to_string()));
+        assert_eq!(
+            sheet.borders.get_side(BorderSide::Bottom, pos![B2]),
+            Some(BorderStyle {
+                color: Rgba::new(255, 0, 0, 255),
+                line: CellBorderLine::Line2,
+            })
+        );
+        assert_eq!(sheet.cell_format(pos![C3]), Format::default());
+
+        // removing the style keeps its attributes
+        gc.apply_cell_style(&A1Selection::test_a1("A1"), None, None)
+            .unwrap();
+        let format = gc.sheet(SheetId::TEST).cell_format(pos![A1]);
+        assert_eq!(format.style, None);
+        assert_eq!(format.bold, Some(true));
+
+        assert!(
+            gc.apply_cell_style(&A1Selection::test_a1("A1"), Some("Missing"), None)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_update_cell_style() {
+        let mut gc = GridController::test();
+        test_create_data_table(&mut gc, SheetId::TEST, pos![E1], 2, 2);
+        gc.update_cell_style(None, header(), None).unwrap();
+        gc.apply_cell_style(&A1Selection::test_a1("A1:B2"), Some("Header"), None)
+            .unwrap();
+        gc.apply_cell_style(&A1Selection::test_a1("E3"), Some("Header"), None)
+            .unwrap();
+        gc.set_italic(&A1Selection::test_a1("A1"), Some(true), None)
+            .unwrap();
+
+        let mut style = header();
+        style.name = "Title".to_string();
+        style.format.bold = None;
+        style.format.fill_color = Some("#00ff00".to_string());
+        style.borders.bottom = None;
+        gc.update_cell_style(Some("Header"), style, None).unwrap();
+
+        let sheet = gc.sheet(SheetId::TEST);
+        for pos in [pos![A1], pos![B2], pos![E3]] {
+            let format = sheet.cell_format(pos);
+            assert_eq!(format.bold, None);
+            assert_eq!(format.fill_color, Some("#00ff00".to_string()));
+            assert_eq!(format.style, Some("Title".to_string()));
+        }
+        assert_eq!(sheet.borders.get_side(BorderSide::Bottom, pos![B2]), None);
+
+        // direct formatting is kept
+        assert_eq!(sheet.cell_format(pos![A1]).italic, Some(true));
+        assert_eq!(gc.cell_styles()[0].name, "Title");
+
+        gc.undo(None);
+        let sheet = gc.sheet(SheetId::TEST);
+        let format = sheet.cell_format(pos![E3]);
+        assert_eq!(format.bold, Some(true));
+        assert_eq!(format.style, Some("Header".to_string()));
+        assert!(
+            sheet
+                .borders
+                .get_side(BorderSide::Bottom, pos![B2])
+                .is_some()
+        );
+        assert_eq!(gc.cell_styles()[0].name, "Header");
+
+        // names must be unique
+        gc.update_cell_style(None, header(), None).unwrap_err();
+    }
+
+    #[test]
+    fn test_delete_cell_style() {
+        let mut gc = GridController::test();
+        gc.update_cell_style(None, header(), None).unwrap();
+        gc.apply_cell_style(&A1Selection::test_a1("A1"), Some("Header"), None)
+            .unwrap();
+        gc.delete_cell_style("Header", None).unwrap();
+
+        assert!(gc.cell_styles().is_empty());
+        let format = gc.sheet(SheetId::TEST).cell_format(pos![A1]);
+        assert_eq!(format.style, None);
+        assert_eq!(format.bold, Some(true));
+
+        gc.undo(None);
+        assert_eq!(gc.cell_styles().len(), 1);
+        let format = gc.sheet(SheetId::TEST).cell_format(pos![A1]);
+        assert_eq!(format.style, Some("Header".to_string()));
+    }
+
+    #[test]
+    fn test_set_theme() {
+        let mut gc = GridController::test();
+        gc.update_cell_style(None, header(), None).unwrap();
+        gc.apply_cell_style(&A1Selection::test_a1("A1"), Some("Header"), None)
+            .unwrap();
+        gc.set_fill_color(
+            &A1Selection::test_a1("B1"),
+            Some("#4472c4".to_string()),
+            None,
+        )
+        .unwrap();
+
+        let mut theme = Theme::default();
+        theme
+            .colors
+            .iter_mut()
+            .find(|color| color.name == "accent1")
+            .unwrap()
+            .color = "#123456".to_string();
+        gc.set_theme(theme.clone(), None).unwrap();
+
+        assert_eq!(gc.theme(), &theme);
+        let sheet = gc.sheet(SheetId::TEST);
+        assert_eq!(
+            sheet.cell_format(pos![A1]).fill_color,
+            Some("#123456".to_string())
+        );
+
+        // cells without the style are not changed
+        assert_eq!(
+            sheet.cell_format(pos![B1]).fill_color,
+            Some("#4472c4".to_string())
+        );
+
+        gc.undo(None);
+        assert_eq!(gc.theme(), &Theme::default());
+        assert_eq!(
+            gc.sheet(SheetId::TEST).cell_format(pos![A1]).fill_color,
+            Some("#4472c4".to_string())
+        );
+    }
+
+    #[test]
+    fn test_cell_styles_file() {
+        let mut gc = GridController::test();
+        gc.update_cell_style(None, header(), None).unwrap();
+        gc.apply_cell_style(&A1Selection::test_a1("A1"), Some("Header"), None)
+            .unwrap();
+        let mut theme = Theme::default();
+        theme.colors[0].color = "#111111".to_string();
+        gc.set_theme(theme, None).unwrap();
+
+        let grid = import(export(gc.grid().clone()).unwrap()).unwrap();
+        assert_eq!(grid.cell_styles, gc.grid().cell_styles);
+        assert_eq!(grid.theme, gc.grid().theme);
+        assert_eq!(
+            grid.sheets[0].formats.style.get(pos![A1]),
+            Some("Header".to_string())
+        );
+    }
+}
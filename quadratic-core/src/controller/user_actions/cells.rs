@@ -1,6 +1,6 @@
 use crate::controller::GridController;
 use crate::controller::active_transactions::transaction_name::TransactionName;
-use crate::{SheetPos, a1::A1Selection};
+use crate::{CellValue, RichText, SheetPos, a1::A1Selection};
 
 impl GridController {
     /// Starts a transaction to set the value of a cell by converting a user's String input
@@ -28,6 +28,23 @@ impl GridController {
         }
     }
 
+    /// Starts a transaction to set the rich text of a cell (eg, from the
+    /// inline editor).
+    pub fn set_cell_rich_text(
+        &mut self,
+        sheet_pos: SheetPos,
+        rich_text: RichText,
+        cursor: Option<String>,
+    ) -> anyhow::Result<()> {
+        let (ops, data_table_ops) = self.set_cell_rich_text_operations(sheet_pos, rich_text)?;
+        self.start_user_transaction(ops, cursor.to_owned(), TransactionName::SetCells);
+
+        if !data_table_ops.is_empty() {
+            self.start_user_transaction(data_table_ops, cursor, TransactionName::SetCells);
+        }
+        Ok(())
+    }
+
     /// Starts a transaction to deletes the cell values and code in a given rect and updates dependent cells.
     pub fn delete_cells(&mut self, selection: &A1Selection, cursor: Option<String>) {
         let ops = self.delete_cells_operations(selection, true);
@@ -49,12 +66,23 @@ impl GridController {
         let ops = self.delete_values_and_formatting_operations(selection, false);
         self.start_user_transaction(ops, cursor, TransactionName::SetCells);
     }
+
+    /// Returns the selection of the internal hyperlink displayed at a cell
+    /// (including hyperlinks returned by formulas), or None if the cell does
+    /// not contain an internal link.
+    pub fn hyperlink_selection(&self, sheet_pos: SheetPos) -> Option<A1Selection> {
+        let sheet = self.try_sheet(sheet_pos.sheet_id)?;
+        match sheet.display_value(sheet_pos.into())? {
+            CellValue::Hyperlink(link) => link.to_selection(sheet_pos.sheet_id, self.a1_context()),
+            _ => None,
+        }
+    }
 }
 
 #[cfg(test)]
 mod test {
     use crate::{
-        CellValue, Pos, Rect, SheetPos,
+        CellValue, Hyperlink, Pos, Rect, RichText, SheetPos, TextSpan,
         a1::A1Selection,
         controller::{GridController, user_actions::import::tests::simple_csv_at},
         grid::{NumericFormat, SheetId, sort::SortDirection},
@@ -754,4 +782,80 @@ mod test {
         assert_cell_value_col(&gc, sheet_id, 5, 3, 5, vec!["a", "b", "c"]);
         assert_cell_value_col(&gc, sheet_id, 6, 3, 5, vec!["d", "e", "f"]);
     }
+
+    #[test]
+    fn test_hyperlink_selection() {
+        let mut gc = GridController::test();
+        let sheet_id = gc.sheet_ids()[0];
+        gc.add_sheet_with_name("Data".to_string(), None);
+
+        let sheet = gc.sheet_mut(sheet_id);
+        sheet.set_cell_value(
+            pos![A1],
+            CellValue::Hyperlink(Box::new(Hyperlink::new("#Data!B2:C3", "Go").unwrap())),
+        );
+        sheet.set_cell_value(
+            pos![A2],
+            CellValue::Hyperlink(Box::new(Hyperlink::new("https://example.com", "").unwrap())),
+        );
+        gc.set_cell_value(pos![sheet_id!A3], "https://example.com".to_string(), None);
+
+        let data_id = gc.sheet_ids()[1];
+        let mut expected = A1Selection::test_a1("B2:C3");
+        expected.sheet_id = data_id;
+        assert_eq!(gc.hyperlink_selection(pos![sheet_id!A1]), Some(expected));
+        assert_eq!(gc.hyperlink_selection(pos![sheet_id!A2]), None);
+        assert_eq!(gc.hyperlink_selection(pos![sheet_id!A3]), None);
+        assert_eq!(gc.hyperlink_selection(pos![sheet_id!A4]), None);
+    }
+
+    #[test]
+    fn test_set_cell_rich_text() {
+        let mut gc = GridController::test();
+        let sheet_id = gc.sheet_ids()[0];
+        let rich_text = RichText::new([
+            TextSpan {
+                bold: Some(true),
+                ..TextSpan::new("Total:")
+            },
+            TextSpan::new(" 42"),
+        ]);
+
+        gc.set_cell_rich_text(pos![sheet_id!A1], rich_text.clone(), None)
+            .unwrap();
+        assert_eq!(
+            gc.sheet(sheet_id).cell_value(pos![A1]),
+            Some(CellValue::RichText(rich_text.clone()))
+        );
+        assert_display_cell_value_pos(&gc, sheet_id, pos![A1], "Total: 42");
+
+        // formulas see the plain text
+        gc.set_cell_value(pos![sheet_id!B1], "=LEN(A1)".to_string(), None);
+        assert_display_cell_value_pos(&gc, sheet_id, pos![B1], "9");
+
+        // numbers and unstyled text are set as the user's input
+        let number = RichText::new([TextSpan {
+            bold: Some(true),
+            ..TextSpan::new("42")
+        }]);
+        gc.set_cell_rich_text(pos![sheet_id!A2], number, None)
+            .unwrap();
+        gc.set_cell_rich_text(pos![sheet_id!A3], RichText::new([TextSpan::new("a")]), None)
+            .unwrap();
+        let sheet = gc.sheet(sheet_id);
+        assert_eq!(
+            sheet.cell_value(pos![A2]),
+            Some(CellValue::Number(BigDecimal::from(42)))
+        );
+        assert_eq!(
+            sheet.cell_value(pos![A3]),
+            Some(CellValue::Text("a".into()))
+        );
+
+        gc.undo(None);
+        gc.undo(None);
+        gc.undo(None);
+        gc.undo(None);
+        assert_eq!(gc.sheet(sheet_id).cell_value(pos![A1]), None);
+    }
 }
@@ -349,6 +349,11 @@ mod test {
                 cell_type: None,
                 underline: None,
                 strike_through: None,
+                font_size: None,
+                font_family: None,
+                text_rotation: None,
+                indent: None,
+                style: None,
             }
         );
         assert_eq!(
@@ -369,7 +374,12 @@ mod test {
                 date_time: None,
                 cell_type: None,
                 underline: None,
-                strike_through: None
+                strike_through: None,
+                font_size: None,
+                font_family: None,
+                text_rotation: None,
+                indent: None,
+                style: None,
             }
         );
         assert_eq!(
@@ -390,7 +400,12 @@ mod test {
                 date_time: None,
                 cell_type: None,
                 underline: Some(true),
-                strike_through: None
+                strike_through: None,
+                font_size: None,
+                font_family: None,
+                text_rotation: None,
+                indent: None,
+                style: None,
             }
         );
         assert_eq!(
@@ -412,6 +427,11 @@ mod test {
                 cell_type: None,
                 underline: None,
                 strike_through: Some(true),
+                font_size: None,
+                font_family: None,
+                text_rotation: None,
+                indent: None,
+                style: None,
             }
         );
     }
@@ -958,7 +978,12 @@ mod test {
                 date_time: None,
                 cell_type: None,
                 underline: None,
-                strike_through: None
+                strike_through: None,
+                font_size: None,
+                font_family: None,
+                text_rotation: None,
+                indent: None,
+                style: None,
             }
         );
         assert_eq!(
@@ -975,7 +1000,12 @@ mod test {
                 date_time: None,
                 cell_type: None,
                 underline: None,
-                strike_through: None
+                strike_through: None,
+                font_size: None,
+                font_family: None,
+                text_rotation: None,
+                indent: None,
+                style: None,
             }
         );
     }
@@ -1109,7 +1139,12 @@ mod test {
                 date_time: None,
                 cell_type: None,
                 underline: None,
-                strike_through: None
+                strike_through: None,
+                font_size: None,
+                font_family: None,
+                text_rotation: None,
+                indent: None,
+                style: None,
             }
         );
         assert_eq!(
@@ -1126,7 +1161,12 @@ mod test {
                 date_time: None,
                 cell_type: None,
                 underline: None,
-                strike_through: None
+                strike_through: None,
+                font_size: None,
+                font_family: None,
+                text_rotation: None,
+                indent: None,
+                style: None,
             }
         );
     }
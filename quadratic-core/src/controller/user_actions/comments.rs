@@ -0,0 +1,211 @@
+use crate::SheetPos;
+use crate::controller::GridController;
+use crate::controller::active_transactions::transaction_name::TransactionName;
+use anyhow::Result;
+use uuid::Uuid;
+
+impl GridController {
+    /// Adds a comment to a cell. The comment starts a new thread or is added
+    /// as a reply to the cell's thread.
+    pub fn add_comment(
+        &mut self,
+        sheet_pos: SheetPos,
+        author: String,
+        text: String,
+        cursor: Option<String>,
+    ) -> Result<()> {
+        let ops = self.add_comment_operations(sheet_pos, author, text)?;
+        self.start_user_transaction(ops, cursor, TransactionName::Comment);
+        Ok(())
+    }
+
+    /// Deletes a comment. The thread is deleted with its last comment.
+    pub fn delete_comment(
+        &mut self,
+        sheet_pos: SheetPos,
+        comment_id: Uuid,
+        cursor: Option<String>,
+    ) -> Result<()> {
+        let ops = self.delete_comment_operations(sheet_pos, comment_id)?;
+        self.start_user_transaction(ops, cursor, TransactionName::Comment);
+        Ok(())
+    }
+
+    /// Resolves (or reopens) the comment thread of a cell.
+    pub fn set_comment_resolved(
+        &mut self,
+        sheet_pos: SheetPos,
+        resolved: bool,
+        cursor: Option<String>,
+    ) -> Result<()> {
+        let ops = self.set_comment_resolved_operations(sheet_pos, resolved)?;
+        if !ops.is_empty() {
+            self.start_user_transaction(ops, cursor, TransactionName::Comment);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Pos, SheetPos,
+        a1::A1Selection,
+        controller::{
+            GridController,
+            operations::clipboard::{ClipboardOperation, PasteSpecial},
+        },
+        grid::{SheetId, js_types::JsClipboard, sheet::comments::CommentThread},
+    };
+
+    fn thread(gc: &GridController, pos: Pos) -> Option<CommentThread> {
+        gc.sheet(SheetId::TEST).comments.get(pos).cloned()
+    }
+
+    fn texts(gc: &GridController, pos: Pos) -> Vec<String> {
+        thread(gc, pos)
+            .map(|thread| thread.comments.into_iter().map(|c| c.text).collect())
+            .unwrap_or_default()
+    }
+
+    fn add(gc: &mut GridController, pos: Pos, text: &str) {
+        gc.add_comment(
+            SheetPos::new(SheetId::TEST, pos.x, pos.y),
+            "jane".into(),
+            text.into(),
+            None,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_add_reply_delete() {
+        let mut gc = GridController::test();
+        let sheet_pos = SheetPos::new(SheetId::TEST, 2, 2);
+
+        add(&mut gc, pos![B2], "Is this right? @bob");
+        add(&mut gc, pos![B2], "yes");
+        assert_eq!(texts(&gc, pos![B2]), vec!["Is this right? @bob", "yes"]);
+        assert_eq!(
+            thread(&gc, pos![B2]).unwrap().comments[0].mentions,
+            vec!["bob"]
+        );
+
+        gc.undo(None);
+        assert_eq!(texts(&gc, pos![B2]), vec!["Is this right? @bob"]);
+        gc.redo(None);
+
+        let first = thread(&gc, pos![B2]).unwrap().comments[0].id;
+        gc.delete_comment(sheet_pos, first, None).unwrap();
+        assert_eq!(texts(&gc, pos![B2]), vec!["yes"]);
+        gc.undo(None);
+        assert_eq!(texts(&gc, pos![B2]), vec!["Is this right? @bob", "yes"]);
+
+        assert!(
+            gc.add_comment(sheet_pos, "jane".into(), "  ".into(), None)
+                .is_err()
+        );
+        assert!(
+            gc.delete_comment(SheetPos::new(SheetId::TEST, 5, 5), first, None)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_resolve_and_delete_thread() {
+        let mut gc = GridController::test();
+        let sheet_pos = SheetPos::new(SheetId::TEST, 2, 2);
+        add(&mut gc, pos![B2], "first");
+
+        gc.set_comment_resolved(sheet_pos, true, None).unwrap();
+        assert!(thread(&gc, pos![B2]).unwrap().resolved);
+
+        // deleting the last comment removes the thread; undo restores it
+        // (including its resolved state)
+        let id = thread(&gc, pos![B2]).unwrap().comments[0].id;
+        gc.delete_comment(sheet_pos, id, None).unwrap();
+        assert!(thread(&gc, pos![B2]).is_none());
+        gc.undo(None);
+        assert!(thread(&gc, pos![B2]).unwrap().resolved);
+
+        gc.undo(None);
+        assert!(!thread(&gc, pos![B2]).unwrap().resolved);
+
+        let file = crate::grid::file::export(gc.grid().clone()).unwrap();
+        let grid = crate::grid::file::import(file).unwrap();
+        assert_eq!(
+            grid.sheets()[0].comments.get(pos![B2]),
+            thread(&gc, pos![B2]).as_ref()
+        );
+    }
+
+    #[test]
+    fn test_comments_insert_delete() {
+        let mut gc = GridController::test();
+        add(&mut gc, pos![B2], "B2");
+        add(&mut gc, pos![C3], "C3");
+
+        gc.insert_columns(SheetId::TEST, 2, 1, true, None);
+        assert_eq!(texts(&gc, pos![C2]), vec!["B2"]);
+        assert_eq!(texts(&gc, pos![D3]), vec!["C3"]);
+        gc.undo(None);
+        assert_eq!(texts(&gc, pos![B2]), vec!["B2"]);
+
+        gc.delete_rows(SheetId::TEST, vec![2], None);
+        assert!(thread(&gc, pos![B2]).is_none());
+        assert_eq!(texts(&gc, pos![C2]), vec!["C3"]);
+        gc.undo(None);
+        assert_eq!(texts(&gc, pos![B2]), vec!["B2"]);
+        assert_eq!(texts(&gc, pos![C3]), vec!["C3"]);
+
+        gc.delete_columns(SheetId::TEST, vec![2], None);
+        assert_eq!(texts(&gc, pos![B3]), vec!["C3"]);
+        gc.undo(None);
+        assert_eq!(texts(&gc, pos![B2]), vec!["B2"]);
+        assert_eq!(texts(&gc, pos![C3]), vec!["C3"]);
+    }
+
+    #[test]
+    fn test_comments_cut_paste() {
+        let mut gc = GridController::test();
+        add(&mut gc, pos![B2], "B2");
+
+        // copying does not copy the comment
+        let JsClipboard { html, .. } = gc
+            .sheet(SheetId::TEST)
+            .copy_to_clipboard(
+                &A1Selection::test_a1("B2"),
+                gc.a1_context(),
+                ClipboardOperation::Copy,
+                false,
+            )
+            .unwrap();
+        gc.paste_from_clipboard(
+            &A1Selection::test_a1("E5"),
+            None,
+            Some(html),
+            PasteSpecial::None,
+            None,
+        );
+        assert!(thread(&gc, pos![E5]).is_none());
+
+        // cutting moves the comment
+        let JsClipboard { html, .. } = gc
+            .cut_to_clipboard(&A1Selection::test_a1("B2"), None)
+            .unwrap();
+        assert!(thread(&gc, pos![B2]).is_none());
+        gc.paste_from_clipboard(
+            &A1Selection::test_a1("D4"),
+            None,
+            Some(html),
+            PasteSpecial::None,
+            None,
+        );
+        assert_eq!(texts(&gc, pos![D4]), vec!["B2"]);
+
+        gc.undo(None);
+        gc.undo(None);
+        assert_eq!(texts(&gc, pos![B2]), vec!["B2"]);
+        assert!(thread(&gc, pos![D4]).is_none());
+    }
+}
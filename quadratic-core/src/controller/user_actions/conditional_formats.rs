@@ -0,0 +1,310 @@
+use anyhow::{Result, bail};
+use uuid::Uuid;
+
+use crate::controller::GridController;
+use crate::controller::active_transactions::transaction_name::TransactionName;
+use crate::controller::operations::operation::Operation;
+use crate::grid::SheetId;
+use crate::grid::sheet::conditional_formats::conditional_format::ConditionalFormat;
+
+impl GridController {
+    /// Gets the conditional formats for a sheet.
+    pub fn conditional_formats(&self, sheet_id: SheetId) -> Option<&Vec<ConditionalFormat>> {
+        let sheet = self.try_sheet(sheet_id)?;
+        Some(&sheet.conditional_formats.conditional_formats)
+    }
+
+    /// Creates or updates a conditional format.
+    pub fn update_conditional_format(
+        &mut self,
+        conditional_format: ConditionalFormat,
+        cursor: Option<String>,
+    ) -> Result<()> {
+        self.try_sheet_result(conditional_format.selection.sheet_id)?;
+        if let Err(e) = conditional_format.rule.validate() {
+            bail!(e);
+        }
+
+        let ops = vec![Operation::SetConditionalFormat { conditional_format }];
+        self.start_user_transaction(ops, cursor, TransactionName::ConditionalFormat);
+        Ok(())
+    }
+
+    /// Removes a conditional format.
+    pub fn remove_conditional_format(
+        &mut self,
+        sheet_id: SheetId,
+        conditional_format_id: Uuid,
+        cursor: Option<String>,
+    ) -> Result<()> {
+        let sheet = self.try_sheet_result(sheet_id)?;
+        if sheet
+            .conditional_formats
+            .conditional_format(conditional_format_id)
+            .is_none()
+        {
+            bail!("Conditional format not found");
+        }
+
+        let ops = vec![Operation::RemoveConditionalFormat {
+            sheet_id,
+            conditional_format_id,
+        }];
+        self.start_user_transaction(ops, cursor, TransactionName::ConditionalFormat);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        CellValue, Pos, Rect, SheetPos,
+        a1::A1Selection,
+        controller::GridController,
+        grid::{
+            SheetId,
+            sheet::conditional_formats::{
+                conditional_format::{ConditionalFormat, ConditionalFormatStyle},
+                rules::{
+                    CompareOperator, ConditionalFormatCondition, ConditionalFormatRule, DataBar,
+                    ScaleValue,
+                },
+            },
+        },
+        wasm_bindings::js::{clear_js_calls, expect_js_call},
+    };
+
+    fn set_values(gc: &mut GridController, values: &[(Pos, &str)]) {
+        for (pos, value) in values {
+            gc.set_cell_value(
+                SheetPos::new(SheetId::TEST, pos.x, pos.y),
+                value.to_string(),
+                None,
+            );
+        }
+    }
+
+    fn red() -> ConditionalFormatStyle {
+        ConditionalFormatStyle {
+            fill_color: Some("#ff0000".into()),
+            ..Default::default()
+        }
+    }
+
+    fn highlighted(gc: &GridController, pos: Pos) -> bool {
+        gc.sheet(SheetId::TEST)
+            .conditional_formats
+            .computed(pos)
+            .is_some_and(|cell| cell.style == red())
+    }
+
+    #[test]
+    fn test_update_conditional_format() {
+        let mut gc = GridController::test();
+        set_values(
+            &mut gc,
+            &[(pos![A1], "1"), (pos![A2], "10"), (pos![A3], "20")],
+        );
+
+        let cf = ConditionalFormat::new(
+            A1Selection::test_a1("A"),
+            ConditionalFormatRule::Highlight {
+                condition: ConditionalFormatCondition::Compare {
+                    operator: CompareOperator::GreaterThan,
+                    values: vec!["5".into()],
+                },
+                style: red(),
+            },
+        );
+        gc.update_conditional_format(cf.clone(), None).unwrap();
+        assert!(!highlighted(&gc, pos![A1]));
+        assert!(highlighted(&gc, pos![A2]));
+        assert!(highlighted(&gc, pos![A3]));
+
+        let fills = gc.sheet(SheetId::TEST).get_all_render_fills();
+        assert_eq!(fills.len(), 2);
+
+        // rules are re-evaluated when cells change
+        set_values(&mut gc, &[(pos![A1], "6"), (pos![A3], "2")]);
+        assert!(highlighted(&gc, pos![A1]));
+        assert!(!highlighted(&gc, pos![A3]));
+
+        // the client receives the rules
+        clear_js_calls();
+        gc.remove_conditional_format(SheetId::TEST, cf.id, None)
+            .unwrap();
+        assert!(!highlighted(&gc, pos![A1]));
+        expect_js_call(
+            "jsSheetConditionalFormats",
+            format!("{},[]", SheetId::TEST),
+            false,
+        );
+
+        gc.undo(None);
+        assert!(highlighted(&gc, pos![A1]));
+        gc.undo(None);
+        gc.undo(None);
+        assert!(!highlighted(&gc, pos![A1]));
+        assert!(highlighted(&gc, pos![A3]));
+
+        assert!(
+            gc.remove_conditional_format(SheetId::TEST, uuid::Uuid::new_v4(), None)
+                .is_err()
+        );
+        let invalid = ConditionalFormat::new(
+            A1Selection::test_a1("A1"),
+            ConditionalFormatRule::ColorScale(vec![]),
+        );
+        assert!(gc.update_conditional_format(invalid, None).is_err());
+    }
+
+    #[test]
+    fn test_formula_rule() {
+        let mut gc = GridController::test();
+        set_values(
+            &mut gc,
+            &[
+                (pos![A1], "1"),
+                (pos![B1], "2"),
+                (pos![A2], "5"),
+                (pos![B2], "3"),
+            ],
+        );
+
+        // references are relative to the top left cell of the selection
+        let cf = ConditionalFormat::new(
+            A1Selection::test_a1("A1:A2"),
+            ConditionalFormatRule::Highlight {
+                condition: ConditionalFormatCondition::Formula("=A1>B1".into()),
+                style: red(),
+            },
+        );
+        gc.update_conditional_format(cf, None).unwrap();
+        assert!(!highlighted(&gc, pos![A1]));
+        assert!(highlighted(&gc, pos![A2]));
+
+        // changing a referenced cell outside of the selection re-evaluates
+        set_values(&mut gc, &[(pos![B1], "0")]);
+        assert!(highlighted(&gc, pos![A1]));
+    }
+
+    #[test]
+    fn test_update_only_affected_conditional_formats() {
+        let mut gc = GridController::test();
+        set_values(&mut gc, &[(pos![A1], "10"), (pos![C1], "1")]);
+        let cf = ConditionalFormat::new(
+            A1Selection::test_a1("A1:A2"),
+            ConditionalFormatRule::Highlight {
+                condition: ConditionalFormatCondition::Formula("=A1>$C$1".into()),
+                style: red(),
+            },
+        );
+        gc.update_conditional_format(cf.clone(), None).unwrap();
+        assert!(highlighted(&gc, pos![A1]));
+
+        // a stale result shows whether the conditional format was evaluated
+        let stale = |gc: &mut GridController| {
+            gc.sheet_mut(SheetId::TEST)
+                .conditional_formats
+                .results
+                .get_mut(&cf.id)
+                .unwrap()
+                .cells
+                .insert(pos![A5], Default::default());
+        };
+        let is_stale = |gc: &GridController| {
+            gc.sheet(SheetId::TEST).conditional_formats.results[&cf.id]
+                .cells
+                .contains_key(&pos![A5])
+        };
+
+        // a change outside of the selection and the formula's references
+        stale(&mut gc);
+        set_values(&mut gc, &[(pos![Z1000], "1")]);
+        assert!(is_stale(&gc));
+
+        // a change to a cell referenced by the formula
+        set_values(&mut gc, &[(pos![C1], "20")]);
+        assert!(!is_stale(&gc));
+        assert!(!highlighted(&gc, pos![A1]));
+
+        // a change within the selection
+        stale(&mut gc);
+        set_values(&mut gc, &[(pos![A2], "30")]);
+        assert!(!is_stale(&gc));
+        assert!(highlighted(&gc, pos![A2]));
+    }
+
+    #[test]
+    fn test_data_bar_render_cells() {
+        let mut gc = GridController::test();
+        set_values(&mut gc, &[(pos![A1], "5"), (pos![A2], "10")]);
+        let cf = ConditionalFormat::new(
+            A1Selection::test_a1("A1:A2"),
+            ConditionalFormatRule::DataBar(DataBar {
+                min: ScaleValue::Number(0.0),
+                max: ScaleValue::Max,
+                color: "#0000ff".into(),
+                show_value: true,
+            }),
+        );
+        gc.update_conditional_format(cf, None).unwrap();
+
+        let cells = gc
+            .sheet(SheetId::TEST)
+            .get_render_cells(Rect::new(1, 1, 1, 2), gc.a1_context());
+        assert_eq!(cells[0].data_bar.as_ref().unwrap().percent, 50);
+        assert_eq!(cells[1].data_bar.as_ref().unwrap().percent, 100);
+        assert_eq!(cells[1].value, "10");
+    }
+
+    #[test]
+    fn test_conditional_formats_insert_delete_duplicate() {
+        let mut gc = GridController::test();
+        set_values(&mut gc, &[(pos![B1], "x"), (pos![B2], "x")]);
+        let cf = ConditionalFormat::new(
+            A1Selection::test_a1("B1:B2"),
+            ConditionalFormatRule::Highlight {
+                condition: ConditionalFormatCondition::Duplicate,
+                style: red(),
+            },
+        );
+        gc.update_conditional_format(cf, None).unwrap();
+        assert!(highlighted(&gc, pos![B1]));
+
+        let selection = |gc: &GridController| {
+            gc.conditional_formats(SheetId::TEST).unwrap()[0]
+                .selection
+                .test_to_string()
+        };
+
+        gc.insert_columns(SheetId::TEST, 2, 1, true, None);
+        assert_eq!(selection(&gc), "C1:C2");
+        assert!(highlighted(&gc, pos![C1]));
+        assert!(!highlighted(&gc, pos![B1]));
+        gc.undo(None);
+        assert_eq!(selection(&gc), "B1:B2");
+
+        gc.delete_columns(SheetId::TEST, vec![2], None);
+        assert!(gc.conditional_formats(SheetId::TEST).unwrap().is_empty());
+        assert!(!highlighted(&gc, pos![B1]));
+        gc.undo(None);
+        assert_eq!(selection(&gc), "B1:B2");
+        assert!(highlighted(&gc, pos![B1]));
+
+        gc.duplicate_sheet(SheetId::TEST, None);
+        let new_sheet_id = gc.sheet_ids()[1];
+        let sheet = gc.sheet(new_sheet_id);
+        let duplicated = &sheet.conditional_formats.conditional_formats[0];
+        assert_eq!(duplicated.selection.sheet_id, new_sheet_id);
+        assert_ne!(
+            duplicated.id,
+            gc.conditional_formats(SheetId::TEST).unwrap()[0].id
+        );
+        assert!(sheet.conditional_formats.computed(pos![B1]).is_some());
+        assert_eq!(
+            sheet.cell_value(pos![B1]),
+            Some(CellValue::Text("x".into()))
+        );
+    }
+}
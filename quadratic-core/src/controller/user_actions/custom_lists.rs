@@ -0,0 +1,103 @@
+use crate::controller::GridController;
+use crate::controller::active_transactions::transaction_name::TransactionName;
+use anyhow::Result;
+
+impl GridController {
+    /// Returns the workbook's custom lists.
+    pub fn custom_lists(&self) -> &[Vec<String>] {
+        &self.grid.custom_lists
+    }
+
+    /// Replaces the workbook's custom lists, which autocomplete uses as
+    /// series.
+    pub fn set_custom_lists(
+        &mut self,
+        custom_lists: Vec<Vec<String>>,
+        cursor: Option<String>,
+    ) -> Result<()> {
+        let ops = self.set_custom_lists_operations(custom_lists)?;
+        self.start_user_transaction(ops, cursor, TransactionName::SetCustomLists);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Rect, SheetPos, grid::SheetId, test_util::*};
+
+    fn quarters() -> Vec<Vec<String>> {
+        vec![
+            ["FY24 Q1", "FY24 Q2", "FY24 Q3", "FY24 Q4"]
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+        ]
+    }
+
+    #[test]
+    fn test_set_custom_lists() {
+        let mut gc = GridController::test();
+        gc.set_custom_lists(
+            vec![vec![
+                " North ".to_string(),
+                "".to_string(),
+                "South".to_string(),
+            ]],
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            gc.custom_lists(),
+            &[vec!["North".to_string(), "South".to_string()]]
+        );
+
+        gc.undo(None);
+        assert!(gc.custom_lists().is_empty());
+        gc.redo(None);
+        assert_eq!(gc.custom_lists().len(), 1);
+
+        assert!(
+            gc.set_custom_lists(vec![vec!["a".to_string()]], None)
+                .is_err()
+        );
+        assert!(
+            gc.set_custom_lists(vec![vec!["a".to_string(), "a".to_string()]], None)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_autocomplete_custom_list() {
+        let mut gc = GridController::test();
+        gc.set_custom_lists(quarters(), None).unwrap();
+        gc.set_cell_value(SheetPos::new(SheetId::TEST, 1, 1), "FY24 Q3".into(), None);
+        gc.set_cell_value(SheetPos::new(SheetId::TEST, 1, 2), "FY24 Q4".into(), None);
+
+        gc.autocomplete(
+            SheetId::TEST,
+            Rect::test_a1("A1:A2"),
+            Rect::test_a1("A1:A5"),
+            None,
+        )
+        .unwrap();
+        assert_cell_value_col(
+            &gc,
+            SheetId::TEST,
+            1,
+            1,
+            5,
+            vec!["FY24 Q3", "FY24 Q4", "FY24 Q1", "FY24 Q2", "FY24 Q3"],
+        );
+    }
+
+    #[test]
+    fn test_custom_lists_saved_in_file() {
+        let mut gc = GridController::test();
+        gc.set_custom_lists(quarters(), None).unwrap();
+
+        let file = crate::grid::file::export(gc.grid().clone()).unwrap();
+        let grid = crate::grid::file::import(file).unwrap();
+        assert_eq!(grid.custom_lists, quarters());
+    }
+}
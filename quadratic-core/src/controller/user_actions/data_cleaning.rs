@@ -0,0 +1,304 @@
+use crate::{
+    a1::A1Selection,
+    controller::{GridController, active_transactions::transaction_name::TransactionName},
+    grid::sheet::data_cleaning::{DataCleaningAction, DataCleaningSummary, DuplicateKeep},
+};
+
+use anyhow::Result;
+
+impl GridController {
+    /// Cleans the values in a selection as a single undoable transaction and
+    /// returns how many cells changed.
+    pub fn clean_data(
+        &mut self,
+        selection: &A1Selection,
+        action: DataCleaningAction,
+        cursor: Option<String>,
+    ) -> Result<DataCleaningSummary> {
+        let (ops, summary) = self.data_cleaning_operations(selection, action)?;
+        if !ops.is_empty() {
+            self.start_user_transaction(ops, cursor, TransactionName::DataCleaning);
+        }
+
+        Ok(summary)
+    }
+
+    /// Removes duplicate rows from a selection as a single undoable
+    /// transaction and returns how many rows were removed.
+    pub fn remove_duplicates(
+        &mut self,
+        selection: &A1Selection,
+        key_columns: Option<Vec<u32>>,
+        keep: DuplicateKeep,
+        cursor: Option<String>,
+    ) -> Result<DataCleaningSummary> {
+        let (ops, summary) = self.remove_duplicates_operations(selection, key_columns, keep)?;
+        if !ops.is_empty() {
+            self.start_user_transaction(ops, cursor, TransactionName::RemoveDuplicates);
+        }
+
+        Ok(summary)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        CellValue, SheetPos,
+        a1::A1Selection,
+        controller::GridController,
+        grid::{
+            NumericFormatKind, SheetId,
+            sheet::data_cleaning::{
+                DataCleaningAction, DataCleaningCase, DataCleaningSummary, DuplicateKeep,
+            },
+        },
+        test_util::*,
+    };
+
+    fn set_values(gc: &mut GridController, values: Vec<Vec<&str>>) {
+        let values = values
+            .into_iter()
+            .map(|row| row.into_iter().map(|v| v.to_string()).collect())
+            .collect();
+        gc.set_cell_values(SheetPos::new(SheetId::TEST, 1, 1), values, None);
+    }
+
+    fn summary(cells_changed: u64, rows_removed: u64) -> DataCleaningSummary {
+        DataCleaningSummary {
+            cells_changed,
+            rows_removed,
+        }
+    }
+
+    #[test]
+    fn test_clean_data_trim_and_case() {
+        let mut gc = GridController::test();
+        set_values(&mut gc, vec![vec!["hello world", "1"], vec!["ABC", "=1+1"]]);
+        gc.sheet_mut(SheetId::TEST)
+            .set_cell_value(pos![A1], CellValue::Text("  hello world ".into()));
+
+        let selection = A1Selection::test_a1("A1:B2");
+        let result = gc
+            .clean_data(&selection, DataCleaningAction::TrimWhitespace, None)
+            .unwrap();
+        assert_eq!(result, summary(1, 0));
+        assert_display_cell_value(&gc, SheetId::TEST, 1, 1, "hello world");
+
+        let result = gc
+            .clean_data(
+                &selection,
+                DataCleaningAction::NormalizeCase(DataCleaningCase::Title),
+                None,
+            )
+            .unwrap();
+        assert_eq!(result, summary(2, 0));
+        assert_display_cell_value(&gc, SheetId::TEST, 1, 1, "Hello World");
+        assert_display_cell_value(&gc, SheetId::TEST, 1, 2, "Abc");
+        // code cells are not changed
+        assert_display_cell_value(&gc, SheetId::TEST, 2, 2, "2");
+
+        // both changes are undone as single transactions
+        gc.undo(None);
+        assert_display_cell_value(&gc, SheetId::TEST, 1, 1, "hello world");
+        assert_display_cell_value(&gc, SheetId::TEST, 1, 2, "ABC");
+        gc.undo(None);
+        assert_display_cell_value(&gc, SheetId::TEST, 1, 1, "  hello world ");
+    }
+
+    #[test]
+    fn test_clean_data_convert_text() {
+        let mut gc = GridController::test();
+        let sheet = gc.sheet_mut(SheetId::TEST);
+        sheet.set_cell_value(pos![A1], CellValue::Text("$1,234".into()));
+        sheet.set_cell_value(pos![A2], CellValue::Text("abc".into()));
+        sheet.set_cell_value(pos![A3], CellValue::Text(" 12 ".into()));
+
+        let result = gc
+            .clean_data(
+                &A1Selection::test_a1("A1:A3"),
+                DataCleaningAction::ConvertText,
+                None,
+            )
+            .unwrap();
+        assert_eq!(result, summary(2, 0));
+        assert_cell_value(&gc, SheetId::TEST, 1, 1, CellValue::from(1234.0));
+        assert_cell_value(&gc, SheetId::TEST, 1, 2, CellValue::Text("abc".into()));
+        assert_cell_value(&gc, SheetId::TEST, 1, 3, CellValue::from(12.0));
+        assert_eq!(
+            gc.sheet(SheetId::TEST)
+                .cell_format(pos![A1])
+                .numeric_format
+                .map(|f| f.kind),
+            Some(NumericFormatKind::Currency)
+        );
+    }
+
+    #[test]
+    fn test_clean_data_fill_blanks_down() {
+        let mut gc = GridController::test();
+        set_values(
+            &mut gc,
+            vec![vec!["a", "1"], vec!["", ""], vec!["b", ""], vec!["", "2"]],
+        );
+
+        let result = gc
+            .clean_data(
+                &A1Selection::test_a1("A1:B4"),
+                DataCleaningAction::FillBlanksDown,
+                None,
+            )
+            .unwrap();
+        assert_eq!(result, summary(4, 0));
+        assert_cell_value_col(&gc, SheetId::TEST, 1, 1, 4, vec!["a", "a", "b", "b"]);
+        assert_cell_value_col(&gc, SheetId::TEST, 2, 1, 4, vec!["1", "1", "1", "2"]);
+
+        gc.undo(None);
+        assert_cell_value_col(&gc, SheetId::TEST, 1, 1, 4, vec!["a", "", "b", ""]);
+    }
+
+    #[test]
+    fn test_remove_duplicates_sheet() {
+        let mut gc = GridController::test();
+        set_values(
+            &mut gc,
+            vec![
+                vec!["a", "1"],
+                vec!["b", "2"],
+                vec!["A", "1"],
+                vec!["c", "1"],
+                vec!["b", "3"],
+            ],
+        );
+        let selection = A1Selection::test_a1("A1:B5");
+
+        let result = gc
+            .remove_duplicates(&selection, None, DuplicateKeep::First, None)
+            .unwrap();
+        assert_eq!(result.rows_removed, 1);
+        assert_cell_value_col(&gc, SheetId::TEST, 1, 1, 5, vec!["a", "b", "c", "b", ""]);
+        assert_cell_value_col(&gc, SheetId::TEST, 2, 1, 5, vec!["1", "2", "1", "3", ""]);
+
+        gc.undo(None);
+        let result = gc
+            .remove_duplicates(&selection, Some(vec![0]), DuplicateKeep::Last, None)
+            .unwrap();
+        assert_eq!(result.rows_removed, 2);
+        assert_cell_value_col(&gc, SheetId::TEST, 1, 1, 5, vec!["A", "c", "b", "", ""]);
+        assert_cell_value_col(&gc, SheetId::TEST, 2, 1, 5, vec!["1", "1", "3", "", ""]);
+
+        assert!(
+            gc.remove_duplicates(&selection, Some(vec![2]), DuplicateKeep::First, None)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_remove_duplicates_sheet_formats() {
+        let mut gc = GridController::test();
+        set_values(&mut gc, vec![vec!["a"], vec!["b"], vec!["a"], vec!["c"]]);
+        gc.set_bold(&A1Selection::test_a1("A3"), Some(true), None)
+            .unwrap();
+        gc.set_italic(&A1Selection::test_a1("A4"), Some(true), None)
+            .unwrap();
+
+        gc.remove_duplicates(
+            &A1Selection::test_a1("A1:A4"),
+            None,
+            DuplicateKeep::First,
+            None,
+        )
+        .unwrap();
+        assert_cell_value_col(&gc, SheetId::TEST, 1, 1, 4, vec!["a", "b", "c", ""]);
+
+        // the formats are moved with the values
+        let sheet = gc.sheet(SheetId::TEST);
+        assert_eq!(sheet.formats.bold.get(pos![A3]), None);
+        assert_eq!(sheet.formats.italic.get(pos![A3]), Some(true));
+        assert_eq!(sheet.formats.italic.get(pos![A4]), None);
+
+        gc.undo(None);
+        let sheet = gc.sheet(SheetId::TEST);
+        assert_eq!(sheet.formats.bold.get(pos![A3]), Some(true));
+        assert_eq!(sheet.formats.italic.get(pos![A4]), Some(true));
+    }
+
+    #[test]
+    fn test_remove_duplicates_data_table() {
+        let mut gc = GridController::test();
+        let sheet_pos = SheetPos::new(SheetId::TEST, 1, 1);
+        gc.add_data_table(
+            sheet_pos,
+            "Table".to_string(),
+            vec![
+                vec!["name".into(), "value".into()],
+                vec!["a".into(), "1".into()],
+                vec!["b".into(), "2".into()],
+                vec!["a".into(), "3".into()],
+            ],
+            true,
+            None,
+        );
+
+        // select the whole table, including its name and header rows
+        let result = gc
+            .remove_duplicates(
+                &A1Selection::test_a1("A1:B5"),
+                Some(vec![0]),
+                DuplicateKeep::First,
+                None,
+            )
+            .unwrap();
+        assert_eq!(result, summary(2, 1));
+
+        let data_table = gc.data_table(sheet_pos).unwrap();
+        assert_eq!(data_table.height(true), 3);
+        assert_data_table_row(data_table, 1, vec!["a", "1"]);
+        assert_data_table_row(data_table, 2, vec!["b", "2"]);
+
+        gc.undo(None);
+        assert_eq!(gc.data_table(sheet_pos).unwrap().height(true), 4);
+    }
+
+    #[test]
+    fn test_clean_data_data_table() {
+        let mut gc = GridController::test();
+        let sheet_pos = SheetPos::new(SheetId::TEST, 1, 1);
+        gc.add_data_table(
+            sheet_pos,
+            "Table".to_string(),
+            vec![
+                vec!["name".into()],
+                vec!["a".into()],
+                vec!["".into()],
+                vec!["b".into()],
+            ],
+            true,
+            None,
+        );
+
+        let result = gc
+            .clean_data(
+                &A1Selection::test_a1("A1:A5"),
+                DataCleaningAction::NormalizeCase(DataCleaningCase::Upper),
+                None,
+            )
+            .unwrap();
+        // the name and header rows are not changed
+        assert_eq!(result, summary(2, 0));
+        let data_table = gc.data_table(sheet_pos).unwrap();
+        assert_data_table_at(data_table, 0, 1, "A");
+        assert_data_table_at(data_table, 0, 3, "B");
+
+        let result = gc
+            .clean_data(
+                &A1Selection::test_a1("A1:A5"),
+                DataCleaningAction::FillBlanksDown,
+                None,
+            )
+            .unwrap();
+        assert_eq!(result, summary(1, 0));
+        let data_table = gc.data_table(sheet_pos).unwrap();
+        assert_data_table_column(data_table, 0, vec!["name", "A", "A", "B"]);
+    }
+}
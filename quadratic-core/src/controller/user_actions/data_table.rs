@@ -1,7 +1,11 @@
 use crate::{
     CopyFormats, Pos, SheetPos, SheetRect,
     controller::{GridController, active_transactions::transaction_name::TransactionName},
-    grid::{DataTable, data_table::column_header::DataTableColumnHeader, sort::DataTableSort},
+    grid::{
+        DataTable,
+        data_table::{column_header::DataTableColumnHeader, table_style::TableStyle},
+        sort::DataTableSort,
+    },
 };
 
 use anyhow::Result;
@@ -73,6 +77,26 @@ impl GridController {
         self.start_user_transaction(ops, cursor, TransactionName::DataTableMeta);
     }
 
+    /// Sets or clears the style of a table.
+    pub fn set_data_table_style(
+        &mut self,
+        sheet_pos: SheetPos,
+        table_style: Option<TableStyle>,
+        cursor: Option<String>,
+    ) {
+        let ops = self.data_table_style_operations(sheet_pos, table_style);
+        self.start_user_transaction(ops, cursor, TransactionName::DataTableMeta);
+    }
+
+    /// Returns Excel's built-in table styles, with colors from the
+    /// workbook's theme.
+    pub fn table_style_presets(&self) -> Vec<TableStyle> {
+        TableStyle::preset_names()
+            .iter()
+            .filter_map(|name| TableStyle::preset(name, &self.grid.theme))
+            .collect()
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn data_table_mutations(
         &mut self,
@@ -621,4 +645,64 @@ mod tests {
         assert_eq!(gc.data_table(pos![sheet_id!A1]), Some(&dt));
         assert!(gc.data_table(pos![sheet_id!A2]).is_none());
     }
+
+    #[test]
+    fn test_set_data_table_style() {
+        let mut gc = GridController::test();
+        let sheet_id = gc.sheet_ids()[0];
+        test_create_data_table(&mut gc, sheet_id, pos![E1], 2, 3);
+
+        let mut table_style = gc
+            .table_style_presets()
+            .into_iter()
+            .find(|table_style| table_style.name == "TableStyleMedium2")
+            .unwrap();
+        table_style.show_first_column = true;
+        gc.set_data_table_style(pos![sheet_id!E1], Some(table_style.clone()), None);
+
+        let sheet = gc.sheet(sheet_id);
+        let dt = sheet.data_table(pos![E1]).unwrap();
+        assert_eq!(dt.table_style, Some(table_style));
+
+        // header row (E2), then banded data rows (E3:F5)
+        let fills = sheet.get_all_render_fills();
+        assert_eq!(fills.len(), 3);
+        assert_eq!((fills[0].x, fills[0].y, fills[0].w), (5, 2, 2));
+        assert_eq!(fills[0].color, "#4472c4");
+        assert_eq!((fills[1].y, fills[1].w), (3, 2));
+        assert_eq!(fills[1].color, "#dae3f3");
+        assert_eq!(fills[2].y, 5);
+
+        // the header row uses the style's text color, and the first column is
+        // bold
+        let cells = sheet.get_render_cells(Rect::test_a1("E2:F4"), gc.a1_context());
+        let cell = |x, y| {
+            cells
+                .iter()
+                .find(|cell| cell.x == x && cell.y == y)
+                .unwrap()
+        };
+        assert_eq!(cell(5, 2).text_color, Some("#ffffff".to_string()));
+        assert_eq!(cell(5, 4).bold, Some(true));
+        assert_eq!(cell(6, 4).bold, None);
+
+        // the style replaces the alternating colors
+        assert!(
+            !sheet
+                .get_render_code_cell(pos![E1])
+                .unwrap()
+                .alternating_colors
+        );
+
+        gc.undo(None);
+        let sheet = gc.sheet(sheet_id);
+        assert_eq!(sheet.data_table(pos![E1]).unwrap().table_style, None);
+        assert!(sheet.get_all_render_fills().is_empty());
+        assert!(
+            sheet
+                .get_render_code_cell(pos![E1])
+                .unwrap()
+                .alternating_colors
+        );
+    }
 }
@@ -0,0 +1,185 @@
+use crate::{
+    SheetPos,
+    controller::{GridController, active_transactions::transaction_name::TransactionName},
+    grid::data_table::transform::DataTableTransform,
+};
+
+use anyhow::Result;
+
+impl GridController {
+    /// Creates a new data table at `sheet_pos` by grouping, pivoting,
+    /// unpivoting, or joining existing tables. See
+    /// [`GridController::data_table_transform_operations`].
+    pub fn data_table_transform(
+        &mut self,
+        sheet_pos: SheetPos,
+        transform: DataTableTransform,
+        live: bool,
+        name: Option<String>,
+        cursor: Option<String>,
+    ) -> Result<()> {
+        let ops = self.data_table_transform_operations(sheet_pos, transform, live, name)?;
+        self.start_user_transaction(ops, cursor, TransactionName::DataTableTransform);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        CellValue, Pos, SheetPos,
+        controller::GridController,
+        grid::{
+            DataTableKind, SheetId,
+            data_table::transform::{DataTableAggregate, DataTableJoinKind, DataTableTransform},
+        },
+        test_util::*,
+    };
+
+    fn sales(gc: &mut GridController, sheet_id: SheetId) {
+        gc.add_data_table(
+            SheetPos::new(sheet_id, 1, 1),
+            "Sales".to_string(),
+            vec![
+                vec!["region".into(), "units".into()],
+                vec!["East".into(), "10".into()],
+                vec!["West".into(), "5".into()],
+                vec!["East".into(), "3".into()],
+            ],
+            true,
+            None,
+        );
+    }
+
+    fn group_by_region() -> DataTableTransform {
+        DataTableTransform::GroupBy {
+            table: "Sales".into(),
+            group_by: vec!["region".into()],
+            values: vec!["units".into()],
+            aggregate: DataTableAggregate::Sum,
+        }
+    }
+
+    #[test]
+    fn test_data_table_transform_static() {
+        let mut gc = test_create_gc();
+        let sheet_id = first_sheet_id(&gc);
+        sales(&mut gc, sheet_id);
+
+        let sheet_pos = SheetPos::new(sheet_id, 5, 1);
+        gc.data_table_transform(sheet_pos, group_by_region(), false, None, None)
+            .unwrap();
+
+        let data_table = gc.data_table(sheet_pos).unwrap();
+        assert!(matches!(data_table.kind, DataTableKind::Import(_)));
+        assert_eq!(data_table.name.to_display(), "Sales_grouped");
+        assert!(data_table.header_is_first_row);
+        assert_data_table_row(data_table, 1, vec!["East", "13"]);
+        assert_data_table_row(data_table, 2, vec!["West", "5"]);
+
+        // a static table does not change with its source
+        gc.set_cell_value(SheetPos::new(sheet_id, 2, 3), "100".into(), None);
+        assert_data_table_row(gc.data_table(sheet_pos).unwrap(), 1, vec!["East", "13"]);
+
+        gc.undo(None);
+        gc.undo(None);
+        assert!(gc.data_table(sheet_pos).is_none());
+    }
+
+    #[test]
+    fn test_data_table_transform_live() {
+        let mut gc = test_create_gc();
+        let sheet_id = first_sheet_id(&gc);
+        sales(&mut gc, sheet_id);
+
+        let sheet_pos = SheetPos::new(sheet_id, 5, 1);
+        gc.data_table_transform(
+            sheet_pos,
+            group_by_region(),
+            true,
+            Some("Totals".to_string()),
+            None,
+        )
+        .unwrap();
+
+        let data_table = gc.data_table(sheet_pos).unwrap();
+        assert!(matches!(data_table.kind, DataTableKind::CodeRun(_)));
+        assert_eq!(data_table.name.to_display(), "Totals");
+        assert!(data_table.header_is_first_row);
+        assert_data_table_row(data_table, 1, vec!["East", "13"]);
+
+        // a live table recomputes when its source changes
+        gc.set_cell_value(SheetPos::new(sheet_id, 2, 3), "100".into(), None);
+        assert_data_table_row(gc.data_table(sheet_pos).unwrap(), 1, vec!["East", "103"]);
+    }
+
+    #[test]
+    fn test_data_table_transform_join() {
+        let mut gc = test_create_gc();
+        let sheet_id = first_sheet_id(&gc);
+        sales(&mut gc, sheet_id);
+        gc.add_data_table(
+            SheetPos::new(sheet_id, 5, 1),
+            "Managers".to_string(),
+            vec![
+                vec!["region".into(), "manager".into()],
+                vec!["West".into(), "Kim".into()],
+            ],
+            true,
+            None,
+        );
+
+        let sheet_pos = SheetPos::new(sheet_id, 1, 10);
+        let transform = DataTableTransform::Join {
+            left: "Sales".into(),
+            right: "Managers".into(),
+            left_on: vec!["region".into()],
+            right_on: vec!["region".into()],
+            kind: DataTableJoinKind::Left,
+        };
+        gc.data_table_transform(sheet_pos, transform, false, None, None)
+            .unwrap();
+
+        let data_table = gc.data_table(sheet_pos).unwrap();
+        assert_eq!(data_table.name.to_display(), "Sales_Managers");
+        assert_data_table_row(data_table, 0, vec!["region", "units", "manager"]);
+        assert_data_table_row(data_table, 1, vec!["East", "10"]);
+        assert_data_table_row(data_table, 2, vec!["West", "5", "Kim"]);
+    }
+
+    #[test]
+    fn test_data_table_transform_errors() {
+        let mut gc = test_create_gc();
+        let sheet_id = first_sheet_id(&gc);
+        sales(&mut gc, sheet_id);
+        let sheet_pos = SheetPos::new(sheet_id, 5, 1);
+
+        let missing_table = DataTableTransform::Pivot {
+            table: "Missing".into(),
+            rows: vec![],
+            column: "region".into(),
+            value: "units".into(),
+            aggregate: DataTableAggregate::Sum,
+        };
+        assert!(
+            gc.data_table_transform(sheet_pos, missing_table, false, None, None)
+                .is_err()
+        );
+
+        let missing_column = DataTableTransform::GroupBy {
+            table: "Sales".into(),
+            group_by: vec!["country".into()],
+            values: vec!["units".into()],
+            aggregate: DataTableAggregate::Sum,
+        };
+        assert!(
+            gc.data_table_transform(sheet_pos, missing_column, false, None, None)
+                .is_err()
+        );
+        assert_eq!(
+            gc.sheet(sheet_id).cell_value(Pos { x: 5, y: 1 }),
+            None::<CellValue>
+        );
+    }
+}
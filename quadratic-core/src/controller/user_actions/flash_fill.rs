@@ -0,0 +1,104 @@
+use crate::controller::GridController;
+use crate::controller::active_transactions::transaction_name::TransactionName;
+use crate::{Rect, grid::SheetId};
+use anyhow::Result;
+
+impl GridController {
+    /// Fills a column by learning a pattern from the examples in it.
+    ///
+    /// range: a single column that contains one or more examples next to the
+    /// source data
+    ///
+    /// cursor: the cursor position for the undo/redo stack
+    pub fn flash_fill(
+        &mut self,
+        sheet_id: SheetId,
+        range: Rect,
+        cursor: Option<String>,
+    ) -> Result<()> {
+        let ops = self.flash_fill_operations(sheet_id, range)?;
+        if !ops.is_empty() {
+            self.start_user_transaction(ops, cursor, TransactionName::FlashFill);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{SheetPos, test_util::*};
+
+    fn set_values(gc: &mut GridController, values: Vec<Vec<&str>>) {
+        let values = values
+            .into_iter()
+            .map(|row| row.into_iter().map(|v| v.to_string()).collect())
+            .collect();
+        gc.set_cell_values(SheetPos::new(SheetId::TEST, 1, 1), values, None);
+    }
+
+    #[test]
+    fn test_flash_fill() {
+        let mut gc = GridController::test();
+        set_values(
+            &mut gc,
+            vec![
+                vec!["jane@example.com", "example.com"],
+                vec!["bob@quadratic.co", ""],
+                vec!["", ""],
+                vec!["sam@test.org", ""],
+            ],
+        );
+
+        // the range is extended to the end of the source data
+        gc.flash_fill(SheetId::TEST, Rect::test_a1("B1:B2"), None)
+            .unwrap();
+        assert_cell_value_col(
+            &gc,
+            SheetId::TEST,
+            2,
+            1,
+            4,
+            vec!["example.com", "quadratic.co", "", "test.org"],
+        );
+
+        gc.undo(None);
+        assert_cell_value_col(&gc, SheetId::TEST, 2, 1, 4, vec!["example.com", "", "", ""]);
+    }
+
+    #[test]
+    fn test_flash_fill_two_sources() {
+        let mut gc = GridController::test();
+        set_values(
+            &mut gc,
+            vec![
+                vec!["jane", "smith", "Smith, J."],
+                vec!["john", "doe", "Doe, J."],
+                vec!["sam", "roe", ""],
+            ],
+        );
+
+        gc.flash_fill(SheetId::TEST, Rect::test_a1("C1:C3"), None)
+            .unwrap();
+        assert_display_cell_value(&gc, SheetId::TEST, 3, 3, "Roe, S.");
+    }
+
+    #[test]
+    fn test_flash_fill_errors() {
+        let mut gc = GridController::test();
+        assert!(
+            gc.flash_fill(SheetId::TEST, Rect::test_a1("B1:B3"), None)
+                .is_err()
+        );
+
+        set_values(&mut gc, vec![vec!["", "example"]]);
+        assert!(
+            gc.flash_fill(SheetId::TEST, Rect::test_a1("B1:B3"), None)
+                .is_err()
+        );
+        assert!(
+            gc.flash_fill(SheetId::TEST, Rect::test_a1("A1:B3"), None)
+                .is_err()
+        );
+    }
+}
@@ -10,7 +10,9 @@ use crate::controller::GridController;
 use crate::controller::active_transactions::transaction_name::TransactionName;
 use crate::controller::operations::operation::Operation;
 use crate::grid::formats::{FormatUpdate, SheetFormatUpdates};
+use crate::grid::formatting::VERTICAL_TEXT;
 use crate::grid::{CellAlign, CellVerticalAlign, CellWrap, NumericFormat, NumericFormatKind};
+use crate::values::format_code::FormatCode;
 
 impl GridController {
     pub(crate) fn clear_format_borders(&mut self, selection: &A1Selection, cursor: Option<String>) {
@@ -320,6 +322,18 @@ impl GridController {
         Ok(())
     }
 
+    /// Changes the Selection to use an Excel number format code (eg,
+    /// `#,##0.00;[Red](#,##0.00)`). Returns an error if the code is invalid.
+    pub(crate) fn set_custom_number_format(
+        &mut self,
+        selection: &A1Selection,
+        code: String,
+        cursor: Option<String>,
+    ) -> Result<(), JsValue> {
+        FormatCode::parse(&code)?;
+        self.set_numeric_format(selection, NumericFormatKind::Custom, Some(code), cursor)
+    }
+
     pub(crate) fn set_commas(
         &mut self,
         selection: &A1Selection,
@@ -480,6 +494,78 @@ impl GridController {
         Ok(())
     }
 
+    /// Sets the font size in points; `None` resets it to the default.
+    pub(crate) fn set_font_size(
+        &mut self,
+        selection: &A1Selection,
+        font_size: Option<i16>,
+        cursor: Option<String>,
+    ) -> Result<(), JsValue> {
+        if font_size.is_some_and(|font_size| font_size <= 0) {
+            return Err(JsValue::from_str("Font size must be positive"));
+        }
+        let format_update = FormatUpdate {
+            font_size: Some(font_size),
+            ..Default::default()
+        };
+        let ops = self.format_ops(selection, format_update);
+        self.start_user_transaction(ops, cursor, TransactionName::SetFormats);
+        Ok(())
+    }
+
+    pub(crate) fn set_font_family(
+        &mut self,
+        selection: &A1Selection,
+        font_family: Option<String>,
+        cursor: Option<String>,
+    ) -> Result<(), JsValue> {
+        let format_update = FormatUpdate {
+            font_family: Some(font_family),
+            ..Default::default()
+        };
+        let ops = self.format_ops(selection, format_update);
+        self.start_user_transaction(ops, cursor, TransactionName::SetFormats);
+        Ok(())
+    }
+
+    /// Sets the text rotation in degrees (-90 to 90) or [`VERTICAL_TEXT`].
+    pub(crate) fn set_text_rotation(
+        &mut self,
+        selection: &A1Selection,
+        text_rotation: Option<i16>,
+        cursor: Option<String>,
+    ) -> Result<(), JsValue> {
+        if text_rotation
+            .is_some_and(|rotation| rotation != VERTICAL_TEXT && !(-90..=90).contains(&rotation))
+        {
+            return Err(JsValue::from_str(
+                "Text rotation must be between -90 and 90 degrees",
+            ));
+        }
+        let format_update = FormatUpdate {
+            text_rotation: Some(text_rotation),
+            ..Default::default()
+        };
+        let ops = self.format_ops(selection, format_update);
+        self.start_user_transaction(ops, cursor, TransactionName::SetFormats);
+        Ok(())
+    }
+
+    pub(crate) fn set_indent(
+        &mut self,
+        selection: &A1Selection,
+        indent: Option<u8>,
+        cursor: Option<String>,
+    ) -> Result<(), JsValue> {
+        let format_update = FormatUpdate {
+            indent: Some(indent),
+            ..Default::default()
+        };
+        let ops = self.format_ops(selection, format_update);
+        self.start_user_transaction(ops, cursor, TransactionName::SetFormats);
+        Ok(())
+    }
+
     pub(crate) fn set_formats(&mut self, selection: &A1Selection, format_update: FormatUpdate) {
         let ops = self.format_ops(selection, format_update);
         self.start_user_transaction(ops, None, TransactionName::SetFormats);
@@ -494,8 +580,9 @@ mod test {
     use crate::controller::operations::operation::Operation;
     use crate::grid::CellWrap;
     use crate::grid::formats::{FormatUpdate, SheetFormatUpdates};
-    use crate::{Pos, a1::A1Selection};
+    use crate::grid::formatting::VERTICAL_TEXT;
     use crate::test_util::*;
+    use crate::{Pos, a1::A1Selection};
 
     #[test]
     fn test_set_align_selection() {
@@ -635,6 +722,36 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_set_custom_number_format() {
+        let mut gc = GridController::test();
+        let sheet_id = gc.sheet_ids()[0];
+        gc.set_cell_value(pos![sheet_id!A1], "-1234.5".into(), None);
+        gc.set_cell_value(pos![sheet_id!A2], "1234.5".into(), None);
+        gc.set_custom_number_format(
+            &A1Selection::test_a1("A1:A2"),
+            "* #,##0.00;[Red]* (#,##0.00)".into(),
+            None,
+        )
+        .unwrap();
+
+        let sheet = gc.sheet(sheet_id);
+        assert_eq!(sheet.rendered_value(pos![A1]).unwrap(), "(1,234.50)");
+        let cells = sheet.get_render_cells(crate::Rect::new(1, 1, 1, 2), gc.a1_context());
+        assert_eq!(cells[0].value, "(1,234.50)");
+        assert_eq!(cells[0].text_color, Some("#ff0000".to_string()));
+        assert_eq!(cells[0].number, None);
+        assert_eq!(
+            cells[0].number_fill,
+            Some(crate::grid::js_types::JsNumberFill {
+                index: 0,
+                char: " ".to_string()
+            })
+        );
+        assert_eq!(cells[1].value, "1,234.50");
+        assert_eq!(cells[1].text_color, None);
+    }
+
     #[test]
     fn test_toggle_commas_selection() {
         let mut gc = GridController::test();
@@ -768,6 +885,32 @@ mod test {
         assert_eq!(sheet.formats.strike_through.get(pos![A2]), Some(true));
     }
 
+    #[test]
+    fn test_set_font_formats() {
+        let mut gc = GridController::test();
+        let sheet_id = gc.sheet_ids()[0];
+        gc.set_cell_value(pos![sheet_id!A1], "hello".to_string(), None);
+        let selection = A1Selection::test_a1("A1:B2");
+        gc.set_font_size(&selection, Some(18), None).unwrap();
+        gc.set_font_family(&selection, Some("Georgia".to_string()), None)
+            .unwrap();
+        gc.set_text_rotation(&selection, Some(VERTICAL_TEXT), None)
+            .unwrap();
+        gc.set_indent(&selection, Some(2), None).unwrap();
+
+        let format = gc.sheet(sheet_id).cell_format(pos![B2]);
+        assert_eq!(format.font_size, Some(18));
+        assert_eq!(format.font_family, Some("Georgia".to_string()));
+        assert_eq!(format.text_rotation, Some(VERTICAL_TEXT));
+        assert_eq!(format.indent, Some(2));
+
+        gc.set_font_size(&selection, None, None).unwrap();
+        assert_eq!(gc.sheet(sheet_id).formats.font_size.get(pos![A1]), None);
+
+        gc.undo(None);
+        assert_eq!(gc.sheet(sheet_id).formats.font_size.get(pos![A1]), Some(18));
+    }
+
     #[test]
     fn test_clear_format() {
         let mut gc = GridController::test();
@@ -89,8 +89,8 @@ pub(crate) mod tests {
     use std::str::FromStr;
 
     use crate::{
-        CellValue, Rect, RunError, RunErrorMsg, Span,
-        grid::{CodeCellLanguage, CodeCellValue},
+        CellValue, Hyperlink, Rect, RichText, RunError, RunErrorMsg, Span, TextSpan,
+        grid::{CodeCellLanguage, CodeCellValue, sheet::outlines::OutlineGroup},
         test_util::{assert_cell_value_row, print_table_at, print_table_in_rect},
         wasm_bindings::js::clear_js_calls,
     };
@@ -233,6 +233,134 @@ pub(crate) mod tests {
         serde_json::to_string(op).unwrap();
     }
 
+    #[test]
+    fn import_excel_merged_cells() {
+        let mut gc = GridController::new_blank();
+        let file: Vec<u8> =
+            std::fs::read("../quadratic-rust-shared/data/excel/merged_cells.xlsx").unwrap();
+        gc.import_excel(&file, "merged_cells.xlsx", None).unwrap();
+
+        let sheet = &gc.grid.sheets()[0];
+        assert_eq!(
+            sheet.merge_cells.rects(),
+            &[Rect::test_a1("A3:C4"), Rect::test_a1("E3:E5")]
+        );
+    }
+
+    #[test]
+    fn import_excel_hidden_columns_rows() {
+        let mut gc = GridController::new_blank();
+        let file: Vec<u8> =
+            std::fs::read("../quadratic-rust-shared/data/excel/hidden_columns_rows.xlsx").unwrap();
+        gc.import_excel(&file, "hidden_columns_rows.xlsx", None)
+            .unwrap();
+
+        let sheet = &gc.grid.sheets()[0];
+        assert_eq!(sheet.offsets.hidden_columns(), vec![3]);
+        assert_eq!(sheet.offsets.hidden_rows(), vec![2]);
+
+        // the second sheet has nothing hidden
+        let sheet = &gc.grid.sheets()[1];
+        assert!(sheet.offsets.hidden_columns().is_empty());
+    }
+
+    #[test]
+    fn import_excel_outlines() {
+        let mut gc = GridController::new_blank();
+        let file: Vec<u8> =
+            std::fs::read("../quadratic-rust-shared/data/excel/outlines.xlsx").unwrap();
+        gc.import_excel(&file, "outlines.xlsx", None).unwrap();
+
+        let sheet = &gc.grid.sheets()[0];
+        assert_eq!(
+            sheet.outlines.columns.groups(),
+            vec![OutlineGroup {
+                start: 4,
+                end: 5,
+                level: 1,
+                collapsed: false,
+            }]
+        );
+        assert_eq!(
+            sheet.outlines.rows.groups(),
+            vec![OutlineGroup {
+                start: 2,
+                end: 2,
+                level: 1,
+                collapsed: true,
+            }]
+        );
+        assert_eq!(sheet.offsets.hidden_rows(), vec![2]);
+        assert!(gc.grid.sheets()[1].outlines.rows.is_empty());
+    }
+
+    #[test]
+    fn import_excel_frozen_panes() {
+        let mut gc = GridController::new_blank();
+        let file: Vec<u8> =
+            std::fs::read("../quadratic-rust-shared/data/excel/frozen_panes.xlsx").unwrap();
+        gc.import_excel(&file, "frozen_panes.xlsx", None).unwrap();
+
+        let sheet = &gc.grid.sheets()[0];
+        assert_eq!((sheet.frozen_columns, sheet.frozen_rows), (1, 1));
+        let sheet = &gc.grid.sheets()[1];
+        assert_eq!((sheet.frozen_columns, sheet.frozen_rows), (0, 0));
+    }
+
+    #[test]
+    fn import_excel_rich_text() {
+        let mut gc = GridController::new_blank();
+        let file: Vec<u8> =
+            std::fs::read("../quadratic-rust-shared/data/excel/rich_text.xlsx").unwrap();
+        gc.import_excel(&file, "rich_text.xlsx", None).unwrap();
+
+        let sheet = &gc.grid.sheets()[0];
+        assert_eq!(
+            sheet.cell_value(pos![B2]),
+            Some(CellValue::RichText(RichText::new([
+                TextSpan {
+                    bold: Some(true),
+                    text_color: Some("#FF0000".to_string()),
+                    ..TextSpan::new("Hel")
+                },
+                TextSpan::new("lo"),
+            ])))
+        );
+        assert_eq!(
+            sheet.cell_value(pos![B1]),
+            Some(CellValue::Text("String".into()))
+        );
+    }
+
+    #[test]
+    fn import_excel_hyperlinks() {
+        let mut gc = GridController::new_blank();
+        let file: Vec<u8> =
+            std::fs::read("../quadratic-rust-shared/data/excel/hyperlinks.xlsx").unwrap();
+        gc.import_excel(&file, "hyperlinks.xlsx", None).unwrap();
+
+        let sheet = &gc.grid.sheets()[0];
+        assert_eq!(
+            sheet.cell_value(pos![B2]),
+            Some(CellValue::Hyperlink(Box::new(
+                Hyperlink::new("https://example.com", "Hello").unwrap()
+            )))
+        );
+        // linked numbers keep their value
+        let link = Hyperlink::new("#'My Second Sheet'!A1", "")
+            .unwrap()
+            .attach_to(CellValue::Number(BigDecimal::from_str("1.1").unwrap()));
+        assert_eq!(link.display(), "1.1");
+        assert_eq!(
+            sheet.cell_value(pos![E2]),
+            Some(CellValue::Hyperlink(Box::new(link)))
+        );
+        assert!(!matches!(
+            sheet.cell_value(pos![C2]),
+            Some(CellValue::Hyperlink(_))
+        ));
+    }
+
     #[test]
     fn imports_a_simple_excel_file() {
         let mut gc = GridController::new_blank();
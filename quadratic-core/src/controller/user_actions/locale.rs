@@ -0,0 +1,224 @@
+use crate::controller::GridController;
+use crate::controller::active_transactions::transaction_name::TransactionName;
+use crate::date_time::DateSettings;
+use crate::grid::Locale;
+use anyhow::Result;
+use chrono::{Local, NaiveDateTime, Utc};
+use chrono_tz::Tz;
+
+impl GridController {
+    /// Returns the file's locale.
+    pub fn locale(&self) -> &Locale {
+        &self.grid.locale
+    }
+
+    /// Replaces the file's locale, which is used to parse typed values and
+    /// display dates and numbers.
+    pub fn set_locale(&mut self, locale: Locale, cursor: Option<String>) -> Result<()> {
+        let ops = self.set_locale_operations(locale)?;
+        self.start_user_transaction(ops, cursor, TransactionName::SetLocale);
+        Ok(())
+    }
+
+    /// Returns the file's time zone, or `None` if each user's local time zone
+    /// is used.
+    pub fn time_zone(&self) -> Option<Tz> {
+        self.grid.time_zone
+    }
+
+    /// Returns the current date and time in the file's time zone, or in the
+    /// user's local time zone if the file has none.
+    pub fn now(&self) -> NaiveDateTime {
+        match self.grid.time_zone {
+            Some(time_zone) => Utc::now().with_timezone(&time_zone).naive_local(),
+            None => Local::now().naive_local(),
+        }
+    }
+
+    /// Replaces the file's time zone using an IANA name (eg, `Europe/Berlin`).
+    /// An empty name uses each user's local time zone.
+    pub fn set_time_zone(&mut self, time_zone: &str, cursor: Option<String>) -> Result<()> {
+        let ops = self.set_time_zone_operations(time_zone)?;
+        self.start_user_transaction(ops, cursor, TransactionName::SetTimeZone);
+        Ok(())
+    }
+
+    /// Returns the file's date system and serial date settings.
+    pub fn date_settings(&self) -> DateSettings {
+        self.grid.date_settings
+    }
+
+    /// Replaces the file's date system and serial date settings.
+    pub fn set_date_settings(&mut self, date_settings: DateSettings, cursor: Option<String>) {
+        let ops = self.set_date_settings_operations(date_settings);
+        self.start_user_transaction(ops, cursor, TransactionName::SetDateSettings);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use bigdecimal::BigDecimal;
+    use chrono::{NaiveDate, NaiveTime};
+
+    use super::*;
+    use crate::{
+        CellValue,
+        grid::{NumericFormat, NumericFormatKind},
+        test_util::*,
+    };
+
+    fn de() -> Locale {
+        Locale::preset("de-DE").unwrap()
+    }
+
+    #[test]
+    fn test_set_locale() {
+        let mut gc = test_create_gc();
+        gc.set_locale(de(), None).unwrap();
+        assert_eq!(gc.locale(), &de());
+
+        gc.undo(None);
+        assert_eq!(gc.locale(), &Locale::default());
+        gc.redo(None);
+        assert_eq!(gc.locale().name, "de-DE");
+
+        let invalid = Locale {
+            argument_separator: ',',
+            ..de()
+        };
+        assert!(gc.set_locale(invalid, None).is_err());
+        let invalid = Locale {
+            group_separator: ',',
+            ..de()
+        };
+        assert!(gc.set_locale(invalid, None).is_err());
+    }
+
+    #[test]
+    fn test_set_time_zone() {
+        let mut gc = test_create_gc();
+        assert_eq!(gc.time_zone(), None);
+
+        gc.set_time_zone("America/New_York", None).unwrap();
+        assert_eq!(gc.time_zone(), Some(Tz::America__New_York));
+
+        gc.undo(None);
+        assert_eq!(gc.time_zone(), None);
+        gc.redo(None);
+        assert_eq!(gc.time_zone(), Some(Tz::America__New_York));
+
+        assert!(gc.set_time_zone("Not/A_Zone", None).is_err());
+        assert_eq!(gc.time_zone(), Some(Tz::America__New_York));
+
+        gc.set_time_zone("", None).unwrap();
+        assert_eq!(gc.time_zone(), None);
+    }
+
+    #[test]
+    fn test_now() {
+        let mut gc = test_create_gc();
+        let local = Local::now().naive_local();
+        assert!((gc.now() - local).num_seconds().abs() < 5);
+
+        gc.set_time_zone("Asia/Tokyo", None).unwrap();
+        let tokyo = Utc::now().with_timezone(&Tz::Asia__Tokyo).naive_local();
+        assert!((gc.now() - tokyo).num_seconds().abs() < 5);
+    }
+
+    #[test]
+    fn test_set_date_settings() {
+        let mut gc = test_create_gc();
+        assert_eq!(gc.date_settings(), DateSettings::default());
+
+        let date_settings = DateSettings {
+            date_system: crate::date_time::DateSystem::Excel1904,
+            serial_dates: true,
+        };
+        gc.set_date_settings(date_settings, None);
+        assert_eq!(gc.date_settings(), date_settings);
+
+        gc.undo(None);
+        assert_eq!(gc.date_settings(), DateSettings::default());
+        gc.redo(None);
+        assert_eq!(gc.date_settings(), date_settings);
+    }
+
+    #[test]
+    fn test_locale_parsing() {
+        let mut gc = test_create_gc();
+        let sheet_id = first_sheet_id(&gc);
+        gc.set_locale(de(), None).unwrap();
+
+        gc.set_cell_value(pos![sheet_id!A1], "31/12/2024".into(), None);
+        gc.set_cell_value(pos![sheet_id!A2], "01.02.2024 14:30".into(), None);
+        gc.set_cell_value(pos![sheet_id!A3], "1.234,56".into(), None);
+        gc.set_cell_value(pos![sheet_id!A4], "-12,5 €".into(), None);
+        gc.set_cell_value(pos![sheet_id!A5], "1.5".into(), None);
+        gc.set_cell_value(pos![sheet_id!A6], "=SUM(1,5;2)".into(), None);
+
+        let sheet = gc.sheet(sheet_id);
+        assert_eq!(
+            sheet.cell_value(pos![A1]),
+            Some(CellValue::Date(
+                NaiveDate::from_ymd_opt(2024, 12, 31).unwrap()
+            ))
+        );
+        assert_eq!(
+            sheet.cell_value(pos![A2]),
+            Some(CellValue::DateTime(
+                NaiveDate::from_ymd_opt(2024, 2, 1)
+                    .unwrap()
+                    .and_time(NaiveTime::from_hms_opt(14, 30, 0).unwrap())
+            ))
+        );
+        assert_eq!(
+            sheet.cell_value(pos![A3]),
+            Some(CellValue::Number(BigDecimal::from_str("1234.56").unwrap()))
+        );
+        assert_eq!(sheet.formats.numeric_commas.get(pos![A3]), Some(true));
+        assert_eq!(
+            sheet.cell_value(pos![A4]),
+            Some(CellValue::Number(BigDecimal::from_str("-12.5").unwrap()))
+        );
+        assert_eq!(
+            sheet.formats.numeric_format.get(pos![A4]),
+            Some(NumericFormat {
+                kind: NumericFormatKind::Currency,
+                symbol: Some("€".to_string()),
+            })
+        );
+        assert_eq!(
+            sheet.cell_value(pos![A5]),
+            Some(CellValue::Text("1.5".into()))
+        );
+        assert_eq!(
+            sheet
+                .edit_code_value(pos![A6], gc.a1_context())
+                .unwrap()
+                .code_string,
+            "SUM(1.5,2)"
+        );
+        assert_eq!(
+            sheet.display_value(pos![A6]),
+            Some(CellValue::Number(BigDecimal::from_str("3.5").unwrap()))
+        );
+
+        // values are displayed and edited using the locale
+        assert_eq!(
+            sheet
+                .cell_value(pos![A3])
+                .unwrap()
+                .to_edit_with_locale(gc.locale()),
+            "1234,56"
+        );
+        assert_eq!(
+            sheet
+                .cell_value(pos![A1])
+                .unwrap()
+                .to_edit_with_locale(gc.locale()),
+            "31.12.2024"
+        );
+    }
+}
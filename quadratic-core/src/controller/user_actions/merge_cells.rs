@@ -0,0 +1,149 @@
+use crate::a1::A1Selection;
+use crate::controller::GridController;
+use crate::controller::active_transactions::transaction_name::TransactionName;
+use anyhow::Result;
+
+impl GridController {
+    /// Merges each range of the selection into a single cell.
+    pub fn merge_cells(&mut self, selection: &A1Selection, cursor: Option<String>) -> Result<()> {
+        let ops = self.merge_cells_operations(selection)?;
+        if !ops.is_empty() {
+            self.start_user_transaction(ops, cursor, TransactionName::MergeCells);
+        }
+        Ok(())
+    }
+
+    /// Unmerges all merged cells that intersect the selection.
+    pub fn unmerge_cells(&mut self, selection: &A1Selection, cursor: Option<String>) -> Result<()> {
+        let ops = self.unmerge_cells_operations(selection)?;
+        if !ops.is_empty() {
+            self.start_user_transaction(ops, cursor, TransactionName::MergeCells);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Rect, SheetPos, a1::A1Selection, controller::GridController, grid::SheetId, test_util::*,
+    };
+
+    fn merged(gc: &GridController) -> Vec<Rect> {
+        gc.sheet(SheetId::TEST).merge_cells.rects().to_vec()
+    }
+
+    #[test]
+    fn test_merge_cells() {
+        let mut gc = GridController::test();
+        gc.set_cell_values(
+            SheetPos::new(SheetId::TEST, 1, 1),
+            vec![
+                vec!["a".into(), "b".into(), "c".into()],
+                vec!["d".into(), "e".into(), "f".into()],
+            ],
+            None,
+        );
+
+        gc.merge_cells(&A1Selection::test_a1("A1:B2"), None)
+            .unwrap();
+        assert_eq!(merged(&gc), vec![Rect::test_a1("A1:B2")]);
+        assert_cell_value_col(&gc, SheetId::TEST, 1, 1, 2, vec!["a", ""]);
+        assert_cell_value_col(&gc, SheetId::TEST, 2, 1, 2, vec!["", ""]);
+        assert_cell_value_col(&gc, SheetId::TEST, 3, 1, 2, vec!["c", "f"]);
+
+        // merging over a merged cell replaces it
+        gc.merge_cells(&A1Selection::test_a1("B1:C1"), None)
+            .unwrap();
+        assert_eq!(merged(&gc), vec![Rect::test_a1("B1:C1")]);
+
+        gc.undo(None);
+        assert_eq!(merged(&gc), vec![Rect::test_a1("A1:B2")]);
+
+        gc.undo(None);
+        assert!(merged(&gc).is_empty());
+        assert_cell_value_col(&gc, SheetId::TEST, 2, 1, 2, vec!["b", "e"]);
+
+        gc.redo(None);
+        assert_eq!(merged(&gc), vec![Rect::test_a1("A1:B2")]);
+        assert_cell_value_col(&gc, SheetId::TEST, 2, 1, 2, vec!["", ""]);
+    }
+
+    #[test]
+    fn test_merge_cells_errors() {
+        let mut gc = GridController::test();
+        assert!(gc.merge_cells(&A1Selection::test_a1("A:B"), None).is_err());
+
+        gc.add_data_table(
+            SheetPos::new(SheetId::TEST, 2, 2),
+            "Table".to_string(),
+            vec![vec!["a".into()], vec!["b".into()]],
+            true,
+            None,
+        );
+        assert!(
+            gc.merge_cells(&A1Selection::test_a1("A1:B2"), None)
+                .is_err()
+        );
+
+        // a single cell formula at the anchor is kept
+        gc.set_cell_value(SheetPos::new(SheetId::TEST, 5, 1), "=1+1".into(), None);
+        gc.merge_cells(&A1Selection::test_a1("E1:F2"), None)
+            .unwrap();
+        assert_eq!(merged(&gc), vec![Rect::test_a1("E1:F2")]);
+        assert_display_cell_value(&gc, SheetId::TEST, 5, 1, "2");
+    }
+
+    #[test]
+    fn test_unmerge_cells() {
+        let mut gc = GridController::test();
+        gc.merge_cells(&A1Selection::test_a1("A1:B2"), None)
+            .unwrap();
+        gc.merge_cells(&A1Selection::test_a1("D1:E2"), None)
+            .unwrap();
+
+        gc.unmerge_cells(&A1Selection::test_a1("B2"), None).unwrap();
+        assert_eq!(merged(&gc), vec![Rect::test_a1("D1:E2")]);
+
+        gc.undo(None);
+        assert_eq!(merged(&gc).len(), 2);
+    }
+
+    #[test]
+    fn test_merge_cells_insert_delete() {
+        let mut gc = GridController::test();
+        gc.merge_cells(&A1Selection::test_a1("B2:C3"), None)
+            .unwrap();
+
+        gc.insert_columns(SheetId::TEST, 3, 1, true, None);
+        assert_eq!(merged(&gc), vec![Rect::test_a1("B2:D3")]);
+        gc.insert_rows(SheetId::TEST, 1, 1, true, None);
+        assert_eq!(merged(&gc), vec![Rect::test_a1("B3:D4")]);
+
+        gc.delete_columns(SheetId::TEST, vec![2, 3], None);
+        assert_eq!(merged(&gc), vec![Rect::test_a1("B3:B4")]);
+        gc.delete_rows(SheetId::TEST, vec![3], None);
+        assert!(merged(&gc).is_empty());
+
+        gc.undo(None);
+        assert_eq!(merged(&gc), vec![Rect::test_a1("B3:B4")]);
+        gc.undo(None);
+        assert_eq!(merged(&gc), vec![Rect::test_a1("B3:D4")]);
+        gc.undo(None);
+        assert_eq!(merged(&gc), vec![Rect::test_a1("B2:D3")]);
+    }
+
+    #[test]
+    fn test_merge_cells_file() {
+        let mut gc = GridController::test();
+        gc.merge_cells(&A1Selection::test_a1("A1:C1"), None)
+            .unwrap();
+
+        let file = crate::grid::file::export(gc.grid().clone()).unwrap();
+        let grid = crate::grid::file::import(file).unwrap();
+        assert_eq!(
+            grid.sheets()[0].merge_cells.rects(),
+            &[Rect::test_a1("A1:C1")]
+        );
+    }
+}
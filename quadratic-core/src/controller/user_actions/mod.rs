@@ -2,15 +2,27 @@
 ///
 pub mod auto_complete;
 pub mod borders;
+pub mod cell_styles;
 pub mod cells;
 pub mod clipboard;
 pub mod code;
 pub mod col_row;
+pub mod comments;
+pub mod conditional_formats;
+pub mod custom_lists;
+pub mod data_cleaning;
 pub mod data_table;
+pub mod data_table_transform;
+pub mod flash_fill;
 pub mod formats;
 pub mod import;
+pub mod locale;
+pub mod merge_cells;
 mod move_col_row;
 mod offsets;
+pub mod outlines;
+pub mod protection;
 pub mod sheets;
+pub mod text_to_columns;
 pub mod undo;
 pub mod validations;
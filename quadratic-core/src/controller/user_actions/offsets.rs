@@ -45,4 +45,64 @@ impl GridController {
         let ops = vec![Operation::DefaultRowSize { sheet_id, size }];
         self.start_user_transaction(ops, cursor, TransactionName::ResizeRows);
     }
+
+    /// Hides columns. Hidden columns keep their width.
+    pub fn hide_columns(&mut self, sheet_id: SheetId, columns: Vec<i64>, cursor: Option<String>) {
+        self.set_columns_hidden(sheet_id, columns, true, cursor);
+    }
+
+    /// Unhides columns.
+    pub fn unhide_columns(&mut self, sheet_id: SheetId, columns: Vec<i64>, cursor: Option<String>) {
+        self.set_columns_hidden(sheet_id, columns, false, cursor);
+    }
+
+    /// Hides rows. Hidden rows keep their height.
+    pub fn hide_rows(&mut self, sheet_id: SheetId, rows: Vec<i64>, cursor: Option<String>) {
+        self.set_rows_hidden(sheet_id, rows, true, cursor);
+    }
+
+    /// Unhides rows.
+    pub fn unhide_rows(&mut self, sheet_id: SheetId, rows: Vec<i64>, cursor: Option<String>) {
+        self.set_rows_hidden(sheet_id, rows, false, cursor);
+    }
+
+    fn set_columns_hidden(
+        &mut self,
+        sheet_id: SheetId,
+        mut columns: Vec<i64>,
+        hidden: bool,
+        cursor: Option<String>,
+    ) {
+        columns.sort_unstable();
+        columns.dedup();
+        if columns.is_empty() {
+            return;
+        }
+        let ops = vec![Operation::SetColumnsHidden {
+            sheet_id,
+            columns,
+            hidden,
+        }];
+        self.start_user_transaction(ops, cursor, TransactionName::HideColumnsRows);
+    }
+
+    fn set_rows_hidden(
+        &mut self,
+        sheet_id: SheetId,
+        mut rows: Vec<i64>,
+        hidden: bool,
+        cursor: Option<String>,
+    ) {
+        rows.sort_unstable();
+        rows.dedup();
+        if rows.is_empty() {
+            return;
+        }
+        let ops = vec![Operation::SetRowsHidden {
+            sheet_id,
+            rows,
+            hidden,
+        }];
+        self.start_user_transaction(ops, cursor, TransactionName::HideColumnsRows);
+    }
 }
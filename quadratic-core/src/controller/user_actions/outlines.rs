@@ -0,0 +1,241 @@
+use crate::Axis;
+use crate::controller::GridController;
+use crate::controller::active_transactions::transaction_name::TransactionName;
+use crate::grid::SheetId;
+use anyhow::Result;
+
+impl GridController {
+    /// Groups the columns (X) or rows (Y) from start to end, adding one
+    /// outline level.
+    pub fn group_outline(
+        &mut self,
+        sheet_id: SheetId,
+        axis: Axis,
+        start: i64,
+        end: i64,
+        cursor: Option<String>,
+    ) -> Result<()> {
+        let ops = self.group_operations(sheet_id, axis, start, end, true)?;
+        if !ops.is_empty() {
+            self.start_user_transaction(ops, cursor, TransactionName::GroupColumnsRows);
+        }
+        Ok(())
+    }
+
+    /// Ungroups the columns (X) or rows (Y) from start to end, removing one
+    /// outline level.
+    pub fn ungroup_outline(
+        &mut self,
+        sheet_id: SheetId,
+        axis: Axis,
+        start: i64,
+        end: i64,
+        cursor: Option<String>,
+    ) -> Result<()> {
+        let ops = self.group_operations(sheet_id, axis, start, end, false)?;
+        if !ops.is_empty() {
+            self.start_user_transaction(ops, cursor, TransactionName::GroupColumnsRows);
+        }
+        Ok(())
+    }
+
+    /// Collapses a group, hiding its columns or rows.
+    pub fn collapse_outline_group(
+        &mut self,
+        sheet_id: SheetId,
+        axis: Axis,
+        start: i64,
+        level: u8,
+        cursor: Option<String>,
+    ) -> Result<()> {
+        let ops = self.collapse_group_operations(sheet_id, axis, start, level, true)?;
+        if !ops.is_empty() {
+            self.start_user_transaction(ops, cursor, TransactionName::GroupColumnsRows);
+        }
+        Ok(())
+    }
+
+    /// Expands a group, showing its columns or rows (except those within a
+    /// collapsed nested group).
+    pub fn expand_outline_group(
+        &mut self,
+        sheet_id: SheetId,
+        axis: Axis,
+        start: i64,
+        level: u8,
+        cursor: Option<String>,
+    ) -> Result<()> {
+        let ops = self.collapse_group_operations(sheet_id, axis, start, level, false)?;
+        if !ops.is_empty() {
+            self.start_user_transaction(ops, cursor, TransactionName::GroupColumnsRows);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Axis,
+        controller::GridController,
+        grid::{SheetId, sheet::outlines::OutlineGroup},
+    };
+
+    fn groups(gc: &GridController, axis: Axis) -> Vec<OutlineGroup> {
+        gc.sheet(SheetId::TEST).outlines.get(axis).groups()
+    }
+
+    fn group(start: i64, end: i64, level: u8, collapsed: bool) -> OutlineGroup {
+        OutlineGroup {
+            start,
+            end,
+            level,
+            collapsed,
+        }
+    }
+
+    #[test]
+    fn test_group_ungroup() {
+        let mut gc = GridController::test();
+
+        gc.group_outline(SheetId::TEST, Axis::Y, 2, 6, None)
+            .unwrap();
+        gc.group_outline(SheetId::TEST, Axis::Y, 3, 4, None)
+            .unwrap();
+        assert_eq!(
+            groups(&gc, Axis::Y),
+            vec![group(2, 6, 1, false), group(3, 4, 2, false)]
+        );
+        assert!(groups(&gc, Axis::X).is_empty());
+
+        gc.undo(None);
+        assert_eq!(groups(&gc, Axis::Y), vec![group(2, 6, 1, false)]);
+        gc.redo(None);
+
+        gc.ungroup_outline(SheetId::TEST, Axis::Y, 2, 6, None)
+            .unwrap();
+        assert_eq!(groups(&gc, Axis::Y), vec![group(3, 4, 1, false)]);
+
+        gc.group_outline(SheetId::TEST, Axis::X, 2, 3, None)
+            .unwrap();
+        assert_eq!(groups(&gc, Axis::X), vec![group(2, 3, 1, false)]);
+
+        assert!(
+            gc.group_outline(SheetId::TEST, Axis::X, 3, 2, None)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_collapse_expand() {
+        let mut gc = GridController::test();
+        gc.group_outline(SheetId::TEST, Axis::Y, 2, 6, None)
+            .unwrap();
+        gc.group_outline(SheetId::TEST, Axis::Y, 3, 4, None)
+            .unwrap();
+
+        gc.collapse_outline_group(SheetId::TEST, Axis::Y, 3, 2, None)
+            .unwrap();
+        assert_eq!(gc.sheet(SheetId::TEST).offsets.hidden_rows(), vec![3, 4]);
+
+        gc.collapse_outline_group(SheetId::TEST, Axis::Y, 2, 1, None)
+            .unwrap();
+        assert_eq!(
+            gc.sheet(SheetId::TEST).offsets.hidden_rows(),
+            vec![2, 3, 4, 5, 6]
+        );
+        assert_eq!(
+            groups(&gc, Axis::Y),
+            vec![group(2, 6, 1, true), group(3, 4, 2, true)]
+        );
+
+        // the collapsed nested group stays hidden
+        gc.expand_outline_group(SheetId::TEST, Axis::Y, 2, 1, None)
+            .unwrap();
+        assert_eq!(gc.sheet(SheetId::TEST).offsets.hidden_rows(), vec![3, 4]);
+
+        gc.undo(None);
+        assert_eq!(
+            gc.sheet(SheetId::TEST).offsets.hidden_rows(),
+            vec![2, 3, 4, 5, 6]
+        );
+        gc.undo(None);
+        assert_eq!(gc.sheet(SheetId::TEST).offsets.hidden_rows(), vec![3, 4]);
+        assert_eq!(
+            groups(&gc, Axis::Y),
+            vec![group(2, 6, 1, false), group(3, 4, 2, true)]
+        );
+
+        assert!(
+            gc.collapse_outline_group(SheetId::TEST, Axis::Y, 7, 1, None)
+                .is_err()
+        );
+
+        let file = crate::grid::file::export(gc.grid().clone()).unwrap();
+        let grid = crate::grid::file::import(file).unwrap();
+        assert_eq!(
+            grid.sheets()[0].outlines.get(Axis::Y).groups(),
+            vec![group(2, 6, 1, false), group(3, 4, 2, true)]
+        );
+    }
+
+    #[test]
+    fn test_outline_insert_delete() {
+        let mut gc = GridController::test();
+        gc.group_outline(SheetId::TEST, Axis::X, 2, 3, None)
+            .unwrap();
+
+        gc.insert_columns(SheetId::TEST, 3, 1, true, None);
+        assert_eq!(groups(&gc, Axis::X), vec![group(2, 4, 1, false)]);
+        gc.undo(None);
+        assert_eq!(groups(&gc, Axis::X), vec![group(2, 3, 1, false)]);
+
+        gc.delete_columns(SheetId::TEST, vec![2], None);
+        assert_eq!(groups(&gc, Axis::X), vec![group(2, 2, 1, false)]);
+        gc.undo(None);
+        assert_eq!(groups(&gc, Axis::X), vec![group(2, 3, 1, false)]);
+
+        gc.group_outline(SheetId::TEST, Axis::Y, 3, 4, None)
+            .unwrap();
+        gc.collapse_outline_group(SheetId::TEST, Axis::Y, 3, 1, None)
+            .unwrap();
+        gc.delete_rows(SheetId::TEST, vec![1], None);
+        assert_eq!(groups(&gc, Axis::Y), vec![group(2, 3, 1, true)]);
+        assert_eq!(gc.sheet(SheetId::TEST).offsets.hidden_rows(), vec![2, 3]);
+        gc.undo(None);
+        assert_eq!(groups(&gc, Axis::Y), vec![group(3, 4, 1, true)]);
+        assert_eq!(gc.sheet(SheetId::TEST).offsets.hidden_rows(), vec![3, 4]);
+    }
+
+    #[test]
+    fn test_outline_insert_collapsed() {
+        let mut gc = GridController::test();
+        gc.group_outline(SheetId::TEST, Axis::Y, 3, 4, None)
+            .unwrap();
+        gc.collapse_outline_group(SheetId::TEST, Axis::Y, 3, 1, None)
+            .unwrap();
+
+        // a row inserted within a collapsed group is hidden
+        gc.insert_rows(SheetId::TEST, 4, 1, false, None);
+        assert_eq!(groups(&gc, Axis::Y), vec![group(3, 5, 1, true)]);
+        assert_eq!(gc.sheet(SheetId::TEST).offsets.hidden_rows(), vec![3, 4, 5]);
+        gc.undo(None);
+        assert_eq!(gc.sheet(SheetId::TEST).offsets.hidden_rows(), vec![3, 4]);
+
+        // a row inserted before the group is not
+        gc.insert_rows(SheetId::TEST, 3, 1, false, None);
+        assert_eq!(groups(&gc, Axis::Y), vec![group(4, 5, 1, true)]);
+        assert_eq!(gc.sheet(SheetId::TEST).offsets.hidden_rows(), vec![4, 5]);
+
+        gc.group_outline(SheetId::TEST, Axis::X, 2, 3, None)
+            .unwrap();
+        gc.collapse_outline_group(SheetId::TEST, Axis::X, 2, 1, None)
+            .unwrap();
+        gc.insert_columns(SheetId::TEST, 3, 1, true, None);
+        assert_eq!(groups(&gc, Axis::X), vec![group(2, 4, 1, true)]);
+        assert_eq!(
+            gc.sheet(SheetId::TEST).offsets.hidden_columns(),
+            vec![2, 3, 4]
+        );
+    }
+}
@@ -0,0 +1,633 @@
+//! Sheet and range protection.
+//!
+//! Operations are checked against the protection of their sheet before a
+//! user transaction (or undo/redo) runs. The same check is used by the server
+//! and by every client when they apply a transaction received from
+//! multiplayer (with the email of its sender), so a client that skips it
+//! cannot change protected cells and all copies of the file stay in sync.
+
+use anyhow::Result;
+
+use crate::a1::{A1Selection, CellRefRange};
+use crate::controller::GridController;
+use crate::controller::active_transactions::transaction_name::TransactionName;
+use crate::controller::operations::operation::Operation;
+use crate::error_core::{self, CoreError};
+use crate::grid::sheet::protection::SheetProtection;
+use crate::grid::{DataTable, SheetId};
+use crate::{Pos, Rect, SheetPos};
+
+impl GridController {
+    /// Sets the email of the user editing the file, used to check the
+    /// protection of sheets and ranges.
+    pub fn set_editor(&mut self, editor: Option<String>) {
+        self.editor = editor;
+    }
+
+    /// Gets the protection of a sheet.
+    pub fn sheet_protection(&self, sheet_id: SheetId) -> Option<&SheetProtection> {
+        self.try_sheet(sheet_id).map(|sheet| &sheet.protection)
+    }
+
+    /// Replaces the protection of a sheet.
+    pub fn set_sheet_protection(
+        &mut self,
+        sheet_id: SheetId,
+        protection: SheetProtection,
+        cursor: Option<String>,
+    ) -> Result<()> {
+        let sheet = self.try_sheet_result(sheet_id)?;
+        sheet
+            .protection
+            .check_change(&protection, self.editor.as_deref())?;
+
+        let ops = vec![Operation::SetSheetProtection {
+            sheet_id,
+            protection,
+        }];
+        self.start_user_transaction(ops, cursor, TransactionName::Protection);
+        Ok(())
+    }
+
+    /// Checks whether the editor may apply the operations.
+    pub fn check_protection(
+        &self,
+        operations: &[Operation],
+        editor: Option<&str>,
+    ) -> error_core::Result<()> {
+        operations
+            .iter()
+            .try_for_each(|op| self.check_operation_protection(op, editor))
+    }
+
+    /// Returns true if the current editor may apply the operations. Otherwise
+    /// tells the user why not.
+    pub(crate) fn allow_operations(&self, operations: &[Operation]) -> bool {
+        let result = self.check_protection(operations, self.editor.as_deref());
+
+        #[cfg(any(target_family = "wasm", test))]
+        if let Err(e) = &result {
+            let severity = crate::grid::js_types::JsSnackbarSeverity::Error;
+            crate::wasm_bindings::js::jsClientMessage(e.to_string(), severity.to_string());
+        }
+
+        result.is_ok()
+    }
+
+    fn check_rects(
+        &self,
+        sheet_id: SheetId,
+        rects: impl IntoIterator<Item = Rect>,
+        editor: Option<&str>,
+    ) -> error_core::Result<()> {
+        let Some(sheet) = self.try_sheet(sheet_id) else {
+            return Ok(());
+        };
+        if sheet.protection.is_empty() {
+            return Ok(());
+        }
+        rects
+            .into_iter()
+            .try_for_each(|rect| sheet.protection.check_rect(rect, editor, &self.a1_context))
+    }
+
+    fn check_selection(
+        &self,
+        selection: &A1Selection,
+        editor: Option<&str>,
+    ) -> error_core::Result<()> {
+        let rects = selection.ranges.iter().filter_map(|range| match range {
+            CellRefRange::Sheet { range } => Some(range.to_rect_unbounded()),
+            CellRefRange::Table { range } => range.to_largest_rect(&self.a1_context),
+        });
+        self.check_rects(selection.sheet_id, rects, editor)
+    }
+
+    /// Checks the cells of the data table at the position (or the cell if
+    /// there is no data table).
+    fn check_data_table(
+        &self,
+        sheet_pos: SheetPos,
+        editor: Option<&str>,
+    ) -> error_core::Result<()> {
+        let pos = Pos::from(sheet_pos);
+        let rect = self
+            .try_sheet(sheet_pos.sheet_id)
+            .and_then(|sheet| sheet.data_table(pos))
+            .map_or(Rect::single_pos(pos), |data_table| {
+                data_table.output_rect(pos, false)
+            });
+        self.check_rects(sheet_pos.sheet_id, [rect], editor)
+    }
+
+    /// Checks the columns from `column` to the right, which move when columns
+    /// are inserted, deleted or moved.
+    fn check_columns_from(
+        &self,
+        sheet_id: SheetId,
+        column: i64,
+        editor: Option<&str>,
+    ) -> error_core::Result<()> {
+        self.check_rects(sheet_id, [Rect::new(column, 1, i64::MAX, i64::MAX)], editor)
+    }
+
+    /// Checks the rows from `row` down, which move when rows are inserted,
+    /// deleted or moved.
+    fn check_rows_from(
+        &self,
+        sheet_id: SheetId,
+        row: i64,
+        editor: Option<&str>,
+    ) -> error_core::Result<()> {
+        self.check_rects(sheet_id, [Rect::new(1, row, i64::MAX, i64::MAX)], editor)
+    }
+
+    /// Checks a change to the sheet itself (eg, renaming or deleting it),
+    /// which needs the editor to be allowed to edit the whole sheet, including
+    /// its protected ranges.
+    fn check_sheet(&self, sheet_id: SheetId, editor: Option<&str>) -> error_core::Result<()> {
+        match self.try_sheet(sheet_id) {
+            Some(sheet) if !sheet.protection.can_edit_sheet(editor) => {
+                Err(CoreError::Protected("The sheet is protected".to_string()))
+            }
+            _ => self.check_rows_from(sheet_id, 1, editor),
+        }
+    }
+
+    /// Checks a change to the whole workbook (eg, its theme), which needs the
+    /// editor to be allowed to change every sheet.
+    fn check_workbook(&self, editor: Option<&str>) -> error_core::Result<()> {
+        self.grid
+            .sheets()
+            .iter()
+            .try_for_each(|sheet| self.check_sheet(sheet.id, editor))
+    }
+
+    /// Checks a change to the data table at the position. Recalculating a
+    /// code cell (eg, a protected formula that depends on an editable cell)
+    /// sets its data table with the same code, which is allowed.
+    fn check_set_data_table(
+        &self,
+        sheet_pos: SheetPos,
+        data_table: Option<&DataTable>,
+        editor: Option<&str>,
+    ) -> error_core::Result<()> {
+        let pos = Pos::from(sheet_pos);
+        let old = self
+            .try_sheet(sheet_pos.sheet_id)
+            .and_then(|sheet| sheet.data_table(pos));
+        let old_code = old.and_then(|data_table| data_table.code_run());
+        let new_code = data_table.and_then(|data_table| data_table.code_run());
+        if let (Some(old_code), Some(new_code)) = (old_code, new_code)
+            && old_code.language == new_code.language
+            && old_code.code == new_code.code
+        {
+            return Ok(());
+        }
+
+        self.check_data_table(sheet_pos, editor)?;
+        match data_table {
+            Some(data_table) => self.check_rects(
+                sheet_pos.sheet_id,
+                [data_table.output_rect(pos, false)],
+                editor,
+            ),
+            None => Ok(()),
+        }
+    }
+
+    fn check_operation_protection(
+        &self,
+        op: &Operation,
+        editor: Option<&str>,
+    ) -> error_core::Result<()> {
+        match op {
+            Operation::SetCellValues { sheet_pos, values } => self.check_rects(
+                sheet_pos.sheet_id,
+                [Rect::from_numbers(
+                    sheet_pos.x,
+                    sheet_pos.y,
+                    values.w as i64,
+                    values.h as i64,
+                )],
+                editor,
+            ),
+            Operation::SetDataTable {
+                sheet_pos,
+                data_table,
+                ..
+            } => self.check_set_data_table(*sheet_pos, data_table.as_ref(), editor),
+            Operation::AddDataTable {
+                sheet_pos,
+                data_table,
+                ..
+            } => self.check_rects(
+                sheet_pos.sheet_id,
+                [data_table.output_rect((*sheet_pos).into(), false)],
+                editor,
+            ),
+            Operation::DeleteDataTable { sheet_pos }
+            | Operation::ComputeCode { sheet_pos }
+            | Operation::SetChartSize { sheet_pos, .. }
+            | Operation::SetChartCellSize { sheet_pos, .. }
+            | Operation::SetDataTableAt { sheet_pos, .. }
+            | Operation::FlattenDataTable { sheet_pos }
+            | Operation::SwitchDataTableKind { sheet_pos, .. }
+            | Operation::DataTableMeta { sheet_pos, .. }
+            | Operation::DataTableOptionMeta { sheet_pos, .. }
+            | Operation::DataTableFormats { sheet_pos, .. }
+            | Operation::DataTableBorders { sheet_pos, .. }
+            | Operation::SortDataTable { sheet_pos, .. }
+            | Operation::DataTableFirstRowAsHeader { sheet_pos, .. }
+            | Operation::InsertDataTableColumns { sheet_pos, .. }
+            | Operation::DeleteDataTableColumns { sheet_pos, .. }
+            | Operation::InsertDataTableRows { sheet_pos, .. }
+            | Operation::DeleteDataTableRows { sheet_pos, .. } => {
+                self.check_data_table(*sheet_pos, editor)
+            }
+            Operation::GridToDataTable { sheet_rect }
+            | Operation::SetCellFormats { sheet_rect, .. }
+            | Operation::SetBorders { sheet_rect, .. } => {
+                self.check_rects(sheet_rect.sheet_id, [(*sheet_rect).into()], editor)
+            }
+            Operation::SetCellFormatsSelection { selection, .. }
+            | Operation::SetBordersSelection { selection, .. } => {
+                // deprecated operations are checked against the whole sheet
+                self.check_rows_from(selection.sheet_id, 1, editor)
+            }
+            Operation::SetCellFormatsA1 { sheet_id, formats } => {
+                self.check_rects(*sheet_id, formats.to_rects(), editor)
+            }
+            Operation::SetBordersA1 { sheet_id, borders } => {
+                self.check_rects(*sheet_id, borders.to_rects(), editor)
+            }
+            Operation::MoveCells { source, dest, .. } => {
+                let source_rect = Rect::from(*source);
+                let dest_rect = Rect::from_numbers(
+                    dest.x,
+                    dest.y,
+                    source_rect.width() as i64,
+                    source_rect.height() as i64,
+                );
+                self.check_rects(source.sheet_id, [source_rect], editor)?;
+                self.check_rects(dest.sheet_id, [dest_rect], editor)
+            }
+            Operation::SetValidation { validation } => {
+                self.check_selection(&validation.selection, editor)
+            }
+            Operation::RemoveValidation {
+                sheet_id,
+                validation_id,
+            } => match self
+                .try_sheet(*sheet_id)
+                .and_then(|sheet| sheet.validations.validation(*validation_id))
+            {
+                Some(validation) => self.check_selection(&validation.selection, editor),
+                None => Ok(()),
+            },
+            Operation::SetConditionalFormat { conditional_format } => {
+                self.check_selection(&conditional_format.selection, editor)
+            }
+            Operation::RemoveConditionalFormat {
+                sheet_id,
+                conditional_format_id,
+            } => match self.try_sheet(*sheet_id).and_then(|sheet| {
+                sheet
+                    .conditional_formats
+                    .conditional_format(*conditional_format_id)
+            }) {
+                Some(conditional_format) => {
+                    self.check_selection(&conditional_format.selection, editor)
+                }
+                None => Ok(()),
+            },
+            Operation::MergeCells { sheet_id, rects }
+            | Operation::UnmergeCells { sheet_id, rects } => {
+                self.check_rects(*sheet_id, rects.iter().copied(), editor)
+            }
+            Operation::DeleteColumn {
+                sheet_id, column, ..
+            }
+            | Operation::InsertColumn {
+                sheet_id, column, ..
+            } => self.check_columns_from(*sheet_id, *column, editor),
+            Operation::DeleteColumns {
+                sheet_id, columns, ..
+            } => match columns.iter().min() {
+                Some(column) => self.check_columns_from(*sheet_id, *column, editor),
+                None => Ok(()),
+            },
+            Operation::MoveColumns {
+                sheet_id,
+                col_start,
+                to,
+                ..
+            } => self.check_columns_from(*sheet_id, (*col_start).min(*to), editor),
+            Operation::DeleteRow { sheet_id, row, .. }
+            | Operation::InsertRow { sheet_id, row, .. } => {
+                self.check_rows_from(*sheet_id, *row, editor)
+            }
+            Operation::DeleteRows { sheet_id, rows, .. } => match rows.iter().min() {
+                Some(row) => self.check_rows_from(*sheet_id, *row, editor),
+                None => Ok(()),
+            },
+            Operation::MoveRows {
+                sheet_id,
+                row_start,
+                to,
+                ..
+            } => self.check_rows_from(*sheet_id, (*row_start).min(*to), editor),
+            Operation::DeleteSheet { sheet_id }
+            | Operation::SetSheetName { sheet_id, .. }
+            | Operation::SetSheetColor { sheet_id, .. } => self.check_sheet(*sheet_id, editor),
+            Operation::SetSheetProtection {
+                sheet_id,
+                protection,
+            } => match self.try_sheet(*sheet_id) {
+                Some(sheet) => sheet.protection.check_change(protection, editor),
+                None => Ok(()),
+            },
+            Operation::SetCellStyles { .. }
+            | Operation::SetTheme { .. }
+            | Operation::SetLocale { .. }
+            | Operation::SetTimeZone { .. }
+            | Operation::SetDateSettings { .. } => self.check_workbook(editor),
+
+            // validation warnings, cursors, layout (sizes, hidden and frozen
+            // columns and rows, outlines), comments, new sheets and custom
+            // lists do not change protected content
+            Operation::AddSheet { .. }
+            | Operation::AddSheetSchema { .. }
+            | Operation::DuplicateSheet { .. }
+            | Operation::ReorderSheet { .. }
+            | Operation::ResizeColumn { .. }
+            | Operation::ResizeRow { .. }
+            | Operation::ResizeColumns { .. }
+            | Operation::ResizeRows { .. }
+            | Operation::DefaultRowSize { .. }
+            | Operation::DefaultColumnSize { .. }
+            | Operation::SetCursor { .. }
+            | Operation::SetCursorSelection { .. }
+            | Operation::SetCursorA1 { .. }
+            | Operation::SetValidationWarning { .. }
+            | Operation::SetCustomLists { .. }
+            | Operation::SetColumnsHidden { .. }
+            | Operation::SetRowsHidden { .. }
+            | Operation::SetColumnOutline { .. }
+            | Operation::SetRowOutline { .. }
+            | Operation::SetFrozenPanes { .. }
+            | Operation::AddComment { .. }
+            | Operation::DeleteComment { .. }
+            | Operation::SetCommentResolved { .. }
+            | Operation::SetCommentThreads { .. } => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::{
+        CellValue,
+        a1::A1Selection,
+        cell_values::CellValues,
+        grid::{CodeCellLanguage, js_types::JsSnackbarSeverity, sheet::protection::ProtectedRange},
+        wasm_bindings::js::{clear_js_calls, expect_js_call},
+    };
+
+    const OWNER: &str = "owner@example.com";
+    const USER: &str = "user@example.com";
+
+    fn protected_gc() -> GridController {
+        let mut gc = GridController::test();
+        gc.set_editor(Some(OWNER.to_string()));
+        gc.set_sheet_protection(
+            SheetId::TEST,
+            SheetProtection {
+                protected: true,
+                editors: vec![OWNER.to_string()],
+                editable_ranges: vec![A1Selection::test_a1("B2:B10")],
+                ranges: vec![ProtectedRange {
+                    id: Uuid::new_v4(),
+                    selection: A1Selection::test_a1("B5"),
+                    description: Some("Total".to_string()),
+                    editors: vec![OWNER.to_string()],
+                }],
+            },
+            None,
+        )
+        .unwrap();
+        gc.set_editor(Some(USER.to_string()));
+        gc
+    }
+
+    fn value(gc: &GridController, pos: Pos) -> Option<CellValue> {
+        gc.sheet(SheetId::TEST).cell_value(pos)
+    }
+
+    #[test]
+    fn test_protected_sheet() {
+        clear_js_calls();
+        let mut gc = protected_gc();
+
+        gc.set_cell_value(pos![(SheetId::TEST)!A1], "1".to_string(), None);
+        assert_eq!(value(&gc, pos![A1]), None);
+        expect_js_call(
+            "jsClientMessage",
+            format!("The sheet is protected,{}", JsSnackbarSeverity::Error),
+            true,
+        );
+
+        // editable range
+        gc.set_cell_value(pos![(SheetId::TEST)!B2], "2".to_string(), None);
+        assert_eq!(value(&gc, pos![B2]), Some(CellValue::Number(2.into())));
+
+        // protected range within the editable range
+        gc.set_cell_value(pos![(SheetId::TEST)!B5], "5".to_string(), None);
+        assert_eq!(value(&gc, pos![B5]), None);
+        expect_js_call(
+            "jsClientMessage",
+            format!("The range Total is protected,{}", JsSnackbarSeverity::Error),
+            true,
+        );
+
+        // sheet editors may edit anything
+        gc.set_editor(Some(OWNER.to_string()));
+        gc.set_cell_value(pos![(SheetId::TEST)!A1], "1".to_string(), None);
+        gc.set_cell_value(pos![(SheetId::TEST)!B5], "5".to_string(), None);
+        assert_eq!(value(&gc, pos![A1]), Some(CellValue::Number(1.into())));
+        assert_eq!(value(&gc, pos![B5]), Some(CellValue::Number(5.into())));
+    }
+
+    #[test]
+    fn test_protected_undo() {
+        let mut gc = protected_gc();
+
+        // only editors may undo the protection; a refused undo is dropped
+        gc.undo(None);
+        assert!(gc.sheet(SheetId::TEST).protection.protected);
+        assert!(!gc.has_undo());
+
+        let mut gc = protected_gc();
+        gc.set_editor(Some(OWNER.to_string()));
+        gc.undo(None);
+        assert!(gc.sheet(SheetId::TEST).protection.is_empty());
+    }
+
+    #[test]
+    fn test_protected_received_transaction() {
+        let mut gc = GridController::test();
+        gc.sheet_mut(SheetId::TEST).protection = SheetProtection {
+            protected: true,
+            editors: vec![OWNER.to_string()],
+            ..Default::default()
+        };
+        let ops = vec![Operation::SetCellValues {
+            sheet_pos: pos![(SheetId::TEST)!A1],
+            values: CellValues::from(CellValue::Text("other".to_string())),
+        }];
+
+        // skipped (as by the server) when its sender may not edit the cells
+        gc.received_transaction(Uuid::new_v4(), 1, ops.clone(), Some(USER.to_string()));
+        assert_eq!(value(&gc, pos![A1]), None);
+        assert_eq!(gc.active_transactions().last_sequence_num, 1);
+
+        gc.received_transaction(Uuid::new_v4(), 2, ops, Some(OWNER.to_string()));
+        assert_eq!(
+            value(&gc, pos![A1]),
+            Some(CellValue::Text("other".to_string()))
+        );
+        assert_eq!(gc.active_transactions().last_sequence_num, 2);
+    }
+
+    #[test]
+    fn test_protected_unsaved_transaction() {
+        clear_js_calls();
+        let mut gc = GridController::test();
+        gc.set_editor(Some(USER.to_string()));
+        gc.set_cell_value(pos![(SheetId::TEST)!A1], "1".to_string(), None);
+        let transaction = gc.last_transaction().unwrap().clone();
+        assert_eq!(value(&gc, pos![A1]), Some(CellValue::Number(1.into())));
+
+        // the sheet is protected before the server receives our change
+        let protect = vec![Operation::SetSheetProtection {
+            sheet_id: SheetId::TEST,
+            protection: SheetProtection {
+                protected: true,
+                editors: vec![OWNER.to_string()],
+                ..Default::default()
+            },
+        }];
+        gc.received_transaction(Uuid::new_v4(), 1, protect, Some(OWNER.to_string()));
+
+        // our change is rolled back, dropped and reported
+        assert_eq!(value(&gc, pos![A1]), None);
+        assert!(gc.active_transactions().unsaved_transactions.is_empty());
+        assert!(!gc.has_undo());
+        expect_js_call(
+            "jsClientMessage",
+            format!("The sheet is protected,{}", JsSnackbarSeverity::Error),
+            true,
+        );
+
+        // the server skips it as well
+        gc.received_transaction(
+            transaction.id,
+            2,
+            transaction.operations,
+            Some(USER.to_string()),
+        );
+        assert_eq!(value(&gc, pos![A1]), None);
+    }
+
+    #[test]
+    fn test_protected_code_cell() {
+        let mut gc = protected_gc();
+        gc.set_editor(Some(OWNER.to_string()));
+        gc.set_code_cell(
+            pos![(SheetId::TEST)!A1],
+            CodeCellLanguage::Formula,
+            "B2 * 2".to_string(),
+            None,
+            None,
+        );
+        gc.set_editor(Some(USER.to_string()));
+
+        // the protected formula recalculates when an editable cell changes
+        gc.set_cell_value(pos![(SheetId::TEST)!B2], "3".to_string(), None);
+        assert_eq!(
+            gc.sheet(SheetId::TEST).display_value(pos![A1]),
+            Some(CellValue::Number(6.into()))
+        );
+        let forward = gc.last_transaction().unwrap().operations.clone();
+        assert!(
+            forward
+                .iter()
+                .any(|op| matches!(op, Operation::SetDataTable { .. }))
+        );
+        assert!(gc.check_protection(&forward, Some(USER)).is_ok());
+
+        // but it may not be rerun or removed
+        let rerun = [Operation::ComputeCode {
+            sheet_pos: pos![(SheetId::TEST)!A1],
+        }];
+        assert!(gc.check_protection(&rerun, Some(USER)).is_err());
+        let remove = [Operation::SetDataTable {
+            sheet_pos: pos![(SheetId::TEST)!A1],
+            data_table: None,
+            index: 0,
+        }];
+        assert!(gc.check_protection(&remove, Some(USER)).is_err());
+        assert!(gc.check_protection(&remove, Some(OWNER)).is_ok());
+    }
+
+    #[test]
+    fn test_check_protection() {
+        let gc = protected_gc();
+        let delete_column = [Operation::DeleteColumn {
+            sheet_id: SheetId::TEST,
+            column: 3,
+            copy_formats: Default::default(),
+        }];
+        assert!(gc.check_protection(&delete_column, Some(USER)).is_err());
+        assert!(gc.check_protection(&delete_column, Some(OWNER)).is_ok());
+
+        let rename = [Operation::SetSheetName {
+            sheet_id: SheetId::TEST,
+            name: "Renamed".to_string(),
+        }];
+        assert!(gc.check_protection(&rename, None).is_err());
+
+        // sheet-level operations also need the editors of protected ranges
+        let mut gc = GridController::test();
+        gc.sheet_mut(SheetId::TEST).protection.ranges = vec![ProtectedRange {
+            id: Uuid::new_v4(),
+            selection: A1Selection::test_a1("B5"),
+            description: None,
+            editors: vec![OWNER.to_string()],
+        }];
+        let delete = [Operation::DeleteSheet {
+            sheet_id: SheetId::TEST,
+        }];
+        assert!(gc.check_protection(&delete, Some(USER)).is_err());
+        assert!(gc.check_protection(&delete, Some(OWNER)).is_ok());
+        assert!(gc.check_protection(&rename, Some(USER)).is_err());
+
+        // so do changes to the whole workbook
+        let theme = [Operation::SetTheme {
+            theme: Default::default(),
+        }];
+        assert!(gc.check_protection(&theme, Some(USER)).is_err());
+        assert!(gc.check_protection(&theme, Some(OWNER)).is_ok());
+
+        let resize = [Operation::ResizeColumn {
+            sheet_id: SheetId::TEST,
+            column: 1,
+            new_size: 100.0,
+            client_resized: true,
+        }];
+        assert!(gc.check_protection(&resize, None).is_ok());
+    }
+}
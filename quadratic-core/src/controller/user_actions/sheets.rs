@@ -19,6 +19,19 @@ impl GridController {
         self.start_user_transaction(ops, cursor, TransactionName::SetSheetMetadata);
     }
 
+    /// Freezes the first `columns` columns and `rows` rows of the sheet (0
+    /// unfreezes).
+    pub fn set_frozen_panes(
+        &mut self,
+        sheet_id: SheetId,
+        columns: u32,
+        rows: u32,
+        cursor: Option<String>,
+    ) {
+        let ops = self.set_frozen_panes_operations(sheet_id, columns, rows);
+        self.start_user_transaction(ops, cursor, TransactionName::SetSheetMetadata);
+    }
+
     pub fn add_sheet(&mut self, cursor: Option<String>) {
         let ops = self.add_sheet_operations(None);
         self.start_user_transaction(ops, cursor, TransactionName::SheetAdd);
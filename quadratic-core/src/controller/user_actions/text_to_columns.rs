@@ -0,0 +1,154 @@
+use crate::{
+    a1::A1Selection,
+    controller::{GridController, active_transactions::transaction_name::TransactionName},
+    grid::sheet::text_to_columns::TextToColumnsOptions,
+};
+
+use anyhow::Result;
+
+impl GridController {
+    /// Splits the text in a single column selection into adjacent columns.
+    /// Use `text_to_columns_preview` to check whether existing cells would be
+    /// overwritten.
+    pub fn text_to_columns(
+        &mut self,
+        selection: &A1Selection,
+        options: &TextToColumnsOptions,
+        cursor: Option<String>,
+    ) -> Result<()> {
+        let ops = self.text_to_columns_operations(selection, options)?;
+        if !ops.is_empty() {
+            self.start_user_transaction(ops, cursor, TransactionName::TextToColumns);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        CellValue, SheetPos,
+        a1::A1Selection,
+        controller::GridController,
+        grid::{
+            CodeCellLanguage, SheetId,
+            sheet::text_to_columns::{TextToColumnsOptions, TextToColumnsSplit},
+        },
+        test_util::*,
+    };
+
+    fn comma_options() -> TextToColumnsOptions {
+        TextToColumnsOptions {
+            split: TextToColumnsSplit::Delimiter {
+                delimiter: ",".to_string(),
+                merge_consecutive: false,
+            },
+            trim_whitespace: true,
+            detect_types: true,
+        }
+    }
+
+    fn set_column(gc: &mut GridController, values: Vec<&str>) {
+        let values = values.into_iter().map(|v| vec![v.to_string()]).collect();
+        gc.set_cell_values(SheetPos::new(SheetId::TEST, 1, 1), values, None);
+    }
+
+    #[test]
+    fn test_text_to_columns() {
+        let mut gc = GridController::test();
+        set_column(
+            &mut gc,
+            vec!["Smith, Jane, 10", "5", "Doe, John, 20", "", "Roe"],
+        );
+
+        let selection = A1Selection::test_a1("A1:A5");
+        gc.text_to_columns(&selection, &comma_options(), None)
+            .unwrap();
+
+        assert_cell_value_col(
+            &gc,
+            SheetId::TEST,
+            1,
+            1,
+            5,
+            vec!["Smith", "5", "Doe", "", "Roe"],
+        );
+        assert_cell_value_col(
+            &gc,
+            SheetId::TEST,
+            2,
+            1,
+            5,
+            vec!["Jane", "", "John", "", ""],
+        );
+        assert_cell_value(&gc, SheetId::TEST, 3, 1, CellValue::from(10.0));
+        assert_cell_value(&gc, SheetId::TEST, 3, 3, CellValue::from(20.0));
+
+        gc.undo(None);
+        assert_cell_value_col(
+            &gc,
+            SheetId::TEST,
+            1,
+            1,
+            3,
+            vec!["Smith, Jane, 10", "5", "Doe, John, 20"],
+        );
+        assert_cell_value_col(&gc, SheetId::TEST, 2, 1, 3, vec!["", "", ""]);
+    }
+
+    #[test]
+    fn test_text_to_columns_preview() {
+        let mut gc = GridController::test();
+        set_column(&mut gc, vec!["a,b", "1", "c,d,e"]);
+
+        let selection = A1Selection::test_a1("A1:A3");
+        let preview = gc
+            .text_to_columns_preview(&selection, &comma_options(), 2)
+            .unwrap();
+        assert_eq!(preview.rows, vec![vec!["a", "b"], vec!["1"]]);
+        assert!(!preview.overwrites_cells);
+
+        // a cell next to a row that is not split is not overwritten
+        gc.set_cell_value(SheetPos::new(SheetId::TEST, 2, 2), "x".to_string(), None);
+        let preview = gc
+            .text_to_columns_preview(&selection, &comma_options(), 2)
+            .unwrap();
+        assert!(!preview.overwrites_cells);
+
+        gc.set_cell_value(SheetPos::new(SheetId::TEST, 3, 3), "y".to_string(), None);
+        let preview = gc
+            .text_to_columns_preview(&selection, &comma_options(), 2)
+            .unwrap();
+        assert!(preview.overwrites_cells);
+        assert!(!preview.overwrites_code);
+
+        // code is reported by the preview, but is not overwritten
+        gc.set_code_cell(
+            SheetPos::new(SheetId::TEST, 2, 3),
+            CodeCellLanguage::Formula,
+            "1".to_string(),
+            None,
+            None,
+        );
+        let preview = gc
+            .text_to_columns_preview(&selection, &comma_options(), 2)
+            .unwrap();
+        assert_eq!(preview.rows, vec![vec!["a", "b"], vec!["1"]]);
+        assert!(preview.overwrites_cells);
+        assert!(preview.overwrites_code);
+        assert!(
+            gc.text_to_columns(&selection, &comma_options(), None)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_text_to_columns_invalid_selection() {
+        let mut gc = GridController::test();
+        assert!(
+            gc.text_to_columns(&A1Selection::test_a1("A1:B2"), &comma_options(), None)
+                .is_err()
+        );
+    }
+}
@@ -11,6 +11,11 @@ impl GridController {
     }
     pub fn undo(&mut self, cursor: Option<String>) {
         if let Some(mut transaction) = self.undo_stack.pop() {
+            // a transaction that changes protected ranges is dropped (and the
+            // user is told why) so it doesn't block the rest of the stack
+            if !self.allow_operations(&transaction.operations) {
+                return;
+            }
             // we need to assign the transaction a new id to avoid conflicts with the original transaction.
             transaction.id = Uuid::new_v4();
             self.start_undo_transaction(transaction, TransactionSource::Undo, cursor);
@@ -18,6 +23,9 @@ impl GridController {
     }
     pub fn redo(&mut self, cursor: Option<String>) {
         if let Some(mut transaction) = self.redo_stack.pop() {
+            if !self.allow_operations(&transaction.operations) {
+                return;
+            }
             // we need to assign the transaction a new id to avoid conflicts with the original transaction.
             transaction.id = Uuid::new_v4();
             self.start_undo_transaction(transaction, TransactionSource::Redo, cursor);
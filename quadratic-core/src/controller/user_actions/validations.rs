@@ -10,7 +10,7 @@ use crate::{
     grid::{
         SheetId,
         sheet::validations::{
-            rules::ValidationRule,
+            rules::{ValidationRule, validation_list::ValidationListSource},
             validation::{Validation, ValidationStyle},
         },
     },
@@ -94,14 +94,25 @@ impl GridController {
         })
     }
 
-    /// Gets a list of strings for a validation list (user defined or from a selection).
+    /// Gets a list of strings for a validation list (user defined, from a
+    /// selection, or from a formula evaluated for the cell).
     pub fn validation_list(&self, sheet_id: SheetId, x: i64, y: i64) -> Option<Vec<String>> {
         let sheet = self.try_sheet(sheet_id)?;
         let validation = sheet
             .validations
             .get_validation_from_pos(Pos { x, y }, &self.a1_context)?;
         match validation.rule {
-            ValidationRule::List(ref list) => list.to_drop_down(sheet, &self.a1_context),
+            ValidationRule::List(ref list) => match &list.source {
+                ValidationListSource::Formula(formula) if list.drop_down => {
+                    let anchor = validation
+                        .selection
+                        .anchor(&self.a1_context)
+                        .to_sheet_pos(sheet_id);
+                    let pos = Pos { x, y }.to_sheet_pos(sheet_id);
+                    Some(self.formula_list(formula, anchor, pos))
+                }
+                _ => list.to_drop_down(sheet, &self.a1_context),
+            },
             _ => None,
         }
     }
@@ -118,14 +129,14 @@ impl GridController {
             return None;
         }
         let cell_value = CellValue::parse_from_str(input);
-        if validation
-            .rule
-            .validate(sheet, Some(&cell_value), &self.a1_context)
-        {
-            None
+        let valid = if validation.rule.has_formula() {
+            self.validate_formula_rule(validation, pos.to_sheet_pos(sheet_id), Some(cell_value))
         } else {
-            Some(validation.id)
-        }
+            validation
+                .rule
+                .validate(sheet, Some(&cell_value), &self.a1_context)
+        };
+        if valid { None } else { Some(validation.id) }
     }
 }
 
@@ -283,6 +294,7 @@ mod tests {
             source: ValidationListSource::List(vec!["a".to_string(), "b".to_string()]),
             ignore_blank: true,
             drop_down: true,
+            multi_select: false,
         };
         let validation = Validation {
             id: Uuid::new_v4(),
@@ -316,6 +328,7 @@ mod tests {
             )),
             ignore_blank: true,
             drop_down: true,
+            multi_select: false,
         };
         let validation = Validation {
             id: Uuid::new_v4(),
@@ -347,6 +360,7 @@ mod tests {
             source: ValidationListSource::List(vec!["a".to_string(), "b".to_string()]),
             ignore_blank: true,
             drop_down: true,
+            multi_select: false,
         };
         let validation = Validation {
             id: Uuid::new_v4(),
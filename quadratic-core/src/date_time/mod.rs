@@ -6,23 +6,26 @@
 //! error.)
 
 use itertools::Itertools;
+use serde::{Deserialize, Serialize};
 use std::{cmp::Ordering, str::FromStr};
 
 use chrono::{
-    DateTime, Datelike, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Utc,
+    DateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Utc,
     format::{Fixed, Item, Numeric, StrftimeItems},
 };
+use ts_rs::TS;
 
 mod date_time_convert;
+mod serial;
 mod wasm;
 
+pub use serial::{DateSettings, DateSystem};
+
 pub const DEFAULT_DATE_FORMAT: &str = "%m/%d/%Y";
 pub const DEFAULT_TIME_FORMAT: &str = "%-I:%M %p";
 pub const DEFAULT_DATE_TIME_FORMAT: &str = "%m/%d/%Y %-I:%M %p";
+pub const DEFAULT_DATE_TIME_TZ_FORMAT: &str = "%m/%d/%Y %-I:%M %p %:z";
 
-/// Whether to prefer the American style M/D/Y instead of the international
-/// D/M/Y.
-pub const AMERICAN: bool = true;
 /// Cutoff year between for 2-digit parsing. For example, should `12/31/36`
 /// parse as 1936 or 2036?
 ///
@@ -99,6 +102,21 @@ fn is_time_item(item: &Item<'_>) -> bool {
     )
 }
 
+fn is_offset_item(item: &Item<'_>) -> bool {
+    matches!(
+        item,
+        Item::Fixed(
+            Fixed::TimezoneName
+                | Fixed::TimezoneOffset
+                | Fixed::TimezoneOffsetColon
+                | Fixed::TimezoneOffsetColonZ
+                | Fixed::TimezoneOffsetDoubleColon
+                | Fixed::TimezoneOffsetTripleColon
+                | Fixed::TimezoneOffsetZ
+        )
+    )
+}
+
 fn is_space_item(item: &Item<'_>) -> bool {
     matches!(item, Item::Space(_))
 }
@@ -116,9 +134,25 @@ fn find_items_date_start(items: &[Item<'_>]) -> Option<usize> {
 pub fn date_time_to_date_time_string(date_time: NaiveDateTime, format: Option<String>) -> String {
     let format = format.map_or(DEFAULT_DATE_TIME_FORMAT.to_string(), |f| f);
     let strftime_items = StrftimeItems::new(&format);
-    let Ok(items) = strftime_items.parse() else {
+    let Ok(mut items) = strftime_items.parse() else {
         return date_time.format(DEFAULT_DATE_TIME_FORMAT).to_string();
     };
+    // a value without a time zone cannot display an offset
+    items.retain(|item| !is_offset_item(item));
+    date_time.format_with_items(items.iter()).to_string()
+}
+
+/// Converts a DateTime with a UTC offset to a date and time string using a
+/// strftime format string. The default format includes the offset.
+pub fn date_time_tz_to_date_time_string(
+    date_time: DateTime<FixedOffset>,
+    format: Option<String>,
+) -> String {
+    let format = format.map_or(DEFAULT_DATE_TIME_TZ_FORMAT.to_string(), |f| f);
+    let strftime_items = StrftimeItems::new(&format);
+    let Ok(items) = strftime_items.parse() else {
+        return date_time.format(DEFAULT_DATE_TIME_TZ_FORMAT).to_string();
+    };
     date_time.format_with_items(items.iter()).to_string()
 }
 
@@ -233,6 +267,30 @@ pub fn parse_time(value: &str) -> Option<NaiveTime> {
     None
 }
 
+/// Parses a date and time string that includes an explicit UTC offset (eg,
+/// `2024-03-10T14:30:00+02:00` or `03/10/2024 2:30 PM -05:00`).
+pub fn parse_date_time_tz(value: &str) -> Option<DateTime<FixedOffset>> {
+    let value = value.trim();
+    if let Ok(parsed) = DateTime::parse_from_rfc3339(value) {
+        return Some(parsed);
+    }
+
+    let formats = [
+        "%Y-%m-%d %H:%M:%S%.f%:z",
+        "%Y-%m-%d %H:%M:%S%.f %:z",
+        "%Y-%m-%d %H:%M:%S%.f %z",
+        "%Y-%m-%d %H:%M%:z",
+        "%Y-%m-%d %H:%M %:z",
+        "%m/%d/%Y %I:%M %p %:z",
+        "%m/%d/%Y %I:%M %p %z",
+        "%m/%d/%Y %H:%M:%S %:z",
+        "%m/%d/%Y %H:%M %:z",
+    ];
+    formats
+        .iter()
+        .find_map(|format| DateTime::parse_from_str(value, format).ok())
+}
+
 #[derive(Debug, Clone)]
 struct ParsedDateComponents {
     /// Character used to separate components.
@@ -397,8 +455,27 @@ impl ParsedDateComponent {
     }
 }
 
-/// Parses a date string using a list of possible formats.
+/// Order in which ambiguous numeric dates such as `10/12/24` are read.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq, Hash, TS)]
+pub enum DateOrder {
+    /// Month, day, year (`12/31/2024`).
+    #[default]
+    Mdy,
+    /// Day, month, year (`31/12/2024`).
+    Dmy,
+    /// Year, month, day (`2024/12/31`).
+    Ymd,
+}
+
+/// Parses a date string using a list of possible formats, reading ambiguous
+/// dates as month/day/year.
 pub fn parse_date(value: &str) -> Option<NaiveDate> {
+    parse_date_in_order(value, DateOrder::Mdy)
+}
+
+/// Parses a date string using a list of possible formats, reading ambiguous
+/// numeric dates in the given order.
+pub fn parse_date_in_order(value: &str, order: DateOrder) -> Option<NaiveDate> {
     let components = ParsedDateComponents::from_str(value).ok()?;
     let sep = components.separator;
 
@@ -428,7 +505,10 @@ pub fn parse_date(value: &str) -> Option<NaiveDate> {
             //
             // `10/12/24` is `mdy` or `dmy` depending on locale.
             // This also covers `10/12/2024`.
-            if AMERICAN { "mdy" } else { "dmy" },
+            match order {
+                DateOrder::Mdy | DateOrder::Ymd => "mdy",
+                DateOrder::Dmy => "dmy",
+            },
             // Always accept the other pattern if there's a named month.
             "dMy",
             "Mdy",
@@ -438,7 +518,10 @@ pub fn parse_date(value: &str) -> Option<NaiveDate> {
             // 2 COMPONENTS
             //
             // `12/12` is `md` or `dm` depending on locale.
-            if AMERICAN { "md" } else { "dm" },
+            match order {
+                DateOrder::Mdy | DateOrder::Ymd => "md",
+                DateOrder::Dmy => "dm",
+            },
             // Always accept the other pattern if there's a named month.
             "dM",
             "Md",
@@ -583,6 +666,21 @@ mod tests {
         assert_eq!(parse_date("06-1902"), NaiveDate::from_ymd_opt(1902, 6, 1));
     }
 
+    #[test]
+    fn test_parse_date_in_order() {
+        let dmy = |s| parse_date_in_order(s, DateOrder::Dmy);
+        assert_eq!(dmy("31/12/2024"), NaiveDate::from_ymd_opt(2024, 12, 31));
+        assert_eq!(dmy("01.02.2024"), NaiveDate::from_ymd_opt(2024, 2, 1));
+        assert_eq!(dmy("4/6/24"), NaiveDate::from_ymd_opt(2024, 6, 4));
+        assert_eq!(dmy("2024-12-31"), NaiveDate::from_ymd_opt(2024, 12, 31));
+        assert_eq!(dmy("Dec 31 2024"), NaiveDate::from_ymd_opt(2024, 12, 31));
+        assert_eq!(dmy("12/31/2024"), None);
+
+        let ymd = |s| parse_date_in_order(s, DateOrder::Ymd);
+        assert_eq!(ymd("2024/12/31"), NaiveDate::from_ymd_opt(2024, 12, 31));
+        assert_eq!(ymd("12/31/2024"), NaiveDate::from_ymd_opt(2024, 12, 31));
+    }
+
     #[test]
     fn test_parse_time() {
         let time = "4:45 PM".to_string();
@@ -0,0 +1,120 @@
+//! Converts between dates and Excel serial numbers (the number of days since
+//! the epoch of a date system, with the time as a fraction of a day).
+
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime, TimeDelta};
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+const MILLISECONDS_PER_DAY: f64 = 86_400_000.0;
+
+/// Epoch used by Excel to number dates.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq, Hash, TS)]
+pub enum DateSystem {
+    /// Serial `1` is January 1, 1900. Excel treats 1900 as a leap year, so
+    /// serials before March 1, 1900 are off by one.
+    #[default]
+    Excel1900,
+    /// Serial `0` is January 1, 1904 (used by older Mac workbooks).
+    Excel1904,
+}
+
+impl DateSystem {
+    fn epoch(self) -> NaiveDateTime {
+        let epoch = match self {
+            DateSystem::Excel1900 => NaiveDate::from_ymd_opt(1899, 12, 30),
+            DateSystem::Excel1904 => NaiveDate::from_ymd_opt(1904, 1, 1),
+        };
+        epoch.unwrap_or_default().and_time(NaiveTime::MIN)
+    }
+
+    /// Returns the date time of a serial number.
+    pub fn serial_to_date_time(self, serial: f64) -> Option<NaiveDateTime> {
+        if !serial.is_finite() {
+            return None;
+        }
+        // skip Excel's February 29, 1900
+        let days = match self {
+            DateSystem::Excel1900 if serial < 60.0 => serial + 1.0,
+            _ => serial,
+        };
+        let milliseconds = (days * MILLISECONDS_PER_DAY).round() as i64;
+        self.epoch()
+            .checked_add_signed(TimeDelta::try_milliseconds(milliseconds)?)
+    }
+
+    /// Returns the serial number of a date time.
+    pub fn date_time_to_serial(self, date_time: NaiveDateTime) -> f64 {
+        let days = (date_time - self.epoch()).num_milliseconds() as f64 / MILLISECONDS_PER_DAY;
+        match self {
+            DateSystem::Excel1900 if days < 61.0 => days - 1.0,
+            _ => days,
+        }
+    }
+}
+
+/// How a file converts between dates and serial numbers.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq, Hash, TS)]
+pub struct DateSettings {
+    pub date_system: DateSystem,
+
+    /// Excel compatibility mode: numbers passed to date functions are read as
+    /// serial dates, and date functions (eg, `DATEVALUE`) return serial
+    /// numbers instead of dates.
+    pub serial_dates: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date_time(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M").unwrap()
+    }
+
+    #[test]
+    fn test_serial_to_date_time() {
+        let system = DateSystem::Excel1900;
+        assert_eq!(
+            system.serial_to_date_time(1.0),
+            Some(date_time("1900-01-01 00:00"))
+        );
+        assert_eq!(
+            system.serial_to_date_time(59.0),
+            Some(date_time("1900-02-28 00:00"))
+        );
+        assert_eq!(
+            system.serial_to_date_time(61.0),
+            Some(date_time("1900-03-01 00:00"))
+        );
+        assert_eq!(
+            system.serial_to_date_time(45000.75),
+            Some(date_time("2023-03-15 18:00"))
+        );
+        assert_eq!(system.serial_to_date_time(f64::NAN), None);
+
+        // the 1904 system is four years and a day later
+        let system = DateSystem::Excel1904;
+        assert_eq!(
+            system.serial_to_date_time(0.0),
+            Some(date_time("1904-01-01 00:00"))
+        );
+        assert_eq!(
+            system.serial_to_date_time(45000.75 - 1462.0),
+            Some(date_time("2023-03-15 18:00"))
+        );
+    }
+
+    #[test]
+    fn test_date_time_to_serial() {
+        for system in [DateSystem::Excel1900, DateSystem::Excel1904] {
+            for serial in [1.0, 59.0, 61.0, 366.5, 45000.75] {
+                let date_time = system.serial_to_date_time(serial).unwrap();
+                assert_eq!(system.date_time_to_serial(date_time), serial);
+            }
+        }
+        assert_eq!(
+            DateSystem::Excel1904.date_time_to_serial(date_time("2023-03-15 00:00")),
+            43538.0
+        );
+    }
+}
@@ -35,6 +35,9 @@ pub enum CoreError {
 
     #[error("A1Error: {0}")]
     A1Error(String),
+
+    #[error("{0}")]
+    Protected(String),
 }
 
 impl From<serde_json::Error> for CoreError {
@@ -1,13 +1,14 @@
 use std::borrow::Cow;
 
+use bigdecimal::BigDecimal;
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 use smallvec::smallvec;
 
 use super::*;
 use crate::{
-    Array, ArraySize, CellValue, CodeResult, CoerceInto, Pos, RunErrorMsg, SheetRect, Spanned,
-    Value,
+    Array, ArraySize, CellValue, CodeResult, CoerceInto, Pos, RefAdjust, RunErrorMsg, SheetRect,
+    Spanned, Value,
     a1::{CellRefCoord, CellRefRange, CellRefRangeEnd, RefRangeBounds, SheetCellRefRange},
     grid::SheetId,
 };
@@ -32,7 +33,7 @@ pub enum AstNodeContents {
     CellRef(Option<SheetId>, RefRangeBounds),
     RangeRef(SheetCellRefRange),
     String(String),
-    Number(f64),
+    Number(BigDecimal),
     Bool(bool),
     Error(RunErrorMsg),
 }
@@ -69,9 +70,72 @@ impl Formula {
             inner: e.into(),
         })
     }
+
+    /// Returns a copy of the formula with its cell references adjusted (eg, to
+    /// evaluate the same formula for another cell without parsing it again).
+    /// References that are out of bounds after the adjustment are replaced
+    /// with an error.
+    #[must_use = "this method returns a new value instead of modifying its input"]
+    pub fn adjust_references(&self, adjust: RefAdjust) -> Self {
+        let mut ast = self.ast.clone();
+        ast.adjust_references(adjust);
+        Self { ast }
+    }
 }
 
 impl AstNode {
+    /// Adjusts the cell references of the expression. References that are
+    /// out of bounds after the adjustment are replaced with an error.
+    fn adjust_references(&mut self, adjust: RefAdjust) {
+        let is_bad_ref = |node: &AstNode| {
+            matches!(
+                node.inner,
+                AstNodeContents::Error(RunErrorMsg::BadCellReference)
+            )
+        };
+        let out_of_bounds = match &mut self.inner {
+            AstNodeContents::FunctionCall { func, args } => {
+                args.iter_mut()
+                    .for_each(|arg| arg.adjust_references(adjust));
+                // a range is out of bounds if either end is
+                func.inner == ":" && args.iter().any(is_bad_ref)
+            }
+            AstNodeContents::Paren(args) => {
+                args.iter_mut()
+                    .for_each(|arg| arg.adjust_references(adjust));
+                false
+            }
+            AstNodeContents::Array(rows) => {
+                rows.iter_mut()
+                    .flatten()
+                    .for_each(|elem| elem.adjust_references(adjust));
+                false
+            }
+            AstNodeContents::CellRef(sheet_id, bounds)
+                if sheet_id.is_none_or(|sheet_id| adjust.affects_sheet(sheet_id)) =>
+            {
+                match bounds.adjust(adjust) {
+                    Ok(adjusted) => {
+                        *bounds = adjusted;
+                        false
+                    }
+                    Err(_) => true,
+                }
+            }
+            AstNodeContents::RangeRef(range) => match range.clone().adjust(adjust) {
+                Ok(adjusted) => {
+                    *range = adjusted;
+                    false
+                }
+                Err(_) => true,
+            },
+            _ => false,
+        };
+        if out_of_bounds {
+            self.inner = AstNodeContents::Error(RunErrorMsg::BadCellReference);
+        }
+    }
+
     fn eval<'expr, 'ctx: 'expr>(&'expr self, ctx: &'expr mut Ctx<'ctx>) -> CodeResult {
         let value: Value = match &self.inner {
             AstNodeContents::Empty => Value::Single(CellValue::Blank),
@@ -89,8 +153,7 @@ impl AstNode {
                 let func_name = &func.inner;
                 match functions::lookup_function(func_name) {
                     Some(f) => {
-                        let arg_values: Vec<Spanned<Value>> =
-                            args.iter().map(|arg| arg.eval(&mut *ctx)).try_collect()?;
+                        let arg_values = Self::eval_args(ctx, f, args)?;
                         let args = FormulaFnArgs::new(arg_values, self.span, f.name);
                         (f.eval)(&mut *ctx, args)?
                     }
@@ -151,7 +214,7 @@ impl AstNode {
             }
 
             AstNodeContents::String(s) => Value::from(s.to_string()),
-            AstNodeContents::Number(n) => Value::from(*n),
+            AstNodeContents::Number(n) => Value::from(n.clone()),
             AstNodeContents::Bool(b) => Value::from(*b),
             AstNodeContents::Error(e) => {
                 Value::Single(CellValue::Error(Box::new(e.clone().with_span(self.span))))
@@ -164,6 +227,31 @@ impl AstNode {
         })
     }
 
+    /// Evaluates the arguments of a function call. If the function skips
+    /// hidden rows for its first argument, cell references in the other
+    /// arguments are read without hidden rows.
+    fn eval_args<'expr, 'ctx: 'expr>(
+        ctx: &'expr mut Ctx<'ctx>,
+        f: &functions::FormulaFunction,
+        args: &'expr [AstNode],
+    ) -> CodeResult<Vec<Spanned<Value>>> {
+        let mut arg_values = Vec::with_capacity(args.len());
+        let mut skip_hidden_rows = false;
+        for arg in args {
+            if skip_hidden_rows && let Ok(range) = arg.to_ref_range(ctx) {
+                let rect = ctx.resolve_range_ref(&range, arg.span)?.inner;
+                arg_values.push(ctx.get_visible_cell_array(rect, arg.span)?);
+                continue;
+            }
+            let value = arg.eval(&mut *ctx)?;
+            if arg_values.is_empty() {
+                skip_hidden_rows = f.skip_hidden_rows.is_some_and(|skip| skip(&value));
+            }
+            arg_values.push(value);
+        }
+        Ok(arg_values)
+    }
+
     /// Evaluates the expression to a tuple of range references, or returns an
     /// error if this cannot be done
     fn to_range_ref_tuple<'expr>(
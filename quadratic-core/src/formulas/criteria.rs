@@ -106,6 +106,11 @@ impl Criterion {
             },
             CellValue::DateTime(dt) => match lhs {
                 CellValue::DateTime(lhs) => compare_fn.compare(lhs, dt),
+                CellValue::DateTimeTz(lhs) => compare_fn.compare(&lhs.naive_local(), dt),
+                _ => false,
+            },
+            CellValue::DateTimeTz(dt) => match lhs {
+                CellValue::DateTimeTz(lhs) => compare_fn.compare(lhs, dt),
                 _ => false,
             },
             CellValue::Error(_) => false,
@@ -113,6 +118,8 @@ impl Criterion {
             CellValue::Code(_) => false,
             CellValue::Image(_) => false,
             CellValue::Import(_) => false,
+            CellValue::Hyperlink(_) => false,
+            CellValue::RichText(_) => false,
         }
     }
 
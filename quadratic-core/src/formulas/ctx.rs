@@ -3,11 +3,11 @@ use smallvec::{SmallVec, smallvec};
 
 use super::*;
 use crate::{
-    Array, CellValue, CodeResult, CodeResultExt, Pos, RunErrorMsg, SheetPos, SheetRect, Span,
-    Spanned, Value,
+    Array, ArraySize, CellValue, CodeResult, CodeResultExt, Pos, RunErrorMsg, SheetPos, SheetRect,
+    Span, Spanned, Value,
     a1::{CellRefRange, SheetCellRefRange, UNBOUNDED},
     controller::GridController,
-    grid::CellsAccessed,
+    grid::{CellsAccessed, Sheet},
 };
 
 /// Formula execution context.
@@ -22,6 +22,13 @@ pub struct Ctx<'ctx> {
 
     /// Whether to only parse, skipping expensive computations.
     pub skip_computation: bool,
+
+    /// Whether the formula may reference the cell at `sheet_pos`.
+    pub allow_self_reference: bool,
+
+    /// Value to use for the cell at `sheet_pos` instead of its current value
+    /// (eg, input that is validated before it is set).
+    pub pending_value: Option<CellValue>,
 }
 impl<'ctx> Ctx<'ctx> {
     /// Constructs a context for evaluating a formula at `pos` in `grid`.
@@ -31,6 +38,18 @@ impl<'ctx> Ctx<'ctx> {
             sheet_pos,
             cells_accessed: Default::default(),
             skip_computation: false,
+            allow_self_reference: false,
+            pending_value: None,
+        }
+    }
+
+    /// Constructs a context for evaluating a rule (eg, a conditional format)
+    /// for the cell at `sheet_pos`. The rule is not stored in the cell, so it may
+    /// reference the cell itself.
+    pub fn new_for_rule(grid_controller: &'ctx GridController, sheet_pos: SheetPos) -> Self {
+        Ctx {
+            allow_self_reference: true,
+            ..Ctx::new(grid_controller, sheet_pos)
         }
     }
 
@@ -43,6 +62,8 @@ impl<'ctx> Ctx<'ctx> {
             sheet_pos: Pos::ORIGIN.to_sheet_pos(grid_controller.grid().sheets()[0].id),
             cells_accessed: Default::default(),
             skip_computation: true,
+            allow_self_reference: false,
+            pending_value: None,
         }
     }
 
@@ -95,7 +116,7 @@ impl<'ctx> Ctx<'ctx> {
         let Some(sheet) = self.grid_controller.try_sheet(pos.sheet_id) else {
             return error_value(RunErrorMsg::BadCellReference);
         };
-        if pos == self.sheet_pos {
+        if pos == self.sheet_pos && !self.allow_self_reference {
             return error_value(RunErrorMsg::CircularReference);
         }
 
@@ -103,6 +124,15 @@ impl<'ctx> Ctx<'ctx> {
             self.cells_accessed.add_sheet_pos(pos);
         }
 
+        if pos == self.sheet_pos
+            && let Some(value) = &self.pending_value
+        {
+            return Spanned {
+                inner: value.clone(),
+                span,
+            };
+        }
+
         let value = sheet.get_cell_for_formula(pos.into());
         Spanned { inner: value, span }
     }
@@ -117,42 +147,9 @@ impl<'ctx> Ctx<'ctx> {
         let Some(sheet) = self.grid_controller.try_sheet(rect.sheet_id) else {
             return Err(RunErrorMsg::BadCellReference.with_span(span));
         };
-        let bounds = sheet.bounds(true);
-
         self.cells_accessed.add_sheet_rect(rect);
 
-        let mut bounded_rect = rect;
-
-        // convert unbounded values to the data bounds of the sheet
-        if bounded_rect.min.x == UNBOUNDED && bounded_rect.min.y == UNBOUNDED {
-            bounded_rect.min.x = bounds.first_column().unwrap_or(1);
-            bounded_rect.min.y = bounds.first_row().unwrap_or(1);
-        } else if bounded_rect.min.x == UNBOUNDED {
-            bounded_rect.min.x = sheet
-                .row_bounds(bounded_rect.min.y, true)
-                .unwrap_or((1, 1))
-                .0;
-        } else if bounded_rect.min.y == UNBOUNDED {
-            bounded_rect.min.y = sheet
-                .column_bounds(bounded_rect.min.x, true)
-                .unwrap_or((1, 1))
-                .0;
-        }
-
-        if bounded_rect.max.x == UNBOUNDED && bounded_rect.max.y == UNBOUNDED {
-            bounded_rect.max.x = bounds.last_column().unwrap_or(1);
-            bounded_rect.max.y = bounds.last_row().unwrap_or(1);
-        } else if bounded_rect.max.x == UNBOUNDED {
-            bounded_rect.max.x = sheet
-                .row_bounds(bounded_rect.max.y, true)
-                .unwrap_or((1, 1))
-                .1;
-        } else if bounded_rect.max.y == UNBOUNDED {
-            bounded_rect.max.y = sheet
-                .column_bounds(bounded_rect.max.x, true)
-                .unwrap_or((1, 1))
-                .1;
-        }
+        let bounded_rect = bounded_rect(sheet, rect);
 
         let sheet_id = bounded_rect.sheet_id;
         let array_size = bounded_rect.size();
@@ -180,6 +177,44 @@ impl<'ctx> Ctx<'ctx> {
         Ok(Array::new_row_major(array_size, flat_array)?).with_span(span)
     }
 
+    /// Fetches the contents of the cell array at `rect` without the cells in
+    /// hidden rows, or returns an error in the case of a circular reference.
+    /// Returns a blank value if all rows are hidden.
+    pub fn get_visible_cell_array(
+        &mut self,
+        rect: SheetRect,
+        span: Span,
+    ) -> CodeResult<Spanned<Value>> {
+        let array = self.get_cell_array(rect, span)?.inner;
+        let Some(sheet) = self.grid_controller.try_sheet(rect.sheet_id) else {
+            return Ok(Value::from(array)).with_span(span);
+        };
+        let bounded_rect = bounded_rect(sheet, rect);
+        if self.skip_computation
+            || !sheet
+                .offsets
+                .has_hidden_rows(bounded_rect.min.y, bounded_rect.max.y)
+        {
+            return Ok(Value::from(array)).with_span(span);
+        }
+
+        let visible_rows = array
+            .rows()
+            .zip(bounded_rect.y_range())
+            .filter(|(_, y)| !sheet.offsets.is_row_hidden(*y))
+            .map(|(row, _)| row)
+            .collect_vec();
+        let Some(height) = std::num::NonZeroU32::new(visible_rows.len() as u32) else {
+            return Ok(Value::from(CellValue::Blank)).with_span(span);
+        };
+        let size = ArraySize {
+            w: array.size().w,
+            h: height,
+        };
+        let values = visible_rows.into_iter().flatten().cloned().collect();
+        Ok(Value::from(Array::new_row_major(size, values)?)).with_span(span)
+    }
+
     /// Evaluates a function once for each corresponding set of values from
     /// `arrays`.
     ///
@@ -269,3 +304,42 @@ impl<'ctx> Ctx<'ctx> {
         })
     }
 }
+
+/// Converts unbounded values of `rect` to the data bounds of the sheet.
+fn bounded_rect(sheet: &Sheet, rect: SheetRect) -> SheetRect {
+    let bounds = sheet.bounds(true);
+    let mut bounded_rect = rect;
+
+    // convert unbounded values to the data bounds of the sheet
+    if bounded_rect.min.x == UNBOUNDED && bounded_rect.min.y == UNBOUNDED {
+        bounded_rect.min.x = bounds.first_column().unwrap_or(1);
+        bounded_rect.min.y = bounds.first_row().unwrap_or(1);
+    } else if bounded_rect.min.x == UNBOUNDED {
+        bounded_rect.min.x = sheet
+            .row_bounds(bounded_rect.min.y, true)
+            .unwrap_or((1, 1))
+            .0;
+    } else if bounded_rect.min.y == UNBOUNDED {
+        bounded_rect.min.y = sheet
+            .column_bounds(bounded_rect.min.x, true)
+            .unwrap_or((1, 1))
+            .0;
+    }
+
+    if bounded_rect.max.x == UNBOUNDED && bounded_rect.max.y == UNBOUNDED {
+        bounded_rect.max.x = bounds.last_column().unwrap_or(1);
+        bounded_rect.max.y = bounds.last_row().unwrap_or(1);
+    } else if bounded_rect.max.x == UNBOUNDED {
+        bounded_rect.max.x = sheet
+            .row_bounds(bounded_rect.max.y, true)
+            .unwrap_or((1, 1))
+            .1;
+    } else if bounded_rect.max.y == UNBOUNDED {
+        bounded_rect.max.y = sheet
+            .column_bounds(bounded_rect.max.x, true)
+            .unwrap_or((1, 1))
+            .1;
+    }
+
+    bounded_rect
+}
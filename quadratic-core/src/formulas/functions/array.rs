@@ -4,6 +4,7 @@ use smallvec::SmallVec;
 
 use super::*;
 use crate::CellValueHash;
+use crate::grid::data_table::transform::{self, DataTableAggregate, DataTableJoinKind};
 
 pub const CATEGORY: FormulaFunctionCategory = FormulaFunctionCategory {
     include_in_docs: true,
@@ -261,9 +262,164 @@ fn get_functions() -> Vec<FormulaFunction> {
                 }
             }
         ),
+        formula_fn!(
+            /// Groups the rows of a table by one or more columns and
+            /// aggregates the other columns.
+            ///
+            /// `table` must include its header row (eg, `Sales[#ALL]`).
+            /// `group_by` and `values` are column names or 1-based column
+            /// numbers. `aggregate` is one of `"SUM"` (the default),
+            /// `"AVERAGE"`, `"COUNT"`, `"COUNTA"`, `"MIN"`, or `"MAX"`.
+            ///
+            /// Groups are returned in ascending order and the first row of
+            /// the output holds the column headers.
+            #[name = "TABLE.GROUPBY"]
+            #[examples(
+                "TABLE.GROUPBY(Sales[#ALL], \"region\", \"units\")",
+                "TABLE.GROUPBY(Sales[#ALL], {\"region\", \"product\"}, {\"units\", \"price\"}, \"AVERAGE\")"
+            )]
+            fn TABLE_GROUPBY(
+                span: Span,
+                table: Array,
+                group_by: (Spanned<Array>),
+                values: (Spanned<Array>),
+                aggregate: (Option<Spanned<String>>),
+            ) {
+                let headers = table_headers(&table, span)?;
+                let group_by = table_columns(headers, &group_by)?;
+                let values = table_columns(headers, &values)?;
+                let aggregate = table_aggregate(aggregate)?;
+                transform::group_by(&table, &group_by, &values, aggregate)
+                    .map_err(|e| e.with_span(span))?
+            }
+        ),
+        formula_fn!(
+            /// Creates a table with one row per distinct combination of the
+            /// `rows` columns and one column per distinct value of `column`,
+            /// aggregating `value` at each intersection.
+            ///
+            /// `table` must include its header row (eg, `Sales[#ALL]`).
+            /// Columns are given by name or 1-based column number.
+            /// `aggregate` is one of `"SUM"` (the default), `"AVERAGE"`,
+            /// `"COUNT"`, `"COUNTA"`, `"MIN"`, or `"MAX"`. Combinations that
+            /// do not appear in `table` are left blank.
+            #[name = "TABLE.PIVOT"]
+            #[examples(
+                "TABLE.PIVOT(Sales[#ALL], \"product\", \"region\", \"units\")",
+                "TABLE.PIVOT(Sales[#ALL], {\"product\", \"size\"}, \"month\", \"price\", \"MAX\")"
+            )]
+            fn TABLE_PIVOT(
+                span: Span,
+                table: Array,
+                rows: (Spanned<Array>),
+                column: (Spanned<CellValue>),
+                value: (Spanned<CellValue>),
+                aggregate: (Option<Spanned<String>>),
+            ) {
+                let headers = table_headers(&table, span)?;
+                let rows = table_columns(headers, &rows)?;
+                let column = transform::find_column(headers, &column.inner)
+                    .map_err(|e| e.with_span(column.span))?;
+                let value = transform::find_column(headers, &value.inner)
+                    .map_err(|e| e.with_span(value.span))?;
+                let aggregate = table_aggregate(aggregate)?;
+                transform::pivot(&table, &rows, column, value, aggregate)
+                    .map_err(|e| e.with_span(span))?
+            }
+        ),
+        formula_fn!(
+            /// Turns every column of a table that is not an id column into
+            /// rows of attribute/value pairs.
+            ///
+            /// `table` must include its header row (eg, `Sales[#ALL]`).
+            /// `id_columns` are column names or 1-based column numbers that
+            /// are repeated on every output row; use `""` for none. The
+            /// attribute and value columns are named `attribute_header` and
+            /// `value_header`, which default to `"Attribute"` and `"Value"`.
+            #[name = "TABLE.UNPIVOT"]
+            #[examples(
+                "TABLE.UNPIVOT(Quarters[#ALL], \"name\")",
+                "TABLE.UNPIVOT(Quarters[#ALL], \"name\", \"quarter\", \"amount\")"
+            )]
+            fn TABLE_UNPIVOT(
+                span: Span,
+                table: Array,
+                id_columns: (Spanned<Array>),
+                attribute_header: (Option<String>),
+                value_header: (Option<String>),
+            ) {
+                let headers = table_headers(&table, span)?;
+                let id_columns = table_columns(headers, &id_columns)?;
+                transform::unpivot(
+                    &table,
+                    &id_columns,
+                    attribute_header
+                        .as_deref()
+                        .unwrap_or(transform::DEFAULT_ATTRIBUTE_HEADER),
+                    value_header
+                        .as_deref()
+                        .unwrap_or(transform::DEFAULT_VALUE_HEADER),
+                )
+                .map_err(|e| e.with_span(span))?
+            }
+        ),
+        formula_fn!(
+            /// Joins the rows of two tables where the `left_on` columns of
+            /// `left` equal the `right_on` columns of `right`.
+            ///
+            /// Both tables must include their header rows (eg,
+            /// `Users[#ALL]`). Columns are given by name or 1-based column
+            /// number. `kind` is `"INNER"` (the default), `"LEFT"`, or
+            /// `"OUTER"`. The output contains every column of `left` followed
+            /// by the columns of `right` that are not keys. Rows with a blank
+            /// key never match.
+            #[name = "TABLE.JOIN"]
+            #[examples(
+                "TABLE.JOIN(Users[#ALL], Orders[#ALL], \"id\", \"user_id\")",
+                "TABLE.JOIN(Users[#ALL], Orders[#ALL], {\"first\", \"last\"}, {\"first\", \"last\"}, \"LEFT\")"
+            )]
+            fn TABLE_JOIN(
+                span: Span,
+                left: Array,
+                right: Array,
+                left_on: (Spanned<Array>),
+                right_on: (Spanned<Array>),
+                kind: (Option<Spanned<String>>),
+            ) {
+                let left_on = table_columns(table_headers(&left, span)?, &left_on)?;
+                let right_on = table_columns(table_headers(&right, span)?, &right_on)?;
+                let kind = match kind {
+                    None => DataTableJoinKind::Inner,
+                    Some(kind) => DataTableJoinKind::parse(&kind.inner)
+                        .ok_or(RunErrorMsg::InvalidArgument.with_span(kind.span))?,
+                };
+                transform::join(&left, &right, &left_on, &right_on, kind)
+                    .map_err(|e| e.with_span(span))?
+            }
+        ),
     ]
 }
 
+/// Returns the header row of a table passed to one of the `TABLE.*`
+/// functions.
+fn table_headers(table: &Array, span: Span) -> CodeResult<&[CellValue]> {
+    table.get_row(0).map_err(|e| e.with_span(span))
+}
+
+/// Looks up the columns named in `columns` within `headers`.
+fn table_columns(headers: &[CellValue], columns: &Spanned<Array>) -> CodeResult<Vec<usize>> {
+    transform::find_columns(headers, columns.inner.cell_values_slice())
+        .map_err(|e| e.with_span(columns.span))
+}
+
+fn table_aggregate(aggregate: Option<Spanned<String>>) -> CodeResult<DataTableAggregate> {
+    match aggregate {
+        None => Ok(DataTableAggregate::Sum),
+        Some(aggregate) => DataTableAggregate::parse(&aggregate.inner)
+            .ok_or(RunErrorMsg::InvalidArgument.with_span(aggregate.span)),
+    }
+}
+
 fn by_column_to_axis(by_column: Option<bool>) -> Axis {
     match by_column {
         Some(true) => Axis::X,
@@ -693,4 +849,93 @@ mod tests {
         // Excel rejects this but it's perfectly reasonable
         assert_eq!("0", eval_to_string(&g, "SUMPRODUCT()"));
     }
+
+    #[test]
+    fn test_formula_table_transforms() {
+        let sales = array![
+            "region", "product", "units";
+            "East", "apple", 10;
+            "West", "apple", 5;
+            "East", "pear", 3;
+            "West", "pear", 7;
+        ];
+        let g = GridController::from_grid(Grid::from_array(pos![A1], &sales), 0);
+
+        let expected = array![
+            "region", "units";
+            "East", 13;
+            "West", 12;
+        ];
+        assert_eq!(
+            expected.to_string(),
+            eval_to_string(&g, "TABLE.GROUPBY(A1:C5, \"Region\", 3)"),
+        );
+        let expected = array![
+            "product", "units";
+            "apple", 7.5;
+            "pear", 5;
+        ];
+        assert_eq!(
+            expected.to_string(),
+            eval_to_string(
+                &g,
+                "TABLE.GROUPBY(A1:C5, {\"product\"}, \"units\", \"avg\")"
+            ),
+        );
+
+        let expected = array![
+            "product", "East", "West";
+            "apple", 10, 5;
+            "pear", 3, 7;
+        ];
+        assert_eq!(
+            expected.to_string(),
+            eval_to_string(&g, "TABLE.PIVOT(A1:C5, \"product\", \"region\", \"units\")"),
+        );
+
+        let expected = array![
+            "region", "product", "Attribute", "Value";
+            "East", "apple", "units", 10;
+            "West", "apple", "units", 5;
+            "East", "pear", "units", 3;
+            "West", "pear", "units", 7;
+        ];
+        assert_eq!(
+            expected.to_string(),
+            eval_to_string(&g, "TABLE.UNPIVOT(A1:C5, {1, 2})"),
+        );
+
+        let expected = array![
+            "region", "product", "units", "product_2", "units_2";
+            "East", "apple", 10, "apple", 10;
+            "East", "pear", 3, "apple", 10;
+        ];
+        assert_eq!(
+            expected.to_string(),
+            eval_to_string(
+                &g,
+                "TABLE.JOIN(A1:C5, A1:C2, \"region\", \"region\", \"inner\")"
+            ),
+        );
+
+        assert_eq!(
+            RunErrorMsg::Expected {
+                expected: "table column".into(),
+                got: Some("price".into()),
+            },
+            eval_to_err(&g, "TABLE.GROUPBY(A1:C5, \"region\", \"price\")").msg,
+        );
+        assert_eq!(
+            RunErrorMsg::InvalidArgument,
+            eval_to_err(
+                &g,
+                "TABLE.GROUPBY(A1:C5, \"region\", \"units\", \"median\")"
+            )
+            .msg,
+        );
+        assert_eq!(
+            RunErrorMsg::InvalidArgument,
+            eval_to_err(&g, "TABLE.JOIN(A1:C5, A1:C5, 1, 1, \"sideways\")").msg,
+        );
+    }
 }
@@ -1,5 +1,9 @@
 use bigdecimal::num_traits::ToPrimitive;
-use chrono::{Datelike, Months, NaiveDate, Timelike};
+use chrono::{
+    DateTime, Datelike, FixedOffset, Local, Months, NaiveDate, NaiveDateTime, NaiveTime, TimeZone,
+    Timelike,
+};
+use chrono_tz::Tz;
 
 use super::*;
 
@@ -15,23 +19,21 @@ fn get_functions() -> Vec<FormulaFunction> {
     vec![
         // Current date/time
         formula_fn!(
-            /// Returns the current local date and time.
-            ///
-            /// This depends on the time configuration of the computer where the
-            /// formula is run, which may depend on timezone.
+            /// Returns the current date and time in the file's time zone, or in
+            /// the user's local time zone if the file has none.
             #[examples("NOW()")]
-            fn NOW() {
-                CellValue::DateTime(chrono::Local::now().naive_local())
+            fn NOW(ctx: Ctx) {
+                let now = CellValue::DateTime(ctx.grid_controller.now());
+                date_result(ctx, now)
             }
         ),
         formula_fn!(
-            /// Returns the current local date.
-            ///
-            /// This depends on the time configuration of the computer where the
-            /// formula is run, which may depend on timezone.
+            /// Returns the current date in the file's time zone, or in the user's
+            /// local time zone if the file has none.
             #[examples("TODAY()")]
-            fn TODAY() {
-                CellValue::Date(chrono::Local::now().date_naive())
+            fn TODAY(ctx: Ctx) {
+                let today = CellValue::Date(ctx.grid_controller.now().date());
+                date_result(ctx, today)
             }
         ),
         // Constructors
@@ -57,9 +59,9 @@ fn get_functions() -> Vec<FormulaFunction> {
             /// (in UTC) that Alexei Leonov began the first-ever spacewalk.
             #[examples("DATE(2024, 04, 08)", "DATE(1995, 12, 25)", "DATE(1965, 3, 18)")]
             #[zip_map]
-            fn DATE(span: Span, [year]: i64, [month]: i64, [day]: i64) {
+            fn DATE(ctx: Ctx, span: Span, [year]: i64, [month]: i64, [day]: i64) {
                 // IIFE to mimic try_block
-                (|| {
+                let date = (|| {
                     let mut ret = chrono::NaiveDate::from_ymd_opt(year.try_into().ok()?, 1, 1)?;
 
                     ret = match month.checked_sub(1)? {
@@ -82,7 +84,31 @@ fn get_functions() -> Vec<FormulaFunction> {
 
                     Some(ret)
                 })()
-                .ok_or(RunErrorMsg::Overflow.with_span(span))?
+                .ok_or(RunErrorMsg::Overflow.with_span(span))?;
+                date_result(ctx, CellValue::Date(date))
+            }
+        ),
+        formula_fn!(
+            /// Returns the date of a date string (such as `"2024-04-08"` or
+            /// `"4/8/2024"`), using the file's locale to read ambiguous dates.
+            /// The time of a date time string is ignored.
+            #[examples("DATEVALUE(\"2024-04-08\")", "DATEVALUE(\"4/8/2024 2:30 PM\")")]
+            #[zip_map]
+            fn DATEVALUE(ctx: Ctx, [date_text]: (Spanned<String>)) {
+                let locale = &ctx.grid_controller.grid().locale;
+                let date = match CellValue::string_to_cell_value_with_locale(
+                    &date_text.inner,
+                    false,
+                    locale,
+                )
+                .0
+                {
+                    CellValue::Date(d) => d,
+                    CellValue::DateTime(dt) => dt.date(),
+                    CellValue::DateTimeTz(dt) => dt.date_naive(),
+                    _ => return Err(RunErrorMsg::Value.with_span(date_text.span)),
+                };
+                date_result(ctx, CellValue::Date(date))
             }
         ),
         formula_fn!(
@@ -216,10 +242,12 @@ fn get_functions() -> Vec<FormulaFunction> {
                 "YEAR(-DURATION.YMD(1, 2, 3) - DURATION.HMS(6, 10, 15)) = -2"
             )]
             #[zip_map]
-            fn YEAR([date]: (Spanned<CellValue>)) {
+            fn YEAR(ctx: Ctx, [date]: (Spanned<CellValue>)) {
+                let date = serial_date_arg(ctx, *date)?;
                 match &date.inner {
                     CellValue::Blank => 0,
                     CellValue::DateTime(dt) => dt.year(),
+                    CellValue::DateTimeTz(dt) => dt.year(),
                     CellValue::Date(d) => d.year(),
                     CellValue::Time(_t) => 0,
                     CellValue::Duration(d) => d.years(),
@@ -257,10 +285,12 @@ fn get_functions() -> Vec<FormulaFunction> {
                 "MONTH(-DURATION.YMD(1, 2, 3) - DURATION.HMS(6, 10, 15)) = 1"
             )]
             #[zip_map]
-            fn MONTH([date]: (Spanned<CellValue>)) {
+            fn MONTH(ctx: Ctx, [date]: (Spanned<CellValue>)) {
+                let date = serial_date_arg(ctx, *date)?;
                 match &date.inner {
                     CellValue::Blank => 0,
                     CellValue::DateTime(dt) => dt.month(),
+                    CellValue::DateTimeTz(dt) => dt.month(),
                     CellValue::Date(d) => d.month(),
                     CellValue::Time(_t) => 0,
                     CellValue::Duration(d) => d.subyear_months() as u32,
@@ -296,7 +326,8 @@ fn get_functions() -> Vec<FormulaFunction> {
                 "DAY(-DURATION.YMD(1, 2, 3) - DURATION.HMS(6, 10, 15)) = -4"
             )]
             #[zip_map]
-            fn DAY([date]: (Spanned<CellValue>)) {
+            fn DAY(ctx: Ctx, [date]: (Spanned<CellValue>)) {
+                let date = serial_date_arg(ctx, *date)?;
                 match &date.inner {
                     CellValue::Blank => 0,
                     CellValue::Number(n) => n
@@ -304,6 +335,7 @@ fn get_functions() -> Vec<FormulaFunction> {
                         .to_i64()
                         .ok_or(RunErrorMsg::Overflow.with_span(date.span))?,
                     CellValue::DateTime(dt) => dt.day() as i64,
+                    CellValue::DateTimeTz(dt) => dt.day() as i64,
                     CellValue::Date(d) => d.day() as i64,
                     CellValue::Time(_t) => 0,
                     CellValue::Duration(d) => d.days(),
@@ -353,6 +385,7 @@ fn get_functions() -> Vec<FormulaFunction> {
                         .ok_or(RunErrorMsg::Overflow.with_span(time.span))?
                         .rem_euclid(24) as u32,
                     CellValue::DateTime(dt) => dt.hour(),
+                    CellValue::DateTimeTz(dt) => dt.hour(),
                     CellValue::Date(_d) => 0,
                     CellValue::Time(t) => t.hour(),
                     CellValue::Duration(d) => d.subday_hours() as u32,
@@ -402,6 +435,7 @@ fn get_functions() -> Vec<FormulaFunction> {
                         .ok_or(RunErrorMsg::Overflow.with_span(time.span))?
                         .rem_euclid(60) as u32,
                     CellValue::DateTime(dt) => dt.minute(),
+                    CellValue::DateTimeTz(dt) => dt.minute(),
                     CellValue::Date(_d) => 0,
                     CellValue::Time(t) => t.minute(),
                     CellValue::Duration(d) => d.subhour_minutes() as u32,
@@ -451,6 +485,7 @@ fn get_functions() -> Vec<FormulaFunction> {
                         .ok_or(RunErrorMsg::Overflow.with_span(time.span))?
                         .rem_euclid(60) as u32,
                     CellValue::DateTime(dt) => dt.second(),
+                    CellValue::DateTimeTz(dt) => dt.second(),
                     CellValue::Date(_d) => 0,
                     CellValue::Time(t) => t.second(),
                     CellValue::Duration(d) => d.subminute_seconds() as u32,
@@ -472,9 +507,11 @@ fn get_functions() -> Vec<FormulaFunction> {
             /// month is returned.
             #[examples("EDATE(DATE(2024, 04, 08), 8)")]
             #[zip_map]
-            fn EDATE(span: Span, [day]: NaiveDate, [months_offset]: i64) {
-                add_months_offset_to_day(day, months_offset)
-                    .ok_or_else(|| RunErrorMsg::Overflow.with_span(span))?
+            fn EDATE(ctx: Ctx, span: Span, [day]: (Spanned<CellValue>), [months_offset]: i64) {
+                let day = date_arg(ctx, *day)?;
+                let date = add_months_offset_to_day(day, months_offset)
+                    .ok_or_else(|| RunErrorMsg::Overflow.with_span(span))?;
+                date_result(ctx, CellValue::Date(date))
             }
         ),
         formula_fn!(
@@ -489,17 +526,163 @@ fn get_functions() -> Vec<FormulaFunction> {
             ///   many months earlier.
             #[examples("EOMONTH(DATE(2024, 04, 08))", "EOMONTH(DATE(2024, 04, 08), 8)")]
             #[zip_map]
-            fn EOMONTH(span: Span, [day]: NaiveDate, [months_offset]: (Option<i64>)) {
+            fn EOMONTH(
+                ctx: Ctx,
+                span: Span,
+                [day]: (Spanned<CellValue>),
+                [months_offset]: (Option<i64>),
+            ) {
+                let day = date_arg(ctx, *day)?;
                 let day = add_months_offset_to_day(day, months_offset.unwrap_or(0))
                     .ok_or_else(|| RunErrorMsg::Overflow.with_span(span))?;
 
                 // Find last day of month
-                (1..=31).rev().find_map(|i| day.with_day(i))
+                match (1..=31).rev().find_map(|i| day.with_day(i)) {
+                    Some(date) => date_result(ctx, CellValue::Date(date)),
+                    None => CellValue::Blank,
+                }
+            }
+        ),
+        // Time zones
+        formula_fn!(
+            /// Converts a date time from one time zone to another, returning a
+            /// date time with a UTC offset.
+            ///
+            /// Time zones may be IANA names (such as `"America/New_York"`) or
+            /// UTC offsets (such as `"+05:30"`). An empty string refers to the
+            /// file's time zone, or the user's local time zone if the file has
+            /// none.
+            ///
+            /// If `date_time` already has a UTC offset, then `from_zone` is
+            /// ignored.
+            #[examples(
+                "CONVERT_TZ(DATE(2024, 3, 10) + TIME(14, 30, 0), \"UTC\", \"America/New_York\")",
+                "CONVERT_TZ(NOW(), \"\", \"+05:30\")"
+            )]
+            fn CONVERT_TZ(
+                ctx: Ctx,
+                date_time: (Spanned<CellValue>),
+                from_zone: (Spanned<String>),
+                to_zone: (Spanned<String>),
+            ) {
+                let file_time_zone = ctx.grid_controller.grid().time_zone;
+                let to_zone = TimeZoneArg::parse(&to_zone.inner, file_time_zone)
+                    .ok_or(RunErrorMsg::InvalidArgument.with_span(to_zone.span))?;
+                let date_time_span = date_time.span;
+                let date_time = match date_time.inner {
+                    CellValue::DateTimeTz(dt) => dt,
+                    CellValue::DateTime(_) | CellValue::Date(_) => {
+                        let naive = NaiveDateTime::try_from(&date_time.inner)
+                            .map_err(|e| e.with_span(date_time_span))?;
+                        TimeZoneArg::parse(&from_zone.inner, file_time_zone)
+                            .ok_or(RunErrorMsg::InvalidArgument.with_span(from_zone.span))?
+                            .localize(naive)
+                            .ok_or(RunErrorMsg::InvalidArgument.with_span(date_time_span))?
+                    }
+                    other => {
+                        return Err(RunErrorMsg::Expected {
+                            expected: "date time".into(),
+                            got: Some(other.type_name().into()),
+                        }
+                        .with_span(date_time_span));
+                    }
+                };
+                CellValue::DateTimeTz(to_zone.convert(date_time))
             }
         ),
     ]
 }
 
+/// Returns a date or date time as an Excel serial number when the file uses
+/// serial dates. Other values are returned unchanged.
+fn date_result(ctx: &Ctx<'_>, value: CellValue) -> CellValue {
+    let date_settings = ctx.grid_controller.grid().date_settings;
+    if !date_settings.serial_dates {
+        return value;
+    }
+    let date_time = match value {
+        CellValue::Date(date) => date.and_time(NaiveTime::MIN),
+        CellValue::DateTime(date_time) => date_time,
+        _ => return value,
+    };
+    CellValue::from(date_settings.date_system.date_time_to_serial(date_time))
+}
+
+/// Reads a number as an Excel serial date when the file uses serial dates.
+/// Other values are returned unchanged.
+fn serial_date_arg(ctx: &Ctx<'_>, value: Spanned<&CellValue>) -> CodeResult<Spanned<CellValue>> {
+    let date_settings = ctx.grid_controller.grid().date_settings;
+    match value.inner {
+        CellValue::Number(n) if date_settings.serial_dates => {
+            let date_time = n
+                .to_f64()
+                .and_then(|serial| date_settings.date_system.serial_to_date_time(serial))
+                .ok_or(RunErrorMsg::Num.with_span(value.span))?;
+            Ok(Spanned {
+                span: value.span,
+                inner: CellValue::DateTime(date_time),
+            })
+        }
+        _ => Ok(value.map(|value| value.clone())),
+    }
+}
+
+/// Coerces a value to a date, reading numbers as Excel serial dates when the
+/// file uses serial dates.
+fn date_arg(ctx: &Ctx<'_>, value: Spanned<&CellValue>) -> CodeResult<NaiveDate> {
+    let value = serial_date_arg(ctx, value)?;
+    NaiveDate::try_from(&value.inner).map_err(|e| e.with_span(value.span))
+}
+
+/// Time zone argument for a formula: either an IANA name, a fixed UTC
+/// offset, or the user's local time zone.
+#[derive(Debug, Clone, Copy)]
+enum TimeZoneArg {
+    Named(Tz),
+    Offset(FixedOffset),
+    Local,
+}
+impl TimeZoneArg {
+    /// Parses a time zone name or offset. An empty string refers to the
+    /// file's time zone (or the user's local time zone if the file has none).
+    fn parse(s: &str, file_time_zone: Option<Tz>) -> Option<Self> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Some(file_time_zone.map_or(Self::Local, Self::Named));
+        }
+        if let Ok(tz) = s.parse::<Tz>() {
+            return Some(Self::Named(tz));
+        }
+        s.parse::<FixedOffset>().ok().map(Self::Offset)
+    }
+
+    /// Reads a date time without an offset as a local time in this zone. Times
+    /// skipped by daylight saving return `None`; repeated times use the
+    /// earlier offset.
+    fn localize(self, date_time: NaiveDateTime) -> Option<DateTime<FixedOffset>> {
+        match self {
+            Self::Named(tz) => tz
+                .from_local_datetime(&date_time)
+                .earliest()
+                .map(|dt| dt.fixed_offset()),
+            Self::Offset(offset) => offset.from_local_datetime(&date_time).single(),
+            Self::Local => Local
+                .from_local_datetime(&date_time)
+                .earliest()
+                .map(|dt| dt.fixed_offset()),
+        }
+    }
+
+    /// Converts a date time to this zone.
+    fn convert(self, date_time: DateTime<FixedOffset>) -> DateTime<FixedOffset> {
+        match self {
+            Self::Named(tz) => date_time.with_timezone(&tz).fixed_offset(),
+            Self::Offset(offset) => date_time.with_timezone(&offset),
+            Self::Local => date_time.with_timezone(&Local).fixed_offset(),
+        }
+    }
+}
+
 /// Adds a number of months to a date, or returns `None` in the case of
 /// overflow.
 ///
@@ -517,7 +700,11 @@ fn add_months_offset_to_day(day: NaiveDate, months: i64) -> Option<NaiveDate> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{controller::GridController, formulas::tests::*};
+    use crate::{
+        controller::GridController,
+        date_time::{DateSettings, DateSystem},
+        formulas::tests::*,
+    };
 
     #[test]
     fn test_formula_now_today() {
@@ -841,4 +1028,127 @@ mod tests {
             eval_to_string(&g, "EDATE(DATE(2008, 03, 30), -1)"),
         );
     }
+
+    #[test]
+    fn test_formula_convert_tz() {
+        let mut g = GridController::new();
+        let dt = "DATE(2024, 3, 10) + TIME(14, 30, 0)";
+
+        assert_eq!(
+            "2024-03-10 10:30:00 -04:00",
+            eval_to_string(
+                &g,
+                &format!("CONVERT_TZ({dt}, \"UTC\", \"America/New_York\")")
+            ),
+        );
+        assert_eq!(
+            "2024-03-10 20:00:00 +05:30",
+            eval_to_string(&g, &format!("CONVERT_TZ({dt}, \"+00:00\", \"+05:30\")")),
+        );
+        // a value with an offset ignores `from_zone`
+        assert_eq!(
+            "2024-03-10 14:30:00 +00:00",
+            eval_to_string(
+                &g,
+                &format!(
+                    "CONVERT_TZ(CONVERT_TZ({dt}, \"UTC\", \"Asia/Tokyo\"), \"Europe/Paris\", \"UTC\")"
+                ),
+            ),
+        );
+        // the time of a converted value is in its zone
+        assert_eq!(
+            "10",
+            eval_to_string(
+                &g,
+                &format!("HOUR(CONVERT_TZ({dt}, \"UTC\", \"America/New_York\"))")
+            ),
+        );
+
+        // an empty zone is the file's time zone
+        g.set_time_zone("Asia/Tokyo", None).unwrap();
+        assert_eq!(
+            "2024-03-10 05:30:00 +00:00",
+            eval_to_string(&g, &format!("CONVERT_TZ({dt}, \"\", \"UTC\")")),
+        );
+
+        // skipped by daylight saving time
+        assert_eq!(
+            RunErrorMsg::InvalidArgument,
+            eval_to_err(
+                &g,
+                "CONVERT_TZ(DATE(2024, 3, 10) + TIME(2, 30, 0), \"America/New_York\", \"UTC\")"
+            )
+            .msg,
+        );
+        assert_eq!(
+            RunErrorMsg::InvalidArgument,
+            eval_to_err(&g, &format!("CONVERT_TZ({dt}, \"UTC\", \"Mars/Olympus\")")).msg,
+        );
+        assert!(matches!(
+            eval_to_err(&g, "CONVERT_TZ(5, \"UTC\", \"UTC\")").msg,
+            RunErrorMsg::Expected { .. },
+        ));
+    }
+
+    #[test]
+    fn test_formula_datevalue() {
+        let g = GridController::new();
+        assert_eq!(
+            "2024-04-08",
+            eval_to_string(&g, "DATEVALUE(\"2024-04-08\")")
+        );
+        assert_eq!(
+            "2024-04-08",
+            eval_to_string(&g, "DATEVALUE(\"4/8/2024 2:30 PM\")")
+        );
+        assert_eq!(
+            RunErrorMsg::Value,
+            eval_to_err(&g, "DATEVALUE(\"not a date\")").msg,
+        );
+    }
+
+    #[test]
+    fn test_formula_serial_dates() {
+        let mut g = GridController::new();
+
+        // numbers are not dates by default
+        assert_eq!("45000", eval_to_string(&g, "DAY(45000)"));
+        assert!(
+            eval_to_err(&g, "YEAR(45000)")
+                .msg
+                .to_string()
+                .contains("date")
+        );
+
+        g.set_date_settings(
+            DateSettings {
+                date_system: DateSystem::Excel1900,
+                serial_dates: true,
+            },
+            None,
+        );
+        assert_eq!("45390", eval_to_string(&g, "DATEVALUE(\"2024-04-08\")"));
+        assert_eq!("45390", eval_to_string(&g, "DATE(2024, 4, 8)"));
+        assert_eq!("2023", eval_to_string(&g, "YEAR(45000)"));
+        assert_eq!("3", eval_to_string(&g, "MONTH(45000)"));
+        assert_eq!("15", eval_to_string(&g, "DAY(45000)"));
+        assert_eq!("45031", eval_to_string(&g, "EDATE(45000, 1)"));
+        assert_eq!("45016", eval_to_string(&g, "EOMONTH(45000, 0)"));
+        assert_eq!("45390", eval_to_string(&g, "EDATE(DATE(2024, 3, 8), 1)"));
+        assert!(matches!(
+            eval(&g, "NOW()"),
+            Value::Single(CellValue::Number(_))
+        ));
+
+        g.set_date_settings(
+            DateSettings {
+                date_system: DateSystem::Excel1904,
+                serial_dates: true,
+            },
+            None,
+        );
+        assert_eq!("43928", eval_to_string(&g, "DATEVALUE(\"2024-04-08\")"));
+        assert_eq!("2023", eval_to_string(&g, "YEAR(43538)"));
+        assert_eq!("15", eval_to_string(&g, "DAY(43538)"));
+    }
 }
@@ -2,7 +2,7 @@ use itertools::Itertools;
 use regex::Regex;
 use smallvec::smallvec;
 
-use crate::{ArraySize, CodeResultExt, a1::SheetCellRefRange};
+use crate::{ArraySize, CodeResultExt, Hyperlink, a1::SheetCellRefRange};
 
 use super::*;
 
@@ -379,6 +379,25 @@ fn get_functions() -> Vec<FormulaFunction> {
                     .inner
             }
         ),
+        formula_fn!(
+            /// Returns a link to `url` that is displayed as `label` (or the
+            /// url if `label` is omitted).
+            ///
+            /// `url` may be a web address (http, https, or mailto) or `#`
+            /// followed by a reference to a location in the file, such as
+            /// `"#Sheet2!A1"`.
+            #[examples(
+                "HYPERLINK(\"https://www.quadratichq.com\")",
+                "HYPERLINK(\"https://www.quadratichq.com\", \"Quadratic\")",
+                "HYPERLINK(\"#Sheet2!A1\", \"Go to Sheet2\")"
+            )]
+            #[zip_map]
+            fn HYPERLINK(span: Span, [url]: String, [label]: (Option<String>)) {
+                let link = Hyperlink::new(url, label.unwrap_or_default())
+                    .ok_or(RunErrorMsg::InvalidArgument.with_span(span))?;
+                CellValue::Hyperlink(Box::new(link))
+            }
+        ),
     ]
 }
 
@@ -1418,4 +1437,28 @@ mod tests {
             check_syntax_to_err(&g, s).msg,
         );
     }
+
+    #[test]
+    fn test_formula_hyperlink() {
+        let g = GridController::new();
+        assert_eq!(
+            eval(&g, "HYPERLINK(\"https://example.com\", \"Example\")"),
+            Value::Single(CellValue::Hyperlink(Box::new(
+                crate::Hyperlink::new("https://example.com", "Example").unwrap()
+            ))),
+        );
+        assert_eq!(
+            "https://example.com",
+            eval_to_string(&g, "HYPERLINK(\"https://example.com\")"),
+        );
+        assert_eq!("Go", eval_to_string(&g, "HYPERLINK(\"#B2\", \"Go\")"));
+        assert_eq!(
+            RunErrorMsg::InvalidArgument,
+            eval_to_err(&g, "HYPERLINK(\"\")").msg,
+        );
+        assert_eq!(
+            RunErrorMsg::InvalidArgument,
+            eval_to_err(&g, "HYPERLINK(\"javascript:alert(1)\", \"Click\")").msg,
+        );
+    }
 }
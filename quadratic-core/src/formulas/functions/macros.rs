@@ -139,6 +139,7 @@ macro_rules! formula_fn {
                 $(#[$($attr)*])*
                 $($params)*
             ),
+            skip_hidden_rows: None,
         }
     };
 
@@ -147,6 +148,7 @@ macro_rules! formula_fn {
         $(#[doc = $additional_doc:expr])*
         $(#[include_args_in_completion($include_args_in_completion:expr)])?
         $(#[name = $name_str:literal])?
+        $(#[skip_hidden_rows($skip_hidden_rows:expr)])?
         #[examples($($example_str:expr),+ $(,)?)]
         $(#[$($attr:tt)*])*
         fn $fn_name:ident( $($params:tt)* ) { $($body:tt)* }
@@ -169,6 +171,10 @@ macro_rules! formula_fn {
                 $(#[$($attr)*])*
                 $($params)*
             ),
+            skip_hidden_rows: [
+                $(Some($skip_hidden_rows as $crate::formulas::functions::SkipHiddenRowsFn), )?
+                None,
+            ][0],
         }
     }};
 }
@@ -505,6 +511,8 @@ macro_rules! params_list {
     // Entry points (at the bottom so that the other rules take priority)
     () => { vec![] };
     ($($arg_name:tt: $arg_type:tt),+ $(,)?) => {{
+        // special params (eg, `ctx: Ctx`) are not appended
+        #[allow(unused_mut)]
         let mut result = vec![];
 
         $(
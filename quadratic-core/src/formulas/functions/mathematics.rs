@@ -1,6 +1,7 @@
 use bigdecimal::BigDecimal;
 
 use super::*;
+use crate::util::normalize_decimal;
 
 pub const CATEGORY: FormulaFunctionCategory = FormulaFunctionCategory {
     include_in_docs: true,
@@ -17,8 +18,10 @@ fn get_functions() -> Vec<FormulaFunction> {
             /// Adds all values.
             /// Returns `0` if given no values.
             #[examples("SUM(B2:C6, 15, E1)")]
-            fn SUM(numbers: (Iter<f64>)) {
-                numbers.sum::<CodeResult<f64>>()
+            fn SUM(numbers: (Iter<BigDecimal>)) {
+                numbers
+                    .sum::<CodeResult<BigDecimal>>()
+                    .map(normalize_decimal)
             }
         ),
         formula_fn!(
@@ -40,8 +43,10 @@ fn get_functions() -> Vec<FormulaFunction> {
             ) {
                 let criteria = Criterion::try_from(*criteria)?;
                 let numbers =
-                    criteria.iter_matching_coerced::<f64>(eval_range, sum_range.as_ref())?;
-                numbers.sum::<CodeResult<f64>>()
+                    criteria.iter_matching_coerced::<BigDecimal>(eval_range, sum_range.as_ref())?;
+                numbers
+                    .sum::<CodeResult<BigDecimal>>()
+                    .map(normalize_decimal)
             }
         ),
         formula_fn!(
@@ -65,11 +70,11 @@ fn get_functions() -> Vec<FormulaFunction> {
                     more_eval_ranges_and_criteria,
                     |_ctx, eval_ranges_and_criteria| {
                         // Same as `SUMIF`
-                        let numbers = Criterion::iter_matching_multi_coerced::<f64>(
+                        let numbers = Criterion::iter_matching_multi_coerced::<BigDecimal>(
                             &eval_ranges_and_criteria,
                             &sum_range,
                         )?;
-                        Ok(numbers.sum::<CodeResult<f64>>()?.into())
+                        Ok(normalize_decimal(numbers.sum::<CodeResult<BigDecimal>>()?).into())
                     },
                 )?
             }
@@ -476,6 +481,39 @@ mod tests {
         assert_eq!("21", eval_to_string(&g, "SUM(({1, 2}, ({3; 4}, {5, 6})))"));
     }
 
+    #[test]
+    fn test_sum_decimal_precision() {
+        let mut g = GridController::new();
+        let sheet_id = g.sheet_ids()[0];
+        let sheet = g.sheet_mut(sheet_id);
+        for y in 1..=10 {
+            let _ =
+                sheet.set_cell_value(Pos { x: 1, y }, CellValue::Number("0.1".parse().unwrap()));
+        }
+        assert_eq!("1", eval_to_string(&g, "SUM(A1:A10)"));
+        assert_eq!("TRUE", eval_to_string(&g, "SUM(A1:A10) = 1"));
+        assert_eq!("0.3", eval_to_string(&g, "SUM(0.1, 0.2)"));
+        assert_eq!("2.5", eval_to_string(&g, "SUM(1.50, 1)"));
+        assert_eq!("300", eval_to_string(&g, "SUM(100, 200)"));
+        assert_eq!("1", eval_to_string(&g, "1.0"));
+        assert_eq!("TRUE", eval_to_string(&g, "0.1 + 0.2 = 0.3"));
+        assert_eq!("TRUE", eval_to_string(&g, "SUM(0.1, 0.2) = 0.3"));
+        assert_eq!("0.3", eval_to_string(&g, "SUMIF(A1:A3, \">0\")"));
+        assert_eq!("0.3", eval_to_string(&g, "SUMIFS(A1:A3, A1:A3, \">0\")"));
+
+        // Literals keep all of their digits.
+        assert_eq!(
+            "12345678901234.57",
+            eval_to_string(&g, "12345678901234.56 + 0.01"),
+        );
+        assert_eq!(
+            "1234567890123456.78",
+            eval_to_string(&g, "SUM(1234567890123456.77, 0.01)"),
+        );
+        assert_eq!("2.68", eval_to_string(&g, "ROUND(2.675, 2)"));
+        assert_eq!("1.21", eval_to_string(&g, "1.1 * 1.1"));
+    }
+
     #[test]
     fn test_sumif() {
         let g = GridController::new();
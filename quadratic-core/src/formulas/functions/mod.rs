@@ -155,6 +155,10 @@ impl FormulaFnArgs {
 /// Function pointer that represents the body of a formula function.
 pub type FormulaFn = for<'a> fn(&'a mut Ctx<'_>, FormulaFnArgs) -> CodeResult<Value>;
 
+/// Function pointer that returns whether a formula function skips hidden rows,
+/// given the value of its first argument.
+pub type SkipHiddenRowsFn = fn(&Spanned<Value>) -> bool;
+
 /// Formula function with associated metadata.
 pub struct FormulaFunction {
     pub name: &'static str,
@@ -163,6 +167,11 @@ pub struct FormulaFunction {
     pub examples: &'static [&'static str],
     pub doc: &'static str,
     pub eval: FormulaFn,
+
+    /// If set and it returns true for the first argument, cell references in
+    /// the other arguments are read without hidden rows (eg, `SUBTOTAL(109,
+    /// A1:A10)`).
+    pub skip_hidden_rows: Option<SkipHiddenRowsFn>,
 }
 impl FormulaFunction {
     /// Returns a user-friendly string containing the usages of this function,
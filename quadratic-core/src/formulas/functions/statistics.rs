@@ -191,6 +191,105 @@ fn get_functions() -> Vec<FormulaFunction> {
                 Ok(CellValue::from(stdev))
             }
         ),
+        formula_fn!(
+            /// Returns the variance of all values (population variance).
+            /// Uses the formula: Σ(x - μ)²/n where μ is the mean and n is the count.
+            #[examples("VARP(A1:A6)", "VARP(1, 2, 3, 4, 5)")]
+            fn VARP(span: Span, numbers: (Iter<f64>)) {
+                let mut sum = 0.0;
+                let mut sum_sq = 0.0;
+                let mut count = 0;
+
+                for num in numbers {
+                    let val = num?;
+                    sum += val;
+                    sum_sq += val * val;
+                    count += 1;
+                }
+
+                if count == 0 {
+                    return Err(RunErrorMsg::DivideByZero.with_span(span));
+                }
+
+                let mean = sum / (count as f64);
+                let variance = (sum_sq - sum * mean) / (count as f64);
+                Ok(CellValue::from(variance))
+            }
+        ),
+        formula_fn!(
+            /// Returns the standard deviation of all values (population standard deviation).
+            /// Uses the formula: √(Σ(x - μ)²/n) where μ is the mean and n is the count.
+            #[examples("STDEVP(A1:A6)", "STDEVP(1, 2, 3, 4, 5)")]
+            fn STDEVP(span: Span, numbers: (Iter<f64>)) {
+                let mut sum = 0.0;
+                let mut sum_sq = 0.0;
+                let mut count = 0;
+
+                for x in numbers {
+                    let x = x?;
+                    sum += x;
+                    sum_sq += x * x;
+                    count += 1;
+                }
+
+                if count == 0 {
+                    return Err(RunErrorMsg::DivideByZero.with_span(span));
+                }
+
+                let mean = sum / (count as f64);
+                let variance = (sum_sq - sum * mean) / (count as f64);
+                let stdev = variance.sqrt();
+
+                Ok(CellValue::from(stdev))
+            }
+        ),
+        formula_fn!(
+            /// Returns an aggregate of the values in one or more ranges. The
+            /// aggregate is chosen by `function_num`:
+            ///
+            /// | Includes hidden rows | Ignores hidden rows | Function  |
+            /// | -------------------- | ------------------- | --------- |
+            /// | 1                    | 101                 | `AVERAGE` |
+            /// | 2                    | 102                 | `COUNT`   |
+            /// | 3                    | 103                 | `COUNTA`  |
+            /// | 4                    | 104                 | `MAX`     |
+            /// | 5                    | 105                 | `MIN`     |
+            /// | 6                    | 106                 | `PRODUCT` |
+            /// | 7                    | 107                 | `STDEV`   |
+            /// | 8                    | 108                 | `STDEVP`  |
+            /// | 9                    | 109                 | `SUM`     |
+            /// | 10                   | 110                 | `VAR`     |
+            /// | 11                   | 111                 | `VARP`    |
+            #[skip_hidden_rows(|function_num| {
+                function_num
+                    .clone()
+                    .try_coerce::<f64>()
+                    .is_ok_and(|n| n.inner > 100.0)
+            })]
+            #[examples("SUBTOTAL(9, A1:A10)", "SUBTOTAL(101, A1:A10, C1:C10)")]
+            fn SUBTOTAL(ctx: Ctx, span: Span, function_num: (Spanned<i64>), ranges: FormulaFnArgs) {
+                let name = match function_num.inner {
+                    1 | 101 => "AVERAGE",
+                    2 | 102 => "COUNT",
+                    3 | 103 => "COUNTA",
+                    4 | 104 => "MAX",
+                    5 | 105 => "MIN",
+                    6 | 106 => "PRODUCT",
+                    7 | 107 => "STDEV",
+                    8 | 108 => "STDEVP",
+                    9 | 109 => "SUM",
+                    10 | 110 => "VAR",
+                    11 | 111 => "VARP",
+                    _ => return Err(RunErrorMsg::InvalidArgument.with_span(function_num.span)),
+                };
+                let mut ranges = ranges;
+                ranges.error_if_no_more_args("ref1")?;
+                let f = lookup_function(name)
+                    .ok_or_else(|| RunErrorMsg::InternalError(name.into()).with_span(span))?;
+                let values = ranges.take_rest().collect::<Vec<_>>();
+                (f.eval)(ctx, FormulaFnArgs::new(values, span, f.name))?
+            }
+        ),
     ]
 }
 
@@ -199,7 +298,8 @@ mod tests {
     use itertools::Itertools;
 
     use crate::{
-        Pos, a1::A1Context, controller::GridController, formulas::tests::*, grid::SheetId,
+        Pos, RunErrorMsg, a1::A1Context, controller::GridController, formulas::tests::*,
+        grid::SheetId,
     };
 
     #[test]
@@ -474,4 +574,62 @@ mod tests {
         // Test basic standard deviation calculation
         assert_eq!("2", eval_to_string(&g, "STDEV(1, 3, 5)"));
     }
+
+    #[test]
+    fn test_varp_stdevp() {
+        let g = GridController::new();
+        assert_eq!("5", eval_to_string(&g, "VARP(1, 3, 5, 7)"));
+        assert_eq!("2", eval_to_string(&g, "STDEVP(2, 4, 4, 4, 5, 5, 7, 9)"));
+    }
+
+    #[test]
+    fn test_varp_stdevp_no_numbers() {
+        let mut g = GridController::new();
+        let sheet_id = g.sheet_ids()[0];
+        g.sheet_mut(sheet_id).set_cell_value(pos![B1], "a");
+        g.sheet_mut(sheet_id).set_cell_value(pos![B2], "b");
+
+        for formula in [
+            "VARP(C1:C5)",
+            "STDEVP(C1:C5)",
+            "VARP(B1:B2)",
+            "STDEVP(B1:B2)",
+        ] {
+            assert_eq!(RunErrorMsg::DivideByZero, eval_to_err(&g, formula).msg);
+        }
+    }
+
+    #[test]
+    fn test_subtotal() {
+        let mut g = GridController::new();
+        let sheet_id = g.sheet_ids()[0];
+        for y in 1..=4 {
+            g.sheet_mut(sheet_id).set_cell_value(Pos { x: 1, y }, y);
+        }
+
+        assert_eq!("10", eval_to_string(&g, "SUBTOTAL(9, A1:A4)"));
+        assert_eq!("2.5", eval_to_string(&g, "SUBTOTAL(101, A1:A4)"));
+        assert_eq!("4", eval_to_string(&g, "SUBTOTAL(2, A1:A4)"));
+        assert_eq!("24", eval_to_string(&g, "SUBTOTAL(6, A1:A4)"));
+
+        g.sheet_mut(sheet_id).offsets.set_row_hidden(2, true);
+
+        // 1-11 include hidden rows
+        assert_eq!("10", eval_to_string(&g, "SUBTOTAL(9, A1:A4)"));
+
+        // 101-111 ignore hidden rows
+        assert_eq!("8", eval_to_string(&g, "SUBTOTAL(109, A1:A4)"));
+        assert_eq!("3", eval_to_string(&g, "SUBTOTAL(102, A1:A4)"));
+        assert_eq!("4", eval_to_string(&g, "SUBTOTAL(104, A:A)"));
+        assert_eq!("16", eval_to_string(&g, "SUBTOTAL(109, A1:A4, A1:A4)"));
+
+        assert_eq!(
+            RunErrorMsg::InvalidArgument,
+            eval_to_err(&g, "SUBTOTAL(12, A1:A4)").msg,
+        );
+        assert!(matches!(
+            eval_to_err(&g, "SUBTOTAL(9)").msg,
+            RunErrorMsg::MissingRequiredArgument { .. },
+        ));
+    }
 }
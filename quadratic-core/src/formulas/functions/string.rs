@@ -1,4 +1,5 @@
 use super::*;
+use crate::values::format_code::FormatCode;
 
 pub const CATEGORY: FormulaFunctionCategory = FormulaFunctionCategory {
     include_in_docs: true,
@@ -346,32 +347,7 @@ fn get_functions() -> Vec<FormulaFunction> {
             )]
             #[zip_map]
             fn PROPER([s]: String) {
-                let mut last_char = '\0';
-                let mut ret = String::new();
-                // Convert to lowercase first so that we get correct handling of
-                // word-final sigma. This *may* cause issues where the first
-                // character is not preserved, since it gets lowercased and then
-                // titlecased.
-                for c in s.to_lowercase().chars() {
-                    if last_char.is_alphabetic() {
-                        ret.push(c);
-                    } else {
-                        // We can't just uppercase the charater, because Unicode
-                        // contains some ligatures like `ǆ` which should be
-                        // titlecased to `ǅ` rather than `Ǆ`.
-                        match unicode_case_mapping::to_titlecase(c) {
-                            [0, 0, 0] => ret.push(c), // unchanged
-                            char_seq => ret.extend(
-                                char_seq
-                                    .into_iter()
-                                    .filter(|&c| c != 0)
-                                    .filter_map(char::from_u32),
-                            ),
-                        }
-                    }
-                    last_char = c;
-                }
-                ret
+                crate::util::title_case(&s)
             }
         ),
         // Other string conversions
@@ -456,6 +432,33 @@ fn get_functions() -> Vec<FormulaFunction> {
                 }
             }
         ),
+        formula_fn!(
+            /// Formats a value as text using an Excel number format code.
+            ///
+            /// The format code may have up to four sections separated by `;`
+            /// for positive numbers, negative numbers, zero and text. It
+            /// supports digit placeholders (`0`, `#`, `?`), thousands
+            /// separators and scaling (`#,##0`, `0.0,,"M"`), percentages,
+            /// scientific notation, fractions, quoted text, and date and time
+            /// parts (`yyyy-mm-dd`, `[h]:mm:ss`). Colors are ignored.
+            ///
+            /// Returns an error if the format code is invalid.
+            #[examples(
+                "TEXT(1234.5, \"#,##0.00\") = \"1,234.50\"",
+                "TEXT(-5, \"0;(0)\") = \"(5)\"",
+                "TEXT(0.256, \"0.0%\") = \"25.6%\"",
+                "TEXT(45356, \"yyyy-mm-dd\") = \"2024-03-05\""
+            )]
+            #[zip_map]
+            fn TEXT(ctx: Ctx, [value]: CellValue, [format_text]: (Spanned<String>)) {
+                let code = FormatCode::parse(&format_text.inner)
+                    .map_err(|_| RunErrorMsg::InvalidArgument.with_span(format_text.span))?;
+                let date_system = ctx.grid_controller.grid().date_settings.date_system;
+                code.format_with_date_system(value, date_system)
+                    .map(|formatted| formatted.text)
+                    .ok_or_else(|| RunErrorMsg::InvalidArgument.with_span(format_text.span))?
+            }
+        ),
         // Comparison
         formula_fn!(
             /// Returns whether two strings are exactly equal, using
@@ -531,7 +534,11 @@ fn first_char_of_nonempty_string(arg: &Option<Spanned<String>>) -> CodeResult<Op
 
 #[cfg(test)]
 mod tests {
-    use crate::{controller::GridController, formulas::tests::*};
+    use crate::{
+        controller::GridController,
+        date_time::{DateSettings, DateSystem},
+        formulas::tests::*,
+    };
 
     #[test]
     fn test_formula_array_to_text() {
@@ -894,6 +901,47 @@ mod tests {
         assert_eq!("1.5", eval_to_string(&g, "NUMBERVALUE(185, '88888888')"));
     }
 
+    #[test]
+    fn test_formula_text() {
+        let g = GridController::new();
+
+        assert_eq!("1,234.50", eval_to_string(&g, "TEXT(1234.5, \"#,##0.00\")"));
+        assert_eq!("(5)", eval_to_string(&g, "TEXT(-5, \"0;(0)\")"));
+        assert_eq!("25.6%", eval_to_string(&g, "TEXT(0.256, \"0.0%\")"));
+        assert_eq!("12.3M", eval_to_string(&g, "TEXT(12345678, '0.0,,\"M\"')"));
+        assert_eq!(
+            "2024-03-05",
+            eval_to_string(&g, "TEXT(45356, \"yyyy-mm-dd\")")
+        );
+        assert_eq!(
+            "03/05/2024",
+            eval_to_string(&g, "TEXT(DATE(2024, 3, 5), \"mm/dd/yyyy\")")
+        );
+        assert_eq!(
+            "10000000000000000000000000    ",
+            eval_to_string(&g, "TEXT(1E25, \"# ?/?\")")
+        );
+        assert_eq!("abc", eval_to_string(&g, "TEXT(\"abc\", \"0.00\")"));
+        eval_to_err(&g, "TEXT(1, \"0;0;0;0;0\")");
+        eval_to_err(&g, "TEXT(-1, \"yyyy\")");
+        eval_to_err(&g, "TEXT(1E12, \"yyyy-mm-dd\")");
+
+        // numbers are read as serial dates in the file's date system
+        let mut g = GridController::new();
+        g.set_date_settings(
+            DateSettings {
+                date_system: DateSystem::Excel1904,
+                serial_dates: false,
+            },
+            None,
+        );
+        assert_eq!("1904-01-01", eval_to_string(&g, "TEXT(0, \"yyyy-mm-dd\")"));
+        assert_eq!(
+            "03/05/2024",
+            eval_to_string(&g, "TEXT(DATE(2024, 3, 5), \"mm/dd/yyyy\")")
+        );
+    }
+
     #[test]
     fn test_formula_exact() {
         let g = GridController::new();
@@ -1,6 +1,9 @@
+use bigdecimal::BigDecimal;
+
 use crate::{
     RefError,
     a1::{A1Error, SheetCellRefRange},
+    util::normalize_decimal,
 };
 
 use super::*;
@@ -37,12 +40,14 @@ impl SyntaxRule for NumericLiteral {
     fn consume_match(&self, p: &mut Parser<'_>) -> CodeResult<Self::Output> {
         match p.next() {
             Some(Token::NumericLiteral) => {
-                let Ok(n) = p.token_str().parse::<f64>() else {
+                // Parse as a decimal so that literals such as `0.1` are exact.
+                let Ok(n) = p.token_str().parse::<BigDecimal>() else {
                     return Err(RunErrorMsg::BadNumber.with_span(p.span()));
                 };
                 Ok(AstNode {
                     span: p.span(),
-                    inner: ast::AstNodeContents::Number(n),
+                    // `1.50` is read as `1.5` and `6.022e23` as an integer
+                    inner: ast::AstNodeContents::Number(normalize_decimal(n)),
                 })
             }
             _ => p.expected(self),
@@ -126,6 +126,104 @@ fn test_formula_cell_ref() {
     );
 }
 
+#[test]
+fn test_formula_rich_text_cell_ref() {
+    let mut g = GridController::new();
+    let sheet_id = g.sheet_ids()[0];
+    let sheet = g.sheet_mut(sheet_id);
+    let bold = |text: &str| TextSpan {
+        bold: Some(true),
+        ..TextSpan::new(text)
+    };
+    sheet.set_cell_value(
+        pos![B1],
+        CellValue::RichText(RichText::new([bold("hel"), TextSpan::new("lo")])),
+    );
+    sheet.set_cell_value(
+        pos![B2],
+        CellValue::RichText(RichText::new([bold("1"), TextSpan::new("0")])),
+    );
+    sheet.set_cell_value(pos![C2], "10");
+
+    assert_eq!("TRUE", eval_to_string(&g, "B1=\"hello\""));
+    assert_eq!("11", eval_to_string(&g, "B2+1"));
+
+    // rich text sums and counts the same as plain text
+    assert_eq!(
+        eval_to_string(&g, "SUM(C1:C2)"),
+        eval_to_string(&g, "SUM(B1:B2)")
+    );
+    assert_eq!("1", eval_to_string(&g, "COUNTIF(B1:B2, \"hello\")"));
+    assert_eq!("1", eval_to_string(&g, "COUNTIF(B1:B2, \"h*\")"));
+    assert_eq!(
+        eval_to_string(&g, "COUNTIF(C1:C2, 10)"),
+        eval_to_string(&g, "COUNTIF(B1:B2, 10)")
+    );
+}
+
+#[test]
+fn test_formula_hyperlink_cell_ref() {
+    let mut g = GridController::new();
+    let sheet_id = g.sheet_ids()[0];
+    let sheet = g.sheet_mut(sheet_id);
+    let link = Hyperlink::new("https://www.quadratichq.com", "").unwrap();
+    sheet.set_cell_value(
+        pos![B1],
+        CellValue::Hyperlink(Box::new(
+            link.attach_to(CellValue::Text("Quadratic".into())),
+        )),
+    );
+    sheet.set_cell_value(
+        pos![B2],
+        CellValue::Hyperlink(Box::new(link.attach_to(CellValue::Number(5.into())))),
+    );
+    sheet.set_cell_value(pos![B3], CellValue::Hyperlink(Box::new(link)));
+
+    assert_eq!("TRUE", eval_to_string(&g, "B1=\"Quadratic\""));
+    assert_eq!(
+        "TRUE",
+        eval_to_string(&g, "B3=\"https://www.quadratichq.com\"")
+    );
+    assert_eq!("6", eval_to_string(&g, "B2+1"));
+    assert_eq!("5", eval_to_string(&g, "SUM(B1:B3)"));
+    assert_eq!("1", eval_to_string(&g, "COUNTIF(B1:B3, 5)"));
+    assert_eq!("1", eval_to_string(&g, "COUNTIF(B1:B3, \"quadratic\")"));
+}
+
+#[test]
+fn test_formula_adjust_references() {
+    let mut g = GridController::new();
+    let sheet_id = g.sheet_ids()[0];
+    let sheet = g.sheet_mut(sheet_id);
+    sheet.set_cell_value(pos![A1], 1);
+    sheet.set_cell_value(pos![A2], 10);
+    sheet.set_cell_value(pos![B2], 100);
+
+    let pos = pos![sheet_id!C3];
+    let formula = parse_formula("A1 + $B$2 + SUM(A1:A2)", g.a1_context(), pos).unwrap();
+    let eval = |formula: &Formula| formula.eval(&mut Ctx::new(&g, pos)).inner.to_string();
+    assert_eq!("112", eval(&formula));
+
+    // only relative references are moved
+    let adjust = |dx, dy| crate::RefAdjust {
+        relative_only: true,
+        dx,
+        dy,
+        ..crate::RefAdjust::NO_OP
+    };
+    assert_eq!("120", eval(&formula.adjust_references(adjust(0, 1))));
+
+    // references moved out of bounds become errors
+    let value = formula
+        .adjust_references(adjust(-1, 0))
+        .eval(&mut Ctx::new(&g, pos))
+        .inner;
+    assert!(matches!(
+        value.into_cell_value(),
+        Ok(CellValue::Error(e)) if e.msg == RunErrorMsg::BadCellReference
+    ));
+}
+
 #[test]
 fn test_formula_circular_array_ref() {
     let g = GridController::new();
@@ -275,6 +373,25 @@ fn test_bool_parsing() {
     assert_eq!("2", eval_to_string(&g, "IF(FALSE(), 1, 2)"));
 }
 
+#[test]
+fn test_number_parsing() {
+    let g = GridController::new();
+
+    assert_eq!("42", eval_to_string(&g, "42"));
+    assert_eq!("1", eval_to_string(&g, "1."));
+    assert_eq!("0.5", eval_to_string(&g, ".5"));
+    assert_eq!("602200000000000000000000", eval_to_string(&g, "6.022e23"));
+    assert_eq!("0.00015", eval_to_string(&g, "1.5E-4"));
+    assert_eq!(
+        "123456789012345678",
+        eval_to_string(&g, "123456789012345678")
+    );
+    assert_eq!(
+        "0.1000000000000000001",
+        eval_to_string(&g, "0.1000000000000000001")
+    );
+}
+
 #[test]
 fn test_leading_equals() {
     let g = GridController::new();
@@ -304,6 +304,7 @@ pub mod test {
             show_columns: Some(true),
             header_is_first_row: true,
             alternating_colors: true,
+            table_style: None,
             formats: Default::default(),
             borders: Default::default(),
             chart_output: None,
@@ -356,6 +357,7 @@ pub mod test {
             show_columns: Some(true),
             header_is_first_row: false,
             alternating_colors: true,
+            table_style: None,
             formats: Default::default(),
             borders: Default::default(),
             chart_output: None,
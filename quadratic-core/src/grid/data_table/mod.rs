@@ -11,6 +11,8 @@ pub mod formats;
 pub mod row;
 pub mod send_render;
 pub mod sort;
+pub mod table_style;
+pub mod transform;
 
 use std::num::NonZeroU32;
 
@@ -29,6 +31,7 @@ use regex::Regex;
 use serde::{Deserialize, Serialize};
 use sort::DataTableSort;
 use strum_macros::Display;
+use table_style::TableStyle;
 
 use super::sheet::borders::Borders;
 use super::{CodeCellLanguage, Grid, SheetFormatting, SheetId};
@@ -147,6 +150,10 @@ pub struct DataTable {
     pub spill_error: bool,
     pub last_modified: DateTime<Utc>,
     pub alternating_colors: bool,
+
+    #[serde(default)]
+    pub table_style: Option<TableStyle>,
+
     pub formats: SheetFormatting,
     pub borders: Borders,
 
@@ -199,6 +206,7 @@ impl DataTable {
             spill_error,
             last_modified: Utc::now(),
             alternating_colors: true,
+            table_style: None,
 
             show_name,
             show_columns,
@@ -236,6 +244,7 @@ impl DataTable {
             spill_error: self.spill_error,
             last_modified: self.last_modified,
             alternating_colors: self.alternating_colors,
+            table_style: self.table_style.clone(),
             formats: self.formats.clone(),
             borders: self.borders.clone(),
 
@@ -532,9 +541,11 @@ impl DataTable {
                 Value::Single(v) => match v {
                     CellValue::Image(_) => CellValue::Blank,
                     CellValue::Html(_) => CellValue::Blank,
-                    _ => v.clone(),
+                    _ => v.to_formula_value(),
                 },
-                Value::Array(a) => a.get(x, y).cloned().unwrap_or(CellValue::Blank),
+                Value::Array(a) => a
+                    .get(x, y)
+                    .map_or(CellValue::Blank, CellValue::to_formula_value),
                 Value::Tuple(_) => CellValue::Error(Box::new(
                     // should never happen
                     RunErrorMsg::InternalError("tuple saved as code run result".into())
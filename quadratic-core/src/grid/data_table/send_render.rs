@@ -4,12 +4,13 @@ use crate::{
     ClearOption, Pos, Rect, SheetPos,
     controller::active_transactions::pending_transaction::PendingTransaction,
     grid::{
-        CellWrap, Sheet, SheetId,
+        CellWrap, Format, Sheet, SheetId,
         formats::{SheetFormatUpdates, SheetFormatUpdatesType},
+        js_types::JsRenderFill,
     },
 };
 
-use super::DataTable;
+use super::{DataTable, table_style::TableStyle};
 
 use anyhow::Result;
 
@@ -57,7 +58,7 @@ impl DataTable {
             return;
         }
 
-        if self.formats.has_fills() {
+        if self.formats.has_fills() || self.table_style.is_some() {
             transaction.add_fill_cells(sheet_id);
         }
         if !self.borders.is_default() {
@@ -65,6 +66,118 @@ impl DataTable {
         }
     }
 
+    /// Returns the table style's format for a position on the sheet, given
+    /// the table's output rect and the first row of its data.
+    fn table_style_cell_format(
+        &self,
+        table_style: &TableStyle,
+        rect: Rect,
+        data_y: i64,
+        pos: Pos,
+    ) -> Option<Format> {
+        if !rect.contains(pos) {
+            return None;
+        }
+        let row = if pos.y >= data_y {
+            Some((pos.y - data_y) as usize)
+        } else if self.get_show_columns() && pos.y == data_y - 1 {
+            None
+        } else {
+            // table name
+            return None;
+        };
+        let rows = (rect.max.y - data_y + 1).max(0) as usize;
+        Some(table_style.cell_format(
+            (pos.x - rect.min.x) as usize,
+            rect.width() as usize,
+            row,
+            rows,
+        ))
+    }
+
+    /// Returns the table style and the output rect and first data row of the
+    /// table, if the table is rendered with a style.
+    fn table_style_bounds(&self, data_table_pos: Pos) -> Option<(&TableStyle, Rect, i64)> {
+        let table_style = self.table_style.as_ref()?;
+        if self.spill_error || self.has_error() || self.is_html_or_image() || self.is_single_value()
+        {
+            return None;
+        }
+        let rect = self.output_rect(data_table_pos, false);
+        let data_y = data_table_pos.y + self.y_adjustment(false);
+        Some((table_style, rect, data_y))
+    }
+
+    /// Returns the format of the table style at a position on the sheet.
+    /// Returns None if the table has no style or the position is not part of
+    /// the styled cells.
+    pub(crate) fn table_style_format(&self, data_table_pos: Pos, pos: Pos) -> Option<Format> {
+        let (table_style, rect, data_y) = self.table_style_bounds(data_table_pos)?;
+        self.table_style_cell_format(table_style, rect, data_y, pos)
+    }
+
+    /// Returns the fills of the table style. Runs of the same color are
+    /// combined, and rows with the same fills as the row above extend its
+    /// fills.
+    pub(crate) fn table_style_fills(&self, data_table_pos: Pos) -> Vec<JsRenderFill> {
+        let Some((table_style, rect, data_y)) = self.table_style_bounds(data_table_pos) else {
+            return vec![];
+        };
+        let start_y = if self.get_show_columns() {
+            data_y - 1
+        } else {
+            data_y
+        };
+
+        let mut fills: Vec<JsRenderFill> = vec![];
+        let mut previous_row: Vec<usize> = vec![];
+        for y in start_y.max(rect.min.y)..=rect.max.y {
+            let mut runs: Vec<(i64, u32, String)> = vec![];
+            for x in rect.x_range() {
+                let Some(color) = self
+                    .table_style_cell_format(table_style, rect, data_y, Pos { x, y })
+                    .and_then(|format| format.fill_color)
+                else {
+                    continue;
+                };
+                match runs.last_mut() {
+                    Some((x0, w, run_color)) if *x0 + *w as i64 == x && *run_color == color => {
+                        *w += 1;
+                    }
+                    _ => runs.push((x, 1, color)),
+                }
+            }
+
+            let same_as_previous = previous_row.len() == runs.len()
+                && previous_row
+                    .iter()
+                    .zip(runs.iter())
+                    .all(|(&i, (x, w, color))| {
+                        fills[i].x == *x && fills[i].w == *w && fills[i].color == *color
+                    });
+            if same_as_previous {
+                for &i in previous_row.iter() {
+                    fills[i].h += 1;
+                }
+            } else {
+                previous_row = runs
+                    .into_iter()
+                    .map(|(x, w, color)| {
+                        fills.push(JsRenderFill {
+                            x,
+                            y,
+                            w,
+                            h: 1,
+                            color,
+                        });
+                        fills.len() - 1
+                    })
+                    .collect();
+            }
+        }
+        fills
+    }
+
     fn has_content_in_row(&self, row: i64) -> bool {
         let data_table_rect = self.output_rect((0, 0).into(), false);
         for x in data_table_rect.x_range() {
@@ -96,9 +209,7 @@ impl DataTable {
 
                 let check_wrap = self
                     .formats
-                    .wrap
-                    .get((x - formats_x_offset, y - formats_y_offset).into())
-                    .is_some_and(|wrap| wrap == CellWrap::Wrap);
+                    .needs_auto_height((x - formats_x_offset, y - formats_y_offset).into());
 
                 if check_value && check_wrap {
                     rows.push(y);
@@ -270,6 +381,34 @@ impl DataTable {
             &mut dirty_hashes,
             &mut resize_rows,
         );
+        self.format_transaction_changes(
+            data_table_pos,
+            formats.font_size.to_owned(),
+            true,
+            &mut dirty_hashes,
+            &mut resize_rows,
+        );
+        self.format_transaction_changes(
+            data_table_pos,
+            formats.font_family.to_owned(),
+            true,
+            &mut dirty_hashes,
+            &mut resize_rows,
+        );
+        self.format_transaction_changes(
+            data_table_pos,
+            formats.text_rotation.to_owned(),
+            true,
+            &mut dirty_hashes,
+            &mut resize_rows,
+        );
+        self.format_transaction_changes(
+            data_table_pos,
+            formats.indent.to_owned(),
+            true,
+            &mut dirty_hashes,
+            &mut resize_rows,
+        );
 
         // for wrap, we need to check if the new formats is wrap or old is wrap
         // no need to resize rows if wrap is not present in both new and old formats
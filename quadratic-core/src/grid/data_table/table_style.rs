@@ -0,0 +1,328 @@
+//! Table styles for data tables.
+//!
+//! A table style sets the fill and font of parts of a table (the header row,
+//! banded rows and columns, the first and last columns, and the totals row).
+//! Styles start from one of Excel's built-in presets (eg, `TableStyleMedium2`)
+//! and can then be customized per table.
+//!
+//! The style is applied to a table's display positions, so banding does not
+//! move when the table is sorted. Formats set directly on the table's cells
+//! take precedence over the style.
+
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use crate::color::Rgba;
+use crate::grid::Format;
+use crate::grid::formats::Theme;
+
+lazy_static! {
+    static ref PRESET_NAME: Regex =
+        Regex::new(r"(?i)^TableStyle(Light|Medium|Dark)(\d+)$").expect("regex should compile");
+}
+
+/// The theme colors used by the presets, in Excel's order: each family of
+/// presets cycles through these.
+const PRESET_COLORS: [&str; 7] = [
+    "dark1", "accent1", "accent2", "accent3", "accent4", "accent5", "accent6",
+];
+
+const WHITE: &str = "#ffffff";
+const BLACK: &str = "#000000";
+
+/// The number of presets in each family.
+const LIGHT_PRESETS: u8 = 21;
+const MEDIUM_PRESETS: u8 = 28;
+const DARK_PRESETS: u8 = 11;
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq, TS)]
+pub struct TableStyleElement {
+    pub fill_color: Option<String>,
+    pub text_color: Option<String>,
+    pub bold: Option<bool>,
+}
+
+impl TableStyleElement {
+    fn new(fill_color: Option<String>, text_color: Option<&str>, bold: bool) -> Self {
+        Self {
+            fill_color,
+            text_color: text_color.map(String::from),
+            bold: bold.then_some(true),
+        }
+    }
+
+    fn fill(fill_color: String) -> Self {
+        Self::new(Some(fill_color), None, false)
+    }
+
+    fn bold() -> Self {
+        Self::new(None, None, true)
+    }
+
+    /// Solid fill with white, bold text.
+    fn solid(fill_color: String) -> Self {
+        Self::new(Some(fill_color), Some(WHITE), true)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fill_color.is_none() && self.text_color.is_none() && self.bold.is_none()
+    }
+
+    fn format(&self) -> Format {
+        Format {
+            fill_color: self.fill_color.clone(),
+            text_color: self.text_color.clone(),
+            bold: self.bold,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq, TS)]
+pub struct TableStyle {
+    /// The preset the style is based on (eg, `TableStyleMedium2`).
+    pub name: String,
+
+    pub whole_table: TableStyleElement,
+    pub header_row: TableStyleElement,
+
+    /// Applied to the first, third, etc. data rows.
+    pub row_band: TableStyleElement,
+
+    /// Applied to the first, third, etc. columns.
+    pub column_band: TableStyleElement,
+
+    pub first_column: TableStyleElement,
+    pub last_column: TableStyleElement,
+    pub totals_row: TableStyleElement,
+
+    pub banded_rows: bool,
+    pub banded_columns: bool,
+    pub show_first_column: bool,
+    pub show_last_column: bool,
+
+    /// Whether the table's last row is a totals row.
+    pub show_totals_row: bool,
+}
+
+/// Mixes a color with white (tint > 0) or black (tint < 0).
+fn tint(color: &str, tint: f32) -> String {
+    let Ok(color) = Rgba::color_from_str(color) else {
+        return color.to_string();
+    };
+    let mix = |c: u8| {
+        let c = c as f32;
+        let mixed = if tint >= 0.0 {
+            c + (255.0 - c) * tint
+        } else {
+            c * (1.0 + tint)
+        };
+        mixed.round().clamp(0.0, 255.0) as u8
+    };
+    format!(
+        "#{:02x}{:02x}{:02x}",
+        mix(color.red),
+        mix(color.green),
+        mix(color.blue)
+    )
+}
+
+impl TableStyle {
+    /// Returns the names of Excel's built-in table styles.
+    pub fn preset_names() -> Vec<String> {
+        [
+            ("Light", LIGHT_PRESETS),
+            ("Medium", MEDIUM_PRESETS),
+            ("Dark", DARK_PRESETS),
+        ]
+        .into_iter()
+        .flat_map(|(family, count)| (1..=count).map(move |n| format!("TableStyle{family}{n}")))
+        .collect()
+    }
+
+    /// Returns the built-in table style with the given (Excel) name, with
+    /// its colors taken from the theme. The presets approximate Excel's
+    /// fills and fonts; Excel's preset borders are not included.
+    pub fn preset(name: &str, theme: &Theme) -> Option<TableStyle> {
+        let captures = PRESET_NAME.captures(name)?;
+        let family = captures[1].to_ascii_lowercase();
+        let n = captures[2].parse::<u8>().ok()?;
+        let (family_name, count) = match family.as_str() {
+            "light" => ("Light", LIGHT_PRESETS),
+            "medium" => ("Medium", MEDIUM_PRESETS),
+            _ => ("Dark", DARK_PRESETS),
+        };
+        if n == 0 || n > count {
+            return None;
+        }
+
+        let theme_color = |index: usize| {
+            theme
+                .color(PRESET_COLORS[index % PRESET_COLORS.len()])
+                .unwrap_or(BLACK)
+                .to_string()
+        };
+        let index = (n as usize - 1) % PRESET_COLORS.len();
+        let color = theme_color(index);
+
+        let mut style = TableStyle {
+            name: format!("TableStyle{family_name}{n}"),
+            header_row: TableStyleElement::bold(),
+            first_column: TableStyleElement::bold(),
+            last_column: TableStyleElement::bold(),
+            totals_row: TableStyleElement::bold(),
+            banded_rows: true,
+            ..Default::default()
+        };
+        match (family.as_str(), (n - 1) / 7) {
+            // banded rows
+            ("light", 0) | ("light", 2) => {
+                style.row_band = TableStyleElement::fill(tint(&color, 0.8));
+                style.column_band = style.row_band.clone();
+            }
+            // solid header
+            ("light", _) => {
+                style.header_row = TableStyleElement::solid(color.clone());
+            }
+            // solid header and banded rows
+            ("medium", 0) | ("medium", 2) => {
+                style.header_row = TableStyleElement::solid(color.clone());
+                style.row_band = TableStyleElement::fill(tint(&color, 0.8));
+                style.column_band = style.row_band.clone();
+                if n > 14 {
+                    style.first_column = TableStyleElement::solid(color.clone());
+                    style.last_column = TableStyleElement::solid(color.clone());
+                }
+            }
+            // filled body with solid header, first and last columns
+            ("medium", 1) => {
+                style.whole_table = TableStyleElement::fill(tint(&color, 0.8));
+                style.header_row = TableStyleElement::solid(color.clone());
+                style.row_band = TableStyleElement::fill(tint(&color, 0.6));
+                style.column_band = style.row_band.clone();
+                style.first_column = TableStyleElement::solid(color.clone());
+                style.last_column = TableStyleElement::solid(color.clone());
+                style.totals_row = TableStyleElement::solid(color);
+            }
+            // filled body
+            ("medium", _) => {
+                style.whole_table = TableStyleElement::fill(tint(&color, 0.8));
+                style.row_band = TableStyleElement::fill(tint(&color, 0.6));
+                style.column_band = style.row_band.clone();
+            }
+            // dark body with white text
+            ("dark", 0) => {
+                // black cannot be darkened, so dark1 uses a gray
+                let color = if index == 0 {
+                    tint(&color, 0.35)
+                } else {
+                    tint(&color, -0.25)
+                };
+                style.whole_table = TableStyleElement::new(Some(color.clone()), Some(WHITE), false);
+                style.header_row = TableStyleElement::solid(BLACK.to_string());
+                style.row_band = TableStyleElement::fill(tint(&color, -0.25));
+                style.column_band = style.row_band.clone();
+                style.first_column = TableStyleElement::solid(tint(&color, -0.25));
+                style.last_column = style.first_column.clone();
+                style.totals_row = TableStyleElement::solid(tint(&color, -0.5));
+            }
+            // light body with a black header
+            _ => {
+                let color = theme_color([0, 1, 3, 5][(n as usize - 8).min(3)]);
+                style.whole_table = TableStyleElement::fill(tint(&color, 0.6));
+                style.header_row = TableStyleElement::solid(BLACK.to_string());
+                style.row_band = TableStyleElement::fill(tint(&color, 0.4));
+                style.column_band = style.row_band.clone();
+                style.totals_row = TableStyleElement::new(Some(tint(&color, 0.4)), None, true);
+            }
+        }
+        Some(style)
+    }
+
+    /// Returns the style's format for a cell of a table with `columns`
+    /// columns and `rows` data rows. `column` and `row` are display indices;
+    /// a `row` of None is the header row.
+    pub fn cell_format(
+        &self,
+        column: usize,
+        columns: usize,
+        row: Option<usize>,
+        rows: usize,
+    ) -> Format {
+        let Some(row) = row else {
+            return self.header_row.format().combine(&self.whole_table.format());
+        };
+        if self.show_totals_row && row + 1 == rows {
+            return self.totals_row.format().combine(&self.whole_table.format());
+        }
+
+        // lowest precedence last
+        let elements = [
+            (self.show_first_column && column == 0).then_some(&self.first_column),
+            (self.show_last_column && column + 1 == columns).then_some(&self.last_column),
+            (self.banded_rows && row.is_multiple_of(2)).then_some(&self.row_band),
+            (self.banded_columns && column.is_multiple_of(2)).then_some(&self.column_band),
+            Some(&self.whole_table),
+        ];
+        elements
+            .into_iter()
+            .flatten()
+            .fold(Format::default(), |format, element| {
+                format.combine(&element.format())
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_preset() {
+        let theme = Theme::default();
+        let names = TableStyle::preset_names();
+        assert_eq!(names.len(), 60);
+        for name in names.iter() {
+            assert_eq!(TableStyle::preset(name, &theme).unwrap().name, *name);
+        }
+        assert!(TableStyle::preset("TableStyleMedium29", &theme).is_none());
+        assert!(TableStyle::preset("TableStyleLight0", &theme).is_none());
+        assert!(TableStyle::preset("Custom", &theme).is_none());
+
+        let style = TableStyle::preset("tablestylemedium2", &theme).unwrap();
+        assert_eq!(style.name, "TableStyleMedium2");
+        assert_eq!(style.header_row.fill_color, Some("#4472c4".to_string()));
+        assert_eq!(style.header_row.text_color, Some(WHITE.to_string()));
+        assert_eq!(style.row_band.fill_color, Some("#dae3f3".to_string()));
+    }
+
+    #[test]
+    fn test_cell_format() {
+        let mut style = TableStyle::preset("TableStyleMedium2", &Theme::default()).unwrap();
+        style.show_first_column = true;
+        style.show_totals_row = true;
+
+        let header = style.cell_format(1, 3, None, 4);
+        assert_eq!(header.bold, Some(true));
+        assert_eq!(header.fill_color, Some("#4472c4".to_string()));
+
+        // banded rows
+        assert_eq!(
+            style.cell_format(1, 3, Some(0), 4).fill_color,
+            Some("#dae3f3".to_string())
+        );
+        assert_eq!(style.cell_format(1, 3, Some(1), 4), Format::default());
+
+        // first column is bold over the band
+        let first = style.cell_format(0, 3, Some(0), 4);
+        assert_eq!(first.bold, Some(true));
+        assert_eq!(first.fill_color, Some("#dae3f3".to_string()));
+
+        // totals row
+        let totals = style.cell_format(1, 3, Some(3), 4);
+        assert_eq!(totals.bold, Some(true));
+        assert_eq!(totals.fill_color, None);
+    }
+}
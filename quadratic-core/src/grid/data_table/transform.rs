@@ -0,0 +1,827 @@
+//! Transforms that reshape one or two data tables into a new table: group-by,
+//! pivot, unpivot, and joins.
+//!
+//! Each transform works on arrays whose first row holds the column headers
+//! (ie, the output of a `Table[#ALL]` reference) and returns a new array in
+//! the same shape. The transforms are exposed as formula functions
+//! (`TABLE.GROUPBY`, etc.) so that a live table is just a formula code cell;
+//! a static table evaluates the same formula once and stores the result as an
+//! import.
+
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+
+use bigdecimal::{BigDecimal, ToPrimitive};
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use crate::formulas::escape_string;
+use crate::util::{case_fold, case_fold_ascii};
+use crate::{Array, CellValue, RunErrorMsg};
+
+/// Aggregation used to combine the values of a group.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, TS)]
+pub enum DataTableAggregate {
+    Sum,
+    Average,
+    Count,
+    CountA,
+    Min,
+    Max,
+}
+
+impl DataTableAggregate {
+    /// Parses an aggregate name (case-insensitive), as used in formulas.
+    pub fn parse(s: &str) -> Option<Self> {
+        match case_fold_ascii(s.trim()).as_str() {
+            "SUM" => Some(Self::Sum),
+            "AVERAGE" | "AVG" | "MEAN" => Some(Self::Average),
+            "COUNT" => Some(Self::Count),
+            "COUNTA" => Some(Self::CountA),
+            "MIN" => Some(Self::Min),
+            "MAX" => Some(Self::Max),
+            _ => None,
+        }
+    }
+
+    /// Returns the name of the aggregate, as used in formulas.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Sum => "SUM",
+            Self::Average => "AVERAGE",
+            Self::Count => "COUNT",
+            Self::CountA => "COUNTA",
+            Self::Min => "MIN",
+            Self::Max => "MAX",
+        }
+    }
+
+    /// Combines `values` into a single value. Non-numeric values are ignored
+    /// by every aggregate except `CountA`, which counts all non-blank values.
+    pub fn apply<'a>(self, values: impl IntoIterator<Item = &'a CellValue>) -> CellValue {
+        let mut numbers = vec![];
+        let mut non_blank = 0_usize;
+        for value in values {
+            if value.is_blank_or_empty_string() {
+                continue;
+            }
+            non_blank += 1;
+            if let CellValue::Number(n) = value {
+                numbers.push(n);
+            }
+        }
+
+        match self {
+            Self::Sum => CellValue::Number(numbers.into_iter().sum()),
+            Self::Average => {
+                if numbers.is_empty() {
+                    return CellValue::Error(Box::new(RunErrorMsg::DivideByZero.without_span()));
+                }
+                let count = numbers.len() as f64;
+                let sum: BigDecimal = numbers.into_iter().sum();
+                CellValue::from(sum.to_f64().unwrap_or(f64::NAN) / count)
+            }
+            Self::Count => CellValue::from(numbers.len() as f64),
+            Self::CountA => CellValue::from(non_blank as f64),
+            Self::Min => CellValue::Number(numbers.into_iter().min().cloned().unwrap_or_default()),
+            Self::Max => CellValue::Number(numbers.into_iter().max().cloned().unwrap_or_default()),
+        }
+    }
+}
+
+/// How rows without a match on the other side of a join are handled.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, TS)]
+pub enum DataTableJoinKind {
+    /// Only rows with a match in both tables.
+    Inner,
+    /// Every row of the left table, with blanks where the right table has no
+    /// match.
+    Left,
+    /// Every row of both tables.
+    Outer,
+}
+
+impl DataTableJoinKind {
+    /// Parses a join kind (case-insensitive), as used in formulas.
+    pub fn parse(s: &str) -> Option<Self> {
+        match case_fold_ascii(s.trim()).as_str() {
+            "INNER" => Some(Self::Inner),
+            "LEFT" => Some(Self::Left),
+            "OUTER" | "FULL" => Some(Self::Outer),
+            _ => None,
+        }
+    }
+
+    /// Returns the name of the join kind, as used in formulas.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Inner => "INNER",
+            Self::Left => "LEFT",
+            Self::Outer => "OUTER",
+        }
+    }
+}
+
+/// A transform that creates a new table from one or two existing tables.
+/// Columns are referenced by their header name.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, TS)]
+pub enum DataTableTransform {
+    /// One row per distinct combination of the `group_by` columns, with the
+    /// `values` columns aggregated.
+    GroupBy {
+        table: String,
+        group_by: Vec<String>,
+        values: Vec<String>,
+        aggregate: DataTableAggregate,
+    },
+
+    /// Turns every column that is not an id column into rows of
+    /// (attribute, value) pairs.
+    Unpivot {
+        table: String,
+        id_columns: Vec<String>,
+        attribute_header: Option<String>,
+        value_header: Option<String>,
+    },
+
+    /// One row per distinct combination of the `rows` columns and one column
+    /// per distinct value of `column`, with `value` aggregated at each
+    /// intersection.
+    Pivot {
+        table: String,
+        rows: Vec<String>,
+        column: String,
+        value: String,
+        aggregate: DataTableAggregate,
+    },
+
+    /// Combines the rows of two tables where the key columns match.
+    Join {
+        left: String,
+        right: String,
+        left_on: Vec<String>,
+        right_on: Vec<String>,
+        kind: DataTableJoinKind,
+    },
+}
+
+impl DataTableTransform {
+    /// Returns the formula that computes the transform.
+    pub fn to_formula(&self) -> String {
+        let table = |name: &str| format!("{name}[#ALL]");
+        let list = |columns: &[String]| {
+            if columns.is_empty() {
+                escape_string("")
+            } else {
+                let items: Vec<String> = columns.iter().map(|c| escape_string(c)).collect();
+                format!("{{{}}}", items.join(", "))
+            }
+        };
+
+        match self {
+            Self::GroupBy {
+                table: name,
+                group_by,
+                values,
+                aggregate,
+            } => format!(
+                "TABLE.GROUPBY({}, {}, {}, {})",
+                table(name),
+                list(group_by),
+                list(values),
+                escape_string(aggregate.name()),
+            ),
+            Self::Unpivot {
+                table: name,
+                id_columns,
+                attribute_header,
+                value_header,
+            } => format!(
+                "TABLE.UNPIVOT({}, {}, {}, {})",
+                table(name),
+                list(id_columns),
+                escape_string(
+                    attribute_header
+                        .as_deref()
+                        .unwrap_or(DEFAULT_ATTRIBUTE_HEADER)
+                ),
+                escape_string(value_header.as_deref().unwrap_or(DEFAULT_VALUE_HEADER)),
+            ),
+            Self::Pivot {
+                table: name,
+                rows,
+                column,
+                value,
+                aggregate,
+            } => format!(
+                "TABLE.PIVOT({}, {}, {}, {}, {})",
+                table(name),
+                list(rows),
+                escape_string(column),
+                escape_string(value),
+                escape_string(aggregate.name()),
+            ),
+            Self::Join {
+                left,
+                right,
+                left_on,
+                right_on,
+                kind,
+            } => format!(
+                "TABLE.JOIN({}, {}, {}, {}, {})",
+                table(left),
+                table(right),
+                list(left_on),
+                list(right_on),
+                escape_string(kind.name()),
+            ),
+        }
+    }
+
+    /// Returns the default name for the output table.
+    pub fn default_name(&self) -> String {
+        match self {
+            Self::GroupBy { table, .. } => format!("{table}_grouped"),
+            Self::Unpivot { table, .. } => format!("{table}_unpivoted"),
+            Self::Pivot { table, .. } => format!("{table}_pivot"),
+            Self::Join { left, right, .. } => format!("{left}_{right}"),
+        }
+    }
+
+    /// Returns the names of the tables the transform reads from.
+    pub fn source_tables(&self) -> Vec<&str> {
+        match self {
+            Self::GroupBy { table, .. }
+            | Self::Unpivot { table, .. }
+            | Self::Pivot { table, .. } => {
+                vec![table]
+            }
+            Self::Join { left, right, .. } => vec![left, right],
+        }
+    }
+}
+
+pub const DEFAULT_ATTRIBUTE_HEADER: &str = "Attribute";
+pub const DEFAULT_VALUE_HEADER: &str = "Value";
+
+/// Values of several columns, ordered using [`CellValue::total_cmp`] so they
+/// can be used as a map key.
+#[derive(Debug, Clone)]
+pub(crate) struct RowKey(pub(crate) Vec<CellValue>);
+
+impl PartialEq for RowKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+impl Eq for RowKey {}
+impl PartialOrd for RowKey {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for RowKey {
+    fn cmp(&self, other: &Self) -> Ordering {
+        for (a, b) in std::iter::zip(&self.0, &other.0) {
+            let ordering = a.total_cmp(b);
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+        }
+        self.0.len().cmp(&other.0.len())
+    }
+}
+
+impl RowKey {
+    pub(crate) fn from_row(row: &[CellValue], columns: &[usize]) -> Self {
+        RowKey(columns.iter().map(|&i| row[i].clone()).collect())
+    }
+
+    fn has_blank(&self) -> bool {
+        self.0.iter().any(|v| v.is_blank_or_empty_string())
+    }
+}
+
+/// Splits a header-first array into its headers and data rows.
+fn split_headers(table: &Array) -> (&[CellValue], Vec<&[CellValue]>) {
+    let mut rows = table.rows();
+    let headers = rows.next().unwrap_or_default();
+    (headers, rows.collect())
+}
+
+/// Finds a column by its header (case-insensitive) or by its 1-based index.
+/// Blank and empty values are skipped, so `""` can be used for "no columns".
+pub fn find_columns<'a>(
+    headers: &[CellValue],
+    columns: impl IntoIterator<Item = &'a CellValue>,
+) -> Result<Vec<usize>, RunErrorMsg> {
+    columns
+        .into_iter()
+        .filter(|column| !column.is_blank_or_empty_string())
+        .map(|column| find_column(headers, column))
+        .collect()
+}
+
+/// Finds a single column by its header (case-insensitive) or by its 1-based
+/// index.
+pub fn find_column(headers: &[CellValue], column: &CellValue) -> Result<usize, RunErrorMsg> {
+    let index = match column {
+        CellValue::Number(n) => n
+            .to_usize()
+            .filter(|&i| i >= 1 && i <= headers.len())
+            .map(|i| i - 1),
+        other => {
+            let name = case_fold(other.to_display().trim());
+            headers
+                .iter()
+                .position(|header| case_fold(header.to_display().trim()) == name)
+        }
+    };
+    index.ok_or_else(|| RunErrorMsg::Expected {
+        expected: "table column".into(),
+        got: Some(column.to_display().into()),
+    })
+}
+
+/// Groups the rows of `table` by the `group_by` columns and aggregates the
+/// `values` columns. Groups are returned in ascending order.
+pub fn group_by(
+    table: &Array,
+    group_by: &[usize],
+    values: &[usize],
+    aggregate: DataTableAggregate,
+) -> Result<Array, RunErrorMsg> {
+    if group_by.is_empty() {
+        return Err(RunErrorMsg::InvalidArgument);
+    }
+    let (headers, rows) = split_headers(table);
+
+    let mut groups: BTreeMap<RowKey, Vec<&[CellValue]>> = BTreeMap::new();
+    for row in rows {
+        groups
+            .entry(RowKey::from_row(row, group_by))
+            .or_default()
+            .push(row);
+    }
+
+    let mut output = vec![
+        group_by
+            .iter()
+            .chain(values)
+            .map(|&i| headers[i].clone())
+            .collect::<Vec<_>>(),
+    ];
+    for (key, rows) in groups {
+        let mut out_row = key.0;
+        out_row.extend(
+            values
+                .iter()
+                .map(|&column| aggregate.apply(rows.iter().map(|row| &row[column]))),
+        );
+        output.push(out_row);
+    }
+
+    Ok(Array::from(output))
+}
+
+/// Turns every column of `table` that is not in `id_columns` into rows of
+/// (id columns..., attribute, value).
+pub fn unpivot(
+    table: &Array,
+    id_columns: &[usize],
+    attribute_header: &str,
+    value_header: &str,
+) -> Result<Array, RunErrorMsg> {
+    let (headers, rows) = split_headers(table);
+    let value_columns: Vec<usize> = (0..headers.len())
+        .filter(|i| !id_columns.contains(i))
+        .collect();
+    if value_columns.is_empty() {
+        return Err(RunErrorMsg::InvalidArgument);
+    }
+
+    let mut header_row: Vec<CellValue> = id_columns.iter().map(|&i| headers[i].clone()).collect();
+    header_row.push(CellValue::Text(attribute_header.to_string()));
+    header_row.push(CellValue::Text(value_header.to_string()));
+
+    let mut output = vec![header_row];
+    for row in rows {
+        for &column in &value_columns {
+            let mut out_row: Vec<CellValue> = id_columns.iter().map(|&i| row[i].clone()).collect();
+            out_row.push(headers[column].clone());
+            out_row.push(row[column].clone());
+            output.push(out_row);
+        }
+    }
+
+    Ok(Array::from(output))
+}
+
+/// Creates one row per distinct combination of the `rows` columns and one
+/// column per distinct value of `column`, aggregating `value` at each
+/// intersection. Combinations without any source rows are left blank.
+pub fn pivot(
+    table: &Array,
+    rows: &[usize],
+    column: usize,
+    value: usize,
+    aggregate: DataTableAggregate,
+) -> Result<Array, RunErrorMsg> {
+    let (headers, data) = split_headers(table);
+
+    let mut column_keys: BTreeMap<RowKey, usize> = BTreeMap::new();
+    for row in &data {
+        column_keys.insert(RowKey(vec![row[column].clone()]), 0);
+    }
+    for (i, index) in column_keys.values_mut().enumerate() {
+        *index = i;
+    }
+
+    let mut groups: BTreeMap<RowKey, Vec<Vec<&CellValue>>> = BTreeMap::new();
+    for row in &data {
+        let cells = groups
+            .entry(RowKey::from_row(row, rows))
+            .or_insert_with(|| vec![vec![]; column_keys.len()]);
+        let index = column_keys[&RowKey(vec![row[column].clone()])];
+        cells[index].push(&row[value]);
+    }
+
+    let mut header_row: Vec<CellValue> = rows.iter().map(|&i| headers[i].clone()).collect();
+    header_row.extend(
+        column_keys
+            .keys()
+            .map(|key| CellValue::Text(key.0[0].to_display())),
+    );
+
+    let mut output = vec![header_row];
+    for (key, cells) in groups {
+        let mut out_row = key.0;
+        out_row.extend(cells.into_iter().map(|values| {
+            if values.is_empty() {
+                CellValue::Blank
+            } else {
+                aggregate.apply(values)
+            }
+        }));
+        output.push(out_row);
+    }
+
+    if output[0].is_empty() {
+        return Err(RunErrorMsg::EmptyArray);
+    }
+    Ok(Array::from(output))
+}
+
+/// Joins the rows of `left` and `right` where the `left_on` columns equal the
+/// `right_on` columns. The output contains every left column followed by the
+/// right columns that are not keys. Right headers that are already used are
+/// suffixed with a number (eg, `units_2`). Rows with a blank key never match.
+pub fn join(
+    left: &Array,
+    right: &Array,
+    left_on: &[usize],
+    right_on: &[usize],
+    kind: DataTableJoinKind,
+) -> Result<Array, RunErrorMsg> {
+    if left_on.is_empty() || left_on.len() != right_on.len() {
+        return Err(RunErrorMsg::InvalidArgument);
+    }
+    let (left_headers, left_rows) = split_headers(left);
+    let (right_headers, right_rows) = split_headers(right);
+    let right_columns: Vec<usize> = (0..right_headers.len())
+        .filter(|i| !right_on.contains(i))
+        .collect();
+
+    let mut right_index: BTreeMap<RowKey, Vec<usize>> = BTreeMap::new();
+    for (i, row) in right_rows.iter().enumerate() {
+        let key = RowKey::from_row(row, right_on);
+        if !key.has_blank() {
+            right_index.entry(key).or_default().push(i);
+        }
+    }
+
+    let mut header_row = left_headers.to_vec();
+    for (n, &i) in right_columns.iter().enumerate() {
+        let later_headers = right_columns[n + 1..]
+            .iter()
+            .map(|&i| right_headers[i].clone())
+            .collect::<Vec<_>>();
+        let header = unique_header(&header_row, &later_headers, &right_headers[i]);
+        header_row.push(header);
+    }
+
+    let mut output = vec![header_row];
+    let mut right_matched = vec![false; right_rows.len()];
+    for left_row in &left_rows {
+        let key = RowKey::from_row(left_row, left_on);
+        let matches = right_index.get(&key).filter(|_| !key.has_blank());
+        match matches {
+            Some(matches) => {
+                for &i in matches {
+                    right_matched[i] = true;
+                    let mut out_row = left_row.to_vec();
+                    out_row.extend(right_columns.iter().map(|&c| right_rows[i][c].clone()));
+                    output.push(out_row);
+                }
+            }
+            None => {
+                if kind != DataTableJoinKind::Inner {
+                    let mut out_row = left_row.to_vec();
+                    out_row.resize(left_headers.len() + right_columns.len(), CellValue::Blank);
+                    output.push(out_row);
+                }
+            }
+        }
+    }
+
+    if kind == DataTableJoinKind::Outer {
+        for (right_row, _) in std::iter::zip(&right_rows, right_matched).filter(|(_, m)| !m) {
+            let mut out_row = vec![CellValue::Blank; left_headers.len()];
+            for (&l, &r) in std::iter::zip(left_on, right_on) {
+                out_row[l] = right_row[r].clone();
+            }
+            out_row.extend(right_columns.iter().map(|&c| right_row[c].clone()));
+            output.push(out_row);
+        }
+    }
+
+    Ok(Array::from(output))
+}
+
+/// Returns `header`, or `header` with the first unused suffix (eg, `units_2`)
+/// if one of `headers` already has its name (case-insensitive). Suffixed
+/// names also skip the names in `reserved`.
+fn unique_header(headers: &[CellValue], reserved: &[CellValue], header: &CellValue) -> CellValue {
+    let is_used = |name: &str, headers: &[CellValue]| {
+        let name = case_fold(name.trim());
+        headers
+            .iter()
+            .any(|header| case_fold(header.to_display().trim()) == name)
+    };
+    let name = header.to_display();
+    if name.trim().is_empty() || !is_used(&name, headers) {
+        return header.clone();
+    }
+    (2..)
+        .map(|i| format!("{name}_{i}"))
+        .find(|name| !is_used(name, headers) && !is_used(name, reserved))
+        .map_or_else(|| header.clone(), CellValue::Text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sales() -> Array {
+        array(vec![
+            vec!["region", "product", "units"],
+            vec!["East", "apple", "10"],
+            vec!["West", "apple", "5"],
+            vec!["east", "pear", "3"],
+            vec!["West", "pear", "7"],
+            vec!["East", "apple", "1"],
+        ])
+    }
+
+    fn array(rows: Vec<Vec<&str>>) -> Array {
+        Array::from_str_vec(rows, true).unwrap()
+    }
+
+    fn rows_display(array: &Array) -> Vec<Vec<String>> {
+        array
+            .rows()
+            .map(|row| row.iter().map(|v| v.to_display()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_find_columns() {
+        let table = sales();
+        let headers = table.get_row(0).unwrap();
+        let columns = [
+            CellValue::Text("Units".into()),
+            CellValue::from(1.0),
+            CellValue::Text("".into()),
+        ];
+        assert_eq!(find_columns(headers, &columns), Ok(vec![2, 0]));
+        assert!(find_column(headers, &CellValue::Text("price".into())).is_err());
+        assert!(find_column(headers, &CellValue::from(4.0)).is_err());
+    }
+
+    #[test]
+    fn test_group_by() {
+        let result = group_by(&sales(), &[0], &[2], DataTableAggregate::Sum).unwrap();
+        assert_eq!(
+            rows_display(&result),
+            vec![
+                vec!["region", "units"],
+                vec!["East", "14"],
+                vec!["West", "12"],
+            ]
+        );
+
+        let result = group_by(&sales(), &[0, 1], &[2], DataTableAggregate::Count).unwrap();
+        assert_eq!(
+            rows_display(&result),
+            vec![
+                vec!["region", "product", "units"],
+                vec!["East", "apple", "2"],
+                vec!["east", "pear", "1"],
+                vec!["West", "apple", "1"],
+                vec!["West", "pear", "1"],
+            ]
+        );
+
+        assert!(group_by(&sales(), &[], &[2], DataTableAggregate::Sum).is_err());
+    }
+
+    #[test]
+    fn test_aggregates() {
+        let values = [
+            CellValue::from(2.0),
+            CellValue::from(4.0),
+            CellValue::Text("x".into()),
+            CellValue::Blank,
+        ];
+        let apply = |aggregate: DataTableAggregate| aggregate.apply(&values).to_display();
+        assert_eq!(apply(DataTableAggregate::Sum), "6");
+        assert_eq!(apply(DataTableAggregate::Average), "3");
+        assert_eq!(apply(DataTableAggregate::Count), "2");
+        assert_eq!(apply(DataTableAggregate::CountA), "3");
+        assert_eq!(apply(DataTableAggregate::Min), "2");
+        assert_eq!(apply(DataTableAggregate::Max), "4");
+
+        assert!(matches!(
+            DataTableAggregate::Average.apply(&[CellValue::Blank]),
+            CellValue::Error(_)
+        ));
+        assert_eq!(
+            DataTableAggregate::parse(" avg "),
+            Some(DataTableAggregate::Average)
+        );
+        assert_eq!(DataTableAggregate::parse("median"), None);
+    }
+
+    #[test]
+    fn test_unpivot() {
+        let table = array(vec![
+            vec!["name", "q1", "q2"],
+            vec!["a", "1", "2"],
+            vec!["b", "3", ""],
+        ]);
+        let result = unpivot(&table, &[0], "quarter", "amount").unwrap();
+        assert_eq!(
+            rows_display(&result),
+            vec![
+                vec!["name", "quarter", "amount"],
+                vec!["a", "q1", "1"],
+                vec!["a", "q2", "2"],
+                vec!["b", "q1", "3"],
+                vec!["b", "q2", ""],
+            ]
+        );
+
+        assert!(unpivot(&table, &[0, 1, 2], "a", "v").is_err());
+    }
+
+    #[test]
+    fn test_pivot() {
+        let result = pivot(&sales(), &[1], 0, 2, DataTableAggregate::Sum).unwrap();
+        assert_eq!(
+            rows_display(&result),
+            vec![
+                vec!["product", "East", "West"],
+                vec!["apple", "11", "5"],
+                vec!["pear", "3", "7"],
+            ]
+        );
+
+        let result = pivot(&sales(), &[0], 1, 2, DataTableAggregate::Max).unwrap();
+        assert_eq!(
+            rows_display(&result),
+            vec![
+                vec!["region", "apple", "pear"],
+                vec!["East", "10", "3"],
+                vec!["West", "5", "7"],
+            ]
+        );
+
+        // a missing combination is left blank
+        let table = array(vec![
+            vec!["k", "c", "v"],
+            vec!["a", "x", "1"],
+            vec!["b", "y", "2"],
+        ]);
+        let result = pivot(&table, &[0], 1, 2, DataTableAggregate::Sum).unwrap();
+        assert_eq!(
+            rows_display(&result),
+            vec![vec!["k", "x", "y"], vec!["a", "1", ""], vec!["b", "", "2"]]
+        );
+    }
+
+    #[test]
+    fn test_join() {
+        let left = array(vec![
+            vec!["id", "name"],
+            vec!["1", "Ann"],
+            vec!["2", "Bob"],
+            vec!["", "Nobody"],
+        ]);
+        let right = array(vec![
+            vec!["user", "city"],
+            vec!["2", "Paris"],
+            vec!["3", "Rome"],
+            vec!["2", "Oslo"],
+        ]);
+
+        let inner = join(&left, &right, &[0], &[0], DataTableJoinKind::Inner).unwrap();
+        assert_eq!(
+            rows_display(&inner),
+            vec![
+                vec!["id", "name", "city"],
+                vec!["2", "Bob", "Paris"],
+                vec!["2", "Bob", "Oslo"],
+            ]
+        );
+
+        let left_join = join(&left, &right, &[0], &[0], DataTableJoinKind::Left).unwrap();
+        assert_eq!(
+            rows_display(&left_join),
+            vec![
+                vec!["id", "name", "city"],
+                vec!["1", "Ann", ""],
+                vec!["2", "Bob", "Paris"],
+                vec!["2", "Bob", "Oslo"],
+                vec!["", "Nobody", ""],
+            ]
+        );
+
+        let outer = join(&left, &right, &[0], &[0], DataTableJoinKind::Outer).unwrap();
+        assert_eq!(
+            rows_display(&outer),
+            vec![
+                vec!["id", "name", "city"],
+                vec!["1", "Ann", ""],
+                vec!["2", "Bob", "Paris"],
+                vec!["2", "Bob", "Oslo"],
+                vec!["", "Nobody", ""],
+                vec!["3", "", "Rome"],
+            ]
+        );
+
+        // right headers that are already used are suffixed
+        let right = array(vec![
+            vec!["id", "Name", "name_2"],
+            vec!["2", "Robert", "Bobby"],
+        ]);
+        let inner = join(&left, &right, &[0], &[0], DataTableJoinKind::Inner).unwrap();
+        assert_eq!(
+            rows_display(&inner),
+            vec![
+                vec!["id", "name", "Name_3", "name_2"],
+                vec!["2", "Bob", "Robert", "Bobby"],
+            ]
+        );
+
+        assert!(join(&left, &right, &[0], &[], DataTableJoinKind::Inner).is_err());
+    }
+
+    #[test]
+    fn test_to_formula() {
+        let transform = DataTableTransform::GroupBy {
+            table: "Sales".into(),
+            group_by: vec!["region".into(), "product".into()],
+            values: vec!["units".into()],
+            aggregate: DataTableAggregate::Sum,
+        };
+        assert_eq!(
+            transform.to_formula(),
+            r#"TABLE.GROUPBY(Sales[#ALL], {"region", "product"}, {"units"}, "SUM")"#
+        );
+        assert_eq!(transform.default_name(), "Sales_grouped");
+
+        let transform = DataTableTransform::Unpivot {
+            table: "Sales".into(),
+            id_columns: vec![],
+            attribute_header: None,
+            value_header: Some("units".into()),
+        };
+        assert_eq!(
+            transform.to_formula(),
+            r#"TABLE.UNPIVOT(Sales[#ALL], "", "Attribute", "units")"#
+        );
+
+        let transform = DataTableTransform::Join {
+            left: "Users".into(),
+            right: "Cities".into(),
+            left_on: vec!["id".into()],
+            right_on: vec!["user".into()],
+            kind: DataTableJoinKind::Left,
+        };
+        assert_eq!(
+            transform.to_formula(),
+            r#"TABLE.JOIN(Users[#ALL], Cities[#ALL], {"id"}, {"user"}, "LEFT")"#
+        );
+        assert_eq!(transform.source_tables(), vec!["Users", "Cities"]);
+    }
+}
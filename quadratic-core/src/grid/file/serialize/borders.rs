@@ -24,7 +24,7 @@ fn import_rgba(schema: current::RgbaSchema) -> Rgba {
     }
 }
 
-fn import_border_line(schema: current::CellBorderLineSchema) -> CellBorderLine {
+pub(crate) fn import_border_line(schema: current::CellBorderLineSchema) -> CellBorderLine {
     match schema {
         current::CellBorderLineSchema::Line1 => CellBorderLine::Line1,
         current::CellBorderLineSchema::Line2 => CellBorderLine::Line2,
@@ -32,6 +32,13 @@ fn import_border_line(schema: current::CellBorderLineSchema) -> CellBorderLine {
         current::CellBorderLineSchema::Dotted => CellBorderLine::Dotted,
         current::CellBorderLineSchema::Dashed => CellBorderLine::Dashed,
         current::CellBorderLineSchema::Double => CellBorderLine::Double,
+        current::CellBorderLineSchema::Hair => CellBorderLine::Hair,
+        current::CellBorderLineSchema::MediumDashed => CellBorderLine::MediumDashed,
+        current::CellBorderLineSchema::DashDot => CellBorderLine::DashDot,
+        current::CellBorderLineSchema::MediumDashDot => CellBorderLine::MediumDashDot,
+        current::CellBorderLineSchema::DashDotDot => CellBorderLine::DashDotDot,
+        current::CellBorderLineSchema::MediumDashDotDot => CellBorderLine::MediumDashDotDot,
+        current::CellBorderLineSchema::SlantDashDot => CellBorderLine::SlantDashDot,
         current::CellBorderLineSchema::Clear => CellBorderLine::Clear,
     }
 }
@@ -56,6 +63,14 @@ pub(crate) fn import_borders(borders: current::BordersSchema) -> Borders {
         right: import_contiguous_2d(borders.right, opt_fn(import_border_style_timestamp)),
         top: import_contiguous_2d(borders.top, opt_fn(import_border_style_timestamp)),
         bottom: import_contiguous_2d(borders.bottom, opt_fn(import_border_style_timestamp)),
+        diagonal_up: import_contiguous_2d(
+            borders.diagonal_up,
+            opt_fn(import_border_style_timestamp),
+        ),
+        diagonal_down: import_contiguous_2d(
+            borders.diagonal_down,
+            opt_fn(import_border_style_timestamp),
+        ),
     }
 }
 
@@ -68,7 +83,7 @@ fn export_rgba(color: Rgba) -> current::RgbaSchema {
     }
 }
 
-fn export_border_style(line: CellBorderLine) -> current::CellBorderLineSchema {
+pub(crate) fn export_border_style(line: CellBorderLine) -> current::CellBorderLineSchema {
     match line {
         CellBorderLine::Line1 => current::CellBorderLineSchema::Line1,
         CellBorderLine::Line2 => current::CellBorderLineSchema::Line2,
@@ -76,6 +91,13 @@ fn export_border_style(line: CellBorderLine) -> current::CellBorderLineSchema {
         CellBorderLine::Dotted => current::CellBorderLineSchema::Dotted,
         CellBorderLine::Dashed => current::CellBorderLineSchema::Dashed,
         CellBorderLine::Double => current::CellBorderLineSchema::Double,
+        CellBorderLine::Hair => current::CellBorderLineSchema::Hair,
+        CellBorderLine::MediumDashed => current::CellBorderLineSchema::MediumDashed,
+        CellBorderLine::DashDot => current::CellBorderLineSchema::DashDot,
+        CellBorderLine::MediumDashDot => current::CellBorderLineSchema::MediumDashDot,
+        CellBorderLine::DashDotDot => current::CellBorderLineSchema::DashDotDot,
+        CellBorderLine::MediumDashDotDot => current::CellBorderLineSchema::MediumDashDotDot,
+        CellBorderLine::SlantDashDot => current::CellBorderLineSchema::SlantDashDot,
         CellBorderLine::Clear => current::CellBorderLineSchema::Clear,
     }
 }
@@ -108,5 +130,7 @@ pub(crate) fn export_borders(borders: Borders) -> current::BordersSchema {
         right: export_border_side(borders.right),
         top: export_border_side(borders.top),
         bottom: export_border_side(borders.bottom),
+        diagonal_up: export_border_side(borders.diagonal_up),
+        diagonal_down: export_border_side(borders.diagonal_down),
     }
 }
@@ -0,0 +1,173 @@
+use crate::grid::{
+    Format,
+    formats::{CellStyle, CellStyleBorder, CellStyleBorders, Theme, ThemeColor},
+};
+
+use super::{
+    borders::{export_border_style, import_border_line},
+    current,
+    formats::{
+        export_cell_align, export_cell_vertical_align, export_cell_wrap, export_numeric_format,
+        import_cell_align, import_cell_vertical_align, import_cell_wrap, import_numeric_format,
+    },
+};
+
+fn import_format(format: current::CellStyleFormatSchema) -> Format {
+    Format {
+        align: format.align.map(import_cell_align),
+        vertical_align: format.vertical_align.map(import_cell_vertical_align),
+        wrap: format.wrap.map(import_cell_wrap),
+        numeric_format: format.numeric_format.map(import_numeric_format),
+        numeric_decimals: format.numeric_decimals,
+        numeric_commas: format.numeric_commas,
+        bold: format.bold,
+        italic: format.italic,
+        text_color: format.text_color,
+        fill_color: format.fill_color,
+        date_time: format.date_time,
+        underline: format.underline,
+        strike_through: format.strike_through,
+        font_size: format.font_size,
+        font_family: format.font_family,
+        text_rotation: format.text_rotation,
+        indent: format.indent,
+        style: None,
+    }
+}
+
+fn import_border(border: current::CellStyleBorderSchema) -> CellStyleBorder {
+    CellStyleBorder {
+        color: border.color,
+        line: import_border_line(border.line),
+    }
+}
+
+pub(crate) fn import_cell_styles(cell_styles: Vec<current::CellStyleSchema>) -> Vec<CellStyle> {
+    cell_styles
+        .into_iter()
+        .map(|cell_style| CellStyle {
+            name: cell_style.name,
+            format: import_format(cell_style.format),
+            borders: CellStyleBorders {
+                top: cell_style.borders.top.map(import_border),
+                bottom: cell_style.borders.bottom.map(import_border),
+                left: cell_style.borders.left.map(import_border),
+                right: cell_style.borders.right.map(import_border),
+            },
+        })
+        .collect()
+}
+
+pub(crate) fn import_theme(theme: Option<current::ThemeSchema>) -> Theme {
+    match theme {
+        Some(theme) => Theme {
+            colors: theme
+                .colors
+                .into_iter()
+                .map(|color| ThemeColor {
+                    name: color.name,
+                    color: color.color,
+                })
+                .collect(),
+        },
+        None => Theme::default(),
+    }
+}
+
+fn export_format(format: Format) -> current::CellStyleFormatSchema {
+    current::CellStyleFormatSchema {
+        align: format.align.map(export_cell_align),
+        vertical_align: format.vertical_align.map(export_cell_vertical_align),
+        wrap: format.wrap.map(export_cell_wrap),
+        numeric_format: format.numeric_format.map(export_numeric_format),
+        numeric_decimals: format.numeric_decimals,
+        numeric_commas: format.numeric_commas,
+        bold: format.bold,
+        italic: format.italic,
+        text_color: format.text_color,
+        fill_color: format.fill_color,
+        date_time: format.date_time,
+        underline: format.underline,
+        strike_through: format.strike_through,
+        font_size: format.font_size,
+        font_family: format.font_family,
+        text_rotation: format.text_rotation,
+        indent: format.indent,
+    }
+}
+
+fn export_border(border: CellStyleBorder) -> current::CellStyleBorderSchema {
+    current::CellStyleBorderSchema {
+        color: border.color,
+        line: export_border_style(border.line),
+    }
+}
+
+pub(crate) fn export_cell_styles(cell_styles: Vec<CellStyle>) -> Vec<current::CellStyleSchema> {
+    cell_styles
+        .into_iter()
+        .map(|cell_style| current::CellStyleSchema {
+            name: cell_style.name,
+            format: export_format(cell_style.format),
+            borders: current::CellStyleBordersSchema {
+                top: cell_style.borders.top.map(export_border),
+                bottom: cell_style.borders.bottom.map(export_border),
+                left: cell_style.borders.left.map(export_border),
+                right: cell_style.borders.right.map(export_border),
+            },
+        })
+        .collect()
+}
+
+pub(crate) fn export_theme(theme: Theme) -> Option<current::ThemeSchema> {
+    Some(current::ThemeSchema {
+        colors: theme
+            .colors
+            .into_iter()
+            .map(|color| current::ThemeColorSchema {
+                name: color.name,
+                color: color.color,
+            })
+            .collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::{NumericFormat, NumericFormatKind, sheet::borders::CellBorderLine};
+
+    #[test]
+    fn test_import_export_cell_styles() {
+        let cell_styles = vec![CellStyle {
+            name: "Currency Total".to_string(),
+            format: Format {
+                bold: Some(true),
+                numeric_format: Some(NumericFormat {
+                    kind: NumericFormatKind::Currency,
+                    symbol: Some("$".to_string()),
+                }),
+                fill_color: Some("theme:accent1".to_string()),
+                ..Default::default()
+            },
+            borders: CellStyleBorders {
+                top: Some(CellStyleBorder {
+                    color: "#000000".to_string(),
+                    line: CellBorderLine::Line1,
+                }),
+                bottom: Some(CellStyleBorder {
+                    color: "theme:dark1".to_string(),
+                    line: CellBorderLine::Double,
+                }),
+                ..Default::default()
+            },
+        }];
+        let exported = export_cell_styles(cell_styles.clone());
+        assert_eq!(import_cell_styles(exported), cell_styles);
+
+        let mut theme = Theme::default();
+        theme.colors[4].color = "#123456".to_string();
+        assert_eq!(import_theme(export_theme(theme.clone())), theme);
+        assert_eq!(import_theme(None), Theme::default());
+    }
+}
@@ -1,6 +1,6 @@
 use super::current;
 use crate::{
-    CellValue,
+    CellValue, Hyperlink, RichText, TextSpan,
     cellvalue::Import,
     grid::{CodeCellLanguage, CodeCellValue, ConnectionKind},
 };
@@ -41,6 +41,7 @@ pub fn export_cell_value(cell_value: CellValue) -> current::CellValueSchema {
         CellValue::Date(d) => current::CellValueSchema::Date(d),
         CellValue::Time(t) => current::CellValueSchema::Time(t),
         CellValue::DateTime(dt) => current::CellValueSchema::DateTime(dt),
+        CellValue::DateTimeTz(dt) => current::CellValueSchema::DateTimeTz(dt),
         CellValue::Error(error) => {
             current::CellValueSchema::Error(current::RunErrorSchema::from_grid_run_error(*error))
         }
@@ -48,6 +49,27 @@ pub fn export_cell_value(cell_value: CellValue) -> current::CellValueSchema {
         CellValue::Import(import) => current::CellValueSchema::Import(current::ImportSchema {
             file_name: import.file_name,
         }),
+        CellValue::Hyperlink(link) => {
+            current::CellValueSchema::Hyperlink(current::HyperlinkSchema {
+                url: link.url,
+                text: link.text,
+                value: link.value.map(|value| Box::new(export_cell_value(*value))),
+            })
+        }
+        CellValue::RichText(rich_text) => current::CellValueSchema::RichText(
+            rich_text
+                .spans
+                .into_iter()
+                .map(|span| current::TextSpanSchema {
+                    text: span.text,
+                    bold: span.bold,
+                    italic: span.italic,
+                    underline: span.underline,
+                    strike_through: span.strike_through,
+                    text_color: span.text_color,
+                })
+                .collect(),
+        ),
     }
 }
 
@@ -101,17 +123,41 @@ pub fn import_cell_value(value: current::CellValueSchema) -> CellValue {
         current::CellValueSchema::Date(date) => CellValue::Date(date),
         current::CellValueSchema::Time(time) => CellValue::Time(time),
         current::CellValueSchema::DateTime(dt) => CellValue::DateTime(dt),
+        current::CellValueSchema::DateTimeTz(dt) => CellValue::DateTimeTz(dt),
         current::CellValueSchema::Error(error) => CellValue::Error(Box::new(error.into())),
         current::CellValueSchema::Image(text) => CellValue::Image(text),
         current::CellValueSchema::Import(current::ImportSchema { file_name }) => {
             CellValue::Import(Import::new(file_name))
         }
+        current::CellValueSchema::Hyperlink(current::HyperlinkSchema { url, text, value }) => {
+            let value = value.map(|value| import_cell_value(*value));
+            match Hyperlink::new(url, text.clone()) {
+                Some(link) => CellValue::Hyperlink(Box::new(Hyperlink {
+                    value: value.map(Box::new),
+                    ..link
+                })),
+                None => value.unwrap_or(CellValue::Text(text)),
+            }
+        }
+        current::CellValueSchema::RichText(spans) => {
+            CellValue::RichText(RichText::new(spans.into_iter().map(|span| TextSpan {
+                text: span.text,
+                bold: span.bold,
+                italic: span.italic,
+                underline: span.underline,
+                strike_through: span.strike_through,
+                text_color: span.text_color,
+            })))
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{a1::A1Selection, controller::GridController, grid::file};
+    use crate::{
+        CellValue, Hyperlink, RichText, TextSpan, a1::A1Selection, controller::GridController,
+        grid::file,
+    };
 
     #[test]
     fn test_import_and_export_date_time() {
@@ -124,4 +170,40 @@ mod tests {
         let imported = file::import(exported).unwrap();
         assert_eq!(imported, *gc.grid());
     }
+
+    #[test]
+    fn test_import_and_export_links_and_rich_text() {
+        let mut gc = GridController::test();
+        let sheet_id = gc.sheet_ids()[0];
+        let sheet = gc.sheet_mut(sheet_id);
+        sheet.set_cell_value(
+            pos![A1],
+            CellValue::Hyperlink(Box::new(
+                Hyperlink::new("https://example.com", "Example").unwrap(),
+            )),
+        );
+        sheet.set_cell_value(
+            pos![A2],
+            CellValue::RichText(RichText::new([
+                TextSpan {
+                    bold: Some(true),
+                    text_color: Some("red".to_string()),
+                    ..TextSpan::new("Total:")
+                },
+                TextSpan::new(" 42"),
+            ])),
+        );
+        let link = Hyperlink::new("#A1", "").unwrap();
+        gc.sheet_mut(sheet_id).set_cell_value(
+            pos![A3],
+            CellValue::Hyperlink(Box::new(link.attach_to(CellValue::Number(42.into())))),
+        );
+        let grid = gc.grid().clone();
+        let exported = file::export(grid).unwrap();
+        let imported = file::import(exported).unwrap();
+        let sheet = imported.try_sheet(sheet_id).unwrap();
+        for pos in [pos![A1], pos![A2], pos![A3]] {
+            assert_eq!(sheet.cell_value(pos), gc.sheet(sheet_id).cell_value(pos));
+        }
+    }
 }
@@ -0,0 +1,55 @@
+use crate::{
+    Pos,
+    grid::sheet::comments::{Comment, CommentThread, Comments},
+};
+
+use super::current;
+
+pub(crate) fn import_comments(comments: current::CommentsSchema) -> Comments {
+    let mut imported = Comments::default();
+    for (pos, thread) in comments {
+        imported.set(
+            Pos { x: pos.x, y: pos.y },
+            Some(CommentThread {
+                comments: thread
+                    .comments
+                    .into_iter()
+                    .map(|comment| Comment {
+                        id: comment.id,
+                        author: comment.author,
+                        created: comment.created,
+                        text: comment.text,
+                        mentions: comment.mentions,
+                    })
+                    .collect(),
+                resolved: thread.resolved,
+            }),
+        );
+    }
+    imported
+}
+
+pub(crate) fn export_comments(comments: Comments) -> current::CommentsSchema {
+    comments
+        .threads()
+        .map(|(pos, thread)| {
+            (
+                current::PosSchema { x: pos.x, y: pos.y },
+                current::CommentThreadSchema {
+                    comments: thread
+                        .comments
+                        .iter()
+                        .map(|comment| current::CommentSchema {
+                            id: comment.id,
+                            author: comment.author.clone(),
+                            created: comment.created,
+                            text: comment.text.clone(),
+                            mentions: comment.mentions.clone(),
+                        })
+                        .collect(),
+                    resolved: thread.resolved,
+                },
+            )
+        })
+        .collect()
+}
@@ -0,0 +1,366 @@
+use crate::grid::sheet::conditional_formats::{
+    ConditionalFormats,
+    conditional_format::{ConditionalFormat, ConditionalFormatStyle},
+    rules::{
+        ColorScalePoint, CompareOperator, ConditionalFormatCondition, ConditionalFormatRule,
+        DataBar, DateOccurring, IconSet, IconSetKind, ScaleValue, TextOperator,
+    },
+};
+
+use super::{
+    current,
+    selection::{export_selection, import_selection},
+};
+
+fn import_style(style: current::ConditionalFormatStyleSchema) -> ConditionalFormatStyle {
+    ConditionalFormatStyle {
+        fill_color: style.fill_color,
+        text_color: style.text_color,
+        bold: style.bold,
+        italic: style.italic,
+        underline: style.underline,
+        strike_through: style.strike_through,
+    }
+}
+
+fn import_scale_value(value: current::ScaleValueSchema) -> ScaleValue {
+    match value {
+        current::ScaleValueSchema::Min => ScaleValue::Min,
+        current::ScaleValueSchema::Max => ScaleValue::Max,
+        current::ScaleValueSchema::Number(n) => ScaleValue::Number(n),
+        current::ScaleValueSchema::Percent(n) => ScaleValue::Percent(n),
+        current::ScaleValueSchema::Percentile(n) => ScaleValue::Percentile(n),
+    }
+}
+
+fn import_condition(
+    condition: current::ConditionalFormatConditionSchema,
+) -> ConditionalFormatCondition {
+    match condition {
+        current::ConditionalFormatConditionSchema::Compare { operator, values } => {
+            ConditionalFormatCondition::Compare {
+                operator: match operator {
+                    current::CompareOperatorSchema::Equal => CompareOperator::Equal,
+                    current::CompareOperatorSchema::NotEqual => CompareOperator::NotEqual,
+                    current::CompareOperatorSchema::GreaterThan => CompareOperator::GreaterThan,
+                    current::CompareOperatorSchema::GreaterThanOrEqual => {
+                        CompareOperator::GreaterThanOrEqual
+                    }
+                    current::CompareOperatorSchema::LessThan => CompareOperator::LessThan,
+                    current::CompareOperatorSchema::LessThanOrEqual => {
+                        CompareOperator::LessThanOrEqual
+                    }
+                    current::CompareOperatorSchema::Between => CompareOperator::Between,
+                    current::CompareOperatorSchema::NotBetween => CompareOperator::NotBetween,
+                },
+                values,
+            }
+        }
+        current::ConditionalFormatConditionSchema::Text { operator, text } => {
+            ConditionalFormatCondition::Text {
+                operator: match operator {
+                    current::TextOperatorSchema::Contains => TextOperator::Contains,
+                    current::TextOperatorSchema::NotContains => TextOperator::NotContains,
+                    current::TextOperatorSchema::BeginsWith => TextOperator::BeginsWith,
+                    current::TextOperatorSchema::EndsWith => TextOperator::EndsWith,
+                },
+                text,
+            }
+        }
+        current::ConditionalFormatConditionSchema::DateOccurring(occurring) => {
+            ConditionalFormatCondition::DateOccurring(match occurring {
+                current::DateOccurringSchema::Yesterday => DateOccurring::Yesterday,
+                current::DateOccurringSchema::Today => DateOccurring::Today,
+                current::DateOccurringSchema::Tomorrow => DateOccurring::Tomorrow,
+                current::DateOccurringSchema::Last7Days => DateOccurring::Last7Days,
+                current::DateOccurringSchema::LastWeek => DateOccurring::LastWeek,
+                current::DateOccurringSchema::ThisWeek => DateOccurring::ThisWeek,
+                current::DateOccurringSchema::NextWeek => DateOccurring::NextWeek,
+                current::DateOccurringSchema::LastMonth => DateOccurring::LastMonth,
+                current::DateOccurringSchema::ThisMonth => DateOccurring::ThisMonth,
+                current::DateOccurringSchema::NextMonth => DateOccurring::NextMonth,
+            })
+        }
+        current::ConditionalFormatConditionSchema::Duplicate => {
+            ConditionalFormatCondition::Duplicate
+        }
+        current::ConditionalFormatConditionSchema::Unique => ConditionalFormatCondition::Unique,
+        current::ConditionalFormatConditionSchema::TopBottom { top, rank, percent } => {
+            ConditionalFormatCondition::TopBottom { top, rank, percent }
+        }
+        current::ConditionalFormatConditionSchema::Average { above, or_equal } => {
+            ConditionalFormatCondition::Average { above, or_equal }
+        }
+        current::ConditionalFormatConditionSchema::Formula(formula) => {
+            ConditionalFormatCondition::Formula(formula)
+        }
+    }
+}
+
+fn import_rule(rule: current::ConditionalFormatRuleSchema) -> ConditionalFormatRule {
+    match rule {
+        current::ConditionalFormatRuleSchema::Highlight { condition, style } => {
+            ConditionalFormatRule::Highlight {
+                condition: import_condition(condition),
+                style: import_style(style),
+            }
+        }
+        current::ConditionalFormatRuleSchema::ColorScale(points) => {
+            ConditionalFormatRule::ColorScale(
+                points
+                    .into_iter()
+                    .map(|point| ColorScalePoint {
+                        value: import_scale_value(point.value),
+                        color: point.color,
+                    })
+                    .collect(),
+            )
+        }
+        current::ConditionalFormatRuleSchema::DataBar(data_bar) => {
+            ConditionalFormatRule::DataBar(DataBar {
+                min: import_scale_value(data_bar.min),
+                max: import_scale_value(data_bar.max),
+                color: data_bar.color,
+                show_value: data_bar.show_value,
+            })
+        }
+        current::ConditionalFormatRuleSchema::IconSet(icon_set) => {
+            ConditionalFormatRule::IconSet(IconSet {
+                kind: match icon_set.kind {
+                    current::IconSetKindSchema::Arrows3 => IconSetKind::Arrows3,
+                    current::IconSetKindSchema::TrafficLights3 => IconSetKind::TrafficLights3,
+                    current::IconSetKindSchema::Symbols3 => IconSetKind::Symbols3,
+                    current::IconSetKindSchema::Flags3 => IconSetKind::Flags3,
+                    current::IconSetKindSchema::Arrows4 => IconSetKind::Arrows4,
+                    current::IconSetKindSchema::Rating4 => IconSetKind::Rating4,
+                    current::IconSetKindSchema::Arrows5 => IconSetKind::Arrows5,
+                    current::IconSetKindSchema::Rating5 => IconSetKind::Rating5,
+                },
+                thresholds: icon_set
+                    .thresholds
+                    .into_iter()
+                    .map(import_scale_value)
+                    .collect(),
+                reverse: icon_set.reverse,
+                show_value: icon_set.show_value,
+            })
+        }
+    }
+}
+
+pub(crate) fn import_conditional_formats(
+    conditional_formats: Vec<current::ConditionalFormatSchema>,
+) -> ConditionalFormats {
+    ConditionalFormats {
+        conditional_formats: conditional_formats
+            .into_iter()
+            .map(|cf| ConditionalFormat {
+                id: cf.id,
+                selection: import_selection(cf.selection),
+                rule: import_rule(cf.rule),
+            })
+            .collect(),
+        ..Default::default()
+    }
+}
+
+fn export_style(style: ConditionalFormatStyle) -> current::ConditionalFormatStyleSchema {
+    current::ConditionalFormatStyleSchema {
+        fill_color: style.fill_color,
+        text_color: style.text_color,
+        bold: style.bold,
+        italic: style.italic,
+        underline: style.underline,
+        strike_through: style.strike_through,
+    }
+}
+
+fn export_scale_value(value: ScaleValue) -> current::ScaleValueSchema {
+    match value {
+        ScaleValue::Min => current::ScaleValueSchema::Min,
+        ScaleValue::Max => current::ScaleValueSchema::Max,
+        ScaleValue::Number(n) => current::ScaleValueSchema::Number(n),
+        ScaleValue::Percent(n) => current::ScaleValueSchema::Percent(n),
+        ScaleValue::Percentile(n) => current::ScaleValueSchema::Percentile(n),
+    }
+}
+
+fn export_condition(
+    condition: ConditionalFormatCondition,
+) -> current::ConditionalFormatConditionSchema {
+    match condition {
+        ConditionalFormatCondition::Compare { operator, values } => {
+            current::ConditionalFormatConditionSchema::Compare {
+                operator: match operator {
+                    CompareOperator::Equal => current::CompareOperatorSchema::Equal,
+                    CompareOperator::NotEqual => current::CompareOperatorSchema::NotEqual,
+                    CompareOperator::GreaterThan => current::CompareOperatorSchema::GreaterThan,
+                    CompareOperator::GreaterThanOrEqual => {
+                        current::CompareOperatorSchema::GreaterThanOrEqual
+                    }
+                    CompareOperator::LessThan => current::CompareOperatorSchema::LessThan,
+                    CompareOperator::LessThanOrEqual => {
+                        current::CompareOperatorSchema::LessThanOrEqual
+                    }
+                    CompareOperator::Between => current::CompareOperatorSchema::Between,
+                    CompareOperator::NotBetween => current::CompareOperatorSchema::NotBetween,
+                },
+                values,
+            }
+        }
+        ConditionalFormatCondition::Text { operator, text } => {
+            current::ConditionalFormatConditionSchema::Text {
+                operator: match operator {
+                    TextOperator::Contains => current::TextOperatorSchema::Contains,
+                    TextOperator::NotContains => current::TextOperatorSchema::NotContains,
+                    TextOperator::BeginsWith => current::TextOperatorSchema::BeginsWith,
+                    TextOperator::EndsWith => current::TextOperatorSchema::EndsWith,
+                },
+                text,
+            }
+        }
+        ConditionalFormatCondition::DateOccurring(occurring) => {
+            current::ConditionalFormatConditionSchema::DateOccurring(match occurring {
+                DateOccurring::Yesterday => current::DateOccurringSchema::Yesterday,
+                DateOccurring::Today => current::DateOccurringSchema::Today,
+                DateOccurring::Tomorrow => current::DateOccurringSchema::Tomorrow,
+                DateOccurring::Last7Days => current::DateOccurringSchema::Last7Days,
+                DateOccurring::LastWeek => current::DateOccurringSchema::LastWeek,
+                DateOccurring::ThisWeek => current::DateOccurringSchema::ThisWeek,
+                DateOccurring::NextWeek => current::DateOccurringSchema::NextWeek,
+                DateOccurring::LastMonth => current::DateOccurringSchema::LastMonth,
+                DateOccurring::ThisMonth => current::DateOccurringSchema::ThisMonth,
+                DateOccurring::NextMonth => current::DateOccurringSchema::NextMonth,
+            })
+        }
+        ConditionalFormatCondition::Duplicate => {
+            current::ConditionalFormatConditionSchema::Duplicate
+        }
+        ConditionalFormatCondition::Unique => current::ConditionalFormatConditionSchema::Unique,
+        ConditionalFormatCondition::TopBottom { top, rank, percent } => {
+            current::ConditionalFormatConditionSchema::TopBottom { top, rank, percent }
+        }
+        ConditionalFormatCondition::Average { above, or_equal } => {
+            current::ConditionalFormatConditionSchema::Average { above, or_equal }
+        }
+        ConditionalFormatCondition::Formula(formula) => {
+            current::ConditionalFormatConditionSchema::Formula(formula)
+        }
+    }
+}
+
+fn export_rule(rule: ConditionalFormatRule) -> current::ConditionalFormatRuleSchema {
+    match rule {
+        ConditionalFormatRule::Highlight { condition, style } => {
+            current::ConditionalFormatRuleSchema::Highlight {
+                condition: export_condition(condition),
+                style: export_style(style),
+            }
+        }
+        ConditionalFormatRule::ColorScale(points) => {
+            current::ConditionalFormatRuleSchema::ColorScale(
+                points
+                    .into_iter()
+                    .map(|point| current::ColorScalePointSchema {
+                        value: export_scale_value(point.value),
+                        color: point.color,
+                    })
+                    .collect(),
+            )
+        }
+        ConditionalFormatRule::DataBar(data_bar) => {
+            current::ConditionalFormatRuleSchema::DataBar(current::DataBarSchema {
+                min: export_scale_value(data_bar.min),
+                max: export_scale_value(data_bar.max),
+                color: data_bar.color,
+                show_value: data_bar.show_value,
+            })
+        }
+        ConditionalFormatRule::IconSet(icon_set) => {
+            current::ConditionalFormatRuleSchema::IconSet(current::IconSetSchema {
+                kind: match icon_set.kind {
+                    IconSetKind::Arrows3 => current::IconSetKindSchema::Arrows3,
+                    IconSetKind::TrafficLights3 => current::IconSetKindSchema::TrafficLights3,
+                    IconSetKind::Symbols3 => current::IconSetKindSchema::Symbols3,
+                    IconSetKind::Flags3 => current::IconSetKindSchema::Flags3,
+                    IconSetKind::Arrows4 => current::IconSetKindSchema::Arrows4,
+                    IconSetKind::Rating4 => current::IconSetKindSchema::Rating4,
+                    IconSetKind::Arrows5 => current::IconSetKindSchema::Arrows5,
+                    IconSetKind::Rating5 => current::IconSetKindSchema::Rating5,
+                },
+                thresholds: icon_set
+                    .thresholds
+                    .into_iter()
+                    .map(export_scale_value)
+                    .collect(),
+                reverse: icon_set.reverse,
+                show_value: icon_set.show_value,
+            })
+        }
+    }
+}
+
+pub(crate) fn export_conditional_formats(
+    conditional_formats: ConditionalFormats,
+) -> Vec<current::ConditionalFormatSchema> {
+    conditional_formats
+        .conditional_formats
+        .into_iter()
+        .map(|cf| current::ConditionalFormatSchema {
+            id: cf.id,
+            selection: export_selection(cf.selection),
+            rule: export_rule(cf.rule),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::a1::A1Selection;
+
+    #[test]
+    fn test_import_export_conditional_formats() {
+        let mut conditional_formats = ConditionalFormats::default();
+        conditional_formats.set(ConditionalFormat::new(
+            A1Selection::test_a1("A1:B5"),
+            ConditionalFormatRule::Highlight {
+                condition: ConditionalFormatCondition::Text {
+                    operator: TextOperator::BeginsWith,
+                    text: "abc".into(),
+                },
+                style: ConditionalFormatStyle {
+                    text_color: Some("#00ff00".into()),
+                    italic: Some(true),
+                    ..Default::default()
+                },
+            },
+        ));
+        conditional_formats.set(ConditionalFormat::new(
+            A1Selection::test_a1("C"),
+            ConditionalFormatRule::ColorScale(vec![
+                ColorScalePoint {
+                    value: ScaleValue::Min,
+                    color: "#ffffff".into(),
+                },
+                ColorScalePoint {
+                    value: ScaleValue::Percentile(50.0),
+                    color: "#ffff00".into(),
+                },
+            ]),
+        ));
+        conditional_formats.set(ConditionalFormat::new(
+            A1Selection::test_a1("D1:D10"),
+            ConditionalFormatRule::IconSet(IconSet {
+                kind: IconSetKind::TrafficLights3,
+                thresholds: vec![ScaleValue::Percent(33.0), ScaleValue::Percent(67.0)],
+                reverse: true,
+                show_value: false,
+            }),
+        ));
+
+        let exported = export_conditional_formats(conditional_formats.clone());
+        let imported = import_conditional_formats(exported);
+        assert_eq!(imported, conditional_formats);
+    }
+}
@@ -15,6 +15,7 @@ use crate::{
         data_table::{
             column_header::DataTableColumnHeader,
             sort::{DataTableSort, SortDirection},
+            table_style::{TableStyle, TableStyleElement},
         },
     },
 };
@@ -264,6 +265,32 @@ fn import_code_run_builder(code_run: current::CodeRunSchema) -> Result<CodeRun>
     Ok(code_run)
 }
 
+fn import_table_style_element(element: current::TableStyleElementSchema) -> TableStyleElement {
+    TableStyleElement {
+        fill_color: element.fill_color,
+        text_color: element.text_color,
+        bold: element.bold,
+    }
+}
+
+fn import_table_style(table_style: current::TableStyleSchema) -> TableStyle {
+    TableStyle {
+        name: table_style.name,
+        whole_table: import_table_style_element(table_style.whole_table),
+        header_row: import_table_style_element(table_style.header_row),
+        row_band: import_table_style_element(table_style.row_band),
+        column_band: import_table_style_element(table_style.column_band),
+        first_column: import_table_style_element(table_style.first_column),
+        last_column: import_table_style_element(table_style.last_column),
+        totals_row: import_table_style_element(table_style.totals_row),
+        banded_rows: table_style.banded_rows,
+        banded_columns: table_style.banded_columns,
+        show_first_column: table_style.show_first_column,
+        show_last_column: table_style.show_last_column,
+        show_totals_row: table_style.show_totals_row,
+    }
+}
+
 pub(crate) fn import_data_table_builder(
     data_tables: Vec<(current::PosSchema, current::DataTableSchema)>,
 ) -> Result<IndexMap<Pos, DataTable>> {
@@ -331,6 +358,7 @@ pub(crate) fn import_data_table_builder(
             sort_dirty: data_table.sort_dirty,
             display_buffer: data_table.display_buffer,
             alternating_colors: data_table.alternating_colors,
+            table_style: data_table.table_style.map(import_table_style),
             formats: import_formats(data_table.formats),
             borders: import_borders(data_table.borders),
             chart_pixel_output: data_table.chart_pixel_output,
@@ -461,6 +489,32 @@ fn export_code_run(code_run: CodeRun) -> current::CodeRunSchema {
     }
 }
 
+fn export_table_style_element(element: TableStyleElement) -> current::TableStyleElementSchema {
+    current::TableStyleElementSchema {
+        fill_color: element.fill_color,
+        text_color: element.text_color,
+        bold: element.bold,
+    }
+}
+
+fn export_table_style(table_style: TableStyle) -> current::TableStyleSchema {
+    current::TableStyleSchema {
+        name: table_style.name,
+        whole_table: export_table_style_element(table_style.whole_table),
+        header_row: export_table_style_element(table_style.header_row),
+        row_band: export_table_style_element(table_style.row_band),
+        column_band: export_table_style_element(table_style.column_band),
+        first_column: export_table_style_element(table_style.first_column),
+        last_column: export_table_style_element(table_style.last_column),
+        totals_row: export_table_style_element(table_style.totals_row),
+        banded_rows: table_style.banded_rows,
+        banded_columns: table_style.banded_columns,
+        show_first_column: table_style.show_first_column,
+        show_last_column: table_style.show_last_column,
+        show_totals_row: table_style.show_totals_row,
+    }
+}
+
 pub(crate) fn export_data_tables(
     data_tables: IndexMap<Pos, DataTable>,
 ) -> Vec<(current::PosSchema, current::DataTableSchema)> {
@@ -542,6 +596,7 @@ pub(crate) fn export_data_tables(
                 spill_error: data_table.spill_error,
                 value,
                 alternating_colors: data_table.alternating_colors,
+                table_style: data_table.table_style.map(export_table_style),
                 formats: export_formats(data_table.formats),
                 borders: export_borders(data_table.borders),
                 chart_pixel_output: data_table.chart_pixel_output,
@@ -12,7 +12,7 @@ use super::{
     current,
 };
 
-fn import_cell_align(align: current::CellAlignSchema) -> CellAlign {
+pub(crate) fn import_cell_align(align: current::CellAlignSchema) -> CellAlign {
     match align {
         current::CellAlignSchema::Left => CellAlign::Left,
         current::CellAlignSchema::Center => CellAlign::Center,
@@ -20,7 +20,7 @@ fn import_cell_align(align: current::CellAlignSchema) -> CellAlign {
     }
 }
 
-fn import_cell_vertical_align(
+pub(crate) fn import_cell_vertical_align(
     vertical_align: current::CellVerticalAlignSchema,
 ) -> CellVerticalAlign {
     match vertical_align {
@@ -30,7 +30,7 @@ fn import_cell_vertical_align(
     }
 }
 
-fn import_cell_wrap(wrap: current::CellWrapSchema) -> CellWrap {
+pub(crate) fn import_cell_wrap(wrap: current::CellWrapSchema) -> CellWrap {
     match wrap {
         current::CellWrapSchema::Wrap => CellWrap::Wrap,
         current::CellWrapSchema::Overflow => CellWrap::Overflow,
@@ -38,13 +38,14 @@ fn import_cell_wrap(wrap: current::CellWrapSchema) -> CellWrap {
     }
 }
 
-fn import_numeric_format(numeric_format: current::NumericFormatSchema) -> NumericFormat {
+pub(crate) fn import_numeric_format(numeric_format: current::NumericFormatSchema) -> NumericFormat {
     NumericFormat {
         kind: match numeric_format.kind {
             current::NumericFormatKindSchema::Number => NumericFormatKind::Number,
             current::NumericFormatKindSchema::Currency => NumericFormatKind::Currency,
             current::NumericFormatKindSchema::Percentage => NumericFormatKind::Percentage,
             current::NumericFormatKindSchema::Exponential => NumericFormatKind::Exponential,
+            current::NumericFormatKindSchema::Custom => NumericFormatKind::Custom,
         },
         symbol: numeric_format.symbol,
     }
@@ -68,10 +69,15 @@ pub(crate) fn import_formats(formats: current::SheetFormattingSchema) -> SheetFo
         date_time: import_contiguous_2d(formats.date_time, |x| x),
         underline: import_contiguous_2d(formats.underline, |x| x),
         strike_through: import_contiguous_2d(formats.strike_through, |x| x),
+        font_size: import_contiguous_2d(formats.font_size, |x| x),
+        font_family: import_contiguous_2d(formats.font_family, |x| x),
+        text_rotation: import_contiguous_2d(formats.text_rotation, |x| x),
+        indent: import_contiguous_2d(formats.indent, |x| x),
+        style: import_contiguous_2d(formats.style, |x| x),
     }
 }
 
-fn export_cell_align(align: CellAlign) -> current::CellAlignSchema {
+pub(crate) fn export_cell_align(align: CellAlign) -> current::CellAlignSchema {
     match align {
         CellAlign::Left => current::CellAlignSchema::Left,
         CellAlign::Center => current::CellAlignSchema::Center,
@@ -79,7 +85,7 @@ fn export_cell_align(align: CellAlign) -> current::CellAlignSchema {
     }
 }
 
-fn export_cell_vertical_align(
+pub(crate) fn export_cell_vertical_align(
     vertical_align: CellVerticalAlign,
 ) -> current::CellVerticalAlignSchema {
     match vertical_align {
@@ -89,7 +95,7 @@ fn export_cell_vertical_align(
     }
 }
 
-fn export_cell_wrap(wrap: CellWrap) -> current::CellWrapSchema {
+pub(crate) fn export_cell_wrap(wrap: CellWrap) -> current::CellWrapSchema {
     match wrap {
         CellWrap::Wrap => current::CellWrapSchema::Wrap,
         CellWrap::Overflow => current::CellWrapSchema::Overflow,
@@ -97,13 +103,14 @@ fn export_cell_wrap(wrap: CellWrap) -> current::CellWrapSchema {
     }
 }
 
-fn export_numeric_format(numeric_format: NumericFormat) -> current::NumericFormatSchema {
+pub(crate) fn export_numeric_format(numeric_format: NumericFormat) -> current::NumericFormatSchema {
     current::NumericFormatSchema {
         kind: match numeric_format.kind {
             NumericFormatKind::Number => current::NumericFormatKindSchema::Number,
             NumericFormatKind::Currency => current::NumericFormatKindSchema::Currency,
             NumericFormatKind::Percentage => current::NumericFormatKindSchema::Percentage,
             NumericFormatKind::Exponential => current::NumericFormatKindSchema::Exponential,
+            NumericFormatKind::Custom => current::NumericFormatKindSchema::Custom,
         },
         symbol: numeric_format.symbol,
     }
@@ -135,5 +142,10 @@ pub(crate) fn export_formats(formats: SheetFormatting) -> current::SheetFormatti
         date_time: export_contiguous_2d(formats.date_time, |x| x),
         underline: export_contiguous_2d(formats.underline, |x| x),
         strike_through: export_contiguous_2d(formats.strike_through, |x| x),
+        font_size: export_contiguous_2d(formats.font_size, |x| x),
+        font_family: export_contiguous_2d(formats.font_family, |x| x),
+        text_rotation: export_contiguous_2d(formats.text_rotation, |x| x),
+        indent: export_contiguous_2d(formats.indent, |x| x),
+        style: export_contiguous_2d(formats.style, |x| x),
     }
 }
@@ -0,0 +1,122 @@
+use chrono_tz::Tz;
+
+use crate::{
+    date_time::{DateOrder, DateSettings, DateSystem},
+    grid::{Locale, file::current},
+};
+
+fn import_date_order(date_order: current::DateOrderSchema) -> DateOrder {
+    match date_order {
+        current::DateOrderSchema::Mdy => DateOrder::Mdy,
+        current::DateOrderSchema::Dmy => DateOrder::Dmy,
+        current::DateOrderSchema::Ymd => DateOrder::Ymd,
+    }
+}
+
+fn export_date_order(date_order: DateOrder) -> current::DateOrderSchema {
+    match date_order {
+        DateOrder::Mdy => current::DateOrderSchema::Mdy,
+        DateOrder::Dmy => current::DateOrderSchema::Dmy,
+        DateOrder::Ymd => current::DateOrderSchema::Ymd,
+    }
+}
+
+pub(crate) fn import_locale(locale: Option<current::LocaleSchema>) -> Locale {
+    match locale {
+        Some(locale) => Locale {
+            name: locale.name,
+            date_order: import_date_order(locale.date_order),
+            date_format: locale.date_format,
+            time_format: locale.time_format,
+            decimal_separator: locale.decimal_separator,
+            group_separator: locale.group_separator,
+            currency_symbol: locale.currency_symbol,
+            first_day_of_week: locale.first_day_of_week,
+            argument_separator: locale.argument_separator,
+        },
+        None => Locale::default(),
+    }
+}
+
+pub(crate) fn export_locale(locale: Locale) -> Option<current::LocaleSchema> {
+    Some(current::LocaleSchema {
+        name: locale.name,
+        date_order: export_date_order(locale.date_order),
+        date_format: locale.date_format,
+        time_format: locale.time_format,
+        decimal_separator: locale.decimal_separator,
+        group_separator: locale.group_separator,
+        currency_symbol: locale.currency_symbol,
+        first_day_of_week: locale.first_day_of_week,
+        argument_separator: locale.argument_separator,
+    })
+}
+
+/// Unknown time zone names fall back to the user's local time zone.
+pub(crate) fn import_time_zone(time_zone: Option<String>) -> Option<Tz> {
+    time_zone.and_then(|time_zone| time_zone.parse().ok())
+}
+
+pub(crate) fn export_time_zone(time_zone: Option<Tz>) -> Option<String> {
+    time_zone.map(|time_zone| time_zone.name().to_string())
+}
+
+pub(crate) fn import_date_settings(
+    date_settings: Option<current::DateSettingsSchema>,
+) -> DateSettings {
+    match date_settings {
+        Some(date_settings) => DateSettings {
+            date_system: match date_settings.date_system {
+                current::DateSystemSchema::Excel1900 => DateSystem::Excel1900,
+                current::DateSystemSchema::Excel1904 => DateSystem::Excel1904,
+            },
+            serial_dates: date_settings.serial_dates,
+        },
+        None => DateSettings::default(),
+    }
+}
+
+pub(crate) fn export_date_settings(
+    date_settings: DateSettings,
+) -> Option<current::DateSettingsSchema> {
+    Some(current::DateSettingsSchema {
+        date_system: match date_settings.date_system {
+            DateSystem::Excel1900 => current::DateSystemSchema::Excel1900,
+            DateSystem::Excel1904 => current::DateSystemSchema::Excel1904,
+        },
+        serial_dates: date_settings.serial_dates,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_import_export_locale() {
+        let locale = Locale::preset("de-DE").unwrap();
+        assert_eq!(import_locale(export_locale(locale.clone())), locale);
+        assert_eq!(import_locale(None), Locale::default());
+    }
+
+    #[test]
+    fn test_import_export_time_zone() {
+        let time_zone = Some(Tz::America__New_York);
+        assert_eq!(import_time_zone(export_time_zone(time_zone)), time_zone);
+        assert_eq!(import_time_zone(export_time_zone(None)), None);
+        assert_eq!(import_time_zone(Some("Not/A_Zone".into())), None);
+    }
+
+    #[test]
+    fn test_import_export_date_settings() {
+        let date_settings = DateSettings {
+            date_system: DateSystem::Excel1904,
+            serial_dates: true,
+        };
+        assert_eq!(
+            import_date_settings(export_date_settings(date_settings)),
+            date_settings
+        );
+        assert_eq!(import_date_settings(None), DateSettings::default());
+    }
+}
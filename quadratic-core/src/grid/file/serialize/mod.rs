@@ -9,11 +9,16 @@ pub use crate::grid::file::current;
 use super::CURRENT_VERSION;
 
 pub(crate) mod borders;
+pub(crate) mod cell_styles;
 pub(crate) mod cell_value;
 pub(crate) mod column;
+pub(crate) mod comments;
+pub(crate) mod conditional_formats;
 pub(crate) mod contiguous_2d;
 pub(crate) mod data_table;
 pub(crate) mod formats;
+pub(crate) mod locale;
+pub(crate) mod protection;
 pub(crate) mod row_resizes;
 pub(crate) mod selection;
 pub mod sheets;
@@ -26,6 +31,12 @@ pub fn import(file: current::GridSchema) -> Result<Grid> {
             .into_iter()
             .map(import_sheet)
             .collect::<Result<_>>()?,
+        custom_lists: file.custom_lists,
+        cell_styles: cell_styles::import_cell_styles(file.cell_styles),
+        theme: cell_styles::import_theme(file.theme),
+        locale: locale::import_locale(file.locale),
+        time_zone: locale::import_time_zone(file.time_zone),
+        date_settings: locale::import_date_settings(file.date_settings),
     };
     let a1_context = grid.make_a1_context();
     for sheet in grid.sheets.iter_mut() {
@@ -38,5 +49,11 @@ pub fn export(grid: Grid) -> Result<current::GridSchema> {
     Ok(current::GridSchema {
         version: Some(CURRENT_VERSION.into()),
         sheets: grid.sheets.into_iter().map(export_sheet).collect(),
+        custom_lists: grid.custom_lists,
+        cell_styles: cell_styles::export_cell_styles(grid.cell_styles),
+        theme: cell_styles::export_theme(grid.theme),
+        locale: locale::export_locale(grid.locale),
+        time_zone: locale::export_time_zone(grid.time_zone),
+        date_settings: locale::export_date_settings(grid.date_settings),
     })
 }
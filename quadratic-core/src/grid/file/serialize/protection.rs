@@ -0,0 +1,75 @@
+use crate::grid::sheet::protection::{ProtectedRange, SheetProtection};
+
+use super::{
+    current,
+    selection::{export_selection, import_selection},
+};
+
+pub(crate) fn import_protection(protection: current::SheetProtectionSchema) -> SheetProtection {
+    SheetProtection {
+        protected: protection.protected,
+        editors: protection.editors,
+        editable_ranges: protection
+            .editable_ranges
+            .into_iter()
+            .map(import_selection)
+            .collect(),
+        ranges: protection
+            .ranges
+            .into_iter()
+            .map(|range| ProtectedRange {
+                id: range.id,
+                selection: import_selection(range.selection),
+                description: range.description,
+                editors: range.editors,
+            })
+            .collect(),
+    }
+}
+
+pub(crate) fn export_protection(protection: SheetProtection) -> current::SheetProtectionSchema {
+    current::SheetProtectionSchema {
+        protected: protection.protected,
+        editors: protection.editors,
+        editable_ranges: protection
+            .editable_ranges
+            .into_iter()
+            .map(export_selection)
+            .collect(),
+        ranges: protection
+            .ranges
+            .into_iter()
+            .map(|range| current::ProtectedRangeSchema {
+                id: range.id,
+                selection: export_selection(range.selection),
+                description: range.description,
+                editors: range.editors,
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::a1::A1Selection;
+
+    #[test]
+    fn test_import_export_protection() {
+        let protection = SheetProtection {
+            protected: true,
+            editors: vec!["owner@example.com".to_string()],
+            editable_ranges: vec![A1Selection::test_a1("B2:B10")],
+            ranges: vec![ProtectedRange {
+                id: Uuid::new_v4(),
+                selection: A1Selection::test_a1("D1:D"),
+                description: Some("Formulas".to_string()),
+                editors: vec![],
+            }],
+        };
+        let exported = export_protection(protection.clone());
+        assert_eq!(import_protection(exported), protection);
+    }
+}
@@ -3,16 +3,27 @@ use std::str::FromStr;
 use anyhow::Result;
 
 use crate::{
-    grid::{GridBounds, Sheet, SheetFormatting, SheetId, sheet::borders::Borders},
+    Rect,
+    grid::{
+        GridBounds, Sheet, SheetFormatting, SheetId,
+        sheet::{
+            borders::Borders,
+            merge_cells::MergeCells,
+            outlines::{Outline, Outlines},
+        },
+    },
     sheet_offsets::SheetOffsets,
 };
 
 use super::{
     borders::{export_borders, import_borders},
     column::{export_column_builder, import_column_builder},
+    comments::{export_comments, import_comments},
+    conditional_formats::{export_conditional_formats, import_conditional_formats},
     current,
     data_table::{export_data_tables, import_data_table_builder},
     formats::{export_formats, import_formats},
+    protection::{export_protection, import_protection},
     row_resizes::{export_rows_size, import_rows_resize},
     validations::{export_validations, import_validations},
 };
@@ -32,11 +43,29 @@ pub fn import_sheet(sheet: current::SheetSchema) -> Result<Sheet> {
         validations: import_validations(sheet.validations),
         borders: import_borders(sheet.borders),
         formats: import_formats(sheet.formats),
+        merge_cells: MergeCells::from(sheet.merge_cells.iter().map(Rect::from).collect::<Vec<_>>()),
+        outlines: Outlines {
+            columns: import_outline(sheet.column_outline),
+            rows: import_outline(sheet.row_outline),
+        },
+        frozen_columns: sheet.frozen_columns,
+        frozen_rows: sheet.frozen_rows,
+        comments: import_comments(sheet.comments),
+        conditional_formats: import_conditional_formats(sheet.conditional_formats),
+        protection: import_protection(sheet.protection),
     };
+    for column in sheet.hidden_columns {
+        new_sheet.offsets.set_column_hidden(column, true);
+    }
+    for row in sheet.hidden_rows {
+        new_sheet.offsets.set_row_hidden(row, true);
+    }
     Ok(new_sheet)
 }
 
 pub(crate) fn export_sheet(sheet: Sheet) -> current::SheetSchema {
+    let hidden_columns = sheet.offsets.hidden_columns();
+    let hidden_rows = sheet.offsets.hidden_rows();
     current::SheetSchema {
         id: current::IdSchema {
             id: sheet.id.to_string(),
@@ -51,5 +80,31 @@ pub(crate) fn export_sheet(sheet: Sheet) -> current::SheetSchema {
         data_tables: export_data_tables(sheet.data_tables),
         formats: export_formats(sheet.formats),
         columns: export_column_builder(sheet.columns),
+        merge_cells: sheet
+            .merge_cells
+            .rects()
+            .iter()
+            .map(current::RectSchema::from)
+            .collect(),
+        hidden_columns,
+        hidden_rows,
+        column_outline: export_outline(&sheet.outlines.columns),
+        row_outline: export_outline(&sheet.outlines.rows),
+        frozen_columns: sheet.frozen_columns,
+        frozen_rows: sheet.frozen_rows,
+        comments: export_comments(sheet.comments),
+        conditional_formats: export_conditional_formats(sheet.conditional_formats),
+        protection: export_protection(sheet.protection),
+    }
+}
+
+fn import_outline(outline: current::OutlineSchema) -> Outline {
+    Outline::new(outline.levels, outline.collapsed)
+}
+
+fn export_outline(outline: &Outline) -> current::OutlineSchema {
+    current::OutlineSchema {
+        levels: outline.levels().collect(),
+        collapsed: outline.collapsed().collect(),
     }
 }
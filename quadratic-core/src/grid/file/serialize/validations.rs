@@ -3,6 +3,7 @@ use super::current;
 use crate::Pos;
 use crate::grid::sheet::validations::Validations;
 use crate::grid::sheet::validations::rules::ValidationRule;
+use crate::grid::sheet::validations::rules::validation_custom::ValidationCustom;
 use crate::grid::sheet::validations::rules::validation_date_time::{
     DateTimeRange, ValidationDateTime,
 };
@@ -57,6 +58,7 @@ pub fn import_validations(validations: current::ValidationsSchema) -> Validation
             .into_iter()
             .map(|(pos, id)| (Pos { x: pos.x, y: pos.y }, id))
             .collect(),
+        ..Default::default()
     }
 }
 
@@ -69,9 +71,13 @@ fn import_validation_rule(rule: current::ValidationRuleSchema) -> ValidationRule
                     ValidationListSource::Selection(import_selection(selection))
                 }
                 current::ValidationListSourceSchema::List(list) => ValidationListSource::List(list),
+                current::ValidationListSourceSchema::Formula(formula) => {
+                    ValidationListSource::Formula(formula)
+                }
             },
             ignore_blank: list.ignore_blank,
             drop_down: list.drop_down,
+            multi_select: list.multi_select,
         }),
         current::ValidationRuleSchema::Logical(logical) => {
             ValidationRule::Logical(ValidationLogical {
@@ -154,6 +160,10 @@ fn import_validation_rule(rule: current::ValidationRuleSchema) -> ValidationRule
                     .collect(),
             })
         }
+        current::ValidationRuleSchema::Custom(custom) => ValidationRule::Custom(ValidationCustom {
+            formula: custom.formula,
+            ignore_blank: custom.ignore_blank,
+        }),
     }
 }
 
@@ -169,9 +179,13 @@ fn export_validation_rule(rule: ValidationRule) -> current::ValidationRuleSchema
                     ValidationListSource::List(list) => {
                         current::ValidationListSourceSchema::List(list)
                     }
+                    ValidationListSource::Formula(formula) => {
+                        current::ValidationListSourceSchema::Formula(formula)
+                    }
                 },
                 ignore_blank: list.ignore_blank,
                 drop_down: list.drop_down,
+                multi_select: list.multi_select,
             })
         }
         ValidationRule::Logical(logical) => {
@@ -271,6 +285,12 @@ fn export_validation_rule(rule: ValidationRule) -> current::ValidationRuleSchema
                     .collect(),
             })
         }
+        ValidationRule::Custom(custom) => {
+            current::ValidationRuleSchema::Custom(current::ValidationCustomSchema {
+                formula: custom.formula,
+                ignore_blank: custom.ignore_blank,
+            })
+        }
     }
 }
 
@@ -332,6 +352,7 @@ mod tests {
                     source: ValidationListSource::Selection(A1Selection::test_a1("A2,C4:D6,F7:H9")),
                     ignore_blank: true,
                     drop_down: true,
+                    multi_select: false,
                 }),
                 message: crate::grid::sheet::validations::validation::ValidationMessage {
                     show: true,
@@ -346,6 +367,7 @@ mod tests {
                 },
             }],
             warnings,
+            ..Default::default()
         };
         let imported = import_validations(export_validations(validations.clone()));
         assert_eq!(validations, imported);
@@ -381,6 +403,7 @@ mod tests {
                 },
             }],
             warnings,
+            ..Default::default()
         };
         let imported = import_validations(export_validations(validations.clone()));
         assert_eq!(validations, imported);
@@ -430,6 +453,7 @@ mod tests {
                 },
             }],
             warnings,
+            ..Default::default()
         };
         let imported = import_validations(export_validations(validations.clone()));
         assert_eq!(validations, imported);
@@ -471,6 +495,7 @@ mod tests {
                 },
             }],
             warnings,
+            ..Default::default()
         };
         let imported = import_validations(export_validations(validations.clone()));
         assert_eq!(validations, imported);
@@ -30,6 +30,14 @@ fn export_border_line(line: CellBorderLine) -> v1_7::schema::CellBorderLineSchem
         CellBorderLine::Dotted => v1_7::schema::CellBorderLineSchema::Dotted,
         CellBorderLine::Dashed => v1_7::schema::CellBorderLineSchema::Dashed,
         CellBorderLine::Double => v1_7::schema::CellBorderLineSchema::Double,
+        // older files do not have these lines
+        CellBorderLine::Hair => v1_7::schema::CellBorderLineSchema::Dotted,
+        CellBorderLine::MediumDashed
+        | CellBorderLine::DashDot
+        | CellBorderLine::MediumDashDot
+        | CellBorderLine::DashDotDot
+        | CellBorderLine::MediumDashDotDot
+        | CellBorderLine::SlantDashDot => v1_7::schema::CellBorderLineSchema::Dashed,
         CellBorderLine::Clear => v1_7::schema::CellBorderLineSchema::Clear,
     }
 }
@@ -27,6 +27,214 @@ fn upgrade_data_table_kind(
     }
 }
 
+fn upgrade_border_line(line: current::CellBorderLineSchema) -> v1_9::CellBorderLineSchema {
+    match line {
+        current::CellBorderLineSchema::Line1 => v1_9::CellBorderLineSchema::Line1,
+        current::CellBorderLineSchema::Line2 => v1_9::CellBorderLineSchema::Line2,
+        current::CellBorderLineSchema::Line3 => v1_9::CellBorderLineSchema::Line3,
+        current::CellBorderLineSchema::Dotted => v1_9::CellBorderLineSchema::Dotted,
+        current::CellBorderLineSchema::Dashed => v1_9::CellBorderLineSchema::Dashed,
+        current::CellBorderLineSchema::Double => v1_9::CellBorderLineSchema::Double,
+        current::CellBorderLineSchema::Clear => v1_9::CellBorderLineSchema::Clear,
+    }
+}
+
+fn upgrade_border_style(
+    style: current::BorderStyleTimestampSchema,
+) -> v1_9::BorderStyleTimestampSchema {
+    v1_9::BorderStyleTimestampSchema {
+        color: style.color,
+        line: upgrade_border_line(style.line),
+        timestamp: style.timestamp,
+    }
+}
+
+fn upgrade_borders_side(side: current::BordersSideSchema) -> v1_9::BordersSideSchema {
+    side.into_iter()
+        .map(|x_block| v1_9::BlockSchema {
+            start: x_block.start,
+            end: x_block.end,
+            value: x_block
+                .value
+                .into_iter()
+                .map(|y_block| v1_9::BlockSchema {
+                    start: y_block.start,
+                    end: y_block.end,
+                    value: y_block.value.map(upgrade_border_style),
+                })
+                .collect(),
+        })
+        .collect()
+}
+
+fn upgrade_borders(borders: current::BordersSchema) -> v1_9::BordersSchema {
+    v1_9::BordersSchema {
+        left: upgrade_borders_side(borders.left),
+        right: upgrade_borders_side(borders.right),
+        top: upgrade_borders_side(borders.top),
+        bottom: upgrade_borders_side(borders.bottom),
+        diagonal_up: vec![],
+        diagonal_down: vec![],
+    }
+}
+
+fn upgrade_contiguous_2d<T, U>(
+    contiguous_2d: current::Contiguous2DSchema<T>,
+    f: impl Fn(T) -> U,
+) -> v1_9::Contiguous2DSchema<U> {
+    contiguous_2d
+        .into_iter()
+        .map(|x_block| v1_9::BlockSchema {
+            start: x_block.start,
+            end: x_block.end,
+            value: x_block
+                .value
+                .into_iter()
+                .map(|y_block| v1_9::BlockSchema {
+                    start: y_block.start,
+                    end: y_block.end,
+                    value: f(y_block.value),
+                })
+                .collect(),
+        })
+        .collect()
+}
+
+fn upgrade_numeric_format(
+    numeric_format: current::NumericFormatSchema,
+) -> v1_9::NumericFormatSchema {
+    v1_9::NumericFormatSchema {
+        kind: match numeric_format.kind {
+            current::NumericFormatKindSchema::Number => v1_9::NumericFormatKindSchema::Number,
+            current::NumericFormatKindSchema::Currency => v1_9::NumericFormatKindSchema::Currency,
+            current::NumericFormatKindSchema::Percentage => {
+                v1_9::NumericFormatKindSchema::Percentage
+            }
+            current::NumericFormatKindSchema::Exponential => {
+                v1_9::NumericFormatKindSchema::Exponential
+            }
+        },
+        symbol: numeric_format.symbol,
+    }
+}
+
+fn upgrade_formats(formats: current::SheetFormattingSchema) -> v1_9::SheetFormattingSchema {
+    v1_9::SheetFormattingSchema {
+        align: formats.align,
+        vertical_align: formats.vertical_align,
+        wrap: formats.wrap,
+        numeric_format: upgrade_contiguous_2d(formats.numeric_format, |numeric_format| {
+            numeric_format.map(upgrade_numeric_format)
+        }),
+        numeric_decimals: formats.numeric_decimals,
+        numeric_commas: formats.numeric_commas,
+        bold: formats.bold,
+        italic: formats.italic,
+        text_color: formats.text_color,
+        fill_color: formats.fill_color,
+        date_time: formats.date_time,
+        underline: formats.underline,
+        strike_through: formats.strike_through,
+        font_size: vec![],
+        font_family: vec![],
+        text_rotation: vec![],
+        indent: vec![],
+        style: vec![],
+    }
+}
+
+fn upgrade_columns(columns: current::ColumnsSchema) -> v1_9::ColumnsSchema {
+    columns
+        .into_iter()
+        .map(|(x, column)| {
+            (
+                x,
+                column
+                    .into_iter()
+                    .map(|(y, value)| (y, value.into()))
+                    .collect(),
+            )
+        })
+        .collect()
+}
+
+fn upgrade_output_value(value: current::OutputValueSchema) -> v1_9::OutputValueSchema {
+    match value {
+        current::OutputValueSchema::Single(value) => v1_9::OutputValueSchema::Single(value.into()),
+        current::OutputValueSchema::Array(array) => {
+            v1_9::OutputValueSchema::Array(v1_9::OutputArraySchema {
+                size: array.size,
+                values: array.values.into_iter().map(Into::into).collect(),
+            })
+        }
+    }
+}
+
+fn upgrade_data_table_columns(
+    columns: Vec<current::DataTableColumnSchema>,
+) -> Vec<v1_9::DataTableColumnSchema> {
+    columns
+        .into_iter()
+        .map(|column| v1_9::DataTableColumnSchema {
+            name: column.name.into(),
+            display: column.display,
+            value_index: column.value_index,
+        })
+        .collect()
+}
+
+fn upgrade_validation_list(list: current::ValidationListSchema) -> v1_9::ValidationListSchema {
+    v1_9::ValidationListSchema {
+        source: match list.source {
+            current::ValidationListSourceSchema::Selection(selection) => {
+                v1_9::ValidationListSourceSchema::Selection(selection)
+            }
+            current::ValidationListSourceSchema::List(list) => {
+                v1_9::ValidationListSourceSchema::List(list)
+            }
+        },
+        ignore_blank: list.ignore_blank,
+        drop_down: list.drop_down,
+        multi_select: false,
+    }
+}
+
+fn upgrade_validation(validation: current::ValidationSchema) -> v1_9::ValidationSchema {
+    v1_9::ValidationSchema {
+        selection: validation.selection,
+        id: validation.id,
+        rule: match validation.rule {
+            current::ValidationRuleSchema::None => v1_9::ValidationRuleSchema::None,
+            current::ValidationRuleSchema::List(list) => {
+                v1_9::ValidationRuleSchema::List(upgrade_validation_list(list))
+            }
+            current::ValidationRuleSchema::Logical(logical) => {
+                v1_9::ValidationRuleSchema::Logical(logical)
+            }
+            current::ValidationRuleSchema::Text(text) => v1_9::ValidationRuleSchema::Text(text),
+            current::ValidationRuleSchema::Number(number) => {
+                v1_9::ValidationRuleSchema::Number(number)
+            }
+            current::ValidationRuleSchema::DateTime(date_time) => {
+                v1_9::ValidationRuleSchema::DateTime(date_time)
+            }
+        },
+        message: validation.message,
+        error: validation.error,
+    }
+}
+
+fn upgrade_validations(validations: current::ValidationsSchema) -> v1_9::ValidationsSchema {
+    v1_9::ValidationsSchema {
+        validations: validations
+            .validations
+            .into_iter()
+            .map(upgrade_validation)
+            .collect(),
+        warnings: validations.warnings,
+    }
+}
+
 fn upgrade_data_tables(
     data_tables: current::DataTablesSchema,
     columns: &current::ColumnsSchema,
@@ -75,16 +283,17 @@ fn upgrade_data_tables(
                     header_is_first_row: data_table.header_is_first_row,
                     show_name,
                     show_columns,
-                    columns: data_table.columns,
+                    columns: data_table.columns.map(upgrade_data_table_columns),
                     sort: data_table.sort,
                     sort_dirty: data_table.sort_dirty,
                     display_buffer: data_table.display_buffer,
-                    value: data_table.value,
+                    value: upgrade_output_value(data_table.value),
                     spill_error: data_table.spill_error,
                     last_modified: data_table.last_modified,
                     alternating_colors: data_table.alternating_colors,
-                    formats: data_table.formats,
-                    borders: data_table.borders,
+                    table_style: None,
+                    formats: upgrade_formats(data_table.formats),
+                    borders: upgrade_borders(data_table.borders),
                     chart_pixel_output: data_table.chart_pixel_output,
                     chart_output: data_table.chart_output,
                 },
@@ -102,12 +311,22 @@ pub fn upgrade_sheet(sheet: current::SheetSchema) -> v1_9::SheetSchema {
         color: sheet.color,
         order: sheet.order,
         offsets: sheet.offsets,
-        validations: sheet.validations,
-        columns: sheet.columns,
+        validations: upgrade_validations(sheet.validations),
+        columns: upgrade_columns(sheet.columns),
         data_tables,
         rows_resize: sheet.rows_resize,
-        borders: sheet.borders,
-        formats: sheet.formats,
+        borders: upgrade_borders(sheet.borders),
+        formats: upgrade_formats(sheet.formats),
+        merge_cells: vec![],
+        hidden_columns: vec![],
+        hidden_rows: vec![],
+        column_outline: v1_9::OutlineSchema::default(),
+        row_outline: v1_9::OutlineSchema::default(),
+        frozen_columns: 0,
+        frozen_rows: 0,
+        comments: vec![],
+        conditional_formats: vec![],
+        protection: v1_9::SheetProtectionSchema::default(),
     }
 }
 
@@ -115,6 +334,12 @@ pub fn upgrade(grid: current::GridSchema) -> Result<v1_9::GridSchema> {
     let new_grid = v1_9::GridSchema {
         version: Some("1.9".to_string()),
         sheets: grid.sheets.into_iter().map(upgrade_sheet).collect(),
+        custom_lists: vec![],
+        cell_styles: vec![],
+        theme: None,
+        locale: None,
+        time_zone: None,
+        date_settings: None,
     };
     Ok(new_grid)
 }
@@ -1,3 +1,5 @@
 mod schema;
+mod sheet_formatting_schema;
 
 pub use schema::*;
+pub use sheet_formatting_schema::*;
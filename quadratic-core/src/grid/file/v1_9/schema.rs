@@ -1,23 +1,21 @@
 use crate::grid::file::v1_8;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::SheetFormattingSchema;
 
 pub type A1SelectionSchema = v1_8::A1SelectionSchema;
 pub type AxisSchema = v1_8::AxisSchema;
 pub type BlockSchema<T> = v1_8::BlockSchema<T>;
-pub type BordersSchema = v1_8::BordersSchema;
-pub type BordersSideSchema = v1_8::BordersSideSchema;
 pub type BorderStyleCell = v1_8::BorderStyleCellSchema;
 pub type BorderStyleCellSchema = v1_8::BorderStyleCellSchema;
-pub type BorderStyleTimestampSchema = v1_8::BorderStyleTimestampSchema;
 pub type CellAlignSchema = v1_8::CellAlignSchema;
-pub type CellBorderLineSchema = v1_8::CellBorderLineSchema;
 pub type CellBorderSchema = v1_8::CellBorderSchema;
 pub type CellRefCoordSchema = v1_8::CellRefCoordSchema;
 pub type CellRefRangeEndSchema = v1_8::CellRefRangeEndSchema;
 pub type CellRefRangeSchema = v1_8::CellRefRangeSchema;
 pub type CellsAccessedSchema = v1_8::CellsAccessedSchema;
-pub type CellValueSchema = v1_8::CellValueSchema;
 pub type CellVerticalAlignSchema = v1_8::CellVerticalAlignSchema;
 pub type CellWrapSchema = v1_8::CellWrapSchema;
 pub type CodeCellLanguageSchema = v1_8::CodeCellLanguageSchema;
@@ -25,23 +23,17 @@ pub type CodeCellSchema = v1_8::CodeCellSchema;
 pub type CodeRunResultSchema = v1_8::CodeRunResultSchema;
 pub type ColRangeSchema = v1_8::ColRangeSchema;
 pub type ColumnRepeatSchema<T> = v1_8::ColumnRepeatSchema<T>;
-pub type ColumnSchema = v1_8::ColumnSchema;
-pub type ColumnsSchema = v1_8::ColumnsSchema;
 pub type ConnectionKindSchema = v1_8::ConnectionKindSchema;
 pub type Contiguous2DSchema<T> = v1_8::Contiguous2DSchema<T>;
-pub type DataTableColumnSchema = v1_8::DataTableColumnSchema;
+pub type BordersSideSchema = Contiguous2DSchema<Option<BorderStyleTimestampSchema>>;
 pub type DataTableSortOrderSchema = v1_8::DataTableSortOrderSchema;
 pub type DateTimeRangeSchema = v1_8::DateTimeRangeSchema;
 pub type FormatSchema = v1_8::FormatSchema;
 pub type IdSchema = v1_8::IdSchema;
 pub type ImportSchema = v1_8::ImportSchema;
 pub type NumberRangeSchema = v1_8::NumberRangeSchema;
-pub type NumericFormatKindSchema = v1_8::NumericFormatKindSchema;
-pub type NumericFormatSchema = v1_8::NumericFormatSchema;
 pub type OffsetsSchema = v1_8::OffsetsSchema;
-pub type OutputArraySchema = v1_8::OutputArraySchema;
 pub type OutputSizeSchema = v1_8::OutputSizeSchema;
-pub type OutputValueSchema = v1_8::OutputValueSchema;
 pub type PosSchema = v1_8::PosSchema;
 pub type RectSchema = v1_8::RectSchema;
 pub type RefRangeBoundsSchema = v1_8::RefRangeBoundsSchema;
@@ -53,7 +45,6 @@ pub type RowsResizesSchema = v1_8::RowsResizesSchema;
 pub type RunErrorMsgSchema = v1_8::RunErrorMsgSchema;
 pub type RunErrorSchema = v1_8::RunErrorSchema;
 pub type SelectionSchema = v1_8::A1SelectionSchema;
-pub type SheetFormattingSchema = v1_8::SheetFormattingSchema;
 pub type SheetRectSchema = v1_8::SheetRectSchema;
 pub type SortDirectionSchema = v1_8::SortDirectionSchema;
 pub type SpanSchema = v1_8::SpanSchema;
@@ -63,16 +54,161 @@ pub type TextCaseSchema = v1_8::TextCaseSchema;
 pub type TextMatchSchema = v1_8::TextMatchSchema;
 pub type ValidationDateTimeSchema = v1_8::ValidationDateTimeSchema;
 pub type ValidationErrorSchema = v1_8::ValidationErrorSchema;
-pub type ValidationListSchema = v1_8::ValidationListSchema;
-pub type ValidationListSourceSchema = v1_8::ValidationListSourceSchema;
 pub type ValidationLogicalSchema = v1_8::ValidationLogicalSchema;
 pub type ValidationMessageSchema = v1_8::ValidationMessageSchema;
 pub type ValidationNumberSchema = v1_8::ValidationNumberSchema;
-pub type ValidationRuleSchema = v1_8::ValidationRuleSchema;
-pub type ValidationSchema = v1_8::ValidationSchema;
 pub type ValidationStyleSchema = v1_8::ValidationStyleSchema;
 pub type ValidationTextSchema = v1_8::ValidationTextSchema;
-pub type ValidationsSchema = v1_8::ValidationsSchema;
+
+pub type ColumnSchema = Vec<(i64, CellValueSchema)>;
+pub type ColumnsSchema = Vec<(i64, ColumnSchema)>;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HyperlinkSchema {
+    pub url: String,
+    pub text: String,
+
+    #[serde(default)]
+    pub value: Option<Box<CellValueSchema>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TextSpanSchema {
+    pub text: String,
+    pub bold: Option<bool>,
+    pub italic: Option<bool>,
+    pub underline: Option<bool>,
+    pub strike_through: Option<bool>,
+    pub text_color: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum CellValueSchema {
+    Blank,
+    Text(String),
+    Number(String),
+    Html(String),
+    Code(CodeCellSchema),
+    Logical(bool),
+    Instant(String),
+    Date(NaiveDate),
+    Time(NaiveTime),
+    DateTime(NaiveDateTime),
+    Duration(String),
+    Error(RunErrorSchema),
+    Image(String),
+    Import(ImportSchema),
+    Hyperlink(HyperlinkSchema),
+    RichText(Vec<TextSpanSchema>),
+    DateTimeTz(DateTime<FixedOffset>),
+}
+impl From<v1_8::CellValueSchema> for CellValueSchema {
+    fn from(value: v1_8::CellValueSchema) -> Self {
+        match value {
+            v1_8::CellValueSchema::Blank => Self::Blank,
+            v1_8::CellValueSchema::Text(s) => Self::Text(s),
+            v1_8::CellValueSchema::Number(n) => Self::Number(n),
+            v1_8::CellValueSchema::Html(h) => Self::Html(h),
+            v1_8::CellValueSchema::Code(code_cell) => Self::Code(code_cell),
+            v1_8::CellValueSchema::Logical(l) => Self::Logical(l),
+            v1_8::CellValueSchema::Instant(i) => Self::Instant(i),
+            v1_8::CellValueSchema::Date(naive_date) => Self::Date(naive_date),
+            v1_8::CellValueSchema::Time(naive_time) => Self::Time(naive_time),
+            v1_8::CellValueSchema::DateTime(naive_date_time) => Self::DateTime(naive_date_time),
+            v1_8::CellValueSchema::Duration(d) => Self::Duration(d),
+            v1_8::CellValueSchema::Error(run_error_schema) => Self::Error(run_error_schema),
+            v1_8::CellValueSchema::Image(i) => Self::Image(i),
+            v1_8::CellValueSchema::Import(import_schema) => Self::Import(import_schema),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum OutputValueSchema {
+    Single(CellValueSchema),
+    Array(OutputArraySchema),
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OutputArraySchema {
+    pub size: OutputSizeSchema,
+    pub values: Vec<CellValueSchema>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum NumericFormatKindSchema {
+    #[default]
+    Number,
+    Currency,
+    Percentage,
+    Exponential,
+    Custom,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NumericFormatSchema {
+    pub kind: NumericFormatKindSchema,
+    pub symbol: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ValidationListSchema {
+    pub source: ValidationListSourceSchema,
+    pub ignore_blank: bool,
+    pub drop_down: bool,
+
+    #[serde(default)]
+    pub multi_select: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ValidationCustomSchema {
+    pub formula: String,
+    pub ignore_blank: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum ValidationRuleSchema {
+    None,
+    List(ValidationListSchema),
+    Logical(ValidationLogicalSchema),
+    Text(ValidationTextSchema),
+    Number(ValidationNumberSchema),
+    DateTime(ValidationDateTimeSchema),
+    Custom(ValidationCustomSchema),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum ValidationListSourceSchema {
+    Selection(A1SelectionSchema),
+    List(Vec<String>),
+    Formula(String),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ValidationSchema {
+    pub selection: A1SelectionSchema,
+    pub id: Uuid,
+    pub rule: ValidationRuleSchema,
+    pub message: ValidationMessageSchema,
+    pub error: ValidationErrorSchema,
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq)]
+pub struct ValidationsSchema {
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub validations: Vec<ValidationSchema>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub warnings: Vec<(PosSchema, Uuid)>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DataTableColumnSchema {
+    pub name: CellValueSchema,
+    pub display: bool,
+    pub value_index: u32,
+}
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CodeRunSchema {
@@ -109,14 +245,209 @@ pub struct DataTableSchema {
     pub spill_error: bool,
     pub last_modified: Option<DateTime<Utc>>,
     pub alternating_colors: bool,
+    #[serde(default)]
+    pub table_style: Option<TableStyleSchema>,
     pub formats: SheetFormattingSchema,
     pub borders: BordersSchema,
     pub chart_pixel_output: Option<(f32, f32)>,
     pub chart_output: Option<(u32, u32)>,
 }
 
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TableStyleElementSchema {
+    pub fill_color: Option<String>,
+    pub text_color: Option<String>,
+    pub bold: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TableStyleSchema {
+    pub name: String,
+    pub whole_table: TableStyleElementSchema,
+    pub header_row: TableStyleElementSchema,
+    pub row_band: TableStyleElementSchema,
+    pub column_band: TableStyleElementSchema,
+    pub first_column: TableStyleElementSchema,
+    pub last_column: TableStyleElementSchema,
+    pub totals_row: TableStyleElementSchema,
+    pub banded_rows: bool,
+    pub banded_columns: bool,
+    pub show_first_column: bool,
+    pub show_last_column: bool,
+    pub show_totals_row: bool,
+}
+
 pub type DataTablesSchema = Vec<(PosSchema, DataTableSchema)>;
 
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OutlineSchema {
+    pub levels: Vec<(i64, u8)>,
+    pub collapsed: Vec<(i64, u8)>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CommentSchema {
+    pub id: Uuid,
+    pub author: String,
+    pub created: DateTime<Utc>,
+    pub text: String,
+    pub mentions: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CommentThreadSchema {
+    pub comments: Vec<CommentSchema>,
+    pub resolved: bool,
+}
+
+pub type CommentsSchema = Vec<(PosSchema, CommentThreadSchema)>;
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConditionalFormatStyleSchema {
+    pub fill_color: Option<String>,
+    pub text_color: Option<String>,
+    pub bold: Option<bool>,
+    pub italic: Option<bool>,
+    pub underline: Option<bool>,
+    pub strike_through: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum CompareOperatorSchema {
+    Equal,
+    NotEqual,
+    GreaterThan,
+    GreaterThanOrEqual,
+    LessThan,
+    LessThanOrEqual,
+    Between,
+    NotBetween,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum TextOperatorSchema {
+    Contains,
+    NotContains,
+    BeginsWith,
+    EndsWith,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum DateOccurringSchema {
+    Yesterday,
+    Today,
+    Tomorrow,
+    Last7Days,
+    LastWeek,
+    ThisWeek,
+    NextWeek,
+    LastMonth,
+    ThisMonth,
+    NextMonth,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ConditionalFormatConditionSchema {
+    Compare {
+        operator: CompareOperatorSchema,
+        values: Vec<String>,
+    },
+    Text {
+        operator: TextOperatorSchema,
+        text: String,
+    },
+    DateOccurring(DateOccurringSchema),
+    Duplicate,
+    Unique,
+    TopBottom {
+        top: bool,
+        rank: u32,
+        percent: bool,
+    },
+    Average {
+        above: bool,
+        or_equal: bool,
+    },
+    Formula(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ScaleValueSchema {
+    Min,
+    Max,
+    Number(f64),
+    Percent(f64),
+    Percentile(f64),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ColorScalePointSchema {
+    pub value: ScaleValueSchema,
+    pub color: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DataBarSchema {
+    pub min: ScaleValueSchema,
+    pub max: ScaleValueSchema,
+    pub color: String,
+    pub show_value: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum IconSetKindSchema {
+    Arrows3,
+    TrafficLights3,
+    Symbols3,
+    Flags3,
+    Arrows4,
+    Rating4,
+    Arrows5,
+    Rating5,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IconSetSchema {
+    pub kind: IconSetKindSchema,
+    pub thresholds: Vec<ScaleValueSchema>,
+    pub reverse: bool,
+    pub show_value: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ConditionalFormatRuleSchema {
+    Highlight {
+        condition: ConditionalFormatConditionSchema,
+        style: ConditionalFormatStyleSchema,
+    },
+    ColorScale(Vec<ColorScalePointSchema>),
+    DataBar(DataBarSchema),
+    IconSet(IconSetSchema),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConditionalFormatSchema {
+    pub id: Uuid,
+    pub selection: A1SelectionSchema,
+    pub rule: ConditionalFormatRuleSchema,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProtectedRangeSchema {
+    pub id: Uuid,
+    pub selection: A1SelectionSchema,
+    pub description: Option<String>,
+    pub editors: Vec<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SheetProtectionSchema {
+    pub protected: bool,
+    pub editors: Vec<String>,
+    pub editable_ranges: Vec<A1SelectionSchema>,
+    pub ranges: Vec<ProtectedRangeSchema>,
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SheetSchema {
     pub id: IdSchema,
@@ -130,10 +461,185 @@ pub struct SheetSchema {
     pub rows_resize: RowsResizesSchema,
     pub borders: BordersSchema,
     pub formats: SheetFormattingSchema,
+
+    #[serde(default)]
+    pub merge_cells: Vec<RectSchema>,
+
+    #[serde(default)]
+    pub hidden_columns: Vec<i64>,
+
+    #[serde(default)]
+    pub hidden_rows: Vec<i64>,
+
+    #[serde(default)]
+    pub column_outline: OutlineSchema,
+
+    #[serde(default)]
+    pub row_outline: OutlineSchema,
+
+    #[serde(default)]
+    pub frozen_columns: u32,
+
+    #[serde(default)]
+    pub frozen_rows: u32,
+
+    #[serde(default)]
+    pub comments: CommentsSchema,
+
+    #[serde(default)]
+    pub conditional_formats: Vec<ConditionalFormatSchema>,
+
+    #[serde(default)]
+    pub protection: SheetProtectionSchema,
 }
 
 #[derive(Default, Debug, PartialEq, Serialize, Deserialize, Clone)]
 pub struct GridSchema {
     pub sheets: Vec<SheetSchema>,
     pub version: Option<String>,
+
+    #[serde(default)]
+    pub custom_lists: Vec<Vec<String>>,
+
+    #[serde(default)]
+    pub cell_styles: Vec<CellStyleSchema>,
+
+    #[serde(default)]
+    pub theme: Option<ThemeSchema>,
+
+    #[serde(default)]
+    pub locale: Option<LocaleSchema>,
+
+    /// IANA time zone name (eg, `America/New_York`).
+    #[serde(default)]
+    pub time_zone: Option<String>,
+
+    #[serde(default)]
+    pub date_settings: Option<DateSettingsSchema>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CellStyleFormatSchema {
+    pub align: Option<CellAlignSchema>,
+    pub vertical_align: Option<CellVerticalAlignSchema>,
+    pub wrap: Option<CellWrapSchema>,
+    pub numeric_format: Option<NumericFormatSchema>,
+    pub numeric_decimals: Option<i16>,
+    pub numeric_commas: Option<bool>,
+    pub bold: Option<bool>,
+    pub italic: Option<bool>,
+    pub text_color: Option<String>,
+    pub fill_color: Option<String>,
+    pub date_time: Option<String>,
+    pub underline: Option<bool>,
+    pub strike_through: Option<bool>,
+    pub font_size: Option<i16>,
+    pub font_family: Option<String>,
+    pub text_rotation: Option<i16>,
+    pub indent: Option<u8>,
+}
+
+#[derive(Default, Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum CellBorderLineSchema {
+    #[default]
+    Line1,
+    Line2,
+    Line3,
+    Dotted,
+    Dashed,
+    Double,
+    Hair,
+    MediumDashed,
+    DashDot,
+    MediumDashDot,
+    DashDotDot,
+    MediumDashDotDot,
+    SlantDashDot,
+    Clear,
+}
+
+#[derive(Default, Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct BorderStyleTimestampSchema {
+    pub color: RgbaSchema,
+    pub line: CellBorderLineSchema,
+    pub timestamp: u32,
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq)]
+pub struct BordersSchema {
+    pub left: BordersSideSchema,
+    pub right: BordersSideSchema,
+    pub top: BordersSideSchema,
+    pub bottom: BordersSideSchema,
+
+    #[serde(default)]
+    pub diagonal_up: BordersSideSchema,
+    #[serde(default)]
+    pub diagonal_down: BordersSideSchema,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CellStyleBorderSchema {
+    pub color: String,
+    pub line: CellBorderLineSchema,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CellStyleBordersSchema {
+    pub top: Option<CellStyleBorderSchema>,
+    pub bottom: Option<CellStyleBorderSchema>,
+    pub left: Option<CellStyleBorderSchema>,
+    pub right: Option<CellStyleBorderSchema>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CellStyleSchema {
+    pub name: String,
+    pub format: CellStyleFormatSchema,
+
+    #[serde(default)]
+    pub borders: CellStyleBordersSchema,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ThemeColorSchema {
+    pub name: String,
+    pub color: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ThemeSchema {
+    pub colors: Vec<ThemeColorSchema>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum DateOrderSchema {
+    Mdy,
+    Dmy,
+    Ymd,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LocaleSchema {
+    pub name: String,
+    pub date_order: DateOrderSchema,
+    pub date_format: String,
+    pub time_format: String,
+    pub decimal_separator: char,
+    pub group_separator: char,
+    pub currency_symbol: String,
+    pub first_day_of_week: u8,
+    pub argument_separator: char,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum DateSystemSchema {
+    Excel1900,
+    Excel1904,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DateSettingsSchema {
+    pub date_system: DateSystemSchema,
+    pub serial_dates: bool,
 }
@@ -0,0 +1,219 @@
+//! Named cell styles and the workbook theme.
+//!
+//! A cell style bundles format attributes and borders under a name (eg,
+//! "Input" or "Currency Total"). Applying a style writes its attributes to
+//! the cells along with the style's name, so that changing the style's
+//! definition can update every cell that references it.
+//!
+//! Colors in a style may reference a color of the workbook theme (eg,
+//! `theme:accent1`). These are resolved when the style is applied, and
+//! reapplied when the theme changes.
+
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use super::{Format, FormatUpdate};
+use crate::color::Rgba;
+use crate::grid::sheet::borders::{BorderStyle, CellBorderLine};
+
+/// Prefix for a color that references a theme color.
+pub const THEME_COLOR_PREFIX: &str = "theme:";
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, TS)]
+pub struct ThemeColor {
+    pub name: String,
+    pub color: String,
+}
+
+/// The workbook's palette of named colors.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, TS)]
+pub struct Theme {
+    pub colors: Vec<ThemeColor>,
+}
+
+impl Default for Theme {
+    /// The Office theme palette, which is also Excel's default.
+    fn default() -> Self {
+        let colors = [
+            ("dark1", "#000000"),
+            ("light1", "#ffffff"),
+            ("dark2", "#44546a"),
+            ("light2", "#e7e6e6"),
+            ("accent1", "#4472c4"),
+            ("accent2", "#ed7d31"),
+            ("accent3", "#a5a5a5"),
+            ("accent4", "#ffc000"),
+            ("accent5", "#5b9bd5"),
+            ("accent6", "#70ad47"),
+            ("hyperlink", "#0563c1"),
+        ];
+        Self {
+            colors: colors
+                .into_iter()
+                .map(|(name, color)| ThemeColor {
+                    name: name.to_string(),
+                    color: color.to_string(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl Theme {
+    /// Returns the color with the given name.
+    pub fn color(&self, name: &str) -> Option<&str> {
+        self.colors
+            .iter()
+            .find(|color| color.name.eq_ignore_ascii_case(name))
+            .map(|color| color.color.as_str())
+    }
+
+    /// Resolves a color that may reference a theme color. Returns None if the
+    /// theme color does not exist.
+    pub fn resolve(&self, color: &str) -> Option<String> {
+        match color.strip_prefix(THEME_COLOR_PREFIX) {
+            Some(name) => self.color(name).map(String::from),
+            None => Some(color.to_string()),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, TS)]
+pub struct CellStyleBorder {
+    /// Css color, or a theme color (eg, `theme:accent1`).
+    pub color: String,
+    pub line: CellBorderLine,
+}
+
+impl CellStyleBorder {
+    fn resolve(&self, theme: &Theme) -> BorderStyle {
+        let color = theme.resolve(&self.color).unwrap_or_default();
+        let color = if color.starts_with('#') && color.len() >= 7 {
+            Rgba::color_from_str(&color).ok()
+        } else {
+            Rgba::from_css_str(&color).ok()
+        };
+        BorderStyle {
+            color: color.unwrap_or_default(),
+            line: self.line,
+        }
+    }
+}
+
+#[derive(Default, Serialize, Deserialize, Debug, Clone, PartialEq, Eq, TS)]
+pub struct CellStyleBorders {
+    pub top: Option<CellStyleBorder>,
+    pub bottom: Option<CellStyleBorder>,
+    pub left: Option<CellStyleBorder>,
+    pub right: Option<CellStyleBorder>,
+}
+
+/// Borders of a style with the theme colors resolved.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResolvedCellStyleBorders {
+    pub top: Option<BorderStyle>,
+    pub bottom: Option<BorderStyle>,
+    pub left: Option<BorderStyle>,
+    pub right: Option<BorderStyle>,
+}
+
+impl ResolvedCellStyleBorders {
+    pub fn is_empty(&self) -> bool {
+        self.top.is_none() && self.bottom.is_none() && self.left.is_none() && self.right.is_none()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, TS)]
+pub struct CellStyle {
+    pub name: String,
+
+    /// Attributes that the style sets. Colors may reference the theme.
+    pub format: Format,
+
+    #[serde(default)]
+    pub borders: CellStyleBorders,
+}
+
+impl CellStyle {
+    /// Returns the format that is written to cells with this style.
+    pub fn resolved_format(&self, theme: &Theme) -> Format {
+        let resolve = |color: &Option<String>| color.as_ref().and_then(|c| theme.resolve(c));
+        Format {
+            text_color: resolve(&self.format.text_color),
+            fill_color: resolve(&self.format.fill_color),
+            style: Some(self.name.clone()),
+            ..self.format.clone()
+        }
+    }
+
+    /// Returns the update that applies the style to cells.
+    pub fn apply_update(&self, theme: &Theme) -> FormatUpdate {
+        self.resolved_format(theme).into()
+    }
+
+    pub fn resolved_borders(&self, theme: &Theme) -> ResolvedCellStyleBorders {
+        let resolve =
+            |border: &Option<CellStyleBorder>| border.as_ref().map(|border| border.resolve(theme));
+        ResolvedCellStyleBorders {
+            top: resolve(&self.borders.top),
+            bottom: resolve(&self.borders.bottom),
+            left: resolve(&self.borders.left),
+            right: resolve(&self.borders.right),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_theme_resolve() {
+        let theme = Theme::default();
+        assert_eq!(theme.resolve("theme:accent1"), Some("#4472c4".to_string()));
+        assert_eq!(theme.resolve("theme:Accent1"), Some("#4472c4".to_string()));
+        assert_eq!(theme.resolve("#123456"), Some("#123456".to_string()));
+        assert_eq!(theme.resolve("theme:missing"), None);
+    }
+
+    #[test]
+    fn test_cell_style_resolve() {
+        let style = CellStyle {
+            name: "Header".to_string(),
+            format: Format {
+                bold: Some(true),
+                fill_color: Some("theme:accent1".to_string()),
+                text_color: Some("#ffffff".to_string()),
+                ..Default::default()
+            },
+            borders: CellStyleBorders {
+                bottom: Some(CellStyleBorder {
+                    color: "theme:dark1".to_string(),
+                    line: CellBorderLine::Line2,
+                }),
+                ..Default::default()
+            },
+        };
+        let theme = Theme::default();
+        assert_eq!(
+            style.resolved_format(&theme),
+            Format {
+                bold: Some(true),
+                fill_color: Some("#4472c4".to_string()),
+                text_color: Some("#ffffff".to_string()),
+                style: Some("Header".to_string()),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            style.resolved_borders(&theme),
+            ResolvedCellStyleBorders {
+                bottom: Some(BorderStyle {
+                    color: Rgba::new(0, 0, 0, 255),
+                    line: CellBorderLine::Line2,
+                }),
+                ..Default::default()
+            }
+        );
+    }
+}
@@ -23,6 +23,22 @@ pub struct Format {
     pub date_time: Option<String>,
     pub underline: Option<bool>,
     pub strike_through: Option<bool>,
+
+    /// Font size in points.
+    pub font_size: Option<i16>,
+
+    pub font_family: Option<String>,
+
+    /// Counterclockwise rotation in degrees (-90 to 90), or `VERTICAL_TEXT`
+    /// for letters stacked vertically.
+    pub text_rotation: Option<i16>,
+
+    /// Indent level (each level is the width of a few characters).
+    pub indent: Option<u8>,
+
+    /// Name of the named cell style applied to the cell. The style's
+    /// attributes are stored in the other fields.
+    pub style: Option<String>,
 }
 
 impl Format {
@@ -40,6 +56,11 @@ impl Format {
             && self.date_time.is_none()
             && self.underline.is_none()
             && self.strike_through.is_none()
+            && self.font_size.is_none()
+            && self.font_family.is_none()
+            && self.text_rotation.is_none()
+            && self.indent.is_none()
+            && self.style.is_none()
     }
 
     pub fn is_table_default(&self) -> bool {
@@ -56,6 +77,11 @@ impl Format {
             && self.date_time.is_none()
             && self.underline.is_none()
             && self.strike_through.is_none()
+            && self.font_size.is_none()
+            && self.font_family.is_none()
+            && self.text_rotation.is_none()
+            && self.indent.is_none()
+            && self.style.is_none()
     }
 
     /// Clears all formatting.
@@ -73,6 +99,11 @@ impl Format {
         self.date_time = None;
         self.underline = None;
         self.strike_through = None;
+        self.font_size = None;
+        self.font_family = None;
+        self.text_rotation = None;
+        self.indent = None;
+        self.style = None;
     }
 
     /// Combines two formats. The first takes precedence over the second.
@@ -91,6 +122,11 @@ impl Format {
             date_time: self.date_time.clone().or(other.date_time.clone()),
             underline: self.underline.or(other.underline),
             strike_through: self.strike_through.or(other.strike_through),
+            font_size: self.font_size.or(other.font_size),
+            font_family: self.font_family.clone().or(other.font_family.clone()),
+            text_rotation: self.text_rotation.or(other.text_rotation),
+            indent: self.indent.or(other.indent),
+            style: self.style.clone().or(other.style.clone()),
         }
     }
 
@@ -117,6 +153,11 @@ impl Format {
             date_time: replace_opt(&mut self.date_time, &update.date_time),
             underline: replace_opt(&mut self.underline, &update.underline),
             strike_through: replace_opt(&mut self.strike_through, &update.strike_through),
+            font_size: replace_opt(&mut self.font_size, &update.font_size),
+            font_family: replace_opt(&mut self.font_family, &update.font_family),
+            text_rotation: replace_opt(&mut self.text_rotation, &update.text_rotation),
+            indent: replace_opt(&mut self.indent, &update.indent),
+            style: replace_opt(&mut self.style, &update.style),
             render_size: None,
         }
     }
@@ -190,6 +231,21 @@ impl Format {
         if self.strike_through.is_some() && update.strike_through.is_some() {
             old.strike_through = Some(None);
         }
+        if self.font_size.is_some() && update.font_size.is_some() {
+            old.font_size = Some(None);
+        }
+        if self.font_family.is_some() && update.font_family.is_some() {
+            old.font_family = Some(None);
+        }
+        if self.text_rotation.is_some() && update.text_rotation.is_some() {
+            old.text_rotation = Some(None);
+        }
+        if self.indent.is_some() && update.indent.is_some() {
+            old.indent = Some(None);
+        }
+        if self.style.is_some() && update.style.is_some() {
+            old.style = Some(None);
+        }
         if old.is_default() { None } else { Some(old) }
     }
 
@@ -210,6 +266,41 @@ impl Format {
             date_time: Some(self.date_time.clone()),
             underline: Some(self.underline),
             strike_through: Some(self.strike_through),
+            font_size: Some(self.font_size),
+            font_family: Some(self.font_family.clone()),
+            text_rotation: Some(self.text_rotation),
+            indent: Some(self.indent),
+            style: Some(self.style.clone()),
+            render_size: None,
+        }
+    }
+
+    /// Returns a FormatUpdate that changes `old` into this format. Only the
+    /// fields that differ are included.
+    pub fn changed_from(&self, old: &Format) -> FormatUpdate {
+        fn changed<T: Clone + PartialEq>(new: &Option<T>, old: &Option<T>) -> Option<Option<T>> {
+            (new != old).then(|| new.clone())
+        }
+
+        FormatUpdate {
+            align: changed(&self.align, &old.align),
+            vertical_align: changed(&self.vertical_align, &old.vertical_align),
+            wrap: changed(&self.wrap, &old.wrap),
+            numeric_format: changed(&self.numeric_format, &old.numeric_format),
+            numeric_decimals: changed(&self.numeric_decimals, &old.numeric_decimals),
+            numeric_commas: changed(&self.numeric_commas, &old.numeric_commas),
+            bold: changed(&self.bold, &old.bold),
+            italic: changed(&self.italic, &old.italic),
+            text_color: changed(&self.text_color, &old.text_color),
+            fill_color: changed(&self.fill_color, &old.fill_color),
+            date_time: changed(&self.date_time, &old.date_time),
+            underline: changed(&self.underline, &old.underline),
+            strike_through: changed(&self.strike_through, &old.strike_through),
+            font_size: changed(&self.font_size, &old.font_size),
+            font_family: changed(&self.font_family, &old.font_family),
+            text_rotation: changed(&self.text_rotation, &old.text_rotation),
+            indent: changed(&self.indent, &old.indent),
+            style: changed(&self.style, &old.style),
             render_size: None,
         }
     }
@@ -254,6 +345,21 @@ impl Display for Format {
         if let Some(strike_through) = self.strike_through {
             s.push_str(&format!("strike_through: {:?}, ", strike_through));
         }
+        if let Some(font_size) = self.font_size {
+            s.push_str(&format!("font_size: {:?}, ", font_size));
+        }
+        if let Some(font_family) = &self.font_family {
+            s.push_str(&format!("font_family: {:?}, ", font_family));
+        }
+        if let Some(text_rotation) = self.text_rotation {
+            s.push_str(&format!("text_rotation: {:?}, ", text_rotation));
+        }
+        if let Some(indent) = self.indent {
+            s.push_str(&format!("indent: {:?}, ", indent));
+        }
+        if let Some(style) = &self.style {
+            s.push_str(&format!("style: {:?}, ", style));
+        }
         write!(f, "{}", s)
     }
 }
@@ -276,6 +382,11 @@ impl From<&Format> for FormatUpdate {
             date_time: format.date_time.clone().map(Some),
             underline: format.underline.map(Some),
             strike_through: format.strike_through.map(Some),
+            font_size: format.font_size.map(Some),
+            font_family: format.font_family.clone().map(Some),
+            text_rotation: format.text_rotation.map(Some),
+            indent: format.indent.map(Some),
+            style: format.style.clone().map(Some),
         }
     }
 }
@@ -298,6 +409,11 @@ impl From<Format> for FormatUpdate {
             date_time: format.date_time.clone().map(Some),
             underline: format.underline.map(Some),
             strike_through: format.strike_through.map(Some),
+            font_size: format.font_size.map(Some),
+            font_family: format.font_family.clone().map(Some),
+            text_rotation: format.text_rotation.map(Some),
+            indent: format.indent.map(Some),
+            style: format.style.clone().map(Some),
         }
     }
 }
@@ -332,6 +448,11 @@ mod test {
             date_time: Some("%H".to_string()),
             underline: Some(true),
             strike_through: Some(true),
+            font_size: Some(12),
+            font_family: Some("Arial".to_string()),
+            text_rotation: Some(45),
+            indent: Some(1),
+            style: Some("Input".to_string()),
         };
 
         format.clear();
@@ -349,6 +470,11 @@ mod test {
         assert_eq!(format.date_time, None);
         assert_eq!(format.underline, None);
         assert_eq!(format.strike_through, None);
+        assert_eq!(format.font_size, None);
+        assert_eq!(format.font_family, None);
+        assert_eq!(format.text_rotation, None);
+        assert_eq!(format.indent, None);
+        assert_eq!(format.style, None);
     }
 
     #[test]
@@ -370,6 +496,11 @@ mod test {
             date_time: Some("%H".to_string()),
             underline: Some(true),
             strike_through: Some(true),
+            font_size: Some(12),
+            font_family: Some("Arial".to_string()),
+            text_rotation: Some(45),
+            indent: Some(1),
+            style: Some("Input".to_string()),
         };
 
         let update = FormatUpdate {
@@ -390,6 +521,11 @@ mod test {
             date_time: Some(Some("%M".to_string())),
             underline: Some(Some(true)),
             strike_through: Some(Some(true)),
+            font_size: Some(Some(12)),
+            font_family: Some(Some("Arial".to_string())),
+            text_rotation: Some(Some(45)),
+            indent: Some(Some(1)),
+            style: Some(Some("Input".to_string())),
         };
 
         let clear_update = format
@@ -412,6 +548,11 @@ mod test {
                 date_time: Some(None),
                 underline: Some(None),
                 strike_through: Some(None),
+                font_size: Some(None),
+                font_family: Some(None),
+                text_rotation: Some(None),
+                indent: Some(None),
+                style: Some(None),
             }
         );
     }
@@ -437,6 +578,11 @@ mod test {
             date_time: Some(Some("%H".to_string())),
             underline: Some(Some(true)),
             strike_through: Some(Some(true)),
+            font_size: Some(Some(12)),
+            font_family: Some(Some("Arial".to_string())),
+            text_rotation: Some(Some(45)),
+            indent: Some(Some(1)),
+            style: Some(Some("Input".to_string())),
         };
 
         let old = format.apply_update(&update);
@@ -485,6 +631,11 @@ mod test {
             date_time: Some("%H".to_string()),
             underline: Some(true),
             strike_through: Some(true),
+            font_size: Some(12),
+            font_family: Some("Arial".to_string()),
+            text_rotation: Some(45),
+            indent: Some(1),
+            style: Some("Input".to_string()),
         };
 
         let update: FormatUpdate = (&format).into();
@@ -529,6 +680,11 @@ mod test {
             date_time: Some("%H".to_string()),
             underline: Some(true),
             strike_through: Some(true),
+            font_size: Some(12),
+            font_family: Some("Arial".to_string()),
+            text_rotation: Some(45),
+            indent: Some(1),
+            style: Some("Input".to_string()),
         };
 
         let update: FormatUpdate = format.into();
@@ -574,6 +730,11 @@ mod test {
                 date_time: Some(None),
                 underline: Some(None),
                 strike_through: Some(None),
+                font_size: Some(None),
+                font_family: Some(None),
+                text_rotation: Some(None),
+                indent: Some(None),
+                style: Some(None),
             }
         );
     }
@@ -600,4 +761,29 @@ mod test {
             }
         );
     }
+
+    #[test]
+    fn test_changed_from() {
+        let old = Format {
+            bold: Some(true),
+            fill_color: Some("#ff0000".to_string()),
+            style: Some("Input".to_string()),
+            ..Default::default()
+        };
+        let new = Format {
+            bold: Some(true),
+            italic: Some(true),
+            style: Some("Input".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            new.changed_from(&old),
+            FormatUpdate {
+                italic: Some(Some(true)),
+                fill_color: Some(None),
+                ..Default::default()
+            }
+        );
+        assert!(new.changed_from(&new).is_default());
+    }
 }
@@ -107,6 +107,43 @@ pub struct FormatUpdate {
     )]
     #[ts(as = "Option<Option<bool>>")]
     pub strike_through: Option<Option<bool>>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "::serde_with::rust::double_option"
+    )]
+    #[ts(as = "Option<Option<i16>>")]
+    pub font_size: Option<Option<i16>>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "::serde_with::rust::double_option"
+    )]
+    #[ts(as = "Option<Option<String>>")]
+    pub font_family: Option<Option<String>>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "::serde_with::rust::double_option"
+    )]
+    #[ts(as = "Option<Option<i16>>")]
+    pub text_rotation: Option<Option<i16>>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "::serde_with::rust::double_option"
+    )]
+    #[ts(as = "Option<Option<u8>>")]
+    pub indent: Option<Option<u8>>,
+
+    /// Name of the named cell style.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "::serde_with::rust::double_option"
+    )]
+    #[ts(as = "Option<Option<String>>")]
+    pub style: Option<Option<String>>,
 }
 
 impl FormatUpdate {
@@ -128,6 +165,11 @@ impl FormatUpdate {
             date_time: Some(None),
             underline: Some(None),
             strike_through: Some(None),
+            font_size: Some(None),
+            font_family: Some(None),
+            text_rotation: Some(None),
+            indent: Some(None),
+            style: Some(None),
         }
     }
 
@@ -149,6 +191,11 @@ impl FormatUpdate {
             date_time: self.date_time.clone().filter(|a| a.is_none()),
             underline: self.underline.filter(|a| a.is_none()),
             strike_through: self.strike_through.filter(|a| a.is_none()),
+            font_size: self.font_size.filter(|a| a.is_none()),
+            font_family: self.font_family.clone().filter(|a| a.is_none()),
+            text_rotation: self.text_rotation.filter(|a| a.is_none()),
+            indent: self.indent.filter(|a| a.is_none()),
+            style: self.style.clone().filter(|a| a.is_none()),
         };
         if update.is_default() {
             None
@@ -172,6 +219,11 @@ impl FormatUpdate {
             && self.date_time.is_none()
             && self.underline.is_none()
             && self.strike_through.is_none()
+            && self.font_size.is_none()
+            && self.font_family.is_none()
+            && self.text_rotation.is_none()
+            && self.indent.is_none()
+            && self.style.is_none()
     }
 
     /// Whether we need to send a client html update.
@@ -193,6 +245,10 @@ impl FormatUpdate {
             || self.date_time.is_some()
             || self.underline.is_some()
             || self.strike_through.is_some()
+            || self.font_size.is_some()
+            || self.font_family.is_some()
+            || self.text_rotation.is_some()
+            || self.indent.is_some()
     }
 
     pub fn fill_changed(&self) -> bool {
@@ -206,6 +262,10 @@ impl FormatUpdate {
             || self.bold.is_some()
             || self.italic.is_some()
             || self.date_time.is_some()
+            || self.font_size.is_some()
+            || self.font_family.is_some()
+            || self.text_rotation.is_some()
+            || self.indent.is_some()
     }
 
     pub fn combine(&self, other: &FormatUpdate) -> FormatUpdate {
@@ -224,6 +284,11 @@ impl FormatUpdate {
             date_time: self.date_time.clone().or(other.date_time.clone()),
             underline: self.underline.or(other.underline),
             strike_through: self.strike_through.or(other.strike_through),
+            font_size: self.font_size.or(other.font_size),
+            font_family: self.font_family.clone().or(other.font_family.clone()),
+            text_rotation: self.text_rotation.or(other.text_rotation),
+            indent: self.indent.or(other.indent),
+            style: self.style.clone().or(other.style.clone()),
         }
     }
 
@@ -272,6 +337,21 @@ impl FormatUpdate {
         if self.strike_through.is_some() {
             clear.strike_through = Some(None);
         }
+        if self.font_size.is_some() {
+            clear.font_size = Some(None);
+        }
+        if self.font_family.is_some() {
+            clear.font_family = Some(None);
+        }
+        if self.text_rotation.is_some() {
+            clear.text_rotation = Some(None);
+        }
+        if self.indent.is_some() {
+            clear.indent = Some(None);
+        }
+        if self.style.is_some() {
+            clear.style = Some(None);
+        }
         clear
     }
 }
@@ -293,6 +373,11 @@ impl From<&FormatUpdate> for Format {
             date_time: update.date_time.clone().unwrap_or(None),
             underline: update.underline.unwrap_or(None),
             strike_through: update.strike_through.unwrap_or(None),
+            font_size: update.font_size.unwrap_or(None),
+            font_family: update.font_family.clone().unwrap_or(None),
+            text_rotation: update.text_rotation.unwrap_or(None),
+            indent: update.indent.unwrap_or(None),
+            style: update.style.clone().unwrap_or(None),
         }
     }
 }
@@ -333,6 +418,11 @@ mod tests {
                 date_time: Some(None),
                 underline: Some(None),
                 strike_through: Some(None),
+                font_size: Some(None),
+                font_family: Some(None),
+                text_rotation: Some(None),
+                indent: Some(None),
+                style: Some(None),
             }
         );
     }
@@ -465,6 +555,11 @@ mod tests {
             date_time: Some(Some("%H".to_string())),
             underline: Some(Some(true)),
             strike_through: Some(Some(true)),
+            font_size: Some(Some(12)),
+            font_family: Some(Some("Arial".to_string())),
+            text_rotation: Some(Some(45)),
+            indent: Some(Some(1)),
+            style: Some(Some("Input".to_string())),
         };
 
         let format2 = FormatUpdate {
@@ -485,6 +580,11 @@ mod tests {
             date_time: Some(Some("%M".to_string())),
             underline: Some(Some(false)),
             strike_through: Some(Some(false)),
+            font_size: Some(Some(14)),
+            font_family: Some(Some("Georgia".to_string())),
+            text_rotation: Some(Some(-30)),
+            indent: Some(Some(2)),
+            style: Some(Some("Header".to_string())),
         };
 
         let combined = format1.combine(&format2);
@@ -534,6 +634,11 @@ mod tests {
             date_time: Some(Some("%H".to_string())),
             underline: Some(Some(true)),
             strike_through: Some(Some(true)),
+            font_size: Some(Some(12)),
+            font_family: Some(Some("Arial".to_string())),
+            text_rotation: Some(Some(45)),
+            indent: Some(Some(1)),
+            style: Some(Some("Input".to_string())),
         };
 
         let cleared = format.clear_update();
@@ -552,6 +657,11 @@ mod tests {
         assert_eq!(cleared.date_time, Some(None));
         assert_eq!(cleared.underline, Some(None));
         assert_eq!(cleared.strike_through, Some(None));
+        assert_eq!(cleared.font_size, Some(None));
+        assert_eq!(cleared.font_family, Some(None));
+        assert_eq!(cleared.text_rotation, Some(None));
+        assert_eq!(cleared.indent, Some(None));
+        assert_eq!(cleared.style, Some(None));
     }
 
     #[test]
@@ -574,6 +684,11 @@ mod tests {
             date_time: Some(Some("%H".to_string())),
             underline: Some(Some(true)),
             strike_through: Some(Some(true)),
+            font_size: Some(Some(12)),
+            font_family: Some(Some("Arial".to_string())),
+            text_rotation: Some(Some(45)),
+            indent: Some(Some(1)),
+            style: Some(Some("Input".to_string())),
         };
 
         let format: Format = (&update).into();
@@ -4,6 +4,7 @@
 //!
 //! Formats is used to store multiple formats for use in Operations.
 
+mod cell_style;
 mod format;
 mod format_update;
 mod sheet_format_updates;
@@ -12,6 +13,10 @@ use crate::RunLengthEncoding;
 use serde::{Deserialize, Serialize};
 use std::ops::{Deref, DerefMut};
 
+pub use cell_style::{
+    CellStyle, CellStyleBorder, CellStyleBorders, ResolvedCellStyleBorders, THEME_COLOR_PREFIX,
+    Theme, ThemeColor,
+};
 pub use format::Format;
 pub use format_update::FormatUpdate;
 pub use sheet_format_updates::{SheetFormatUpdates, SheetFormatUpdatesType};
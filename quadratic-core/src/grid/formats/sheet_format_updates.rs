@@ -29,6 +29,11 @@ pub struct SheetFormatUpdates {
     pub date_time: SheetFormatUpdatesType<String>,
     pub underline: SheetFormatUpdatesType<bool>,
     pub strike_through: SheetFormatUpdatesType<bool>,
+    pub font_size: SheetFormatUpdatesType<i16>,
+    pub font_family: SheetFormatUpdatesType<String>,
+    pub text_rotation: SheetFormatUpdatesType<i16>,
+    pub indent: SheetFormatUpdatesType<u8>,
+    pub style: SheetFormatUpdatesType<String>,
 }
 
 impl SheetFormatUpdates {
@@ -63,6 +68,11 @@ impl SheetFormatUpdates {
             date_time: Self::apply_selection(selection, update.date_time),
             underline: Self::apply_selection(selection, update.underline),
             strike_through: Self::apply_selection(selection, update.strike_through),
+            font_size: Self::apply_selection(selection, update.font_size),
+            font_family: Self::apply_selection(selection, update.font_family),
+            text_rotation: Self::apply_selection(selection, update.text_rotation),
+            indent: Self::apply_selection(selection, update.indent),
+            style: Self::apply_selection(selection, update.style),
         }
     }
 
@@ -85,6 +95,11 @@ impl SheetFormatUpdates {
             date_time: Some(formats.date_time.get_update_for_selection(selection)),
             underline: Some(formats.underline.get_update_for_selection(selection)),
             strike_through: Some(formats.strike_through.get_update_for_selection(selection)),
+            font_size: Some(formats.font_size.get_update_for_selection(selection)),
+            font_family: Some(formats.font_family.get_update_for_selection(selection)),
+            text_rotation: Some(formats.text_rotation.get_update_for_selection(selection)),
+            indent: Some(formats.indent.get_update_for_selection(selection)),
+            style: Some(formats.style.get_update_for_selection(selection)),
         }
     }
 
@@ -111,6 +126,46 @@ impl SheetFormatUpdates {
             || Self::item_intersects(&self.date_time, rect)
             || Self::item_intersects(&self.underline, rect)
             || Self::item_intersects(&self.strike_through, rect)
+            || Self::item_intersects(&self.font_size, rect)
+            || Self::item_intersects(&self.font_family, rect)
+            || Self::item_intersects(&self.text_rotation, rect)
+            || Self::item_intersects(&self.indent, rect)
+            || Self::item_intersects(&self.style, rect)
+    }
+
+    fn item_rects<T>(item: &SheetFormatUpdatesType<T>, rects: &mut Vec<Rect>)
+    where
+        T: Clone + Debug + PartialEq,
+    {
+        if let Some(item) = item {
+            rects.extend(item.to_rects().map(|(x1, y1, x2, y2, _)| {
+                Rect::new(x1, y1, x2.unwrap_or(i64::MAX), y2.unwrap_or(i64::MAX))
+            }));
+        }
+    }
+
+    /// Returns the (possibly unbounded) rects changed by the format update.
+    pub fn to_rects(&self) -> Vec<Rect> {
+        let mut rects = vec![];
+        Self::item_rects(&self.align, &mut rects);
+        Self::item_rects(&self.vertical_align, &mut rects);
+        Self::item_rects(&self.wrap, &mut rects);
+        Self::item_rects(&self.numeric_format, &mut rects);
+        Self::item_rects(&self.numeric_decimals, &mut rects);
+        Self::item_rects(&self.numeric_commas, &mut rects);
+        Self::item_rects(&self.bold, &mut rects);
+        Self::item_rects(&self.italic, &mut rects);
+        Self::item_rects(&self.text_color, &mut rects);
+        Self::item_rects(&self.fill_color, &mut rects);
+        Self::item_rects(&self.date_time, &mut rects);
+        Self::item_rects(&self.underline, &mut rects);
+        Self::item_rects(&self.strike_through, &mut rects);
+        Self::item_rects(&self.font_size, &mut rects);
+        Self::item_rects(&self.font_family, &mut rects);
+        Self::item_rects(&self.text_rotation, &mut rects);
+        Self::item_rects(&self.indent, &mut rects);
+        Self::item_rects(&self.style, &mut rects);
+        rects
     }
 
     /// Returns whether the format update is empty.
@@ -143,6 +198,14 @@ impl SheetFormatUpdates {
                 .strike_through
                 .as_ref()
                 .is_none_or(|a| a.is_all_default())
+            && self.font_size.as_ref().is_none_or(|a| a.is_all_default())
+            && self.font_family.as_ref().is_none_or(|a| a.is_all_default())
+            && self
+                .text_rotation
+                .as_ref()
+                .is_none_or(|a| a.is_all_default())
+            && self.indent.as_ref().is_none_or(|a| a.is_all_default())
+            && self.style.as_ref().is_none_or(|a| a.is_all_default())
     }
 
     /// Sets a single format for a cell
@@ -174,6 +237,11 @@ impl SheetFormatUpdates {
         Self::set_format_cell_item(pos, &mut self.date_time, update.date_time);
         Self::set_format_cell_item(pos, &mut self.underline, update.underline);
         Self::set_format_cell_item(pos, &mut self.strike_through, update.strike_through);
+        Self::set_format_cell_item(pos, &mut self.font_size, update.font_size);
+        Self::set_format_cell_item(pos, &mut self.font_family, update.font_family);
+        Self::set_format_cell_item(pos, &mut self.text_rotation, update.text_rotation);
+        Self::set_format_cell_item(pos, &mut self.indent, update.indent);
+        Self::set_format_cell_item(pos, &mut self.style, update.style);
     }
 
     /// Returns the format for a cell within the SheetFormatUpdates.
@@ -201,6 +269,11 @@ impl SheetFormatUpdates {
             date_time: Self::format_update_item(&self.date_time, pos),
             underline: Self::format_update_item(&self.underline, pos),
             strike_through: Self::format_update_item(&self.strike_through, pos),
+            font_size: Self::format_update_item(&self.font_size, pos),
+            font_family: Self::format_update_item(&self.font_family, pos),
+            text_rotation: Self::format_update_item(&self.text_rotation, pos),
+            indent: Self::format_update_item(&self.indent, pos),
+            style: Self::format_update_item(&self.style, pos),
             render_size: None,
         }
     }
@@ -239,6 +312,11 @@ impl SheetFormatUpdates {
         Self::set_format_rect_item(&mut self.date_time, rect, update.date_time);
         Self::set_format_rect_item(&mut self.underline, rect, update.underline);
         Self::set_format_rect_item(&mut self.strike_through, rect, update.strike_through);
+        Self::set_format_rect_item(&mut self.font_size, rect, update.font_size);
+        Self::set_format_rect_item(&mut self.font_family, rect, update.font_family);
+        Self::set_format_rect_item(&mut self.text_rotation, rect, update.text_rotation);
+        Self::set_format_rect_item(&mut self.indent, rect, update.indent);
+        Self::set_format_rect_item(&mut self.style, rect, update.style);
     }
 
     fn translate_rect_item<T>(item: &mut SheetFormatUpdatesType<T>, x: i64, y: i64)
@@ -263,6 +341,11 @@ impl SheetFormatUpdates {
         Self::translate_rect_item(&mut self.date_time, x, y);
         Self::translate_rect_item(&mut self.underline, x, y);
         Self::translate_rect_item(&mut self.strike_through, x, y);
+        Self::translate_rect_item(&mut self.font_size, x, y);
+        Self::translate_rect_item(&mut self.font_family, x, y);
+        Self::translate_rect_item(&mut self.text_rotation, x, y);
+        Self::translate_rect_item(&mut self.indent, x, y);
+        Self::translate_rect_item(&mut self.style, x, y);
     }
 
     /// Merges another SheetFormatUpdates into this one.
@@ -280,6 +363,11 @@ impl SheetFormatUpdates {
         Self::merge_item(&mut self.date_time, &other.date_time);
         Self::merge_item(&mut self.underline, &other.underline);
         Self::merge_item(&mut self.strike_through, &other.strike_through);
+        Self::merge_item(&mut self.font_size, &other.font_size);
+        Self::merge_item(&mut self.font_family, &other.font_family);
+        Self::merge_item(&mut self.text_rotation, &other.text_rotation);
+        Self::merge_item(&mut self.indent, &other.indent);
+        Self::merge_item(&mut self.style, &other.style);
     }
 
     /// Merges another SheetFormatUpdates into this one.
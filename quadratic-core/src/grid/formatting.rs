@@ -5,6 +5,7 @@ use strum_macros::{Display, EnumString};
 use ts_rs::TS;
 
 use crate::RunLengthEncoding;
+use crate::values::format_code::FormatCode;
 
 pub struct NumericDecimals;
 
@@ -99,14 +100,30 @@ impl CellWrap {
     }
 }
 
+/// The `text_rotation` of vertical text (letters stacked top to bottom), as
+/// in Excel.
+pub const VERTICAL_TEXT: i16 = 255;
+
 #[derive(Default, Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, ts_rs::TS)]
 #[cfg_attr(test, derive(proptest_derive::Arbitrary))]
 pub struct NumericFormat {
     #[serde(rename = "type")]
     pub kind: NumericFormatKind,
+
+    /// The currency symbol for `Currency`, or the format code for `Custom`.
     pub symbol: Option<String>,
 }
 
+impl NumericFormat {
+    /// Returns the parsed format code of a `Custom` format.
+    pub fn format_code(&self) -> Option<FormatCode> {
+        match (&self.kind, &self.symbol) {
+            (NumericFormatKind::Custom, Some(code)) => FormatCode::parse(code).ok(),
+            _ => None,
+        }
+    }
+}
+
 #[derive(
     Default,
     Serialize,
@@ -127,9 +144,13 @@ pub struct NumericFormat {
 pub enum NumericFormatKind {
     #[default]
     Number,
-    Currency, // { symbol: String }, // TODO: would be nice if this were just a single char (and it could be)
+    Currency,
     Percentage,
     Exponential,
+
+    /// An Excel format code (eg, `#,##0.00;[Red](#,##0.00)`), stored in
+    /// `NumericFormat::symbol`.
+    Custom,
 }
 
 #[derive(Default, Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, ts_rs::TS)]
@@ -8,9 +8,11 @@ use super::cells_accessed::JsCellsAccessed;
 use super::data_table::{column_header::DataTableColumnHeader, sort::DataTableSort};
 use super::formats::Format;
 use super::formatting::{CellAlign, CellVerticalAlign, CellWrap};
+use super::sheet::comments::CommentThread;
+use super::sheet::conditional_formats::rules::IconSetKind;
 use super::sheet::validations::validation::ValidationStyle;
 use super::{CodeCellLanguage, NumericFormat};
-use crate::Pos;
+use crate::{Pos, Rect, TextSpan};
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, TS)]
 pub enum JsRenderCellSpecial {
@@ -20,6 +22,8 @@ pub enum JsRenderCellSpecial {
     Logical,
     Checkbox,
     List,
+    MultiSelectList,
+    Hyperlink,
 }
 
 #[derive(Default, Serialize, Deserialize, Debug, Clone, PartialEq, Eq, TS)]
@@ -27,6 +31,15 @@ pub struct JsNumber {
     pub decimals: Option<i16>,
     pub commas: Option<bool>,
     pub format: Option<NumericFormat>,
+
+    /// Separators of the file's locale, set only when they differ from `.`
+    /// and `,`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub decimal_separator: Option<char>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub group_separator: Option<char>,
 }
 
 impl JsNumber {
@@ -49,6 +62,8 @@ impl From<&Format> for JsNumber {
             decimals: format.numeric_decimals,
             commas: format.numeric_commas,
             format: format.numeric_format.clone(),
+            decimal_separator: None,
+            group_separator: None,
         }
     }
 }
@@ -186,8 +201,72 @@ pub struct JsRenderCell {
     pub underline: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub strike_through: Option<bool>,
+
+    /// Font size in points.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub font_size: Option<i16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub font_family: Option<String>,
+
+    /// Rotation in degrees, or `VERTICAL_TEXT` for stacked letters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_rotation: Option<i16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub indent: Option<u8>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub column_header: Option<bool>,
+
+    /// The merged cell, set only for the top left cell of a merged cell.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub merge_rect: Option<Rect>,
+
+    /// The styled spans of rich text. `value` is the plain text. Styles that
+    /// are not set on a span use the cell's style.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rich_text: Option<Vec<TextSpan>>,
+
+    /// Data bar from a conditional format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data_bar: Option<JsRenderDataBar>,
+
+    /// Icon from a conditional format icon set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon: Option<JsRenderIcon>,
+
+    /// A character repeated to fill the cell, from a custom number format
+    /// (eg, the `* ` in accounting formats).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number_fill: Option<JsNumberFill>,
+
+    /// The selected values of a multi-select list, rendered as chips.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chips: Option<Vec<String>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, TS)]
+pub struct JsRenderDataBar {
+    pub color: String,
+
+    /// Width of the bar as a percent of the cell's width.
+    pub percent: u8,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, TS)]
+#[serde(rename_all = "camelCase")]
+pub struct JsRenderIcon {
+    pub icon_set: IconSetKind,
+
+    /// Index of the icon in the set, starting with the icon for the lowest
+    /// values.
+    pub index: u8,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, TS)]
+pub struct JsNumberFill {
+    /// The character index in the value where the fill is inserted.
+    pub index: u32,
+    pub char: String,
 }
 
 #[cfg(test)]
@@ -268,6 +347,14 @@ pub struct CellFormatSummary {
 
     pub underline: Option<bool>,
     pub strike_through: Option<bool>,
+
+    pub font_size: Option<i16>,
+    pub font_family: Option<String>,
+    pub text_rotation: Option<i16>,
+    pub indent: Option<u8>,
+
+    /// Name of the named cell style.
+    pub style: Option<String>,
 }
 
 #[derive(Serialize, Debug, PartialEq, Eq, TS)]
@@ -423,6 +510,13 @@ pub struct JsValidationWarning {
     pub style: Option<ValidationStyle>,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, TS)]
+pub struct JsCommentThread {
+    pub x: i64,
+    pub y: i64,
+    pub thread: CommentThread,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, TS)]
 pub struct JsSummarizeSelectionResult {
     pub count: i64,
@@ -0,0 +1,435 @@
+//! The file's locale.
+//!
+//! The locale controls how typed values are parsed (eg, whether `10/12/2024`
+//! is October 12 or December 10, and whether `1.234,56` is a number), the
+//! default formats used to display dates and times, the separators used to
+//! display numbers, and the separators used when typing formulas.
+//!
+//! Values are always stored in a locale-independent form. Formulas are stored
+//! with `,` between arguments and `.` as the decimal separator, and are
+//! converted when entered or edited.
+
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use crate::date_time::{DEFAULT_DATE_FORMAT, DEFAULT_TIME_FORMAT, DateOrder};
+
+/// Currency symbols that are recognized regardless of the locale.
+const COMMON_CURRENCY_SYMBOLS: [&str; 4] = ["$", "€", "£", "¥"];
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, TS)]
+pub struct Locale {
+    /// BCP 47 name of the locale (eg, "de-DE").
+    pub name: String,
+
+    /// Order used to read ambiguous numeric dates.
+    pub date_order: DateOrder,
+
+    /// Default strftime format for dates.
+    pub date_format: String,
+
+    /// Default strftime format for times.
+    pub time_format: String,
+
+    pub decimal_separator: char,
+    pub group_separator: char,
+
+    /// Currency symbol used for new currency formats.
+    pub currency_symbol: String,
+
+    /// First day of the week, where 0 is Sunday and 1 is Monday.
+    pub first_day_of_week: u8,
+
+    /// Separator between formula arguments (`,` or `;`).
+    pub argument_separator: char,
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Self {
+            name: "en-US".to_string(),
+            date_order: DateOrder::Mdy,
+            date_format: DEFAULT_DATE_FORMAT.to_string(),
+            time_format: DEFAULT_TIME_FORMAT.to_string(),
+            decimal_separator: '.',
+            group_separator: ',',
+            currency_symbol: "$".to_string(),
+            first_day_of_week: 0,
+            argument_separator: ',',
+        }
+    }
+}
+
+/// A number typed in a locale, converted to the form used by `en-US`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DelocalizedNumber {
+    /// The number using `,` for grouping and `.` for decimals, including any
+    /// sign, parentheses, or percent sign.
+    pub value: String,
+
+    /// The currency symbol, if the number had one.
+    pub currency: Option<String>,
+}
+
+/// Separators used within a formula.
+#[derive(Clone, Copy)]
+struct FormulaSeparators {
+    argument: char,
+    decimal: char,
+    array_column: char,
+}
+
+impl FormulaSeparators {
+    const DEFAULT: Self = Self {
+        argument: ',',
+        decimal: '.',
+        array_column: ',',
+    };
+}
+
+impl Locale {
+    /// Names of the locales returned by [`Locale::preset`].
+    pub fn preset_names() -> Vec<&'static str> {
+        vec![
+            "en-US", "en-GB", "de-DE", "fr-FR", "es-ES", "it-IT", "nl-NL", "pt-BR", "ja-JP",
+        ]
+    }
+
+    /// Returns a locale by its BCP 47 name.
+    pub fn preset(name: &str) -> Option<Self> {
+        let (date_order, date_format, time_format, decimal, group, currency, first_day) = match name
+        {
+            "en-US" => return Some(Self::default()),
+            "en-GB" => (DateOrder::Dmy, "%d/%m/%Y", "%H:%M", '.', ',', "£", 1),
+            "de-DE" => (DateOrder::Dmy, "%d.%m.%Y", "%H:%M", ',', '.', "€", 1),
+            "fr-FR" => (DateOrder::Dmy, "%d/%m/%Y", "%H:%M", ',', ' ', "€", 1),
+            "es-ES" => (DateOrder::Dmy, "%d/%m/%Y", "%H:%M", ',', '.', "€", 1),
+            "it-IT" => (DateOrder::Dmy, "%d/%m/%Y", "%H:%M", ',', '.', "€", 1),
+            "nl-NL" => (DateOrder::Dmy, "%d-%m-%Y", "%H:%M", ',', '.', "€", 1),
+            "pt-BR" => (DateOrder::Dmy, "%d/%m/%Y", "%H:%M", ',', '.', "R$", 0),
+            "ja-JP" => (DateOrder::Ymd, "%Y/%m/%d", "%H:%M", '.', ',', "¥", 0),
+            _ => return None,
+        };
+        Some(Self {
+            name: name.to_string(),
+            date_order,
+            date_format: date_format.to_string(),
+            time_format: time_format.to_string(),
+            decimal_separator: decimal,
+            group_separator: group,
+            currency_symbol: currency.to_string(),
+            first_day_of_week: first_day,
+            // a `,` decimal separator requires another argument separator
+            argument_separator: if decimal == ',' { ';' } else { ',' },
+        })
+    }
+
+    /// Default strftime format for date times.
+    pub fn date_time_format(&self) -> String {
+        format!("{} {}", self.date_format, self.time_format)
+    }
+
+    /// Default format for date times that carry a UTC offset.
+    pub fn date_time_tz_format(&self) -> String {
+        format!("{} %:z", self.date_time_format())
+    }
+
+    /// Whether numbers use `.` for decimals and `,` for grouping.
+    pub fn has_default_number_separators(&self) -> bool {
+        self.decimal_separator == '.' && self.group_separator == ','
+    }
+
+    fn is_group_separator(&self, c: char) -> bool {
+        c == self.group_separator
+            || (self.group_separator.is_whitespace() && (c == '\u{a0}' || c == '\u{202f}'))
+    }
+
+    /// Converts a number typed in this locale (eg, `-1.234,56 €`) to the form
+    /// used by `en-US` (eg, `-1,234.56` with a currency of `€`). Returns None
+    /// if the value is not a number in this locale.
+    pub fn delocalize_number(&self, value: &str) -> Option<DelocalizedNumber> {
+        let mut s = value.trim();
+
+        let parentheses = s.starts_with('(') && s.ends_with(')') && s.len() > 1;
+        if parentheses {
+            s = s[1..s.len() - 1].trim();
+        }
+        let negative = s.starts_with('-');
+        if negative {
+            if parentheses {
+                return None;
+            }
+            s = s[1..].trim_start();
+        }
+        let percent = s.ends_with('%');
+        if percent {
+            s = s[..s.len() - 1].trim_end();
+        }
+
+        let mut currency = None;
+        for symbol in std::iter::once(self.currency_symbol.as_str()).chain(COMMON_CURRENCY_SYMBOLS)
+        {
+            if symbol.is_empty() {
+                continue;
+            }
+            if let Some(stripped) = s.strip_prefix(symbol) {
+                s = stripped.trim_start();
+            } else if let Some(stripped) = s.strip_suffix(symbol) {
+                s = stripped.trim_end();
+            } else {
+                continue;
+            }
+            currency = Some(symbol.to_string());
+            break;
+        }
+        if percent && currency.is_some() {
+            return None;
+        }
+
+        let (integer, fraction) = match s.split_once(self.decimal_separator) {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (s, None),
+        };
+        if integer.is_empty() && fraction.is_none_or(str::is_empty) {
+            return None;
+        }
+        if let Some(fraction) = fraction
+            && (fraction.is_empty() || !fraction.chars().all(|c| c.is_ascii_digit()))
+        {
+            return None;
+        }
+        let groups = integer
+            .split(|c| self.is_group_separator(c))
+            .collect::<Vec<_>>();
+        if !groups.iter().all(|g| g.chars().all(|c| c.is_ascii_digit())) {
+            return None;
+        }
+        if groups.len() > 1
+            && (groups[0].is_empty()
+                || groups[0].len() > 3
+                || groups[1..].iter().any(|g| g.len() != 3))
+        {
+            return None;
+        }
+
+        let mut number = groups.join(",");
+        if let Some(fraction) = fraction {
+            number.push('.');
+            number.push_str(fraction);
+        }
+        if negative {
+            number.insert(0, '-');
+        }
+        if percent {
+            number.push('%');
+        }
+        if parentheses {
+            number = format!("({number})");
+        }
+        Some(DelocalizedNumber {
+            value: number,
+            currency,
+        })
+    }
+
+    /// Converts a number formatted by `en-US` (using `,` for grouping and `.`
+    /// for decimals) to this locale.
+    pub fn localize_number(&self, number: &str) -> String {
+        if self.has_default_number_separators() {
+            return number.to_string();
+        }
+        number
+            .chars()
+            .map(|c| match c {
+                '.' => self.decimal_separator,
+                ',' => self.group_separator,
+                c => c,
+            })
+            .collect()
+    }
+
+    fn formula_separators(&self) -> FormulaSeparators {
+        FormulaSeparators {
+            argument: self.argument_separator,
+            decimal: self.decimal_separator,
+            // array literals need a column separator that differs from both
+            array_column: if self.argument_separator == ',' {
+                ','
+            } else {
+                '\\'
+            },
+        }
+    }
+
+    /// Converts a formula typed in this locale (eg, `SUM(1,5; A1)`) to the
+    /// stored form (eg, `SUM(1.5, A1)`).
+    pub fn delocalize_formula(&self, formula: &str) -> String {
+        translate_formula(
+            formula,
+            self.formula_separators(),
+            FormulaSeparators::DEFAULT,
+        )
+    }
+
+    /// Converts a stored formula to this locale for editing.
+    pub fn localize_formula(&self, formula: &str) -> String {
+        translate_formula(
+            formula,
+            FormulaSeparators::DEFAULT,
+            self.formula_separators(),
+        )
+    }
+}
+
+/// Whether the character at `i` follows a number (or starts one), as opposed
+/// to a cell reference like `A1`.
+fn follows_number(chars: &[char], i: usize) -> bool {
+    let mut start = i;
+    while start > 0 && chars[start - 1].is_ascii_digit() {
+        start -= 1;
+    }
+    start == 0
+        || !(chars[start - 1].is_alphanumeric() || matches!(chars[start - 1], '_' | '$' | '.'))
+}
+
+/// Replaces the separators of a formula. Strings, quoted sheet names, and
+/// table references are not changed. Decimal separators are only replaced
+/// within numbers.
+fn translate_formula(formula: &str, from: FormulaSeparators, to: FormulaSeparators) -> String {
+    let chars = formula.chars().collect::<Vec<_>>();
+    let mut result = String::with_capacity(formula.len());
+    let mut quote = None;
+    let mut brackets = 0_u32;
+    let mut braces = 0_u32;
+
+    for (i, &c) in chars.iter().enumerate() {
+        if let Some(q) = quote {
+            if c == q {
+                quote = None;
+            }
+            result.push(c);
+            continue;
+        }
+        let translated = match c {
+            '"' | '\'' => {
+                quote = Some(c);
+                c
+            }
+            '[' => {
+                brackets += 1;
+                c
+            }
+            ']' => {
+                brackets = brackets.saturating_sub(1);
+                c
+            }
+            '{' => {
+                braces += 1;
+                c
+            }
+            '}' => {
+                braces = braces.saturating_sub(1);
+                c
+            }
+            _ if brackets > 0 => c,
+            _ if c == from.decimal
+                && chars.get(i + 1).is_some_and(|next| next.is_ascii_digit())
+                && follows_number(&chars, i) =>
+            {
+                to.decimal
+            }
+            _ if braces > 0 && c == from.array_column => to.array_column,
+            _ if braces == 0 && c == from.argument => to.argument,
+            _ => c,
+        };
+        result.push(translated);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn de() -> Locale {
+        Locale::preset("de-DE").unwrap()
+    }
+
+    #[test]
+    fn test_presets() {
+        for name in Locale::preset_names() {
+            let locale = Locale::preset(name).unwrap();
+            assert_eq!(locale.name, name);
+            assert_ne!(locale.decimal_separator, locale.group_separator);
+            assert_ne!(locale.decimal_separator, locale.argument_separator);
+        }
+        assert_eq!(Locale::preset("en-US"), Some(Locale::default()));
+        assert_eq!(Locale::preset("xx-XX"), None);
+        assert_eq!(de().date_time_format(), "%d.%m.%Y %H:%M");
+    }
+
+    #[test]
+    fn test_delocalize_number() {
+        let number = |value: &str, currency: Option<&str>| DelocalizedNumber {
+            value: value.to_string(),
+            currency: currency.map(String::from),
+        };
+        let de = de();
+        assert_eq!(
+            de.delocalize_number("1.234,56"),
+            Some(number("1,234.56", None))
+        );
+        assert_eq!(
+            de.delocalize_number("-1.234,5 €"),
+            Some(number("-1,234.5", Some("€")))
+        );
+        assert_eq!(
+            de.delocalize_number("(12,5%)"),
+            Some(number("(12.5%)", None))
+        );
+        assert_eq!(de.delocalize_number("1.234"), Some(number("1,234", None)));
+        assert_eq!(de.delocalize_number("1.5"), None);
+        assert_eq!(de.delocalize_number("31.12.2024"), None);
+        assert_eq!(de.delocalize_number("abc"), None);
+        assert_eq!(de.delocalize_number(","), None);
+
+        let fr = Locale::preset("fr-FR").unwrap();
+        assert_eq!(
+            fr.delocalize_number("1\u{202f}234,5"),
+            Some(number("1,234.5", None))
+        );
+
+        let us = Locale::default();
+        assert_eq!(
+            us.delocalize_number("$1,234.50"),
+            Some(number("1,234.50", Some("$")))
+        );
+        assert_eq!(us.delocalize_number("1,2"), None);
+    }
+
+    #[test]
+    fn test_localize_number() {
+        assert_eq!(de().localize_number("-1,234.56"), "-1.234,56");
+        assert_eq!(Locale::default().localize_number("1,234.56"), "1,234.56");
+    }
+
+    #[test]
+    fn test_formulas() {
+        let de = de();
+        assert_eq!(
+            de.delocalize_formula("SUM(1,5; A1; \"a;b\"; 'x;y'!B2)"),
+            "SUM(1.5, A1, \"a;b\", 'x;y'!B2)"
+        );
+        assert_eq!(de.delocalize_formula("ROUND(A1,2)"), "ROUND(A1,2)");
+        assert_eq!(de.delocalize_formula("{1\\2;3\\4}"), "{1,2;3,4}");
+        assert_eq!(de.delocalize_formula("Table1[a;b]"), "Table1[a;b]");
+        assert_eq!(
+            de.localize_formula("IF(A1>0.5, {1,2;3,4}, \"1,5\")"),
+            "IF(A1>0,5; {1\\2;3\\4}; \"1,5\")"
+        );
+
+        let us = Locale::default();
+        assert_eq!(us.delocalize_formula("SUM(1.5, A1)"), "SUM(1.5, A1)");
+        assert_eq!(us.localize_formula("SUM(1.5, A1)"), "SUM(1.5, A1)");
+    }
+}
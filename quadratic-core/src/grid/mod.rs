@@ -11,14 +11,17 @@ pub use formatting::{
     NumericDecimals, NumericFormat, NumericFormatKind, StrikeThrough, TextColor, Underline,
 };
 pub use ids::*;
+pub use locale::Locale;
+pub use region_map::RegionMap;
 use serde::{Deserialize, Serialize};
 pub use sheet::Sheet;
 pub use sheet_formatting::SheetFormatting;
-pub use region_map::RegionMap;
 
 use crate::CellValue;
+use crate::date_time::DateSettings;
 #[cfg(test)]
 use crate::{Array, Pos};
+use formats::{CellStyle, Theme};
 
 mod a1_context;
 pub mod ai;
@@ -30,12 +33,13 @@ mod code_run;
 pub mod column;
 pub mod contiguous;
 pub mod data_table;
-mod region_map;
 pub mod file;
 pub mod formats;
 pub mod formatting;
 mod ids;
 pub mod js_types;
+pub mod locale;
+mod region_map;
 pub mod resize;
 pub mod search;
 pub mod selection;
@@ -47,6 +51,33 @@ pub mod sheets;
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct Grid {
     pub sheets: Vec<Sheet>,
+
+    /// Workbook-level lists of values (eg, fiscal quarters) that are used as
+    /// series when autocompleting.
+    #[serde(default)]
+    pub custom_lists: Vec<Vec<String>>,
+
+    /// Workbook-level named cell styles (eg, "Input" or "Header").
+    #[serde(default)]
+    pub cell_styles: Vec<CellStyle>,
+
+    /// Workbook-level palette that cell style colors may reference.
+    #[serde(default)]
+    pub theme: Theme,
+
+    /// Locale used to parse typed values and display dates and numbers.
+    #[serde(default)]
+    pub locale: Locale,
+
+    /// Time zone used for the current time (eg, `NOW()`) and as the default
+    /// zone when converting date times. If `None`, each user's local time
+    /// zone is used.
+    #[serde(default)]
+    pub time_zone: Option<chrono_tz::Tz>,
+
+    /// Date system and Excel serial date compatibility.
+    #[serde(default)]
+    pub date_settings: DateSettings,
 }
 impl Default for Grid {
     fn default() -> Self {
@@ -60,7 +91,15 @@ impl Grid {
         ret
     }
     pub fn new_blank() -> Self {
-        Grid { sheets: vec![] }
+        Grid {
+            sheets: vec![],
+            custom_lists: vec![],
+            cell_styles: vec![],
+            theme: Theme::default(),
+            locale: Locale::default(),
+            time_zone: None,
+            date_settings: DateSettings::default(),
+        }
     }
 
     /// Creates a grid for testing.
@@ -0,0 +1,473 @@
+//! Flash fill: learns a string transformation from one or more examples and
+//! applies it to other rows.
+//!
+//! A program is a concatenation of parts. Each part is either constant text,
+//! a piece of a source split by a delimiter, or a substring of a source
+//! between two positions. Any source part may also change case.
+//!
+//! Programs are found by searching the ways to build the first example's
+//! output from its sources, using as few parts as possible, and keeping the
+//! first program that produces every example. Constant text is first limited
+//! to separators (eg, ", ") so that a single example is not simply copied.
+
+use crate::util::title_case;
+
+/// Delimiters used to split sources and to find substring positions.
+const DELIMITERS: [char; 11] = [' ', ',', '.', '@', '-', '_', '/', ':', ';', '|', '\t'];
+
+/// Maximum number of parts in a program.
+const MAX_PARTS: usize = 5;
+
+/// Maximum number of steps in the search for a program.
+const MAX_SEARCH_STEPS: usize = 100_000;
+
+/// Examples longer than this (in characters) are not learned from, and
+/// longer sources are not used.
+const MAX_EXAMPLE_LENGTH: usize = 256;
+
+/// Maximum number of source parts considered while learning a program.
+const MAX_CANDIDATES: usize = 10_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Case {
+    Unchanged,
+    Lower,
+    Upper,
+    Title,
+}
+
+impl Case {
+    const ALL: [Case; 4] = [Case::Unchanged, Case::Lower, Case::Upper, Case::Title];
+
+    fn apply(self, s: &str) -> String {
+        match self {
+            Case::Unchanged => s.to_string(),
+            Case::Lower => s.to_lowercase(),
+            Case::Upper => s.to_uppercase(),
+            Case::Title => title_case(s),
+        }
+    }
+}
+
+/// A character position within a source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Position {
+    /// Characters from the start.
+    Start(usize),
+
+    /// Characters from the end.
+    End(usize),
+
+    /// After an occurrence of a delimiter. Negative occurrences count from
+    /// the end (-1 is the last).
+    After(char, i32),
+
+    /// Before an occurrence of a delimiter.
+    Before(char, i32),
+}
+
+impl Position {
+    fn resolve(self, chars: &[char]) -> Option<usize> {
+        let occurrence = |delimiter: char, occurrence: i32| {
+            let indices = chars
+                .iter()
+                .enumerate()
+                .filter(|(_, c)| **c == delimiter)
+                .map(|(i, _)| i)
+                .collect::<Vec<_>>();
+            let index = if occurrence < 0 {
+                indices
+                    .len()
+                    .checked_sub(occurrence.unsigned_abs() as usize)?
+            } else {
+                occurrence as usize
+            };
+            indices.get(index).copied()
+        };
+
+        match self {
+            Position::Start(n) => (n <= chars.len()).then_some(n),
+            Position::End(n) => chars.len().checked_sub(n),
+            Position::After(delimiter, n) => occurrence(delimiter, n).map(|i| i + 1),
+            Position::Before(delimiter, n) => occurrence(delimiter, n),
+        }
+    }
+
+    /// Returns the ways to describe position `index` within `chars`, in order
+    /// of preference. `is_start` is true for the start of a substring.
+    fn candidates(chars: &[char], index: usize, is_start: bool) -> Vec<Position> {
+        let mut positions = vec![];
+        if is_start && index == 0 {
+            positions.push(Position::Start(0));
+        }
+        if !is_start && index == chars.len() {
+            positions.push(Position::End(0));
+        }
+
+        let delimiter = if is_start {
+            index.checked_sub(1).and_then(|i| chars.get(i))
+        } else {
+            chars.get(index)
+        };
+        if let Some(&delimiter) = delimiter.filter(|c| DELIMITERS.contains(c)) {
+            let delimiter_index = if is_start { index - 1 } else { index };
+            let count = chars.iter().filter(|c| **c == delimiter).count() as i32;
+            let occurrence = chars[..delimiter_index]
+                .iter()
+                .filter(|c| **c == delimiter)
+                .count() as i32;
+            let from_end = occurrence - count;
+            let (first, second) = if occurrence > 0 && from_end == -1 {
+                (from_end, occurrence)
+            } else {
+                (occurrence, from_end)
+            };
+            for occurrence in [first, second] {
+                positions.push(if is_start {
+                    Position::After(delimiter, occurrence)
+                } else {
+                    Position::Before(delimiter, occurrence)
+                });
+            }
+        }
+
+        if !positions.contains(&Position::Start(index)) {
+            positions.push(Position::Start(index));
+        }
+        if !positions.contains(&Position::End(chars.len() - index)) {
+            positions.push(Position::End(chars.len() - index));
+        }
+        positions
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Part {
+    Const(String),
+    Split {
+        column: usize,
+        delimiter: char,
+        index: i32,
+        case: Case,
+    },
+    Substring {
+        column: usize,
+        start: Position,
+        end: Position,
+        case: Case,
+    },
+}
+
+impl Part {
+    fn apply(&self, sources: &[String]) -> Option<String> {
+        match self {
+            Part::Const(s) => Some(s.clone()),
+            Part::Split {
+                column,
+                delimiter,
+                index,
+                case,
+            } => {
+                let pieces = sources.get(*column)?.split(*delimiter).collect::<Vec<_>>();
+                let index = if *index < 0 {
+                    pieces.len().checked_sub(index.unsigned_abs() as usize)?
+                } else {
+                    *index as usize
+                };
+                pieces.get(index).map(|piece| case.apply(piece))
+            }
+            Part::Substring {
+                column,
+                start,
+                end,
+                case,
+            } => {
+                let chars = sources.get(*column)?.chars().collect::<Vec<_>>();
+                let start = start.resolve(&chars)?;
+                let end = end.resolve(&chars)?;
+                (start < end).then(|| case.apply(&chars[start..end].iter().collect::<String>()))
+            }
+        }
+    }
+
+    /// Returns the source parts that produce `target`, in order of
+    /// preference.
+    fn candidates(sources: &[String], target: &str) -> Vec<Part> {
+        let target_len = target.chars().count();
+        let mut parts = vec![];
+        for (column, source) in sources.iter().enumerate() {
+            if source.chars().count() > MAX_EXAMPLE_LENGTH {
+                continue;
+            }
+            let column_start = parts.len();
+            for case in Case::ALL {
+                for delimiter in DELIMITERS {
+                    let pieces = source.split(delimiter).collect::<Vec<_>>();
+                    if pieces.len() < 2 {
+                        continue;
+                    }
+                    for (index, piece) in pieces.iter().enumerate() {
+                        if case.apply(piece) != target {
+                            continue;
+                        }
+                        let index = index as i32;
+                        let from_end = index - pieces.len() as i32;
+                        let (first, second) = if index > 0 && from_end == -1 {
+                            (from_end, index)
+                        } else {
+                            (index, from_end)
+                        };
+                        for index in [first, second] {
+                            parts.push(Part::Split {
+                                column,
+                                delimiter,
+                                index,
+                                case,
+                            });
+                        }
+                    }
+                }
+
+                let chars = source.chars().collect::<Vec<_>>();
+                for start in 0..(chars.len() + 1).saturating_sub(target_len) {
+                    let end = start + target_len;
+                    if case.apply(&chars[start..end].iter().collect::<String>()) != target {
+                        continue;
+                    }
+                    for start_position in Position::candidates(&chars, start, true) {
+                        for end_position in Position::candidates(&chars, end, false) {
+                            parts.push(Part::Substring {
+                                column,
+                                start: start_position,
+                                end: end_position,
+                                case,
+                            });
+                        }
+                    }
+                }
+
+                // only try other cases if the text was not found unchanged
+                if parts.len() > column_start {
+                    break;
+                }
+            }
+        }
+        parts
+    }
+}
+
+/// A string transformation learned from examples.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlashFillProgram {
+    parts: Vec<Part>,
+}
+
+impl FlashFillProgram {
+    /// Learns a program from examples of (sources, output). Returns None if
+    /// no program produces every example.
+    pub fn learn(examples: &[(Vec<String>, String)]) -> Option<Self> {
+        let (sources, output) = examples.first()?;
+        let output = output.chars().collect::<Vec<_>>();
+        if output.is_empty() || output.len() > MAX_EXAMPLE_LENGTH {
+            return None;
+        }
+
+        // parts that produce output[i..j], for each i: source parts (longest
+        // first), then constants (shortest first). Only text found in a source
+        // (ignoring case) can be a source part.
+        let lower_sources = sources
+            .iter()
+            .map(|source| source.to_lowercase())
+            .collect::<Vec<_>>();
+        let mut candidates = 0;
+        let mut edges = vec![vec![]; output.len()];
+        for (i, edges) in edges.iter_mut().enumerate() {
+            for j in (i + 1..=output.len()).rev() {
+                if candidates >= MAX_CANDIDATES {
+                    break;
+                }
+                let target = output[i..j].iter().collect::<String>();
+                let lower_target = target.to_lowercase();
+                if !lower_sources
+                    .iter()
+                    .any(|source| source.contains(&lower_target))
+                {
+                    continue;
+                }
+                for part in Part::candidates(sources, &target) {
+                    candidates += 1;
+                    edges.push((j, part));
+                }
+            }
+            for j in i + 1..=output.len() {
+                edges.push((j, Part::Const(output[i..j].iter().collect())));
+            }
+        }
+
+        let mut search = Search {
+            edges: &edges,
+            examples,
+            parts: vec![],
+            text_constants: false,
+            steps: 0,
+        };
+        [false, true].into_iter().find_map(|text_constants| {
+            search.text_constants = text_constants;
+            (1..=MAX_PARTS).find_map(|max_parts| search.find(0, max_parts))
+        })
+    }
+
+    /// Applies the program to a row's sources. Returns None if the program
+    /// does not apply (eg, a delimiter is missing).
+    pub fn apply(&self, sources: &[String]) -> Option<String> {
+        self.parts.iter().map(|part| part.apply(sources)).collect()
+    }
+}
+
+/// Depth-first search through the ways to build the first example's output.
+struct Search<'a> {
+    edges: &'a [Vec<(usize, Part)>],
+    examples: &'a [(Vec<String>, String)],
+    parts: Vec<Part>,
+
+    /// Whether constants may contain letters and digits.
+    text_constants: bool,
+
+    steps: usize,
+}
+
+impl Search<'_> {
+    fn find(&mut self, index: usize, max_parts: usize) -> Option<FlashFillProgram> {
+        self.steps += 1;
+        if index == self.edges.len() {
+            // a program made only of constants copies the example
+            if self.parts.iter().all(|part| matches!(part, Part::Const(_))) {
+                return None;
+            }
+            let program = FlashFillProgram {
+                parts: self.parts.clone(),
+            };
+            return self
+                .examples
+                .iter()
+                .all(|(sources, output)| program.apply(sources).as_ref() == Some(output))
+                .then_some(program);
+        }
+        if self.parts.len() == max_parts || self.steps >= MAX_SEARCH_STEPS {
+            return None;
+        }
+
+        let previous_is_const = matches!(self.parts.last(), Some(Part::Const(_)));
+        for (next, part) in &self.edges[index] {
+            if let Part::Const(s) = part {
+                // consecutive constants are always merged into one
+                if previous_is_const
+                    || (!self.text_constants && s.chars().any(char::is_alphanumeric))
+                {
+                    continue;
+                }
+            }
+            self.parts.push(part.clone());
+            let program = self.find(*next, max_parts);
+            self.parts.pop();
+            if program.is_some() {
+                return program;
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example(sources: &[&str], output: &str) -> (Vec<String>, String) {
+        (
+            sources.iter().map(|s| s.to_string()).collect(),
+            output.to_string(),
+        )
+    }
+
+    fn fill(examples: &[(Vec<String>, String)], sources: &[&str]) -> Option<String> {
+        let sources = sources.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        FlashFillProgram::learn(examples).unwrap().apply(&sources)
+    }
+
+    #[test]
+    fn test_flash_fill_split() {
+        let examples = [example(&["jane@example.com"], "example.com")];
+        assert_eq!(
+            fill(&examples, &["bob@quadratic.co"]),
+            Some("quadratic.co".to_string())
+        );
+
+        let examples = [example(&["jane@example.com"], "example")];
+        assert_eq!(
+            fill(&examples, &["bob@quadratic.co"]),
+            Some("quadratic".to_string())
+        );
+        assert_eq!(fill(&examples, &["no email"]), None);
+    }
+
+    #[test]
+    fn test_flash_fill_concat_and_case() {
+        let examples = [example(&["Jane Smith"], "Smith, Jane")];
+        assert_eq!(
+            fill(&examples, &["John Doe"]),
+            Some("Doe, John".to_string())
+        );
+
+        let examples = [example(&["jane", "smith"], "J. SMITH")];
+        assert_eq!(
+            fill(&examples, &["john", "doe"]),
+            Some("J. DOE".to_string())
+        );
+
+        let examples = [example(&["hello WORLD"], "Hello World")];
+        assert_eq!(
+            fill(&examples, &["gOOD morning"]),
+            Some("Good Morning".to_string())
+        );
+    }
+
+    #[test]
+    fn test_flash_fill_two_examples() {
+        // one example is ambiguous; the second shows that the last 3
+        // characters are used
+        let examples = [
+            example(&["555-1234"], "234"),
+            example(&["555-98765"], "765"),
+        ];
+        assert_eq!(fill(&examples, &["1-23456"]), Some("456".to_string()));
+    }
+
+    #[test]
+    fn test_flash_fill_text_constant() {
+        let examples = [example(&["123"], "ID-123")];
+        assert_eq!(fill(&examples, &["45"]), Some("ID-45".to_string()));
+    }
+
+    #[test]
+    fn test_flash_fill_long_examples() {
+        // repeated text has many candidates; learning is bounded
+        let source = "a".repeat(MAX_EXAMPLE_LENGTH);
+        let sources = vec![source.as_str(); 10];
+        let examples = [example(&sources, &source)];
+        let start = std::time::Instant::now();
+        FlashFillProgram::learn(&examples);
+        assert!(start.elapsed() < std::time::Duration::from_secs(10));
+
+        // sources that are too long are not used
+        let long = format!("{} x", "a".repeat(MAX_EXAMPLE_LENGTH));
+        let examples = [example(&[&long], "x")];
+        assert_eq!(FlashFillProgram::learn(&examples), None);
+    }
+
+    #[test]
+    fn test_flash_fill_no_program() {
+        let examples = [example(&["a"], "b"), example(&["c"], "d")];
+        assert_eq!(FlashFillProgram::learn(&examples), None);
+        assert_eq!(FlashFillProgram::learn(&[]), None);
+    }
+}
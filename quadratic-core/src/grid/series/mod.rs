@@ -9,6 +9,7 @@ use crate::{CellValue, Pos};
 
 pub mod date_series;
 pub mod date_time_series;
+pub mod flash_fill;
 pub mod number_series;
 pub mod string_series;
 pub mod time_series;
@@ -52,6 +53,15 @@ fn copy_series(options: SeriesOptions) -> Vec<(CellValue, Option<Pos>)> {
 
 /// Finds auto complete series.
 pub fn find_auto_complete(options: SeriesOptions) -> Vec<(CellValue, Option<Pos>)> {
+    find_auto_complete_with_custom_lists(options, &[])
+}
+
+/// Finds auto complete series. Text is also matched against the workbook's
+/// custom lists.
+pub fn find_auto_complete_with_custom_lists(
+    options: SeriesOptions,
+    custom_lists: &[Vec<String>],
+) -> Vec<(CellValue, Option<Pos>)> {
     // if cells are missing, just copy series
     if options.series.iter().all(|s| s.0 == CellValue::Blank) {
         return copy_series(options);
@@ -89,7 +99,7 @@ pub fn find_auto_complete(options: SeriesOptions) -> Vec<(CellValue, Option<Pos>
     {
         find_date_time_series(&options)
     } else {
-        find_string_series(&options)
+        find_string_series(&options, custom_lists)
     };
 
     if let Some(results) = results {
@@ -119,14 +119,23 @@ fn get_series_next_key(last_key: &str, all_keys: &&[&str], negative: bool) -> Re
     Ok(next_key.to_string())
 }
 
-pub fn find_string_series(options: &SeriesOptions) -> Option<Vec<CellValue>> {
+/// Finds a series of text values. Custom lists are checked before the
+/// built-in series.
+pub fn find_string_series(
+    options: &SeriesOptions,
+    custom_lists: &[Vec<String>],
+) -> Option<Vec<CellValue>> {
     let mut results: Vec<CellValue> = vec![];
     let SeriesOptions {
         series,
         spaces,
         negative,
     } = options;
-    let text_series: &[&[&str]] = &[
+    let custom_lists = custom_lists
+        .iter()
+        .map(|list| list.iter().map(String::as_str).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let built_in_series: [&[&str]; 10] = [
         &ALPHABET_LOWER,
         &ALPHABET_UPPER,
         &MONTHS_SHORT,
@@ -138,6 +147,11 @@ pub fn find_string_series(options: &SeriesOptions) -> Option<Vec<CellValue>> {
         &DAYS_FULL,
         &DAYS_FULL_UPPER,
     ];
+    let text_series = custom_lists
+        .iter()
+        .map(Vec::as_slice)
+        .chain(built_in_series)
+        .collect::<Vec<_>>();
 
     let mut possible_text_series = text_series.iter().map(|_| Some(vec![])).collect::<Vec<_>>();
 
@@ -4,8 +4,13 @@ use std::str::FromStr;
 use anyhow::{Result, anyhow};
 use bigdecimal::{BigDecimal, RoundingMode};
 use borders::Borders;
+use comments::Comments;
+use conditional_formats::ConditionalFormats;
 use indexmap::IndexMap;
 use lazy_static::lazy_static;
+use merge_cells::MergeCells;
+use outlines::Outlines;
+use protection::SheetProtection;
 use rand::Rng;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
@@ -14,6 +19,7 @@ use validations::Validations;
 use super::bounds::GridBounds;
 use super::column::Column;
 use super::data_table::DataTable;
+use super::formatting::VERTICAL_TEXT;
 use super::ids::SheetId;
 use super::js_types::{CellFormatSummary, CellType, JsCellValue, JsCellValuePos};
 use super::resize::ResizeMap;
@@ -33,9 +39,15 @@ mod chart;
 pub mod clipboard;
 pub mod code;
 pub mod col_row;
+pub mod comments;
+pub mod conditional_formats;
+pub mod data_cleaning;
 pub mod data_table;
 pub mod formats;
 pub mod keyboard;
+pub mod merge_cells;
+pub mod outlines;
+pub mod protection;
 pub mod rendering;
 pub mod rendering_date_time;
 pub mod row_resize;
@@ -43,6 +55,7 @@ pub mod search;
 #[cfg(test)]
 pub mod sheet_test;
 pub mod summarize;
+pub mod text_to_columns;
 pub mod validations;
 
 const SHEET_NAME_VALID_CHARS: &str = r#"^[a-zA-Z0-9_\-(][a-zA-Z0-9_\- .()\p{Pd}]*[a-zA-Z0-9_\-)]$"#;
@@ -82,6 +95,20 @@ pub struct Sheet {
     pub(super) rows_resize: ResizeMap,
 
     pub borders: Borders,
+
+    pub merge_cells: MergeCells,
+
+    pub outlines: Outlines,
+
+    pub comments: Comments,
+
+    pub conditional_formats: ConditionalFormats,
+
+    pub protection: SheetProtection,
+
+    /// Number of columns and rows frozen at the left and top of the sheet.
+    pub frozen_columns: u32,
+    pub frozen_rows: u32,
 }
 impl Sheet {
     /// Constructs a new empty sheet.
@@ -100,6 +127,13 @@ impl Sheet {
             validations: Validations::default(),
             rows_resize: ResizeMap::default(),
             borders: Borders::default(),
+            merge_cells: MergeCells::default(),
+            outlines: Outlines::default(),
+            comments: Comments::default(),
+            conditional_formats: ConditionalFormats::default(),
+            protection: SheetProtection::default(),
+            frozen_columns: 0,
+            frozen_rows: 0,
         }
     }
 
@@ -429,13 +463,13 @@ impl Sheet {
                         None => CellValue::Blank,
                     }
                 }
-                other => other.clone(),
+                other => other.to_formula_value(),
             }
         } else if let Some(value) = self.get_code_cell_value(pos) {
             if matches!(value, CellValue::Html(_) | CellValue::Image(_)) {
                 CellValue::Blank
             } else {
-                value
+                value.to_formula_value()
             }
         } else {
             CellValue::Blank
@@ -522,6 +556,25 @@ impl Sheet {
             if let Some(date_time) = format.date_time {
                 values.push(format!("date time is {}", date_time.clone()));
             }
+            if let Some(font_size) = format.font_size {
+                values.push(format!("font size is {}", font_size));
+            }
+            if let Some(font_family) = format.font_family {
+                values.push(format!("font family is {}", font_family));
+            }
+            if let Some(text_rotation) = format.text_rotation {
+                if text_rotation == VERTICAL_TEXT {
+                    values.push("text is vertical".to_string());
+                } else {
+                    values.push(format!("text rotation is {}", text_rotation));
+                }
+            }
+            if let Some(indent) = format.indent {
+                values.push(format!("indent is {}", indent));
+            }
+            if let Some(style) = format.style {
+                values.push(format!("style is {}", style));
+            }
 
             Some(values.join(", "))
         }
@@ -534,7 +587,7 @@ impl Sheet {
             .display_value(pos)
             .and_then(|cell_value| match cell_value {
                 CellValue::Date(_) => Some(CellType::Date),
-                CellValue::DateTime(_) => Some(CellType::DateTime),
+                CellValue::DateTime(_) | CellValue::DateTimeTz(_) => Some(CellType::DateTime),
                 _ => None,
             });
         CellFormatSummary {
@@ -550,6 +603,11 @@ impl Sheet {
             cell_type,
             underline: format.underline,
             strike_through: format.strike_through,
+            font_size: format.font_size,
+            font_family: format.font_family,
+            text_rotation: format.text_rotation,
+            indent: format.indent,
+            style: format.style,
         }
     }
 
@@ -636,13 +694,7 @@ impl Sheet {
         let mut rows = vec![];
         if let Some((start, end)) = self.column_bounds(x, true) {
             for y in start..=end {
-                if self.has_content(Pos { x, y })
-                    && self
-                        .formats
-                        .wrap
-                        .get(Pos { x, y })
-                        .is_some_and(|wrap| wrap == CellWrap::Wrap)
-                {
+                if self.has_content(Pos { x, y }) && self.formats.needs_auto_height(Pos { x, y }) {
                     rows.push(y);
                 }
             }
@@ -655,11 +707,7 @@ impl Sheet {
         for y in rect.y_range() {
             for x in rect.x_range() {
                 if (include_blanks || self.has_content(Pos { x, y }))
-                    && self
-                        .formats
-                        .wrap
-                        .get((x, y).into())
-                        .is_some_and(|wrap| wrap == CellWrap::Wrap)
+                    && self.formats.needs_auto_height(Pos { x, y })
                 {
                     rows.push(y);
                     break;
@@ -15,6 +15,8 @@ impl Borders {
             right: Some(self.right.get_update_for_selection(selection)),
             top: Some(self.top.get_update_for_selection(selection)),
             bottom: Some(self.bottom.get_update_for_selection(selection)),
+            diagonal_up: Some(self.diagonal_up.get_update_for_selection(selection)),
+            diagonal_down: Some(self.diagonal_down.get_update_for_selection(selection)),
         })
     }
 }
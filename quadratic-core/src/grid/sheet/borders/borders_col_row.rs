@@ -11,6 +11,8 @@ impl Borders {
         self.right.insert_column(column, copy_formats);
         self.top.insert_column(column, copy_formats);
         self.bottom.insert_column(column, copy_formats);
+        self.diagonal_up.insert_column(column, copy_formats);
+        self.diagonal_down.insert_column(column, copy_formats);
     }
 
     pub fn remove_column(&mut self, column: i64) -> BordersUpdates {
@@ -24,6 +26,8 @@ impl Borders {
             right: remove_column_item(&mut self.right),
             top: remove_column_item(&mut self.top),
             bottom: remove_column_item(&mut self.bottom),
+            diagonal_up: remove_column_item(&mut self.diagonal_up),
+            diagonal_down: remove_column_item(&mut self.diagonal_down),
         }
     }
 
@@ -38,6 +42,8 @@ impl Borders {
             right: copy_column_item(&self.right),
             top: copy_column_item(&self.top),
             bottom: copy_column_item(&self.bottom),
+            diagonal_up: copy_column_item(&self.diagonal_up),
+            diagonal_down: copy_column_item(&self.diagonal_down),
         };
 
         if updates.is_empty() {
@@ -52,6 +58,8 @@ impl Borders {
         self.right.insert_row(row, copy_formats);
         self.top.insert_row(row, copy_formats);
         self.bottom.insert_row(row, copy_formats);
+        self.diagonal_up.insert_row(row, copy_formats);
+        self.diagonal_down.insert_row(row, copy_formats);
     }
 
     pub fn remove_row(&mut self, row: i64) -> BordersUpdates {
@@ -65,6 +73,8 @@ impl Borders {
             right: remove_row_item(&mut self.right),
             top: remove_row_item(&mut self.top),
             bottom: remove_row_item(&mut self.bottom),
+            diagonal_up: remove_row_item(&mut self.diagonal_up),
+            diagonal_down: remove_row_item(&mut self.diagonal_down),
         }
     }
 
@@ -78,6 +88,8 @@ impl Borders {
             right: copy_row_item(&self.right),
             top: copy_row_item(&self.top),
             bottom: copy_row_item(&self.bottom),
+            diagonal_up: copy_row_item(&self.diagonal_up),
+            diagonal_down: copy_row_item(&self.diagonal_down),
         };
 
         if updates.is_empty() {
@@ -29,6 +29,19 @@ impl Borders {
             }
         });
 
+        self.diagonal_up.to_rects().for_each(|(x1, y1, x2, y2, _)| {
+            if let (Some(x2), Some(y2)) = (x2, y2) {
+                bounds.add_rect(Rect::new(x1, y1, x2, y2));
+            }
+        });
+        self.diagonal_down
+            .to_rects()
+            .for_each(|(x1, y1, x2, y2, _)| {
+                if let (Some(x2), Some(y2)) = (x2, y2) {
+                    bounds.add_rect(Rect::new(x1, y1, x2, y2));
+                }
+            });
+
         bounds.into()
     }
 
@@ -38,6 +51,8 @@ impl Borders {
             && self.bottom.is_all_default()
             && self.left.is_all_default()
             && self.right.is_all_default()
+            && self.diagonal_up.is_all_default()
+            && self.diagonal_down.is_all_default()
     }
 
     /// Returns true if the border and update are the same.
@@ -86,6 +101,24 @@ impl Borders {
                 return false;
             }
         }
+        if let Some(update_diagonal_up) = border_update.diagonal_up.as_ref()
+            && self
+                .diagonal_up
+                .zip_any(update_diagonal_up, |border, update| {
+                    !Borders::same_border_and_update(border, update)
+                })
+        {
+            return false;
+        }
+        if let Some(update_diagonal_down) = border_update.diagonal_down.as_ref()
+            && self
+                .diagonal_down
+                .zip_any(update_diagonal_down, |border, update| {
+                    !Borders::same_border_and_update(border, update)
+                })
+        {
+            return false;
+        }
 
         true
     }
@@ -97,6 +130,8 @@ impl Borders {
             BorderSide::Bottom => self.bottom.get(pos).map(|b| b.into()),
             BorderSide::Left => self.left.get(pos).map(|b| b.into()),
             BorderSide::Right => self.right.get(pos).map(|b| b.into()),
+            BorderSide::DiagonalUp => self.diagonal_up.get(pos).map(|b| b.into()),
+            BorderSide::DiagonalDown => self.diagonal_down.get(pos).map(|b| b.into()),
         }
     }
 }
@@ -147,6 +182,7 @@ mod tests {
                 bottom: None,
                 left: None,
                 right: None,
+                ..Default::default()
             },
         );
         assert!(!borders.is_toggle_borders(&border_update));
@@ -4,7 +4,7 @@ use crate::{
     a1::UNBOUNDED,
     grid::{
         DataTable,
-        sheet::borders::{JsBorderHorizontal, JsBorderVertical},
+        sheet::borders::{JsBorderDiagonal, JsBorderHorizontal, JsBorderVertical},
     },
 };
 
@@ -300,11 +300,60 @@ impl Borders {
             Some(vertical_vec)
         }
     }
+
+    /// Returns diagonal borders for rendering. Diagonals stay within their
+    /// cells, so there is no need to resolve overlaps with neighbors.
+    pub(crate) fn diagonal_borders(
+        &self,
+        table: Option<(Pos, &DataTable)>,
+    ) -> Option<Vec<JsBorderDiagonal>> {
+        let table_rect = table.map(|(pos, table)| {
+            let mut table_rect = table.output_rect(pos, true);
+            // use table data bounds for borders, exclude table name and column headers
+            table_rect.min.y += table.y_adjustment(true);
+            table_rect
+        });
+
+        let mut diagonal_vec = vec![];
+        for (up, diagonal) in [(true, &self.diagonal_up), (false, &self.diagonal_down)] {
+            diagonal.into_iter().for_each(|(x1, y1, x2, y2, border)| {
+                if border.line == CellBorderLine::Clear {
+                    return;
+                }
+                let (x1, y1, x2, y2) = if let Some(table_rect) = table_rect {
+                    let adjust_x = |x: u64| x.saturating_add_signed(table_rect.min.x - 1);
+                    let adjust_y = |y: u64| y.saturating_add_signed(table_rect.min.y - 1);
+                    (
+                        adjust_x(x1),
+                        adjust_y(y1),
+                        Some(x2.map_or(table_rect.max.x as u64, adjust_x)),
+                        Some(y2.map_or(table_rect.max.y as u64, adjust_y)),
+                    )
+                } else {
+                    (x1, y1, x2, y2)
+                };
+                diagonal_vec.push(JsBorderDiagonal {
+                    color: border.color,
+                    line: border.line,
+                    x: x1 as i64,
+                    y: y1 as i64,
+                    width: x2.map(|x2| x2 as i64 - x1 as i64 + 1),
+                    height: y2.map(|y2| y2 as i64 - y1 as i64 + 1),
+                    up,
+                });
+            });
+        }
+        if diagonal_vec.is_empty() {
+            None
+        } else {
+            Some(diagonal_vec)
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{a1::A1Selection, controller::GridController, grid::SheetId};
+    use crate::{a1::A1Selection, color::Rgba, controller::GridController, grid::SheetId};
 
     use super::*;
 
@@ -398,6 +447,37 @@ mod tests {
         assert_eq!(vertical.len(), 1);
     }
 
+    #[test]
+    fn test_render_borders_diagonal() {
+        let mut gc = GridController::test();
+        let sheet_id = gc.sheet_ids()[0];
+        gc.set_borders(
+            A1Selection::test_a1("B2:C3"),
+            BorderSelection::DiagonalDown,
+            Some(BorderStyle {
+                line: CellBorderLine::MediumDashDot,
+                ..Default::default()
+            }),
+            None,
+        );
+        let sheet = gc.sheet(sheet_id);
+        assert!(sheet.borders.horizontal_borders(None).is_none());
+        assert!(sheet.borders.vertical_borders(None).is_none());
+        let diagonal = sheet.borders.diagonal_borders(None).unwrap();
+        assert_eq!(
+            diagonal,
+            vec![JsBorderDiagonal {
+                color: Rgba::default(),
+                line: CellBorderLine::MediumDashDot,
+                x: 2,
+                y: 2,
+                width: Some(2),
+                height: Some(2),
+                up: false,
+            }]
+        );
+    }
+
     #[test]
     fn test_render_borders_outer() {
         let mut gc = GridController::test();
@@ -17,6 +17,8 @@ impl Borders {
             right: Self::set_borders_item(&borders.right, &mut self.right),
             top: Self::set_borders_item(&borders.top, &mut self.top),
             bottom: Self::set_borders_item(&borders.bottom, &mut self.bottom),
+            diagonal_up: Self::set_borders_item(&borders.diagonal_up, &mut self.diagonal_up),
+            diagonal_down: Self::set_borders_item(&borders.diagonal_down, &mut self.diagonal_down),
         }
     }
 
@@ -27,6 +29,8 @@ impl Borders {
             right: Self::set_borders_item(&updates.right, &mut self.right),
             top: Self::set_borders_item(&updates.top, &mut self.top),
             bottom: Self::set_borders_item(&updates.bottom, &mut self.bottom),
+            diagonal_up: Self::set_borders_item(&updates.diagonal_up, &mut self.diagonal_up),
+            diagonal_down: Self::set_borders_item(&updates.diagonal_down, &mut self.diagonal_down),
         }
     }
 }
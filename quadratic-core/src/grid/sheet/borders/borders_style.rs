@@ -20,6 +20,8 @@ pub enum BorderSelection {
     Top,
     Right,
     Bottom,
+    DiagonalUp,
+    DiagonalDown,
     Clear,
 }
 
@@ -50,6 +52,15 @@ pub enum CellBorderLine {
     Dashed,
     Double,
 
+    // the remaining Excel line styles
+    Hair,
+    MediumDashed,
+    DashDot,
+    MediumDashDot,
+    DashDotDot,
+    MediumDashDotDot,
+    SlantDashDot,
+
     // this is needed to ensure that the border is cleared when compared to
     // neighbors or all, columns, rows
     Clear,
@@ -64,9 +75,56 @@ impl CellBorderLine {
             CellBorderLine::Dotted => "1px dashed",
             CellBorderLine::Dashed => "1px dotted",
             CellBorderLine::Double => "3px double",
+            CellBorderLine::Hair => "1px dotted",
+            CellBorderLine::MediumDashed => "2px dashed",
+            CellBorderLine::DashDot => "1px dashed",
+            CellBorderLine::MediumDashDot => "2px dashed",
+            CellBorderLine::DashDotDot => "1px dashed",
+            CellBorderLine::MediumDashDotDot => "2px dashed",
+            CellBorderLine::SlantDashDot => "2px dashed",
             CellBorderLine::Clear => "0px solid",
         }
     }
+
+    /// Returns the width of the line in pixels.
+    pub fn thickness(&self) -> u8 {
+        match self {
+            CellBorderLine::Clear => 0,
+            CellBorderLine::Line1
+            | CellBorderLine::Dotted
+            | CellBorderLine::Dashed
+            | CellBorderLine::Hair
+            | CellBorderLine::DashDot
+            | CellBorderLine::DashDotDot => 1,
+            CellBorderLine::Line2
+            | CellBorderLine::MediumDashed
+            | CellBorderLine::MediumDashDot
+            | CellBorderLine::MediumDashDotDot
+            | CellBorderLine::SlantDashDot => 2,
+            CellBorderLine::Line3 | CellBorderLine::Double => 3,
+        }
+    }
+
+    /// Converts an Excel border style (eg, `mediumDashDot`). Returns None for
+    /// `none` and unknown styles.
+    pub fn from_excel(style: &str) -> Option<Self> {
+        match style {
+            "thin" => Some(CellBorderLine::Line1),
+            "medium" => Some(CellBorderLine::Line2),
+            "thick" => Some(CellBorderLine::Line3),
+            "dotted" => Some(CellBorderLine::Dotted),
+            "dashed" => Some(CellBorderLine::Dashed),
+            "double" => Some(CellBorderLine::Double),
+            "hair" => Some(CellBorderLine::Hair),
+            "mediumDashed" => Some(CellBorderLine::MediumDashed),
+            "dashDot" => Some(CellBorderLine::DashDot),
+            "mediumDashDot" => Some(CellBorderLine::MediumDashDot),
+            "dashDotDot" => Some(CellBorderLine::DashDotDot),
+            "mediumDashDotDot" => Some(CellBorderLine::MediumDashDotDot),
+            "slantDashDot" => Some(CellBorderLine::SlantDashDot),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq, TS)]
@@ -75,6 +133,8 @@ pub enum BorderSide {
     Bottom,
     Left,
     Right,
+    DiagonalUp,
+    DiagonalDown,
 }
 
 #[derive(Default, Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq, Hash, TS)]
@@ -150,6 +210,14 @@ pub struct BorderStyleCell {
     pub bottom: Option<BorderStyleTimestamp>,
     pub left: Option<BorderStyleTimestamp>,
     pub right: Option<BorderStyleTimestamp>,
+
+    /// Diagonal from the bottom-left to the top-right corner.
+    #[serde(default)]
+    pub diagonal_up: Option<BorderStyleTimestamp>,
+
+    /// Diagonal from the top-left to the bottom-right corner.
+    #[serde(default)]
+    pub diagonal_down: Option<BorderStyleTimestamp>,
 }
 impl BorderStyleCell {
     #[cfg(test)]
@@ -159,11 +227,18 @@ impl BorderStyleCell {
             bottom: Some(BorderStyleTimestamp::default()),
             left: Some(BorderStyleTimestamp::default()),
             right: Some(BorderStyleTimestamp::default()),
+            diagonal_up: None,
+            diagonal_down: None,
         }
     }
 
     pub fn is_empty(&self) -> bool {
-        self.top.is_none() && self.bottom.is_none() && self.left.is_none() && self.right.is_none()
+        self.top.is_none()
+            && self.bottom.is_none()
+            && self.left.is_none()
+            && self.right.is_none()
+            && self.diagonal_up.is_none()
+            && self.diagonal_down.is_none()
     }
 }
 
@@ -230,8 +305,32 @@ impl JsBorderVertical {
     }
 }
 
+/// A diagonal border across a rect of cells. Each cell in the rect has its
+/// own diagonal line.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, TS)]
+pub struct JsBorderDiagonal {
+    pub color: Rgba,
+    pub line: CellBorderLine,
+    pub x: i64,
+    pub y: i64,
+    pub width: Option<i64>,
+    pub height: Option<i64>,
+
+    /// true for bottom-left to top-right, false for top-left to bottom-right
+    pub up: bool,
+}
+
+impl JsBorderDiagonal {
+    pub fn translate_in_place(&mut self, x: i64, y: i64) {
+        self.x += x;
+        self.y += y;
+    }
+}
+
 #[derive(Default, Serialize, Deserialize, Debug, TS)]
 pub struct JsBordersSheet {
     pub horizontal: Option<Vec<JsBorderHorizontal>>,
     pub vertical: Option<Vec<JsBorderVertical>>,
+    #[serde(default)]
+    pub diagonal: Option<Vec<JsBorderDiagonal>>,
 }
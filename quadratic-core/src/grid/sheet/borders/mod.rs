@@ -30,6 +30,13 @@ pub struct Borders {
     pub(crate) right: BordersType,
     pub(crate) top: BordersType,
     pub(crate) bottom: BordersType,
+
+    /// Diagonals are drawn within their cell and do not interact with
+    /// neighboring cells.
+    #[serde(default)]
+    pub(crate) diagonal_up: BordersType,
+    #[serde(default)]
+    pub(crate) diagonal_down: BordersType,
 }
 
 impl Borders {
@@ -40,6 +47,8 @@ impl Borders {
             right: self.right.get(pos),
             top: self.top.get(pos),
             bottom: self.bottom.get(pos),
+            diagonal_up: self.diagonal_up.get(pos),
+            diagonal_down: self.diagonal_down.get(pos),
         }
     }
 
@@ -58,6 +67,8 @@ impl Borders {
             bottom: self.bottom.get(pos).map(Some).unwrap_or(clear),
             left: self.left.get(pos).map(Some).unwrap_or(clear),
             right: self.right.get(pos).map(Some).unwrap_or(clear),
+            diagonal_up: self.diagonal_up.get(pos).map(Some).unwrap_or(clear),
+            diagonal_down: self.diagonal_down.get(pos).map(Some).unwrap_or(clear),
         }
     }
 
@@ -68,6 +79,8 @@ impl Borders {
             BorderSide::Bottom => self.bottom.get(pos).map(|b| b.into()),
             BorderSide::Left => self.left.get(pos).map(|b| b.into()),
             BorderSide::Right => self.right.get(pos).map(|b| b.into()),
+            BorderSide::DiagonalUp => self.diagonal_up.get(pos).map(|b| b.into()),
+            BorderSide::DiagonalDown => self.diagonal_down.get(pos).map(|b| b.into()),
         }
     }
 
@@ -76,6 +89,8 @@ impl Borders {
         self.bottom.set(pos, style.bottom);
         self.left.set(pos, style.left);
         self.right.set(pos, style.right);
+        self.diagonal_up.set(pos, style.diagonal_up);
+        self.diagonal_down.set(pos, style.diagonal_down);
     }
 
     pub fn translate_in_place(&mut self, x: i64, y: i64) {
@@ -83,6 +98,8 @@ impl Borders {
         self.right.translate_in_place(x, y);
         self.top.translate_in_place(x, y);
         self.bottom.translate_in_place(x, y);
+        self.diagonal_up.translate_in_place(x, y);
+        self.diagonal_down.translate_in_place(x, y);
     }
 
     #[cfg(test)]
@@ -119,6 +136,20 @@ impl Borders {
         if bottom_1 != bottom_2 {
             return false;
         }
+        let diagonal_up_1: Contiguous2D<Option<BorderStyle>> =
+            borders.diagonal_up.map_ref(|d| d.map(|d| d.into()));
+        let diagonal_up_2: Contiguous2D<Option<BorderStyle>> =
+            other.diagonal_up.map_ref(|d| d.map(|d| d.into()));
+        if diagonal_up_1 != diagonal_up_2 {
+            return false;
+        }
+        let diagonal_down_1: Contiguous2D<Option<BorderStyle>> =
+            borders.diagonal_down.map_ref(|d| d.map(|d| d.into()));
+        let diagonal_down_2: Contiguous2D<Option<BorderStyle>> =
+            other.diagonal_down.map_ref(|d| d.map(|d| d.into()));
+        if diagonal_down_1 != diagonal_down_2 {
+            return false;
+        }
         true
     }
 }
@@ -131,6 +162,10 @@ pub struct BordersUpdates {
     pub(crate) right: BordersUpdatesType,
     pub(crate) top: BordersUpdatesType,
     pub(crate) bottom: BordersUpdatesType,
+    #[serde(default)]
+    pub(crate) diagonal_up: BordersUpdatesType,
+    #[serde(default)]
+    pub(crate) diagonal_down: BordersUpdatesType,
 }
 
 impl BordersUpdates {
@@ -155,6 +190,16 @@ impl BordersUpdates {
                 .get_or_insert_with(Default::default)
                 .set(pos, Some(ClearOption::Some(right)));
         }
+        if let Some(diagonal_up) = style.diagonal_up {
+            self.diagonal_up
+                .get_or_insert_with(Default::default)
+                .set(pos, Some(ClearOption::Some(diagonal_up)));
+        }
+        if let Some(diagonal_down) = style.diagonal_down {
+            self.diagonal_down
+                .get_or_insert_with(Default::default)
+                .set(pos, Some(ClearOption::Some(diagonal_down)));
+        }
     }
 
     /// Returns true if there are no updates.
@@ -163,6 +208,31 @@ impl BordersUpdates {
             && self.right.as_ref().is_none_or(|c| c.is_all_default())
             && self.top.as_ref().is_none_or(|c| c.is_all_default())
             && self.bottom.as_ref().is_none_or(|c| c.is_all_default())
+            && self.diagonal_up.as_ref().is_none_or(|c| c.is_all_default())
+            && self
+                .diagonal_down
+                .as_ref()
+                .is_none_or(|c| c.is_all_default())
+    }
+
+    /// Returns the (possibly unbounded) rects changed by the updates.
+    pub fn to_rects(&self) -> Vec<Rect> {
+        [
+            &self.left,
+            &self.right,
+            &self.top,
+            &self.bottom,
+            &self.diagonal_up,
+            &self.diagonal_down,
+        ]
+        .into_iter()
+        .flatten()
+        .flat_map(|updates| {
+            updates.to_rects().map(|(x1, y1, x2, y2, _)| {
+                Rect::new(x1, y1, x2.unwrap_or(i64::MAX), y2.unwrap_or(i64::MAX))
+            })
+        })
+        .collect()
     }
 
     pub fn intersects(&self, rect: Rect) -> bool {
@@ -176,6 +246,14 @@ impl BordersUpdates {
                 .bottom
                 .as_ref()
                 .is_some_and(|bottom| bottom.intersects(rect))
+            || self
+                .diagonal_up
+                .as_ref()
+                .is_some_and(|diagonal_up| diagonal_up.intersects(rect))
+            || self
+                .diagonal_down
+                .as_ref()
+                .is_some_and(|diagonal_down| diagonal_down.intersects(rect))
     }
 
     pub fn translate_in_place(&mut self, x: i64, y: i64) {
@@ -191,6 +269,12 @@ impl BordersUpdates {
         if let Some(bottom) = self.bottom.as_mut() {
             bottom.translate_in_place(x, y);
         }
+        if let Some(diagonal_up) = self.diagonal_up.as_mut() {
+            diagonal_up.translate_in_place(x, y);
+        }
+        if let Some(diagonal_down) = self.diagonal_down.as_mut() {
+            diagonal_down.translate_in_place(x, y);
+        }
     }
 }
 
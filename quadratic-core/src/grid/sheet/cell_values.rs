@@ -8,6 +8,7 @@ use crate::{
         active_transactions::pending_transaction::PendingTransaction,
         operations::operation::Operation,
     },
+    grid::Locale,
 };
 
 use super::Sheet;
@@ -64,7 +65,7 @@ impl Sheet {
                             sheet_pos,
                             validation_id: Some(validation.id),
                         });
-                } else if self.validations.has_warning(pos) {
+                } else if self.validations.warnings.contains_key(&pos) {
                     transaction
                         .forward_operations
                         .push(Operation::SetValidationWarning {
@@ -112,14 +113,31 @@ impl Sheet {
     /// numbers. (We no longer format numbers in Rust because the client needs to
     /// be able to change the precision of the number when rendering.)
     pub fn rendered_value(&self, pos: Pos) -> Option<String> {
+        self.rendered_value_with_locale(pos, &Locale::default())
+    }
+
+    /// Returns the rendered value of the cell at the given position using the
+    /// locale's separators and default date and time formats.
+    pub fn rendered_value_with_locale(&self, pos: Pos, locale: &Locale) -> Option<String> {
         let value = self.display_value(pos)?;
         match value {
             CellValue::Number(_) => {
                 let numeric_format = self.formats.numeric_format.get(pos);
                 let numeric_decimals = self.formats.numeric_decimals.get(pos);
                 let numeric_commas = self.formats.numeric_commas.get(pos);
-                Some(value.to_number_display(numeric_format, numeric_decimals, numeric_commas))
+                Some(locale.localize_number(&value.to_number_display(
+                    numeric_format,
+                    numeric_decimals,
+                    numeric_commas,
+                )))
             }
+            CellValue::Date(_) | CellValue::DateTime(_) | CellValue::Time(_) => Some(
+                Self::value_date_time(&value, Some(locale.date_time_format())),
+            ),
+            CellValue::DateTimeTz(_) => Some(Self::value_date_time(
+                &value,
+                Some(locale.date_time_tz_format()),
+            )),
             _ => Some(value.to_display()),
         }
     }
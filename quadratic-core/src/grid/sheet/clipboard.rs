@@ -5,8 +5,10 @@ use crate::cell_values::CellValues;
 use crate::color::Rgba;
 use crate::controller::operations::clipboard::{Clipboard, ClipboardOperation, ClipboardOrigin};
 use crate::grid::Sheet;
+use crate::grid::formats::SheetFormatUpdates;
 use crate::grid::js_types::JsClipboard;
-use crate::{Pos, Rect};
+use crate::grid::sheet::borders::BordersUpdates;
+use crate::{CellValue, Pos, Rect};
 
 impl Sheet {
     /// Copies the selection to the clipboard.
@@ -18,6 +20,26 @@ impl Sheet {
         a1_context: &A1Context,
         clipboard_operation: ClipboardOperation,
         include_plain_text: bool,
+    ) -> Result<JsClipboard, String> {
+        self.copy_cells_to_clipboard(
+            selection,
+            a1_context,
+            clipboard_operation,
+            include_plain_text,
+            false,
+        )
+    }
+
+    /// Copies the selection to the clipboard. When `visible_only` is set,
+    /// hidden columns and rows are skipped and the visible cells are copied
+    /// as a single block.
+    fn copy_cells_to_clipboard(
+        &self,
+        selection: &A1Selection,
+        a1_context: &A1Context,
+        clipboard_operation: ClipboardOperation,
+        include_plain_text: bool,
+        visible_only: bool,
     ) -> Result<JsClipboard, String> {
         let mut clipboard_origin = ClipboardOrigin::default(selection.sheet_id);
         let mut plain_text = String::new();
@@ -26,14 +48,35 @@ impl Sheet {
         let mut values = CellValues::default();
         let mut data_tables = IndexMap::new();
         let mut sheet_bounds: Option<Rect> = None;
+        let mut merge_cells = vec![];
+        let mut visible_formats = SheetFormatUpdates::default();
+        let mut visible_borders = BordersUpdates::default();
 
         if let Some(bounds) = self.selection_bounds(selection, true, true, a1_context) {
+            let columns = bounds
+                .x_range()
+                .filter(|x| !visible_only || !self.offsets.is_column_hidden(*x))
+                .collect::<Vec<_>>();
+            let rows = bounds
+                .y_range()
+                .filter(|y| !visible_only || !self.offsets.is_row_hidden(*y))
+                .collect::<Vec<_>>();
+            if columns.is_empty() || rows.is_empty() {
+                return Err("All cells in the selection are hidden".to_string());
+            }
+
             clipboard_origin.x = bounds.min.x;
             clipboard_origin.y = bounds.min.y;
-            sheet_bounds = Some(bounds);
+            let copied_bounds = Rect::new(
+                bounds.min.x,
+                bounds.min.y,
+                bounds.min.x + columns.len() as i64 - 1,
+                bounds.min.y + rows.len() as i64 - 1,
+            );
+            sheet_bounds = Some(copied_bounds);
 
-            for y in bounds.y_range() {
-                if y != bounds.min.y {
+            for (new_y, &y) in rows.iter().enumerate() {
+                if new_y != 0 {
                     if include_plain_text {
                         plain_text.push('\n');
                     }
@@ -42,8 +85,8 @@ impl Sheet {
 
                 html_body.push_str("<tr>");
 
-                for x in bounds.x_range() {
-                    if x != bounds.min.x {
+                for (new_x, &x) in columns.iter().enumerate() {
+                    if new_x != 0 {
                         if include_plain_text {
                             plain_text.push('\t');
                         }
@@ -62,12 +105,27 @@ impl Sheet {
                     // the CellValue at the cell (ignoring code_runs)
                     let real_value = self.cell_value(pos);
 
-                    let new_x = (x - bounds.min.x) as u32;
-                    let new_y = (y - bounds.min.y) as u32;
+                    let new_x = new_x as u32;
+                    let new_y = new_y as u32;
 
                     // create quadratic clipboard values
                     if let Some(real_value) = real_value {
                         cells.set(new_x, new_y, real_value);
+                    } else if visible_only && let Some(simple_value) = &simple_value {
+                        // data tables are not copied when skipping hidden
+                        // cells, so their visible values are copied instead
+                        cells.set(new_x, new_y, simple_value.clone());
+                    }
+
+                    // formats and borders are moved next to each other with
+                    // the visible cells
+                    if visible_only {
+                        let new_pos = Pos {
+                            x: bounds.min.x + new_x as i64,
+                            y: bounds.min.y + new_y as i64,
+                        };
+                        visible_formats.set_format_cell(new_pos, self.formats.format(pos).into());
+                        visible_borders.set_style_cell(new_pos, self.borders.get_style_cell(pos));
                     }
 
                     // create quadratic clipboard value-only
@@ -199,11 +257,39 @@ impl Sheet {
                         if include_plain_text {
                             plain_text.push_str(&value.to_string());
                         }
-                        html_body.push_str(&value.to_string());
+                        match value {
+                            CellValue::Hyperlink(link) => html_body.push_str(&link.to_html()),
+                            CellValue::RichText(rich_text) => {
+                                html_body.push_str(&rich_text.to_html());
+                            }
+                            _ => html_body.push_str(&value.to_string()),
+                        }
                     }
                 }
             }
 
+            if visible_only {
+                return Ok(Self::encode_clipboard(
+                    Clipboard {
+                        cells,
+                        formats: Some(visible_formats),
+                        borders: Some(visible_borders),
+                        values,
+                        w: columns.len() as u32,
+                        h: rows.len() as u32,
+                        origin: clipboard_origin,
+                        selection: A1Selection::from_rect(copied_bounds.to_sheet_rect(self.id)),
+                        validations: None,
+                        merge_cells,
+                        comments: vec![],
+                        data_tables,
+                        operation: clipboard_operation,
+                    },
+                    plain_text,
+                    html_body,
+                ));
+            }
+
             let include_code_table_values = matches!(clipboard_operation, ClipboardOperation::Cut);
             let data_tables_in_rect = self.data_tables_and_cell_values_in_rect(
                 &bounds,
@@ -215,6 +301,13 @@ impl Sheet {
             );
 
             data_tables.extend(data_tables_in_rect);
+
+            merge_cells = self
+                .merge_cells
+                .in_rect(bounds)
+                .into_iter()
+                .filter(|merge| bounds.contains_rect(merge))
+                .collect();
         }
 
         let formats = self.formats.to_clipboard(selection, self, a1_context).ok();
@@ -223,6 +316,16 @@ impl Sheet {
             .validations
             .to_clipboard(selection, &clipboard_origin, a1_context);
 
+        let comments = if matches!(clipboard_operation, ClipboardOperation::Cut) {
+            self.comments
+                .threads()
+                .filter(|(pos, _)| selection.contains_pos(*pos, a1_context))
+                .map(|(pos, thread)| (pos, thread.clone()))
+                .collect()
+        } else {
+            vec![]
+        };
+
         let clipboard = Clipboard {
             cells,
             formats,
@@ -233,10 +336,20 @@ impl Sheet {
             origin: clipboard_origin,
             selection: selection.clone(),
             validations,
+            merge_cells,
+            comments,
             data_tables,
             operation: clipboard_operation,
         };
+        Ok(Self::encode_clipboard(clipboard, plain_text, html_body))
+    }
 
+    /// Encodes the clipboard into the html table used when pasting.
+    fn encode_clipboard(
+        clipboard: Clipboard,
+        plain_text: String,
+        mut html_body: String,
+    ) -> JsClipboard {
         html_body.push_str("</td></tr></tbody></table>");
         let mut html = String::from("<table data-quadratic=\"");
         let data = serde_json::to_string(&clipboard).unwrap_or_default();
@@ -244,7 +357,24 @@ impl Sheet {
         html.push_str(&encoded);
         html.push_str(&String::from("\">"));
         html.push_str(&html_body);
-        Ok(JsClipboard { plain_text, html })
+        JsClipboard { plain_text, html }
+    }
+
+    /// Copies the selection to the clipboard, skipping hidden columns and
+    /// rows. The visible cells are copied as a single block.
+    pub fn copy_visible_to_clipboard(
+        &self,
+        selection: &A1Selection,
+        a1_context: &A1Context,
+        include_plain_text: bool,
+    ) -> Result<JsClipboard, String> {
+        self.copy_cells_to_clipboard(
+            selection,
+            a1_context,
+            ClipboardOperation::Copy,
+            include_plain_text,
+            true,
+        )
     }
 }
 
@@ -368,4 +498,97 @@ mod tests {
         let clipboard = set_clipboard(&mut gc, "A3");
         assert!(get_format(pos![A3], &clipboard).unwrap());
     }
+
+    #[test]
+    fn clipboard_merge_cells() {
+        let mut gc = GridController::test();
+        let sheet_id = gc.sheet_ids()[0];
+        gc.merge_cells(&A1Selection::test_a1("A1:B2"), None)
+            .unwrap();
+
+        let JsClipboard { html, .. } = gc
+            .sheet(sheet_id)
+            .copy_to_clipboard(
+                &A1Selection::test_a1("A1:C3"),
+                gc.a1_context(),
+                ClipboardOperation::Copy,
+                false,
+            )
+            .unwrap();
+
+        gc.paste_from_clipboard(
+            &A1Selection::test_a1("E5"),
+            None,
+            Some(html),
+            PasteSpecial::None,
+            None,
+        );
+        assert_eq!(
+            gc.sheet(sheet_id).merge_cells.rects(),
+            &[Rect::test_a1("A1:B2"), Rect::test_a1("E5:F6")]
+        );
+
+        // merged cells partially outside the copied rect are not copied
+        let JsClipboard { html, .. } = gc
+            .sheet(sheet_id)
+            .copy_to_clipboard(
+                &A1Selection::test_a1("A1"),
+                gc.a1_context(),
+                ClipboardOperation::Copy,
+                false,
+            )
+            .unwrap();
+        assert!(Clipboard::decode(&html).unwrap().merge_cells.is_empty());
+    }
+
+    #[test]
+    fn copy_visible_to_clipboard() {
+        let mut gc = GridController::test();
+        let sheet_id = gc.sheet_ids()[0];
+        gc.set_cell_values(
+            crate::SheetPos::new(sheet_id, 1, 1),
+            vec![
+                vec!["a".into(), "b".into(), "c".into()],
+                vec!["d".into(), "e".into(), "f".into()],
+                vec!["g".into(), "h".into(), "i".into()],
+            ],
+            None,
+        );
+        gc.hide_columns(sheet_id, vec![2], None);
+        gc.hide_rows(sheet_id, vec![2], None);
+
+        let sheet = gc.sheet(sheet_id);
+        let selection = A1Selection::test_a1("A1:C3");
+        let JsClipboard { plain_text, .. } = sheet
+            .copy_visible_to_clipboard(&selection, gc.a1_context(), true)
+            .unwrap();
+        assert_eq!(plain_text, "a\tc\ng\ti");
+
+        // formats of the visible cells are pasted next to each other
+        gc.set_bold(&A1Selection::test_a1("C3"), Some(true), None)
+            .unwrap();
+        let JsClipboard { html, .. } = gc
+            .sheet(sheet_id)
+            .copy_visible_to_clipboard(&selection, gc.a1_context(), true)
+            .unwrap();
+        gc.paste_from_clipboard(
+            &A1Selection::test_a1("E1"),
+            None,
+            Some(html),
+            PasteSpecial::None,
+            None,
+        );
+        let sheet = gc.sheet(sheet_id);
+        assert_eq!(sheet.display_value(pos![F2]), Some("i".into()));
+        assert_eq!(sheet.formats.bold.get(pos![F2]), Some(true));
+        assert_eq!(sheet.display_value(pos![F3]), None);
+
+        let sheet = gc.sheet(sheet_id);
+
+        // the hidden cells are included in a normal copy
+        let JsClipboard { plain_text, .. } = sheet
+            .copy_to_clipboard(&selection, gc.a1_context(), ClipboardOperation::Copy, true)
+            .unwrap();
+        assert_eq!(plain_text, "a\tb\tc\nd\te\tf\ng\th\ti");
+    }
 }
@@ -67,7 +67,85 @@ impl Sheet {
         }
     }
 
+    /// Creates reverse operations to restore the merged cells that include
+    /// the column (they shrink or are removed when it is deleted).
+    fn reverse_merge_cells_ops_for_column(&self, column: i64) -> Vec<Operation> {
+        let rects = self
+            .merge_cells
+            .rects()
+            .iter()
+            .filter(|rect| rect.contains_col(column))
+            .copied()
+            .collect::<Vec<_>>();
+        if rects.is_empty() {
+            vec![]
+        } else {
+            vec![Operation::MergeCells {
+                sheet_id: self.id,
+                rects,
+            }]
+        }
+    }
+
+    /// Creates reverse operations to restore the comment threads in the
+    /// column.
+    fn reverse_comments_ops_for_column(&self, column: i64) -> Vec<Operation> {
+        let threads = self
+            .comments
+            .threads()
+            .filter(|(pos, _)| pos.x == column)
+            .map(|(pos, thread)| (pos, Some(thread.clone())))
+            .collect::<Vec<_>>();
+        if threads.is_empty() {
+            vec![]
+        } else {
+            vec![Operation::SetCommentThreads {
+                sheet_id: self.id,
+                threads,
+            }]
+        }
+    }
+
+    /// Creates reverse operations to restore the frozen columns when the column
+    /// is within them.
+    fn reverse_frozen_ops_for_column(&self, column: i64) -> Vec<Operation> {
+        if column <= self.frozen_columns as i64 {
+            vec![Operation::SetFrozenPanes {
+                sheet_id: self.id,
+                columns: self.frozen_columns,
+                rows: self.frozen_rows,
+            }]
+        } else {
+            vec![]
+        }
+    }
+
+    /// Creates reverse operations to restore the column outline (groups)
+    /// when the column is deleted.
+    fn reverse_outline_ops_for_column(&self, column: i64) -> Vec<Operation> {
+        if self.outlines.columns.has_levels_from(column) {
+            vec![Operation::SetColumnOutline {
+                sheet_id: self.id,
+                outline: self.outlines.columns.clone(),
+            }]
+        } else {
+            vec![]
+        }
+    }
+
     fn delete_column_offset(&mut self, transaction: &mut PendingTransaction, column: i64) {
+        if self.offsets.is_column_hidden(column) {
+            transaction
+                .reverse_operations
+                .push(Operation::SetColumnsHidden {
+                    sheet_id: self.id,
+                    columns: vec![column],
+                    hidden: true,
+                });
+        }
+        if self.offsets.has_hidden_columns(column, i64::MAX) {
+            transaction.sheet_info.insert(self.id);
+        }
         let (changed, new_size) = self.offsets.delete_column(column);
         if let Some(new_size) = new_size {
             transaction
@@ -97,6 +175,18 @@ impl Sheet {
         // create undo operations for the deleted column (only when needed since
         // it's a bit expensive)
         if transaction.is_user_undo_redo() {
+            transaction
+                .reverse_operations
+                .extend(self.reverse_frozen_ops_for_column(column));
+            transaction
+                .reverse_operations
+                .extend(self.reverse_comments_ops_for_column(column));
+            transaction
+                .reverse_operations
+                .extend(self.reverse_outline_ops_for_column(column));
+            transaction
+                .reverse_operations
+                .extend(self.reverse_merge_cells_ops_for_column(column));
             transaction
                 .reverse_operations
                 .extend(self.reverse_borders_ops_for_column(column));
@@ -128,6 +218,27 @@ impl Sheet {
         self.borders.remove_column(column);
         transaction.sheet_borders.insert(self.id);
 
+        // remove the column from merged cells
+        if self.merge_cells.remove_column(column) {
+            transaction.sheet_info.insert(self.id);
+        }
+
+        // remove the column from the outline
+        if self.outlines.columns.remove(column) {
+            transaction.sheet_info.insert(self.id);
+        }
+
+        // remove the column's comments
+        if self.comments.remove_column(column) {
+            transaction.comments.insert(self.id);
+        }
+
+        // a deleted frozen column shrinks the frozen columns
+        if column <= self.frozen_columns as i64 {
+            self.frozen_columns -= 1;
+            transaction.sheet_info.insert(self.id);
+        }
+
         self.columns.remove(&column);
 
         // update the indices of all columns impacted by the deletion
@@ -149,6 +260,12 @@ impl Sheet {
             self.validations
                 .remove_column(transaction, self.id, column, a1_context);
         transaction.add_dirty_hashes_from_selections(self, a1_context, changed_selections);
+        self.conditional_formats
+            .remove_column(transaction, self.id, column, a1_context);
+
+        // update protected and editable ranges
+        self.protection
+            .remove_column(transaction, self.id, column, a1_context);
 
         if transaction.is_user_undo_redo() {
             // reverse operation to create the column (this will also shift all impacted columns)
@@ -64,12 +64,44 @@ impl Sheet {
         self.borders.insert_column(column, copy_formats);
         transaction.sheet_borders.insert(self.id);
 
+        // update merged cells
+        if self.merge_cells.insert_column(column) {
+            transaction.sheet_info.insert(self.id);
+        }
+
+        // update the outline
+        if self.outlines.columns.insert(column) {
+            transaction.sheet_info.insert(self.id);
+        }
+
+        // update comments
+        if self.comments.insert_column(column) {
+            transaction.comments.insert(self.id);
+        }
+
+        // a column inserted within the frozen columns is also frozen
+        if column <= self.frozen_columns as i64 {
+            self.frozen_columns += 1;
+            transaction.sheet_info.insert(self.id);
+        }
+
         // update validations
         let changed_selections =
             self.validations
                 .insert_column(transaction, self.id, column, a1_context);
         transaction.add_dirty_hashes_from_selections(self, a1_context, changed_selections);
 
+        // update conditional formats
+        self.conditional_formats
+            .insert_column(transaction, self.id, column, a1_context);
+
+        // update protected and editable ranges
+        self.protection
+            .insert_column(transaction, self.id, column, a1_context);
+
+        if self.offsets.has_hidden_columns(column, i64::MAX) {
+            transaction.sheet_info.insert(self.id);
+        }
         let changes = self.offsets.insert_column(column, copy_formats);
         if !changes.is_empty() {
             changes.iter().for_each(|(index, size)| {
@@ -77,6 +109,14 @@ impl Sheet {
             });
         }
 
+        // a column inserted within a collapsed group is hidden
+        if self.outlines.columns.is_collapsed(column) {
+            self.offsets.set_column_hidden(column, true);
+            let width = self.offsets.column_width(column);
+            transaction.offsets_modified(self.id, Some(column), None, Some(width));
+            transaction.sheet_info.insert(self.id);
+        }
+
         // create undo operations for the inserted column
         if transaction.is_user_undo_redo() {
             // reverse operation to delete the column (this will also shift all impacted columns)
@@ -116,6 +156,7 @@ mod tests {
                 bottom: Some(BorderStyleTimestamp::default()),
                 left: Some(BorderStyleTimestamp::default()),
                 right: Some(BorderStyleTimestamp::default()),
+                ..Default::default()
             },
         );
         sheet.borders.set_style_cell(
@@ -125,6 +166,7 @@ mod tests {
                 bottom: Some(BorderStyleTimestamp::default()),
                 left: Some(BorderStyleTimestamp::default()),
                 right: Some(BorderStyleTimestamp::default()),
+                ..Default::default()
             },
         );
         sheet.borders.set_style_cell(
@@ -134,6 +176,7 @@ mod tests {
                 bottom: Some(BorderStyleTimestamp::default()),
                 left: Some(BorderStyleTimestamp::default()),
                 right: Some(BorderStyleTimestamp::default()),
+                ..Default::default()
             },
         );
 
@@ -66,6 +66,72 @@ impl Sheet {
         }
     }
 
+    /// Creates reverse operations to restore the merged cells that include
+    /// the row (they shrink or are removed when it is deleted).
+    fn reverse_merge_cells_ops_for_row(&self, row: i64) -> Vec<Operation> {
+        let rects = self
+            .merge_cells
+            .rects()
+            .iter()
+            .filter(|rect| rect.contains_row(row))
+            .copied()
+            .collect::<Vec<_>>();
+        if rects.is_empty() {
+            vec![]
+        } else {
+            vec![Operation::MergeCells {
+                sheet_id: self.id,
+                rects,
+            }]
+        }
+    }
+
+    /// Creates reverse operations to restore the comment threads in the
+    /// row.
+    fn reverse_comments_ops_for_row(&self, row: i64) -> Vec<Operation> {
+        let threads = self
+            .comments
+            .threads()
+            .filter(|(pos, _)| pos.y == row)
+            .map(|(pos, thread)| (pos, Some(thread.clone())))
+            .collect::<Vec<_>>();
+        if threads.is_empty() {
+            vec![]
+        } else {
+            vec![Operation::SetCommentThreads {
+                sheet_id: self.id,
+                threads,
+            }]
+        }
+    }
+
+    /// Creates reverse operations to restore the frozen rows when the row
+    /// is within them.
+    fn reverse_frozen_ops_for_row(&self, row: i64) -> Vec<Operation> {
+        if row <= self.frozen_rows as i64 {
+            vec![Operation::SetFrozenPanes {
+                sheet_id: self.id,
+                columns: self.frozen_columns,
+                rows: self.frozen_rows,
+            }]
+        } else {
+            vec![]
+        }
+    }
+
+    /// Creates reverse operations to restore the row outline (groups) when
+    /// the row is deleted.
+    fn reverse_outline_ops_for_row(&self, row: i64) -> Vec<Operation> {
+        if self.outlines.rows.has_levels_from(row) {
+            vec![Operation::SetRowOutline {
+                sheet_id: self.id,
+                outline: self.outlines.rows.clone(),
+            }]
+        } else {
+            vec![]
+        }
+    }
+
     /// Removes any value at row and shifts the remaining values up by 1.
     fn delete_and_shift_values(&mut self, row: i64) {
         // use the sheet bounds to determine the approximate bounds for the impacted range
@@ -97,6 +163,18 @@ impl Sheet {
     }
 
     fn delete_row_offset(&mut self, transaction: &mut PendingTransaction, row: i64) {
+        if self.offsets.is_row_hidden(row) {
+            transaction
+                .reverse_operations
+                .push(Operation::SetRowsHidden {
+                    sheet_id: self.id,
+                    rows: vec![row],
+                    hidden: true,
+                });
+        }
+        if self.offsets.has_hidden_rows(row, i64::MAX) {
+            transaction.sheet_info.insert(self.id);
+        }
         let (changed, new_size) = self.offsets.delete_row(row);
 
         if let Some(new_size) = new_size {
@@ -127,6 +205,18 @@ impl Sheet {
         // create undo operations for the deleted row (only when needed since
         // it's a bit expensive)
         if transaction.is_user_undo_redo() {
+            transaction
+                .reverse_operations
+                .extend(self.reverse_frozen_ops_for_row(row));
+            transaction
+                .reverse_operations
+                .extend(self.reverse_comments_ops_for_row(row));
+            transaction
+                .reverse_operations
+                .extend(self.reverse_outline_ops_for_row(row));
+            transaction
+                .reverse_operations
+                .extend(self.reverse_merge_cells_ops_for_row(row));
             transaction
                 .reverse_operations
                 .extend(self.reverse_borders_ops_for_row(row));
@@ -157,6 +247,27 @@ impl Sheet {
         self.borders.remove_row(row);
         transaction.sheet_borders.insert(self.id);
 
+        // remove the row from merged cells
+        if self.merge_cells.remove_row(row) {
+            transaction.sheet_info.insert(self.id);
+        }
+
+        // remove the row from the outline
+        if self.outlines.rows.remove(row) {
+            transaction.sheet_info.insert(self.id);
+        }
+
+        // remove the row's comments
+        if self.comments.remove_row(row) {
+            transaction.comments.insert(self.id);
+        }
+
+        // a deleted frozen row shrinks the frozen rows
+        if row <= self.frozen_rows as i64 {
+            self.frozen_rows -= 1;
+            transaction.sheet_info.insert(self.id);
+        }
+
         // update all cells that were impacted by the deletion
         self.delete_and_shift_values(row);
 
@@ -164,6 +275,12 @@ impl Sheet {
             .validations
             .remove_row(transaction, self.id, row, a1_context);
         transaction.add_dirty_hashes_from_selections(self, a1_context, changed_selections);
+        self.conditional_formats
+            .remove_row(transaction, self.id, row, a1_context);
+
+        // update protected and editable ranges
+        self.protection
+            .remove_row(transaction, self.id, row, a1_context);
 
         if transaction.is_user_undo_redo() {
             // reverse operation to create the row (this will also shift all impacted rows)
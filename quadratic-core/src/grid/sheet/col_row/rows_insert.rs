@@ -59,17 +59,57 @@ impl Sheet {
         self.borders.insert_row(row, copy_formats);
         transaction.sheet_borders.insert(self.id);
 
+        // update merged cells
+        if self.merge_cells.insert_row(row) {
+            transaction.sheet_info.insert(self.id);
+        }
+
+        // update the outline
+        if self.outlines.rows.insert(row) {
+            transaction.sheet_info.insert(self.id);
+        }
+
+        // update comments
+        if self.comments.insert_row(row) {
+            transaction.comments.insert(self.id);
+        }
+
+        // a row inserted within the frozen rows is also frozen
+        if row <= self.frozen_rows as i64 {
+            self.frozen_rows += 1;
+            transaction.sheet_info.insert(self.id);
+        }
+
         // update validations
         let changed_selections = self
             .validations
             .insert_row(transaction, self.id, row, a1_context);
         transaction.add_dirty_hashes_from_selections(self, a1_context, changed_selections);
 
+        // update conditional formats
+        self.conditional_formats
+            .insert_row(transaction, self.id, row, a1_context);
+
+        // update protected and editable ranges
+        self.protection
+            .insert_row(transaction, self.id, row, a1_context);
+
+        if self.offsets.has_hidden_rows(row, i64::MAX) {
+            transaction.sheet_info.insert(self.id);
+        }
         let changes = self.offsets.insert_row(row, copy_formats);
         changes.iter().for_each(|(index, size)| {
             transaction.offsets_modified(self.id, None, Some(*index), Some(*size));
         });
 
+        // a row inserted within a collapsed group is hidden
+        if self.outlines.rows.is_collapsed(row) {
+            self.offsets.set_row_hidden(row, true);
+            let height = self.offsets.row_height(row);
+            transaction.offsets_modified(self.id, None, Some(row), Some(height));
+            transaction.sheet_info.insert(self.id);
+        }
+
         // create undo operations for the inserted row
         if transaction.is_user_undo_redo() {
             // reverse operation to delete the row (this will also shift all impacted rows)
@@ -108,6 +148,7 @@ mod test {
                 bottom: Some(BorderStyleTimestamp::default()),
                 left: Some(BorderStyleTimestamp::default()),
                 right: Some(BorderStyleTimestamp::default()),
+                ..Default::default()
             },
         );
         sheet.borders.set_style_cell(
@@ -117,6 +158,7 @@ mod test {
                 bottom: Some(BorderStyleTimestamp::default()),
                 left: Some(BorderStyleTimestamp::default()),
                 right: Some(BorderStyleTimestamp::default()),
+                ..Default::default()
             },
         );
         sheet.borders.set_style_cell(
@@ -126,6 +168,7 @@ mod test {
                 bottom: Some(BorderStyleTimestamp::default()),
                 left: Some(BorderStyleTimestamp::default()),
                 right: Some(BorderStyleTimestamp::default()),
+                ..Default::default()
             },
         );
         sheet.test_set_code_run_array(4, 1, vec!["A", "B"], false);
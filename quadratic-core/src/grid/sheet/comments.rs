@@ -0,0 +1,261 @@
+//! Comment threads anchored to cells.
+//!
+//! A thread is stored by the position of its cell and moves with the cell when
+//! columns or rows are inserted or deleted (and when the cell is cut and
+//! pasted). Replies are appended to the thread in the order they were created.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use uuid::Uuid;
+
+use crate::{Pos, Rect};
+
+lazy_static! {
+    static ref MENTION: Regex = Regex::new(r"(?:^|[^\w@])@([\w.+-]*\w(?:@[\w-]+(?:\.[\w-]+)+)?)")
+        .expect("regex should compile");
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, TS)]
+pub struct Comment {
+    pub id: Uuid,
+    pub author: String,
+    #[ts(type = "string")]
+    pub created: DateTime<Utc>,
+    pub text: String,
+
+    /// Users mentioned in the text with @name (without the @).
+    pub mentions: Vec<String>,
+}
+
+impl Comment {
+    /// Creates a new comment and finds its @mentions.
+    pub fn new(author: String, text: String) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            author,
+            created: Utc::now(),
+            mentions: Self::find_mentions(&text),
+            text,
+        }
+    }
+
+    /// Returns the unique @mentions in the text (without the @).
+    pub fn find_mentions(text: &str) -> Vec<String> {
+        let mut mentions: Vec<String> = vec![];
+        for captures in MENTION.captures_iter(text) {
+            let mention = captures[1].to_string();
+            if !mentions.contains(&mention) {
+                mentions.push(mention);
+            }
+        }
+        mentions
+    }
+}
+
+/// A comment and its replies.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, TS)]
+pub struct CommentThread {
+    pub comments: Vec<Comment>,
+    pub resolved: bool,
+}
+
+#[derive(Default, Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Comments {
+    #[serde(with = "crate::util::btreemap_serde")]
+    threads: BTreeMap<Pos, CommentThread>,
+}
+
+impl Comments {
+    /// Returns true if there are no comments.
+    pub fn is_empty(&self) -> bool {
+        self.threads.is_empty()
+    }
+
+    /// Returns the thread at the position.
+    pub fn get(&self, pos: Pos) -> Option<&CommentThread> {
+        self.threads.get(&pos)
+    }
+
+    /// Returns all threads.
+    pub fn threads(&self) -> impl Iterator<Item = (Pos, &CommentThread)> {
+        self.threads.iter().map(|(pos, thread)| (*pos, thread))
+    }
+
+    /// Returns the threads within the rect.
+    pub fn in_rect(&self, rect: Rect) -> Vec<(Pos, CommentThread)> {
+        self.threads
+            .range(rect.min..=rect.max)
+            .filter(|(pos, _)| rect.contains(**pos))
+            .map(|(pos, thread)| (*pos, thread.clone()))
+            .collect()
+    }
+
+    /// Replaces (or removes) the thread at the position. Returns the old
+    /// thread.
+    pub fn set(&mut self, pos: Pos, thread: Option<CommentThread>) -> Option<CommentThread> {
+        match thread {
+            Some(thread) => self.threads.insert(pos, thread),
+            None => self.threads.remove(&pos),
+        }
+    }
+
+    /// Adds a comment to the thread at the position, creating the thread if
+    /// needed. Comments are kept in the order they were created.
+    pub fn add(&mut self, pos: Pos, comment: Comment) {
+        let thread = self.threads.entry(pos).or_insert_with(|| CommentThread {
+            comments: vec![],
+            resolved: false,
+        });
+        let index = thread
+            .comments
+            .partition_point(|existing| existing.created <= comment.created);
+        thread.comments.insert(index, comment);
+    }
+
+    /// Removes a comment. The thread is removed with its last comment.
+    /// Returns the removed comment.
+    pub fn remove(&mut self, pos: Pos, id: Uuid) -> Option<Comment> {
+        let thread = self.threads.get_mut(&pos)?;
+        let index = thread
+            .comments
+            .iter()
+            .position(|comment| comment.id == id)?;
+        let comment = thread.comments.remove(index);
+        if thread.comments.is_empty() {
+            self.threads.remove(&pos);
+        }
+        Some(comment)
+    }
+
+    /// Sets whether a thread is resolved. Returns the old state, or None if
+    /// there is no thread.
+    pub fn set_resolved(&mut self, pos: Pos, resolved: bool) -> Option<bool> {
+        let thread = self.threads.get_mut(&pos)?;
+        Some(std::mem::replace(&mut thread.resolved, resolved))
+    }
+
+    fn shift(&mut self, shift: impl Fn(Pos) -> Option<Pos>) -> bool {
+        let mut changed = false;
+        self.threads = std::mem::take(&mut self.threads)
+            .into_iter()
+            .filter_map(|(pos, thread)| {
+                let shifted = shift(pos);
+                changed |= shifted != Some(pos);
+                shifted.map(|shifted| (shifted, thread))
+            })
+            .collect();
+        changed
+    }
+
+    /// Shifts threads for an inserted column. Returns true if any thread
+    /// moved.
+    pub fn insert_column(&mut self, column: i64) -> bool {
+        self.shift(|pos| {
+            Some(if pos.x >= column {
+                pos.translate(1, 0, 1, 1)
+            } else {
+                pos
+            })
+        })
+    }
+
+    /// Shifts threads for an inserted row. Returns true if any thread moved.
+    pub fn insert_row(&mut self, row: i64) -> bool {
+        self.shift(|pos| {
+            Some(if pos.y >= row {
+                pos.translate(0, 1, 1, 1)
+            } else {
+                pos
+            })
+        })
+    }
+
+    /// Removes the threads in a deleted column and shifts the rest. Returns
+    /// true if any thread changed.
+    pub fn remove_column(&mut self, column: i64) -> bool {
+        self.shift(|pos| match pos.x.cmp(&column) {
+            std::cmp::Ordering::Less => Some(pos),
+            std::cmp::Ordering::Equal => None,
+            std::cmp::Ordering::Greater => Some(pos.translate(-1, 0, 1, 1)),
+        })
+    }
+
+    /// Removes the threads in a deleted row and shifts the rest. Returns true
+    /// if any thread changed.
+    pub fn remove_row(&mut self, row: i64) -> bool {
+        self.shift(|pos| match pos.y.cmp(&row) {
+            std::cmp::Ordering::Less => Some(pos),
+            std::cmp::Ordering::Equal => None,
+            std::cmp::Ordering::Greater => Some(pos.translate(0, -1, 1, 1)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_mentions() {
+        assert_eq!(
+            Comment::find_mentions("@jane please check with @bob.smith@example.com and @jane."),
+            vec!["jane", "bob.smith@example.com"]
+        );
+        assert!(Comment::find_mentions("email me at jane@example.com").is_empty());
+        assert!(Comment::find_mentions("no mentions @ all").is_empty());
+    }
+
+    #[test]
+    fn test_add_remove() {
+        let mut comments = Comments::default();
+        let first = Comment::new("jane".into(), "first".into());
+        let mut reply = Comment::new("bob".into(), "reply".into());
+        reply.created = first.created + chrono::Duration::seconds(1);
+
+        // comments are ordered by when they were created
+        comments.add(pos![B2], reply.clone());
+        comments.add(pos![B2], first.clone());
+        let thread = comments.get(pos![B2]).unwrap();
+        assert_eq!(thread.comments, vec![first.clone(), reply.clone()]);
+
+        assert_eq!(comments.set_resolved(pos![B2], true), Some(false));
+        assert_eq!(comments.set_resolved(pos![C2], true), None);
+
+        assert_eq!(comments.remove(pos![B2], first.id), Some(first));
+        assert_eq!(comments.remove(pos![B2], Uuid::new_v4()), None);
+        assert_eq!(comments.remove(pos![B2], reply.id), Some(reply));
+        assert!(comments.is_empty());
+    }
+
+    #[test]
+    fn test_insert_remove_column_row() {
+        let mut comments = Comments::default();
+        comments.add(pos![B2], Comment::new("jane".into(), "B2".into()));
+        comments.add(pos![D4], Comment::new("jane".into(), "D4".into()));
+
+        assert!(comments.insert_column(3));
+        assert!(comments.get(pos![E4]).is_some());
+        assert!(comments.get(pos![B2]).is_some());
+
+        assert!(comments.insert_row(1));
+        assert!(comments.get(pos![B3]).is_some());
+        assert!(comments.get(pos![E5]).is_some());
+        assert!(!comments.insert_row(10));
+
+        assert!(comments.remove_column(2));
+        assert!(comments.get(pos![B3]).is_none());
+        assert!(comments.get(pos![D5]).is_some());
+
+        assert!(comments.remove_row(5));
+        assert!(comments.is_empty());
+
+        comments.add(pos![B2], Comment::new("jane".into(), "B2".into()));
+        assert_eq!(comments.in_rect(Rect::test_a1("A1:B2")).len(), 1);
+        assert!(comments.in_rect(Rect::test_a1("C1:D5")).is_empty());
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{
+    a1::{A1Context, A1Selection},
+    controller::{
+        active_transactions::pending_transaction::PendingTransaction,
+        operations::operation::Operation,
+    },
+    grid::SheetId,
+};
+
+use super::{ConditionalFormats, conditional_format::ConditionalFormat};
+
+impl ConditionalFormats {
+    /// Updates the selections after a column or row is removed, and adds the
+    /// undo operations. Conditional formats without cells are removed.
+    fn removed(
+        &mut self,
+        transaction: &mut PendingTransaction,
+        sheet_id: SheetId,
+        mut remove: impl FnMut(&mut A1Selection) -> bool,
+    ) {
+        let mut reverse_operations = vec![];
+        self.conditional_formats.retain_mut(|cf| {
+            let original_selection = cf.selection.clone();
+            if remove(&mut cf.selection) {
+                reverse_operations.push(Operation::SetConditionalFormat {
+                    conditional_format: ConditionalFormat {
+                        selection: original_selection,
+                        ..cf.clone()
+                    },
+                });
+                !cf.selection.ranges.is_empty()
+            } else {
+                true
+            }
+        });
+        if !reverse_operations.is_empty() {
+            transaction.conditional_formats.insert(sheet_id);
+            if transaction.is_user_undo_redo() {
+                transaction.reverse_operations.extend(reverse_operations);
+            }
+        }
+    }
+
+    /// Removes a column from all conditional formats.
+    pub(crate) fn remove_column(
+        &mut self,
+        transaction: &mut PendingTransaction,
+        sheet_id: SheetId,
+        column: i64,
+        a1_context: &A1Context,
+    ) {
+        self.removed(transaction, sheet_id, |selection| {
+            selection.removed_column(column, a1_context)
+        });
+    }
+
+    /// Removes a row from all conditional formats.
+    pub(crate) fn remove_row(
+        &mut self,
+        transaction: &mut PendingTransaction,
+        sheet_id: SheetId,
+        row: i64,
+        a1_context: &A1Context,
+    ) {
+        self.removed(transaction, sheet_id, |selection| {
+            selection.removed_row(row, a1_context)
+        });
+    }
+
+    /// Inserts a column in all conditional formats.
+    pub(crate) fn insert_column(
+        &mut self,
+        transaction: &mut PendingTransaction,
+        sheet_id: SheetId,
+        column: i64,
+        a1_context: &A1Context,
+    ) {
+        for cf in self.conditional_formats.iter_mut() {
+            if cf.selection.inserted_column(column, a1_context) {
+                transaction.conditional_formats.insert(sheet_id);
+            }
+        }
+    }
+
+    /// Inserts a row in all conditional formats.
+    pub(crate) fn insert_row(
+        &mut self,
+        transaction: &mut PendingTransaction,
+        sheet_id: SheetId,
+        row: i64,
+        a1_context: &A1Context,
+    ) {
+        for cf in self.conditional_formats.iter_mut() {
+            if cf.selection.inserted_row(row, a1_context) {
+                transaction.conditional_formats.insert(sheet_id);
+            }
+        }
+    }
+}
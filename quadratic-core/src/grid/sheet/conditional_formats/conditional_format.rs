@@ -0,0 +1,370 @@
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use uuid::Uuid;
+
+use crate::{
+    CellValue, Pos,
+    a1::{A1Context, A1Selection},
+    grid::{
+        CellsAccessed, Sheet,
+        js_types::{JsRenderDataBar, JsRenderIcon},
+    },
+};
+
+use super::rules::{
+    ConditionalFormatCondition, ConditionalFormatRule, RangeStats, cell_number, cell_text,
+    interpolate_color, parse_color,
+};
+
+/// The style of a highlight rule. Styles that are not set use the cell's
+/// format.
+#[derive(Default, Serialize, Deserialize, Debug, Clone, PartialEq, Eq, TS)]
+pub struct ConditionalFormatStyle {
+    pub fill_color: Option<String>,
+    pub text_color: Option<String>,
+    pub bold: Option<bool>,
+    pub italic: Option<bool>,
+    pub underline: Option<bool>,
+    pub strike_through: Option<bool>,
+}
+
+impl ConditionalFormatStyle {
+    /// Sets the styles of `other` that are set.
+    fn merge(&mut self, other: &ConditionalFormatStyle) {
+        if other.fill_color.is_some() {
+            self.fill_color.clone_from(&other.fill_color);
+        }
+        if other.text_color.is_some() {
+            self.text_color.clone_from(&other.text_color);
+        }
+        self.bold = other.bold.or(self.bold);
+        self.italic = other.italic.or(self.italic);
+        self.underline = other.underline.or(self.underline);
+        self.strike_through = other.strike_through.or(self.strike_through);
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, TS)]
+pub struct ConditionalFormat {
+    pub id: Uuid,
+    pub selection: A1Selection,
+    pub rule: ConditionalFormatRule,
+}
+
+/// The result of the conditional formats of a sheet for a cell.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct ConditionalFormatCell {
+    pub style: ConditionalFormatStyle,
+    pub data_bar: Option<JsRenderDataBar>,
+    pub icon: Option<JsRenderIcon>,
+
+    /// Set when a data bar or icon set replaces the value.
+    pub hide_value: bool,
+}
+
+impl ConditionalFormatCell {
+    /// Applies the result of a later conditional format over this one.
+    pub(crate) fn merge(&mut self, other: &ConditionalFormatCell) {
+        self.style.merge(&other.style);
+        if other.data_bar.is_some() || other.icon.is_some() {
+            self.hide_value = other.hide_value;
+        }
+        if other.data_bar.is_some() {
+            self.data_bar.clone_from(&other.data_bar);
+        }
+        if other.icon.is_some() {
+            self.icon.clone_from(&other.icon);
+        }
+    }
+}
+
+/// The results of a conditional format, kept until the conditional format or
+/// the cells it accessed change.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConditionalFormatResults {
+    /// The conditional format when it was evaluated.
+    pub conditional_format: ConditionalFormat,
+
+    /// The date used for rules relative to today.
+    pub today: NaiveDate,
+
+    /// The cells in the selection and the cells accessed by the formula.
+    pub cells_accessed: CellsAccessed,
+
+    pub cells: HashMap<Pos, ConditionalFormatCell>,
+}
+
+impl ConditionalFormat {
+    pub fn new(selection: A1Selection, rule: ConditionalFormatRule) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            selection,
+            rule,
+        }
+    }
+
+    /// Returns the position that relative references of a formula rule are
+    /// relative to (the top left cell of the first range).
+    pub fn anchor(&self, a1_context: &A1Context) -> Pos {
+        self.selection.anchor(a1_context)
+    }
+
+    /// Returns the cells within the selection (infinite ranges are clamped to
+    /// the sheet's data). Blank cells are only included for formula rules.
+    fn cells(&self, sheet: &Sheet, a1_context: &A1Context) -> IndexMap<Pos, CellValue> {
+        let include_blanks = matches!(
+            self.rule,
+            ConditionalFormatRule::Highlight {
+                condition: ConditionalFormatCondition::Formula(_),
+                ..
+            }
+        );
+        let mut cells = IndexMap::new();
+        for rect in sheet.selection_to_rects(&self.selection, false, false, a1_context) {
+            for pos in rect.iter() {
+                match sheet.display_value(pos) {
+                    Some(value) if !value.is_blank_or_empty_string() => {
+                        cells.insert(pos, value);
+                    }
+                    _ if include_blanks => {
+                        cells.insert(pos, CellValue::Blank);
+                    }
+                    _ => {}
+                }
+            }
+        }
+        cells
+    }
+
+    /// Applies the rule to the cells in its selection. Later rules override
+    /// earlier ones. `formula` evaluates a formula rule for a cell.
+    pub(crate) fn apply(
+        &self,
+        sheet: &Sheet,
+        a1_context: &A1Context,
+        today: NaiveDate,
+        results: &mut HashMap<Pos, ConditionalFormatCell>,
+        mut formula: impl FnMut(Pos) -> bool,
+    ) {
+        let cells = self.cells(sheet, a1_context);
+        if cells.is_empty() {
+            return;
+        }
+        let stats = RangeStats::new(cells.values());
+
+        match &self.rule {
+            ConditionalFormatRule::Highlight { condition, style } => {
+                let mut text_counts = HashMap::new();
+                if matches!(
+                    condition,
+                    ConditionalFormatCondition::Duplicate | ConditionalFormatCondition::Unique
+                ) {
+                    for value in cells.values() {
+                        *text_counts.entry(cell_text(value)).or_insert(0) += 1;
+                    }
+                }
+                for (pos, value) in cells.iter() {
+                    let matches = match condition {
+                        ConditionalFormatCondition::Formula(_) => formula(*pos),
+                        condition => condition.matches(value, &stats, &text_counts, today),
+                    };
+                    if matches {
+                        results.entry(*pos).or_default().style.merge(style);
+                    }
+                }
+            }
+            ConditionalFormatRule::ColorScale(points) => {
+                let points = points
+                    .iter()
+                    .filter_map(|point| {
+                        Some((stats.resolve(&point.value)?, parse_color(&point.color)?))
+                    })
+                    .collect::<Vec<_>>();
+                if points.len() < 2 {
+                    return;
+                }
+                for (pos, value) in cells.iter() {
+                    let Some(n) = cell_number(value) else {
+                        continue;
+                    };
+                    let segment = points
+                        .windows(2)
+                        .find(|segment| n <= segment[1].0)
+                        .unwrap_or(&points[points.len() - 2..]);
+                    let ((from, from_color), (to, to_color)) = (segment[0], segment[1]);
+                    let t = if to > from {
+                        (n - from) / (to - from)
+                    } else {
+                        1.0
+                    };
+                    let color = interpolate_color(from_color, to_color, t);
+                    results.entry(*pos).or_default().style.fill_color = Some(color);
+                }
+            }
+            ConditionalFormatRule::DataBar(data_bar) => {
+                let (Some(min), Some(max)) =
+                    (stats.resolve(&data_bar.min), stats.resolve(&data_bar.max))
+                else {
+                    return;
+                };
+                for (pos, value) in cells.iter() {
+                    let Some(n) = cell_number(value) else {
+                        continue;
+                    };
+                    let fraction = if max > min {
+                        (n - min) / (max - min)
+                    } else {
+                        1.0
+                    };
+                    let result = results.entry(*pos).or_default();
+                    result.data_bar = Some(JsRenderDataBar {
+                        color: data_bar.color.clone(),
+                        percent: (fraction.clamp(0.0, 1.0) * 100.0).round() as u8,
+                    });
+                    result.hide_value = !data_bar.show_value;
+                }
+            }
+            ConditionalFormatRule::IconSet(icon_set) => {
+                let thresholds = icon_set
+                    .thresholds
+                    .iter()
+                    .map(|threshold| stats.resolve(threshold))
+                    .collect::<Option<Vec<_>>>();
+                let Some(thresholds) = thresholds else {
+                    return;
+                };
+                for (pos, value) in cells.iter() {
+                    let Some(n) = cell_number(value) else {
+                        continue;
+                    };
+                    let mut index = thresholds.iter().filter(|&&t| n >= t).count();
+                    if icon_set.reverse {
+                        index = thresholds.len() - index;
+                    }
+                    let result = results.entry(*pos).or_default();
+                    result.icon = Some(JsRenderIcon {
+                        icon_set: icon_set.kind,
+                        index: index as u8,
+                    });
+                    result.hide_value = !icon_set.show_value;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        a1::A1Context,
+        grid::sheet::conditional_formats::rules::{
+            ColorScalePoint, CompareOperator, DataBar, IconSet, IconSetKind, ScaleValue,
+        },
+    };
+
+    fn test_sheet() -> Sheet {
+        let mut sheet = Sheet::test();
+        for (y, n) in [1, 5, 9].iter().enumerate() {
+            sheet.set_cell_value(
+                Pos {
+                    x: 1,
+                    y: y as i64 + 1,
+                },
+                CellValue::Number((*n).into()),
+            );
+        }
+        sheet
+    }
+
+    fn apply(sheet: &Sheet, rule: ConditionalFormatRule) -> HashMap<Pos, ConditionalFormatCell> {
+        let mut results = HashMap::new();
+        let today = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        ConditionalFormat::new(A1Selection::test_a1("A"), rule).apply(
+            sheet,
+            &A1Context::default(),
+            today,
+            &mut results,
+            |_| false,
+        );
+        results
+    }
+
+    #[test]
+    fn test_highlight() {
+        let sheet = test_sheet();
+        let style = ConditionalFormatStyle {
+            fill_color: Some("#ff0000".into()),
+            bold: Some(true),
+            ..Default::default()
+        };
+        let results = apply(
+            &sheet,
+            ConditionalFormatRule::Highlight {
+                condition: ConditionalFormatCondition::Compare {
+                    operator: CompareOperator::GreaterThan,
+                    values: vec!["4".into()],
+                },
+                style: style.clone(),
+            },
+        );
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[&pos![A2]].style, style);
+        assert!(!results.contains_key(&pos![A1]));
+    }
+
+    #[test]
+    fn test_color_scale() {
+        let sheet = test_sheet();
+        let results = apply(
+            &sheet,
+            ConditionalFormatRule::ColorScale(vec![
+                ColorScalePoint {
+                    value: ScaleValue::Min,
+                    color: "#000000".into(),
+                },
+                ColorScalePoint {
+                    value: ScaleValue::Max,
+                    color: "#ffffff".into(),
+                },
+            ]),
+        );
+        let fill = |pos: Pos| results[&pos].style.fill_color.clone().unwrap();
+        assert_eq!(fill(pos![A1]), "#000000");
+        assert_eq!(fill(pos![A2]), "#808080");
+        assert_eq!(fill(pos![A3]), "#ffffff");
+    }
+
+    #[test]
+    fn test_data_bar_and_icon_set() {
+        let sheet = test_sheet();
+        let results = apply(
+            &sheet,
+            ConditionalFormatRule::DataBar(DataBar {
+                min: ScaleValue::Number(0.0),
+                max: ScaleValue::Max,
+                color: "#0000ff".into(),
+                show_value: false,
+            }),
+        );
+        assert_eq!(results[&pos![A3]].data_bar.as_ref().unwrap().percent, 100);
+        assert_eq!(results[&pos![A2]].data_bar.as_ref().unwrap().percent, 56);
+        assert!(results[&pos![A2]].hide_value);
+
+        let results = apply(
+            &sheet,
+            ConditionalFormatRule::IconSet(IconSet {
+                kind: IconSetKind::Arrows3,
+                thresholds: vec![ScaleValue::Number(5.0), ScaleValue::Number(9.0)],
+                reverse: false,
+                show_value: true,
+            }),
+        );
+        let icon = |pos: Pos| results[&pos].icon.as_ref().unwrap().index;
+        assert_eq!((icon(pos![A1]), icon(pos![A2]), icon(pos![A3])), (0, 1, 2));
+    }
+}
@@ -0,0 +1,260 @@
+//! Conditional formats for a Sheet.
+//!
+//! Rules are evaluated by the GridController (formula rules need the whole
+//! grid). After a transaction, only conditional formats that changed or whose
+//! cells (or the cells their formula accessed) changed are evaluated again.
+//! The results are merged in `computed` and are applied to the render cells
+//! and fills of the sheet.
+
+use std::collections::{HashMap, HashSet};
+
+use conditional_format::{ConditionalFormat, ConditionalFormatCell, ConditionalFormatResults};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{Pos, controller::operations::operation::Operation, grid::SheetId};
+
+pub mod col_row;
+pub mod conditional_format;
+pub mod rules;
+
+#[derive(Default, Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ConditionalFormats {
+    #[serde(default)]
+    pub conditional_formats: Vec<ConditionalFormat>,
+
+    /// The results of each conditional format, by id.
+    #[serde(skip)]
+    pub(crate) results: HashMap<Uuid, ConditionalFormatResults>,
+
+    /// The merged results of the rules, by cell.
+    #[serde(skip)]
+    pub(crate) computed: HashMap<Pos, ConditionalFormatCell>,
+}
+
+impl ConditionalFormats {
+    /// Updates or adds a conditional format. New conditional formats are
+    /// applied after (and override) existing ones. Returns the reverse
+    /// operations.
+    pub fn set(&mut self, conditional_format: ConditionalFormat) -> Vec<Operation> {
+        if let Some(existing) = self
+            .conditional_formats
+            .iter_mut()
+            .find(|cf| cf.id == conditional_format.id)
+        {
+            let reverse = vec![Operation::SetConditionalFormat {
+                conditional_format: existing.clone(),
+            }];
+            *existing = conditional_format;
+            return reverse;
+        }
+        let reverse = vec![Operation::RemoveConditionalFormat {
+            sheet_id: conditional_format.selection.sheet_id,
+            conditional_format_id: conditional_format.id,
+        }];
+        self.conditional_formats.push(conditional_format);
+        reverse
+    }
+
+    /// Removes a conditional format. Returns the reverse operations.
+    pub fn remove(&mut self, conditional_format_id: Uuid) -> Vec<Operation> {
+        let mut reverse = vec![];
+        self.conditional_formats.retain(|cf| {
+            if cf.id == conditional_format_id {
+                reverse.push(Operation::SetConditionalFormat {
+                    conditional_format: cf.clone(),
+                });
+                false
+            } else {
+                true
+            }
+        });
+        reverse
+    }
+
+    /// Gets a conditional format based on its id.
+    pub fn conditional_format(&self, conditional_format_id: Uuid) -> Option<&ConditionalFormat> {
+        self.conditional_formats
+            .iter()
+            .find(|cf| cf.id == conditional_format_id)
+    }
+
+    /// Returns true if the sheet has no rules and no results.
+    pub fn is_empty(&self) -> bool {
+        self.conditional_formats.is_empty() && self.results.is_empty()
+    }
+
+    /// Gets the computed result for a cell.
+    pub fn computed(&self, pos: Pos) -> Option<&ConditionalFormatCell> {
+        self.computed.get(&pos)
+    }
+
+    /// Replaces the results of the evaluated conditional formats, drops the
+    /// results of removed conditional formats, and merges the results of the
+    /// affected cells. Returns the cells whose result changed and whether any
+    /// fill changed.
+    pub(crate) fn set_results(
+        &mut self,
+        results: Vec<ConditionalFormatResults>,
+    ) -> (Vec<Pos>, bool) {
+        let mut affected = HashSet::new();
+        let ids = self
+            .conditional_formats
+            .iter()
+            .map(|cf| cf.id)
+            .collect::<HashSet<_>>();
+        self.results.retain(|id, old| {
+            let keep = ids.contains(id);
+            if !keep {
+                affected.extend(old.cells.keys().copied());
+            }
+            keep
+        });
+        for results in results {
+            affected.extend(results.cells.keys().copied());
+            if let Some(old) = self.results.insert(results.conditional_format.id, results) {
+                affected.extend(old.cells.keys().copied());
+            }
+        }
+
+        let mut changed = vec![];
+        let mut fills_changed = false;
+        for pos in affected {
+            let mut new = None;
+            for cf in self.conditional_formats.iter() {
+                if let Some(cell) = self.results.get(&cf.id).and_then(|r| r.cells.get(&pos)) {
+                    new.get_or_insert_with(ConditionalFormatCell::default)
+                        .merge(cell);
+                }
+            }
+            let old = match &new {
+                Some(new) => self.computed.insert(pos, new.clone()),
+                None => self.computed.remove(&pos),
+            };
+            if old != new {
+                changed.push(pos);
+                let fill = |cell: Option<&ConditionalFormatCell>| {
+                    cell.and_then(|cell| cell.style.fill_color.clone())
+                };
+                fills_changed |= fill(old.as_ref()) != fill(new.as_ref());
+            }
+        }
+        (changed, fills_changed)
+    }
+
+    /// Updates the conditional formats of a duplicated sheet.
+    pub(crate) fn duplicate(&mut self, new_sheet_id: SheetId) {
+        for cf in self.conditional_formats.iter_mut() {
+            cf.id = Uuid::new_v4();
+            cf.selection.sheet_id = new_sheet_id;
+        }
+    }
+
+    /// Stringifies the conditional formats to send to the client.
+    pub fn to_string(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(&self.conditional_formats)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        a1::A1Selection,
+        grid::sheet::conditional_formats::rules::{
+            ConditionalFormatCondition, ConditionalFormatRule,
+        },
+    };
+
+    fn rule() -> ConditionalFormatRule {
+        ConditionalFormatRule::Highlight {
+            condition: ConditionalFormatCondition::Duplicate,
+            style: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_set_remove() {
+        let mut conditional_formats = ConditionalFormats::default();
+        let cf = ConditionalFormat::new(A1Selection::test_a1("A1:B2"), rule());
+
+        let reverse = conditional_formats.set(cf.clone());
+        assert_eq!(
+            reverse,
+            vec![Operation::RemoveConditionalFormat {
+                sheet_id: cf.selection.sheet_id,
+                conditional_format_id: cf.id,
+            }]
+        );
+
+        let updated = ConditionalFormat {
+            selection: A1Selection::test_a1("C"),
+            ..cf.clone()
+        };
+        let reverse = conditional_formats.set(updated.clone());
+        assert_eq!(
+            reverse,
+            vec![Operation::SetConditionalFormat {
+                conditional_format: cf.clone(),
+            }]
+        );
+        assert_eq!(
+            conditional_formats.conditional_format(cf.id),
+            Some(&updated)
+        );
+
+        let reverse = conditional_formats.remove(cf.id);
+        assert_eq!(
+            reverse,
+            vec![Operation::SetConditionalFormat {
+                conditional_format: updated,
+            }]
+        );
+        assert!(conditional_formats.is_empty());
+    }
+
+    #[test]
+    fn test_set_results() {
+        let mut conditional_formats = ConditionalFormats::default();
+        let bold = ConditionalFormat::new(A1Selection::test_a1("A1:B2"), rule());
+        let fill = ConditionalFormat::new(A1Selection::test_a1("A1"), rule());
+        conditional_formats.set(bold.clone());
+        conditional_formats.set(fill.clone());
+
+        let results =
+            |cf: &ConditionalFormat, cell: ConditionalFormatCell| ConditionalFormatResults {
+                conditional_format: cf.clone(),
+                today: chrono::NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+                cells_accessed: Default::default(),
+                cells: HashMap::from([(pos![A1], cell)]),
+            };
+        let mut bold_cell = ConditionalFormatCell::default();
+        bold_cell.style.bold = Some(true);
+        let mut fill_cell = ConditionalFormatCell::default();
+        fill_cell.style.fill_color = Some("#ff0000".into());
+
+        let (changed, fills) = conditional_formats.set_results(vec![results(&bold, bold_cell)]);
+        assert_eq!(changed, vec![pos![A1]]);
+        assert!(!fills);
+
+        // results of later conditional formats are merged over earlier ones
+        let (changed, fills) = conditional_formats.set_results(vec![results(&fill, fill_cell)]);
+        assert_eq!(changed, vec![pos![A1]]);
+        assert!(fills);
+        let computed = conditional_formats.computed(pos![A1]).unwrap();
+        assert_eq!(computed.style.bold, Some(true));
+        assert_eq!(computed.style.fill_color, Some("#ff0000".into()));
+
+        // results of removed conditional formats are dropped
+        conditional_formats.remove(fill.id);
+        let (changed, fills) = conditional_formats.set_results(vec![]);
+        assert_eq!(changed, vec![pos![A1]]);
+        assert!(fills);
+        let computed = conditional_formats.computed(pos![A1]).unwrap();
+        assert_eq!(computed.style.fill_color, None);
+
+        // unchanged results do not change any cell
+        let (changed, _) = conditional_formats.set_results(vec![results(&bold, computed.clone())]);
+        assert!(changed.is_empty());
+    }
+}
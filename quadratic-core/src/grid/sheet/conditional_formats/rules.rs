@@ -0,0 +1,567 @@
+//! Conditional format rules and how they are applied to the values in their
+//! range.
+
+use chrono::{Datelike, Duration, NaiveDate};
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use crate::{CellValue, color::Rgba};
+
+use super::conditional_format::ConditionalFormatStyle;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, TS)]
+pub enum CompareOperator {
+    Equal,
+    NotEqual,
+    GreaterThan,
+    GreaterThanOrEqual,
+    LessThan,
+    LessThanOrEqual,
+    Between,
+    NotBetween,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, TS)]
+pub enum TextOperator {
+    Contains,
+    NotContains,
+    BeginsWith,
+    EndsWith,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, TS)]
+pub enum DateOccurring {
+    Yesterday,
+    Today,
+    Tomorrow,
+    Last7Days,
+    LastWeek,
+    ThisWeek,
+    NextWeek,
+    LastMonth,
+    ThisMonth,
+    NextMonth,
+}
+
+/// The condition of a highlight rule.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, TS)]
+pub enum ConditionalFormatCondition {
+    /// Compares the cell with a value (two values for Between and
+    /// NotBetween). Values that are not numbers are compared as text (case
+    /// insensitive).
+    Compare {
+        operator: CompareOperator,
+        values: Vec<String>,
+    },
+
+    /// Compares the displayed text of the cell (case insensitive).
+    Text {
+        operator: TextOperator,
+        text: String,
+    },
+
+    /// Dates relative to today.
+    DateOccurring(DateOccurring),
+
+    /// Values that appear more than once in the range.
+    Duplicate,
+
+    /// Values that appear only once in the range.
+    Unique,
+
+    /// The top (or bottom) `rank` numbers in the range, or `rank` percent of
+    /// the numbers when `percent` is set.
+    TopBottom { top: bool, rank: u32, percent: bool },
+
+    /// Numbers above (or below) the average of the range.
+    Average { above: bool, or_equal: bool },
+
+    /// A formula that is true for the cells to highlight. References are
+    /// relative to the top left cell of the selection, and are moved for each
+    /// cell (eg, `=A1>B1` over A1:A10 compares A2 with B2 for the second cell).
+    Formula(String),
+}
+
+/// A point on a color scale, data bar or icon set.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, TS)]
+pub enum ScaleValue {
+    Min,
+    Max,
+    Number(f64),
+    Percent(f64),
+    Percentile(f64),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, TS)]
+pub struct ColorScalePoint {
+    pub value: ScaleValue,
+    pub color: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, TS)]
+pub struct DataBar {
+    pub min: ScaleValue,
+    pub max: ScaleValue,
+    pub color: String,
+    pub show_value: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, TS)]
+pub enum IconSetKind {
+    Arrows3,
+    TrafficLights3,
+    Symbols3,
+    Flags3,
+    Arrows4,
+    Rating4,
+    Arrows5,
+    Rating5,
+}
+
+impl IconSetKind {
+    /// Returns the number of icons in the set.
+    pub fn icons(&self) -> usize {
+        match self {
+            IconSetKind::Arrows3
+            | IconSetKind::TrafficLights3
+            | IconSetKind::Symbols3
+            | IconSetKind::Flags3 => 3,
+            IconSetKind::Arrows4 | IconSetKind::Rating4 => 4,
+            IconSetKind::Arrows5 | IconSetKind::Rating5 => 5,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, TS)]
+pub struct IconSet {
+    pub kind: IconSetKind,
+
+    /// The lowest value of each icon after the first one, in ascending order.
+    pub thresholds: Vec<ScaleValue>,
+
+    /// Uses the icons in the reverse order (ie, the first icon for the highest
+    /// values).
+    pub reverse: bool,
+    pub show_value: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, TS)]
+pub enum ConditionalFormatRule {
+    /// Applies a style to the cells that match a condition.
+    Highlight {
+        condition: ConditionalFormatCondition,
+        style: ConditionalFormatStyle,
+    },
+
+    /// Fills numbers with a color between the colors of two or three points.
+    ColorScale(Vec<ColorScalePoint>),
+
+    DataBar(DataBar),
+    IconSet(IconSet),
+}
+
+impl ConditionalFormatRule {
+    /// Returns an error message if the rule cannot be applied.
+    pub fn validate(&self) -> Result<(), String> {
+        let check_color = |color: &str| {
+            parse_color(color)
+                .map(|_| ())
+                .ok_or_else(|| format!("Invalid color '{color}'"))
+        };
+        match self {
+            ConditionalFormatRule::Highlight { condition, style } => {
+                if let Some(color) = &style.fill_color {
+                    check_color(color)?;
+                }
+                match condition {
+                    ConditionalFormatCondition::Compare { operator, values } => {
+                        let expected = match operator {
+                            CompareOperator::Between | CompareOperator::NotBetween => 2,
+                            _ => 1,
+                        };
+                        if values.len() != expected {
+                            return Err(format!("Expected {expected} value(s) to compare"));
+                        }
+                    }
+                    ConditionalFormatCondition::TopBottom { rank, percent, .. }
+                        if *rank == 0 || (*percent && *rank > 100) =>
+                    {
+                        return Err("Invalid rank".to_string());
+                    }
+                    ConditionalFormatCondition::Formula(formula)
+                        if formula.trim_start_matches('=').trim().is_empty() =>
+                    {
+                        return Err("Formula cannot be empty".to_string());
+                    }
+                    _ => {}
+                }
+            }
+            ConditionalFormatRule::ColorScale(points) => {
+                if !(2..=3).contains(&points.len()) {
+                    return Err("A color scale needs two or three colors".to_string());
+                }
+                for point in points {
+                    check_color(&point.color)?;
+                }
+            }
+            ConditionalFormatRule::DataBar(data_bar) => check_color(&data_bar.color)?,
+            ConditionalFormatRule::IconSet(icon_set) => {
+                if icon_set.thresholds.len() + 1 != icon_set.kind.icons() {
+                    return Err(format!(
+                        "Expected {} thresholds for the icon set",
+                        icon_set.kind.icons() - 1
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parses a `#rrggbb` (or `#rrggbbaa`) color.
+pub(crate) fn parse_color(color: &str) -> Option<Rgba> {
+    if !color.is_ascii() || !color.starts_with('#') || !(color.len() == 7 || color.len() == 9) {
+        return None;
+    }
+    Rgba::color_from_str(color).ok()
+}
+
+/// Returns the color between `from` (t = 0) and `to` (t = 1) as `#rrggbb`.
+pub(crate) fn interpolate_color(from: Rgba, to: Rgba, t: f64) -> String {
+    let t = t.clamp(0.0, 1.0);
+    let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+    Rgba::new(
+        mix(from.red, to.red),
+        mix(from.green, to.green),
+        mix(from.blue, to.blue),
+        255,
+    )
+    .as_rgb_hex()
+}
+
+/// The numbers of a rule's range, used to resolve relative values (eg,
+/// percentiles and averages).
+#[derive(Debug, Default)]
+pub(crate) struct RangeStats {
+    /// Numbers in ascending order.
+    numbers: Vec<f64>,
+}
+
+impl RangeStats {
+    pub(crate) fn new<'a>(values: impl Iterator<Item = &'a CellValue>) -> Self {
+        let mut numbers = values.filter_map(cell_number).collect::<Vec<_>>();
+        numbers.sort_by(f64::total_cmp);
+        Self { numbers }
+    }
+
+    fn min(&self) -> Option<f64> {
+        self.numbers.first().copied()
+    }
+
+    fn max(&self) -> Option<f64> {
+        self.numbers.last().copied()
+    }
+
+    fn average(&self) -> Option<f64> {
+        (!self.numbers.is_empty())
+            .then(|| self.numbers.iter().sum::<f64>() / self.numbers.len() as f64)
+    }
+
+    /// Returns the percentile (0-100) using linear interpolation.
+    fn percentile(&self, percentile: f64) -> Option<f64> {
+        let last = self.numbers.len().checked_sub(1)?;
+        let rank = (percentile.clamp(0.0, 100.0) / 100.0) * last as f64;
+        let low = rank.floor() as usize;
+        let high = rank.ceil() as usize;
+        let fraction = rank - low as f64;
+        Some(self.numbers[low] + (self.numbers[high] - self.numbers[low]) * fraction)
+    }
+
+    /// Resolves a scale value to a number.
+    pub(crate) fn resolve(&self, value: &ScaleValue) -> Option<f64> {
+        match value {
+            ScaleValue::Min => self.min(),
+            ScaleValue::Max => self.max(),
+            ScaleValue::Number(n) => Some(*n),
+            ScaleValue::Percent(percent) => {
+                let (min, max) = (self.min()?, self.max()?);
+                Some(min + (max - min) * percent.clamp(0.0, 100.0) / 100.0)
+            }
+            ScaleValue::Percentile(percentile) => self.percentile(*percentile),
+        }
+    }
+
+    /// Returns the lowest number of the top (or highest number of the bottom)
+    /// values of a top/bottom rule.
+    fn top_bottom_threshold(&self, top: bool, rank: u32, percent: bool) -> Option<f64> {
+        let len = self.numbers.len();
+        if len == 0 {
+            return None;
+        }
+        let count = if percent {
+            ((len as f64 * rank as f64 / 100.0).floor() as usize).max(1)
+        } else {
+            rank as usize
+        }
+        .min(len);
+        if top {
+            self.numbers.get(len - count).copied()
+        } else {
+            self.numbers.get(count - 1).copied()
+        }
+    }
+}
+
+/// Returns the number of a cell, used by the numeric rules.
+pub(crate) fn cell_number(value: &CellValue) -> Option<f64> {
+    match value {
+        CellValue::Number(_) => f64::try_from(value).ok(),
+        _ => None,
+    }
+}
+
+/// Returns the text of a cell, used by text rules and for counting duplicates.
+pub(crate) fn cell_text(value: &CellValue) -> String {
+    value.to_display().to_lowercase()
+}
+
+fn cell_date(value: &CellValue) -> Option<NaiveDate> {
+    match value {
+        CellValue::Date(date) => Some(*date),
+        CellValue::DateTime(date_time) => Some(date_time.date()),
+        CellValue::DateTimeTz(date_time) => Some(date_time.date_naive()),
+        _ => None,
+    }
+}
+
+impl CompareOperator {
+    fn compare(&self, value: &CellValue, values: &[String]) -> bool {
+        let ordering = |other: &str| {
+            let other = other.trim();
+            match (cell_number(value), other.parse::<f64>()) {
+                (Some(n), Ok(other)) => n.partial_cmp(&other),
+                (None, Err(_)) => Some(cell_text(value).cmp(&other.to_lowercase())),
+                _ => None,
+            }
+        };
+        let first = values.first().and_then(|v| ordering(v));
+        match self {
+            CompareOperator::Equal => first.is_some_and(|o| o.is_eq()),
+            CompareOperator::NotEqual => !first.is_some_and(|o| o.is_eq()),
+            CompareOperator::GreaterThan => first.is_some_and(|o| o.is_gt()),
+            CompareOperator::GreaterThanOrEqual => first.is_some_and(|o| o.is_ge()),
+            CompareOperator::LessThan => first.is_some_and(|o| o.is_lt()),
+            CompareOperator::LessThanOrEqual => first.is_some_and(|o| o.is_le()),
+            CompareOperator::Between | CompareOperator::NotBetween => {
+                let second = values.get(1).and_then(|v| ordering(v));
+                let between = match (first, second) {
+                    // the values may be in either order
+                    (Some(a), Some(b)) => (a.is_ge() && b.is_le()) || (a.is_le() && b.is_ge()),
+                    _ => false,
+                };
+                between == (*self == CompareOperator::Between)
+            }
+        }
+    }
+}
+
+impl TextOperator {
+    fn compare(&self, value: &CellValue, text: &str) -> bool {
+        let value = cell_text(value);
+        let text = text.to_lowercase();
+        match self {
+            TextOperator::Contains => value.contains(&text),
+            TextOperator::NotContains => !value.contains(&text),
+            TextOperator::BeginsWith => value.starts_with(&text),
+            TextOperator::EndsWith => value.ends_with(&text),
+        }
+    }
+}
+
+impl DateOccurring {
+    fn matches(&self, date: NaiveDate, today: NaiveDate) -> bool {
+        // weeks start on Sunday
+        let week_start =
+            |date: NaiveDate| date - Duration::days(date.weekday().num_days_from_sunday() as i64);
+        let month = |date: NaiveDate| date.year() * 12 + date.month0() as i32;
+        match self {
+            DateOccurring::Yesterday => date == today - Duration::days(1),
+            DateOccurring::Today => date == today,
+            DateOccurring::Tomorrow => date == today + Duration::days(1),
+            DateOccurring::Last7Days => date <= today && date > today - Duration::days(7),
+            DateOccurring::LastWeek => week_start(date) == week_start(today) - Duration::days(7),
+            DateOccurring::ThisWeek => week_start(date) == week_start(today),
+            DateOccurring::NextWeek => week_start(date) == week_start(today) + Duration::days(7),
+            DateOccurring::LastMonth => month(date) == month(today) - 1,
+            DateOccurring::ThisMonth => month(date) == month(today),
+            DateOccurring::NextMonth => month(date) == month(today) + 1,
+        }
+    }
+}
+
+impl ConditionalFormatCondition {
+    /// Returns true if a (non-blank) value matches the condition. Formula
+    /// conditions are evaluated by the caller.
+    pub(crate) fn matches(
+        &self,
+        value: &CellValue,
+        stats: &RangeStats,
+        text_counts: &std::collections::HashMap<String, usize>,
+        today: NaiveDate,
+    ) -> bool {
+        match self {
+            ConditionalFormatCondition::Compare { operator, values } => {
+                operator.compare(value, values)
+            }
+            ConditionalFormatCondition::Text { operator, text } => operator.compare(value, text),
+            ConditionalFormatCondition::DateOccurring(occurring) => {
+                cell_date(value).is_some_and(|date| occurring.matches(date, today))
+            }
+            ConditionalFormatCondition::Duplicate => {
+                text_counts.get(&cell_text(value)).is_some_and(|&c| c > 1)
+            }
+            ConditionalFormatCondition::Unique => {
+                text_counts.get(&cell_text(value)).is_some_and(|&c| c == 1)
+            }
+            ConditionalFormatCondition::TopBottom { top, rank, percent } => {
+                let (Some(n), Some(threshold)) = (
+                    cell_number(value),
+                    stats.top_bottom_threshold(*top, *rank, *percent),
+                ) else {
+                    return false;
+                };
+                if *top { n >= threshold } else { n <= threshold }
+            }
+            ConditionalFormatCondition::Average { above, or_equal } => {
+                let (Some(n), Some(average)) = (cell_number(value), stats.average()) else {
+                    return false;
+                };
+                match (above, or_equal) {
+                    (true, false) => n > average,
+                    (true, true) => n >= average,
+                    (false, false) => n < average,
+                    (false, true) => n <= average,
+                }
+            }
+            ConditionalFormatCondition::Formula(_) => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    fn n(value: f64) -> CellValue {
+        CellValue::from(value)
+    }
+
+    #[test]
+    fn test_range_stats() {
+        let values = [n(4.0), n(1.0), CellValue::Text("x".into()), n(3.0), n(2.0)];
+        let stats = RangeStats::new(values.iter());
+        assert_eq!(stats.resolve(&ScaleValue::Min), Some(1.0));
+        assert_eq!(stats.resolve(&ScaleValue::Max), Some(4.0));
+        assert_eq!(stats.resolve(&ScaleValue::Percent(50.0)), Some(2.5));
+        assert_eq!(stats.resolve(&ScaleValue::Percentile(50.0)), Some(2.5));
+        assert_eq!(stats.average(), Some(2.5));
+        assert_eq!(stats.top_bottom_threshold(true, 2, false), Some(3.0));
+        assert_eq!(stats.top_bottom_threshold(false, 25, true), Some(1.0));
+        assert_eq!(RangeStats::default().resolve(&ScaleValue::Min), None);
+    }
+
+    #[test]
+    fn test_conditions() {
+        let stats = RangeStats::default();
+        let counts = HashMap::from([("a".to_string(), 2), ("b".to_string(), 1)]);
+        let today = NaiveDate::from_ymd_opt(2024, 5, 15).unwrap();
+        let matches = |condition: ConditionalFormatCondition, value: CellValue| {
+            condition.matches(&value, &stats, &counts, today)
+        };
+        let compare = |operator, values: &[&str]| ConditionalFormatCondition::Compare {
+            operator,
+            values: values.iter().map(|v| v.to_string()).collect(),
+        };
+
+        assert!(matches(
+            compare(CompareOperator::GreaterThan, &["5"]),
+            n(6.0)
+        ));
+        assert!(!matches(
+            compare(CompareOperator::GreaterThan, &["5"]),
+            n(5.0)
+        ));
+        assert!(matches(
+            compare(CompareOperator::Between, &["10", "1"]),
+            n(5.0)
+        ));
+        assert!(matches(
+            compare(CompareOperator::NotBetween, &["1", "3"]),
+            n(5.0)
+        ));
+        assert!(matches(
+            compare(CompareOperator::Equal, &["Done"]),
+            CellValue::Text("done".into())
+        ));
+        assert!(!matches(
+            compare(CompareOperator::Equal, &["5"]),
+            CellValue::Text("x".into())
+        ));
+
+        let contains = ConditionalFormatCondition::Text {
+            operator: TextOperator::Contains,
+            text: "RAT".into(),
+        };
+        assert!(matches(contains, CellValue::Text("Quadratic".into())));
+
+        let this_week = ConditionalFormatCondition::DateOccurring(DateOccurring::ThisWeek);
+        assert!(matches(
+            this_week.clone(),
+            CellValue::Date(NaiveDate::from_ymd_opt(2024, 5, 12).unwrap())
+        ));
+        assert!(!matches(
+            this_week,
+            CellValue::Date(NaiveDate::from_ymd_opt(2024, 5, 19).unwrap())
+        ));
+        assert!(matches(
+            ConditionalFormatCondition::DateOccurring(DateOccurring::LastMonth),
+            CellValue::Date(NaiveDate::from_ymd_opt(2024, 4, 30).unwrap())
+        ));
+
+        assert!(matches(
+            ConditionalFormatCondition::Duplicate,
+            CellValue::Text("A".into())
+        ));
+        assert!(matches(
+            ConditionalFormatCondition::Unique,
+            CellValue::Text("b".into())
+        ));
+    }
+
+    #[test]
+    fn test_validate() {
+        let scale = ConditionalFormatRule::ColorScale(vec![ColorScalePoint {
+            value: ScaleValue::Min,
+            color: "#ff0000".into(),
+        }]);
+        assert!(scale.validate().is_err());
+
+        let icons = ConditionalFormatRule::IconSet(IconSet {
+            kind: IconSetKind::Arrows3,
+            thresholds: vec![ScaleValue::Percent(33.0), ScaleValue::Percent(67.0)],
+            reverse: false,
+            show_value: true,
+        });
+        assert!(icons.validate().is_ok());
+
+        let bar = ConditionalFormatRule::DataBar(DataBar {
+            min: ScaleValue::Min,
+            max: ScaleValue::Max,
+            color: "blue".into(),
+            show_value: true,
+        });
+        assert!(bar.validate().is_err());
+    }
+}
@@ -0,0 +1,243 @@
+//! Data cleaning: remove duplicate rows, trim whitespace, normalize case,
+//! convert text to typed values, and fill blanks down.
+//!
+//! This module decides what the new values should be; the operations are
+//! created in `controller::operations::data_cleaning`.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::Sheet;
+use crate::grid::Locale;
+use crate::grid::data_table::transform::RowKey;
+use crate::grid::formats::FormatUpdate;
+use crate::util::title_case;
+use crate::{CellValue, Pos};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "js", derive(ts_rs::TS))]
+pub enum DataCleaningCase {
+    Upper,
+    Lower,
+    /// Capitalizes the first letter of each word.
+    Title,
+}
+
+/// A cleaning action that changes values in place.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "js", derive(ts_rs::TS))]
+pub enum DataCleaningAction {
+    /// Removes leading and trailing whitespace from text.
+    TrimWhitespace,
+
+    /// Changes the case of text.
+    NormalizeCase(DataCleaningCase),
+
+    /// Converts text that looks like a number, date, time, etc. to that type,
+    /// using the same detection as user input.
+    ConvertText,
+
+    /// Fills each blank cell with the nearest non-blank value above it.
+    FillBlanksDown,
+}
+
+/// Which row of a set of duplicates is kept.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "js", derive(ts_rs::TS))]
+pub enum DuplicateKeep {
+    #[default]
+    First,
+    Last,
+}
+
+/// Summary of a data cleaning transaction.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "js", derive(ts_rs::TS))]
+pub struct DataCleaningSummary {
+    pub cells_changed: u64,
+    pub rows_removed: u64,
+}
+
+impl DataCleaningAction {
+    /// Returns the cleaned value (and any format that goes with it) if the
+    /// value changes. Text is converted using the file's `locale`.
+    /// `FillBlanksDown` depends on its neighbors and is not handled here.
+    pub fn clean_value(
+        self,
+        value: &CellValue,
+        locale: &Locale,
+    ) -> Option<(CellValue, FormatUpdate)> {
+        let CellValue::Text(text) = value else {
+            return None;
+        };
+        let cleaned = match self {
+            Self::TrimWhitespace => CellValue::Text(text.trim().to_string()),
+            Self::NormalizeCase(case) => CellValue::Text(match case {
+                DataCleaningCase::Upper => text.to_uppercase(),
+                DataCleaningCase::Lower => text.to_lowercase(),
+                DataCleaningCase::Title => title_case(text),
+            }),
+            Self::ConvertText => {
+                let (cell_value, format_update) =
+                    CellValue::string_to_cell_value_with_locale(text.trim(), false, locale);
+                return match cell_value {
+                    CellValue::Text(_) | CellValue::Blank => None,
+                    cell_value => Some((cell_value, format_update)),
+                };
+            }
+            Self::FillBlanksDown => return None,
+        };
+        (&cleaned != value).then(|| (cleaned, FormatUpdate::default()))
+    }
+}
+
+/// Returns the indices of the rows that duplicate another row in their
+/// `key_columns`, keeping either the first or last occurrence. Rows whose key
+/// cells are all blank are never removed.
+pub fn duplicate_rows(
+    rows: &[Vec<CellValue>],
+    key_columns: &[usize],
+    keep: DuplicateKeep,
+) -> Vec<usize> {
+    let mut seen = BTreeMap::new();
+    let mut duplicates = vec![];
+
+    let mut visit = |index: usize, row: &[CellValue]| {
+        let key = RowKey::from_row(row, key_columns);
+        if key.0.iter().all(|v| v.is_blank_or_empty_string()) {
+            return;
+        }
+        if seen.insert(key, index).is_some() {
+            duplicates.push(index);
+        }
+    };
+    match keep {
+        DuplicateKeep::First => rows.iter().enumerate().for_each(|(i, row)| visit(i, row)),
+        DuplicateKeep::Last => rows
+            .iter()
+            .enumerate()
+            .rev()
+            .for_each(|(i, row)| visit(i, row)),
+    }
+
+    duplicates.sort_unstable();
+    duplicates
+}
+
+impl Sheet {
+    /// Returns the value at `pos` if it can be changed by data cleaning, along
+    /// with the position of the imported data table that contains it (if any).
+    ///
+    /// Returns None for code cells, code output, and table name and column
+    /// header rows.
+    pub fn data_cleaning_value(&self, pos: Pos) -> Option<(CellValue, Option<Pos>)> {
+        if let Some((data_table_pos, data_table)) = self.data_table_at(pos) {
+            if data_table.is_code() || pos.y < data_table_pos.y + data_table.y_adjustment(false) {
+                return None;
+            }
+            let value = self.display_value(pos).unwrap_or(CellValue::Blank);
+            return Some((value, Some(data_table_pos)));
+        }
+
+        match self.cell_value(pos) {
+            Some(CellValue::Code(_) | CellValue::Import(_)) => None,
+            value => Some((value.unwrap_or(CellValue::Blank), None)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clean_value() {
+        let locale = Locale::default();
+        let text = CellValue::Text("  hello World ".into());
+        assert_eq!(
+            DataCleaningAction::TrimWhitespace.clean_value(&text, &locale),
+            Some((
+                CellValue::Text("hello World".into()),
+                FormatUpdate::default()
+            ))
+        );
+        assert_eq!(
+            DataCleaningAction::NormalizeCase(DataCleaningCase::Upper)
+                .clean_value(&text, &locale)
+                .map(|(v, _)| v),
+            Some(CellValue::Text("  HELLO WORLD ".into()))
+        );
+        assert_eq!(
+            DataCleaningAction::NormalizeCase(DataCleaningCase::Lower)
+                .clean_value(&CellValue::Text("abc".into()), &locale),
+            None
+        );
+
+        let (value, format) = DataCleaningAction::ConvertText
+            .clean_value(&CellValue::Text(" 1,234 ".into()), &locale)
+            .unwrap();
+        assert_eq!(value, CellValue::from(1234.0));
+        assert_eq!(format.numeric_commas, Some(Some(true)));
+        assert_eq!(
+            DataCleaningAction::ConvertText.clean_value(&CellValue::Text("abc".into()), &locale),
+            None
+        );
+
+        // only text is changed
+        assert_eq!(
+            DataCleaningAction::TrimWhitespace.clean_value(&CellValue::from(1.0), &locale),
+            None
+        );
+    }
+
+    #[test]
+    fn test_clean_value_locale() {
+        let locale = Locale::preset("de-DE").unwrap();
+        let (value, format) = DataCleaningAction::ConvertText
+            .clean_value(&CellValue::Text(" 1.234,5 ".into()), &locale)
+            .unwrap();
+        assert_eq!(value, CellValue::from(1234.5));
+        assert_eq!(format.numeric_commas, Some(Some(true)));
+
+        let (value, _) = DataCleaningAction::ConvertText
+            .clean_value(&CellValue::Text("31/12/2024".into()), &locale)
+            .unwrap();
+        assert_eq!(
+            value,
+            CellValue::Date(chrono::NaiveDate::from_ymd_opt(2024, 12, 31).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_duplicate_rows() {
+        let row =
+            |a: &str, b: &str| vec![CellValue::parse_from_str(a), CellValue::parse_from_str(b)];
+        let rows = vec![
+            row("a", "1"),
+            row("A", "2"),
+            row("b", "1"),
+            row("a", "1"),
+            row("", "1"),
+            row("", "1"),
+        ];
+
+        assert_eq!(
+            duplicate_rows(&rows, &[0, 1], DuplicateKeep::First),
+            vec![3, 5]
+        );
+        assert_eq!(
+            duplicate_rows(&rows, &[0, 1], DuplicateKeep::Last),
+            vec![0, 4]
+        );
+        // text is compared case-insensitively
+        assert_eq!(
+            duplicate_rows(&rows, &[0], DuplicateKeep::First),
+            vec![1, 3]
+        );
+        assert_eq!(
+            duplicate_rows(&rows, &[1], DuplicateKeep::Last),
+            vec![0, 2, 3, 4]
+        );
+    }
+}
@@ -68,6 +68,37 @@ impl Sheet {
         }
     }
 
+    /// Font size, font family and rotation change the height of any cell with
+    /// content, so rows are resized whether the value is set or cleared.
+    fn auto_height_transaction_changes<T: std::fmt::Debug + Clone + PartialEq>(
+        &self,
+        format: SheetFormatUpdatesType<T>,
+        dirty_hashes: &mut HashSet<Pos>,
+        resize_rows: &mut HashSet<i64>,
+    ) {
+        let sheet_bounds =
+            |ignore_formatting: bool| -> Option<Rect> { self.bounds(ignore_formatting).into() };
+        let columns_bounds = |start: i64, end: i64, ignore_formatting: bool| {
+            self.columns_bounds(start, end, ignore_formatting)
+        };
+        let rows_bounds = |start: i64, end: i64, ignore_formatting: bool| {
+            self.rows_bounds(start, end, ignore_formatting)
+        };
+        if let Some(format) = format {
+            format
+                .to_rects_with_grid_bounds(&sheet_bounds, &columns_bounds, &rows_bounds, true)
+                .for_each(|(x1, y1, x2, y2, _)| {
+                    let rect = Rect::new(x1, y1, x2, y2);
+                    dirty_hashes.extend(rect.to_hashes());
+                    for y in y1..=y2 {
+                        if self.row_bounds(y, true).is_some() {
+                            resize_rows.insert(y);
+                        }
+                    }
+                });
+        }
+    }
+
     /// Returns the dirty hashes and rows changed for the formats
     fn formats_transaction_changes(
         &self,
@@ -144,6 +175,27 @@ impl Sheet {
             &mut dirty_hashes,
             &mut resize_rows,
         );
+        self.auto_height_transaction_changes(
+            formats.font_size.to_owned(),
+            &mut dirty_hashes,
+            &mut resize_rows,
+        );
+        self.auto_height_transaction_changes(
+            formats.font_family.to_owned(),
+            &mut dirty_hashes,
+            &mut resize_rows,
+        );
+        self.auto_height_transaction_changes(
+            formats.text_rotation.to_owned(),
+            &mut dirty_hashes,
+            &mut resize_rows,
+        );
+        self.format_transaction_changes(
+            formats.indent.to_owned(),
+            true,
+            &mut dirty_hashes,
+            &mut resize_rows,
+        );
 
         // for wrap, we need to check if the new formats is wrap or old is wrap
         // no need to resize rows if wrap is not present in both new and old formats
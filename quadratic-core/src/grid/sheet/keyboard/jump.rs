@@ -216,12 +216,16 @@ impl Sheet {
 
     /// Returns the SheetPos after a jump (ctrl/cmd + arrow key)
     pub fn jump_cursor(&self, current: Pos, direction: Direction) -> Pos {
-        match direction {
+        let from = current;
+        let current = self.merged_cell_edge(current, direction);
+        let pos = match direction {
             Direction::Up => self.jump_up(current),
             Direction::Down => self.jump_down(current),
             Direction::Left => self.jump_left(current),
             Direction::Right => self.jump_right(current),
-        }
+        };
+        let pos = self.skip_hidden(from, pos, direction);
+        self.merged_cell_anchor(pos)
     }
 }
 
@@ -572,4 +576,28 @@ mod tests {
 
         assert_eq!(sheet.jump_left(pos![D2]), pos![A2]);
     }
+
+    #[test]
+    fn test_jump_merged_cells() {
+        let mut sheet = Sheet::test();
+        sheet.set_cell_value(pos![B2], CellValue::Number(1.into()));
+        sheet.set_cell_value(pos![E2], CellValue::Number(2.into()));
+        sheet.merge_cells.merge(crate::Rect::test_a1("B2:C3"));
+
+        assert_eq!(sheet.jump_cursor(pos![B2], Direction::Right), pos![E2]);
+        assert_eq!(sheet.jump_cursor(pos![E2], Direction::Left), pos![B2]);
+        // a jump that ends within a merged cell selects its top left cell
+        assert_eq!(sheet.jump_cursor(pos![A3], Direction::Right), pos![B2]);
+    }
+
+    #[test]
+    fn test_jump_hidden() {
+        let mut sheet = Sheet::test();
+        sheet.set_cell_value(pos![C2], CellValue::Number(1.into()));
+        sheet.set_cell_value(pos![D2], CellValue::Number(2.into()));
+        sheet.offsets.set_column_hidden(3, true);
+
+        // a jump that ends in a hidden column continues to the next visible one
+        assert_eq!(sheet.jump_cursor(pos![A2], Direction::Right), pos![D2]);
+    }
 }
@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 
+use crate::{Pos, grid::Sheet};
+
 mod jump;
 mod move_cursor;
 
@@ -11,3 +13,72 @@ pub enum Direction {
     Left,
     Right,
 }
+
+impl Sheet {
+    /// Returns the edge of the merged cell that contains the position in the
+    /// direction of movement (or the position if it is not merged).
+    fn merged_cell_edge(&self, pos: Pos, direction: Direction) -> Pos {
+        let Some(merge) = self.merge_cells.get(pos) else {
+            return pos;
+        };
+        match direction {
+            Direction::Up => Pos {
+                x: pos.x,
+                y: merge.min.y,
+            },
+            Direction::Down => Pos {
+                x: pos.x,
+                y: merge.max.y,
+            },
+            Direction::Left => Pos {
+                x: merge.min.x,
+                y: pos.y,
+            },
+            Direction::Right => Pos {
+                x: merge.max.x,
+                y: pos.y,
+            },
+        }
+    }
+
+    /// Moves the position past hidden columns or rows in the direction of
+    /// movement. Returns `from` if there is no visible cell before the edge
+    /// of the sheet.
+    fn skip_hidden(&self, from: Pos, mut pos: Pos, direction: Direction) -> Pos {
+        match direction {
+            Direction::Up | Direction::Down => {
+                let step = if matches!(direction, Direction::Up) {
+                    -1
+                } else {
+                    1
+                };
+                while self.offsets.is_row_hidden(pos.y) {
+                    pos.y += step;
+                    if pos.y < 1 {
+                        return from;
+                    }
+                }
+            }
+            Direction::Left | Direction::Right => {
+                let step = if matches!(direction, Direction::Left) {
+                    -1
+                } else {
+                    1
+                };
+                while self.offsets.is_column_hidden(pos.x) {
+                    pos.x += step;
+                    if pos.x < 1 {
+                        return from;
+                    }
+                }
+            }
+        }
+        pos
+    }
+
+    /// Returns the top left cell of the merged cell that contains the
+    /// position (or the position if it is not merged).
+    fn merged_cell_anchor(&self, pos: Pos) -> Pos {
+        self.merge_cells.get(pos).map_or(pos, |merge| merge.min)
+    }
+}
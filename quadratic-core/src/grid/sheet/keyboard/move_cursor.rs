@@ -8,8 +8,16 @@ use super::Direction;
 // todo: this should use A1Context instead of quadraticCore
 
 impl Sheet {
-    /// Returns a new Pos after pressing an arrow key.
+    /// Returns a new Pos after pressing an arrow key. Merged cells are moved
+    /// over as a single cell and hidden columns and rows are skipped.
     pub(crate) fn move_cursor(&self, pos: Pos, direction: Direction) -> Pos {
+        let from = pos;
+        let pos = self.merged_cell_edge(pos, direction);
+        let pos = self.skip_hidden(from, self.move_cursor_cell(pos, direction), direction);
+        self.merged_cell_anchor(pos)
+    }
+
+    fn move_cursor_cell(&self, pos: Pos, direction: Direction) -> Pos {
         match direction {
             Direction::Up => {
                 if pos.y == 1 {
@@ -199,4 +207,34 @@ mod tests {
         assert_eq!(sheet.move_cursor(pos![D3], Direction::Left), pos![C3]);
         assert_eq!(sheet.move_cursor(pos![C3], Direction::Left), pos![B3]);
     }
+
+    #[test]
+    fn test_merged_cells_navigation() {
+        let mut sheet = Sheet::test();
+        sheet.merge_cells.merge(crate::Rect::test_a1("B2:C3"));
+
+        // moving into a merged cell selects its top left cell
+        assert_eq!(sheet.move_cursor(pos![C1], Direction::Down), pos![B2]);
+        assert_eq!(sheet.move_cursor(pos![D3], Direction::Left), pos![B2]);
+
+        // moving out of a merged cell starts from its edge
+        assert_eq!(sheet.move_cursor(pos![B2], Direction::Right), pos![D2]);
+        assert_eq!(sheet.move_cursor(pos![B2], Direction::Down), pos![B4]);
+        assert_eq!(sheet.move_cursor(pos![B2], Direction::Up), pos![B1]);
+        assert_eq!(sheet.move_cursor(pos![B2], Direction::Left), pos![A2]);
+    }
+
+    #[test]
+    fn test_hidden_navigation() {
+        let mut sheet = Sheet::test();
+        sheet.offsets.set_column_hidden(2, true);
+        sheet.offsets.set_column_hidden(3, true);
+        sheet.offsets.set_row_hidden(1, true);
+
+        assert_eq!(sheet.move_cursor(pos![A2], Direction::Right), pos![D2]);
+        assert_eq!(sheet.move_cursor(pos![D2], Direction::Left), pos![A2]);
+
+        // there is no visible row above
+        assert_eq!(sheet.move_cursor(pos![A2], Direction::Up), pos![A2]);
+    }
 }
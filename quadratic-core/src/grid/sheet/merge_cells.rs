@@ -0,0 +1,331 @@
+//! Merged cells.
+//!
+//! A merged cell is a rectangle of cells that is displayed as a single cell.
+//! Its value, formatting, and cursor position are taken from the top-left
+//! (anchor) cell. Merged cells never overlap.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    Pos, Rect,
+    a1::{A1Selection, CellRefRange, CellRefRangeEnd, RefRangeBounds},
+    grid::Sheet,
+};
+
+#[derive(Default, Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct MergeCells {
+    rects: Vec<Rect>,
+}
+
+impl MergeCells {
+    /// Returns true if there are no merged cells.
+    pub fn is_empty(&self) -> bool {
+        self.rects.is_empty()
+    }
+
+    /// Returns all merged cells.
+    pub fn rects(&self) -> &[Rect] {
+        &self.rects
+    }
+
+    /// Returns the merged cell that contains the position.
+    pub fn get(&self, pos: Pos) -> Option<Rect> {
+        self.rects.iter().find(|rect| rect.contains(pos)).copied()
+    }
+
+    /// Returns true if the position is within a merged cell but is not its
+    /// anchor (ie, the cell is hidden by the merge).
+    pub fn is_hidden(&self, pos: Pos) -> bool {
+        self.get(pos).is_some_and(|rect| rect.min != pos)
+    }
+
+    /// Returns the merged cells that intersect the rect.
+    pub fn in_rect(&self, rect: Rect) -> Vec<Rect> {
+        self.rects
+            .iter()
+            .filter(|merge| merge.intersects(rect))
+            .copied()
+            .collect()
+    }
+
+    /// Merges the cells in the rect. Returns the merged cells that were
+    /// removed because they overlap the rect. Single cells are not merged.
+    pub fn merge(&mut self, rect: Rect) -> Vec<Rect> {
+        if rect.len() <= 1 {
+            return vec![];
+        }
+        let removed = self.unmerge(rect);
+        self.rects.push(rect);
+        removed
+    }
+
+    /// Removes all merged cells that intersect the rect. Returns the removed
+    /// merged cells.
+    pub fn unmerge(&mut self, rect: Rect) -> Vec<Rect> {
+        let (removed, kept) = self.rects.iter().partition(|merge| merge.intersects(rect));
+        self.rects = kept;
+        removed
+    }
+
+    /// Expands the rect until it fully contains all merged cells that it
+    /// intersects.
+    pub fn expand_rect(&self, mut rect: Rect) -> Rect {
+        loop {
+            let expanded = self
+                .rects
+                .iter()
+                .filter(|merge| merge.intersects(rect))
+                .fold(rect, |rect, merge| rect.union(merge));
+            if expanded == rect {
+                return rect;
+            }
+            rect = expanded;
+        }
+    }
+
+    /// Shifts merged cells for an inserted column. A merged cell that spans
+    /// the inserted column grows by one. Returns true if any merged cell
+    /// changed.
+    pub fn insert_column(&mut self, column: i64) -> bool {
+        let mut changed = false;
+        for rect in self.rects.iter_mut() {
+            if rect.min.x >= column {
+                rect.min.x += 1;
+                rect.max.x += 1;
+                changed = true;
+            } else if rect.max.x >= column {
+                rect.max.x += 1;
+                changed = true;
+            }
+        }
+        changed
+    }
+
+    /// Shifts merged cells for an inserted row. A merged cell that spans the
+    /// inserted row grows by one. Returns true if any merged cell changed.
+    pub fn insert_row(&mut self, row: i64) -> bool {
+        let mut changed = false;
+        for rect in self.rects.iter_mut() {
+            if rect.min.y >= row {
+                rect.min.y += 1;
+                rect.max.y += 1;
+                changed = true;
+            } else if rect.max.y >= row {
+                rect.max.y += 1;
+                changed = true;
+            }
+        }
+        changed
+    }
+
+    /// Shifts merged cells for a deleted column. A merged cell that contains
+    /// the column shrinks by one; it is removed if only one cell remains.
+    /// Returns true if any merged cell changed.
+    pub fn remove_column(&mut self, column: i64) -> bool {
+        let mut changed = false;
+        for rect in self.rects.iter_mut() {
+            if rect.min.x > column {
+                rect.min.x -= 1;
+                rect.max.x -= 1;
+                changed = true;
+            } else if rect.max.x >= column {
+                rect.max.x -= 1;
+                changed = true;
+            }
+        }
+        self.rects
+            .retain(|rect| rect.max.x >= rect.min.x && rect.len() > 1);
+        changed
+    }
+
+    /// Shifts merged cells for a deleted row. A merged cell that contains the
+    /// row shrinks by one; it is removed if only one cell remains. Returns
+    /// true if any merged cell changed.
+    pub fn remove_row(&mut self, row: i64) -> bool {
+        let mut changed = false;
+        for rect in self.rects.iter_mut() {
+            if rect.min.y > row {
+                rect.min.y -= 1;
+                rect.max.y -= 1;
+                changed = true;
+            } else if rect.max.y >= row {
+                rect.max.y -= 1;
+                changed = true;
+            }
+        }
+        self.rects
+            .retain(|rect| rect.max.y >= rect.min.y && rect.len() > 1);
+        changed
+    }
+}
+
+impl From<Vec<Rect>> for MergeCells {
+    fn from(rects: Vec<Rect>) -> Self {
+        let mut merge_cells = MergeCells::default();
+        for rect in rects {
+            merge_cells.merge(rect);
+        }
+        merge_cells
+    }
+}
+
+impl Sheet {
+    /// Expands the finite ranges of a selection to fully include the merged
+    /// cells they intersect and moves the cursor to the anchor of its merged
+    /// cell. The direction of each range is kept.
+    pub fn expand_selection_to_merged_cells(&self, selection: &A1Selection) -> A1Selection {
+        let mut selection = selection.clone();
+        if self.merge_cells.is_empty() {
+            return selection;
+        }
+        if let Some(merge) = self.merge_cells.get(selection.cursor) {
+            selection.cursor = merge.min;
+        }
+        for range in selection.ranges.iter_mut() {
+            let CellRefRange::Sheet { range: bounds } = range else {
+                continue;
+            };
+            let Some(rect) = bounds.to_rect() else {
+                continue;
+            };
+            let expanded = self.merge_cells.expand_rect(rect);
+            if expanded == rect {
+                continue;
+            }
+            let reverse_x = bounds.start.col() > bounds.end.col();
+            let reverse_y = bounds.start.row() > bounds.end.row();
+            let corner = |max_x: bool, max_y: bool| Pos {
+                x: if max_x {
+                    expanded.max.x
+                } else {
+                    expanded.min.x
+                },
+                y: if max_y {
+                    expanded.max.y
+                } else {
+                    expanded.min.y
+                },
+            };
+            *bounds = RefRangeBounds {
+                start: CellRefRangeEnd::new_relative_pos(corner(reverse_x, reverse_y)),
+                end: CellRefRangeEnd::new_relative_pos(corner(!reverse_x, !reverse_y)),
+            };
+        }
+        selection
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_selection_to_merged_cells() {
+        let mut sheet = Sheet::test();
+        sheet.merge_cells.merge(Rect::test_a1("B2:C3"));
+
+        let mut selection = A1Selection::test_a1("C3");
+        selection.cursor = pos![C3];
+        let expanded = sheet.expand_selection_to_merged_cells(&selection);
+        assert_eq!(expanded.cursor, pos![B2]);
+        assert_eq!(expanded, A1Selection::test_a1("B2:C3"));
+
+        // the direction of the range is kept
+        let expanded = sheet.expand_selection_to_merged_cells(&A1Selection::test_a1("D4:C3"));
+        assert_eq!(expanded.ranges, A1Selection::test_a1("D4:B2").ranges);
+
+        // unbounded ranges are not changed
+        let selection = A1Selection::test_a1("B:C");
+        assert_eq!(
+            sheet.expand_selection_to_merged_cells(&selection),
+            selection
+        );
+    }
+
+    #[test]
+    fn test_merge_and_unmerge() {
+        let mut merge_cells = MergeCells::default();
+        assert!(merge_cells.merge(Rect::test_a1("B2:C3")).is_empty());
+        assert_eq!(merge_cells.get(pos![C3]), Some(Rect::test_a1("B2:C3")));
+        assert_eq!(merge_cells.get(pos![D3]), None);
+        assert!(!merge_cells.is_hidden(pos![B2]));
+        assert!(merge_cells.is_hidden(pos![C2]));
+
+        // single cells are not merged
+        assert!(merge_cells.merge(Rect::test_a1("E5")).is_empty());
+        assert_eq!(merge_cells.rects().len(), 1);
+
+        // overlapping merges are replaced
+        assert_eq!(
+            merge_cells.merge(Rect::test_a1("C3:D4")),
+            vec![Rect::test_a1("B2:C3")]
+        );
+        assert_eq!(merge_cells.rects(), &[Rect::test_a1("C3:D4")]);
+
+        assert_eq!(
+            merge_cells.unmerge(Rect::test_a1("D4")),
+            vec![Rect::test_a1("C3:D4")]
+        );
+        assert!(merge_cells.is_empty());
+    }
+
+    #[test]
+    fn test_expand_rect() {
+        let merge_cells = MergeCells::from(vec![Rect::test_a1("B2:C3"), Rect::test_a1("D3:E6")]);
+        assert_eq!(
+            merge_cells.expand_rect(Rect::test_a1("A1:B2")),
+            Rect::test_a1("A1:C3")
+        );
+        // expanding to one merge can pull in another
+        assert_eq!(
+            merge_cells.expand_rect(Rect::test_a1("C2:D2")),
+            Rect::test_a1("B2:E6")
+        );
+        assert_eq!(
+            merge_cells.expand_rect(Rect::test_a1("G1:H2")),
+            Rect::test_a1("G1:H2")
+        );
+    }
+
+    #[test]
+    fn test_insert_remove_column() {
+        let mut merge_cells =
+            MergeCells::from(vec![Rect::test_a1("B2:C3"), Rect::test_a1("E1:F1")]);
+
+        assert!(merge_cells.insert_column(3));
+        assert_eq!(
+            merge_cells.rects(),
+            &[Rect::test_a1("B2:D3"), Rect::test_a1("F1:G1")]
+        );
+
+        assert!(merge_cells.remove_column(2));
+        assert_eq!(
+            merge_cells.rects(),
+            &[Rect::test_a1("B2:C3"), Rect::test_a1("E1:F1")]
+        );
+
+        // a merge that is reduced to a single cell is removed
+        assert!(merge_cells.remove_column(5));
+        assert_eq!(merge_cells.rects(), &[Rect::test_a1("B2:C3")]);
+
+        assert!(!merge_cells.insert_column(10));
+    }
+
+    #[test]
+    fn test_insert_remove_row() {
+        let mut merge_cells =
+            MergeCells::from(vec![Rect::test_a1("B2:C3"), Rect::test_a1("A5:A6")]);
+
+        assert!(merge_cells.insert_row(2));
+        assert_eq!(
+            merge_cells.rects(),
+            &[Rect::test_a1("B3:C4"), Rect::test_a1("A6:A7")]
+        );
+
+        assert!(merge_cells.remove_row(7));
+        assert_eq!(merge_cells.rects(), &[Rect::test_a1("B3:C4")]);
+
+        assert!(merge_cells.remove_row(3));
+        assert_eq!(merge_cells.rects(), &[Rect::test_a1("B3:C3")]);
+    }
+}
@@ -0,0 +1,397 @@
+//! Row and column grouping (outlines).
+//!
+//! Each column or row has an outline level (0 is ungrouped). A group at level
+//! `n` is a run of consecutive indices whose level is at least `n`, so groups
+//! nest. A collapsed group hides its columns or rows; the group is identified
+//! by its first index and level.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use crate::Axis;
+
+/// The maximum outline level (same as Excel).
+pub const MAX_OUTLINE_LEVEL: u8 = 7;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, TS)]
+pub struct OutlineGroup {
+    pub start: i64,
+    pub end: i64,
+    pub level: u8,
+    pub collapsed: bool,
+}
+
+#[derive(Default, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Outline {
+    /// Outline level by index (only levels > 0 are stored).
+    levels: BTreeMap<i64, u8>,
+
+    /// Collapsed groups by (start, level).
+    collapsed: BTreeSet<(i64, u8)>,
+}
+
+/// The column and row outlines of a sheet.
+#[derive(Default, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Outlines {
+    pub columns: Outline,
+    pub rows: Outline,
+}
+
+impl Outlines {
+    /// Returns the column (X) or row (Y) outline.
+    pub fn get(&self, axis: Axis) -> &Outline {
+        match axis {
+            Axis::X => &self.columns,
+            Axis::Y => &self.rows,
+        }
+    }
+}
+
+impl Outline {
+    /// Creates an outline from levels and collapsed groups. Collapsed groups
+    /// that do not exist are ignored.
+    pub fn new(
+        levels: impl IntoIterator<Item = (i64, u8)>,
+        collapsed: impl IntoIterator<Item = (i64, u8)>,
+    ) -> Self {
+        let mut outline = Self {
+            levels: levels
+                .into_iter()
+                .filter(|(index, level)| *index >= 1 && *level > 0)
+                .map(|(index, level)| (index, level.min(MAX_OUTLINE_LEVEL)))
+                .collect(),
+            collapsed: collapsed.into_iter().collect(),
+        };
+        outline.retain_collapsed();
+        outline
+    }
+
+    /// Returns true if there are no groups.
+    pub fn is_empty(&self) -> bool {
+        self.levels.is_empty()
+    }
+
+    /// Returns the outline level of an index.
+    pub fn level(&self, index: i64) -> u8 {
+        self.levels.get(&index).copied().unwrap_or(0)
+    }
+
+    /// Returns the outline levels (only levels > 0).
+    pub fn levels(&self) -> impl Iterator<Item = (i64, u8)> + '_ {
+        self.levels.iter().map(|(index, level)| (*index, *level))
+    }
+
+    /// Returns the collapsed groups as (start, level).
+    pub fn collapsed(&self) -> impl Iterator<Item = (i64, u8)> + '_ {
+        self.collapsed.iter().copied()
+    }
+
+    /// Returns the highest outline level.
+    pub fn max_level(&self) -> u8 {
+        self.levels.values().max().copied().unwrap_or(0)
+    }
+
+    /// Returns true if any index >= `from` is grouped.
+    pub fn has_levels_from(&self, from: i64) -> bool {
+        self.levels.range(from..).next().is_some()
+    }
+
+    /// Returns all groups, ordered by level and then start.
+    pub fn groups(&self) -> Vec<OutlineGroup> {
+        let mut groups = vec![];
+        for level in 1..=self.max_level() {
+            let mut current: Option<(i64, i64)> = None;
+            for (index, _) in self.levels.iter().filter(|(_, l)| **l >= level) {
+                current = match current {
+                    Some((start, end)) if end + 1 == *index => Some((start, *index)),
+                    Some((start, end)) => {
+                        groups.push(self.make_group(start, end, level));
+                        Some((*index, *index))
+                    }
+                    None => Some((*index, *index)),
+                };
+            }
+            if let Some((start, end)) = current {
+                groups.push(self.make_group(start, end, level));
+            }
+        }
+        groups
+    }
+
+    fn make_group(&self, start: i64, end: i64, level: u8) -> OutlineGroup {
+        OutlineGroup {
+            start,
+            end,
+            level,
+            collapsed: self.collapsed.contains(&(start, level)),
+        }
+    }
+
+    /// Returns the group that starts at the index with the level.
+    pub fn group(&self, start: i64, level: u8) -> Option<OutlineGroup> {
+        if level == 0 || self.level(start) < level || self.level(start - 1) >= level {
+            return None;
+        }
+        let mut end = start;
+        while self.level(end + 1) >= level {
+            end += 1;
+        }
+        Some(self.make_group(start, end, level))
+    }
+
+    /// Returns the indices of a group that are not within a collapsed nested
+    /// group (ie, the indices that are shown when the group is expanded).
+    pub fn visible_when_expanded(&self, group: &OutlineGroup) -> Vec<i64> {
+        let nested = self
+            .groups()
+            .into_iter()
+            .filter(|nested| {
+                nested.collapsed
+                    && nested.level > group.level
+                    && nested.start >= group.start
+                    && nested.end <= group.end
+            })
+            .collect::<Vec<_>>();
+        (group.start..=group.end)
+            .filter(|index| {
+                !nested
+                    .iter()
+                    .any(|nested| (nested.start..=nested.end).contains(index))
+            })
+            .collect()
+    }
+
+    /// Returns true if the index is within a collapsed group (ie, it is hidden
+    /// by the outline).
+    pub fn is_collapsed(&self, index: i64) -> bool {
+        (1..=self.level(index)).any(|level| {
+            let mut start = index;
+            while self.level(start - 1) >= level {
+                start -= 1;
+            }
+            self.collapsed.contains(&(start, level))
+        })
+    }
+
+    /// Adds a level to each index in the range. Returns true if any level
+    /// changed.
+    pub fn group_range(&mut self, start: i64, end: i64) -> bool {
+        let mut changed = false;
+        for index in start.max(1)..=end {
+            let level = self.level(index);
+            if level < MAX_OUTLINE_LEVEL {
+                self.levels.insert(index, level + 1);
+                changed = true;
+            }
+        }
+        self.retain_collapsed();
+        changed
+    }
+
+    /// Removes a level from each index in the range. Returns true if any
+    /// level changed.
+    pub fn ungroup_range(&mut self, start: i64, end: i64) -> bool {
+        let mut changed = false;
+        for index in start.max(1)..=end {
+            match self.level(index) {
+                0 => (),
+                1 => {
+                    self.levels.remove(&index);
+                    changed = true;
+                }
+                level => {
+                    self.levels.insert(index, level - 1);
+                    changed = true;
+                }
+            }
+        }
+        self.retain_collapsed();
+        changed
+    }
+
+    /// Collapses or expands a group. Returns true if the state changed.
+    pub fn set_collapsed(&mut self, start: i64, level: u8, collapsed: bool) -> bool {
+        if self.group(start, level).is_none() {
+            return false;
+        }
+        if collapsed {
+            self.collapsed.insert((start, level))
+        } else {
+            self.collapsed.remove(&(start, level))
+        }
+    }
+
+    /// Removes collapsed state for groups that no longer exist.
+    fn retain_collapsed(&mut self) {
+        let collapsed = std::mem::take(&mut self.collapsed);
+        self.collapsed = collapsed
+            .into_iter()
+            .filter(|(start, level)| self.group(*start, *level).is_some())
+            .collect();
+    }
+
+    /// Shifts the outline for an inserted index. An index inserted within a
+    /// group joins the group. Returns true if the outline changed.
+    pub fn insert(&mut self, index: i64) -> bool {
+        if !self.has_levels_from(index) {
+            return false;
+        }
+        let level = self.level(index - 1).min(self.level(index));
+        let shifted = self.levels.split_off(&index);
+        self.levels
+            .extend(shifted.into_iter().map(|(i, level)| (i + 1, level)));
+        if level > 0 {
+            self.levels.insert(index, level);
+        }
+        self.collapsed = std::mem::take(&mut self.collapsed)
+            .into_iter()
+            .map(|(start, level)| {
+                if start >= index {
+                    (start + 1, level)
+                } else {
+                    (start, level)
+                }
+            })
+            .collect();
+        self.retain_collapsed();
+        true
+    }
+
+    /// Shifts the outline for a removed index. Returns true if the outline
+    /// changed.
+    pub fn remove(&mut self, index: i64) -> bool {
+        if !self.has_levels_from(index) {
+            return false;
+        }
+        let shifted = self.levels.split_off(&index);
+        self.levels.extend(
+            shifted
+                .into_iter()
+                .filter(|(i, _)| *i != index)
+                .map(|(i, level)| (i - 1, level)),
+        );
+        self.collapsed = std::mem::take(&mut self.collapsed)
+            .into_iter()
+            .map(|(start, level)| {
+                if start > index {
+                    (start - 1, level)
+                } else {
+                    (start, level)
+                }
+            })
+            .collect();
+        self.retain_collapsed();
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn group(start: i64, end: i64, level: u8, collapsed: bool) -> OutlineGroup {
+        OutlineGroup {
+            start,
+            end,
+            level,
+            collapsed,
+        }
+    }
+
+    #[test]
+    fn test_groups() {
+        let mut outline = Outline::default();
+        assert!(outline.group_range(2, 6));
+        assert!(outline.group_range(3, 4));
+        assert!(outline.group_range(8, 8));
+        assert_eq!(outline.max_level(), 2);
+        assert_eq!(
+            outline.groups(),
+            vec![
+                group(2, 6, 1, false),
+                group(8, 8, 1, false),
+                group(3, 4, 2, false)
+            ]
+        );
+        assert_eq!(outline.group(2, 1), Some(group(2, 6, 1, false)));
+        assert_eq!(outline.group(3, 1), None);
+        assert_eq!(outline.group(3, 2), Some(group(3, 4, 2, false)));
+
+        assert!(outline.ungroup_range(3, 4));
+        assert_eq!(
+            outline.groups(),
+            vec![group(2, 6, 1, false), group(8, 8, 1, false)]
+        );
+        assert!(outline.ungroup_range(1, 10));
+        assert!(outline.is_empty());
+        assert!(!outline.ungroup_range(1, 10));
+    }
+
+    #[test]
+    fn test_collapsed() {
+        let mut outline = Outline::new([(2, 1), (3, 2), (4, 2), (5, 1)], []);
+        assert!(outline.set_collapsed(3, 2, true));
+        assert!(!outline.set_collapsed(3, 2, true));
+        assert!(!outline.set_collapsed(4, 2, true));
+        assert_eq!(outline.group(3, 2), Some(group(3, 4, 2, true)));
+        assert_eq!(
+            outline.visible_when_expanded(&outline.group(2, 1).unwrap()),
+            vec![2, 5]
+        );
+
+        // removing the group removes its collapsed state
+        outline.ungroup_range(3, 4);
+        assert_eq!(outline.collapsed().count(), 0);
+
+        // collapsed groups that do not exist are ignored
+        let outline = Outline::new([(2, 1)], [(2, 1), (5, 1)]);
+        assert_eq!(outline.collapsed().collect::<Vec<_>>(), vec![(2, 1)]);
+    }
+
+    #[test]
+    fn test_insert_remove() {
+        let mut outline = Outline::new([(2, 1), (3, 1), (5, 1)], [(2, 1), (5, 1)]);
+
+        // inserting within a group grows it
+        assert!(outline.insert(3));
+        assert_eq!(
+            outline.groups(),
+            vec![group(2, 4, 1, true), group(6, 6, 1, true)]
+        );
+
+        // inserting before a group shifts it
+        assert!(outline.insert(2));
+        assert_eq!(
+            outline.groups(),
+            vec![group(3, 5, 1, true), group(7, 7, 1, true)]
+        );
+
+        assert!(outline.remove(3));
+        assert_eq!(
+            outline.groups(),
+            vec![group(3, 4, 1, true), group(6, 6, 1, true)]
+        );
+        assert!(outline.remove(6));
+        assert_eq!(outline.groups(), vec![group(3, 4, 1, true)]);
+        assert!(!outline.remove(10));
+        assert!(!outline.insert(10));
+    }
+
+    #[test]
+    fn test_is_collapsed() {
+        let mut outline = Outline::new([(2, 1), (3, 2), (4, 2), (5, 1)], [(3, 2)]);
+        assert!(!outline.is_collapsed(2));
+        assert!(outline.is_collapsed(3));
+        assert!(outline.is_collapsed(4));
+        assert!(!outline.is_collapsed(5));
+        assert!(!outline.is_collapsed(6));
+
+        // an index inserted within a collapsed group is collapsed
+        outline.insert(4);
+        assert!(outline.is_collapsed(4));
+        assert!(outline.is_collapsed(5));
+        assert!(!outline.is_collapsed(6));
+    }
+}
@@ -0,0 +1,372 @@
+//! Protection of a sheet and of ranges within it.
+//!
+//! A protected range may only be edited by its editors. A protected sheet may
+//! only be edited by the sheet's editors, except for its editable ranges (eg,
+//! the input cells of a template). Editors are identified by their email.
+
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use uuid::Uuid;
+
+use crate::{
+    Rect,
+    a1::{A1Context, A1Selection, CellRefRange},
+    controller::{
+        active_transactions::pending_transaction::PendingTransaction,
+        operations::operation::Operation,
+    },
+    error_core::{CoreError, Result},
+    grid::SheetId,
+};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, TS)]
+pub struct ProtectedRange {
+    pub id: Uuid,
+    pub selection: A1Selection,
+    pub description: Option<String>,
+
+    /// Emails of the users who may edit the range.
+    pub editors: Vec<String>,
+}
+
+#[derive(Default, Serialize, Deserialize, Debug, Clone, PartialEq, TS)]
+pub struct SheetProtection {
+    /// Whether the whole sheet is protected.
+    pub protected: bool,
+
+    /// Emails of the users who may edit a protected sheet and change its
+    /// protection.
+    pub editors: Vec<String>,
+
+    /// Ranges of a protected sheet that anyone may edit.
+    pub editable_ranges: Vec<A1Selection>,
+
+    pub ranges: Vec<ProtectedRange>,
+}
+
+impl SheetProtection {
+    /// Returns true if nothing in the sheet is protected.
+    pub fn is_empty(&self) -> bool {
+        !self.protected && self.ranges.is_empty()
+    }
+
+    fn is_editor(editors: &[String], editor: Option<&str>) -> bool {
+        editor.is_some_and(|editor| {
+            editors
+                .iter()
+                .any(|allowed| allowed.eq_ignore_ascii_case(editor))
+        })
+    }
+
+    /// Returns true if the editor may change the sheet itself (eg, rename or
+    /// delete it).
+    pub fn can_edit_sheet(&self, editor: Option<&str>) -> bool {
+        !self.protected || Self::is_editor(&self.editors, editor)
+    }
+
+    fn contains_rect(selection: &A1Selection, rect: Rect, a1_context: &A1Context) -> bool {
+        selection.ranges.iter().any(|range| match range {
+            CellRefRange::Sheet { range } => range.contains_rect(rect),
+            CellRefRange::Table { range } => range
+                .to_largest_rect(a1_context)
+                .is_some_and(|table_rect| table_rect.contains_rect(&rect)),
+        })
+    }
+
+    /// Checks whether the editor may change the cells in a (possibly
+    /// unbounded) rect.
+    pub fn check_rect(
+        &self,
+        rect: Rect,
+        editor: Option<&str>,
+        a1_context: &A1Context,
+    ) -> Result<()> {
+        for range in self.ranges.iter() {
+            if !Self::is_editor(&range.editors, editor)
+                && range
+                    .selection
+                    .ranges
+                    .iter()
+                    .any(|cell_range| cell_range.might_intersect_rect(rect, a1_context))
+            {
+                let name = match &range.description {
+                    Some(description) => description.clone(),
+                    None => range
+                        .selection
+                        .to_string(Some(range.selection.sheet_id), a1_context),
+                };
+                return Err(CoreError::Protected(format!(
+                    "The range {name} is protected"
+                )));
+            }
+        }
+        if !self.can_edit_sheet(editor)
+            && !self
+                .editable_ranges
+                .iter()
+                .any(|selection| Self::contains_rect(selection, rect, a1_context))
+        {
+            return Err(CoreError::Protected("The sheet is protected".to_string()));
+        }
+        Ok(())
+    }
+
+    /// Checks whether the editor may replace this protection with `new`. A
+    /// protected sheet's protection may only be changed by its editors, and a
+    /// protected range may only be changed or removed by its editors.
+    pub fn check_change(&self, new: &SheetProtection, editor: Option<&str>) -> Result<()> {
+        if !self.can_edit_sheet(editor) {
+            return Err(CoreError::Protected(
+                "Only editors of the sheet may change its protection".to_string(),
+            ));
+        }
+        for range in self.ranges.iter() {
+            if !new.ranges.contains(range) && !Self::is_editor(&range.editors, editor) {
+                return Err(CoreError::Protected(
+                    "Only editors of a protected range may change it".to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Updates the selections after a column or row is inserted or removed.
+    /// Protected and editable ranges without cells are removed. Returns true
+    /// if the protection changed.
+    fn update_selections(
+        &mut self,
+        transaction: &mut PendingTransaction,
+        sheet_id: SheetId,
+        mut update: impl FnMut(&mut A1Selection) -> bool,
+    ) -> bool {
+        if self.ranges.is_empty() && self.editable_ranges.is_empty() {
+            return false;
+        }
+        let original = self.clone();
+        let mut changed = false;
+        self.ranges.retain_mut(|range| {
+            changed |= update(&mut range.selection);
+            !range.selection.ranges.is_empty()
+        });
+        self.editable_ranges.retain_mut(|selection| {
+            changed |= update(selection);
+            !selection.ranges.is_empty()
+        });
+        if changed {
+            transaction.sheet_protection.insert(sheet_id);
+            if transaction.is_user_undo_redo() {
+                transaction
+                    .reverse_operations
+                    .push(Operation::SetSheetProtection {
+                        sheet_id,
+                        protection: original,
+                    });
+            }
+        }
+        changed
+    }
+
+    /// Removes a column from the protected and editable ranges.
+    pub(crate) fn remove_column(
+        &mut self,
+        transaction: &mut PendingTransaction,
+        sheet_id: SheetId,
+        column: i64,
+        a1_context: &A1Context,
+    ) -> bool {
+        self.update_selections(transaction, sheet_id, |selection| {
+            selection.removed_column(column, a1_context)
+        })
+    }
+
+    /// Removes a row from the protected and editable ranges.
+    pub(crate) fn remove_row(
+        &mut self,
+        transaction: &mut PendingTransaction,
+        sheet_id: SheetId,
+        row: i64,
+        a1_context: &A1Context,
+    ) -> bool {
+        self.update_selections(transaction, sheet_id, |selection| {
+            selection.removed_row(row, a1_context)
+        })
+    }
+
+    /// Inserts a column in the protected and editable ranges.
+    pub(crate) fn insert_column(
+        &mut self,
+        transaction: &mut PendingTransaction,
+        sheet_id: SheetId,
+        column: i64,
+        a1_context: &A1Context,
+    ) -> bool {
+        self.update_selections(transaction, sheet_id, |selection| {
+            selection.inserted_column(column, a1_context)
+        })
+    }
+
+    /// Inserts a row in the protected and editable ranges.
+    pub(crate) fn insert_row(
+        &mut self,
+        transaction: &mut PendingTransaction,
+        sheet_id: SheetId,
+        row: i64,
+        a1_context: &A1Context,
+    ) -> bool {
+        self.update_selections(transaction, sheet_id, |selection| {
+            selection.inserted_row(row, a1_context)
+        })
+    }
+
+    /// Updates the protection of a duplicated sheet.
+    pub(crate) fn duplicate(&mut self, new_sheet_id: SheetId) {
+        for range in self.ranges.iter_mut() {
+            range.id = Uuid::new_v4();
+            range.selection.sheet_id = new_sheet_id;
+        }
+        for selection in self.editable_ranges.iter_mut() {
+            selection.sheet_id = new_sheet_id;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn protection() -> SheetProtection {
+        SheetProtection {
+            protected: true,
+            editors: vec!["owner@example.com".to_string()],
+            editable_ranges: vec![A1Selection::test_a1("B2:B10")],
+            ranges: vec![ProtectedRange {
+                id: Uuid::new_v4(),
+                selection: A1Selection::test_a1("B5"),
+                description: Some("Total".to_string()),
+                editors: vec!["finance@example.com".to_string()],
+            }],
+        }
+    }
+
+    #[test]
+    fn test_check_rect() {
+        let protection = protection();
+        let context = A1Context::default();
+        let user = Some("user@example.com");
+
+        // editable range
+        assert!(
+            protection
+                .check_rect(Rect::test_a1("B2:B4"), user, &context)
+                .is_ok()
+        );
+
+        // outside the editable range
+        assert_eq!(
+            protection.check_rect(Rect::test_a1("A1"), user, &context),
+            Err(CoreError::Protected("The sheet is protected".to_string()))
+        );
+        assert!(
+            protection
+                .check_rect(Rect::test_a1("B2:C2"), user, &context)
+                .is_err()
+        );
+        assert!(
+            protection
+                .check_rect(Rect::test_a1("B2:B11"), user, &context)
+                .is_err()
+        );
+
+        // protected range within the editable range
+        assert_eq!(
+            protection.check_rect(Rect::test_a1("B5"), user, &context),
+            Err(CoreError::Protected(
+                "The range Total is protected".to_string()
+            ))
+        );
+
+        // sheet editors may edit the sheet but not the protected range
+        let owner = Some("OWNER@example.com");
+        assert!(
+            protection
+                .check_rect(Rect::test_a1("C1:Z100"), owner, &context)
+                .is_ok()
+        );
+        assert!(
+            protection
+                .check_rect(Rect::test_a1("B5"), owner, &context)
+                .is_err()
+        );
+
+        // only range editors may edit the protected range
+        let finance = Some("finance@example.com");
+        assert!(
+            protection
+                .check_rect(Rect::test_a1("B5"), finance, &context)
+                .is_ok()
+        );
+
+        // unbounded rects
+        assert!(
+            protection
+                .check_rect(Rect::new(2, 1, i64::MAX, i64::MAX), owner, &context)
+                .is_err()
+        );
+        assert!(
+            protection
+                .check_rect(Rect::test_a1("A1"), None, &context)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_check_change() {
+        let old = protection();
+        let mut new = old.clone();
+        new.editable_ranges.clear();
+
+        assert!(old.check_change(&new, Some("owner@example.com")).is_ok());
+        assert!(old.check_change(&new, Some("user@example.com")).is_err());
+
+        // removing the range needs its editors
+        new.ranges.clear();
+        assert!(old.check_change(&new, Some("owner@example.com")).is_err());
+
+        let mut unprotected = old.clone();
+        unprotected.protected = false;
+        assert!(
+            unprotected
+                .check_change(&new, Some("finance@example.com"))
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_protection_col_row() {
+        let mut protection = protection();
+        let original = protection.clone();
+        let context = A1Context::default();
+        let mut transaction = PendingTransaction::default();
+
+        assert!(protection.insert_column(&mut transaction, SheetId::TEST, 1, &context));
+        assert_eq!(protection.ranges[0].selection, A1Selection::test_a1("C5"));
+        assert_eq!(
+            protection.editable_ranges[0],
+            A1Selection::test_a1("C2:C10")
+        );
+
+        assert!(protection.remove_row(&mut transaction, SheetId::TEST, 5, &context));
+        assert!(protection.ranges.is_empty());
+        assert_eq!(protection.editable_ranges[0], A1Selection::test_a1("C2:C9"));
+        assert!(transaction.sheet_protection.contains(&SheetId::TEST));
+        assert_eq!(
+            transaction.reverse_operations[0],
+            Operation::SetSheetProtection {
+                sheet_id: SheetId::TEST,
+                protection: original,
+            }
+        );
+
+        assert!(!protection.remove_row(&mut transaction, SheetId::TEST, 20, &context));
+    }
+}
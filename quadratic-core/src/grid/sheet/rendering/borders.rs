@@ -5,6 +5,7 @@ impl Sheet {
     pub fn borders_in_sheet(&self) -> Option<JsBordersSheet> {
         let mut horizontal = vec![];
         let mut vertical = vec![];
+        let mut diagonal = vec![];
 
         // get sheet borders
         if let Some(h) = self.borders.horizontal_borders(None) {
@@ -13,6 +14,9 @@ impl Sheet {
         if let Some(v) = self.borders.vertical_borders(None) {
             vertical.extend(v);
         }
+        if let Some(d) = self.borders.diagonal_borders(None) {
+            diagonal.extend(d);
+        }
 
         // get table borders and translate them to sheet coordinates
         self.data_tables.iter().for_each(|(pos, table)| {
@@ -22,6 +26,9 @@ impl Sheet {
             if let Some(v) = table.borders.vertical_borders(Some((*pos, table))) {
                 vertical.extend(v);
             }
+            if let Some(d) = table.borders.diagonal_borders(Some((*pos, table))) {
+                diagonal.extend(d);
+            }
         });
 
         Some(JsBordersSheet {
@@ -35,6 +42,11 @@ impl Sheet {
             } else {
                 Some(vertical)
             },
+            diagonal: if diagonal.is_empty() {
+                None
+            } else {
+                Some(diagonal)
+            },
         })
     }
 
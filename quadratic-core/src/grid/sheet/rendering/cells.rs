@@ -1,9 +1,11 @@
 use crate::{
     CellValue, Pos, Rect, RunError, RunErrorMsg,
     a1::A1Context,
+    date_time::DateSystem,
     grid::{
-        CellAlign, CellWrap, CodeCellLanguage, DataTable, Format, Sheet,
-        js_types::{JsNumber, JsRenderCell, JsRenderCellSpecial},
+        CellAlign, CellWrap, CodeCellLanguage, DataTable, Format, Locale, Sheet,
+        js_types::{JsNumber, JsNumberFill, JsRenderCell, JsRenderCellSpecial},
+        sheet::validations::rules::validation_list::ValidationList,
     },
 };
 
@@ -19,6 +21,7 @@ impl Sheet {
     }
 
     /// creates a render for a single cell
+    #[allow(clippy::too_many_arguments)]
     fn get_render_cell(
         &self,
         x: i64,
@@ -27,6 +30,8 @@ impl Sheet {
         mut format: Format,
         language: Option<CodeCellLanguage>,
         special: Option<JsRenderCellSpecial>,
+        locale: &Locale,
+        date_system: DateSystem,
     ) -> JsRenderCell {
         if let CellValue::Html(_) = value {
             return JsRenderCell {
@@ -60,7 +65,7 @@ impl Sheet {
         }
 
         let align = if matches!(value, CellValue::Number(_))
-            || matches!(value, CellValue::DateTime(_))
+            || matches!(value, CellValue::DateTime(_) | CellValue::DateTimeTz(_))
             || matches!(value, CellValue::Date(_))
             || matches!(value, CellValue::Time(_))
         {
@@ -69,19 +74,83 @@ impl Sheet {
             None
         };
 
+        // hyperlinks are underlined unless the format says otherwise
+        let (special, underline) = if matches!(value, CellValue::Hyperlink(_)) {
+            (
+                special.or(Some(JsRenderCellSpecial::Hyperlink)),
+                format.underline.or(Some(true)),
+            )
+        } else {
+            (special, format.underline)
+        };
+
+        let rich_text = match value {
+            CellValue::RichText(rich_text) => Some(rich_text.spans.clone()),
+            _ => None,
+        };
+
+        let chips = if matches!(special, Some(JsRenderCellSpecial::MultiSelectList)) {
+            Some(ValidationList::split_values(&value.to_display()))
+                .filter(|chips| !chips.is_empty())
+        } else {
+            None
+        };
+
+        // custom number formats are formatted here instead of by the client
+        let custom = match value {
+            CellValue::Number(_)
+            | CellValue::Text(_)
+            | CellValue::Date(_)
+            | CellValue::DateTime(_)
+            | CellValue::DateTimeTz(_)
+            | CellValue::Time(_)
+            | CellValue::Duration(_) => format
+                .numeric_format
+                .as_ref()
+                .and_then(|numeric_format| numeric_format.format_code())
+                .and_then(|code| code.format_with_date_system(value, date_system)),
+            _ => None,
+        };
+        let mut number_fill = None;
         let mut number: Option<JsNumber> = None;
-        let value = match value {
-            CellValue::Number(_) => {
-                // get numeric_format and numeric_decimal to turn number into a string
-                // if align is not set, set it to right only for numbers
+        let value = if let Some(custom) = custom {
+            if matches!(value, CellValue::Number(_)) {
                 format.align = format.align.or(Some(CellAlign::Right));
-                number = Some((&format).into());
-                value.to_display()
             }
-            CellValue::Date(_) | CellValue::DateTime(_) | CellValue::Time(_) => {
-                Self::value_date_time(value, format.date_time)
+            if custom.color.is_some() {
+                format.text_color = custom.color;
+            }
+            number_fill = custom.fill.map(|fill| JsNumberFill {
+                index: custom.text[..fill.index].chars().count() as u32,
+                char: fill.char.to_string(),
+            });
+            custom.text
+        } else {
+            match value {
+                CellValue::Number(_) => {
+                    // get numeric_format and numeric_decimal to turn number into a string
+                    // if align is not set, set it to right only for numbers
+                    format.align = format.align.or(Some(CellAlign::Right));
+                    let mut js_number: JsNumber = (&format).into();
+                    if !locale.has_default_number_separators() {
+                        js_number.decimal_separator = Some(locale.decimal_separator);
+                        js_number.group_separator = Some(locale.group_separator);
+                    }
+                    number = Some(js_number);
+                    value.to_display()
+                }
+                CellValue::Date(_) | CellValue::DateTime(_) | CellValue::Time(_) => {
+                    let date_time = format.date_time.or_else(|| Some(locale.date_time_format()));
+                    Self::value_date_time(value, date_time)
+                }
+                CellValue::DateTimeTz(_) => {
+                    let date_time = format
+                        .date_time
+                        .or_else(|| Some(locale.date_time_tz_format()));
+                    Self::value_date_time(value, date_time)
+                }
+                _ => value.to_display(),
             }
-            _ => value.to_display(),
         };
         JsRenderCell {
             x,
@@ -96,13 +165,24 @@ impl Sheet {
             vertical_align: format.vertical_align,
             special,
             number,
-            underline: format.underline,
+            underline,
             strike_through: format.strike_through,
+            font_size: format.font_size,
+            font_family: format.font_family,
+            text_rotation: format.text_rotation,
+            indent: format.indent,
             column_header: None,
+            merge_rect: None,
+            rich_text,
+            data_bar: None,
+            icon: None,
+            number_fill,
+            chips,
         }
     }
 
     // Converts a CodeValue::Code and CodeRun into a vector of JsRenderCell.
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn get_code_cells(
         &self,
         code: &CellValue,
@@ -110,6 +190,8 @@ impl Sheet {
         render_rect: &Rect,
         code_rect: &Rect,
         context: &A1Context,
+        locale: &Locale,
+        date_system: DateSystem,
     ) -> Vec<JsRenderCell> {
         let mut cells = vec![];
 
@@ -125,6 +207,8 @@ impl Sheet {
                     Format::default(),
                     Some(code_cell_value.language),
                     None,
+                    locale,
+                    date_system,
                 ));
             } else if let Some(error) = data_table.get_error() {
                 cells.push(self.get_render_cell(
@@ -134,6 +218,8 @@ impl Sheet {
                     Format::default(),
                     Some(code_cell_value.language),
                     None,
+                    locale,
+                    date_system,
                 ));
             } else {
                 let code_rect_start_y = code_rect.min.y + data_table.y_adjustment(false);
@@ -156,18 +242,22 @@ impl Sheet {
                             let value = data_table.cell_value_at(pos.x as u32, pos.y as u32);
 
                             if let Some(value) = value {
+                                let style_format = data_table
+                                    .table_style_format(code_rect.min, Pos { x, y })
+                                    .unwrap_or_default();
                                 let mut format = if is_header {
                                     // column headers are always clipped and bold
                                     Format {
                                         wrap: Some(CellWrap::Clip),
                                         bold: Some(true),
+                                        text_color: style_format.text_color,
                                         ..Default::default()
                                     }
                                 } else {
                                     let table_format = data_table.get_format(pos);
                                     let sheet_format =
                                         self.formats.try_format(Pos { x, y }).unwrap_or_default();
-                                    table_format.combine(&sheet_format)
+                                    table_format.combine(&sheet_format).combine(&style_format)
                                 };
 
                                 let language = if x == code_rect.min.x && y == code_rect.min.y {
@@ -186,8 +276,16 @@ impl Sheet {
 
                                 Self::ensure_lists_are_clipped(&mut format, &special);
 
-                                let mut render_cell =
-                                    self.get_render_cell(x, y, &value, format, language, special);
+                                let mut render_cell = self.get_render_cell(
+                                    x,
+                                    y,
+                                    &value,
+                                    format,
+                                    language,
+                                    special,
+                                    locale,
+                                    date_system,
+                                );
                                 if is_header {
                                     render_cell.column_header = Some(true);
                                 }
@@ -204,10 +302,12 @@ impl Sheet {
 
     /// ensure that list cells are always clipped or wrapped (so the dropdown icon is visible)
     fn ensure_lists_are_clipped(format: &mut Format, special: &Option<JsRenderCellSpecial>) {
-        if special
-            .as_ref()
-            .is_some_and(|s| matches!(s, JsRenderCellSpecial::List))
-            && !format.wrap.is_some_and(|w| matches!(w, CellWrap::Wrap))
+        if special.as_ref().is_some_and(|s| {
+            matches!(
+                s,
+                JsRenderCellSpecial::List | JsRenderCellSpecial::MultiSelectList
+            )
+        }) && !format.wrap.is_some_and(|w| matches!(w, CellWrap::Wrap))
         {
             format.wrap = Some(CellWrap::Clip);
         }
@@ -216,6 +316,23 @@ impl Sheet {
     /// Returns cell data in a format useful for rendering. This includes only
     /// the data necessary to render raw text values.
     pub fn get_render_cells(&self, rect: Rect, a1_context: &A1Context) -> Vec<JsRenderCell> {
+        self.get_render_cells_with_locale(
+            rect,
+            a1_context,
+            &Locale::default(),
+            DateSystem::default(),
+        )
+    }
+
+    /// Returns the render cells in a rect, displaying dates and numbers using
+    /// the locale, and custom date formats of numbers using the date system.
+    pub fn get_render_cells_with_locale(
+        &self,
+        rect: Rect,
+        a1_context: &A1Context,
+        locale: &Locale,
+        date_system: DateSystem,
+    ) -> Vec<JsRenderCell> {
         let mut render_cells = vec![];
 
         // Fetch ordinary value cells.
@@ -240,7 +357,16 @@ impl Sheet {
 
                         Self::ensure_lists_are_clipped(&mut format, &special);
 
-                        render_cells.push(self.get_render_cell(x, y, value, format, None, special));
+                        render_cells.push(self.get_render_cell(
+                            x,
+                            y,
+                            value,
+                            format,
+                            None,
+                            special,
+                            locale,
+                            date_system,
+                        ));
                     }
                 });
             });
@@ -259,6 +385,8 @@ impl Sheet {
                         &rect,
                         &data_table_rect,
                         a1_context,
+                        locale,
+                        date_system,
                     ));
                 }
             });
@@ -297,8 +425,47 @@ impl Sheet {
                 }
             });
 
+        self.apply_conditional_formats(&mut render_cells);
+        self.remove_hidden_cells(&mut render_cells, rect);
+        self.apply_merge_cells(&mut render_cells, rect);
+
         render_cells
     }
+
+    /// Removes cells in hidden columns and rows.
+    fn remove_hidden_cells(&self, render_cells: &mut Vec<JsRenderCell>, rect: Rect) {
+        if !self.offsets.has_hidden_columns(rect.min.x, rect.max.x)
+            && !self.offsets.has_hidden_rows(rect.min.y, rect.max.y)
+        {
+            return;
+        }
+        render_cells.retain(|cell| {
+            !self.offsets.is_column_hidden(cell.x) && !self.offsets.is_row_hidden(cell.y)
+        });
+    }
+
+    /// Removes cells hidden by merged cells and sets the merged rect on the
+    /// top left cell of each merged cell.
+    fn apply_merge_cells(&self, render_cells: &mut Vec<JsRenderCell>, rect: Rect) {
+        let merges = self.merge_cells.in_rect(rect);
+        if merges.is_empty() {
+            return;
+        }
+        render_cells.retain_mut(|cell| {
+            let pos = Pos {
+                x: cell.x,
+                y: cell.y,
+            };
+            match merges.iter().find(|merge| merge.contains(pos)) {
+                Some(merge) if merge.min == pos => {
+                    cell.merge_rect = Some(*merge);
+                    true
+                }
+                Some(_) => false,
+                None => true,
+            }
+        });
+    }
 }
 
 #[cfg(test)]
@@ -312,7 +479,10 @@ mod tests {
         SheetPos, Value,
         a1::A1Selection,
         controller::GridController,
-        grid::{CellVerticalAlign, CellWrap, CodeCellValue, CodeRun, DataTableKind},
+        grid::{
+            CellVerticalAlign, CellWrap, CodeCellValue, CodeRun, DataTableKind, NumericFormat,
+            NumericFormatKind,
+        },
         wasm_bindings::js::{clear_js_calls, expect_js_call, hash_test},
     };
 
@@ -702,4 +872,92 @@ mod tests {
         Sheet::ensure_lists_are_clipped(&mut format, &special);
         assert_eq!(format.wrap, None);
     }
+
+    #[test]
+    fn test_render_merged_cells() {
+        let mut sheet = Sheet::test();
+        sheet.set_cell_value(pos![B2], CellValue::Text("merged".into()));
+        sheet.set_cell_value(pos![C2], CellValue::Text("hidden".into()));
+        sheet.set_cell_value(pos![D2], CellValue::Text("visible".into()));
+        sheet.merge_cells.merge(Rect::test_a1("B2:C3"));
+
+        let cells = sheet.get_render_cells(Rect::test_a1("A1:E5"), &A1Context::default());
+        assert_eq!(cells.len(), 2);
+        assert_eq!(cells[0].value, "merged");
+        assert_eq!(cells[0].merge_rect, Some(Rect::test_a1("B2:C3")));
+        assert_eq!(cells[1].value, "visible");
+        assert_eq!(cells[1].merge_rect, None);
+    }
+
+    #[test]
+    fn test_render_hidden_cells() {
+        let mut sheet = Sheet::test();
+        sheet.set_cell_value(pos![A1], CellValue::Text("visible".into()));
+        sheet.set_cell_value(pos![B1], CellValue::Text("hidden column".into()));
+        sheet.set_cell_value(pos![A2], CellValue::Text("hidden row".into()));
+        sheet.offsets.set_column_hidden(2, true);
+        sheet.offsets.set_row_hidden(2, true);
+
+        let cells = sheet.get_render_cells(Rect::test_a1("A1:C3"), &A1Context::default());
+        assert_eq!(cells.len(), 1);
+        assert_eq!(cells[0].value, "visible");
+    }
+
+    #[test]
+    fn test_render_hyperlink() {
+        let mut sheet = Sheet::test();
+        sheet.set_cell_value(
+            pos![A1],
+            CellValue::Hyperlink(Box::new(
+                crate::Hyperlink::new("https://example.com", "Example").unwrap(),
+            )),
+        );
+
+        let cells = sheet.get_render_cells(Rect::test_a1("A1"), &A1Context::default());
+        assert_eq!(cells[0].value, "Example");
+        assert_eq!(cells[0].special, Some(JsRenderCellSpecial::Hyperlink));
+        assert_eq!(cells[0].underline, Some(true));
+    }
+
+    #[test]
+    fn test_render_rich_text() {
+        let mut sheet = Sheet::test();
+        let spans = vec![
+            crate::TextSpan {
+                bold: Some(true),
+                ..crate::TextSpan::new("Total:")
+            },
+            crate::TextSpan::new(" 42"),
+        ];
+        sheet.set_cell_value(
+            pos![A1],
+            CellValue::RichText(crate::RichText::new(spans.clone())),
+        );
+
+        let cells = sheet.get_render_cells(Rect::test_a1("A1"), &A1Context::default());
+        assert_eq!(cells[0].value, "Total: 42");
+        assert_eq!(cells[0].bold, None);
+        assert_eq!(cells[0].rich_text, Some(spans));
+    }
+
+    #[test]
+    fn test_render_custom_date_format_date_system() {
+        let mut sheet = Sheet::test();
+        sheet.set_cell_value(pos![A1], CellValue::from(0));
+        sheet.formats.numeric_format.set(
+            pos![A1],
+            Some(NumericFormat {
+                kind: NumericFormatKind::Custom,
+                symbol: Some("yyyy-mm-dd".to_string()),
+            }),
+        );
+
+        let cells = sheet.get_render_cells_with_locale(
+            Rect::test_a1("A1"),
+            &A1Context::default(),
+            &Locale::default(),
+            DateSystem::Excel1904,
+        );
+        assert_eq!(cells[0].value, "1904-01-01");
+    }
 }
@@ -87,7 +87,9 @@ impl Sheet {
             && !data_table.has_error()
             && !data_table.is_image()
             && !data_table.is_html()
-            && data_table.alternating_colors;
+            && data_table.alternating_colors
+            // a table style replaces the alternating colors
+            && data_table.table_style.is_none();
 
         let language = match code {
             CellValue::Code(code) => code.language,
@@ -230,7 +232,8 @@ mod tests {
             GridController,
             transaction_types::{JsCellValueResult, JsCodeResult},
         },
-        grid::{CodeCellValue, CodeRun, DataTableKind, js_types::JsNumber},
+        date_time::DateSystem,
+        grid::{CodeCellValue, CodeRun, DataTableKind, Locale, js_types::JsNumber},
         wasm_bindings::js::{clear_js_calls, expect_js_call, expect_js_call_count},
     };
 
@@ -343,6 +346,8 @@ mod tests {
             &Rect::from_numbers(0, 0, 10, 10),
             &Rect::from_numbers(5, 5, 3, 2),
             &context,
+            &Locale::default(),
+            DateSystem::default(),
         );
         assert_eq!(code_cells.len(), 6);
         assert_eq!(code_cells[0].value, "1".to_string());
@@ -358,6 +363,8 @@ mod tests {
             &Rect::from_numbers(2, 1, 10, 10),
             &Rect::from_numbers(0, 0, 3, 2),
             &context,
+            &Locale::default(),
+            DateSystem::default(),
         );
         assert_eq!(code_cells.len(), 1);
         assert_eq!(code_cells[0].value, "6".to_string());
@@ -370,6 +377,8 @@ mod tests {
             &Rect::from_numbers(0, 0, 3, 2),
             &Rect::from_numbers(2, 1, 10, 10),
             &context,
+            &Locale::default(),
+            DateSystem::default(),
         );
         assert_eq!(code_cells.len(), 1);
         assert_eq!(code_cells[0].value, "1".to_string());
@@ -403,6 +412,8 @@ mod tests {
             &Rect::from_numbers(0, 0, 10, 10),
             &Rect::from_numbers(5, 5, 1, 1),
             &context,
+            &Locale::default(),
+            DateSystem::default(),
         );
         assert_eq!(code_cells[0].value, "1".to_string());
         assert_eq!(code_cells[0].language, Some(CodeCellLanguage::Python));
@@ -411,7 +422,8 @@ mod tests {
             Some(JsNumber {
                 decimals: None,
                 commas: None,
-                format: None
+                format: None,
+                ..Default::default()
             })
         );
     }
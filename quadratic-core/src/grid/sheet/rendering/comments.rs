@@ -0,0 +1,44 @@
+use crate::grid::{Sheet, js_types::JsCommentThread};
+
+impl Sheet {
+    /// Sends all comment threads for this sheet to the client.
+    pub fn send_all_comments(&self) {
+        let threads = self
+            .comments
+            .threads()
+            .map(|(pos, thread)| JsCommentThread {
+                x: pos.x,
+                y: pos.y,
+                thread: thread.clone(),
+            })
+            .collect::<Vec<_>>();
+
+        if let Ok(threads) = serde_json::to_string(&threads) {
+            crate::wasm_bindings::js::jsSheetComments(self.id.to_string(), threads);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        controller::GridController, grid::sheet::comments::Comment,
+        wasm_bindings::js::expect_js_call,
+    };
+
+    #[test]
+    fn send_all_comments() {
+        let mut gc = GridController::test();
+        let sheet_id = gc.sheet_ids()[0];
+        let sheet = gc.sheet_mut(sheet_id);
+        let comment = Comment::new("jane".into(), "hello".into());
+        sheet.comments.add(pos![B3], comment);
+        sheet.send_all_comments();
+        let thread = serde_json::to_string(sheet.comments.get(pos![B3]).unwrap()).unwrap();
+        expect_js_call(
+            "jsSheetComments",
+            format!("{},[{{\"x\":2,\"y\":3,\"thread\":{}}}]", sheet.id, thread),
+            true,
+        );
+    }
+}
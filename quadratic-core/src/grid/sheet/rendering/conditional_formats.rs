@@ -0,0 +1,121 @@
+use crate::grid::{
+    Sheet,
+    js_types::{JsRenderCell, JsRenderFill},
+};
+
+impl Sheet {
+    /// Sends all conditional formats for this sheet to the client.
+    pub fn send_all_conditional_formats(&self) {
+        if let Ok(conditional_formats) = self.conditional_formats.to_string() {
+            crate::wasm_bindings::js::jsSheetConditionalFormats(
+                self.id.to_string(),
+                conditional_formats,
+            );
+        }
+    }
+
+    /// Applies the computed conditional formats to the render cells.
+    pub(crate) fn apply_conditional_formats(&self, render_cells: &mut [JsRenderCell]) {
+        if self.conditional_formats.computed.is_empty() {
+            return;
+        }
+        for cell in render_cells.iter_mut() {
+            let Some(computed) = self.conditional_formats.computed((cell.x, cell.y).into()) else {
+                continue;
+            };
+            let style = &computed.style;
+            if style.text_color.is_some() {
+                cell.text_color.clone_from(&style.text_color);
+            }
+            cell.bold = style.bold.or(cell.bold);
+            cell.italic = style.italic.or(cell.italic);
+            cell.underline = style.underline.or(cell.underline);
+            cell.strike_through = style.strike_through.or(cell.strike_through);
+            cell.data_bar.clone_from(&computed.data_bar);
+            cell.icon.clone_from(&computed.icon);
+            if computed.hide_value {
+                cell.value = String::new();
+                cell.rich_text = None;
+            }
+        }
+    }
+
+    /// Returns the fills of the computed conditional formats. These are drawn
+    /// over the cell fills.
+    pub(crate) fn get_conditional_format_fills(&self) -> Vec<JsRenderFill> {
+        let mut fills = self
+            .conditional_formats
+            .computed
+            .iter()
+            .filter_map(|(pos, computed)| {
+                Some(JsRenderFill {
+                    x: pos.x,
+                    y: pos.y,
+                    w: 1,
+                    h: 1,
+                    color: computed.style.fill_color.clone()?,
+                })
+            })
+            .collect::<Vec<_>>();
+        fills.sort_by_key(|fill| (fill.y, fill.x));
+        fills
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        CellValue, Rect,
+        a1::A1Context,
+        grid::{
+            Sheet,
+            js_types::JsRenderDataBar,
+            sheet::conditional_formats::conditional_format::{
+                ConditionalFormatCell, ConditionalFormatStyle,
+            },
+        },
+    };
+
+    #[test]
+    fn test_apply_conditional_formats() {
+        let mut sheet = Sheet::test();
+        sheet.set_cell_value(pos![A1], CellValue::Number(5.into()));
+        sheet.set_cell_value(pos![A2], CellValue::Number(7.into()));
+        sheet.conditional_formats.computed.insert(
+            pos![A1],
+            ConditionalFormatCell {
+                style: ConditionalFormatStyle {
+                    fill_color: Some("#ff0000".into()),
+                    text_color: Some("#ffffff".into()),
+                    bold: Some(true),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        );
+        let data_bar = JsRenderDataBar {
+            color: "#0000ff".into(),
+            percent: 50,
+        };
+        sheet.conditional_formats.computed.insert(
+            pos![A2],
+            ConditionalFormatCell {
+                data_bar: Some(data_bar.clone()),
+                hide_value: true,
+                ..Default::default()
+            },
+        );
+
+        let cells = sheet.get_render_cells(Rect::new(1, 1, 1, 2), &A1Context::default());
+        assert_eq!(cells[0].text_color, Some("#ffffff".into()));
+        assert_eq!(cells[0].bold, Some(true));
+        assert_eq!(cells[0].value, "5");
+        assert_eq!(cells[1].data_bar, Some(data_bar));
+        assert_eq!(cells[1].value, "");
+
+        let fills = sheet.get_all_render_fills();
+        assert_eq!(fills.len(), 1);
+        assert_eq!((fills[0].x, fills[0].y), (1, 1));
+        assert_eq!(fills[0].color, "#ff0000");
+    }
+}
@@ -14,9 +14,9 @@ use crate::{
 impl Sheet {
     /// Returns true if the table has any fills.
     pub fn table_has_fills(&self, pos: Pos) -> bool {
-        self.data_tables
-            .iter()
-            .any(|(p, dt)| p == &pos && !dt.formats.fill_color.is_all_default())
+        self.data_tables.iter().any(|(p, dt)| {
+            p == &pos && (!dt.formats.fill_color.is_all_default() || dt.table_style.is_some())
+        })
     }
 
     /// Returns all data for rendering cell fill color.
@@ -37,6 +37,11 @@ impl Sheet {
                     None
                 }
             })
+            .chain(
+                self.data_tables
+                    .iter()
+                    .flat_map(|(pos, dt)| dt.table_style_fills(*pos)),
+            )
             .chain(self.data_tables.iter().flat_map(|(pos, dt)| {
                 dt.formats
                     .fill_color
@@ -106,6 +111,7 @@ impl Sheet {
                         fills
                     })
             }))
+            .chain(self.get_conditional_format_fills())
             .collect()
     }
 
@@ -3,5 +3,8 @@
 mod borders;
 mod cells;
 mod code;
+mod comments;
+mod conditional_formats;
 mod fills;
+mod protection;
 mod validations;
@@ -0,0 +1,10 @@
+use crate::grid::Sheet;
+
+impl Sheet {
+    /// Sends the protection of this sheet to the client.
+    pub fn send_sheet_protection(&self) {
+        if let Ok(protection) = serde_json::to_string(&self.protection) {
+            crate::wasm_bindings::js::jsSheetProtection(self.id.to_string(), protection);
+        }
+    }
+}
@@ -27,15 +27,15 @@ impl Sheet {
     pub fn send_all_validation_warnings(&self) {
         let warnings = self
             .validations
-            .warnings
-            .iter()
+            .all_warnings()
+            .into_iter()
             .map(|(pos, validation_id)| JsValidationWarning {
                 x: pos.x,
                 y: pos.y,
-                validation: Some(*validation_id),
+                validation: Some(validation_id),
                 style: self
                     .validations
-                    .validation(*validation_id)
+                    .validation(validation_id)
                     .map(|v| v.error.style.clone()),
             })
             .collect::<Vec<_>>();
@@ -47,15 +47,15 @@ impl Sheet {
     pub fn send_validation_warnings_from_hash(&self, hash_x: i64, hash_y: i64, rect: Rect) {
         let warnings = self
             .validations
-            .warnings
-            .iter()
+            .all_warnings()
+            .into_iter()
             .filter_map(|(pos, validation_id)| {
-                if rect.contains(*pos) {
-                    let validation = self.validations.validation(*validation_id)?;
+                if rect.contains(pos) {
+                    let validation = self.validations.validation(validation_id)?;
                     Some(JsValidationWarning {
                         x: pos.x,
                         y: pos.y,
-                        validation: Some(*validation_id),
+                        validation: Some(validation_id),
                         style: Some(validation.error.style.clone()),
                     })
                 } else {
@@ -96,8 +96,8 @@ mod tests {
         grid::{
             js_types::JsValidationWarning,
             sheet::validations::{
-                validation::{Validation, ValidationStyle},
                 rules::{ValidationRule, validation_logical::ValidationLogical},
+                validation::{Validation, ValidationStyle},
             },
         },
         wasm_bindings::js::expect_js_call,
@@ -6,7 +6,10 @@
 
 use crate::{
     CellValue,
-    date_time::{date_time_to_date_time_string, date_to_date_string, time_to_time_string},
+    date_time::{
+        date_time_to_date_time_string, date_time_tz_to_date_time_string, date_to_date_string,
+        time_to_time_string,
+    },
 };
 
 use super::Sheet;
@@ -21,6 +24,7 @@ impl Sheet {
     pub fn value_date_time(value: &CellValue, date_time: Option<String>) -> String {
         match value {
             CellValue::DateTime(dt) => date_time_to_date_time_string(*dt, date_time),
+            CellValue::DateTimeTz(dt) => date_time_tz_to_date_time_string(*dt, date_time),
             CellValue::Date(d) => date_to_date_string(*d, date_time),
             CellValue::Time(t) => time_to_time_string(*t, date_time),
             _ => value.to_display(),
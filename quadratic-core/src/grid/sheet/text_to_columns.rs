@@ -0,0 +1,355 @@
+//! Text to columns: splits text in a column into adjacent columns by a
+//! delimiter, fixed widths, or a regular expression.
+//!
+//! This module splits the text and detects types; the operations are created
+//! in `controller::operations::text_to_columns`.
+
+use anyhow::{Result, anyhow};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::CellValue;
+use crate::grid::Locale;
+use crate::grid::formats::FormatUpdate;
+
+/// How text is split into columns.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "js", derive(ts_rs::TS))]
+pub enum TextToColumnsSplit {
+    /// Splits on each occurrence of `delimiter`. If `merge_consecutive` is
+    /// true, consecutive delimiters are treated as one.
+    Delimiter {
+        delimiter: String,
+        merge_consecutive: bool,
+    },
+
+    /// Splits into columns of the given widths (in characters). Any remaining
+    /// text goes into a final column.
+    FixedWidths(Vec<u32>),
+
+    /// If the regex has capture groups, each group of the first match is a
+    /// column. Otherwise, the text is split on each match.
+    Regex(String),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "js", derive(ts_rs::TS))]
+pub struct TextToColumnsOptions {
+    pub split: TextToColumnsSplit,
+
+    /// Removes leading and trailing whitespace from each part.
+    pub trim_whitespace: bool,
+
+    /// Converts each resulting column to numbers, dates, etc. when all of its
+    /// values can be converted.
+    pub detect_types: bool,
+}
+
+/// Preview of a text to columns operation.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "js", derive(ts_rs::TS))]
+pub struct TextToColumnsPreview {
+    /// The split text of the first rows.
+    pub rows: Vec<Vec<String>>,
+
+    /// True if the new columns would overwrite existing cells.
+    pub overwrites_cells: bool,
+
+    /// True if the new columns would overwrite code or tables. The operation
+    /// is refused in this case.
+    pub overwrites_code: bool,
+}
+
+/// Splits text using a compiled regex (if needed).
+enum Splitter<'a> {
+    Delimiter(&'a str, bool),
+    FixedWidths(&'a [u32]),
+    Regex(Regex),
+}
+
+impl TextToColumnsSplit {
+    fn splitter(&self) -> Result<Splitter<'_>> {
+        Ok(match self {
+            Self::Delimiter {
+                delimiter,
+                merge_consecutive,
+            } => {
+                if delimiter.is_empty() {
+                    return Err(anyhow!("Delimiter cannot be empty"));
+                }
+                Splitter::Delimiter(delimiter, *merge_consecutive)
+            }
+            Self::FixedWidths(widths) => {
+                if widths.is_empty() || widths.contains(&0) {
+                    return Err(anyhow!("Fixed widths must be greater than 0"));
+                }
+                Splitter::FixedWidths(widths)
+            }
+            Self::Regex(pattern) => Splitter::Regex(
+                Regex::new(pattern).map_err(|e| anyhow!("Invalid regular expression: {e}"))?,
+            ),
+        })
+    }
+}
+
+impl Splitter<'_> {
+    fn split(&self, text: &str) -> Vec<String> {
+        match self {
+            Self::Delimiter(delimiter, merge_consecutive) => {
+                let mut parts = text
+                    .split(delimiter)
+                    .filter(|part| !merge_consecutive || !part.is_empty())
+                    .map(String::from)
+                    .collect::<Vec<_>>();
+                if parts.is_empty() {
+                    parts.push(String::new());
+                }
+                parts
+            }
+            Self::FixedWidths(widths) => {
+                let mut chars = text.chars();
+                let mut parts = widths
+                    .iter()
+                    .map(|&width| chars.by_ref().take(width as usize).collect::<String>())
+                    .collect::<Vec<_>>();
+                parts.push(chars.collect());
+                while parts.len() > 1 && parts.last().is_some_and(|part| part.is_empty()) {
+                    parts.pop();
+                }
+                parts
+            }
+            Self::Regex(regex) => {
+                if regex.captures_len() > 1 {
+                    match regex.captures(text) {
+                        Some(captures) => captures
+                            .iter()
+                            .skip(1)
+                            .map(|m| m.map_or_else(String::new, |m| m.as_str().to_string()))
+                            .collect(),
+                        None => vec![text.to_string()],
+                    }
+                } else {
+                    regex.split(text).map(String::from).collect()
+                }
+            }
+        }
+    }
+}
+
+impl TextToColumnsOptions {
+    /// Splits each text. Rows that are None are not split and stay None.
+    pub fn split(&self, texts: &[Option<String>]) -> Result<Vec<Option<Vec<String>>>> {
+        let splitter = self.split.splitter()?;
+        Ok(texts
+            .iter()
+            .map(|text| {
+                text.as_ref().map(|text| {
+                    let mut parts = splitter.split(text);
+                    if self.trim_whitespace {
+                        parts = parts.into_iter().map(|p| p.trim().to_string()).collect();
+                    }
+                    parts
+                })
+            })
+            .collect())
+    }
+
+    /// Converts split text to cell values using the file's `locale`. Each row
+    /// is padded with blanks to `width`. If `detect_types` is true, a column is
+    /// converted only if all of its non-empty values can be converted;
+    /// otherwise it stays text.
+    pub fn cell_values(
+        &self,
+        rows: &[Option<Vec<String>>],
+        width: usize,
+        locale: &Locale,
+    ) -> Vec<Option<Vec<(CellValue, FormatUpdate)>>> {
+        let typed_columns = (0..width)
+            .map(|column| {
+                self.detect_types
+                    && rows
+                        .iter()
+                        .flatten()
+                        .filter_map(|parts| parts.get(column))
+                        .filter(|part| !part.is_empty())
+                        .all(|part| {
+                            !matches!(
+                                CellValue::string_to_cell_value_with_locale(part, false, locale).0,
+                                CellValue::Text(_)
+                            )
+                        })
+            })
+            .collect::<Vec<_>>();
+
+        rows.iter()
+            .map(|parts| {
+                let parts = parts.as_ref()?;
+                Some(
+                    (0..width)
+                        .map(|column| match parts.get(column) {
+                            None => (CellValue::Blank, FormatUpdate::default()),
+                            Some(part) if part.is_empty() => {
+                                (CellValue::Blank, FormatUpdate::default())
+                            }
+                            Some(part) if typed_columns[column] => {
+                                CellValue::string_to_cell_value_with_locale(part, false, locale)
+                            }
+                            Some(part) => (CellValue::Text(part.clone()), FormatUpdate::default()),
+                        })
+                        .collect(),
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(split: TextToColumnsSplit) -> TextToColumnsOptions {
+        TextToColumnsOptions {
+            split,
+            trim_whitespace: true,
+            detect_types: true,
+        }
+    }
+
+    fn split(split: TextToColumnsSplit, text: &str) -> Vec<String> {
+        options(split).split(&[Some(text.to_string())]).unwrap()[0]
+            .clone()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_split_delimiter() {
+        let delimiter = |merge_consecutive| TextToColumnsSplit::Delimiter {
+            delimiter: ",".to_string(),
+            merge_consecutive,
+        };
+        assert_eq!(
+            split(delimiter(false), "Last, First"),
+            vec!["Last", "First"]
+        );
+        assert_eq!(split(delimiter(false), "a,,b"), vec!["a", "", "b"]);
+        assert_eq!(split(delimiter(true), "a,,b"), vec!["a", "b"]);
+        assert_eq!(split(delimiter(true), "a"), vec!["a"]);
+
+        let empty = TextToColumnsSplit::Delimiter {
+            delimiter: "".to_string(),
+            merge_consecutive: false,
+        };
+        assert!(options(empty).split(&[]).is_err());
+    }
+
+    #[test]
+    fn test_split_fixed_widths() {
+        let widths = TextToColumnsSplit::FixedWidths(vec![3, 2]);
+        assert_eq!(split(widths.clone(), "abcdefg"), vec!["abc", "de", "fg"]);
+        assert_eq!(split(widths.clone(), "abcd"), vec!["abc", "d"]);
+        assert_eq!(split(widths, "éèêëa"), vec!["éèê", "ëa"]);
+        assert!(
+            options(TextToColumnsSplit::FixedWidths(vec![0]))
+                .split(&[])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_split_regex() {
+        let regex = TextToColumnsSplit::Regex(r"\s*;\s*".to_string());
+        assert_eq!(split(regex, "a ; b;c"), vec!["a", "b", "c"]);
+
+        let captures = TextToColumnsSplit::Regex(r"(\w+)@(\w+)\.com".to_string());
+        assert_eq!(
+            split(captures.clone(), "jane@example.com"),
+            vec!["jane", "example"]
+        );
+        assert_eq!(split(captures, "no email"), vec!["no email"]);
+
+        assert!(
+            options(TextToColumnsSplit::Regex("(".to_string()))
+                .split(&[])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_cell_values() {
+        let options = options(TextToColumnsSplit::Delimiter {
+            delimiter: ",".to_string(),
+            merge_consecutive: false,
+        });
+        let rows = options
+            .split(&[
+                Some("a,1,$2".to_string()),
+                None,
+                Some("b,x".to_string()),
+                Some("c,,$3".to_string()),
+            ])
+            .unwrap();
+        let values = options.cell_values(&rows, 3, &Locale::default());
+
+        let row = |index: usize| -> Vec<CellValue> {
+            values[index]
+                .as_ref()
+                .unwrap()
+                .iter()
+                .map(|(value, _)| value.clone())
+                .collect()
+        };
+        // the second column has text so it is not converted
+        assert_eq!(
+            row(0),
+            vec![
+                CellValue::Text("a".into()),
+                CellValue::Text("1".into()),
+                CellValue::from(2.0)
+            ]
+        );
+        assert!(values[1].is_none());
+        assert_eq!(
+            row(2),
+            vec![
+                CellValue::Text("b".into()),
+                CellValue::Text("x".into()),
+                CellValue::Blank
+            ]
+        );
+        assert_eq!(
+            row(3),
+            vec![
+                CellValue::Text("c".into()),
+                CellValue::Blank,
+                CellValue::from(3.0)
+            ]
+        );
+        assert!(values[3].as_ref().unwrap()[2].1.numeric_format.is_some());
+    }
+
+    #[test]
+    fn test_cell_values_locale() {
+        let options = options(TextToColumnsSplit::Delimiter {
+            delimiter: ";".to_string(),
+            merge_consecutive: false,
+        });
+        let rows = options
+            .split(&[Some("a;1.234,5;31/12/2024".to_string())])
+            .unwrap();
+        let values = options.cell_values(&rows, 3, &Locale::preset("de-DE").unwrap());
+        let row = values[0]
+            .as_ref()
+            .unwrap()
+            .iter()
+            .map(|(value, _)| value.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            row,
+            vec![
+                CellValue::Text("a".into()),
+                CellValue::from(1234.5),
+                CellValue::Date(chrono::NaiveDate::from_ymd_opt(2024, 12, 31).unwrap())
+            ]
+        );
+    }
+}
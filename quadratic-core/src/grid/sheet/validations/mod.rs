@@ -5,6 +5,7 @@ use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use validation::{Validation, ValidationDisplay, ValidationDisplaySheet};
+use warnings::ValidationFormulaResults;
 
 use crate::{
     Pos, Rect,
@@ -29,6 +30,12 @@ pub struct Validations {
 
     #[serde(default)]
     pub warnings: HashMap<Pos, Uuid>,
+
+    /// The cells that fail each validation with a formula, by validation id.
+    /// These are derived from the grid after each transaction, so they are not
+    /// saved, undone, or sent to other players.
+    #[serde(skip)]
+    pub(crate) formula_results: HashMap<Uuid, ValidationFormulaResults>,
 }
 
 impl Validations {
@@ -95,12 +102,14 @@ impl Validations {
     ) -> Option<JsRenderCellSpecial> {
         let mut checkbox = false;
         let mut list = false;
+        let mut multi_select = false;
         for v in &self.validations {
             if v.selection.might_contain_pos(pos, a1_context) {
                 match v.rule {
                     rules::ValidationRule::List(ref validation_list) => {
                         if validation_list.drop_down {
                             list = true;
+                            multi_select = validation_list.multi_select;
                             checkbox = false;
                         } else {
                             list = false;
@@ -122,6 +131,8 @@ impl Validations {
         }
         if checkbox {
             Some(JsRenderCellSpecial::Checkbox)
+        } else if list && multi_select {
+            Some(JsRenderCellSpecial::MultiSelectList)
         } else if list {
             Some(JsRenderCellSpecial::List)
         } else {
@@ -5,14 +5,16 @@ use crate::{CellValue, a1::A1Context};
 
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
+use validation_custom::ValidationCustom;
 use validation_date_time::ValidationDateTime;
-use validation_list::ValidationList;
+use validation_list::{ValidationList, ValidationListSource};
 use validation_logical::ValidationLogical;
 use validation_number::ValidationNumber;
 use validation_text::ValidationText;
 
 use super::super::Sheet;
 
+pub mod validation_custom;
 pub mod validation_date_time;
 pub mod validation_list;
 pub mod validation_logical;
@@ -27,10 +29,13 @@ pub enum ValidationRule {
     Text(ValidationText),
     Number(ValidationNumber),
     DateTime(ValidationDateTime),
+    Custom(ValidationCustom),
 }
 
 impl ValidationRule {
-    /// Validate a CellValue against the validation rule.
+    /// Validate a CellValue against the validation rule. Rules with a formula
+    /// need the formula engine, so they always pass here; they are checked by
+    /// the GridController (see `validate_formula_rule`).
     pub fn validate(
         &self,
         sheet: &Sheet,
@@ -43,7 +48,7 @@ impl ValidationRule {
             ValidationRule::Text(text) => text.validate(value),
             ValidationRule::Number(number) => number.validate(value),
             ValidationRule::DateTime(dt) => dt.validate(value),
-            ValidationRule::None => true,
+            ValidationRule::Custom(_) | ValidationRule::None => true,
         }
     }
 
@@ -57,6 +62,24 @@ impl ValidationRule {
         matches!(self, ValidationRule::Logical(_))
     }
 
+    /// Whether the rule is checked with the formula engine (custom rules and
+    /// lists with a formula source).
+    pub fn has_formula(&self) -> bool {
+        self.formula().is_some()
+    }
+
+    /// Returns the formula of a rule that is checked with the formula engine.
+    pub fn formula(&self) -> Option<&str> {
+        match self {
+            ValidationRule::Custom(custom) => Some(&custom.formula),
+            ValidationRule::List(ValidationList {
+                source: ValidationListSource::Formula(formula),
+                ..
+            }) => Some(formula),
+            _ => None,
+        }
+    }
+
     /// Returns true if the validation rule has a UI element.
     pub fn has_ui(&self) -> bool {
         match self {
@@ -73,6 +96,7 @@ impl ValidationRule {
             ValidationRule::Text(text) => text.ignore_blank,
             ValidationRule::Number(number) => number.ignore_blank,
             ValidationRule::DateTime(dt) => dt.ignore_blank,
+            ValidationRule::Custom(custom) => custom.ignore_blank,
             ValidationRule::None => true,
         }
     }
@@ -80,8 +104,6 @@ impl ValidationRule {
 
 #[cfg(test)]
 mod tests {
-    use validation_list::ValidationListSource;
-
     use crate::a1::A1Selection;
 
     use super::*;
@@ -93,6 +115,7 @@ mod tests {
             source: ValidationListSource::List(vec!["test".to_string()]),
             ignore_blank: true,
             drop_down: false,
+            multi_select: false,
         };
 
         let a1_context = sheet.make_a1_context();
@@ -119,6 +142,7 @@ mod tests {
             source: ValidationListSource::Selection(selection),
             ignore_blank: true,
             drop_down: false,
+            multi_select: false,
         };
         let rule = ValidationRule::List(list);
 
@@ -155,6 +179,7 @@ mod tests {
             source: ValidationListSource::List(vec!["test".to_string()]),
             ignore_blank: true,
             drop_down: false,
+            multi_select: false,
         };
         let rule = ValidationRule::List(list);
         assert!(rule.is_list());
@@ -183,6 +208,7 @@ mod tests {
             source: ValidationListSource::List(vec!["test".to_string()]),
             ignore_blank: true,
             drop_down: false,
+            multi_select: false,
         };
         let rule = ValidationRule::List(list);
         assert!(!rule.is_logical());
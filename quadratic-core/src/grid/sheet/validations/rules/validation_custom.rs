@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use crate::CellValue;
+
+/// A validation rule that checks each cell with a formula (eg,
+/// `=COUNTIF($A:$A, A2)=1`). Relative references in the formula are relative
+/// to the top left cell of the validation's selection.
+#[derive(Default, Serialize, Deserialize, Debug, Clone, PartialEq, TS)]
+pub struct ValidationCustom {
+    pub formula: String,
+    pub ignore_blank: bool,
+}
+
+impl ValidationCustom {
+    /// Validate a CellValue against the rule. `evaluate` evaluates the
+    /// formula for the cell and returns whether the result is true.
+    pub fn validate(&self, value: Option<&CellValue>, evaluate: impl FnOnce(&str) -> bool) -> bool {
+        let is_blank = value.is_none_or(|value| value.is_blank_or_empty_string());
+        if is_blank && self.ignore_blank {
+            return true;
+        }
+        evaluate(&self.formula)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_custom() {
+        let custom = ValidationCustom {
+            formula: "=A1>1".to_string(),
+            ignore_blank: true,
+        };
+        assert!(custom.validate(None, |_| false));
+        assert!(custom.validate(Some(&CellValue::Text(String::new())), |_| false));
+        assert!(!custom.validate(Some(&CellValue::Number(1.into())), |_| false));
+        assert!(
+            custom.validate(Some(&CellValue::Number(2.into())), |formula| {
+                formula == "=A1>1"
+            })
+        );
+
+        let custom = ValidationCustom {
+            ignore_blank: false,
+            ..custom
+        };
+        assert!(!custom.validate(None, |_| false));
+    }
+}
@@ -40,6 +40,13 @@ impl ValidationDateTime {
                     }
                     (dt.and_utc().timestamp(), naive_time_to_i32(dt.time()))
                 }
+                CellValue::DateTimeTz(dt) => {
+                    if self.prohibit_time || self.prohibit_date {
+                        return false;
+                    }
+                    let dt = dt.naive_local();
+                    (dt.and_utc().timestamp(), naive_time_to_i32(dt.time()))
+                }
                 CellValue::Date(d) => {
                     if self.prohibit_date || self.require_time {
                         return false;
@@ -8,10 +8,19 @@ use crate::{
     grid::Sheet,
 };
 
+/// The delimiter between the values of a multi-select list cell.
+pub const MULTI_SELECT_DELIMITER: &str = ", ";
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, TS)]
 pub enum ValidationListSource {
     Selection(A1Selection),
     List(Vec<String>),
+
+    /// A formula whose result is the list, evaluated for each cell (eg,
+    /// `=FILTER(Cities!B:B, Cities!A:A=A2)` for a list that depends on
+    /// another cell). Relative references are relative to the top left cell
+    /// of the validation's selection.
+    Formula(String),
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, TS)]
@@ -19,6 +28,11 @@ pub struct ValidationList {
     pub source: ValidationListSource,
     pub ignore_blank: bool,
     pub drop_down: bool,
+
+    /// Whether a cell may hold several values from the list, stored as text
+    /// separated by [`MULTI_SELECT_DELIMITER`] (see [`Self::join_values`]).
+    #[serde(default)]
+    pub multi_select: bool,
 }
 
 impl ValidationList {
@@ -36,36 +50,112 @@ impl ValidationList {
         }
     }
 
-    /// Validates a CellValue against a ValidationList.
+    /// Validates a CellValue against a ValidationList. Formula sources need
+    /// the formula engine, so they always pass here; they are checked by the
+    /// GridController (see `validate_formula_rule`).
     pub(crate) fn validate(
         &self,
         sheet: &Sheet,
         value: Option<&CellValue>,
         a1_context: &A1Context,
     ) -> bool {
-        if let Some(value) = value {
-            // handle cases of blank text
-            match value {
-                CellValue::Blank => return self.ignore_blank,
-                CellValue::Text(text) => {
-                    if text.is_empty() && self.ignore_blank {
-                        return true;
-                    }
-                }
-                _ => {}
+        self.validate_with(value, |value| match &self.source {
+            ValidationListSource::Selection(selection) => {
+                ValidationList::validate_selection(sheet, selection, value, a1_context)
             }
+            ValidationListSource::List(list) => list.contains(&value.to_string()),
+            ValidationListSource::Formula(_) => true,
+        })
+    }
 
-            match &self.source {
-                ValidationListSource::Selection(selection) => {
-                    ValidationList::validate_selection(sheet, selection, value, a1_context)
+    /// Validates a CellValue, using `contains` to check whether a value is in
+    /// the list. Each value of a multi-select cell is checked separately.
+    pub(crate) fn validate_with(
+        &self,
+        value: Option<&CellValue>,
+        contains: impl Fn(&CellValue) -> bool,
+    ) -> bool {
+        let Some(value) = value else {
+            return self.ignore_blank;
+        };
+
+        // handle cases of blank text
+        match value {
+            CellValue::Blank => return self.ignore_blank,
+            CellValue::Text(text) => {
+                if text.is_empty() && self.ignore_blank {
+                    return true;
                 }
-                ValidationListSource::List(list) => list.contains(&value.to_string()),
             }
+            _ => {}
+        }
+
+        if self.multi_select {
+            Self::split_values(&value.to_display())
+                .iter()
+                .all(|item| contains(&CellValue::parse_from_str(item)))
         } else {
-            self.ignore_blank
+            contains(value)
         }
     }
 
+    /// Splits the text of a multi-select cell into its values. Values that
+    /// contain the delimiter are quoted (see [`Self::join_values`]).
+    pub fn split_values(text: &str) -> Vec<String> {
+        let delimiter = MULTI_SELECT_DELIMITER.trim();
+        let mut values = vec![];
+        let mut rest = text.trim_start();
+        while !rest.is_empty() {
+            let value;
+            if let Some(quoted) = rest.strip_prefix('"') {
+                // a quoted value ends at the first quote that is not doubled
+                let mut unquoted = String::new();
+                let mut chars = quoted.char_indices().peekable();
+                let mut end = quoted.len();
+                while let Some((i, c)) = chars.next() {
+                    if c == '"' {
+                        if chars.next_if(|(_, c)| *c == '"').is_some() {
+                            unquoted.push('"');
+                        } else {
+                            end = i + 1;
+                            break;
+                        }
+                    } else {
+                        unquoted.push(c);
+                    }
+                }
+                value = unquoted;
+                rest = &quoted[end..];
+                rest = rest.find(delimiter).map_or("", |i| &rest[i..]);
+            } else {
+                let end = rest.find(delimiter).unwrap_or(rest.len());
+                value = rest[..end].trim().to_string();
+                rest = &rest[end..];
+            }
+            if !value.is_empty() {
+                values.push(value);
+            }
+            rest = rest.strip_prefix(delimiter).unwrap_or(rest).trim_start();
+        }
+        values
+    }
+
+    /// Joins the values of a multi-select cell into its text. Values that
+    /// contain the delimiter or a quote are quoted, with quotes doubled.
+    pub fn join_values(values: &[String]) -> String {
+        let delimiter = MULTI_SELECT_DELIMITER.trim();
+        values
+            .iter()
+            .map(|value| {
+                if value.contains(delimiter) || value.starts_with('"') {
+                    format!("\"{}\"", value.replace('"', "\"\""))
+                } else {
+                    value.clone()
+                }
+            })
+            .join(MULTI_SELECT_DELIMITER)
+    }
+
     /// Gets the drop down list.
     pub fn to_drop_down(&self, sheet: &Sheet, a1_context: &A1Context) -> Option<Vec<String>> {
         if !self.drop_down {
@@ -83,6 +173,9 @@ impl ValidationList {
                 )
             }
             ValidationListSource::List(list) => Some(list.iter().cloned().unique().collect()),
+
+            // evaluated by the GridController for each cell
+            ValidationListSource::Formula(_) => None,
         }
     }
 }
@@ -98,6 +191,7 @@ mod tests {
             source: ValidationListSource::List(vec!["test".to_string()]),
             ignore_blank: true,
             drop_down: true,
+            multi_select: false,
         };
 
         let a1_context = sheet.make_a1_context();
@@ -141,6 +235,7 @@ mod tests {
             source: ValidationListSource::Selection(selection),
             ignore_blank: false,
             drop_down: true,
+            multi_select: false,
         };
         assert!(!list.validate(&sheet, None, &a1_context));
     }
@@ -152,6 +247,7 @@ mod tests {
             source: ValidationListSource::List(vec!["test".to_string(), "test".to_string()]),
             ignore_blank: true,
             drop_down: true,
+            multi_select: false,
         };
 
         let a1_context = sheet.make_a1_context();
@@ -164,6 +260,7 @@ mod tests {
             source: ValidationListSource::List(vec!["test".to_string(), "test2".to_string()]),
             ignore_blank: true,
             drop_down: false,
+            multi_select: false,
         };
         assert_eq!(list.to_drop_down(&sheet, &a1_context), None);
     }
@@ -179,6 +276,7 @@ mod tests {
             source: ValidationListSource::Selection(selection),
             ignore_blank: true,
             drop_down: true,
+            multi_select: false,
         };
 
         let a1_context = sheet.make_a1_context();
@@ -196,6 +294,7 @@ mod tests {
             source: ValidationListSource::List(vec!["test".to_string()]),
             ignore_blank: true,
             drop_down: true,
+            multi_select: false,
         };
 
         let a1_context = sheet.make_a1_context();
@@ -210,6 +309,7 @@ mod tests {
             source: ValidationListSource::List(vec!["test".to_string()]),
             ignore_blank: false,
             drop_down: true,
+            multi_select: false,
         };
 
         assert!(!list_no_ignore.validate(&sheet, Some(&CellValue::Blank), &a1_context));
@@ -220,4 +320,70 @@ mod tests {
         ));
         assert!(!list_no_ignore.validate(&sheet, None, &a1_context));
     }
+
+    #[test]
+    fn validate_multi_select() {
+        let sheet = Sheet::test();
+        let a1_context = sheet.make_a1_context();
+        let list = ValidationList {
+            source: ValidationListSource::List(vec![
+                "red".to_string(),
+                "green".to_string(),
+                "1".to_string(),
+            ]),
+            ignore_blank: true,
+            drop_down: true,
+            multi_select: true,
+        };
+
+        let text = |s: &str| CellValue::Text(s.to_string());
+        assert!(list.validate(&sheet, Some(&text("red")), &a1_context));
+        assert!(list.validate(&sheet, Some(&text("red, green")), &a1_context));
+        assert!(list.validate(&sheet, Some(&text("green,1")), &a1_context));
+        assert!(!list.validate(&sheet, Some(&text("red, blue")), &a1_context));
+
+        assert_eq!(
+            ValidationList::split_values(" red,green ,, 1"),
+            vec!["red".to_string(), "green".to_string(), "1".to_string()]
+        );
+        assert_eq!(
+            ValidationList::join_values(&["red".to_string(), "green".to_string()]),
+            "red, green"
+        );
+    }
+
+    #[test]
+    fn validate_multi_select_with_commas() {
+        let sheet = Sheet::test();
+        let a1_context = sheet.make_a1_context();
+        let list = ValidationList {
+            source: ValidationListSource::List(vec![
+                "Paris, France".to_string(),
+                "Rome".to_string(),
+                "\"Big\" Apple".to_string(),
+            ]),
+            ignore_blank: true,
+            drop_down: true,
+            multi_select: true,
+        };
+
+        let values = vec![
+            "Paris, France".to_string(),
+            "Rome".to_string(),
+            "\"Big\" Apple".to_string(),
+        ];
+        let text = ValidationList::join_values(&values);
+        assert_eq!(text, r#""Paris, France", Rome, """Big"" Apple""#);
+        assert_eq!(ValidationList::split_values(&text), values);
+
+        let text = |s: &str| CellValue::Text(s.to_string());
+        assert!(list.validate(&sheet, Some(&text(r#""Paris, France", Rome"#)), &a1_context));
+        assert!(!list.validate(&sheet, Some(&text("Paris, France, Rome")), &a1_context));
+
+        // an unterminated quote takes the rest of the text
+        assert_eq!(
+            ValidationList::split_values(r#"Rome, "Paris, France"#),
+            vec!["Rome".to_string(), "Paris, France".to_string()]
+        );
+    }
 }
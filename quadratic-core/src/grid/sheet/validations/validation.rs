@@ -66,7 +66,9 @@ impl Validation {
     pub fn render_special(&self) -> Option<JsRenderCellSpecial> {
         match &self.rule {
             ValidationRule::List(list) => {
-                if list.drop_down {
+                if list.drop_down && list.multi_select {
+                    Some(JsRenderCellSpecial::MultiSelectList)
+                } else if list.drop_down {
                     Some(JsRenderCellSpecial::List)
                 } else {
                     None
@@ -178,6 +180,7 @@ mod tests {
                 source: ValidationListSource::List(vec!["test".to_string()]),
                 ignore_blank: true,
                 drop_down: true,
+                multi_select: false,
             }),
             message: Default::default(),
             error: Default::default(),
@@ -1,26 +1,66 @@
 //! Settings and getting validation warnings.
 
+use std::collections::{HashMap, HashSet};
+
 use uuid::Uuid;
 
-use crate::{Pos, SheetPos, controller::operations::operation::Operation};
+use crate::{Pos, SheetPos, controller::operations::operation::Operation, grid::CellsAccessed};
+
+use super::{Validations, validation::Validation};
+
+/// The cells that fail a validation with a formula, kept until the validation
+/// or the cells it accessed change.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationFormulaResults {
+    /// The validation when it was evaluated.
+    pub validation: Validation,
+
+    /// The cells in the selection and the cells accessed by the formula.
+    pub cells_accessed: CellsAccessed,
 
-use super::Validations;
+    pub failing: HashSet<Pos>,
+}
 
 impl Validations {
+    /// Gets the warning of a cell. Validations with a formula only warn when
+    /// the cell has no other warning; of those, the last failing validation
+    /// wins, as with other rules.
     pub fn get_warning(&self, pos: Pos) -> Option<&Uuid> {
-        self.warnings.get(&pos)
+        self.warnings.get(&pos).or_else(|| {
+            self.validations.iter().rev().find_map(|validation| {
+                self.formula_results
+                    .get(&validation.id)
+                    .filter(|results| results.failing.contains(&pos))
+                    .map(|results| &results.validation.id)
+            })
+        })
     }
 
     pub fn has_warning(&self, pos: Pos) -> bool {
-        self.warnings.contains_key(&pos)
+        self.get_warning(pos).is_some()
     }
 
+    /// Whether the cell has a (saved) warning from a validation without a
+    /// formula.
     pub fn has_warning_for_validation(&self, pos: Pos, validation_id: Uuid) -> bool {
         self.warnings
             .iter()
             .any(|(warning_pos, id)| *warning_pos == pos && *id == validation_id)
     }
 
+    /// Returns the warnings of all cells (see [`Self::get_warning`]).
+    pub fn all_warnings(&self) -> HashMap<Pos, Uuid> {
+        let mut warnings = self.warnings.clone();
+        for validation in self.validations.iter().rev() {
+            if let Some(results) = self.formula_results.get(&validation.id) {
+                for pos in results.failing.iter() {
+                    warnings.entry(*pos).or_insert(validation.id);
+                }
+            }
+        }
+        warnings
+    }
+
     /// Sets a validation warning. Removes the validation if None is passed.
     pub fn set_warning(&mut self, sheet_pos: SheetPos, validation_id: Option<Uuid>) -> Operation {
         let old = if let Some(validation_id) = validation_id {
@@ -33,6 +73,42 @@ impl Validations {
             validation_id: old,
         }
     }
+
+    /// Replaces the results of the evaluated validations with a formula and
+    /// drops the results of removed validations. Returns the cells whose
+    /// warning may have changed.
+    pub(crate) fn set_formula_results(
+        &mut self,
+        results: Vec<ValidationFormulaResults>,
+    ) -> HashSet<Pos> {
+        let mut changed = HashSet::new();
+        let ids = self
+            .validations
+            .iter()
+            .filter(|validation| validation.rule.has_formula())
+            .map(|validation| validation.id)
+            .collect::<HashSet<_>>();
+        self.formula_results.retain(|id, old| {
+            let keep = ids.contains(id);
+            if !keep {
+                changed.extend(old.failing.iter().copied());
+            }
+            keep
+        });
+        for results in results {
+            match self.formula_results.get(&results.validation.id) {
+                Some(old) if old.validation == results.validation => {
+                    changed.extend(old.failing.symmetric_difference(&results.failing));
+                }
+                Some(old) => {
+                    changed.extend(old.failing.union(&results.failing));
+                }
+                None => changed.extend(results.failing.iter().copied()),
+            }
+            self.formula_results.insert(results.validation.id, results);
+        }
+        changed
+    }
 }
 
 #[cfg(test)]
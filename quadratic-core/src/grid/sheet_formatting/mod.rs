@@ -39,4 +39,11 @@ pub struct SheetFormatting {
     pub date_time: SheetFormattingType<String>,
     pub underline: SheetFormattingType<bool>,
     pub strike_through: SheetFormattingType<bool>,
+    pub font_size: SheetFormattingType<i16>,
+    pub font_family: SheetFormattingType<String>,
+    pub text_rotation: SheetFormattingType<i16>,
+    pub indent: SheetFormattingType<u8>,
+
+    /// Name of the named cell style applied to the cells.
+    pub style: SheetFormattingType<String>,
 }
@@ -22,6 +22,11 @@ impl SheetFormatting {
             || self.date_time.col_max(column) > 0
             || self.underline.col_max(column) > 0
             || self.strike_through.col_max(column) > 0
+            || self.font_size.col_max(column) > 0
+            || self.font_family.col_max(column) > 0
+            || self.text_rotation.col_max(column) > 0
+            || self.indent.col_max(column) > 0
+            || self.style.col_max(column) > 0
     }
 
     pub fn has_format_in_row(&self, row: i64) -> bool {
@@ -38,6 +43,11 @@ impl SheetFormatting {
             || self.date_time.row_max(row) > 0
             || self.underline.row_max(row) > 0
             || self.strike_through.row_max(row) > 0
+            || self.font_size.row_max(row) > 0
+            || self.font_family.row_max(row) > 0
+            || self.text_rotation.row_max(row) > 0
+            || self.indent.row_max(row) > 0
+            || self.style.row_max(row) > 0
     }
 
     /// Returns format for a cell or None if default.
@@ -66,6 +76,11 @@ impl SheetFormatting {
             date_time: self.date_time.get(pos),
             underline: self.underline.get(pos),
             strike_through: self.strike_through.get(pos),
+            font_size: self.font_size.get(pos),
+            font_family: self.font_family.get(pos),
+            text_rotation: self.text_rotation.get(pos),
+            indent: self.indent.get(pos),
+            style: self.style.get(pos),
         }
     }
 
@@ -81,6 +96,14 @@ impl SheetFormatting {
         !self.wrap.is_row_default(row)
     }
 
+    /// Returns whether the cell's row height depends on its content: wrapped
+    /// text, a custom font size, or rotated text.
+    pub fn needs_auto_height(&self, pos: Pos) -> bool {
+        self.wrap.get(pos) == Some(CellWrap::Wrap)
+            || self.font_size.get(pos).is_some()
+            || self.text_rotation.get(pos).is_some()
+    }
+
     /// Returns the finite bounds of the formatting.
     pub fn finite_bounds(&self) -> Option<Rect> {
         let mut bounds = GridBounds::default();
@@ -123,6 +146,21 @@ impl SheetFormatting {
         if let Some(rect) = self.strike_through.finite_bounds() {
             bounds.add_rect(rect);
         }
+        if let Some(rect) = self.font_size.finite_bounds() {
+            bounds.add_rect(rect);
+        }
+        if let Some(rect) = self.font_family.finite_bounds() {
+            bounds.add_rect(rect);
+        }
+        if let Some(rect) = self.text_rotation.finite_bounds() {
+            bounds.add_rect(rect);
+        }
+        if let Some(rect) = self.indent.finite_bounds() {
+            bounds.add_rect(rect);
+        }
+        if let Some(rect) = self.style.finite_bounds() {
+            bounds.add_rect(rect);
+        }
         bounds.into()
     }
 
@@ -142,6 +180,11 @@ impl SheetFormatting {
             self.date_time.col_min(column),
             self.underline.col_min(column),
             self.strike_through.col_min(column),
+            self.font_size.col_min(column),
+            self.font_family.col_min(column),
+            self.text_rotation.col_min(column),
+            self.indent.col_min(column),
+            self.style.col_min(column),
         ];
         let min = col_mins.iter().filter(|&&x| x != 0).min()?;
         if *min == 0 { None } else { Some(*min) }
@@ -163,6 +206,11 @@ impl SheetFormatting {
             self.date_time.col_max(column),
             self.underline.col_max(column),
             self.strike_through.col_max(column),
+            self.font_size.col_max(column),
+            self.font_family.col_max(column),
+            self.text_rotation.col_max(column),
+            self.indent.col_max(column),
+            self.style.col_max(column),
         ];
         let max = col_maxes.iter().max()?;
         if *max == 0 { None } else { Some(*max) }
@@ -183,6 +231,11 @@ impl SheetFormatting {
             self.date_time.row_min(row),
             self.underline.row_min(row),
             self.strike_through.row_min(row),
+            self.font_size.row_min(row),
+            self.font_family.row_min(row),
+            self.text_rotation.row_min(row),
+            self.indent.row_min(row),
+            self.style.row_min(row),
         ];
         let min = row_mins.iter().filter(|&&x| x != 0).min()?;
         if *min == 0 { None } else { Some(*min) }
@@ -204,6 +257,11 @@ impl SheetFormatting {
             self.date_time.row_max(row),
             self.underline.row_max(row),
             self.strike_through.row_max(row),
+            self.font_size.row_max(row),
+            self.font_family.row_max(row),
+            self.text_rotation.row_max(row),
+            self.indent.row_max(row),
+            self.style.row_max(row),
         ];
         let max = row_maxes.iter().max()?;
         if *max == 0 { None } else { Some(*max) }
@@ -274,6 +332,22 @@ mod tests {
         assert!(!formatting.row_has_wrap(4));
     }
 
+    #[test]
+    fn test_needs_auto_height() {
+        let mut formatting = SheetFormatting::default();
+        assert!(!formatting.needs_auto_height(pos![A1]));
+        formatting.wrap.set(pos![A1], Some(CellWrap::Clip));
+        assert!(!formatting.needs_auto_height(pos![A1]));
+        formatting.wrap.set(pos![A1], Some(CellWrap::Wrap));
+        assert!(formatting.needs_auto_height(pos![A1]));
+        formatting.font_size.set(pos![B2], Some(20));
+        assert!(formatting.needs_auto_height(pos![B2]));
+        formatting.text_rotation.set(pos![C3], Some(45));
+        assert!(formatting.needs_auto_height(pos![C3]));
+        formatting.indent.set(pos![D4], Some(2));
+        assert!(!formatting.needs_auto_height(pos![D4]));
+    }
+
     #[test]
     fn test_row_min() {
         let formatting = create_test_formatting();
@@ -55,6 +55,14 @@ impl SheetFormatting {
                 &updates.strike_through,
                 &mut self.strike_through,
             ),
+            font_size: Self::apply_updates_item(&updates.font_size, &mut self.font_size),
+            font_family: Self::apply_updates_item(&updates.font_family, &mut self.font_family),
+            text_rotation: Self::apply_updates_item(
+                &updates.text_rotation,
+                &mut self.text_rotation,
+            ),
+            indent: Self::apply_updates_item(&updates.indent, &mut self.indent),
+            style: Self::apply_updates_item(&updates.style, &mut self.style),
         }
     }
 
@@ -72,6 +80,11 @@ impl SheetFormatting {
         self.date_time.translate_in_place(x, y);
         self.underline.translate_in_place(x, y);
         self.strike_through.translate_in_place(x, y);
+        self.font_size.translate_in_place(x, y);
+        self.font_family.translate_in_place(x, y);
+        self.text_rotation.translate_in_place(x, y);
+        self.indent.translate_in_place(x, y);
+        self.style.translate_in_place(x, y);
     }
 }
 
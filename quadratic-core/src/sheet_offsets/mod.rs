@@ -97,6 +97,58 @@ impl SheetOffsets {
         old
     }
 
+    /// Hides or unhides a column and returns whether it was hidden before.
+    pub fn set_column_hidden(&mut self, x: i64, hidden: bool) -> bool {
+        let old = self.column_widths.set_hidden(x, hidden);
+        self.calculate_thumbnail();
+        old
+    }
+
+    /// Hides or unhides a row and returns whether it was hidden before.
+    pub fn set_row_hidden(&mut self, y: i64, hidden: bool) -> bool {
+        let old = self.row_heights.set_hidden(y, hidden);
+        self.calculate_thumbnail();
+        old
+    }
+
+    pub fn is_column_hidden(&self, x: i64) -> bool {
+        self.column_widths.is_hidden(x)
+    }
+
+    pub fn is_row_hidden(&self, y: i64) -> bool {
+        self.row_heights.is_hidden(y)
+    }
+
+    /// Returns all hidden columns.
+    pub fn hidden_columns(&self) -> Vec<i64> {
+        self.column_widths.iter_hidden().collect()
+    }
+
+    /// Returns all hidden rows.
+    pub fn hidden_rows(&self) -> Vec<i64> {
+        self.row_heights.iter_hidden().collect()
+    }
+
+    /// Returns true if any column in the range is hidden.
+    pub fn has_hidden_columns(&self, from: i64, to: i64) -> bool {
+        self.column_widths.has_hidden_in_range(from, to)
+    }
+
+    /// Returns true if any row in the range is hidden.
+    pub fn has_hidden_rows(&self, from: i64, to: i64) -> bool {
+        self.row_heights.has_hidden_in_range(from, to)
+    }
+
+    /// Returns the width of a column, ignoring whether it is hidden.
+    pub fn column_set_width(&self, x: i64) -> f64 {
+        self.column_widths.get_set_size(x)
+    }
+
+    /// Returns the height of a row, ignoring whether it is hidden.
+    pub fn row_set_height(&self, y: i64) -> f64 {
+        self.row_heights.get_set_size(y)
+    }
+
     pub fn column_width(&self, x: i64) -> f64 {
         self.column_widths.get_size(x)
     }
@@ -266,7 +318,7 @@ impl SheetOffsets {
     /// Returns a vector of changes made to the offsets structure, where each change
     /// is represented as a tuple (index, new_size).
     pub fn insert_column(&mut self, column: i64, copy_formats: CopyFormats) -> Vec<(i64, f64)> {
-        let source_width = self.column_width(if copy_formats == CopyFormats::Before {
+        let source_width = self.column_set_width(if copy_formats == CopyFormats::Before {
             column - 1
         } else {
             column
@@ -288,7 +340,7 @@ impl SheetOffsets {
     /// Returns a vector of changes made to the offsets structure, where each change
     /// is represented as a tuple (index, new_size).
     pub fn insert_row(&mut self, row: i64, copy_formats: CopyFormats) -> Vec<(i64, f64)> {
-        let source_height = self.row_height(if copy_formats == CopyFormats::Before {
+        let source_height = self.row_set_height(if copy_formats == CopyFormats::Before {
             row - 1
         } else {
             row
@@ -383,6 +435,34 @@ mod test {
         assert_eq!(rect.max.y, (offsets.defaults().1 * 2.0) as i64);
     }
 
+    #[test]
+    fn test_hidden_columns_rows() {
+        let mut offsets = SheetOffsets::default();
+        let (default_col, default_row) = offsets.defaults();
+
+        assert!(!offsets.set_column_hidden(2, true));
+        assert!(!offsets.set_row_hidden(3, true));
+        assert!(offsets.is_column_hidden(2));
+        assert!(offsets.is_row_hidden(3));
+        assert_eq!(offsets.hidden_columns(), vec![2]);
+        assert_eq!(offsets.hidden_rows(), vec![3]);
+        assert_eq!(offsets.column_width(2), 0.0);
+        assert_eq!(offsets.column_set_width(2), default_col);
+        assert_eq!(offsets.column_position_size(3), (default_col, default_col));
+        assert_eq!(
+            offsets.row_position_size(4),
+            (default_row * 2.0, default_row)
+        );
+
+        // the width of a hidden column is not copied when inserting
+        offsets.insert_column(3, CopyFormats::Before);
+        assert_eq!(offsets.column_width(3), default_col);
+        assert_eq!(offsets.hidden_columns(), vec![2]);
+
+        assert!(offsets.set_column_hidden(2, false));
+        assert_eq!(offsets.column_width(2), default_col);
+    }
+
     #[test]
     fn test_defaults() {
         let sheet = super::SheetOffsets::default();
@@ -8,7 +8,7 @@
 
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::ops::Range;
 
 #[cfg(feature = "js")]
@@ -26,6 +26,11 @@ pub struct Offsets {
     default: f64,
     #[serde(with = "crate::util::btreemap_serde")]
     sizes: BTreeMap<i64, f64>,
+
+    /// Hidden columns/rows have a size of 0 but keep their set size so it is
+    /// restored when they are unhidden.
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    hidden: BTreeSet<i64>,
 }
 impl Offsets {
     /// Constructs an empty `Offsets` structure.
@@ -33,6 +38,7 @@ impl Offsets {
         Offsets {
             default,
             sizes: BTreeMap::new(),
+            hidden: BTreeSet::new(),
         }
     }
 
@@ -41,6 +47,7 @@ impl Offsets {
         Offsets {
             default,
             sizes: iter.into_iter().collect(),
+            hidden: BTreeSet::new(),
         }
     }
 
@@ -62,13 +69,59 @@ impl Offsets {
         if let Some(value) = value_to_move {
             self.sizes.insert(to_index, value);
         }
+
+        let hidden_to_move = self.hidden.remove(&from_index);
+        let range = std::cmp::min(from_index, to_index)..=std::cmp::max(from_index, to_index);
+        let hidden = self.hidden.range(range).copied().collect_vec();
+        for k in &hidden {
+            self.hidden.remove(k);
+        }
+        self.hidden.extend(hidden.into_iter().map(|k| k + delta));
+        if hidden_to_move {
+            self.hidden.insert(to_index);
+        }
     }
 
-    /// Returns the width/height of a column/row.
+    /// Returns the width/height of a column/row. Hidden columns/rows have a
+    /// size of 0.
     pub fn get_size(&self, index: i64) -> f64 {
+        if self.hidden.contains(&index) {
+            0.0
+        } else {
+            self.get_set_size(index)
+        }
+    }
+
+    /// Returns the width/height of a column/row, ignoring whether it is
+    /// hidden.
+    pub fn get_set_size(&self, index: i64) -> f64 {
         *self.sizes.get(&index).unwrap_or(&self.default)
     }
 
+    /// Returns true if the column/row is hidden.
+    pub fn is_hidden(&self, index: i64) -> bool {
+        self.hidden.contains(&index)
+    }
+
+    /// Hides or unhides a column/row. Returns whether it was hidden before.
+    pub fn set_hidden(&mut self, index: i64, hidden: bool) -> bool {
+        if hidden {
+            !self.hidden.insert(index)
+        } else {
+            self.hidden.remove(&index)
+        }
+    }
+
+    /// Iterates over all hidden columns/rows.
+    pub fn iter_hidden(&self) -> impl '_ + Iterator<Item = i64> {
+        self.hidden.iter().copied()
+    }
+
+    /// Returns true if any columns/rows in the range are hidden.
+    pub fn has_hidden_in_range(&self, from: i64, to: i64) -> bool {
+        from <= to && self.hidden.range(from..=to).next().is_some()
+    }
+
     /// Sets the width/height of a column/row.
     pub fn set_size(&mut self, index: i64, value: f64) -> f64 {
         if value == self.default {
@@ -91,6 +144,11 @@ impl Offsets {
                 .sizes
                 .range(1..index_range.start)
                 .map(|(_, v)| v - self.default)
+                .sum::<f64>()
+            - self
+                .hidden
+                .range(1..index_range.start)
+                .map(|&index| self.get_set_size(index))
                 .sum::<f64>();
         index_range.map(move |index| {
             let ret = current_position;
@@ -184,6 +242,11 @@ impl Offsets {
         }
 
         self.sizes = sizes;
+        self.hidden = self
+            .hidden
+            .iter()
+            .map(|&k| if k >= index { k + 1 } else { k })
+            .collect();
         changed
     }
 
@@ -220,6 +283,12 @@ impl Offsets {
             }
         }
         self.sizes = sizes;
+        self.hidden = self
+            .hidden
+            .iter()
+            .filter(|&&k| k != index)
+            .map(|&k| if k > index { k - 1 } else { k })
+            .collect();
         (
             changed.into_iter().sorted_by_key(|(k, _)| *k).collect(),
             old,
@@ -446,4 +515,43 @@ mod tests {
         // Verify new entries also use default
         assert_eq!(offsets.get_size(100), 10.0);
     }
+
+    #[test]
+    fn test_hidden() {
+        let mut offsets = Offsets::new(10.0);
+        offsets.set_size(2, 30.0);
+
+        assert!(!offsets.set_hidden(2, true));
+        assert!(offsets.set_hidden(2, true));
+        assert!(offsets.is_hidden(2));
+        assert_eq!(offsets.get_size(2), 0.0);
+        assert_eq!(offsets.get_set_size(2), 30.0);
+        assert_eq!(
+            offsets.iter_offsets(1..5).collect_vec(),
+            vec![0.0, 10.0, 10.0, 20.0]
+        );
+        assert_eq!(offsets.iter_offsets(3..5).collect_vec(), vec![10.0, 20.0]);
+        assert_eq!(offsets.find_offset(10.0), (3, 10.0));
+        assert!(offsets.has_hidden_in_range(1, 2));
+        assert!(!offsets.has_hidden_in_range(3, 10));
+
+        assert!(offsets.set_hidden(2, false));
+        assert_eq!(offsets.get_size(2), 30.0);
+    }
+
+    #[test]
+    fn test_hidden_insert_delete_move() {
+        let mut offsets = Offsets::new(10.0);
+        offsets.set_hidden(2, true);
+        offsets.set_hidden(4, true);
+
+        offsets.insert(3, None);
+        assert_eq!(offsets.iter_hidden().collect_vec(), vec![2, 5]);
+
+        offsets.delete(2);
+        assert_eq!(offsets.iter_hidden().collect_vec(), vec![4]);
+
+        offsets.move_elem(4, 1);
+        assert_eq!(offsets.iter_hidden().collect_vec(), vec![1]);
+    }
 }
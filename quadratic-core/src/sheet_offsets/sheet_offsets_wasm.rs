@@ -56,6 +56,18 @@ impl SheetOffsets {
         self.set_row_height(y as i64, height)
     }
 
+    /// Returns true if the column is hidden.
+    #[wasm_bindgen(js_name = "isColumnHidden")]
+    pub fn js_is_column_hidden(&self, x: i32) -> bool {
+        self.is_column_hidden(x as i64)
+    }
+
+    /// Returns true if the row is hidden.
+    #[wasm_bindgen(js_name = "isRowHidden")]
+    pub fn js_is_row_hidden(&self, y: i32) -> bool {
+        self.is_row_hidden(y as i64)
+    }
+
     /// gets the row height from a row index
     #[wasm_bindgen(js_name = "getRowHeight")]
     pub fn js_row_height(&self, y: i32) -> f32 {
@@ -75,7 +75,7 @@ pub fn assert_validation_warning(
 ) {
     let expected_validation_id = expected_validation.map(|v| v.id);
     let sheet = gc.sheet(sheet_pos.sheet_id);
-    let validation = sheet.validations.warnings.get(&sheet_pos.into());
+    let validation = sheet.validations.get_warning(sheet_pos.into());
     if let Some(validation_id) = validation {
         assert_eq!(
             expected_validation_id,
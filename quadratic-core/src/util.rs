@@ -1,6 +1,7 @@
 use std::fmt;
 use std::ops::Range;
 
+use bigdecimal::BigDecimal;
 use chrono::Utc;
 use itertools::Itertools;
 use lazy_static::lazy_static;
@@ -337,11 +338,54 @@ pub fn round(number: f64, precision: i64) -> f64 {
     (number * y).round() / y
 }
 
+/// Removes the trailing zeros of a decimal (eg, `1.0` becomes `1`). Integers
+/// within the range of `f64` are kept out of scientific notation (eg, `100`
+/// stays `100`).
+pub fn normalize_decimal(n: BigDecimal) -> BigDecimal {
+    let n = n.normalized();
+    let scale = n.fractional_digit_count();
+    if scale < 0 && -scale <= i64::from(f64::MAX_10_EXP) {
+        n.with_scale(0)
+    } else {
+        n
+    }
+}
+
 /// Returns a string suitable for case-insensitive comparison.
 pub fn case_fold(s: &str) -> String {
     s.to_uppercase() // TODO: want proper Unicode case folding
 }
 
+/// Capitalizes the first letter in each word and lowercases the rest.
+pub fn title_case(s: &str) -> String {
+    let mut last_char = '\0';
+    let mut ret = String::new();
+    // Convert to lowercase first so that we get correct handling of
+    // word-final sigma. This *may* cause issues where the first
+    // character is not preserved, since it gets lowercased and then
+    // titlecased.
+    for c in s.to_lowercase().chars() {
+        if last_char.is_alphabetic() {
+            ret.push(c);
+        } else {
+            // We can't just uppercase the charater, because Unicode
+            // contains some ligatures like `ǆ` which should be
+            // titlecased to `ǅ` rather than `Ǆ`.
+            match unicode_case_mapping::to_titlecase(c) {
+                [0, 0, 0] => ret.push(c), // unchanged
+                char_seq => ret.extend(
+                    char_seq
+                        .into_iter()
+                        .filter(|&c| c != 0)
+                        .filter_map(char::from_u32),
+                ),
+            }
+        }
+        last_char = c;
+    }
+    ret
+}
+
 /// Uppercase ascii-only string suitable for case-insensitive comparison.
 pub fn case_fold_ascii(s: &str) -> String {
     s.to_ascii_uppercase()
@@ -428,6 +472,16 @@ mod tests {
         assert_eq!(round(1.23456789, 4), 1.2346);
     }
 
+    #[test]
+    fn test_normalize_decimal() {
+        let normalize = |n: &str| normalize_decimal(n.parse().unwrap()).to_string();
+        assert_eq!(normalize("1.0"), "1");
+        assert_eq!(normalize("1.50"), "1.5");
+        assert_eq!(normalize("100"), "100");
+        assert_eq!(normalize("6.022e23"), "602200000000000000000000");
+        assert_eq!(normalize("0.00"), "0");
+    }
+
     #[test]
     fn test_unused_name() {
         let used = ["Sheet1", "Sheet 2"];
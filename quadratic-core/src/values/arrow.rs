@@ -10,7 +10,8 @@ use arrow_buffer::ArrowNativeType;
 use arrow_data::ArrayData;
 use arrow_schema::{DataType, TimeUnit};
 use bigdecimal::BigDecimal;
-use chrono::{NaiveDate, NaiveTime, TimeDelta, TimeZone, Utc};
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveTime, TimeDelta, TimeZone, Utc};
+use chrono_tz::Tz;
 
 use crate::{CellValue, cell_values::CellValues};
 
@@ -220,10 +221,22 @@ where
     Ok(values)
 }
 
+/// Converts a UTC timestamp to a date time with the offset of an Arrow time
+/// zone, which is either an IANA name (eg, `America/New_York`) or a fixed
+/// offset (eg, `+05:00`).
+fn timestamp_with_time_zone(dt: DateTime<Utc>, time_zone: &str) -> Option<DateTime<FixedOffset>> {
+    if let Ok(tz) = time_zone.parse::<Tz>() {
+        Some(dt.with_timezone(&tz).fixed_offset())
+    } else {
+        let offset = time_zone.parse::<FixedOffset>().ok()?;
+        Some(dt.with_timezone(&offset))
+    }
+}
+
 fn arrow_timestamp_to_cell_value(
     array_date: ArrayData,
     unit: &TimeUnit,
-    _extra: &Option<Arc<str>>,
+    time_zone: &Option<Arc<str>>,
 ) -> Result<Vec<CellValue>> {
     let mut values = vec![];
 
@@ -236,8 +249,17 @@ fn arrow_timestamp_to_cell_value(
                         TimeUnit::Nanosecond => Utc.timestamp_nanos(*v),
                         TimeUnit::Microsecond => map_local_result(Utc.timestamp_micros(*v))?,
                         TimeUnit::Millisecond => map_local_result(Utc.timestamp_millis_opt(*v))?,
-                        TimeUnit::Second => map_local_result(Utc.timestamp_millis_opt(*v))?,
+                        TimeUnit::Second => map_local_result(Utc.timestamp_opt(*v, 0))?,
                     };
+
+                    // timestamps with a time zone (eg, `timestamptz`) keep
+                    // their offset
+                    if let Some(time_zone) = time_zone
+                        && let Some(dt) = timestamp_with_time_zone(dt, time_zone)
+                    {
+                        return Ok(CellValue::DateTimeTz(dt));
+                    }
+
                     let naive_dt = dt.naive_utc();
                     Ok(CellValue::DateTime(naive_dt))
                 })
@@ -247,3 +269,46 @@ fn arrow_timestamp_to_cell_value(
 
     Ok(values)
 }
+
+#[cfg(test)]
+mod tests {
+    use arrow_array::TimestampMillisecondArray;
+
+    use super::*;
+
+    #[test]
+    fn test_arrow_timestamp_to_cell_value() {
+        // 2024-03-10T14:30:00Z
+        let millis = 1_710_081_000_000;
+
+        let array: ArrayRef = Arc::new(TimestampMillisecondArray::from(vec![millis]));
+        assert_eq!(
+            arrow_col_to_cell_value_vec(&array).unwrap(),
+            vec![CellValue::DateTime(
+                NaiveDate::from_ymd_opt(2024, 3, 10)
+                    .unwrap()
+                    .and_hms_opt(14, 30, 0)
+                    .unwrap()
+            )]
+        );
+
+        let array: ArrayRef = Arc::new(
+            TimestampMillisecondArray::from(vec![millis]).with_timezone("America/New_York"),
+        );
+        assert_eq!(
+            arrow_col_to_cell_value_vec(&array).unwrap(),
+            vec![CellValue::DateTimeTz(
+                DateTime::parse_from_rfc3339("2024-03-10T10:30:00-04:00").unwrap()
+            )]
+        );
+
+        let array: ArrayRef =
+            Arc::new(TimestampMillisecondArray::from(vec![millis]).with_timezone("+05:30"));
+        assert_eq!(
+            arrow_col_to_cell_value_vec(&array).unwrap(),
+            vec![CellValue::DateTimeTz(
+                DateTime::parse_from_rfc3339("2024-03-10T20:00:00+05:30").unwrap()
+            )]
+        );
+    }
+}
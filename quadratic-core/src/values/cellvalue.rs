@@ -4,16 +4,20 @@ use std::{fmt, fmt::Display};
 
 use anyhow::Result;
 use bigdecimal::{BigDecimal, Signed, ToPrimitive, Zero};
-use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime};
 use serde::{Deserialize, Serialize};
 
-use super::{Duration, Instant, IsBlank};
+use super::{Duration, Hyperlink, Instant, IsBlank, RichText};
 use crate::grid::formats::FormatUpdate;
 use crate::grid::{CodeCellLanguage, CodeCellValue};
 use crate::{
     CodeResult, Pos, RunError, RunErrorMsg, Span, Spanned,
-    date_time::{DEFAULT_DATE_FORMAT, DEFAULT_DATE_TIME_FORMAT, DEFAULT_TIME_FORMAT},
-    grid::{NumericFormat, NumericFormatKind, js_types::JsCellValuePos},
+    date_time::{
+        DEFAULT_DATE_FORMAT, DEFAULT_DATE_TIME_FORMAT, DEFAULT_DATE_TIME_TZ_FORMAT,
+        DEFAULT_TIME_FORMAT, DateOrder, date_time_to_date_time_string,
+        date_time_tz_to_date_time_string, date_to_date_string, time_to_time_string,
+    },
+    grid::{Locale, NumericFormat, NumericFormatKind, js_types::JsCellValuePos},
 };
 
 // todo: fill this out
@@ -65,6 +69,9 @@ pub enum CellValue {
     // Date + time.
     #[cfg_attr(test, proptest(skip))]
     DateTime(NaiveDateTime),
+    /// Date + time with a UTC offset (eg, a database `timestamptz`).
+    #[cfg_attr(test, proptest(skip))]
+    DateTimeTz(DateTime<FixedOffset>),
     // Date.
     #[cfg_attr(test, proptest(skip))]
     Date(NaiveDate),
@@ -83,6 +90,12 @@ pub enum CellValue {
     Image(String),
     #[cfg_attr(test, proptest(skip))]
     Import(Import),
+    /// Link to a URL or to a location in the file.
+    #[cfg_attr(test, proptest(skip))]
+    Hyperlink(Box<Hyperlink>),
+    /// Text with styled spans.
+    #[cfg_attr(test, proptest(skip))]
+    RichText(RichText),
 }
 impl fmt::Display for CellValue {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -97,11 +110,14 @@ impl fmt::Display for CellValue {
             CellValue::Date(d) => write!(f, "{d}"),
             CellValue::Time(d) => write!(f, "{d}"),
             CellValue::DateTime(dt) => write!(f, "{dt}"),
+            CellValue::DateTimeTz(dt) => write!(f, "{dt}"),
             CellValue::Error(e) => write!(f, "{}", e.msg),
             CellValue::Html(s) => write!(f, "{}", s),
             CellValue::Code(code) => write!(f, "{:?}", code),
             CellValue::Image(s) => write!(f, "{}", s),
             CellValue::Import(import) => write!(f, "{:?}", import),
+            CellValue::Hyperlink(link) => write!(f, "{link}"),
+            CellValue::RichText(rich_text) => write!(f, "{rich_text}"),
         }
     }
 }
@@ -129,8 +145,10 @@ impl CellValue {
             CellValue::Image(_) => "image",
             CellValue::Date(_) => "date",
             CellValue::Time(_) => "time",
-            CellValue::DateTime(_) => "date time",
+            CellValue::DateTime(_) | CellValue::DateTimeTz(_) => "date time",
             CellValue::Import(_) => "import",
+            CellValue::Hyperlink(_) => "hyperlink",
+            CellValue::RichText(_) => "rich text",
         }
     }
 
@@ -138,7 +156,9 @@ impl CellValue {
     pub fn type_u8(&self) -> u8 {
         match self {
             CellValue::Blank => 0,
-            CellValue::Text(_) => 1,
+            // languages receive the display text of a hyperlink and the
+            // plain text of rich text
+            CellValue::Text(_) | CellValue::Hyperlink(_) | CellValue::RichText(_) => 1,
             CellValue::Number(_) => 2,
             CellValue::Logical(_) => 3,
             CellValue::Duration(_) => 4,
@@ -148,7 +168,7 @@ impl CellValue {
             CellValue::Image(_) => 8,
             CellValue::Date(_) => 9,
             CellValue::Time(_) => 10,
-            CellValue::Instant(_) | CellValue::DateTime(_) => 11,
+            CellValue::Instant(_) | CellValue::DateTime(_) | CellValue::DateTimeTz(_) => 11,
             CellValue::Import(_) => 12,
         }
     }
@@ -170,7 +190,10 @@ impl CellValue {
             CellValue::Date(d) => d.to_string(),
             CellValue::Time(d) => d.to_string(),
             CellValue::DateTime(d) => d.to_string(),
+            CellValue::DateTimeTz(d) => d.to_rfc3339(),
             CellValue::Import(import) => import.to_string(),
+            CellValue::Hyperlink(link) => format!("{:?}", link.display()),
+            CellValue::RichText(rich_text) => format!("{:?}", rich_text.to_plain()),
         }
     }
 
@@ -214,7 +237,10 @@ impl CellValue {
             CellValue::Date(d) => d.format(DEFAULT_DATE_FORMAT).to_string(),
             CellValue::Time(d) => d.format(DEFAULT_TIME_FORMAT).to_string(),
             CellValue::DateTime(d) => d.format(DEFAULT_DATE_TIME_FORMAT).to_string(),
+            CellValue::DateTimeTz(d) => d.format(DEFAULT_DATE_TIME_TZ_FORMAT).to_string(),
             CellValue::Import(import) => import.to_string(),
+            CellValue::Hyperlink(link) => link.display().to_string(),
+            CellValue::RichText(rich_text) => rich_text.to_plain(),
 
             // these should not render
             CellValue::Code(_) => String::new(),
@@ -231,6 +257,12 @@ impl CellValue {
         match self {
             CellValue::Number(n) => {
                 let numeric_format = numeric_format.unwrap_or_default();
+                if let Some(formatted) = numeric_format
+                    .format_code()
+                    .and_then(|code| code.format(self))
+                {
+                    return formatted.text;
+                }
                 let use_commas = numeric_commas.is_some_and(|c| c)
                     || (numeric_commas.is_none()
                         && numeric_format.kind == NumericFormatKind::Currency);
@@ -294,8 +326,9 @@ impl CellValue {
                         number.push('%');
                         number
                     }
-                    NumericFormatKind::Number => number,
-                    NumericFormatKind::Exponential => number,
+                    NumericFormatKind::Number
+                    | NumericFormatKind::Exponential
+                    | NumericFormatKind::Custom => number,
                 }
             }
             _ => String::new(),
@@ -316,10 +349,13 @@ impl CellValue {
             CellValue::Date(d) => d.format("%m/%d/%Y").to_string(),
             CellValue::Time(t) => t.format("%-I:%M %p").to_string(),
             CellValue::DateTime(t) => t.format("%m/%d/%Y %-I:%M %p").to_string(),
+            CellValue::DateTimeTz(t) => t.format("%m/%d/%Y %-I:%M %p %:z").to_string(),
 
             CellValue::Duration(d) => d.to_string(),
             CellValue::Error(_) => "[error]".to_string(),
             CellValue::Import(import) => import.to_string(),
+            CellValue::Hyperlink(link) => link.display().to_string(),
+            CellValue::RichText(rich_text) => rich_text.to_plain(),
 
             // this should not be editable
             CellValue::Code(_) => String::new(),
@@ -327,6 +363,23 @@ impl CellValue {
         }
     }
 
+    /// Returns the value for editing using the locale's decimal separator and
+    /// default date and time formats.
+    pub fn to_edit_with_locale(&self, locale: &Locale) -> String {
+        match self {
+            CellValue::Number(n) => locale.localize_number(&n.to_string()),
+            CellValue::Date(d) => date_to_date_string(*d, Some(locale.date_format.clone())),
+            CellValue::Time(t) => time_to_time_string(*t, Some(locale.time_format.clone())),
+            CellValue::DateTime(t) => {
+                date_time_to_date_time_string(*t, Some(locale.date_time_format()))
+            }
+            CellValue::DateTimeTz(t) => {
+                date_time_tz_to_date_time_string(*t, Some(locale.date_time_tz_format()))
+            }
+            _ => self.to_edit(),
+        }
+    }
+
     /// Returns the value as a string that can be used by get_cells in languages
     pub fn to_get_cells(&self) -> String {
         match self {
@@ -340,8 +393,11 @@ impl CellValue {
             CellValue::Date(d) => d.format("%Y-%m-%d").to_string(),
             CellValue::Time(t) => t.format("%H:%M:%S%.3f").to_string(),
             CellValue::DateTime(t) => t.format("%Y-%m-%dT%H:%M:%S%.3f").to_string(),
+            CellValue::DateTimeTz(t) => t.format("%Y-%m-%dT%H:%M:%S%.3f%:z").to_string(),
             CellValue::Duration(d) => d.to_string(),
             CellValue::Error(_) => "[error]".to_string(),
+            CellValue::Hyperlink(link) => link.display().to_string(),
+            CellValue::RichText(rich_text) => rich_text.to_plain(),
 
             // these should not return a value
             CellValue::Code(_) => String::new(),
@@ -476,6 +532,19 @@ impl CellValue {
             _ => None,
         }
     }
+    /// Returns the value that formulas see when they reference this cell.
+    /// Rich text reads as its plain text, and hyperlinks read as their linked
+    /// value or display text.
+    pub fn to_formula_value(&self) -> CellValue {
+        match self {
+            CellValue::Hyperlink(link) => match &link.value {
+                Some(value) => value.to_formula_value(),
+                None => CellValue::Text(link.display().to_string()),
+            },
+            CellValue::RichText(rich_text) => CellValue::Text(rich_text.to_plain()),
+            other => other.clone(),
+        }
+    }
     /// Converts an error value into an actual error.
     pub fn into_non_error_value(self) -> CodeResult<Self> {
         match self {
@@ -512,7 +581,7 @@ impl CellValue {
             CellValue::Text(_) => 1,
             CellValue::Logical(_) => 2,
             CellValue::Error(_) => 3,
-            CellValue::Instant(_) | CellValue::DateTime(_) => 4,
+            CellValue::Instant(_) | CellValue::DateTime(_) | CellValue::DateTimeTz(_) => 4,
             CellValue::Date(_) => 5,
             CellValue::Time(_) => 6,
             CellValue::Duration(_) => 7,
@@ -521,6 +590,8 @@ impl CellValue {
             CellValue::Code(_) => 10,
             CellValue::Image(_) => 11,
             CellValue::Import(_) => 12,
+            CellValue::Hyperlink(_) => 13,
+            CellValue::RichText(_) => 14,
         }
     }
 
@@ -557,9 +628,26 @@ impl CellValue {
                 let b = crate::util::case_fold(b);
                 a.cmp(&b)
             }
+            (CellValue::Hyperlink(a), CellValue::Hyperlink(b)) => {
+                let a = crate::util::case_fold(a.display());
+                let b = crate::util::case_fold(b.display());
+                a.cmp(&b)
+            }
+            (CellValue::RichText(a), CellValue::RichText(b)) => {
+                let a = crate::util::case_fold(&a.to_plain());
+                let b = crate::util::case_fold(&b.to_plain());
+                a.cmp(&b)
+            }
             (CellValue::Number(a), CellValue::Number(b)) => a.cmp(b),
             (CellValue::Logical(a), CellValue::Logical(b)) => a.cmp(b),
             (CellValue::DateTime(a), CellValue::DateTime(b)) => a.cmp(b),
+            // date times with an offset are compared by their local time, as
+            // they are with date times without one, so the order is consistent
+            (CellValue::DateTimeTz(a), CellValue::DateTimeTz(b)) => {
+                a.naive_local().cmp(&b.naive_local())
+            }
+            (CellValue::DateTimeTz(a), CellValue::DateTime(b)) => a.naive_local().cmp(b),
+            (CellValue::DateTime(a), CellValue::DateTimeTz(b)) => a.cmp(&b.naive_local()),
             (CellValue::Date(a), CellValue::Date(b)) => a.cmp(b),
             (CellValue::Time(a), CellValue::Time(b)) => a.cmp(b),
             (CellValue::Duration(a), CellValue::Duration(b)) => a.cmp(b),
@@ -683,6 +771,8 @@ impl CellValue {
                 ..Default::default()
             };
             CellValue::Number(percent)
+        } else if let Some(date_time_tz) = CellValue::unpack_date_time_tz(value) {
+            date_time_tz
         } else if let Some(time) = CellValue::unpack_time(value) {
             time
         } else if let Some(date) = CellValue::unpack_date(value) {
@@ -707,6 +797,53 @@ impl CellValue {
         (cell_value, format_update)
     }
 
+    /// Convert string to a cell_value using the locale's date order, number
+    /// separators, and formula separators.
+    pub fn string_to_cell_value_with_locale(
+        value: &str,
+        allow_code: bool,
+        locale: &Locale,
+    ) -> (CellValue, FormatUpdate) {
+        if let Some(number) = locale.delocalize_number(value) {
+            let (cell_value, mut format_update) =
+                CellValue::string_to_cell_value(&number.value, allow_code);
+            if let Some(currency) = number.currency
+                && matches!(cell_value, CellValue::Number(_))
+            {
+                format_update.numeric_format = Some(Some(NumericFormat {
+                    kind: NumericFormatKind::Currency,
+                    symbol: Some(currency),
+                }));
+            }
+            return (cell_value, format_update);
+        }
+
+        if locale.date_order != DateOrder::Mdy {
+            if let Some(date) = CellValue::unpack_date_in_order(value, locale.date_order) {
+                return (date, FormatUpdate::default());
+            }
+            if let Some(date_time) = CellValue::unpack_date_time_in_order(value, locale.date_order)
+            {
+                return (date_time, FormatUpdate::default());
+            }
+        }
+
+        let (cell_value, format_update) = CellValue::string_to_cell_value(value, allow_code);
+        match cell_value {
+            // a number in this locale would have been converted above
+            CellValue::Number(_)
+                if !locale.has_default_number_separators() && value.contains(['.', ',']) =>
+            {
+                (CellValue::Text(value.into()), FormatUpdate::default())
+            }
+            CellValue::Code(mut code) => {
+                code.code = locale.delocalize_formula(&code.code);
+                (CellValue::Code(code), format_update)
+            }
+            cell_value => (cell_value, format_update),
+        }
+    }
+
     pub fn is_html(&self) -> bool {
         matches!(self, CellValue::Html(_))
     }
@@ -739,6 +876,12 @@ impl CellValue {
         match self {
             CellValue::Blank => CellValueHash::Blank,
             CellValue::Text(s) => CellValueHash::Text(crate::util::case_fold(s)),
+            CellValue::Hyperlink(link) => {
+                CellValueHash::Text(crate::util::case_fold(link.display()))
+            }
+            CellValue::RichText(rich_text) => {
+                CellValueHash::Text(crate::util::case_fold(&rich_text.to_plain()))
+            }
             CellValue::Number(n) => CellValueHash::Number(n.clone()),
             CellValue::Logical(b) => CellValueHash::Logical(*b),
             CellValue::Instant(Instant { seconds }) => {
@@ -765,7 +908,10 @@ impl CellValue {
     fn has_date_or_time(&self) -> bool {
         matches!(
             self,
-            CellValue::Date(_) | CellValue::Time(_) | CellValue::DateTime(_),
+            CellValue::Date(_)
+                | CellValue::Time(_)
+                | CellValue::DateTime(_)
+                | CellValue::DateTimeTz(_),
         )
     }
 
@@ -1295,6 +1441,27 @@ mod test {
         );
     }
 
+    #[test]
+    fn to_number_display_custom() {
+        let custom = |code: &str| {
+            Some(NumericFormat {
+                kind: NumericFormatKind::Custom,
+                symbol: Some(code.to_string()),
+            })
+        };
+        let cv = CellValue::Number(BigDecimal::from_str("-1234.5").unwrap());
+        assert_eq!(
+            cv.to_number_display(custom("#,##0.00;(#,##0.00)"), None, None),
+            String::from("(1,234.50)")
+        );
+
+        // decimals and commas only apply to the other kinds
+        assert_eq!(
+            cv.to_number_display(custom("0"), Some(3), Some(true)),
+            String::from("-1235")
+        );
+    }
+
     #[test]
     fn test_unpack_percentage() {
         let value = String::from("1238.12232%");
@@ -1401,6 +1568,57 @@ mod test {
                 .unwrap(),
         );
         assert_eq!(value.to_get_cells(), "2024-08-15T10:53:48.750");
+
+        let value = CellValue::DateTimeTz(
+            DateTime::parse_from_rfc3339("2024-08-15T10:53:48.750-04:00").unwrap(),
+        );
+        assert_eq!(value.to_get_cells(), "2024-08-15T10:53:48.750-04:00");
+    }
+
+    #[test]
+    fn test_date_time_tz() {
+        let expected = DateTime::parse_from_rfc3339("2024-03-10T14:30:00-05:00").unwrap();
+        for s in [
+            "2024-03-10T14:30:00-05:00",
+            "2024-03-10 14:30:00 -05:00",
+            "2024-03-10 14:30 -0500",
+            "03/10/2024 2:30 PM -05:00",
+        ] {
+            assert_eq!(
+                CellValue::string_to_cell_value(s, false).0,
+                CellValue::DateTimeTz(expected),
+                "{s}"
+            );
+        }
+
+        // without an offset, the value is a date time
+        assert!(matches!(
+            CellValue::string_to_cell_value("2024-03-10 14:30:00", false).0,
+            CellValue::DateTime(_)
+        ));
+
+        let value = CellValue::DateTimeTz(expected);
+        assert_eq!(value.to_display(), "03/10/2024 2:30 PM -05:00");
+        assert_eq!(value.to_edit(), "03/10/2024 2:30 PM -05:00");
+        assert_eq!(
+            CellValue::string_to_cell_value(&value.to_edit(), false).0,
+            value
+        );
+
+        // values compare by local time, with or without an offset
+        let utc = CellValue::DateTimeTz(
+            DateTime::parse_from_rfc3339("2024-03-10T19:30:00+00:00").unwrap(),
+        );
+        let naive = CellValue::DateTime(
+            NaiveDateTime::parse_from_str("2024-03-10 15:00:00", "%Y-%m-%d %H:%M:%S").unwrap(),
+        );
+        assert_eq!(value.total_cmp(&naive), std::cmp::Ordering::Less);
+        assert_eq!(naive.total_cmp(&utc), std::cmp::Ordering::Less);
+        assert_eq!(value.total_cmp(&utc), std::cmp::Ordering::Less);
+
+        let mut values = vec![utc.clone(), naive.clone(), value.clone()];
+        values.sort_by(CellValue::total_cmp);
+        assert_eq!(values, vec![value, naive, utc]);
     }
 
     #[test]
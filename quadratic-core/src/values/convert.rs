@@ -134,11 +134,14 @@ impl<'a> TryFrom<&'a CellValue> for String {
             CellValue::Date(d) => Ok(d.to_string()),
             CellValue::Time(t) => Ok(t.to_string()),
             CellValue::DateTime(dt) => Ok(dt.to_string()),
+            CellValue::DateTimeTz(dt) => Ok(dt.to_string()),
             CellValue::Error(e) => Err(e.msg.clone()),
             CellValue::Html(s) => Ok(s.clone()),
             CellValue::Code(_) => Ok(String::new()),
             CellValue::Image(_) => Ok(String::new()),
             CellValue::Import(_) => Ok(String::new()),
+            CellValue::Hyperlink(link) => Ok(link.display().to_string()),
+            CellValue::RichText(rich_text) => Ok(rich_text.to_plain()),
         }
     }
 }
@@ -173,7 +176,7 @@ impl<'a> TryFrom<&'a CellValue> for BigDecimal {
                 expected: "number".into(),
                 got: Some(value.type_name().into()),
             }),
-            CellValue::DateTime(_) => Err(RunErrorMsg::Expected {
+            CellValue::DateTime(_) | CellValue::DateTimeTz(_) => Err(RunErrorMsg::Expected {
                 expected: "number".into(),
                 got: Some(value.type_name().into()),
             }),
@@ -182,6 +185,17 @@ impl<'a> TryFrom<&'a CellValue> for BigDecimal {
             CellValue::Code(_) => Ok(BigDecimal::zero()),
             CellValue::Image(_) => Ok(BigDecimal::zero()),
             CellValue::Import(_) => Ok(BigDecimal::zero()),
+            CellValue::Hyperlink(link) => match &link.value {
+                Some(value) => BigDecimal::try_from(value.as_ref()),
+                None => Err(RunErrorMsg::Expected {
+                    expected: "number".into(),
+                    got: Some(value.type_name().into()),
+                }),
+            },
+            CellValue::RichText(_) => Err(RunErrorMsg::Expected {
+                expected: "number".into(),
+                got: Some(value.type_name().into()),
+            }),
         }
     }
 }
@@ -236,6 +250,7 @@ impl<'a> TryFrom<&'a CellValue> for NaiveDateTime {
     fn try_from(value: &'a CellValue) -> Result<Self, Self::Error> {
         match value {
             CellValue::DateTime(naive_date_time) => Ok(*naive_date_time),
+            CellValue::DateTimeTz(date_time) => Ok(date_time.naive_local()),
             CellValue::Date(naive_date) => Ok((*naive_date).into()),
             _ => Err(RunErrorMsg::Expected {
                 expected: "date time".into(),
@@ -257,6 +272,7 @@ impl<'a> TryFrom<&'a CellValue> for NaiveTime {
     fn try_from(value: &'a CellValue) -> Result<Self, Self::Error> {
         match value {
             CellValue::DateTime(naive_date_time) => Ok(naive_date_time.time()),
+            CellValue::DateTimeTz(date_time) => Ok(date_time.time()),
             CellValue::Date(_) => Ok(NaiveTime::MIN),
             CellValue::Time(naive_time) => Ok(*naive_time),
             _ => Err(RunErrorMsg::Expected {
@@ -303,6 +319,7 @@ macro_rules! impl_try_from_cell_value_for {
         }
     };
 }
+impl_try_from_cell_value_for!(BigDecimal);
 impl_try_from_cell_value_for!(f64);
 impl_try_from_cell_value_for!(i64);
 impl_try_from_cell_value_for!(bool);
@@ -1,7 +1,9 @@
 use chrono::Utc;
 use dateparser::parse_with_timezone;
 
-use crate::date_time::{parse_date, parse_time};
+use crate::date_time::{
+    DateOrder, parse_date, parse_date_in_order, parse_date_time_tz, parse_time,
+};
 
 use super::CellValue;
 
@@ -22,6 +24,31 @@ impl CellValue {
             .ok()
     }
 
+    /// Parses a date and time that includes a UTC offset, keeping the offset.
+    pub fn unpack_date_time_tz(value: &str) -> Option<CellValue> {
+        parse_date_time_tz(value).map(CellValue::DateTimeTz)
+    }
+
+    /// Parses a date, reading ambiguous numeric dates in the given order.
+    pub fn unpack_date_in_order(value: &str, order: DateOrder) -> Option<CellValue> {
+        let date = parse_date_in_order(value, order)?;
+        Some(CellValue::Date(date))
+    }
+
+    /// Parses a date followed by a time (eg, `31/12/2024 14:30`), reading
+    /// ambiguous numeric dates in the given order.
+    pub fn unpack_date_time_in_order(value: &str, order: DateOrder) -> Option<CellValue> {
+        let value = value.trim();
+        value
+            .char_indices()
+            .filter(|(_, c)| *c == ' ' || *c == 'T')
+            .find_map(|(i, c)| {
+                let date = parse_date_in_order(&value[..i], order)?;
+                let time = parse_time(value[i + c.len_utf8()..].trim())?;
+                Some(CellValue::DateTime(date.and_time(time)))
+            })
+    }
+
     pub fn unpack_duration(value: &str) -> Option<CellValue> {
         value.parse().map(CellValue::Duration).ok()
     }
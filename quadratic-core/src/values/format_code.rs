@@ -0,0 +1,1278 @@
+//! Excel number format codes (eg, `#,##0.00;[Red](#,##0.00);"-"`), used to
+//! display cells with a custom numeric format, by TEXT(), and when importing
+//! Excel files.
+//!
+//! A code has up to four sections separated by `;`: positive numbers,
+//! negative numbers, zero and text. A section may have a color (`[Red]`) and
+//! a condition (`[>=100]`); conditions replace the positive/negative/zero
+//! choice. Sections with date and time parts (`yyyy-mm-dd`, `[h]:mm:ss`)
+//! format numbers as Excel date serials.
+
+use bigdecimal::{BigDecimal, FromPrimitive, RoundingMode, Signed, ToPrimitive, Zero};
+use chrono::{Datelike, NaiveDateTime, NaiveTime};
+
+use crate::{CellValue, date_time::DateSystem};
+
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// The serial after December 31, 9999, the last date Excel can format.
+const MAX_DATE_SERIAL: f64 = 2_958_466.0;
+
+/// The result of formatting a value with a format code.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct FormattedValue {
+    pub text: String,
+
+    /// The section's color (eg, `[Red]`) as a hex string.
+    pub color: Option<String>,
+
+    /// A character repeated to fill the cell (`*` in the format code, eg, in
+    /// accounting formats).
+    pub fill: Option<FormatFill>,
+}
+
+/// A character that is repeated to fill the cell's width. It's inserted at
+/// the byte `index` of the formatted text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FormatFill {
+    pub index: usize,
+    pub char: char,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Placeholder {
+    /// `0` shows insignificant zeros.
+    Zero,
+
+    /// `#` shows only significant digits.
+    Hash,
+
+    /// `?` shows a space for insignificant zeros (to align decimals).
+    Question,
+}
+
+impl Placeholder {
+    /// The text shown when there is no digit for the placeholder.
+    fn empty(self) -> &'static str {
+        match self {
+            Placeholder::Zero => "0",
+            Placeholder::Hash => "",
+            Placeholder::Question => " ",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum DatePart {
+    Year(usize),
+    Month(usize),
+    Day(usize),
+    Hour(usize),
+    Minute(usize),
+    Second(usize),
+    SubSecond(usize),
+    AmPm(String, String),
+    ElapsedHours(usize),
+    ElapsedMinutes(usize),
+    ElapsedSeconds(usize),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Literal(String),
+    Fill(char),
+    Digit(Placeholder),
+    DecimalPoint,
+    Percent,
+
+    /// `E+` (true) or `E-` (false).
+    Exponent(bool),
+
+    Slash,
+    Denominator(u32),
+
+    /// `@`
+    Text,
+
+    General,
+
+    /// A date or time part, and its text in the code.
+    Date(DatePart, String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Condition {
+    Equal(f64),
+    NotEqual(f64),
+    GreaterThan(f64),
+    GreaterThanOrEqual(f64),
+    LessThan(f64),
+    LessThanOrEqual(f64),
+}
+
+impl Condition {
+    fn parse(condition: &str) -> Option<Self> {
+        let (operator, value) = condition
+            .find(|c: char| !matches!(c, '<' | '>' | '='))
+            .map(|i| condition.split_at(i))?;
+        let value = value.trim().parse::<f64>().ok()?;
+        match operator {
+            "=" => Some(Condition::Equal(value)),
+            "<>" => Some(Condition::NotEqual(value)),
+            ">" => Some(Condition::GreaterThan(value)),
+            ">=" => Some(Condition::GreaterThanOrEqual(value)),
+            "<" => Some(Condition::LessThan(value)),
+            "<=" => Some(Condition::LessThanOrEqual(value)),
+            _ => None,
+        }
+    }
+
+    fn matches(self, n: f64) -> bool {
+        match self {
+            Condition::Equal(value) => n == value,
+            Condition::NotEqual(value) => n != value,
+            Condition::GreaterThan(value) => n > value,
+            Condition::GreaterThanOrEqual(value) => n >= value,
+            Condition::LessThan(value) => n < value,
+            Condition::LessThanOrEqual(value) => n <= value,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+struct Section {
+    tokens: Vec<Token>,
+    color: Option<String>,
+    condition: Option<Condition>,
+
+    /// Whether thousands are separated with commas (`#,##0`).
+    grouping: bool,
+
+    /// The number of times the number is divided by 1,000 (`0.0,,"M"`).
+    scale: u32,
+}
+
+/// The text being formatted.
+#[derive(Default)]
+struct Output {
+    text: String,
+    fill: Option<FormatFill>,
+}
+
+impl Output {
+    fn push_str(&mut self, s: &str) {
+        self.text.push_str(s);
+    }
+
+    fn push_fill(&mut self, c: char) {
+        if self.fill.is_none() {
+            self.fill = Some(FormatFill {
+                index: self.text.len(),
+                char: c,
+            });
+        }
+    }
+
+    fn finish(self, color: Option<String>) -> FormattedValue {
+        FormattedValue {
+            text: self.text,
+            color,
+            fill: self.fill,
+        }
+    }
+}
+
+/// A parsed Excel number format code.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FormatCode {
+    sections: Vec<Section>,
+}
+
+impl FormatCode {
+    /// Parses a format code. An empty code is the same as `General`.
+    pub fn parse(code: &str) -> Result<Self, String> {
+        if code.trim().is_empty() {
+            return Self::parse("General");
+        }
+        let sections = split_sections(code)?;
+        if sections.len() > 4 {
+            return Err("A format code can have at most four sections".to_string());
+        }
+        let sections = sections
+            .iter()
+            .map(|section| parse_section(section))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { sections })
+    }
+
+    /// Returns true if the code formats dates or times.
+    pub fn is_date_time(&self) -> bool {
+        self.sections.iter().any(|section| section.is_date_time())
+    }
+
+    /// Formats a value. Returns None if the value cannot be formatted (eg,
+    /// a negative date or a number that matches no condition).
+    pub fn format(&self, value: &CellValue) -> Option<FormattedValue> {
+        self.format_with_date_system(value, DateSystem::default())
+    }
+
+    /// Formats a value, reading numbers as serial dates in `date_system`.
+    pub fn format_with_date_system(
+        &self,
+        value: &CellValue,
+        date_system: DateSystem,
+    ) -> Option<FormattedValue> {
+        match value {
+            CellValue::Blank => self.format_number(&BigDecimal::zero(), date_system),
+            CellValue::Number(n) => self.format_number(n, date_system),
+            CellValue::Text(text) => Some(self.format_text(text)),
+            CellValue::Logical(logical) => Some(FormattedValue {
+                text: if *logical { "TRUE" } else { "FALSE" }.to_string(),
+                ..Default::default()
+            }),
+            CellValue::Date(_)
+            | CellValue::DateTime(_)
+            | CellValue::DateTimeTz(_)
+            | CellValue::Time(_)
+            | CellValue::Duration(_) => {
+                let serial = date_serial(value, date_system)?;
+                self.format_number(&BigDecimal::from_f64(serial)?, date_system)
+            }
+            _ => None,
+        }
+    }
+
+    /// Chooses the section for a number. Returns the section and whether it
+    /// shows the number's sign.
+    fn number_section(&self, n: f64) -> Option<(&Section, bool)> {
+        let sections = &self.sections[..self.sections.len().min(3)];
+        if sections.iter().any(|section| section.condition.is_some()) {
+            return sections
+                .iter()
+                .find(|section| section.condition.is_none_or(|c| c.matches(n)))
+                .map(|section| (section, true));
+        }
+        match sections.len() {
+            1 => Some((&sections[0], true)),
+            2 if n < 0.0 => Some((&sections[1], false)),
+            2 => Some((&sections[0], true)),
+            _ if n > 0.0 => Some((&sections[0], true)),
+            _ if n < 0.0 => Some((&sections[1], false)),
+            _ => Some((&sections[2], true)),
+        }
+    }
+
+    fn format_number(&self, n: &BigDecimal, date_system: DateSystem) -> Option<FormattedValue> {
+        let f = n.to_f64()?;
+        let (section, signed) = self.number_section(f)?;
+        let color = section.color.clone();
+        if section.is_date_time() {
+            return format_date_time(section, f, date_system).map(|output| output.finish(color));
+        }
+
+        let mut output = Output::default();
+        if signed && n.is_negative() {
+            output.push_str("-");
+        }
+        if section.is_text() && !section.has_digits() {
+            output.push_str(&n.abs().to_string());
+        } else {
+            format_number(section, &n.abs(), &mut output);
+        }
+        Some(output.finish(color))
+    }
+
+    /// Formats text with the text section (the fourth section, or a single
+    /// section with `@`). Text is shown as is if there's no text section.
+    fn format_text(&self, text: &str) -> FormattedValue {
+        let section = match self.sections.as_slice() {
+            [_, _, _, section] => Some(section),
+            [section] if section.is_text() => Some(section),
+            _ => None,
+        };
+        let Some(section) = section else {
+            return FormattedValue {
+                text: text.to_string(),
+                ..Default::default()
+            };
+        };
+
+        let mut output = Output::default();
+        for token in section.tokens.iter() {
+            match token {
+                Token::Literal(literal) => output.push_str(literal),
+                Token::Fill(c) => output.push_fill(*c),
+                Token::Text => output.push_str(text),
+                _ => {}
+            }
+        }
+        output.finish(section.color.clone())
+    }
+}
+
+impl Section {
+    fn is_date_time(&self) -> bool {
+        self.tokens
+            .iter()
+            .any(|token| matches!(token, Token::Date(..)))
+    }
+
+    fn is_text(&self) -> bool {
+        self.tokens.contains(&Token::Text)
+    }
+
+    fn has_digits(&self) -> bool {
+        self.tokens
+            .iter()
+            .any(|token| matches!(token, Token::Digit(_) | Token::General))
+    }
+}
+
+/// Splits a format code into sections, ignoring `;` in quotes, brackets and
+/// escaped characters.
+fn split_sections(code: &str) -> Result<Vec<String>, String> {
+    let mut sections = vec![];
+    let mut section = String::new();
+    let mut chars = code.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            ';' => {
+                sections.push(std::mem::take(&mut section));
+                continue;
+            }
+            '"' => {
+                section.push(c);
+                loop {
+                    let c = chars.next().ok_or("Missing closing quote")?;
+                    section.push(c);
+                    if c == '"' {
+                        break;
+                    }
+                }
+                continue;
+            }
+            '[' => {
+                section.push(c);
+                loop {
+                    let c = chars.next().ok_or("Missing closing bracket")?;
+                    section.push(c);
+                    if c == ']' {
+                        break;
+                    }
+                }
+                continue;
+            }
+            '\\' | '_' | '*' => {
+                section.push(c);
+                if let Some(next) = chars.next() {
+                    section.push(next);
+                }
+                continue;
+            }
+            _ => section.push(c),
+        }
+    }
+    sections.push(section);
+    Ok(sections)
+}
+
+fn color(name: &str) -> Option<&'static str> {
+    match name.to_ascii_lowercase().as_str() {
+        "black" => Some("#000000"),
+        "blue" => Some("#0000ff"),
+        "cyan" => Some("#00ffff"),
+        "green" => Some("#00ff00"),
+        "magenta" => Some("#ff00ff"),
+        "red" => Some("#ff0000"),
+        "white" => Some("#ffffff"),
+        "yellow" => Some("#ffff00"),
+        _ => None,
+    }
+}
+
+/// Parses the contents of a `[...]` in a section: a color, a condition, a
+/// currency (`[$€-407]`) or an elapsed time. Anything else is ignored.
+fn parse_bracket(section: &mut Section, contents: &str) -> Result<(), String> {
+    if let Some(color) = color(contents) {
+        section.color = Some(color.to_string());
+    } else if let Some(currency) = contents.strip_prefix('$') {
+        let symbol = currency.split('-').next().unwrap_or_default();
+        if !symbol.is_empty() {
+            section.tokens.push(Token::Literal(symbol.to_string()));
+        }
+    } else if contents.starts_with(['<', '>', '=']) {
+        let condition =
+            Condition::parse(contents).ok_or_else(|| format!("Invalid condition [{contents}]"))?;
+        section.condition = Some(condition);
+    } else if let Some(first) = contents.chars().next()
+        && contents.chars().all(|c| c.eq_ignore_ascii_case(&first))
+    {
+        let len = contents.chars().count();
+        let part = match first.to_ascii_lowercase() {
+            'h' => DatePart::ElapsedHours(len),
+            'm' => DatePart::ElapsedMinutes(len),
+            's' => DatePart::ElapsedSeconds(len),
+            _ => return Ok(()),
+        };
+        section
+            .tokens
+            .push(Token::Date(part, format!("[{contents}]")));
+    }
+    Ok(())
+}
+
+fn parse_section(code: &str) -> Result<Section, String> {
+    let chars = code.chars().collect::<Vec<_>>();
+    let starts_with = |i: usize, s: &str| {
+        let len = s.chars().count();
+        i + len <= chars.len()
+            && chars[i..i + len]
+                .iter()
+                .zip(s.chars())
+                .all(|(a, b)| a.eq_ignore_ascii_case(&b))
+    };
+
+    let mut section = Section::default();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let token = match c {
+            '"' => {
+                let end = chars[i + 1..]
+                    .iter()
+                    .position(|&c| c == '"')
+                    .ok_or("Missing closing quote")?
+                    + i
+                    + 1;
+                section
+                    .tokens
+                    .push(Token::Literal(chars[i + 1..end].iter().collect()));
+                i = end + 1;
+                continue;
+            }
+            '[' => {
+                let end = chars[i + 1..]
+                    .iter()
+                    .position(|&c| c == ']')
+                    .ok_or("Missing closing bracket")?
+                    + i
+                    + 1;
+                let contents = chars[i + 1..end].iter().collect::<String>();
+                parse_bracket(&mut section, &contents)?;
+                i = end + 1;
+                continue;
+            }
+            '\\' => {
+                if let Some(next) = chars.get(i + 1) {
+                    section.tokens.push(Token::Literal(next.to_string()));
+                }
+                i += 2;
+                continue;
+            }
+            '_' => {
+                // padding the width of the next character
+                section.tokens.push(Token::Literal(" ".to_string()));
+                i += 2;
+                continue;
+            }
+            '*' => {
+                if let Some(next) = chars.get(i + 1) {
+                    section.tokens.push(Token::Fill(*next));
+                }
+                i += 2;
+                continue;
+            }
+            '0' => Token::Digit(Placeholder::Zero),
+            '#' => Token::Digit(Placeholder::Hash),
+            '?' => Token::Digit(Placeholder::Question),
+            '.' => Token::DecimalPoint,
+            '%' => Token::Percent,
+            '@' => Token::Text,
+            ',' => {
+                let after_digit = chars[..i]
+                    .iter()
+                    .rev()
+                    .find(|&&c| c != ',')
+                    .is_some_and(|c| matches!(c, '0' | '#' | '?'));
+                let before_digit = matches!(chars.get(i + 1), Some('0' | '#' | '?'));
+                if after_digit && before_digit {
+                    section.grouping = true;
+                } else if after_digit {
+                    section.scale += 1;
+                } else {
+                    section.tokens.push(Token::Literal(",".to_string()));
+                }
+                i += 1;
+                continue;
+            }
+            'E' | 'e' if matches!(chars.get(i + 1), Some('+' | '-')) => {
+                section
+                    .tokens
+                    .push(Token::Exponent(chars.get(i + 1) == Some(&'+')));
+                i += 2;
+                continue;
+            }
+            '/' => {
+                section.tokens.push(Token::Slash);
+                let digits = chars[i + 1..]
+                    .iter()
+                    .take_while(|c| c.is_ascii_digit())
+                    .collect::<String>();
+                if !digits.starts_with('0')
+                    && let Ok(denominator) = digits.parse::<u32>()
+                {
+                    section.tokens.push(Token::Denominator(denominator));
+                    i += digits.len();
+                }
+                i += 1;
+                continue;
+            }
+            _ if starts_with(i, "general") => {
+                section.tokens.push(Token::General);
+                i += 7;
+                continue;
+            }
+            _ if starts_with(i, "am/pm") => {
+                let am = chars[i..i + 2].iter().collect::<String>();
+                let pm = chars[i + 3..i + 5].iter().collect::<String>();
+                let text = chars[i..i + 5].iter().collect();
+                section
+                    .tokens
+                    .push(Token::Date(DatePart::AmPm(am, pm), text));
+                i += 5;
+                continue;
+            }
+            _ if starts_with(i, "a/p") => {
+                let (am, pm) = (chars[i].to_string(), chars[i + 2].to_string());
+                let text = chars[i..i + 3].iter().collect();
+                section
+                    .tokens
+                    .push(Token::Date(DatePart::AmPm(am, pm), text));
+                i += 3;
+                continue;
+            }
+            'y' | 'Y' | 'm' | 'M' | 'd' | 'D' | 'h' | 'H' | 's' | 'S' => {
+                let len = chars[i..]
+                    .iter()
+                    .take_while(|other| other.eq_ignore_ascii_case(&c))
+                    .count();
+                let part = match c.to_ascii_lowercase() {
+                    'y' => DatePart::Year(len),
+                    'm' => DatePart::Month(len),
+                    'd' => DatePart::Day(len),
+                    'h' => DatePart::Hour(len),
+                    _ => DatePart::Second(len),
+                };
+                let text = chars[i..i + len].iter().collect();
+                section.tokens.push(Token::Date(part, text));
+                i += len;
+                continue;
+            }
+            _ => Token::Literal(c.to_string()),
+        };
+        section.tokens.push(token);
+        i += 1;
+    }
+
+    if section.is_date_time() {
+        resolve_date_time(&mut section);
+    }
+    Ok(section)
+}
+
+/// Resolves the tokens of a section with date or time parts: `m` after an
+/// hour or before a second is a minute, and `.0` after a second is a fraction
+/// of a second. Number tokens are shown as text. If the section has digits,
+/// it's a number section and the date parts are shown as text instead.
+fn resolve_date_time(section: &mut Section) {
+    let tokens = std::mem::take(&mut section.tokens);
+    let mut resolved: Vec<Token> = vec![];
+    let mut iter = tokens.into_iter().peekable();
+    while let Some(token) = iter.next() {
+        let after_second = matches!(
+            resolved.last(),
+            Some(Token::Date(
+                DatePart::Second(_) | DatePart::ElapsedSeconds(_),
+                _
+            ))
+        );
+        if token == Token::DecimalPoint
+            && after_second
+            && iter.peek() == Some(&Token::Digit(Placeholder::Zero))
+        {
+            let mut digits = 0;
+            while iter.next_if_eq(&Token::Digit(Placeholder::Zero)).is_some() {
+                digits += 1;
+            }
+            resolved.push(Token::Literal(".".to_string()));
+            resolved.push(Token::Date(DatePart::SubSecond(digits), String::new()));
+        } else {
+            resolved.push(token);
+        }
+    }
+
+    if resolved
+        .iter()
+        .any(|token| matches!(token, Token::Digit(_) | Token::General))
+    {
+        for token in resolved.iter_mut() {
+            if let Token::Date(_, text) = token {
+                *token = Token::Literal(std::mem::take(text));
+            }
+        }
+        section.tokens = resolved;
+        return;
+    }
+
+    let date_parts = resolved
+        .iter()
+        .enumerate()
+        .filter_map(|(i, token)| match token {
+            Token::Date(part, _) => Some((i, part.clone())),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    for (index, (i, part)) in date_parts.iter().enumerate() {
+        let DatePart::Month(len @ 1..=2) = part else {
+            continue;
+        };
+        let after_hour = index > 0
+            && matches!(
+                date_parts[index - 1].1,
+                DatePart::Hour(_) | DatePart::ElapsedHours(_)
+            );
+        let before_second = date_parts.get(index + 1).is_some_and(|(_, next)| {
+            matches!(next, DatePart::Second(_) | DatePart::ElapsedSeconds(_))
+        });
+        if after_hour || before_second {
+            let text = match &resolved[*i] {
+                Token::Date(_, text) => text.clone(),
+                _ => String::new(),
+            };
+            resolved[*i] = Token::Date(DatePart::Minute(*len), text);
+        }
+    }
+
+    section.tokens = resolved
+        .into_iter()
+        .map(|token| match token {
+            Token::DecimalPoint => Token::Literal(".".to_string()),
+            Token::Percent => Token::Literal("%".to_string()),
+            Token::Slash => Token::Literal("/".to_string()),
+            Token::Denominator(denominator) => Token::Literal(denominator.to_string()),
+            Token::Exponent(plus) => Token::Literal(if plus { "E+" } else { "E-" }.to_string()),
+            token => token,
+        })
+        .collect();
+}
+
+/// Returns the text of each placeholder when writing digits into them from
+/// the right. Extra digits go into the first placeholder. When grouping,
+/// thousands are separated with commas.
+fn digit_texts(placeholders: &[Placeholder], digits: &str, grouping: bool) -> Vec<String> {
+    let digits = digits.chars().collect::<Vec<_>>();
+    let (len, count) = (digits.len(), placeholders.len());
+    let separator = |text: &mut String, remaining: usize| {
+        if grouping && remaining > 0 && remaining.is_multiple_of(3) {
+            text.push(',');
+        }
+    };
+    placeholders
+        .iter()
+        .enumerate()
+        .map(|(k, placeholder)| {
+            let mut text = String::new();
+            if k == 0 && len > count {
+                for (index, digit) in digits[..=len - count].iter().enumerate() {
+                    text.push(*digit);
+                    separator(&mut text, len - 1 - index);
+                }
+            } else if k + len >= count {
+                let index = k + len - count;
+                text.push(digits[index]);
+                separator(&mut text, len - 1 - index);
+            } else {
+                text.push_str(placeholder.empty());
+                if *placeholder == Placeholder::Zero {
+                    separator(&mut text, count - 1 - k);
+                }
+            }
+            text
+        })
+        .collect()
+}
+
+/// Writes digits into placeholders (see `digit_texts`).
+fn push_digits(output: &mut Output, placeholders: &[Placeholder], digits: &str, grouping: bool) {
+    for text in digit_texts(placeholders, digits, grouping) {
+        output.push_str(&text);
+    }
+}
+
+/// Returns the placeholders of the digit tokens in a range of tokens.
+fn placeholders(tokens: &[Token]) -> Vec<Placeholder> {
+    tokens
+        .iter()
+        .filter_map(|token| match token {
+            Token::Digit(placeholder) => Some(*placeholder),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Finds the fraction closest to `n` (0 <= n < 1) with a denominator of at
+/// most `max_denominator`.
+fn closest_fraction(n: f64, max_denominator: u32) -> (u64, u64) {
+    let mut best = (0, 1);
+    let mut best_error = n;
+    for denominator in 1..=max_denominator.max(1) as u64 {
+        let numerator = (n * denominator as f64).round() as u64;
+        let error = (n - numerator as f64 / denominator as f64).abs();
+        if error < best_error {
+            best = (numerator, denominator);
+            best_error = error;
+        }
+    }
+    best
+}
+
+/// Formats a non-negative number with a number section.
+fn format_number(section: &Section, n: &BigDecimal, output: &mut Output) {
+    let tokens = &section.tokens;
+    let mut n = n.clone();
+    for _ in 0..tokens.iter().filter(|t| **t == Token::Percent).count() {
+        n *= BigDecimal::from(100);
+    }
+    for _ in 0..section.scale {
+        n = n / BigDecimal::from(1000);
+    }
+
+    let slash = tokens.iter().position(|t| *t == Token::Slash);
+    let exponent = tokens.iter().position(|t| matches!(t, Token::Exponent(_)));
+    let decimal_point = tokens
+        .iter()
+        .position(|t| *t == Token::DecimalPoint)
+        .filter(|&i| slash.is_none() && exponent.is_none_or(|e| i < e));
+
+    // the digit placeholders of each part of the number
+    let integer_end = decimal_point.or(exponent).unwrap_or(tokens.len());
+    let (integer_end, numerator_start) = match slash {
+        Some(slash) => {
+            let start = tokens[..slash]
+                .iter()
+                .rposition(|t| !matches!(t, Token::Digit(_)))
+                .map_or(0, |i| i + 1);
+            (start, start)
+        }
+        None => (integer_end, integer_end),
+    };
+    let integer = placeholders(&tokens[..integer_end]);
+    let decimals = match decimal_point {
+        Some(i) => placeholders(&tokens[i + 1..exponent.unwrap_or(tokens.len())]),
+        None => vec![],
+    };
+
+    // the digits of each part of the number
+    let mut integer_digits;
+    let mut decimal_digits = String::new();
+    let mut exponent_digits = String::new();
+    let mut exponent_negative = false;
+    let mut fraction = None;
+    if let Some(slash) = slash {
+        // the whole part stays a decimal so large numbers are not truncated
+        let mut whole = if integer.is_empty() {
+            BigDecimal::zero()
+        } else {
+            n.with_scale_round(0, RoundingMode::Down)
+        };
+        let f = (&n - &whole).to_f64().unwrap_or_default();
+        let (mut numerator, denominator) = match tokens.get(slash + 1) {
+            Some(Token::Denominator(denominator)) => (
+                (f * *denominator as f64).round() as u64,
+                *denominator as u64,
+            ),
+            _ => {
+                let digits = placeholders(&tokens[slash + 1..]).len().clamp(1, 4) as u32;
+                closest_fraction(f, 10u32.pow(digits) - 1)
+            }
+        };
+        if !integer.is_empty() && numerator == denominator {
+            whole += BigDecimal::from(1);
+            numerator = 0;
+        }
+        integer_digits = if whole.is_zero() && numerator != 0 {
+            String::new()
+        } else {
+            whole.to_string()
+        };
+        fraction = Some((numerator, denominator));
+    } else if exponent.is_some() {
+        let f = n.to_f64().unwrap_or_default();
+        let width = integer.len().max(1) as i32;
+        let mut e = if f == 0.0 {
+            0
+        } else {
+            (f.log10().floor() as i32).div_euclid(width) * width
+        };
+        let mut mantissa = f / 10f64.powi(e);
+        if format!("{:.*}", decimals.len(), mantissa)
+            .parse::<f64>()
+            .unwrap_or(0.0)
+            >= 10f64.powi(width)
+        {
+            e += width;
+            mantissa /= 10f64.powi(width);
+        }
+        let mantissa = format!("{:.*}", decimals.len(), mantissa);
+        let (integer_part, decimal_part) = mantissa.split_once('.').unwrap_or((&mantissa, ""));
+        integer_digits = integer_part.trim_start_matches('0').to_string();
+        decimal_digits = decimal_part.to_string();
+        exponent_negative = e < 0;
+        exponent_digits = e.abs().to_string();
+    } else {
+        let rounded = n.with_scale_round(decimals.len() as i64, RoundingMode::HalfUp);
+        let rounded = rounded.to_string();
+        let (integer_part, decimal_part) = rounded.split_once('.').unwrap_or((&rounded, ""));
+        integer_digits = integer_part.trim_start_matches('0').to_string();
+        decimal_digits = decimal_part.to_string();
+    }
+    if integer.is_empty() && decimal_point.is_none() && slash.is_none() {
+        // there is nowhere to show the integer
+        integer_digits.clear();
+    }
+
+    // insignificant trailing decimals are hidden
+    let mut shown_decimals = decimal_digits.chars().collect::<Vec<_>>();
+    for (digit, placeholder) in shown_decimals.iter_mut().zip(decimals.iter()).rev() {
+        if *digit != '0' || *placeholder == Placeholder::Zero {
+            break;
+        }
+        *digit = if *placeholder == Placeholder::Question {
+            ' '
+        } else {
+            '\0'
+        };
+    }
+
+    let integer_texts = digit_texts(&integer, &integer_digits, section.grouping);
+    let mut digit_index = 0;
+    let mut decimal_index = 0;
+    let mut exponent_placeholders = vec![];
+    let mut numerator_placeholders = vec![];
+    for (i, token) in tokens.iter().enumerate() {
+        match token {
+            Token::Literal(literal) => output.push_str(literal),
+            Token::Fill(c) => output.push_fill(*c),
+            Token::Digit(placeholder) => {
+                if i < integer_end {
+                    // the integer is written at its last placeholder
+                    output.push_str(&integer_texts[digit_index]);
+                    digit_index += 1;
+                } else if slash.is_some_and(|slash| i >= numerator_start && i < slash) {
+                    numerator_placeholders.push(*placeholder);
+                    if tokens.get(i + 1) == Some(&Token::Slash) {
+                        let numerator = fraction.map(|(n, _)| n).unwrap_or_default();
+                        if numerator == 0 && !integer.is_empty() {
+                            output.push_str(&" ".repeat(numerator_placeholders.len()));
+                        } else {
+                            push_digits(
+                                output,
+                                &numerator_placeholders,
+                                &numerator.to_string(),
+                                false,
+                            );
+                        }
+                    }
+                } else if let Some(slash) = slash.filter(|&slash| i > slash) {
+                    // denominators are left aligned
+                    let Some((numerator, denominator)) = fraction else {
+                        continue;
+                    };
+                    let denominator = denominator.to_string();
+                    let position = i - slash - 1;
+                    let hidden = numerator == 0 && !integer.is_empty();
+                    match denominator.chars().nth(position) {
+                        Some(_) if hidden => output.push_str(" "),
+                        Some(digit) => output.text.push(digit),
+                        None => output.push_str(if *placeholder == Placeholder::Hash {
+                            ""
+                        } else {
+                            " "
+                        }),
+                    }
+                    if tokens
+                        .get(i + 1)
+                        .is_none_or(|t| !matches!(t, Token::Digit(_)))
+                        && !hidden
+                        && denominator.len() > position + 1
+                    {
+                        output.push_str(&denominator[position + 1..]);
+                    }
+                } else if exponent.is_some_and(|e| i > e) {
+                    exponent_placeholders.push(*placeholder);
+                    if tokens
+                        .get(i + 1)
+                        .is_none_or(|t| !matches!(t, Token::Digit(_)))
+                    {
+                        push_digits(output, &exponent_placeholders, &exponent_digits, false);
+                    }
+                } else {
+                    if let Some(digit) = shown_decimals.get(decimal_index)
+                        && *digit != '\0'
+                    {
+                        output.text.push(*digit);
+                    }
+                    decimal_index += 1;
+                }
+            }
+            Token::DecimalPoint if Some(i) == decimal_point => {
+                if integer.is_empty() {
+                    output.push_str(&integer_digits);
+                }
+                output.push_str(".");
+            }
+            Token::DecimalPoint => output.push_str("."),
+            Token::Percent => output.push_str("%"),
+            Token::Exponent(plus) => {
+                output.push_str("E");
+                if exponent_negative {
+                    output.push_str("-");
+                } else if *plus {
+                    output.push_str("+");
+                }
+            }
+            Token::Slash => {
+                if fraction.is_some_and(|(n, _)| n == 0) && !integer.is_empty() {
+                    output.push_str(" ");
+                } else {
+                    output.push_str("/");
+                }
+            }
+            Token::Denominator(denominator) => {
+                if fraction.is_some_and(|(n, _)| n == 0) && !integer.is_empty() {
+                    output.push_str(&" ".repeat(denominator.to_string().len()));
+                } else {
+                    output.push_str(&denominator.to_string());
+                }
+            }
+            Token::General => output.push_str(&n.to_string()),
+            Token::Text | Token::Date(..) => {}
+        }
+    }
+}
+
+/// Returns the Excel date serial of a date, time or duration.
+pub fn date_serial(value: &CellValue, date_system: DateSystem) -> Option<f64> {
+    let seconds = match value {
+        CellValue::Date(date) => {
+            return Some(date_system.date_time_to_serial(date.and_time(NaiveTime::MIN)));
+        }
+        CellValue::DateTime(date_time) => {
+            return Some(date_system.date_time_to_serial(*date_time));
+        }
+        CellValue::DateTimeTz(date_time) => {
+            return Some(date_system.date_time_to_serial(date_time.naive_local()));
+        }
+        CellValue::Time(time) => (*time - NaiveTime::MIN).num_milliseconds() as f64 / 1000.0,
+        CellValue::Duration(duration) if duration.months == 0 => duration.seconds,
+        _ => return None,
+    };
+    Some(seconds / SECONDS_PER_DAY as f64)
+}
+
+/// Returns the date of an Excel date serial, or None if it is out of range.
+fn serial_date_time(total_seconds: i64, date_system: DateSystem) -> Option<NaiveDateTime> {
+    date_system.serial_to_date_time(total_seconds as f64 / SECONDS_PER_DAY as f64)
+}
+
+fn format_date_time(section: &Section, serial: f64, date_system: DateSystem) -> Option<Output> {
+    if !(0.0..MAX_DATE_SERIAL).contains(&serial) {
+        return None;
+    }
+    let parts = section
+        .tokens
+        .iter()
+        .filter_map(|token| match token {
+            Token::Date(part, _) => Some(part),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let sub_second_digits = parts
+        .iter()
+        .filter_map(|part| match part {
+            DatePart::SubSecond(digits) => Some((*digits).min(3)),
+            _ => None,
+        })
+        .max()
+        .unwrap_or(0);
+    let twelve_hour = parts.iter().any(|part| matches!(part, DatePart::AmPm(..)));
+
+    // rounded to the precision that is shown
+    let unit = 10i64.pow(sub_second_digits as u32);
+    let total = (serial * (SECONDS_PER_DAY * unit) as f64).round() as i64;
+    let (total_seconds, sub_second) = (total / unit, total % unit);
+    let date_time = serial_date_time(total_seconds, date_system)?;
+    let seconds_of_day = total_seconds % SECONDS_PER_DAY;
+    let hour = seconds_of_day / 3600;
+
+    let pad = |value: i64, len: usize| format!("{value:0len$}");
+    let mut output = Output::default();
+    for token in section.tokens.iter() {
+        match token {
+            Token::Literal(literal) => output.push_str(literal),
+            Token::Fill(c) => output.push_fill(*c),
+            Token::Date(part, _) => {
+                let text = match part {
+                    DatePart::Year(1..=2) => pad(date_time.year() as i64 % 100, 2),
+                    DatePart::Year(_) => date_time.year().to_string(),
+                    DatePart::Month(len) => {
+                        let month = date_time.month() as i64;
+                        let name = MONTHS[month as usize - 1];
+                        match len {
+                            1..=2 => pad(month, *len),
+                            3 => name[..3].to_string(),
+                            4 => name.to_string(),
+                            _ => name[..1].to_string(),
+                        }
+                    }
+                    DatePart::Day(len) => {
+                        let weekday = date_time.weekday().to_string();
+                        match len {
+                            1..=2 => pad(date_time.day() as i64, *len),
+                            3 => weekday,
+                            _ => date_time.format("%A").to_string(),
+                        }
+                    }
+                    DatePart::Hour(len) => {
+                        let hour = if twelve_hour {
+                            (hour + 11) % 12 + 1
+                        } else {
+                            hour
+                        };
+                        pad(hour, (*len).min(2))
+                    }
+                    DatePart::Minute(len) => pad(seconds_of_day % 3600 / 60, (*len).min(2)),
+                    DatePart::Second(len) => pad(seconds_of_day % 60, (*len).min(2)),
+                    DatePart::SubSecond(digits) => {
+                        pad(sub_second, sub_second_digits)[..(*digits).min(3)].to_string()
+                    }
+                    DatePart::AmPm(am, pm) => if hour < 12 { am } else { pm }.clone(),
+                    DatePart::ElapsedHours(len) => pad(total_seconds / 3600, *len),
+                    DatePart::ElapsedMinutes(len) => pad(total_seconds / 60, *len),
+                    DatePart::ElapsedSeconds(len) => pad(total_seconds, *len),
+                };
+                output.push_str(&text);
+            }
+            _ => {}
+        }
+    }
+    Some(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    fn format(code: &str, n: &str) -> String {
+        let value = CellValue::Number(BigDecimal::from_str(n).unwrap());
+        FormatCode::parse(code)
+            .unwrap()
+            .format(&value)
+            .unwrap()
+            .text
+    }
+
+    #[test]
+    fn test_numbers() {
+        assert_eq!(format("0.00", "1234.567"), "1234.57");
+        assert_eq!(format("#,##0.00", "1234567.891"), "1,234,567.89");
+        assert_eq!(format("#,##0", "-1234"), "-1,234");
+        assert_eq!(format("#.##", "0.5"), ".5");
+        assert_eq!(format("0.0#", "2.5"), "2.5");
+        assert_eq!(format("0.0?", "2.5"), "2.5 ");
+        assert_eq!(format("000-0000", "5551234"), "555-1234");
+        assert_eq!(format("0", "2.5"), "3");
+        assert_eq!(format("0%", "0.256"), "26%");
+        assert_eq!(format("0.0,,\"M\"", "12345678"), "12.3M");
+        assert_eq!(format("#,##0,\"K\"", "1234567"), "1,235K");
+        assert_eq!(format("0.00E+00", "12345"), "1.23E+04");
+        assert_eq!(format("0.00E+00", "0.00012"), "1.20E-04");
+        assert_eq!(format("##0.0E+0", "12345"), "12.3E+3");
+        assert_eq!(format("\"Total: \"0", "5"), "Total: 5");
+        assert_eq!(format("0\\ \\k\\g", "5"), "5 kg");
+        assert_eq!(format("[$€-407] #,##0.00", "5"), "€ 5.00");
+        assert_eq!(format("General", "1.5"), "1.5");
+        assert_eq!(format("", "-1.5"), "-1.5");
+    }
+
+    #[test]
+    fn test_sections() {
+        let code = "#,##0.00;[Red](#,##0.00);\"-\"";
+        assert_eq!(format(code, "1234.5"), "1,234.50");
+        assert_eq!(format(code, "-1234.5"), "(1,234.50)");
+        assert_eq!(format(code, "0"), "-");
+
+        let code = FormatCode::parse(code).unwrap();
+        let negative = code.format(&CellValue::from(-1.0)).unwrap();
+        assert_eq!(negative.color, Some("#ff0000".to_string()));
+        assert_eq!(code.format(&CellValue::from(1.0)).unwrap().color, None);
+
+        let code = "[Blue][>=100]0;[Red][<0]0;0.0";
+        assert_eq!(format(code, "150"), "150");
+        assert_eq!(format(code, "-5"), "-5");
+        assert_eq!(format(code, "5"), "5.0");
+
+        // text
+        let code = FormatCode::parse("0;-0;0;\"Name: \"@").unwrap();
+        assert_eq!(
+            code.format(&CellValue::Text("Bob".into())).unwrap().text,
+            "Name: Bob"
+        );
+        let code = FormatCode::parse("0.00").unwrap();
+        assert_eq!(
+            code.format(&CellValue::Text("Bob".into())).unwrap().text,
+            "Bob"
+        );
+
+        assert!(FormatCode::parse("0;0;0;0;0").is_err());
+        assert!(FormatCode::parse("\"open").is_err());
+    }
+
+    #[test]
+    fn test_fractions() {
+        assert_eq!(format("# ?/?", "1.25"), "1 1/4");
+        assert_eq!(format("# ??/??", "3.14159"), "3 14/99");
+        assert_eq!(format("?/?", "1.5"), "3/2");
+        assert_eq!(format("# ?/8", "0.5"), " 4/8");
+        assert_eq!(format("# ??/100", "2.25"), "2 25/100");
+        assert_eq!(format("# ?/?", "2"), "2    ");
+        assert_eq!(format("# ?/?", "0.99"), "1    ");
+        assert_eq!(format("# ?/?", "1E25"), "10000000000000000000000000    ");
+    }
+
+    #[test]
+    fn test_accounting() {
+        let code = FormatCode::parse(
+            "_(\"$\"* #,##0.00_);_(\"$\"* \\(#,##0.00\\);_(\"$\"* \"-\"??_);_(@_)",
+        )
+        .unwrap();
+        let formatted = code.format(&CellValue::from(1234.5)).unwrap();
+        assert_eq!(formatted.text, " $1,234.50 ");
+        assert_eq!(
+            formatted.fill,
+            Some(FormatFill {
+                index: 2,
+                char: ' '
+            })
+        );
+        assert_eq!(
+            code.format(&CellValue::from(-5.0)).unwrap().text,
+            " $(5.00)"
+        );
+        assert_eq!(code.format(&CellValue::from(0.0)).unwrap().text, " $-   ");
+    }
+
+    #[test]
+    fn test_dates_and_times() {
+        let date_time = CellValue::DateTime(
+            NaiveDate::from_ymd_opt(2024, 3, 5)
+                .unwrap()
+                .and_hms_opt(14, 7, 9)
+                .unwrap(),
+        );
+        let format_value = |code: &str, value: &CellValue| {
+            FormatCode::parse(code).unwrap().format(value).unwrap().text
+        };
+        assert_eq!(format_value("yyyy-mm-dd", &date_time), "2024-03-05");
+        assert_eq!(
+            format_value("m/d/yy h:mm AM/PM", &date_time),
+            "3/5/24 2:07 PM"
+        );
+        assert_eq!(format_value("dddd, mmmm d", &date_time), "Tuesday, March 5");
+        assert_eq!(format_value("ddd mmm", &date_time), "Tue Mar");
+        assert_eq!(format_value("hh:mm:ss", &date_time), "14:07:09");
+        assert_eq!(format_value("mm:ss", &date_time), "07:09");
+
+        // numbers are date serials
+        assert_eq!(format("yyyy-mm-dd", "45356"), "2024-03-05");
+        assert_eq!(format("h:mm:ss.00", "0.5000001"), "12:00:00.01");
+
+        // elapsed time
+        assert_eq!(format("[h]:mm:ss", "1.5"), "36:00:00");
+        assert_eq!(format("[mm]:ss", "0.01"), "14:24");
+        assert_eq!(format("[s]", "0.01"), "864");
+
+        // date letters in a number section are text
+        assert_eq!(format("0 \"units\"", "5"), "5 units");
+        assert!(FormatCode::parse("yyyy").unwrap().is_date_time());
+        assert!(!FormatCode::parse("0.00").unwrap().is_date_time());
+        assert!(
+            FormatCode::parse("yyyy")
+                .unwrap()
+                .format(&CellValue::from(-1.0))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_out_of_range_date_serials() {
+        let code = FormatCode::parse("yyyy-mm-dd").unwrap();
+        for n in [1e12, 1e300, 2_958_466.0] {
+            assert!(code.format(&CellValue::from(n)).is_none());
+        }
+        assert!(
+            FormatCode::parse("[h]:mm")
+                .unwrap()
+                .format(&CellValue::from(1e18))
+                .is_none()
+        );
+        assert_eq!(format("yyyy-mm-dd", "2958465"), "9999-12-31");
+
+        // Excel's February 29, 1900 is skipped
+        assert_eq!(format("yyyy-mm-dd", "59"), "1900-02-28");
+        assert_eq!(format("yyyy-mm-dd", "61"), "1900-03-01");
+        assert_eq!(
+            date_serial(
+                &CellValue::Date(NaiveDate::from_ymd_opt(1900, 3, 1).unwrap()),
+                DateSystem::Excel1900
+            ),
+            Some(61.0)
+        );
+    }
+
+    #[test]
+    fn test_date_system() {
+        let code = FormatCode::parse("yyyy-mm-dd").unwrap();
+        let format_1904 = |value: &CellValue| {
+            code.format_with_date_system(value, DateSystem::Excel1904)
+                .unwrap()
+                .text
+        };
+        assert_eq!(format_1904(&CellValue::from(0)), "1904-01-01");
+        assert_eq!(format_1904(&CellValue::from(43894)), "2024-03-05");
+
+        // dates are shown as is in either system
+        let date = CellValue::Date(NaiveDate::from_ymd_opt(2024, 3, 5).unwrap());
+        assert_eq!(format_1904(&date), "2024-03-05");
+        assert_eq!(code.format(&date).unwrap().text, "2024-03-05");
+    }
+}
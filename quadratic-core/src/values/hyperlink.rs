@@ -0,0 +1,229 @@
+//! Hyperlink cell values.
+//!
+//! A hyperlink is either an external URL or an internal link to a sheet, cell
+//! or range. Internal links start with `#` followed by an A1 reference (eg,
+//! `#Sheet2!A1:B5`), the same as Excel's `HYPERLINK("#Sheet2!A1")`. External
+//! URLs must use http, https, or mailto.
+
+use std::fmt;
+
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use crate::{
+    CellValue,
+    a1::{A1Context, A1Selection},
+    grid::SheetId,
+};
+
+lazy_static! {
+    static ref URL: Regex =
+        Regex::new(r"(?i)^(?:https?://|mailto:|www\.)[^\s<>]+$").expect("regex should compile");
+}
+
+/// Schemes that external links may use.
+const ALLOWED_SCHEMES: [&str; 3] = ["http://", "https://", "mailto:"];
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, TS)]
+pub struct Hyperlink {
+    /// The external URL, or `#` followed by an A1 reference for internal
+    /// links.
+    pub url: String,
+
+    /// The display text. The url is displayed when this is empty.
+    pub text: String,
+
+    /// The value of the cell the link was attached to, when it is not text
+    /// (eg, a number with a link in an Excel file). `text` is its display
+    /// text.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(skip)]
+    pub value: Option<Box<CellValue>>,
+}
+
+impl fmt::Display for Hyperlink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.display())
+    }
+}
+
+impl Hyperlink {
+    /// Creates a hyperlink. Returns None if the url is not an internal link
+    /// and does not use an allowed scheme (eg, `javascript:` or `file:`).
+    pub fn new(url: impl Into<String>, text: impl Into<String>) -> Option<Self> {
+        let url = url.into();
+        Self::is_allowed_url(&url).then(|| Self {
+            url,
+            text: text.into(),
+            value: None,
+        })
+    }
+
+    /// Returns true if the url is an internal link or an external URL with
+    /// an allowed scheme.
+    pub fn is_allowed_url(url: &str) -> bool {
+        url.starts_with('#')
+            || ALLOWED_SCHEMES.iter().any(|scheme| {
+                url.get(..scheme.len())
+                    .is_some_and(|prefix| prefix.eq_ignore_ascii_case(scheme))
+            })
+    }
+
+    /// Returns this link attached to a cell's value. Text becomes the link's
+    /// text; other values are kept so they still calculate (eg, numbers).
+    pub fn attach_to(&self, value: CellValue) -> Self {
+        match value {
+            CellValue::Text(text) => Self {
+                text,
+                value: None,
+                ..self.clone()
+            },
+            value => Self {
+                text: value.to_display(),
+                value: Some(Box::new(value)),
+                ..self.clone()
+            },
+        }
+    }
+
+    /// Returns the text shown in the cell.
+    pub fn display(&self) -> &str {
+        if self.text.is_empty() {
+            &self.url
+        } else {
+            &self.text
+        }
+    }
+
+    /// Returns the A1 reference of an internal link.
+    pub fn internal_target(&self) -> Option<&str> {
+        self.url.strip_prefix('#')
+    }
+
+    /// Returns the selection of an internal link, or None if the link is
+    /// external or the reference is invalid.
+    pub fn to_selection(
+        &self,
+        default_sheet_id: SheetId,
+        a1_context: &A1Context,
+    ) -> Option<A1Selection> {
+        let target = self.internal_target()?;
+        A1Selection::parse_a1(target, default_sheet_id, a1_context).ok()
+    }
+
+    /// Returns a hyperlink if the text is a URL (eg, when pasting).
+    pub fn detect(text: &str) -> Option<Self> {
+        let text = text.trim();
+        if !URL.is_match(text) {
+            return None;
+        }
+        let url = if text[..4].eq_ignore_ascii_case("www.") {
+            format!("https://{text}")
+        } else {
+            text.to_string()
+        };
+        Self::new(url, text)
+    }
+
+    /// Returns the hyperlink as an html anchor.
+    pub fn to_html(&self) -> String {
+        format!(
+            "<a href=\"{}\">{}</a>",
+            htmlescape::encode_minimal(&self.url),
+            htmlescape::encode_minimal(self.display())
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let link = Hyperlink::new("https://quadratichq.com", "").unwrap();
+        assert_eq!(link.display(), "https://quadratichq.com");
+        assert_eq!(link.internal_target(), None);
+
+        let link = Hyperlink::new("#Sheet1!A1", "Go").unwrap();
+        assert_eq!(link.display(), "Go");
+        assert_eq!(link.internal_target(), Some("Sheet1!A1"));
+    }
+
+    #[test]
+    fn test_detect() {
+        assert_eq!(
+            Hyperlink::detect(" https://quadratichq.com/docs?a=1&b=2 "),
+            Hyperlink::new(
+                "https://quadratichq.com/docs?a=1&b=2",
+                "https://quadratichq.com/docs?a=1&b=2"
+            )
+        );
+        assert_eq!(
+            Hyperlink::detect("www.example.com"),
+            Hyperlink::new("https://www.example.com", "www.example.com")
+        );
+        assert!(Hyperlink::detect("mailto:jane@example.com").is_some());
+        assert_eq!(Hyperlink::detect("see https://example.com"), None);
+        assert_eq!(Hyperlink::detect("example.com"), None);
+        assert_eq!(Hyperlink::detect("http"), None);
+    }
+
+    #[test]
+    fn test_allowed_url() {
+        assert!(Hyperlink::new("HTTPS://example.com", "").is_some());
+        assert!(Hyperlink::new("http://example.com", "").is_some());
+        assert!(Hyperlink::new("mailto:jane@example.com", "").is_some());
+        assert!(Hyperlink::new("#Sheet1!A1", "").is_some());
+        assert!(Hyperlink::new("javascript:alert(1)", "").is_none());
+        assert!(Hyperlink::new(" javascript:alert(1)", "").is_none());
+        assert!(Hyperlink::new("file:///etc/passwd", "").is_none());
+        assert!(Hyperlink::new("data:text/html,<b>", "").is_none());
+        assert!(Hyperlink::new("", "").is_none());
+    }
+
+    #[test]
+    fn test_attach_to() {
+        let link = Hyperlink::new("https://example.com", "Example").unwrap();
+        let attached = link.attach_to(CellValue::Text("Docs".into()));
+        assert_eq!(attached.text, "Docs");
+        assert_eq!(attached.value, None);
+
+        let attached = link.attach_to(CellValue::Number(42.into()));
+        assert_eq!(attached.display(), "42");
+        assert_eq!(
+            attached.value.as_deref(),
+            Some(&CellValue::Number(42.into()))
+        );
+    }
+
+    #[test]
+    fn test_to_selection() {
+        let context = A1Context::test(&[("Sheet1", SheetId::TEST)], &[]);
+        let link = Hyperlink::new("#Sheet1!B2:C3", "").unwrap();
+        assert_eq!(
+            link.to_selection(SheetId::TEST, &context),
+            Some(A1Selection::test_a1("B2:C3"))
+        );
+        let link = Hyperlink::new("#D4", "").unwrap();
+        assert_eq!(
+            link.to_selection(SheetId::TEST, &context),
+            Some(A1Selection::test_a1("D4"))
+        );
+        let link = Hyperlink::new("#Missing!A1", "").unwrap();
+        assert_eq!(link.to_selection(SheetId::TEST, &context), None);
+        let link = Hyperlink::new("https://example.com", "").unwrap();
+        assert_eq!(link.to_selection(SheetId::TEST, &context), None);
+    }
+
+    #[test]
+    fn test_to_html() {
+        let link = Hyperlink::new("https://example.com/?a=1&b=\"2\"", "<b>").unwrap();
+        assert_eq!(
+            link.to_html(),
+            "<a href=\"https://example.com/?a=1&amp;b=&quot;2&quot;\">&lt;b&gt;</a>"
+        );
+    }
+}
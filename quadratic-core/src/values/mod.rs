@@ -11,16 +11,21 @@ pub mod cell_values;
 pub mod cellvalue;
 mod convert;
 pub mod date_time;
+pub mod format_code;
 mod from_js;
+pub mod hyperlink;
 mod isblank;
 pub mod parquet;
+pub mod rich_text;
 mod time;
 
 pub use array::Array;
 pub use array_size::{ArraySize, Axis};
 pub use cellvalue::{CellValue, CellValueHash};
 pub use convert::CoerceInto;
+pub use hyperlink::Hyperlink;
 pub use isblank::IsBlank;
+pub use rich_text::{RichText, TextSpan};
 pub use time::{Duration, Instant};
 
 use crate::{CodeResult, CodeResultExt, RunError, RunErrorMsg, SpannableIterExt, Spanned};
@@ -0,0 +1,326 @@
+//! Rich text cell values.
+//!
+//! Rich text is a list of spans, each with its own text style (eg, "**Total:**
+//! 42"). Styles that are not set on a span use the cell's format. Formulas,
+//! languages and exports see rich text as its plain text.
+
+use std::fmt;
+
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+lazy_static! {
+    static ref HTML_TOKEN: Regex =
+        Regex::new(r"<(/?)([a-zA-Z][\w-]*)([^>]*)>|([^<]+)").expect("regex should compile");
+    static ref HTML_ATTRIBUTE: Regex =
+        Regex::new(r#"([\w-]+)\s*=\s*(?:"([^"]*)"|'([^']*)')"#).expect("regex should compile");
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq, Hash, TS)]
+#[serde(rename_all = "camelCase")]
+pub struct TextSpan {
+    pub text: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bold: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub italic: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub underline: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub strike_through: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text_color: Option<String>,
+}
+
+impl TextSpan {
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Returns true if the span has no style of its own.
+    pub fn is_plain(&self) -> bool {
+        self.bold.is_none()
+            && self.italic.is_none()
+            && self.underline.is_none()
+            && self.strike_through.is_none()
+            && self.text_color.is_none()
+    }
+
+    fn same_style(&self, other: &TextSpan) -> bool {
+        self.bold == other.bold
+            && self.italic == other.italic
+            && self.underline == other.underline
+            && self.strike_through == other.strike_through
+            && self.text_color == other.text_color
+    }
+
+    /// Returns a span with the same style and new text.
+    fn with_text(&self, text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            ..self.clone()
+        }
+    }
+
+    fn to_css(&self) -> String {
+        let mut css = String::new();
+        match self.bold {
+            Some(true) => css.push_str("font-weight:bold;"),
+            Some(false) => css.push_str("font-weight:normal;"),
+            None => {}
+        }
+        match self.italic {
+            Some(true) => css.push_str("font-style:italic;"),
+            Some(false) => css.push_str("font-style:normal;"),
+            None => {}
+        }
+        let decorations = [
+            (self.underline == Some(true)).then_some("underline"),
+            (self.strike_through == Some(true)).then_some("line-through"),
+        ];
+        let decorations = decorations.into_iter().flatten().collect::<Vec<_>>();
+        if !decorations.is_empty() {
+            css.push_str(&format!("text-decoration:{};", decorations.join(" ")));
+        }
+        if let Some(color) = &self.text_color {
+            css.push_str(&format!("color:{color};"));
+        }
+        css
+    }
+
+    /// Applies an html element's style to the span.
+    fn apply_html(&mut self, tag: &str, attributes: &str) {
+        match tag {
+            "b" | "strong" => self.bold = Some(true),
+            "i" | "em" => self.italic = Some(true),
+            "u" | "ins" => self.underline = Some(true),
+            "s" | "strike" | "del" => self.strike_through = Some(true),
+            _ => {}
+        }
+        for captures in HTML_ATTRIBUTE.captures_iter(attributes) {
+            let value = captures
+                .get(2)
+                .or(captures.get(3))
+                .map_or("", |m| m.as_str());
+            match captures[1].to_ascii_lowercase().as_str() {
+                "color" if tag == "font" => self.text_color = Some(value.to_string()),
+                "style" => self.apply_css(value),
+                _ => {}
+            }
+        }
+    }
+
+    fn apply_css(&mut self, css: &str) {
+        for declaration in css.split(';') {
+            let Some((property, value)) = declaration.split_once(':') else {
+                continue;
+            };
+            let value = value.trim().to_ascii_lowercase();
+            match property.trim().to_ascii_lowercase().as_str() {
+                "font-weight" => {
+                    self.bold = Some(
+                        value == "bold"
+                            || value == "bolder"
+                            || value.parse::<u32>().is_ok_and(|weight| weight >= 600),
+                    );
+                }
+                "font-style" => self.italic = Some(value == "italic" || value == "oblique"),
+                "text-decoration" | "text-decoration-line" => {
+                    if value.contains("none") {
+                        self.underline = Some(false);
+                        self.strike_through = Some(false);
+                    }
+                    if value.contains("underline") {
+                        self.underline = Some(true);
+                    }
+                    if value.contains("line-through") {
+                        self.strike_through = Some(true);
+                    }
+                }
+                "color" => self.text_color = Some(value),
+                _ => {}
+            }
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq, Hash, TS)]
+pub struct RichText {
+    pub spans: Vec<TextSpan>,
+}
+
+impl fmt::Display for RichText {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for span in &self.spans {
+            write!(f, "{}", span.text)?;
+        }
+        Ok(())
+    }
+}
+
+impl RichText {
+    /// Creates rich text from spans. Empty spans are removed and neighboring
+    /// spans with the same style are merged.
+    pub fn new(spans: impl IntoIterator<Item = TextSpan>) -> Self {
+        let mut merged: Vec<TextSpan> = vec![];
+        for span in spans {
+            if span.text.is_empty() {
+                continue;
+            }
+            match merged.last_mut() {
+                Some(last) if last.same_style(&span) => last.text.push_str(&span.text),
+                _ => merged.push(span),
+            }
+        }
+        Self { spans: merged }
+    }
+
+    /// Returns the text without styles.
+    pub fn to_plain(&self) -> String {
+        self.to_string()
+    }
+
+    /// Returns true if no span has a style of its own.
+    pub fn is_plain(&self) -> bool {
+        self.spans.iter().all(TextSpan::is_plain)
+    }
+
+    /// Parses the inline html of a cell (eg, when pasting from another
+    /// spreadsheet). Block elements and unknown styles are ignored.
+    pub fn from_html(html: &str) -> Self {
+        // each open element with the style of its content
+        let mut stack: Vec<(String, TextSpan)> = vec![];
+        let mut spans = vec![];
+        for captures in HTML_TOKEN.captures_iter(html) {
+            let style = stack
+                .last()
+                .map_or_else(TextSpan::default, |(_, style)| style.clone());
+            if let Some(text) = captures.get(4) {
+                let text = htmlescape::decode_html(text.as_str())
+                    .unwrap_or_else(|_| text.as_str().to_string());
+                spans.push(style.with_text(text));
+                continue;
+            }
+            let tag = captures[2].to_ascii_lowercase();
+            let attributes = &captures[3];
+            if tag == "br" {
+                spans.push(style.with_text("\n"));
+            } else if &captures[1] == "/" {
+                if let Some(index) = stack.iter().rposition(|(open, _)| *open == tag) {
+                    stack.truncate(index);
+                }
+            } else if !attributes.trim_end().ends_with('/') {
+                let mut style = style;
+                style.apply_html(&tag, attributes);
+                stack.push((tag, style));
+            }
+        }
+        Self::new(spans)
+    }
+
+    /// Returns the rich text as inline html.
+    pub fn to_html(&self) -> String {
+        self.spans
+            .iter()
+            .map(|span| {
+                let text = htmlescape::encode_minimal(&span.text).replace('\n', "<br>");
+                if span.is_plain() {
+                    text
+                } else {
+                    format!("<span style=\"{}\">{text}</span>", span.to_css())
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bold(text: &str) -> TextSpan {
+        TextSpan {
+            bold: Some(true),
+            ..TextSpan::new(text)
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let rich_text = RichText::new([
+            bold("Total"),
+            bold(":"),
+            TextSpan::new(""),
+            TextSpan::new(" 42"),
+        ]);
+        assert_eq!(rich_text.spans, vec![bold("Total:"), TextSpan::new(" 42")]);
+        assert_eq!(rich_text.to_plain(), "Total: 42");
+        assert!(!rich_text.is_plain());
+        assert!(RichText::new([TextSpan::new("a"), TextSpan::new("b")]).is_plain());
+    }
+
+    #[test]
+    fn test_from_html() {
+        let rich_text = RichText::from_html(
+            "<span style=\"font-weight:bold;\">Total:</span><span> 42 &amp; </span><i>more<br><font color=\"#ff0000\">red</font></i>",
+        );
+        assert_eq!(
+            rich_text.spans,
+            vec![
+                bold("Total:"),
+                TextSpan::new(" 42 & "),
+                TextSpan {
+                    italic: Some(true),
+                    ..TextSpan::new("more\n")
+                },
+                TextSpan {
+                    italic: Some(true),
+                    text_color: Some("#ff0000".to_string()),
+                    ..TextSpan::new("red")
+                },
+            ]
+        );
+
+        let rich_text = RichText::from_html(
+            "<b>a <span style='font-weight: 400; text-decoration: underline line-through'>b</span></b>",
+        );
+        assert_eq!(
+            rich_text.spans,
+            vec![
+                bold("a "),
+                TextSpan {
+                    bold: Some(false),
+                    underline: Some(true),
+                    strike_through: Some(true),
+                    ..TextSpan::new("b")
+                },
+            ]
+        );
+        assert!(RichText::from_html("plain <span>text</span>").is_plain());
+    }
+
+    #[test]
+    fn test_to_html() {
+        let rich_text = RichText::new([
+            bold("<Total>"),
+            TextSpan::new(" 42\n"),
+            TextSpan {
+                underline: Some(true),
+                text_color: Some("red".to_string()),
+                ..TextSpan::new("x")
+            },
+        ]);
+        let html = rich_text.to_html();
+        assert_eq!(
+            html,
+            "<span style=\"font-weight:bold;\">&lt;Total&gt;</span> 42<br><span style=\"text-decoration:underline;color:red;\">x</span>"
+        );
+        assert_eq!(RichText::from_html(&html), rich_text);
+    }
+}
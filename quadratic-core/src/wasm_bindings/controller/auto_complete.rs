@@ -28,4 +28,39 @@ impl GridController {
         });
         Ok(())
     }
+    /// Fill a column by learning a pattern from the examples in it.
+    #[wasm_bindgen(js_name = "flashFill")]
+    pub fn js_flash_fill(
+        &mut self,
+        sheet_id: String,
+        range: String,
+        cursor: Option<String>,
+    ) -> Result<(), JsValue> {
+        handle_core_result(|| -> Result<(), String> {
+            let range: Rect = serde_json::from_str(&range).map_err(|e| e.to_string())?;
+            let sheet_id = SheetId::from_str(&sheet_id).map_err(|e| e.to_string())?;
+            self.flash_fill(sheet_id, range, cursor)
+                .map_err(|e| e.to_string())
+        });
+        Ok(())
+    }
+    /// Returns the workbook's custom lists as a string[][].
+    #[wasm_bindgen(js_name = "getCustomLists")]
+    pub fn js_get_custom_lists(&self) -> Result<JsValue, JsValue> {
+        Ok(serde_wasm_bindgen::to_value(self.custom_lists())?)
+    }
+
+    /// Replaces the workbook's custom lists (a string[][]) used by autocomplete.
+    #[wasm_bindgen(js_name = "setCustomLists")]
+    pub fn js_set_custom_lists(
+        &mut self,
+        custom_lists: String,
+        cursor: Option<String>,
+    ) -> Result<(), JsValue> {
+        let custom_lists: Vec<Vec<String>> =
+            serde_json::from_str(&custom_lists).map_err(|e| e.to_string())?;
+        self.set_custom_lists(custom_lists, cursor)
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
 }
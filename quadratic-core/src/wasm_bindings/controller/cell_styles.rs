@@ -0,0 +1,77 @@
+//! WASM functions for named cell styles and the workbook theme
+
+use crate::a1::A1Selection;
+use crate::grid::formats::{CellStyle, Theme};
+
+use super::*;
+
+#[wasm_bindgen]
+impl GridController {
+    /// Returns the workbook's named cell styles (CellStyle[])
+    #[wasm_bindgen(js_name = "getCellStyles")]
+    pub fn js_cell_styles(&self) -> Result<JsValue, JsValue> {
+        Ok(serde_wasm_bindgen::to_value(self.cell_styles())?)
+    }
+
+    /// Returns the workbook's theme (Theme)
+    #[wasm_bindgen(js_name = "getTheme")]
+    pub fn js_theme(&self) -> Result<JsValue, JsValue> {
+        Ok(serde_wasm_bindgen::to_value(self.theme())?)
+    }
+
+    /// Applies a named cell style to a selection, or removes the style
+    /// reference if `name` is undefined
+    #[wasm_bindgen(js_name = "applyCellStyle")]
+    pub fn js_apply_cell_style(
+        &mut self,
+        selection: String,
+        name: Option<String>,
+        cursor: Option<String>,
+    ) -> Result<(), JsValue> {
+        let selection = serde_json::from_str::<A1Selection>(&selection)
+            .map_err(|_| "Unable to parse A1Selection")?;
+        self.apply_cell_style(&selection, name.as_deref(), cursor)
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    /// Adds a named cell style, or replaces the style named `original_name`
+    #[wasm_bindgen(js_name = "updateCellStyle")]
+    pub fn js_update_cell_style(
+        &mut self,
+        original_name: Option<String>,
+        cell_style: String, // CellStyle
+        cursor: Option<String>,
+    ) -> Result<(), JsValue> {
+        let cell_style = serde_json::from_str::<CellStyle>(&cell_style)
+            .map_err(|e| format!("Error parsing cell style: {e}"))?;
+        self.update_cell_style(original_name.as_deref(), cell_style, cursor)
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    /// Deletes a named cell style
+    #[wasm_bindgen(js_name = "deleteCellStyle")]
+    pub fn js_delete_cell_style(
+        &mut self,
+        name: String,
+        cursor: Option<String>,
+    ) -> Result<(), JsValue> {
+        self.delete_cell_style(&name, cursor)
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    /// Replaces the workbook's theme
+    #[wasm_bindgen(js_name = "setTheme")]
+    pub fn js_set_theme(
+        &mut self,
+        theme: String, // Theme
+        cursor: Option<String>,
+    ) -> Result<(), JsValue> {
+        let theme = serde_json::from_str::<Theme>(&theme)
+            .map_err(|e| format!("Error parsing theme: {e}"))?;
+        self.set_theme(theme, cursor).map_err(|e| e.to_string())?;
+        Ok(())
+    }
+}
@@ -1,7 +1,7 @@
 use std::str::FromStr;
 
-use crate::SheetPos;
 use crate::a1::A1Selection;
+use crate::{CellValue, RichText, SheetPos, TextSpan};
 use crate::{Pos, controller::GridController, grid::SheetId};
 use wasm_bindgen::{JsValue, prelude::wasm_bindgen};
 
@@ -27,6 +27,30 @@ impl GridController {
         Ok(())
     }
 
+    /// Sets the rich text of a cell from the inline editor, given as a
+    /// TextSpan[].
+    #[wasm_bindgen(js_name = "setCellRichText")]
+    pub fn js_set_cell_rich_text(
+        &mut self,
+        sheet_id: String,
+        x: i32,
+        y: i32,
+        spans: JsValue,
+        cursor: Option<String>,
+    ) -> Result<(), JsValue> {
+        let spans: Vec<TextSpan> = serde_wasm_bindgen::from_value(spans)
+            .map_err(|e| JsValue::from_str(&format!("Failed to parse spans: {}", e)))?;
+        let sheet_id = SheetId::from_str(&sheet_id).map_err(|e| e.to_string())?;
+        self.set_cell_rich_text(
+            SheetPos::new(sheet_id, x as i64, y as i64),
+            RichText::new(spans),
+            cursor,
+        )
+        .map_err(|e| e.to_string())?;
+
+        Ok(())
+    }
+
     /// Sets a 2d array of cell values with x and y being the top left corner of the 2d array.
     ///
     /// Returns a [`TransactionSummary`].
@@ -74,7 +98,24 @@ impl GridController {
             .ok_or(JsValue::UNDEFINED)?;
         let val = sheet.get_cell_for_formula(pos);
 
-        Ok(val.to_edit())
+        Ok(val.to_edit_with_locale(self.locale()))
+    }
+
+    /// gets the rich text of a cell for the inline editor
+    ///
+    /// returns a TextSpan[] or undefined if the cell does not have rich text
+    #[wasm_bindgen(js_name = "getEditRichText")]
+    pub fn js_get_edit_rich_text(&self, sheet_id: String, pos: String) -> Result<JsValue, JsValue> {
+        let pos = serde_json::from_str(&pos).map_err(|_| JsValue::UNDEFINED)?;
+        let sheet = self
+            .try_sheet_from_string_id(sheet_id)
+            .ok_or(JsValue::UNDEFINED)?;
+        match sheet.display_value(pos) {
+            Some(CellValue::RichText(rich_text)) => {
+                serde_wasm_bindgen::to_value(&rich_text.spans).map_err(|_| JsValue::UNDEFINED)
+            }
+            _ => Ok(JsValue::UNDEFINED),
+        }
     }
 
     /// gets the display value for a cell
@@ -86,7 +127,35 @@ impl GridController {
         let Some(sheet) = self.try_sheet_from_string_id(sheet_id) else {
             return String::default();
         };
-        sheet.rendered_value(pos).unwrap_or(String::default())
+        sheet
+            .rendered_value_with_locale(pos, self.locale())
+            .unwrap_or(String::default())
+    }
+
+    /// gets the hyperlink displayed at a cell
+    /// returns a Hyperlink or undefined
+    #[wasm_bindgen(js_name = "getHyperlink")]
+    pub fn js_get_hyperlink(&self, sheet_id: String, pos: String) -> Result<JsValue, JsValue> {
+        let pos = serde_json::from_str(&pos).map_err(|_| JsValue::UNDEFINED)?;
+        let sheet = self
+            .try_sheet_from_string_id(sheet_id)
+            .ok_or(JsValue::UNDEFINED)?;
+        match sheet.display_value(pos) {
+            Some(CellValue::Hyperlink(link)) => {
+                serde_wasm_bindgen::to_value(&link).map_err(|_| JsValue::UNDEFINED)
+            }
+            _ => Ok(JsValue::UNDEFINED),
+        }
+    }
+
+    /// gets the selection of the internal hyperlink at a cell
+    /// returns a stringified A1Selection or undefined for external links
+    #[wasm_bindgen(js_name = "followHyperlink")]
+    pub fn js_follow_hyperlink(&self, sheet_id: String, pos: String) -> Option<String> {
+        let sheet_id = SheetId::from_str(&sheet_id).ok()?;
+        let pos: Pos = serde_json::from_str(&pos).ok()?;
+        let selection = self.hyperlink_selection(pos.to_sheet_pos(sheet_id))?;
+        serde_json::to_string(&selection).ok()
     }
 
     /// gets the value and type for a cell
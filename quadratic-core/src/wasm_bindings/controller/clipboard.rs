@@ -13,18 +13,27 @@ use super::Pos;
 
 #[wasm_bindgen]
 impl GridController {
-    /// Returns the clipboard [`JsClipboard`]
+    /// Returns the clipboard [`JsClipboard`]. If skip_hidden is true, hidden
+    /// columns and rows are not copied.
     #[wasm_bindgen(js_name = "copyToClipboard")]
-    pub fn js_copy_to_clipboard(&self, selection: String) -> Result<JsValue, JsValue> {
+    pub fn js_copy_to_clipboard(
+        &self,
+        selection: String,
+        skip_hidden: Option<bool>,
+    ) -> Result<JsValue, JsValue> {
         let selection = serde_json::from_str::<A1Selection>(&selection)
             .map_err(|_| "Unable to parse A1Selection")?;
         let sheet = self.try_sheet(selection.sheet_id).ok_or("No Sheet found")?;
-        let js_clipboard = sheet.copy_to_clipboard(
-            &selection,
-            self.a1_context(),
-            ClipboardOperation::Copy,
-            true,
-        )?;
+        let js_clipboard = if skip_hidden.unwrap_or(false) {
+            sheet.copy_visible_to_clipboard(&selection, self.a1_context(), true)?
+        } else {
+            sheet.copy_to_clipboard(
+                &selection,
+                self.a1_context(),
+                ClipboardOperation::Copy,
+                true,
+            )?
+        };
         Ok(serde_wasm_bindgen::to_value(&js_clipboard).map_err(|e| e.to_string())?)
     }
 
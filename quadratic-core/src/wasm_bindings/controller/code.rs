@@ -50,7 +50,10 @@ impl GridController {
         let Some(sheet) = self.try_sheet_from_string_id(sheet_id) else {
             return Ok(JsValue::null());
         };
-        if let Some(edit_code) = sheet.edit_code_value(pos, self.a1_context()) {
+        if let Some(mut edit_code) = sheet.edit_code_value(pos, self.a1_context()) {
+            if edit_code.language == CodeCellLanguage::Formula {
+                edit_code.code_string = self.locale().localize_formula(&edit_code.code_string);
+            }
             Ok(serde_wasm_bindgen::to_value(&edit_code)?)
         } else {
             Ok(JsValue::null())
@@ -70,7 +73,12 @@ impl GridController {
     ) -> Option<String> {
         if let Ok(pos) = serde_json::from_str::<Pos>(&pos) {
             if let Ok(sheet_id) = SheetId::from_str(&sheet_id) {
-                if let Ok(language) = serde_wasm_bindgen::from_value(language) {
+                if let Ok(language) = serde_wasm_bindgen::from_value::<CodeCellLanguage>(language) {
+                    let code_string = if language == CodeCellLanguage::Formula {
+                        self.locale().delocalize_formula(&code_string)
+                    } else {
+                        code_string
+                    };
                     return Some(self.set_code_cell(
                         pos.to_sheet_pos(sheet_id),
                         language,
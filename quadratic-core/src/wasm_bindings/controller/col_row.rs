@@ -94,4 +94,38 @@ impl GridController {
             );
         }
     }
+
+    #[wasm_bindgen(js_name = "hideColumns")]
+    pub fn js_hide_columns(&mut self, sheet_id: &str, columns: String, cursor: Option<String>) {
+        if let (Ok(sheet_id), Ok(columns)) =
+            (SheetId::from_str(sheet_id), serde_json::from_str(&columns))
+        {
+            self.hide_columns(sheet_id, columns, cursor);
+        }
+    }
+
+    #[wasm_bindgen(js_name = "unhideColumns")]
+    pub fn js_unhide_columns(&mut self, sheet_id: &str, columns: String, cursor: Option<String>) {
+        if let (Ok(sheet_id), Ok(columns)) =
+            (SheetId::from_str(sheet_id), serde_json::from_str(&columns))
+        {
+            self.unhide_columns(sheet_id, columns, cursor);
+        }
+    }
+
+    #[wasm_bindgen(js_name = "hideRows")]
+    pub fn js_hide_rows(&mut self, sheet_id: &str, rows: String, cursor: Option<String>) {
+        if let (Ok(sheet_id), Ok(rows)) = (SheetId::from_str(sheet_id), serde_json::from_str(&rows))
+        {
+            self.hide_rows(sheet_id, rows, cursor);
+        }
+    }
+
+    #[wasm_bindgen(js_name = "unhideRows")]
+    pub fn js_unhide_rows(&mut self, sheet_id: &str, rows: String, cursor: Option<String>) {
+        if let (Ok(sheet_id), Ok(rows)) = (SheetId::from_str(sheet_id), serde_json::from_str(&rows))
+        {
+            self.unhide_rows(sheet_id, rows, cursor);
+        }
+    }
 }
@@ -0,0 +1,78 @@
+//! WASM functions for cell comments
+
+use uuid::Uuid;
+
+use super::*;
+
+#[wasm_bindgen]
+impl GridController {
+    /// Adds a comment to a cell (starting a thread or replying to it).
+    #[wasm_bindgen(js_name = "addComment")]
+    pub fn js_add_comment(
+        &mut self,
+        sheet_id: String,
+        x: i64,
+        y: i64,
+        author: String,
+        text: String,
+        cursor: Option<String>,
+    ) -> Result<(), JsValue> {
+        let sheet_id = SheetId::from_str(&sheet_id).map_err(|_| "Invalid sheet id")?;
+        self.add_comment(SheetPos::new(sheet_id, x, y), author, text, cursor)
+            .map_err(|e| e.to_string())?;
+
+        Ok(())
+    }
+
+    /// Deletes a comment. The thread is deleted with its last comment.
+    #[wasm_bindgen(js_name = "deleteComment")]
+    pub fn js_delete_comment(
+        &mut self,
+        sheet_id: String,
+        x: i64,
+        y: i64,
+        comment_id: String,
+        cursor: Option<String>,
+    ) -> Result<(), JsValue> {
+        let sheet_id = SheetId::from_str(&sheet_id).map_err(|_| "Invalid sheet id")?;
+        let comment_id = Uuid::from_str(&comment_id).map_err(|_| "Invalid comment id")?;
+        self.delete_comment(SheetPos::new(sheet_id, x, y), comment_id, cursor)
+            .map_err(|e| e.to_string())?;
+
+        Ok(())
+    }
+
+    /// Resolves (or reopens) the comment thread of a cell.
+    #[wasm_bindgen(js_name = "setCommentResolved")]
+    pub fn js_set_comment_resolved(
+        &mut self,
+        sheet_id: String,
+        x: i64,
+        y: i64,
+        resolved: bool,
+        cursor: Option<String>,
+    ) -> Result<(), JsValue> {
+        let sheet_id = SheetId::from_str(&sheet_id).map_err(|_| "Invalid sheet id")?;
+        self.set_comment_resolved(SheetPos::new(sheet_id, x, y), resolved, cursor)
+            .map_err(|e| e.to_string())?;
+
+        Ok(())
+    }
+
+    /// Returns the comment thread of a cell (CommentThread | undefined).
+    #[wasm_bindgen(js_name = "getCommentThread")]
+    pub fn js_get_comment_thread(
+        &self,
+        sheet_id: String,
+        x: i64,
+        y: i64,
+    ) -> Result<JsValue, JsValue> {
+        let sheet_id = SheetId::from_str(&sheet_id).map_err(|_| "Invalid sheet id")?;
+        let sheet = self
+            .try_sheet(sheet_id)
+            .ok_or_else(|| JsValue::from_str("Sheet not found"))?;
+        Ok(serde_wasm_bindgen::to_value(
+            &sheet.comments.get(Pos { x, y }),
+        )?)
+    }
+}
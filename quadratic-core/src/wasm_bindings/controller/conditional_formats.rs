@@ -0,0 +1,50 @@
+//! WASM functions for conditional formats
+
+use sheet::conditional_formats::conditional_format::ConditionalFormat;
+use uuid::Uuid;
+
+use super::*;
+
+#[wasm_bindgen]
+impl GridController {
+    /// Returns the conditional formats of a sheet (Vec<ConditionalFormat>)
+    #[wasm_bindgen(js_name = "getConditionalFormats")]
+    pub fn js_conditional_formats(&self, sheet_id: String) -> Result<JsValue, JsValue> {
+        let sheet_id = SheetId::from_str(&sheet_id).map_err(|_| "Invalid sheet id")?;
+        Ok(serde_wasm_bindgen::to_value(
+            &self.conditional_formats(sheet_id),
+        )?)
+    }
+
+    /// Creates or updates a conditional format
+    #[wasm_bindgen(js_name = "updateConditionalFormat")]
+    pub fn js_update_conditional_format(
+        &mut self,
+        conditional_format: String, // ConditionalFormat
+        cursor: Option<String>,
+    ) -> Result<(), JsValue> {
+        let conditional_format = serde_json::from_str::<ConditionalFormat>(&conditional_format)
+            .map_err(|e| format!("Error parsing conditional format: {e}"))?;
+        self.update_conditional_format(conditional_format, cursor)
+            .map_err(|e| e.to_string())?;
+
+        Ok(())
+    }
+
+    /// Removes a conditional format
+    #[wasm_bindgen(js_name = "removeConditionalFormat")]
+    pub fn js_remove_conditional_format(
+        &mut self,
+        sheet_id: String,
+        conditional_format_id: String,
+        cursor: Option<String>,
+    ) -> Result<(), JsValue> {
+        let sheet_id = SheetId::from_str(&sheet_id).map_err(|_| "Invalid sheet id")?;
+        let conditional_format_id =
+            Uuid::from_str(&conditional_format_id).map_err(|_| "Invalid conditional format id")?;
+        self.remove_conditional_format(sheet_id, conditional_format_id, cursor)
+            .map_err(|e| e.to_string())?;
+
+        Ok(())
+    }
+}
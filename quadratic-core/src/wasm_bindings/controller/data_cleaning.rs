@@ -0,0 +1,49 @@
+use crate::{
+    a1::A1Selection,
+    grid::sheet::data_cleaning::{DataCleaningAction, DuplicateKeep},
+};
+
+use super::*;
+
+#[wasm_bindgen]
+impl GridController {
+    /// Cleans the values in a selection. Returns a DataCleaningSummary.
+    #[wasm_bindgen(js_name = "cleanData")]
+    pub fn js_clean_data(
+        &mut self,
+        selection: String,
+        action: String,
+        cursor: Option<String>,
+    ) -> Result<JsValue, JsValue> {
+        let selection = serde_json::from_str::<A1Selection>(&selection)
+            .map_err(|_| "Unable to parse A1Selection")?;
+        let action =
+            serde_json::from_str::<DataCleaningAction>(&action).map_err(|e| e.to_string())?;
+        let summary = self
+            .clean_data(&selection, action, cursor)
+            .map_err(|e| e.to_string())?;
+
+        Ok(serde_wasm_bindgen::to_value(&summary).map_err(|e| e.to_string())?)
+    }
+
+    /// Removes duplicate rows from a selection. `key_columns` are relative to
+    /// the selection; if omitted, all columns are compared. Returns a
+    /// DataCleaningSummary.
+    #[wasm_bindgen(js_name = "removeDuplicates")]
+    pub fn js_remove_duplicates(
+        &mut self,
+        selection: String,
+        key_columns: Option<Vec<u32>>,
+        keep: String,
+        cursor: Option<String>,
+    ) -> Result<JsValue, JsValue> {
+        let selection = serde_json::from_str::<A1Selection>(&selection)
+            .map_err(|_| "Unable to parse A1Selection")?;
+        let keep = serde_json::from_str::<DuplicateKeep>(&keep).map_err(|e| e.to_string())?;
+        let summary = self
+            .remove_duplicates(&selection, key_columns, keep, cursor)
+            .map_err(|e| e.to_string())?;
+
+        Ok(serde_wasm_bindgen::to_value(&summary).map_err(|e| e.to_string())?)
+    }
+}
@@ -1,4 +1,6 @@
 use sort::DataTableSort;
+use table_style::TableStyle;
+use transform::DataTableTransform;
 
 use super::*;
 
@@ -129,6 +131,35 @@ impl GridController {
         Ok(())
     }
 
+    /// Returns Excel's built-in table styles (TableStyle[])
+    #[wasm_bindgen(js_name = "getTableStylePresets")]
+    pub fn js_table_style_presets(&self) -> Result<JsValue, JsValue> {
+        Ok(serde_wasm_bindgen::to_value(&self.table_style_presets())?)
+    }
+
+    /// Sets the style of a Data Table, or clears it if `table_style` is
+    /// undefined
+    #[wasm_bindgen(js_name = "setDataTableStyle")]
+    pub fn js_set_data_table_style(
+        &mut self,
+        sheet_id: String,
+        pos: String,
+        table_style: Option<String>, // TableStyle
+        cursor: Option<String>,
+    ) -> Result<(), JsValue> {
+        let pos = serde_json::from_str::<Pos>(&pos).map_err(|e| e.to_string())?;
+        let sheet_id = SheetId::from_str(&sheet_id).map_err(|e| e.to_string())?;
+        let table_style = table_style
+            .map(|table_style| {
+                serde_json::from_str::<TableStyle>(&table_style)
+                    .map_err(|e| format!("Error parsing table style: {e}"))
+            })
+            .transpose()?;
+        self.set_data_table_style(pos.to_sheet_pos(sheet_id), table_style, cursor);
+
+        Ok(())
+    }
+
     #[allow(clippy::too_many_arguments)]
     #[wasm_bindgen(js_name = "dataTableMutations")]
     pub fn js_data_table_mutations(
@@ -188,4 +219,28 @@ impl GridController {
 
         Ok(())
     }
+
+    /// Creates a new Data Table by grouping, pivoting, unpivoting, or joining
+    /// existing tables. If `live` is true, the new table recomputes when its
+    /// sources change.
+    #[wasm_bindgen(js_name = "dataTableTransform")]
+    pub fn js_data_table_transform(
+        &mut self,
+        sheet_id: String,
+        pos: String,
+        transform: String,
+        live: bool,
+        name: Option<String>,
+        cursor: Option<String>,
+    ) -> Result<(), JsValue> {
+        let pos = serde_json::from_str::<Pos>(&pos).map_err(|e| e.to_string())?;
+        let sheet_id = SheetId::from_str(&sheet_id).map_err(|e| e.to_string())?;
+        let transform =
+            serde_json::from_str::<DataTableTransform>(&transform).map_err(|e| e.to_string())?;
+
+        self.data_table_transform(pos.to_sheet_pos(sheet_id), transform, live, name, cursor)
+            .map_err(|e| e.to_string())?;
+
+        Ok(())
+    }
 }
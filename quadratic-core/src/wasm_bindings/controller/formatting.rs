@@ -127,6 +127,20 @@ impl GridController {
         Ok(())
     }
 
+    /// Sets cells numeric_format to an Excel number format code
+    #[wasm_bindgen(js_name = "setCustomNumberFormat")]
+    pub fn js_set_custom_number_format(
+        &mut self,
+        selection: String,
+        code: String,
+        cursor: Option<String>,
+    ) -> Result<(), JsValue> {
+        let selection = serde_json::from_str::<A1Selection>(&selection)
+            .map_err(|_| "Unable to parse A1Selection")?;
+        self.set_custom_number_format(&selection, code, cursor)?;
+        Ok(())
+    }
+
     /// Sets cells numeric_commas
     #[wasm_bindgen(js_name = "setCommas")]
     pub fn js_set_commas(
@@ -269,6 +283,62 @@ impl GridController {
         Ok(())
     }
 
+    /// Sets cell font size in points, or clears it when `None`.
+    #[wasm_bindgen(js_name = "setFontSize")]
+    pub fn js_set_font_size(
+        &mut self,
+        selection: String,
+        font_size: Option<i16>,
+        cursor: Option<String>,
+    ) -> Result<(), JsValue> {
+        let selection = serde_json::from_str::<A1Selection>(&selection)
+            .map_err(|_| "Unable to parse A1Selection")?;
+        self.set_font_size(&selection, font_size, cursor)?;
+        Ok(())
+    }
+
+    /// Sets cell font family, or clears it when `None`.
+    #[wasm_bindgen(js_name = "setFontFamily")]
+    pub fn js_set_font_family(
+        &mut self,
+        selection: String,
+        font_family: Option<String>,
+        cursor: Option<String>,
+    ) -> Result<(), JsValue> {
+        let selection = serde_json::from_str::<A1Selection>(&selection)
+            .map_err(|_| "Unable to parse A1Selection")?;
+        self.set_font_family(&selection, font_family, cursor)?;
+        Ok(())
+    }
+
+    /// Sets cell text rotation in degrees (255 for vertical text).
+    #[wasm_bindgen(js_name = "setTextRotation")]
+    pub fn js_set_text_rotation(
+        &mut self,
+        selection: String,
+        text_rotation: Option<i16>,
+        cursor: Option<String>,
+    ) -> Result<(), JsValue> {
+        let selection = serde_json::from_str::<A1Selection>(&selection)
+            .map_err(|_| "Unable to parse A1Selection")?;
+        self.set_text_rotation(&selection, text_rotation, cursor)?;
+        Ok(())
+    }
+
+    /// Sets cell indent level, or clears it when `None`.
+    #[wasm_bindgen(js_name = "setIndent")]
+    pub fn js_set_indent(
+        &mut self,
+        selection: String,
+        indent: Option<u8>,
+        cursor: Option<String>,
+    ) -> Result<(), JsValue> {
+        let selection = serde_json::from_str::<A1Selection>(&selection)
+            .map_err(|_| "Unable to parse A1Selection")?;
+        self.set_indent(&selection, indent, cursor)?;
+        Ok(())
+    }
+
     /// Returns a [`TransactionSummary`].
     #[wasm_bindgen(js_name = "clearFormatting")]
     pub fn js_clear_formatting(
@@ -0,0 +1,78 @@
+//! WASM functions for the file's locale
+
+use crate::date_time::DateSettings;
+use crate::grid::Locale;
+
+use super::*;
+
+#[wasm_bindgen]
+impl GridController {
+    /// Returns the file's locale (Locale)
+    #[wasm_bindgen(js_name = "getLocale")]
+    pub fn js_locale(&self) -> Result<JsValue, JsValue> {
+        Ok(serde_wasm_bindgen::to_value(self.locale())?)
+    }
+
+    /// Returns the predefined locales (Locale[])
+    #[wasm_bindgen(js_name = "getLocalePresets")]
+    pub fn js_locale_presets(&self) -> Result<JsValue, JsValue> {
+        let presets = Locale::preset_names()
+            .into_iter()
+            .filter_map(Locale::preset)
+            .collect::<Vec<_>>();
+        Ok(serde_wasm_bindgen::to_value(&presets)?)
+    }
+
+    /// Replaces the file's locale
+    #[wasm_bindgen(js_name = "setLocale")]
+    pub fn js_set_locale(
+        &mut self,
+        locale: String, // Locale
+        cursor: Option<String>,
+    ) -> Result<(), JsValue> {
+        let locale = serde_json::from_str::<Locale>(&locale)
+            .map_err(|e| format!("Error parsing locale: {e}"))?;
+        self.set_locale(locale, cursor).map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    /// Returns the file's IANA time zone name, or undefined if the user's local
+    /// time zone is used
+    #[wasm_bindgen(js_name = "getTimeZone")]
+    pub fn js_time_zone(&self) -> Option<String> {
+        self.time_zone()
+            .map(|time_zone| time_zone.name().to_string())
+    }
+
+    /// Replaces the file's time zone using an IANA name (an empty name uses
+    /// the user's local time zone)
+    #[wasm_bindgen(js_name = "setTimeZone")]
+    pub fn js_set_time_zone(
+        &mut self,
+        time_zone: String,
+        cursor: Option<String>,
+    ) -> Result<(), JsValue> {
+        self.set_time_zone(&time_zone, cursor)
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    /// Returns the file's date settings (DateSettings)
+    #[wasm_bindgen(js_name = "getDateSettings")]
+    pub fn js_date_settings(&self) -> Result<JsValue, JsValue> {
+        Ok(serde_wasm_bindgen::to_value(&self.date_settings())?)
+    }
+
+    /// Replaces the file's date settings
+    #[wasm_bindgen(js_name = "setDateSettings")]
+    pub fn js_set_date_settings(
+        &mut self,
+        date_settings: String, // DateSettings
+        cursor: Option<String>,
+    ) -> Result<(), JsValue> {
+        let date_settings = serde_json::from_str::<DateSettings>(&date_settings)
+            .map_err(|e| format!("Error parsing date settings: {e}"))?;
+        self.set_date_settings(date_settings, cursor);
+        Ok(())
+    }
+}
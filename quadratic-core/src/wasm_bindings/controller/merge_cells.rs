@@ -0,0 +1,52 @@
+use crate::a1::A1Selection;
+
+use super::*;
+
+#[wasm_bindgen]
+impl GridController {
+    /// Merges each range of the selection into a single cell.
+    #[wasm_bindgen(js_name = "mergeCells")]
+    pub fn js_merge_cells(
+        &mut self,
+        selection: String,
+        cursor: Option<String>,
+    ) -> Result<(), JsValue> {
+        let selection = serde_json::from_str::<A1Selection>(&selection)
+            .map_err(|_| "Unable to parse A1Selection")?;
+        self.merge_cells(&selection, cursor)
+            .map_err(|e| e.to_string())?;
+
+        Ok(())
+    }
+
+    /// Unmerges all merged cells that intersect the selection.
+    #[wasm_bindgen(js_name = "unmergeCells")]
+    pub fn js_unmerge_cells(
+        &mut self,
+        selection: String,
+        cursor: Option<String>,
+    ) -> Result<(), JsValue> {
+        let selection = serde_json::from_str::<A1Selection>(&selection)
+            .map_err(|_| "Unable to parse A1Selection")?;
+        self.unmerge_cells(&selection, cursor)
+            .map_err(|e| e.to_string())?;
+
+        Ok(())
+    }
+
+    /// Expands a selection to fully include the merged cells it intersects.
+    /// Returns the expanded A1Selection.
+    #[wasm_bindgen(js_name = "expandSelectionToMergedCells")]
+    pub fn js_expand_selection_to_merged_cells(
+        &self,
+        selection: String,
+    ) -> Result<JsValue, JsValue> {
+        let selection = serde_json::from_str::<A1Selection>(&selection)
+            .map_err(|_| "Unable to parse A1Selection")?;
+        let sheet = self
+            .try_sheet(selection.sheet_id)
+            .ok_or_else(|| JsValue::from_str("Sheet not found"))?;
+        let selection = sheet.expand_selection_to_merged_cells(&selection);
+        Ok(serde_wasm_bindgen::to_value(&selection).map_err(|e| e.to_string())?)
+    }
+}
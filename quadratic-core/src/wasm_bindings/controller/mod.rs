@@ -9,20 +9,29 @@ pub mod ai_context;
 pub mod auto_complete;
 pub mod borders;
 pub mod bounds;
+pub mod cell_styles;
 pub mod cells;
 pub mod clipboard;
 pub mod code;
 pub mod col_row;
+pub mod comments;
+pub mod conditional_formats;
+pub mod data_cleaning;
 pub mod data_table;
 pub mod export;
 pub mod formatting;
 pub mod import;
+pub mod locale;
+pub mod merge_cells;
+pub mod outlines;
+pub mod protection;
 pub mod render;
 pub mod search;
 pub mod sheet_info;
 pub mod sheet_offsets;
 pub mod sheets;
 pub mod summarize;
+pub mod text_to_columns;
 pub mod transactions;
 pub mod validation;
 pub mod worker;
@@ -69,6 +78,13 @@ impl GridController {
                             crate::wasm_bindings::js::jsHtmlOutput(html);
                         }
                     }
+                    // evaluate conditional formats and validations with a
+                    // formula before sending cells, fills, and warnings
+                    for sheet_id in grid.sheet_ids() {
+                        grid.update_sheet_conditional_formats(sheet_id, &[]);
+                        grid.update_sheet_formula_validations(sheet_id, &[]);
+                    }
+
                     grid.sheet_ids().iter().for_each(|sheet_id| {
                         grid.send_all_fills(*sheet_id);
 
@@ -92,6 +108,17 @@ impl GridController {
                             // sends all validation warnings to the client
                             sheet.send_all_validation_warnings();
 
+                            // sends all comments to the client
+                            sheet.send_all_comments();
+
+                            // sends all conditional formats to the client
+                            sheet.send_all_conditional_formats();
+
+                            // sends the sheet's protection to the client
+                            if !sheet.protection.is_empty() {
+                                sheet.send_sheet_protection();
+                            }
+
                             // sends all borders to the client
                             sheet.send_sheet_borders();
                         }
@@ -0,0 +1,106 @@
+use crate::Axis;
+
+use super::*;
+
+#[wasm_bindgen]
+impl GridController {
+    /// Groups the columns from start to end (adds one outline level).
+    #[wasm_bindgen(js_name = "groupColumns")]
+    pub fn js_group_columns(
+        &mut self,
+        sheet_id: &str,
+        start: i64,
+        end: i64,
+        cursor: Option<String>,
+    ) -> Result<(), JsValue> {
+        let sheet_id = SheetId::from_str(sheet_id).map_err(|e| e.to_string())?;
+        self.group_outline(sheet_id, Axis::X, start, end, cursor)
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    /// Ungroups the columns from start to end (removes one outline level).
+    #[wasm_bindgen(js_name = "ungroupColumns")]
+    pub fn js_ungroup_columns(
+        &mut self,
+        sheet_id: &str,
+        start: i64,
+        end: i64,
+        cursor: Option<String>,
+    ) -> Result<(), JsValue> {
+        let sheet_id = SheetId::from_str(sheet_id).map_err(|e| e.to_string())?;
+        self.ungroup_outline(sheet_id, Axis::X, start, end, cursor)
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    /// Groups the rows from start to end (adds one outline level).
+    #[wasm_bindgen(js_name = "groupRows")]
+    pub fn js_group_rows(
+        &mut self,
+        sheet_id: &str,
+        start: i64,
+        end: i64,
+        cursor: Option<String>,
+    ) -> Result<(), JsValue> {
+        let sheet_id = SheetId::from_str(sheet_id).map_err(|e| e.to_string())?;
+        self.group_outline(sheet_id, Axis::Y, start, end, cursor)
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    /// Ungroups the rows from start to end (removes one outline level).
+    #[wasm_bindgen(js_name = "ungroupRows")]
+    pub fn js_ungroup_rows(
+        &mut self,
+        sheet_id: &str,
+        start: i64,
+        end: i64,
+        cursor: Option<String>,
+    ) -> Result<(), JsValue> {
+        let sheet_id = SheetId::from_str(sheet_id).map_err(|e| e.to_string())?;
+        self.ungroup_outline(sheet_id, Axis::Y, start, end, cursor)
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    /// Collapses or expands a column group (see SheetInfo.column_outline).
+    #[wasm_bindgen(js_name = "setColumnGroupCollapsed")]
+    pub fn js_set_column_group_collapsed(
+        &mut self,
+        sheet_id: &str,
+        start: i64,
+        level: u8,
+        collapsed: bool,
+        cursor: Option<String>,
+    ) -> Result<(), JsValue> {
+        let sheet_id = SheetId::from_str(sheet_id).map_err(|e| e.to_string())?;
+        if collapsed {
+            self.collapse_outline_group(sheet_id, Axis::X, start, level, cursor)
+        } else {
+            self.expand_outline_group(sheet_id, Axis::X, start, level, cursor)
+        }
+        .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    /// Collapses or expands a row group (see SheetInfo.row_outline).
+    #[wasm_bindgen(js_name = "setRowGroupCollapsed")]
+    pub fn js_set_row_group_collapsed(
+        &mut self,
+        sheet_id: &str,
+        start: i64,
+        level: u8,
+        collapsed: bool,
+        cursor: Option<String>,
+    ) -> Result<(), JsValue> {
+        let sheet_id = SheetId::from_str(sheet_id).map_err(|e| e.to_string())?;
+        if collapsed {
+            self.collapse_outline_group(sheet_id, Axis::Y, start, level, cursor)
+        } else {
+            self.expand_outline_group(sheet_id, Axis::Y, start, level, cursor)
+        }
+        .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+}
@@ -0,0 +1,40 @@
+//! WASM functions for sheet and range protection
+
+use sheet::protection::SheetProtection;
+
+use super::*;
+
+#[wasm_bindgen]
+impl GridController {
+    /// Sets the email of the user editing the file
+    #[wasm_bindgen(js_name = "setEditor")]
+    pub fn js_set_editor(&mut self, editor: Option<String>) {
+        self.set_editor(editor);
+    }
+
+    /// Returns the protection of a sheet (SheetProtection)
+    #[wasm_bindgen(js_name = "getSheetProtection")]
+    pub fn js_sheet_protection(&self, sheet_id: String) -> Result<JsValue, JsValue> {
+        let sheet_id = SheetId::from_str(&sheet_id).map_err(|_| "Invalid sheet id")?;
+        Ok(serde_wasm_bindgen::to_value(
+            &self.sheet_protection(sheet_id),
+        )?)
+    }
+
+    /// Replaces the protection of a sheet
+    #[wasm_bindgen(js_name = "setSheetProtection")]
+    pub fn js_set_sheet_protection(
+        &mut self,
+        sheet_id: String,
+        protection: String, // SheetProtection
+        cursor: Option<String>,
+    ) -> Result<(), JsValue> {
+        let sheet_id = SheetId::from_str(&sheet_id).map_err(|_| "Invalid sheet id")?;
+        let protection = serde_json::from_str::<SheetProtection>(&protection)
+            .map_err(|e| format!("Error parsing sheet protection: {e}"))?;
+        self.set_sheet_protection(sheet_id, protection, cursor)
+            .map_err(|e| e.to_string())?;
+
+        Ok(())
+    }
+}
@@ -13,7 +13,12 @@ impl GridController {
             return Result::Err("Sheet not found".into());
         };
         sheet.send_validation_warnings_rect(rect);
-        let output = sheet.get_render_cells(rect, self.a1_context());
+        let output = sheet.get_render_cells_with_locale(
+            rect,
+            self.a1_context(),
+            self.locale(),
+            self.grid().date_settings.date_system,
+        );
         Ok(serde_wasm_bindgen::to_value(&output).map_err(|e| e.to_string())?)
     }
 
@@ -1,7 +1,10 @@
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 
-use crate::grid::{GridBounds, Sheet};
+use crate::{
+    Rect,
+    grid::{GridBounds, Sheet, sheet::outlines::OutlineGroup},
+};
 
 #[derive(Serialize, Deserialize, TS)]
 pub struct SheetInfo {
@@ -13,6 +16,14 @@ pub struct SheetInfo {
     pub bounds: GridBounds,
     pub bounds_without_formatting: GridBounds,
     pub format_bounds: GridBounds,
+    pub merge_cells: Vec<Rect>,
+
+    /// Column and row groups, used to render the outline toggles.
+    pub column_outline: Vec<OutlineGroup>,
+    pub row_outline: Vec<OutlineGroup>,
+
+    pub frozen_columns: u32,
+    pub frozen_rows: u32,
 }
 
 impl From<&Sheet> for SheetInfo {
@@ -27,6 +38,11 @@ impl From<&Sheet> for SheetInfo {
             bounds: sheet.bounds(false),
             bounds_without_formatting: sheet.bounds(true),
             format_bounds: sheet.format_bounds(),
+            merge_cells: sheet.merge_cells.rects().to_vec(),
+            column_outline: sheet.outlines.columns.groups(),
+            row_outline: sheet.outlines.rows.groups(),
+            frozen_columns: sheet.frozen_columns,
+            frozen_rows: sheet.frozen_rows,
         }
     }
 }
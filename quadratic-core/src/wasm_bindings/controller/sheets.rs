@@ -106,4 +106,18 @@ impl GridController {
             &self.set_sheet_color(sheet_id, color, cursor),
         )?)
     }
+
+    /// Freezes the first columns and rows of the sheet (0 unfreezes).
+    #[wasm_bindgen(js_name = "setFrozenPanes")]
+    pub fn js_set_frozen_panes(
+        &mut self,
+        sheet_id: String,
+        columns: u32,
+        rows: u32,
+        cursor: Option<String>,
+    ) -> Result<(), JsValue> {
+        let sheet_id = SheetId::from_str(&sheet_id).map_err(|e| e.to_string())?;
+        self.set_frozen_panes(sheet_id, columns, rows, cursor);
+        Ok(())
+    }
 }
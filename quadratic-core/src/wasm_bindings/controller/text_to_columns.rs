@@ -0,0 +1,43 @@
+use crate::{a1::A1Selection, grid::sheet::text_to_columns::TextToColumnsOptions};
+
+use super::*;
+
+#[wasm_bindgen]
+impl GridController {
+    /// Returns a TextToColumnsPreview of the first `max_rows` rows, including
+    /// whether existing cells would be overwritten.
+    #[wasm_bindgen(js_name = "getTextToColumnsPreview")]
+    pub fn js_get_text_to_columns_preview(
+        &self,
+        selection: String,
+        options: String,
+        max_rows: u32,
+    ) -> Result<JsValue, JsValue> {
+        let selection = serde_json::from_str::<A1Selection>(&selection)
+            .map_err(|_| "Unable to parse A1Selection")?;
+        let options =
+            serde_json::from_str::<TextToColumnsOptions>(&options).map_err(|e| e.to_string())?;
+        let preview = self
+            .text_to_columns_preview(&selection, &options, max_rows)
+            .map_err(|e| e.to_string())?;
+
+        Ok(serde_wasm_bindgen::to_value(&preview).map_err(|e| e.to_string())?)
+    }
+
+    #[wasm_bindgen(js_name = "textToColumns")]
+    pub fn js_text_to_columns(
+        &mut self,
+        selection: String,
+        options: String,
+        cursor: Option<String>,
+    ) -> Result<(), JsValue> {
+        let selection = serde_json::from_str::<A1Selection>(&selection)
+            .map_err(|_| "Unable to parse A1Selection")?;
+        let options =
+            serde_json::from_str::<TextToColumnsOptions>(&options).map_err(|e| e.to_string())?;
+        self.text_to_columns(&selection, &options, cursor)
+            .map_err(|e| e.to_string())?;
+
+        Ok(())
+    }
+}
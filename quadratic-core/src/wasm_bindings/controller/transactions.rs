@@ -14,6 +14,7 @@ impl GridController {
         transaction_id: String,
         sequence_num: u32,
         operations: &[u8],
+        editor: Option<String>,
     ) -> Result<JsValue, JsValue> {
         let transaction_id = Uuid::parse_str(&transaction_id)
             .map_err(|e| JsValue::from_str(&format!("Invalid transaction id: {}", e)))?;
@@ -25,6 +26,7 @@ impl GridController {
             transaction_id,
             sequence_num as u64,
             operations,
+            editor.filter(|editor| !editor.is_empty()),
         ))?)
     }
 
@@ -69,7 +71,7 @@ impl GridController {
         let transaction_id = Uuid::parse_str(&transaction_id)
             .map_err(|e| JsValue::from_str(&format!("Invalid transaction id: {}", e)))?;
 
-        self.received_transaction(transaction_id, sequence_num as u64, vec![]);
+        self.received_transaction(transaction_id, sequence_num as u64, vec![], None);
 
         Ok(serde_wasm_bindgen::to_value(&JsResponse {
             result: true,
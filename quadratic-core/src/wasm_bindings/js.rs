@@ -110,6 +110,12 @@ extern "C" {
     pub fn jsRequestRowHeights(transaction_id: String, sheet_id: String, rows: String);
 
     pub fn jsSheetValidations(sheet_id: String, validations: String /* Vec<Validation> */);
+    pub fn jsSheetComments(sheet_id: String, comments: String /* Vec<JsCommentThread> */);
+    pub fn jsSheetConditionalFormats(
+        sheet_id: String,
+        conditional_formats: String, /* Vec<ConditionalFormat> */
+    );
+    pub fn jsSheetProtection(sheet_id: String, protection: String /* SheetProtection */);
     pub fn jsValidationWarning(
         sheet_id: String,
         validations: String, /* Vec<(x, y, validation_id, failed) */
@@ -544,6 +550,30 @@ pub fn jsSheetValidations(sheet_id: String, validations: String /* JsValidation
     );
 }
 
+#[cfg(test)]
+#[allow(non_snake_case)]
+pub fn jsSheetComments(sheet_id: String, comments: String /* Vec<JsCommentThread> */) {
+    js_call("jsSheetComments", format!("{},{}", sheet_id, comments));
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+pub fn jsSheetConditionalFormats(
+    sheet_id: String,
+    conditional_formats: String, /* Vec<ConditionalFormat> */
+) {
+    js_call(
+        "jsSheetConditionalFormats",
+        format!("{},{}", sheet_id, conditional_formats),
+    );
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+pub fn jsSheetProtection(sheet_id: String, protection: String /* SheetProtection */) {
+    js_call("jsSheetProtection", format!("{},{}", sheet_id, protection));
+}
+
 #[cfg(test)]
 #[allow(non_snake_case)]
 pub fn jsRequestRowHeights(
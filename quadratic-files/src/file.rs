@@ -37,8 +37,20 @@ pub(crate) fn export_file(key: &str, grid: Grid) -> Result<Vec<u8>> {
     export(grid).map_err(|e| FilesError::ExportFile(key.into(), e.to_string()))
 }
 
-/// Apply a vec of operations to the grid
-pub(crate) fn apply_transaction(grid: &mut GridController, operations: Vec<Operation>) {
+/// Apply a vec of operations to the grid. The operations are skipped if they
+/// change protected ranges that the editor may not edit. Clients run the same
+/// check when they receive the transaction (and the editor's client drops it
+/// and tells the user), so the file stays in sync with every client.
+pub(crate) fn apply_transaction(
+    grid: &mut GridController,
+    operations: Vec<Operation>,
+    editor: Option<&str>,
+) {
+    if let Err(error) = grid.check_protection(&operations, editor) {
+        tracing::warn!("Skipping transaction from {editor:?}: {error}");
+        return;
+    }
+
     grid.server_apply_transaction(operations, None)
 }
 
@@ -67,7 +79,7 @@ pub(crate) async fn process_transactions(
     file_id: Uuid,
     checkpoint_sequence_num: u64,
     final_sequence_num: u64,
-    operations: Vec<Operation>,
+    transactions: Vec<(Vec<Operation>, Option<String>)>,
 ) -> Result<u64> {
     let mut grid = get_and_load_object(
         storage,
@@ -77,7 +89,11 @@ pub(crate) async fn process_transactions(
     .await?;
     let key = key(file_id, final_sequence_num);
 
-    apply_transaction(&mut grid, operations);
+    // transactions are applied one at a time so each is checked against the
+    // protection left by the previous ones
+    for (operations, editor) in transactions {
+        apply_transaction(&mut grid, operations, editor.as_deref());
+    }
     let body = export_file(&key, grid.into_grid())?;
 
     storage.write(&key, &body.into()).await?;
@@ -141,8 +157,8 @@ pub(crate) async fn process_queue_for_room(
         .cloned()
         .ok_or_else(|| FilesError::Unknown("No transactions to process".into()))?;
 
-    // combine all operations into a single vec
-    let operations = transactions
+    // decode the operations of each transaction
+    let transactions = transactions
         .into_iter()
         .flat_map(|transaction| {
             // tracing::info!(
@@ -152,10 +168,10 @@ pub(crate) async fn process_queue_for_room(
             // );
 
             Transaction::decompress_and_deserialize::<Vec<Operation>>(&transaction.operations)
+                .map(|operations| (operations, transaction.editor))
                 .map_err(|e| FilesError::Serialization(e.to_string()))
         })
-        .flatten()
-        .collect::<Vec<Operation>>();
+        .collect::<Vec<_>>();
 
     // process the transactions and save the file to S3
     let start_processing = Utc::now();
@@ -164,7 +180,7 @@ pub(crate) async fn process_queue_for_room(
         file_id,
         checkpoint_sequence_num,
         last_sequence_num,
-        operations,
+        transactions,
     )
     .await?;
 
@@ -303,7 +319,7 @@ mod tests {
         );
 
         // apply a transaction to the file
-        apply_transaction(&mut gc, transaction.operations);
+        apply_transaction(&mut gc, transaction.operations, None);
         let sheet = gc.grid().try_sheet(sheet_id).unwrap();
 
         assert_eq!(
@@ -11,7 +11,6 @@ use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::error::{ErrorLevel, MpError, Result};
-use crate::get_mut_room;
 use crate::message::response::{BinaryTransaction, Transaction};
 use crate::message::{
     broadcast, request::MessageRequest, response::MessageResponse, send_user_message,
@@ -27,6 +26,7 @@ use crate::state::{
     pubsub::GROUP_NAME,
     user::{User, UserState},
 };
+use crate::{get_mut_room, get_room};
 
 /// Handle incoming messages.  All requests and responses are strictly typed.
 #[tracing::instrument(level = "trace")]
@@ -187,6 +187,10 @@ pub(crate) async fn handle_message(
                 &operations
             );
 
+            // the sender's email is used to check protected ranges when the
+            // transaction is applied (by the files service and by each client)
+            let editor = get_room!(state, file_id)?.get_user(&session_id)?.email;
+
             // get and increment the room's sequence_num
             let room_sequence_num = get_mut_room!(state, file_id)?.increment_sequence_num();
             let decoded_operations = STANDARD.decode(&operations).map_err(|e| {
@@ -198,7 +202,13 @@ pub(crate) async fn handle_message(
 
             // add the transaction to the transaction queue
             let sequence_num = state
-                .push(id, file_id, decoded_operations, room_sequence_num)
+                .push(
+                    id,
+                    file_id,
+                    decoded_operations,
+                    room_sequence_num,
+                    editor.to_owned(),
+                )
                 .await?;
 
             // broadcast the transaction to all users in the room (except the initiator)
@@ -207,6 +217,7 @@ pub(crate) async fn handle_message(
                 file_id,
                 sequence_num,
                 operations,
+                editor: Some(editor),
             };
             broadcast(vec![session_id], file_id, Arc::clone(&state), response);
 
@@ -239,6 +250,10 @@ pub(crate) async fn handle_message(
                 &operations
             );
 
+            // the sender's email is used to check protected ranges when the
+            // transaction is applied (by the files service and by each client)
+            let editor = get_room!(state, file_id)?.get_user(&session_id)?.email;
+
             // get and increment the room's sequence_num
             let room_sequence_num = get_mut_room!(state, file_id)?.increment_sequence_num();
 
@@ -246,7 +261,13 @@ pub(crate) async fn handle_message(
             // we need to clone operations since we broadcast it later
             let start_push_pubsub = std::time::Instant::now();
             let sequence_num = state
-                .push(id, file_id, operations.to_owned(), room_sequence_num)
+                .push(
+                    id,
+                    file_id,
+                    operations.to_owned(),
+                    room_sequence_num,
+                    editor.to_owned(),
+                )
                 .await?;
             tracing::trace!("Pushed to pubsub in {:?}", start_push_pubsub.elapsed());
 
@@ -256,6 +277,7 @@ pub(crate) async fn handle_message(
                 file_id,
                 sequence_num,
                 operations,
+                editor: Some(editor),
             };
             broadcast(vec![session_id], file_id, Arc::clone(&state), response);
 
@@ -654,12 +676,14 @@ pub(crate) mod tests {
             file_id,
             operations: compressed_ops_1.clone(),
             sequence_num: 1,
+            editor: Some(user_1.email.clone()),
         };
         let transaction_2 = BinaryTransaction {
             id,
             file_id,
             operations: compressed_ops_2.clone(),
             sequence_num: 2,
+            editor: Some(user_1.email.clone()),
         };
         let response = MessageResponse::BinaryTransactions {
             transactions: vec![transaction_1, transaction_2],
@@ -32,6 +32,7 @@ impl TryFrom<SendTransaction> for MessageResponse {
             file_id: transaction.file_id.parse()?,
             sequence_num: 0, // This will be set by the server
             operations: transaction.operations,
+            editor: None,
         })
     }
 }
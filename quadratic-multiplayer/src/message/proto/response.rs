@@ -34,12 +34,14 @@ pub(crate) fn encode_message(message: MessageResponse) -> Result<Vec<u8>> {
             file_id,
             sequence_num,
             operations,
+            editor,
         } => encode_transaction(ReceiveTransaction {
             r#type: "BinaryTransaction".to_string(),
             id: id.to_string(),
             file_id: file_id.to_string(),
             sequence_num,
             operations,
+            editor: editor.unwrap_or_default(),
         }),
         MessageResponse::BinaryTransactions { transactions } => {
             let transactions = transactions
@@ -68,6 +70,7 @@ impl From<BinaryTransaction> for ReceiveTransaction {
             file_id: binary_transaction.file_id.to_string(),
             sequence_num: binary_transaction.sequence_num,
             operations: binary_transaction.operations,
+            editor: binary_transaction.editor.unwrap_or_default(),
         }
     }
 }
@@ -17,6 +17,7 @@ pub(crate) struct Transaction {
     pub(crate) file_id: Uuid,
     pub(crate) sequence_num: u64,
     pub(crate) operations: String,
+    pub(crate) editor: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
@@ -25,6 +26,7 @@ pub(crate) struct BinaryTransaction {
     pub(crate) file_id: Uuid,
     pub(crate) sequence_num: u64,
     pub(crate) operations: Vec<u8>,
+    pub(crate) editor: Option<String>,
 }
 
 // TODO: to be deleted after the next release
@@ -51,17 +53,21 @@ pub(crate) enum MessageResponse {
         file_id: Uuid,
         update: UserStateUpdate,
     },
+    // the editor is sent so that clients apply the same protection checks
+    // as the files service
     Transaction {
         id: Uuid,
         file_id: Uuid,
         sequence_num: u64,
         operations: String,
+        editor: Option<String>,
     },
     BinaryTransaction {
         id: Uuid,
         file_id: Uuid,
         sequence_num: u64,
         operations: Vec<u8>,
+        editor: Option<String>,
     },
     TransactionAck {
         id: Uuid,
@@ -103,6 +109,7 @@ impl From<TransactionServer> for Transaction {
             file_id: transaction_server.file_id,
             sequence_num: transaction_server.sequence_num,
             operations: STANDARD.encode(&transaction_server.operations),
+            editor: transaction_server.editor,
         }
     }
 }
@@ -114,6 +121,7 @@ impl From<TransactionServer> for BinaryTransaction {
             file_id: transaction_server.file_id,
             sequence_num: transaction_server.sequence_num,
             operations: transaction_server.operations,
+            editor: transaction_server.editor,
         }
     }
 }
@@ -4,9 +4,11 @@ use quadratic_rust_shared::pubsub::{
 };
 use uuid::Uuid;
 
+use quadratic_rust_shared::protobuf::quadratic::transaction::ReceiveTransaction;
+
 use crate::{
     error::{MpError, Result},
-    message::{proto::response::encode_message, response::MessageResponse},
+    message::proto::response::encode_transaction,
 };
 
 use super::State;
@@ -40,16 +42,19 @@ impl PubSub {
         file_id: Uuid,
         operations: Vec<u8>,
         sequence_num: u64,
+        editor: String,
     ) -> Result<u64> {
-        let transaction = MessageResponse::BinaryTransaction {
-            id,
-            file_id,
-            operations,
+        let transaction = ReceiveTransaction {
+            r#type: "BinaryTransaction".to_string(),
+            id: id.to_string(),
+            file_id: file_id.to_string(),
             sequence_num,
+            operations,
+            editor,
         };
 
-        // turn BinaryTransaction into Protobuf
-        let encoded = encode_message(transaction)?;
+        // turn the transaction into Protobuf
+        let encoded = encode_transaction(transaction)?;
 
         // add header to the message
         let transaction_compressed =
@@ -114,11 +119,12 @@ impl State {
         file_id: Uuid,
         operations: Vec<u8>,
         sequence_num: u64,
+        editor: String,
     ) -> Result<u64> {
         self.pubsub
             .lock()
             .await
-            .push_protobuf(id, file_id, operations, sequence_num)
+            .push_protobuf(id, file_id, operations, sequence_num, editor)
             .await
     }
 
@@ -215,7 +221,13 @@ mod tests {
             Transaction::serialize_and_compress(vec![operations_2.clone()]).unwrap();
 
         state
-            .push(transaction_id_1, file_id, transaction_1.clone(), 1)
+            .push(
+                transaction_id_1,
+                file_id,
+                transaction_1.clone(),
+                1,
+                "user@example.com".to_string(),
+            )
             .await
             .unwrap();
         let transactions = state.get_messages_from_pubsub(&file_id, 0).await.unwrap();
@@ -224,12 +236,19 @@ mod tests {
             file_id,
             operations: transaction_1,
             sequence_num: 1,
+            editor: Some("user@example.com".to_string()),
         };
 
         assert_eq!(transactions[0], expected_transaction_1);
 
         state
-            .push(transaction_id_2, file_id, transaction_2.clone(), 2)
+            .push(
+                transaction_id_2,
+                file_id,
+                transaction_2.clone(),
+                2,
+                String::new(),
+            )
             .await
             .unwrap();
         let transaction = state.get_messages_from_pubsub(&file_id, 0).await.unwrap();
@@ -238,6 +257,7 @@ mod tests {
             file_id,
             operations: transaction_2,
             sequence_num: 2,
+            editor: None,
         };
         assert_eq!(
             transaction,